@@ -0,0 +1,14 @@
+CREATE TABLE share_links(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    slug VARCHAR(64) NOT NULL UNIQUE,
+    scope VARCHAR(20) NOT NULL,
+    location_id BIGINT UNSIGNED,
+    collection_id BIGINT UNSIGNED,
+    user_id BIGINT UNSIGNED NOT NULL,
+    expires_at TIMESTAMP NULL,
+    revoked_at TIMESTAMP NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    CONSTRAINT fk_share_links_locations FOREIGN KEY(location_id) REFERENCES locations(id) ON DELETE CASCADE,
+    CONSTRAINT fk_share_links_collections FOREIGN KEY(collection_id) REFERENCES collections(id) ON DELETE CASCADE,
+    CONSTRAINT fk_share_links_users FOREIGN KEY(user_id) REFERENCES users(id) ON DELETE CASCADE
+);
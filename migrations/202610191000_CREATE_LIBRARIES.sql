@@ -0,0 +1,64 @@
+CREATE TABLE libraries(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    name VARCHAR(300) NOT NULL,
+    owner_id BIGINT UNSIGNED NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    CONSTRAINT fk_libraries_users FOREIGN KEY(owner_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE TABLE library_members(
+    library_id BIGINT UNSIGNED NOT NULL,
+    user_id BIGINT UNSIGNED NOT NULL,
+    role VARCHAR(10) NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    PRIMARY KEY(library_id, user_id),
+    CONSTRAINT fk_library_members_libraries FOREIGN KEY(library_id) REFERENCES libraries(id) ON DELETE CASCADE,
+    CONSTRAINT fk_library_members_users FOREIGN KEY(user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE TABLE library_invitations(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    library_id BIGINT UNSIGNED NOT NULL,
+    email VARCHAR(300) NOT NULL,
+    role VARCHAR(10) NOT NULL,
+    invited_by BIGINT UNSIGNED NOT NULL,
+    status VARCHAR(10) NOT NULL DEFAULT 'pending',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    responded_at TIMESTAMP NULL,
+    CONSTRAINT fk_library_invitations_libraries FOREIGN KEY(library_id) REFERENCES libraries(id) ON DELETE CASCADE,
+    CONSTRAINT fk_library_invitations_users FOREIGN KEY(invited_by) REFERENCES users(id) ON DELETE CASCADE
+);
+
+-- Every existing user gets a personal library holding everything they own
+INSERT INTO libraries (name, owner_id)
+    SELECT 'My library', u.id FROM users u;
+INSERT INTO library_members (library_id, user_id, role)
+    SELECT l.id, l.owner_id, 'owner' FROM libraries l;
+
+ALTER TABLE locations ADD COLUMN library_id BIGINT UNSIGNED;
+UPDATE locations t INNER JOIN libraries l ON l.owner_id = t.user_id SET t.library_id = l.id;
+ALTER TABLE locations
+    MODIFY library_id BIGINT UNSIGNED NOT NULL,
+    ADD CONSTRAINT fk_locations_libraries FOREIGN KEY(library_id) REFERENCES libraries(id) ON DELETE CASCADE,
+    DROP INDEX uq_locations_users,
+    ADD CONSTRAINT uq_locations_libraries UNIQUE(name, library_id);
+
+ALTER TABLE collections ADD COLUMN library_id BIGINT UNSIGNED;
+UPDATE collections t INNER JOIN libraries l ON l.owner_id = t.user_id SET t.library_id = l.id;
+ALTER TABLE collections
+    MODIFY library_id BIGINT UNSIGNED NOT NULL,
+    ADD CONSTRAINT fk_collections_libraries FOREIGN KEY(library_id) REFERENCES libraries(id) ON DELETE CASCADE,
+    DROP INDEX uq_collections_users,
+    ADD CONSTRAINT uq_collections_libraries UNIQUE(name, library_id);
+
+ALTER TABLE books ADD COLUMN library_id BIGINT UNSIGNED;
+UPDATE books t INNER JOIN libraries l ON l.owner_id = t.user_id SET t.library_id = l.id;
+ALTER TABLE books
+    MODIFY library_id BIGINT UNSIGNED NOT NULL,
+    ADD CONSTRAINT fk_books_libraries FOREIGN KEY(library_id) REFERENCES libraries(id) ON DELETE CASCADE;
+
+ALTER TABLE share_links ADD COLUMN library_id BIGINT UNSIGNED;
+UPDATE share_links t INNER JOIN libraries l ON l.owner_id = t.user_id SET t.library_id = l.id;
+ALTER TABLE share_links
+    MODIFY library_id BIGINT UNSIGNED NOT NULL,
+    ADD CONSTRAINT fk_share_links_libraries FOREIGN KEY(library_id) REFERENCES libraries(id) ON DELETE CASCADE;
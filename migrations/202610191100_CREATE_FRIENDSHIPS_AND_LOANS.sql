@@ -0,0 +1,44 @@
+ALTER TABLE libraries ADD COLUMN visibility VARCHAR(10) NOT NULL DEFAULT 'private';
+
+CREATE TABLE friendships(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    requester_id BIGINT UNSIGNED NOT NULL,
+    addressee_id BIGINT UNSIGNED NOT NULL,
+    status VARCHAR(10) NOT NULL DEFAULT 'pending',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    responded_at TIMESTAMP NULL,
+    CONSTRAINT fk_friendships_requesters FOREIGN KEY(requester_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_friendships_addressees FOREIGN KEY(addressee_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT uq_friendships_users UNIQUE(requester_id, addressee_id)
+);
+
+CREATE TABLE borrow_requests(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    book_id BIGINT UNSIGNED NOT NULL,
+    library_id BIGINT UNSIGNED NOT NULL,
+    requester_id BIGINT UNSIGNED NOT NULL,
+    owner_id BIGINT UNSIGNED NOT NULL,
+    status VARCHAR(10) NOT NULL DEFAULT 'pending',
+    message VARCHAR(500) NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    responded_at TIMESTAMP NULL,
+    CONSTRAINT fk_borrow_requests_books FOREIGN KEY(book_id) REFERENCES books(id) ON DELETE CASCADE,
+    CONSTRAINT fk_borrow_requests_libraries FOREIGN KEY(library_id) REFERENCES libraries(id) ON DELETE CASCADE,
+    CONSTRAINT fk_borrow_requests_requesters FOREIGN KEY(requester_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_borrow_requests_owners FOREIGN KEY(owner_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE TABLE loans(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    book_id BIGINT UNSIGNED NOT NULL,
+    borrow_request_id BIGINT UNSIGNED NOT NULL,
+    lender_id BIGINT UNSIGNED NOT NULL,
+    borrower_id BIGINT UNSIGNED NOT NULL,
+    loaned_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    returned_at TIMESTAMP NULL,
+    CONSTRAINT fk_loans_books FOREIGN KEY(book_id) REFERENCES books(id) ON DELETE CASCADE,
+    CONSTRAINT fk_loans_borrow_requests FOREIGN KEY(borrow_request_id) REFERENCES borrow_requests(id) ON DELETE CASCADE,
+    CONSTRAINT fk_loans_lenders FOREIGN KEY(lender_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_loans_borrowers FOREIGN KEY(borrower_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT uq_loans_borrow_requests UNIQUE(borrow_request_id)
+);
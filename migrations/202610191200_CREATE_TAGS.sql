@@ -0,0 +1,16 @@
+CREATE TABLE tags(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    name VARCHAR(50) NOT NULL,
+    user_id BIGINT UNSIGNED NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    CONSTRAINT fk_tags_users FOREIGN KEY(user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT uq_tags_users_names UNIQUE(user_id, name)
+);
+
+CREATE TABLE book_tags(
+    book_id BIGINT UNSIGNED NOT NULL,
+    tag_id BIGINT UNSIGNED NOT NULL,
+    PRIMARY KEY(book_id, tag_id),
+    CONSTRAINT fk_book_tags_books FOREIGN KEY(book_id) REFERENCES books(id) ON DELETE CASCADE,
+    CONSTRAINT fk_book_tags_tags FOREIGN KEY(tag_id) REFERENCES tags(id) ON DELETE CASCADE
+);
@@ -0,0 +1,26 @@
+CREATE TABLE custom_fields(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    name VARCHAR(50) NOT NULL,
+    field_type VARCHAR(10) NOT NULL,
+    options JSON NULL,
+    user_id BIGINT UNSIGNED NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    CONSTRAINT fk_custom_fields_users FOREIGN KEY(user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT uq_custom_fields_users_names UNIQUE(user_id, name)
+);
+
+CREATE TABLE book_custom_field_values(
+    book_id BIGINT UNSIGNED NOT NULL,
+    custom_field_id BIGINT UNSIGNED NOT NULL,
+    value_text VARCHAR(500) NULL,
+    value_number DOUBLE NULL,
+    value_date DATE NULL,
+    value_boolean BOOLEAN NULL,
+    PRIMARY KEY(book_id, custom_field_id),
+    CONSTRAINT fk_book_custom_field_values_books FOREIGN KEY(book_id) REFERENCES books(id) ON DELETE CASCADE,
+    CONSTRAINT fk_book_custom_field_values_custom_fields FOREIGN KEY(custom_field_id) REFERENCES custom_fields(id) ON DELETE CASCADE,
+    INDEX idx_book_custom_field_values_text(custom_field_id, value_text),
+    INDEX idx_book_custom_field_values_number(custom_field_id, value_number),
+    INDEX idx_book_custom_field_values_date(custom_field_id, value_date),
+    INDEX idx_book_custom_field_values_boolean(custom_field_id, value_boolean)
+);
@@ -0,0 +1,13 @@
+CREATE TABLE notes(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    book_id BIGINT UNSIGNED NOT NULL,
+    user_id BIGINT UNSIGNED NOT NULL,
+    note_type VARCHAR(10) NOT NULL DEFAULT 'note',
+    page INT UNSIGNED NULL,
+    text TEXT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP NOT NULL,
+    CONSTRAINT fk_notes_books FOREIGN KEY(book_id) REFERENCES books(id) ON DELETE CASCADE,
+    CONSTRAINT fk_notes_users FOREIGN KEY(user_id) REFERENCES users(id) ON DELETE CASCADE,
+    INDEX idx_notes_users_books(user_id, book_id)
+);
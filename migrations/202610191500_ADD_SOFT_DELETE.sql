@@ -0,0 +1,17 @@
+ALTER TABLE books
+    ADD COLUMN deleted_at TIMESTAMP NULL,
+    ADD INDEX idx_books_libraries_deleted_at(library_id, deleted_at);
+
+-- Names only have to be unique among the items which are not in the trash,
+-- `live_marker` is NULL for trashed rows and NULLs never collide.
+ALTER TABLE locations
+    ADD COLUMN deleted_at TIMESTAMP NULL,
+    ADD COLUMN live_marker TINYINT AS (IF(deleted_at IS NULL, 1, NULL)) STORED,
+    DROP INDEX uq_locations_libraries,
+    ADD CONSTRAINT uq_locations_libraries UNIQUE(name, library_id, live_marker);
+
+ALTER TABLE collections
+    ADD COLUMN deleted_at TIMESTAMP NULL,
+    ADD COLUMN live_marker TINYINT AS (IF(deleted_at IS NULL, 1, NULL)) STORED,
+    DROP INDEX uq_collections_libraries,
+    ADD CONSTRAINT uq_collections_libraries UNIQUE(name, library_id, live_marker);
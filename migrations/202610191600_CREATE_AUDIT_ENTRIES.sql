@@ -0,0 +1,13 @@
+CREATE TABLE audit_entries(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    entity_type VARCHAR(20) NOT NULL,
+    entity_id BIGINT UNSIGNED NOT NULL,
+    library_id BIGINT UNSIGNED NOT NULL,
+    actor_id BIGINT UNSIGNED NOT NULL,
+    action VARCHAR(10) NOT NULL,
+    changes JSON NOT NULL,
+    snapshot JSON NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    CONSTRAINT fk_audit_entries_libraries FOREIGN KEY(library_id) REFERENCES libraries(id) ON DELETE CASCADE,
+    INDEX idx_audit_entries_entities(entity_type, entity_id, created_at)
+);
@@ -0,0 +1,3 @@
+ALTER TABLE books ADD COLUMN version BIGINT UNSIGNED NOT NULL DEFAULT 1;
+ALTER TABLE collections ADD COLUMN version BIGINT UNSIGNED NOT NULL DEFAULT 1;
+ALTER TABLE locations ADD COLUMN version BIGINT UNSIGNED NOT NULL DEFAULT 1;
@@ -0,0 +1,37 @@
+CREATE TABLE webhooks(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    user_id BIGINT UNSIGNED NOT NULL,
+    library_id BIGINT UNSIGNED NOT NULL,
+    url VARCHAR(2000) NOT NULL,
+    secret VARCHAR(64) NOT NULL,
+    events JSON NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    CONSTRAINT fk_webhooks_users FOREIGN KEY(user_id) REFERENCES users(id) ON DELETE CASCADE,
+    CONSTRAINT fk_webhooks_libraries FOREIGN KEY(library_id) REFERENCES libraries(id) ON DELETE CASCADE
+);
+
+CREATE TABLE webhook_deliveries(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    webhook_id BIGINT UNSIGNED NOT NULL,
+    event VARCHAR(30) NOT NULL,
+    payload JSON NOT NULL,
+    status VARCHAR(10) NOT NULL DEFAULT 'pending',
+    attempts INT UNSIGNED NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    last_response_status SMALLINT UNSIGNED NULL,
+    last_error VARCHAR(500) NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    delivered_at TIMESTAMP NULL,
+    CONSTRAINT fk_webhook_deliveries_webhooks FOREIGN KEY(webhook_id) REFERENCES webhooks(id) ON DELETE CASCADE,
+    INDEX idx_webhook_deliveries_due(status, next_attempt_at),
+    INDEX idx_webhook_deliveries_webhooks(webhook_id, created_at)
+);
+
+-- The audit trail doubles as the queue of book events, changes made before
+-- webhooks existed are never published
+ALTER TABLE audit_entries
+    ADD COLUMN published_at TIMESTAMP NULL,
+    ADD INDEX idx_audit_entries_published_at(published_at);
+UPDATE audit_entries SET published_at = created_at;
+
+ALTER TABLE loans ADD COLUMN overdue_notified_at TIMESTAMP NULL;
@@ -0,0 +1,34 @@
+-- Kept by the database itself so every write path is tracked, microseconds
+-- make changes done within the same second distinguishable.
+ALTER TABLE books
+    ADD COLUMN updated_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6) ON UPDATE CURRENT_TIMESTAMP(6),
+    ADD INDEX idx_books_libraries_updated_at(library_id, updated_at, id);
+
+ALTER TABLE collections
+    ADD COLUMN updated_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6) ON UPDATE CURRENT_TIMESTAMP(6),
+    ADD INDEX idx_collections_libraries_updated_at(library_id, updated_at);
+
+ALTER TABLE locations
+    ADD COLUMN updated_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6) ON UPDATE CURRENT_TIMESTAMP(6),
+    ADD INDEX idx_locations_libraries_updated_at(library_id, updated_at);
+
+-- Rows removed for good, by purges or library restores, are remembered here
+-- so clients syncing later learn about the deletion.
+CREATE TABLE sync_tombstones(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    entity_type VARCHAR(20) NOT NULL,
+    entity_id BIGINT UNSIGNED NOT NULL,
+    library_id BIGINT UNSIGNED NOT NULL,
+    deleted_at TIMESTAMP(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    CONSTRAINT fk_sync_tombstones_libraries FOREIGN KEY(library_id) REFERENCES libraries(id) ON DELETE CASCADE,
+    INDEX idx_sync_tombstones_libraries_deleted_at(library_id, deleted_at)
+);
+
+CREATE TRIGGER trg_books_sync_tombstones AFTER DELETE ON books FOR EACH ROW
+    INSERT INTO sync_tombstones(entity_type, entity_id, library_id) VALUES ('book', OLD.id, OLD.library_id);
+
+CREATE TRIGGER trg_collections_sync_tombstones AFTER DELETE ON collections FOR EACH ROW
+    INSERT INTO sync_tombstones(entity_type, entity_id, library_id) VALUES ('collection', OLD.id, OLD.library_id);
+
+CREATE TRIGGER trg_locations_sync_tombstones AFTER DELETE ON locations FOR EACH ROW
+    INSERT INTO sync_tombstones(entity_type, entity_id, library_id) VALUES ('location', OLD.id, OLD.library_id);
@@ -0,0 +1,19 @@
+CREATE TABLE user_two_factors(
+    user_id BIGINT UNSIGNED PRIMARY KEY,
+    secret VARCHAR(64) NOT NULL,
+    -- Two-factor authentication is only required once confirmed.
+    confirmed_at TIMESTAMP NULL,
+    -- Codes can't be used twice, only ones of a later time step are accepted.
+    last_used_step BIGINT UNSIGNED NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    CONSTRAINT fk_user_two_factors_users FOREIGN KEY(user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE TABLE user_recovery_codes(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    user_id BIGINT UNSIGNED NOT NULL,
+    code_hash CHAR(64) NOT NULL,
+    used_at TIMESTAMP NULL,
+    CONSTRAINT fk_user_recovery_codes_users FOREIGN KEY(user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE INDEX idx_user_recovery_codes_users_code_hash(user_id, code_hash)
+);
@@ -0,0 +1,12 @@
+CREATE TABLE user_identities(
+    id BIGINT UNSIGNED PRIMARY KEY AUTO_INCREMENT,
+    user_id BIGINT UNSIGNED NOT NULL,
+    -- Name of the OpenID Connect provider in the settings.
+    provider VARCHAR(64) NOT NULL,
+    -- `sub` claim, unique and stable within the provider.
+    subject VARCHAR(255) NOT NULL,
+    email VARCHAR(255) NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    CONSTRAINT fk_user_identities_users FOREIGN KEY(user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE INDEX idx_user_identities_provider_subject(provider, subject)
+);
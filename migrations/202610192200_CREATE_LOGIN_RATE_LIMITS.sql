@@ -0,0 +1,17 @@
+-- Used by the mysql login rate limit store, so every instance of the
+-- application shares the same limits.
+CREATE TABLE login_rate_limit_buckets(
+    bucket_key VARCHAR(320) PRIMARY KEY,
+    tokens DOUBLE NOT NULL,
+    refilled_at TIMESTAMP(6) NOT NULL,
+    full_at TIMESTAMP(6) NOT NULL,
+    INDEX idx_login_rate_limit_buckets_full_at(full_at)
+);
+
+CREATE TABLE login_failures(
+    account_key VARCHAR(320) PRIMARY KEY,
+    failures INT UNSIGNED NOT NULL,
+    last_failure_at TIMESTAMP(6) NULL,
+    locked_until TIMESTAMP(6) NULL,
+    INDEX idx_login_failures_last_failure_at(last_failure_at)
+);
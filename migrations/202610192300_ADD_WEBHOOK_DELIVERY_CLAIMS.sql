@@ -0,0 +1,3 @@
+-- Deliveries are claimed by a worker until `claimed_until`, other workers
+-- skip them meanwhile and take them over once the claim expires
+ALTER TABLE webhook_deliveries ADD COLUMN claimed_until TIMESTAMP NULL;
@@ -7,6 +7,11 @@ pub struct Settings {
     pub database: DatabaseSettings,
     pub application: ApplicationSettings,
     pub token: TokenSettings,
+    pub trash: TrashSettings,
+    pub graphql: GraphQLSettings,
+    pub webhooks: WebhookSettings,
+    pub login_rate_limit: LoginRateLimitSettings,
+    pub oidc: OidcSettings,
 }
 #[derive(serde::Deserialize)]
 pub struct ApplicationSettings {
@@ -20,6 +25,100 @@ pub struct TokenSettings {
     #[serde(deserialize_with = "deserialize_number_from_string")]
     pub expiration_time: i64,
 }
+#[derive(serde::Deserialize, Clone)]
+pub struct TrashSettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub retention_days: i64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub purge_interval_seconds: u64,
+}
+#[derive(serde::Deserialize, Clone)]
+pub struct WebhookSettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub delivery_interval_seconds: u64,
+    /// Attempts made before a delivery is given up on, retries wait twice as
+    /// long as the previous one starting from `backoff_base_seconds`.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub max_attempts: u32,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub backoff_base_seconds: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub timeout_seconds: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub loan_overdue_days: i64,
+    /// Hosts webhooks may be delivered to even though they resolve to a
+    /// loopback or private address.
+    #[serde(default)]
+    pub allowed_hosts: Vec<String>,
+}
+#[derive(serde::Deserialize, Clone)]
+pub struct LoginRateLimitSettings {
+    /// Login attempts a client address can make at once, it regains one
+    /// every `ip_refill_seconds`.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub ip_capacity: u32,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub ip_refill_seconds: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub account_capacity: u32,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub account_refill_seconds: u64,
+    /// Failed logins in a row after which the account is locked for
+    /// `lockout_base_seconds`, doubled with every further failure up to
+    /// `lockout_max_seconds`.
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub lockout_threshold: u32,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub lockout_base_seconds: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub lockout_max_seconds: u64,
+    /// Whether the client address is read from `Forwarded` or
+    /// `X-Forwarded-For`, only safe behind a proxy setting them.
+    pub trust_forwarded_for: bool,
+    #[serde(default)]
+    pub store: RateLimitStoreBackend,
+}
+#[derive(serde::Deserialize, Clone, Copy, Default, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RateLimitStoreBackend {
+    #[default]
+    Memory,
+    MySQL,
+}
+#[derive(serde::Deserialize, Clone)]
+pub struct OidcSettings {
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub state_expiration_seconds: u64,
+    #[serde(default)]
+    pub providers: Vec<OidcProviderSettings>,
+}
+#[derive(serde::Deserialize, Clone)]
+pub struct OidcProviderSettings {
+    /// Identifies the provider in the routes and in the identities linked
+    /// to it, changing it unlinks them.
+    pub name: String,
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: Secret<String>,
+    pub redirect_uri: String,
+    #[serde(default = "default_oidc_scopes")]
+    pub scopes: Vec<String>,
+    /// Whether users signing in for the first time get an account, otherwise
+    /// identities are only linked to the account of their verified email.
+    #[serde(default)]
+    pub auto_create_users: bool,
+}
+fn default_oidc_scopes() -> Vec<String> {
+    vec![
+        "openid".to_string(),
+        "email".to_string(),
+        "profile".to_string(),
+    ]
+}
+#[derive(serde::Deserialize, Clone)]
+pub struct GraphQLSettings {
+    pub playground: bool,
+}
 #[derive(serde::Deserialize)]
 pub struct DatabaseSettings {
     pub username: String,
@@ -31,7 +130,7 @@ pub struct DatabaseSettings {
     pub require_ssl: bool,
 }
 impl DatabaseSettings {
-    pub fn connection_options(&self) -> MySqlConnectOptions {
+    pub fn without_db(&self) -> MySqlConnectOptions {
         let ssl_mode = if self.require_ssl {
             MySqlSslMode::Required
         } else {
@@ -42,9 +141,11 @@ impl DatabaseSettings {
             .username(&self.username)
             .password(self.password.expose_secret())
             .port(self.port)
-            .database(&self.database_name)
             .ssl_mode(ssl_mode)
     }
+    pub fn connection_options(&self) -> MySqlConnectOptions {
+        self.without_db().database(&self.database_name)
+    }
 }
 
 pub fn get_configuration() -> Result<Settings, config::ConfigError> {
@@ -1,6 +1,9 @@
 use std::net::TcpListener;
+use std::sync::Arc;
 
 use new_online_librarian_backend::configuration::get_configuration;
+use new_online_librarian_backend::modules::books::infra::jobs::trash_purge_job::spawn_trash_purge_job;
+use new_online_librarian_backend::modules::webhooks::infra::jobs::webhook_delivery_job::spawn_webhook_delivery_job;
 use new_online_librarian_backend::startup::run;
 use new_online_librarian_backend::telemetry::{get_subscriber, init_subscriber};
 use sqlx::mysql::MySqlPoolOptions;
@@ -20,5 +23,19 @@ async fn main() -> Result<(), std::io::Error> {
         configuration.application.host, configuration.application.port
     );
     let listener = TcpListener::bind(address)?;
-    run(listener, connection_pool, configuration.token)?.await
+    spawn_trash_purge_job(Arc::new(connection_pool.clone()), configuration.trash);
+    spawn_webhook_delivery_job(
+        Arc::new(connection_pool.clone()),
+        configuration.webhooks.clone(),
+    );
+    run(
+        listener,
+        connection_pool,
+        configuration.token,
+        configuration.graphql,
+        configuration.login_rate_limit,
+        configuration.oidc,
+        configuration.webhooks,
+    )?
+    .await
 }
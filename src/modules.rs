@@ -1,3 +1,10 @@
 pub mod books;
+pub mod events;
+pub mod friends;
+pub mod graphql;
+pub mod libraries;
+pub mod loans;
 pub mod shared;
+pub mod sync;
 pub mod users;
+pub mod webhooks;
@@ -1,11 +1,53 @@
+pub mod audit_entry_dto;
 pub mod author_dto;
+pub mod backup_book_dto;
+pub mod backup_collection_dto;
+pub mod backup_custom_field_dto;
+pub mod backup_location_dto;
+pub mod backup_tag_dto;
+pub mod barcode_lookup_dto;
+pub mod book_import_report_dto;
+pub mod book_import_row_dto;
+pub mod bulk_book_item_result_dto;
+pub mod bulk_book_operation_dto;
+pub mod bulk_book_operation_result_dto;
 pub mod collection_dto;
 pub mod complete_book_dto;
 pub mod create_book_dto;
 pub mod create_collection_dto;
+pub mod create_custom_field_dto;
 pub mod create_location_dto;
+pub mod create_note_dto;
+pub mod create_share_link_dto;
+pub mod create_tag_dto;
+pub mod custom_field_dto;
+pub mod export_books_params;
 pub mod find_all_collections_from_user_dto;
+pub mod find_all_custom_fields_from_user_dto;
 pub mod find_all_locations_from_user_dto;
+pub mod find_all_notes_from_book_dto;
+pub mod find_all_share_links_from_user_dto;
+pub mod find_all_tags_from_user_dto;
+pub mod find_all_trash_items_dto;
+pub mod find_book_history_dto;
 pub mod genre_dto;
+pub mod get_all_books_params;
+pub mod get_shared_books_params;
+pub mod import_books_csv_dto;
+pub mod import_goodreads_dto;
+pub mod import_library_thing_dto;
 pub mod language_dto;
+pub mod library_backup_manifest_dto;
+pub mod library_restore_report_dto;
 pub mod location_dto;
+pub mod note_dto;
+pub mod public_book_dto;
+pub mod public_collection_dto;
+pub mod public_location_dto;
+pub mod restore_library_dto;
+pub mod restore_library_params;
+pub mod search_notes_params;
+pub mod share_link_dto;
+pub mod tag_dto;
+pub mod tag_usage_dto;
+pub mod trash_item_dto;
@@ -0,0 +1,16 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct AuditEntryDto {
+    pub id: u64,
+    pub entity_type: String,
+    pub entity_id: u64,
+    pub library_id: u64,
+    pub action: String,
+    pub actor_id: u64,
+    pub actor_name: String,
+    pub changes: Map<String, Value>,
+    pub created_at: Option<String>,
+}
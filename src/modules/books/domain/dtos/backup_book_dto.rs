@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{author_dto::AuthorDto, genre_dto::GenreDto, language_dto::LanguageDto};
+
+/// Book as stored in a backup manifest, `collection_id`, `location_id` and
+/// `tag_ids` reference the ids of the manifest, not the ids of the restored
+/// account. Custom field values are keyed by the name of the field.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BackupBookDto {
+    pub id: u64,
+    pub title: String,
+    pub authors: Vec<AuthorDto>,
+    pub publisher: String,
+    pub languages: Vec<LanguageDto>,
+    pub edition: Option<String>,
+    pub isbn: Option<String>,
+    pub year: Option<String>,
+    pub genres: Option<Vec<GenreDto>>,
+    pub cover: Option<String>,
+    pub collection_id: Option<u64>,
+    pub location_id: u64,
+    #[serde(default)]
+    pub tag_ids: Vec<u64>,
+    #[serde(default)]
+    pub custom_fields: HashMap<String, Value>,
+}
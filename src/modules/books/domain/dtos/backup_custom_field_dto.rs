@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BackupCustomFieldDto {
+    pub id: u64,
+    pub name: String,
+    pub field_type: String,
+    #[serde(default)]
+    pub options: Vec<String>,
+}
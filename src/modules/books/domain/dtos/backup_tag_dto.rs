@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct BackupTagDto {
+    pub id: u64,
+    pub name: String,
+}
@@ -0,0 +1,12 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::{complete_book_dto::CompleteBookDto, create_book_dto::CreateBookDto};
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct BarcodeLookupDto {
+    pub isbn: String,
+    pub found: bool,
+    pub book: Option<CompleteBookDto>,
+    pub new_book: Option<CreateBookDto>,
+}
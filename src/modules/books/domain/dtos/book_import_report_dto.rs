@@ -0,0 +1,17 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::book_import_row_dto::BookImportRowDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct BookImportReportDto {
+    pub dry_run: bool,
+    pub total_rows: u64,
+    pub valid_rows: u64,
+    pub invalid_rows: u64,
+    pub skipped_rows: u64,
+    pub imported_rows: u64,
+    pub created_locations: Vec<String>,
+    pub created_collections: Vec<String>,
+    pub rows: Vec<BookImportRowDto>,
+}
@@ -0,0 +1,13 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::modules::shared::errors::detailed_api_error::DetailedAPIError;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct BookImportRowDto {
+    pub row: u64,
+    pub status: String,
+    pub title: Option<String>,
+    pub book_id: Option<u64>,
+    pub error: Option<DetailedAPIError>,
+}
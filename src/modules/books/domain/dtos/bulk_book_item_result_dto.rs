@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct BulkBookItemResultDto {
+    pub book_id: u64,
+    pub status: String,
+    pub msg: Option<String>,
+}
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use super::get_all_books_params::GetAllBooksParams;
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct BulkBookOperationDto {
+    pub action: Option<String>,
+    pub ids: Option<Vec<u64>>,
+    pub filter: Option<GetAllBooksParams>,
+    pub location_id: Option<u64>,
+    pub collection_id: Option<u64>,
+    pub tag_ids: Option<Vec<u64>>,
+}
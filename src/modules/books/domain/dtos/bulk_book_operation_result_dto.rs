@@ -0,0 +1,14 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::bulk_book_item_result_dto::BulkBookItemResultDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct BulkBookOperationResultDto {
+    pub action: String,
+    pub committed: bool,
+    pub total_items: u64,
+    pub succeeded_items: u64,
+    pub failed_items: u64,
+    pub items: Vec<BulkBookItemResultDto>,
+}
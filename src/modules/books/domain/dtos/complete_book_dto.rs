@@ -1,11 +1,15 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
 
 use super::{
     author_dto::AuthorDto, collection_dto::CollectionDto, genre_dto::GenreDto,
-    language_dto::LanguageDto, location_dto::LocationDto,
+    language_dto::LanguageDto, location_dto::LocationDto, tag_dto::TagDto,
 };
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
 pub struct CompleteBookDto {
     pub id: u64,
     pub title: String,
@@ -20,4 +24,10 @@ pub struct CompleteBookDto {
     pub collection: Option<CollectionDto>,
     pub location: LocationDto,
     pub user_id: u64,
+    pub library_id: u64,
+    pub version: u64,
+    pub tags: Vec<TagDto>,
+    pub custom_fields: HashMap<String, Value>,
+    /// Number of notes the requesting user wrote about the book.
+    pub note_count: u64,
 }
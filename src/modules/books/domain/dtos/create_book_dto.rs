@@ -1,8 +1,12 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
 
 use super::{author_dto::AuthorDto, genre_dto::GenreDto, language_dto::LanguageDto};
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
 pub struct CreateBookDto {
     pub title: Option<String>,
     pub authors: Option<Vec<AuthorDto>>,
@@ -16,4 +20,8 @@ pub struct CreateBookDto {
     pub collection_id: Option<u64>,
     pub location_id: Option<u64>,
     pub user_id: Option<u64>,
+    pub tag_ids: Option<Vec<u64>>,
+    pub custom_fields: Option<HashMap<String, Value>>,
+    /// Version the update is based on, used when `If-Match` isn't sent.
+    pub version: Option<u64>,
 }
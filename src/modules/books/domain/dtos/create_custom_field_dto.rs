@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct CreateCustomFieldDto {
+    pub name: Option<String>,
+    pub field_type: Option<String>,
+    pub options: Option<Vec<String>>,
+    pub user_id: Option<u64>,
+}
@@ -1,7 +1,9 @@
 use serde::Deserialize;
+use utoipa::ToSchema;
 
-#[derive(Deserialize, Debug, Default)]
+#[derive(Deserialize, Debug, Default, ToSchema)]
 pub struct CreateLocationDto {
     pub name: Option<String>,
     pub user_id: Option<u64>,
+    pub library_id: Option<u64>,
 }
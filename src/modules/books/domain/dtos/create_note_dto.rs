@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct CreateNoteDto {
+    pub note_type: Option<String>,
+    pub page: Option<u32>,
+    pub text: Option<String>,
+    pub book_id: Option<u64>,
+    pub user_id: Option<u64>,
+}
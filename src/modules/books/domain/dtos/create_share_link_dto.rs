@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct CreateShareLinkDto {
+    pub scope: Option<String>,
+    pub location_id: Option<u64>,
+    pub collection_id: Option<u64>,
+    /// RFC 3339 timestamp, links without it never expire
+    pub expires_at: Option<String>,
+    pub user_id: Option<u64>,
+    pub library_id: Option<u64>,
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct CreateTagDto {
+    pub name: Option<String>,
+    pub user_id: Option<u64>,
+}
@@ -0,0 +1,10 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct CustomFieldDto {
+    pub id: u64,
+    pub name: String,
+    pub field_type: String,
+    pub options: Vec<String>,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Default, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportBooksParams {
+    pub format: Option<String>,
+    pub collection_id: Option<i64>,
+    pub location_id: Option<i64>,
+    pub query: Option<String>,
+    pub tag_ids: Option<String>,
+    pub tag_match: Option<String>,
+    pub custom_field: Option<String>,
+    pub custom_field_value: Option<String>,
+    pub custom_field_min: Option<String>,
+    pub custom_field_max: Option<String>,
+    pub sort_custom_field: Option<String>,
+    pub sort_direction: Option<String>,
+}
@@ -1,8 +1,9 @@
 use serde::Serialize;
+use utoipa::ToSchema;
 
 use crate::modules::books::domain::entities::collection::Collection;
 
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Default, Serialize, ToSchema)]
 pub struct FindAllCollectionsFromUserDto {
     pub collections: Vec<Collection>,
 }
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::custom_field_dto::CustomFieldDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FindAllCustomFieldsFromUserDto {
+    pub custom_fields: Vec<CustomFieldDto>,
+}
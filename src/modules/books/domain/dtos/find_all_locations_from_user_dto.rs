@@ -1,8 +1,9 @@
 use serde::Serialize;
+use utoipa::ToSchema;
 
 use crate::modules::books::domain::entities::location::Location;
 
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Default, Serialize, ToSchema)]
 pub struct FindAllLocationsFromUserDto {
     pub locations: Vec<Location>,
 }
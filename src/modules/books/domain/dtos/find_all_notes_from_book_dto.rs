@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::note_dto::NoteDto;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FindAllNotesFromBookDto {
+    pub notes: Vec<NoteDto>,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::share_link_dto::ShareLinkDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FindAllShareLinksFromUserDto {
+    pub share_links: Vec<ShareLinkDto>,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::tag_usage_dto::TagUsageDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FindAllTagsFromUserDto {
+    pub tags: Vec<TagUsageDto>,
+}
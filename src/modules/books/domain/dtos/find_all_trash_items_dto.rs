@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::trash_item_dto::TrashItemDto;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FindAllTrashItemsDto {
+    pub items: Vec<TrashItemDto>,
+}
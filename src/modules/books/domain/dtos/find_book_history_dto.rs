@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::audit_entry_dto::AuditEntryDto;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FindBookHistoryDto {
+    pub entries: Vec<AuditEntryDto>,
+}
@@ -1,6 +1,9 @@
+use async_graphql::SimpleObject;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema, SimpleObject)]
+#[graphql(name = "Genre")]
 pub struct GenreDto {
     pub name: Option<String>,
 }
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Default, Clone, Deserialize, IntoParams, ToSchema)]
+#[into_params(parameter_in = Query)]
+pub struct GetAllBooksParams {
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+    /// Switches the listing to cursor pagination, an empty cursor asks for
+    /// the first page.
+    pub cursor: Option<String>,
+    pub include_total: Option<bool>,
+    pub collection_id: Option<i64>,
+    pub location_id: Option<i64>,
+    pub query: Option<String>,
+    /// Comma separated ids of the user's tags.
+    pub tag_ids: Option<String>,
+    pub tag_match: Option<String>,
+    /// Name of one of the user's custom fields, filtered either by an exact
+    /// value or by a range.
+    pub custom_field: Option<String>,
+    pub custom_field_value: Option<String>,
+    pub custom_field_min: Option<String>,
+    pub custom_field_max: Option<String>,
+    /// Name of one of the user's custom fields to order by, instead of the
+    /// title.
+    pub sort_custom_field: Option<String>,
+    pub sort_direction: Option<String>,
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Default, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetSharedBooksParams {
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+    pub query: Option<String>,
+}
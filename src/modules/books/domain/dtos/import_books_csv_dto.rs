@@ -0,0 +1,14 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct ImportBooksCsvDto {
+    pub content: Option<String>,
+    pub mapping: Option<HashMap<String, String>>,
+    pub delimiter: Option<String>,
+    pub list_separator: Option<String>,
+    pub default_location_id: Option<u64>,
+    pub dry_run: Option<bool>,
+}
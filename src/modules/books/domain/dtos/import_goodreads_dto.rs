@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use super::language_dto::LanguageDto;
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct ImportGoodreadsDto {
+    pub content: Option<String>,
+    pub default_location_id: Option<u64>,
+    pub default_language: Option<LanguageDto>,
+    pub dry_run: Option<bool>,
+}
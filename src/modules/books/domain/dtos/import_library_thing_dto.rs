@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use super::language_dto::LanguageDto;
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct ImportLibraryThingDto {
+    pub content: Option<String>,
+    pub format: Option<String>,
+    pub default_location_id: Option<u64>,
+    pub default_language: Option<LanguageDto>,
+    pub dry_run: Option<bool>,
+}
@@ -1,6 +1,9 @@
+use async_graphql::SimpleObject;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema, SimpleObject)]
+#[graphql(name = "Language")]
 pub struct LanguageDto {
     pub name: Option<String>,
     pub code: Option<String>,
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    backup_book_dto::BackupBookDto, backup_collection_dto::BackupCollectionDto,
+    backup_custom_field_dto::BackupCustomFieldDto, backup_location_dto::BackupLocationDto,
+    backup_tag_dto::BackupTagDto,
+};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LibraryBackupManifestDto {
+    pub schema_version: u32,
+    pub created_at: String,
+    #[serde(default)]
+    pub locations: Vec<BackupLocationDto>,
+    #[serde(default)]
+    pub collections: Vec<BackupCollectionDto>,
+    #[serde(default)]
+    pub tags: Vec<BackupTagDto>,
+    #[serde(default)]
+    pub custom_fields: Vec<BackupCustomFieldDto>,
+    #[serde(default)]
+    pub books: Vec<BackupBookDto>,
+}
@@ -0,0 +1,21 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct LibraryRestoreReportDto {
+    pub schema_version: u32,
+    pub mode: String,
+    pub removed_books: u64,
+    pub created_locations: u64,
+    pub reused_locations: u64,
+    pub created_collections: u64,
+    pub reused_collections: u64,
+    pub created_tags: u64,
+    pub reused_tags: u64,
+    pub created_custom_fields: u64,
+    pub reused_custom_fields: u64,
+    /// Values the custom field already existing under the same name rejects.
+    pub skipped_custom_field_values: u64,
+    pub restored_books: u64,
+    pub skipped_books: u64,
+}
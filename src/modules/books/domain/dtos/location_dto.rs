@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
 pub struct LocationDto {
     pub id: Option<u64>,
     pub name: String,
     pub user_id: u64,
+    pub library_id: u64,
+    pub version: u64,
 }
@@ -0,0 +1,14 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct NoteDto {
+    pub id: u64,
+    pub book_id: u64,
+    pub book_title: String,
+    pub note_type: String,
+    pub page: Option<u32>,
+    pub text: String,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
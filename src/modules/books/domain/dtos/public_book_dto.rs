@@ -0,0 +1,23 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::{
+    author_dto::AuthorDto, genre_dto::GenreDto, language_dto::LanguageDto,
+    public_collection_dto::PublicCollectionDto, public_location_dto::PublicLocationDto,
+};
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct PublicBookDto {
+    pub id: u64,
+    pub title: String,
+    pub authors: Vec<AuthorDto>,
+    pub publisher: String,
+    pub languages: Vec<LanguageDto>,
+    pub edition: Option<String>,
+    pub isbn: Option<String>,
+    pub year: Option<String>,
+    pub genres: Option<Vec<GenreDto>>,
+    pub cover: Option<String>,
+    pub collection: Option<PublicCollectionDto>,
+    pub location: PublicLocationDto,
+}
@@ -0,0 +1,8 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct PublicLocationDto {
+    pub id: Option<u64>,
+    pub name: String,
+}
@@ -0,0 +1,7 @@
+use super::library_backup_manifest_dto::LibraryBackupManifestDto;
+
+#[derive(Debug, Default)]
+pub struct RestoreLibraryDto {
+    pub manifest: LibraryBackupManifestDto,
+    pub mode: Option<String>,
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchNotesParams {
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+    pub query: Option<String>,
+    pub note_type: Option<String>,
+}
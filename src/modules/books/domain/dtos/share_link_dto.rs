@@ -0,0 +1,14 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct ShareLinkDto {
+    pub id: u64,
+    pub slug: String,
+    pub scope: String,
+    pub location_id: Option<u64>,
+    pub collection_id: Option<u64>,
+    pub expires_at: Option<String>,
+    pub revoked_at: Option<String>,
+    pub created_at: Option<String>,
+}
@@ -0,0 +1,10 @@
+use async_graphql::SimpleObject;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, ToSchema, SimpleObject)]
+#[graphql(name = "Tag")]
+pub struct TagDto {
+    pub id: u64,
+    pub name: String,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct TagUsageDto {
+    pub id: u64,
+    pub name: String,
+    pub usage_count: u64,
+}
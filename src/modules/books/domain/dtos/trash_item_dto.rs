@@ -0,0 +1,10 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct TrashItemDto {
+    pub id: u64,
+    pub item_type: String,
+    pub name: String,
+    pub deleted_at: Option<String>,
+}
@@ -1,6 +1,19 @@
+pub mod audit_entry;
 pub mod author;
 pub mod book;
+pub mod book_cursor;
+pub mod book_export;
+pub mod book_import;
+pub mod book_listing;
+pub mod bulk_book_operation;
 pub mod collection;
+pub mod custom_field;
 pub mod genre;
+pub mod isbn;
 pub mod language;
+pub mod library_backup;
 pub mod location;
+pub mod note;
+pub mod share_link;
+pub mod tag;
+pub mod trash;
@@ -0,0 +1,179 @@
+use chrono::{DateTime, Utc};
+use serde_json::{json, Map, Value};
+
+use super::{book::Book, collection::Collection, location::Location, trash::TrashItemType};
+
+/// Fields of a book kept in the audit trail, the ones restored when the
+/// book is reverted to a previous version.
+pub const BOOK_AUDITED_FIELDS: [&str; 11] = [
+    "title",
+    "authors",
+    "publisher",
+    "languages",
+    "edition",
+    "isbn",
+    "year",
+    "genres",
+    "cover",
+    "collection_id",
+    "location_id",
+];
+
+pub const SYSTEM_ACTOR_ID: u64 = 0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuditEntityType {
+    Book,
+    Collection,
+    Location,
+}
+
+impl AuditEntityType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditEntityType::Book => "book",
+            AuditEntityType::Collection => "collection",
+            AuditEntityType::Location => "location",
+        }
+    }
+}
+
+impl TryFrom<&str> for AuditEntityType {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "book" => Ok(AuditEntityType::Book),
+            "collection" => Ok(AuditEntityType::Collection),
+            "location" => Ok(AuditEntityType::Location),
+            _ => Err(format!("Unknown audited entity type {}", value)),
+        }
+    }
+}
+
+impl From<TrashItemType> for AuditEntityType {
+    fn from(item_type: TrashItemType) -> Self {
+        match item_type {
+            TrashItemType::Book => AuditEntityType::Book,
+            TrashItemType::Collection => AuditEntityType::Collection,
+            TrashItemType::Location => AuditEntityType::Location,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    Restore,
+    Purge,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::Create => "create",
+            AuditAction::Update => "update",
+            AuditAction::Delete => "delete",
+            AuditAction::Restore => "restore",
+            AuditAction::Purge => "purge",
+        }
+    }
+}
+
+impl TryFrom<&str> for AuditAction {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "create" => Ok(AuditAction::Create),
+            "update" => Ok(AuditAction::Update),
+            "delete" => Ok(AuditAction::Delete),
+            "restore" => Ok(AuditAction::Restore),
+            "purge" => Ok(AuditAction::Purge),
+            _ => Err(format!("Unknown audit action {}", value)),
+        }
+    }
+}
+
+/// Change made by an user to a book, collection or location. `changes`
+/// holds the `before` and `after` values of every modified field and
+/// `snapshot` the audited fields as they were left by the change, or right
+/// before it for deletes.
+#[derive(Debug)]
+pub struct AuditEntry {
+    pub id: Option<u64>,
+    pub entity_type: AuditEntityType,
+    pub entity_id: u64,
+    pub library_id: u64,
+    pub actor_id: u64,
+    pub action: AuditAction,
+    pub changes: Map<String, Value>,
+    pub snapshot: Option<Value>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl AuditEntry {
+    pub fn new(
+        entity_type: AuditEntityType,
+        entity_id: u64,
+        library_id: u64,
+        actor_id: u64,
+        action: AuditAction,
+        before: Option<Value>,
+        after: Option<Value>,
+    ) -> Self {
+        AuditEntry {
+            id: None,
+            entity_type,
+            entity_id,
+            library_id,
+            actor_id,
+            action,
+            changes: diff_snapshots(before.as_ref(), after.as_ref()),
+            snapshot: after.or(before),
+            created_at: None,
+        }
+    }
+}
+
+pub fn diff_snapshots(before: Option<&Value>, after: Option<&Value>) -> Map<String, Value> {
+    let empty = Map::new();
+    let before = before.and_then(Value::as_object).unwrap_or(&empty);
+    let after = after.and_then(Value::as_object).unwrap_or(&empty);
+
+    let mut changes = Map::new();
+    for field in before.keys().chain(after.keys()) {
+        let before_value = before.get(field).unwrap_or(&Value::Null);
+        let after_value = after.get(field).unwrap_or(&Value::Null);
+        if before_value != after_value && !changes.contains_key(field) {
+            changes.insert(
+                field.clone(),
+                json!({ "before": before_value, "after": after_value }),
+            );
+        }
+    }
+    changes
+}
+
+pub fn book_audit_snapshot(book: &Book) -> Value {
+    let mut snapshot = Map::new();
+    if let Ok(Value::Object(book)) = serde_json::to_value(book) {
+        for field in BOOK_AUDITED_FIELDS {
+            snapshot.insert(
+                field.to_string(),
+                book.get(field).cloned().unwrap_or(Value::Null),
+            );
+        }
+    }
+    Value::Object(snapshot)
+}
+
+pub fn collection_audit_snapshot(collection: &Collection) -> Value {
+    json!({ "name": collection.name })
+}
+
+pub fn location_audit_snapshot(location: &Location) -> Value {
+    json!({ "name": location.name })
+}
@@ -1,4 +1,7 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use super::{author::Author, genre::Genre, language::Language};
 
@@ -17,4 +20,11 @@ pub struct Book {
     pub collection_id: Option<u64>,
     pub location_id: u64,
     pub user_id: u64,
+    pub library_id: u64,
+    pub version: u64,
+    /// Tags of the user saving the book, `None` keeps the current ones. The
+    /// same goes for `custom_fields`, which are only checked against the
+    /// field definitions when the book is saved.
+    pub tag_ids: Option<Vec<u64>>,
+    pub custom_fields: Option<HashMap<String, Value>>,
 }
@@ -0,0 +1,96 @@
+use base64::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BookCursor {
+    #[serde(rename = "t")]
+    pub title: String,
+    #[serde(rename = "i")]
+    pub book_id: u64,
+    #[serde(rename = "b")]
+    pub backwards: bool,
+}
+
+impl BookCursor {
+    pub fn after(title: &str, book_id: u64) -> Self {
+        BookCursor {
+            title: title.to_string(),
+            book_id,
+            backwards: false,
+        }
+    }
+
+    pub fn before(title: &str, book_id: u64) -> Self {
+        BookCursor {
+            title: title.to_string(),
+            book_id,
+            backwards: true,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        BASE64_URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    pub fn decode(token: &str) -> Result<BookCursor, String> {
+        BASE64_URL_SAFE_NO_PAD
+            .decode(token.trim())
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or("Informed cursor is not valid".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursors_survive_a_round_trip() {
+        let cursor = BookCursor::after("Dom Casmurro", 42);
+
+        assert_eq!(Ok(cursor.clone()), BookCursor::decode(&cursor.encode()));
+    }
+
+    #[test]
+    fn direction_is_kept_in_the_token() {
+        let after = BookCursor::after("Helena", 7);
+        let before = BookCursor::before("Helena", 7);
+
+        assert_ne!(after.encode(), before.encode());
+        assert!(!BookCursor::decode(&after.encode()).unwrap().backwards);
+        assert!(BookCursor::decode(&before.encode()).unwrap().backwards);
+    }
+
+    #[test]
+    fn tokens_are_url_safe() {
+        let token = BookCursor::after("Memórias Póstumas de Brás Cubas ???", u64::MAX).encode();
+
+        assert!(token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+    }
+
+    #[test]
+    fn surrounding_whitespace_is_ignored() {
+        let cursor = BookCursor::before("Iaiá Garcia", 3);
+
+        assert_eq!(
+            Ok(cursor.clone()),
+            BookCursor::decode(&format!(" {}\n", cursor.encode()))
+        );
+    }
+
+    #[test]
+    fn malformed_tokens_are_rejected() {
+        let not_json = BASE64_URL_SAFE_NO_PAD.encode("not a cursor");
+        let missing_fields = BASE64_URL_SAFE_NO_PAD.encode(r#"{"t":"Helena"}"#);
+
+        for token in ["***", "", not_json.as_str(), missing_fields.as_str()] {
+            assert_eq!(
+                Err("Informed cursor is not valid".to_string()),
+                BookCursor::decode(token)
+            );
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::modules::books::domain::dtos::get_all_books_params::GetAllBooksParams;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookExportFormat {
+    Csv,
+    Json,
+    Xlsx,
+}
+
+impl BookExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            BookExportFormat::Csv => "text/csv; charset=utf-8",
+            BookExportFormat::Json => "application/json",
+            BookExportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+        }
+    }
+
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            BookExportFormat::Csv => "csv",
+            BookExportFormat::Json => "json",
+            BookExportFormat::Xlsx => "xlsx",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BookExport {
+    pub format: BookExportFormat,
+    /// Only the filters are used, exports aren't paginated.
+    pub listing_params: GetAllBooksParams,
+}
+
+/// Spreadsheets evaluate CSV cells starting with one of these as formulas.
+const CSV_FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// Prefixes `value` with `'` when a spreadsheet would evaluate it, the CSV
+/// import removes the prefix again.
+pub fn escape_csv_formula(value: String) -> String {
+    match value.starts_with(CSV_FORMULA_PREFIXES) {
+        true => format!("'{}", value),
+        false => value,
+    }
+}
+
+pub fn unescape_csv_formula(value: &str) -> &str {
+    match value.strip_prefix('\'') {
+        Some(formula) if formula.starts_with(CSV_FORMULA_PREFIXES) => formula,
+        _ => value,
+    }
+}
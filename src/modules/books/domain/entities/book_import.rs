@@ -0,0 +1,58 @@
+use crate::modules::books::domain::dtos::create_book_dto::CreateBookDto;
+
+use super::book::Book;
+
+pub const MAX_IMPORT_ROWS: usize = 5000;
+
+#[derive(Debug, Default)]
+pub struct BookImportCandidate {
+    pub row_number: u64,
+    pub book_dto: CreateBookDto,
+    pub location_name: Option<String>,
+    pub collection_name: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct BookImport {
+    pub dry_run: bool,
+    pub default_location_id: Option<u64>,
+    pub candidates: Vec<BookImportCandidate>,
+}
+
+/// A validated row ready to be persisted. The names are only set when the
+/// location or collection must be created along with the book, in which case
+/// the matching id on `book` is a placeholder.
+#[derive(Debug, Default)]
+pub struct BookImportRow {
+    pub row_number: u64,
+    pub book: Book,
+    pub new_location_name: Option<String>,
+    pub new_collection_name: Option<String>,
+}
+
+pub fn normalize_isbn(value: &str) -> Option<String> {
+    let isbn: String = value
+        .chars()
+        .filter(|character| character.is_ascii_digit() || *character == 'X' || *character == 'x')
+        .map(|character| character.to_ascii_uppercase())
+        .collect();
+    match isbn.len() {
+        10 | 13 => Some(isbn),
+        _ => None,
+    }
+}
+
+pub fn extract_year(value: &str) -> Option<String> {
+    let characters: Vec<char> = value.chars().collect();
+    characters
+        .windows(4)
+        .enumerate()
+        .find(|(index, window)| {
+            window.iter().all(char::is_ascii_digit)
+                && !characters
+                    .get(index + 4)
+                    .is_some_and(|next| next.is_ascii_digit())
+                && (*index == 0 || !characters[index - 1].is_ascii_digit())
+        })
+        .map(|(_, window)| window.iter().collect())
+}
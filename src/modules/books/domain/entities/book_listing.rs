@@ -0,0 +1,45 @@
+use crate::modules::books::domain::dtos::get_all_books_params::GetAllBooksParams;
+
+use super::{
+    custom_field::{CustomField, CustomFieldFilter, CustomFieldSort},
+    tag::TagFilter,
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct BookListingOptions {
+    pub tag_filter: Option<TagFilter>,
+    pub custom_field_filter: Option<CustomFieldFilter>,
+    pub custom_field_sort: Option<CustomFieldSort>,
+}
+
+impl BookListingOptions {
+    pub fn uses_custom_fields(params: &GetAllBooksParams) -> bool {
+        params.custom_field.is_some() || params.sort_custom_field.is_some()
+    }
+
+    pub fn from_params(
+        user_id: u64,
+        params: &GetAllBooksParams,
+        custom_fields: &[CustomField],
+    ) -> Result<BookListingOptions, String> {
+        Ok(BookListingOptions {
+            tag_filter: TagFilter::from_params(
+                user_id,
+                params.tag_ids.as_deref(),
+                params.tag_match.as_deref(),
+            )?,
+            custom_field_filter: CustomFieldFilter::from_params(
+                custom_fields,
+                params.custom_field.as_deref(),
+                params.custom_field_value.as_deref(),
+                params.custom_field_min.as_deref(),
+                params.custom_field_max.as_deref(),
+            )?,
+            custom_field_sort: CustomFieldSort::from_params(
+                custom_fields,
+                params.sort_custom_field.as_deref(),
+                params.sort_direction.as_deref(),
+            )?,
+        })
+    }
+}
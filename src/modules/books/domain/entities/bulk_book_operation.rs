@@ -0,0 +1,38 @@
+use crate::modules::books::domain::dtos::get_all_books_params::GetAllBooksParams;
+
+pub const MAX_BULK_BOOK_ITEMS: usize = 1000;
+
+#[derive(Debug)]
+pub enum BulkBookAction {
+    MoveToLocation(u64),
+    SetCollection(u64),
+    ClearCollection,
+    AddTags(Vec<u64>),
+    RemoveTags(Vec<u64>),
+    Delete,
+}
+
+impl BulkBookAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BulkBookAction::MoveToLocation(_) => "move_to_location",
+            BulkBookAction::SetCollection(_) => "set_collection",
+            BulkBookAction::ClearCollection => "clear_collection",
+            BulkBookAction::AddTags(_) => "add_tags",
+            BulkBookAction::RemoveTags(_) => "remove_tags",
+            BulkBookAction::Delete => "delete",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum BulkBookTarget {
+    Ids(Vec<u64>),
+    Filter(Box<GetAllBooksParams>),
+}
+
+#[derive(Debug)]
+pub struct BulkBookOperation {
+    pub action: BulkBookAction,
+    pub target: BulkBookTarget,
+}
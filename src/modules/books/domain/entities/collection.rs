@@ -1,8 +1,11 @@
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Default, Serialize, ToSchema)]
 pub struct Collection {
     pub id: Option<u64>,
     pub name: String,
     pub user_id: u64,
+    pub library_id: u64,
+    pub version: u64,
 }
@@ -0,0 +1,417 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use serde_json::Value;
+
+pub const MAX_CUSTOM_FIELD_NAME_LENGTH: usize = 50;
+pub const MAX_CUSTOM_FIELD_OPTIONS: usize = 50;
+pub const MAX_CUSTOM_FIELD_TEXT_LENGTH: usize = 500;
+
+pub const CUSTOM_FIELD_DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize)]
+pub enum CustomFieldType {
+    #[default]
+    Text,
+    Number,
+    Date,
+    Boolean,
+    Enum,
+}
+
+impl CustomFieldType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CustomFieldType::Text => "text",
+            CustomFieldType::Number => "number",
+            CustomFieldType::Date => "date",
+            CustomFieldType::Boolean => "boolean",
+            CustomFieldType::Enum => "enum",
+        }
+    }
+
+    /// Enum values are stored as text, every other type has its own column
+    /// so values can be compared and sorted by the database.
+    pub fn value_column(&self) -> &'static str {
+        match self {
+            CustomFieldType::Text | CustomFieldType::Enum => "value_text",
+            CustomFieldType::Number => "value_number",
+            CustomFieldType::Date => "value_date",
+            CustomFieldType::Boolean => "value_boolean",
+        }
+    }
+
+    pub fn is_ordered(&self) -> bool {
+        matches!(self, CustomFieldType::Number | CustomFieldType::Date)
+    }
+}
+
+impl TryFrom<&str> for CustomFieldType {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "text" => Ok(CustomFieldType::Text),
+            "number" => Ok(CustomFieldType::Number),
+            "date" => Ok(CustomFieldType::Date),
+            "boolean" => Ok(CustomFieldType::Boolean),
+            "enum" => Ok(CustomFieldType::Enum),
+            _ => Err("Field type must be one of text, number, date, boolean or enum".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CustomField {
+    pub id: Option<u64>,
+    pub name: String,
+    pub field_type: CustomFieldType,
+    pub options: Vec<String>,
+    pub user_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CustomFieldValue {
+    Text(String),
+    Number(f64),
+    Date(NaiveDate),
+    Boolean(bool),
+}
+
+impl CustomFieldValue {
+    pub fn to_json(&self) -> Value {
+        match self {
+            CustomFieldValue::Text(text) => Value::from(text.as_str()),
+            CustomFieldValue::Number(number) => Value::from(*number),
+            CustomFieldValue::Date(date) => {
+                Value::from(date.format(CUSTOM_FIELD_DATE_FORMAT).to_string())
+            }
+            CustomFieldValue::Boolean(boolean) => Value::from(*boolean),
+        }
+    }
+}
+
+impl CustomField {
+    pub fn parse_value(&self, raw_value: &str) -> Result<CustomFieldValue, String> {
+        let raw_value = raw_value.trim();
+        match self.field_type {
+            CustomFieldType::Text => self.text_value(raw_value),
+            CustomFieldType::Enum => self.enum_value(raw_value),
+            CustomFieldType::Number => match raw_value.parse::<f64>() {
+                Ok(number) if number.is_finite() => Ok(CustomFieldValue::Number(number)),
+                _ => Err(format!("{} must be a number", self.name)),
+            },
+            CustomFieldType::Date => self.date_value(raw_value),
+            CustomFieldType::Boolean => match raw_value {
+                "true" => Ok(CustomFieldValue::Boolean(true)),
+                "false" => Ok(CustomFieldValue::Boolean(false)),
+                _ => Err(format!("{} must be either true or false", self.name)),
+            },
+        }
+    }
+
+    pub fn value_from_json(&self, value: &Value) -> Result<Option<CustomFieldValue>, String> {
+        match (self.field_type, value) {
+            (_, Value::Null) => Ok(None),
+            (CustomFieldType::Text, Value::String(text)) => self.text_value(text.trim()).map(Some),
+            (CustomFieldType::Enum, Value::String(text)) => self.enum_value(text.trim()).map(Some),
+            (CustomFieldType::Date, Value::String(text)) => self.date_value(text.trim()).map(Some),
+            (CustomFieldType::Number, Value::Number(number)) => match number.as_f64() {
+                Some(number) if number.is_finite() => Ok(Some(CustomFieldValue::Number(number))),
+                _ => Err(format!("{} must be a number", self.name)),
+            },
+            (CustomFieldType::Boolean, Value::Bool(boolean)) => {
+                Ok(Some(CustomFieldValue::Boolean(*boolean)))
+            }
+            (CustomFieldType::Number, _) => Err(format!("{} must be a number", self.name)),
+            (CustomFieldType::Boolean, _) => {
+                Err(format!("{} must be either true or false", self.name))
+            }
+            (_, _) => Err(format!("{} must be a string", self.name)),
+        }
+    }
+
+    fn text_value(&self, text: &str) -> Result<CustomFieldValue, String> {
+        if text.is_empty() {
+            Err(format!("{} must not be empty", self.name))
+        } else if text.chars().count() > MAX_CUSTOM_FIELD_TEXT_LENGTH {
+            Err(format!(
+                "{} must have at most {} characters",
+                self.name, MAX_CUSTOM_FIELD_TEXT_LENGTH
+            ))
+        } else {
+            Ok(CustomFieldValue::Text(text.to_string()))
+        }
+    }
+
+    fn enum_value(&self, text: &str) -> Result<CustomFieldValue, String> {
+        match self
+            .options
+            .iter()
+            .find(|option| option.eq_ignore_ascii_case(text))
+        {
+            Some(option) => Ok(CustomFieldValue::Text(option.clone())),
+            None => Err(format!(
+                "{} must be one of {}",
+                self.name,
+                self.options.join(", ")
+            )),
+        }
+    }
+
+    fn date_value(&self, text: &str) -> Result<CustomFieldValue, String> {
+        match NaiveDate::parse_from_str(text, CUSTOM_FIELD_DATE_FORMAT) {
+            Ok(date) => Ok(CustomFieldValue::Date(date)),
+            Err(_) => Err(format!(
+                "{} must be a date formatted as YYYY-MM-DD",
+                self.name
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BookCustomFieldValue {
+    pub custom_field_id: u64,
+    pub field_type: CustomFieldType,
+    pub value: CustomFieldValue,
+}
+
+#[derive(Debug, Clone)]
+pub enum CustomFieldCondition {
+    Equals(CustomFieldValue),
+    Between(Option<CustomFieldValue>, Option<CustomFieldValue>),
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomFieldFilter {
+    pub custom_field_id: u64,
+    pub field_type: CustomFieldType,
+    pub condition: CustomFieldCondition,
+}
+
+impl CustomFieldFilter {
+    pub fn from_params(
+        custom_fields: &[CustomField],
+        name: Option<&str>,
+        value: Option<&str>,
+        min: Option<&str>,
+        max: Option<&str>,
+    ) -> Result<Option<CustomFieldFilter>, String> {
+        let Some(name) = name else {
+            if value.is_some() || min.is_some() || max.is_some() {
+                return Err("custom_field must be informed to filter by its value".to_string());
+            }
+            return Ok(None);
+        };
+        let custom_field = find_by_name(custom_fields, name)?;
+
+        let condition = match (value, min, max) {
+            (Some(_), Some(_), _) | (Some(_), _, Some(_)) => {
+                return Err(
+                    "custom_field_value can't be combined with custom_field_min or custom_field_max"
+                        .to_string(),
+                );
+            }
+            (Some(value), None, None) => {
+                CustomFieldCondition::Equals(custom_field.parse_value(value)?)
+            }
+            (None, None, None) => {
+                return Err(
+                    "custom_field_value, custom_field_min or custom_field_max must be informed"
+                        .to_string(),
+                );
+            }
+            (None, min, max) => {
+                if !custom_field.field_type.is_ordered() {
+                    return Err(
+                        "Only number and date custom fields can be filtered by range".to_string(),
+                    );
+                }
+                CustomFieldCondition::Between(
+                    min.map(|min| custom_field.parse_value(min)).transpose()?,
+                    max.map(|max| custom_field.parse_value(max)).transpose()?,
+                )
+            }
+        };
+
+        Ok(Some(CustomFieldFilter {
+            custom_field_id: custom_field.id.unwrap_or_default(),
+            field_type: custom_field.field_type,
+            condition,
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomFieldSort {
+    pub custom_field_id: u64,
+    pub field_type: CustomFieldType,
+    pub descending: bool,
+}
+
+impl CustomFieldSort {
+    pub fn from_params(
+        custom_fields: &[CustomField],
+        name: Option<&str>,
+        direction: Option<&str>,
+    ) -> Result<Option<CustomFieldSort>, String> {
+        let descending = match direction.map(str::trim) {
+            None | Some("asc") => false,
+            Some("desc") => true,
+            Some(_) => return Err("Sort direction must be either asc or desc".to_string()),
+        };
+        let Some(name) = name else {
+            return Ok(None);
+        };
+        let custom_field = find_by_name(custom_fields, name)?;
+
+        Ok(Some(CustomFieldSort {
+            custom_field_id: custom_field.id.unwrap_or_default(),
+            field_type: custom_field.field_type,
+            descending,
+        }))
+    }
+}
+
+fn find_by_name<'a>(
+    custom_fields: &'a [CustomField],
+    name: &str,
+) -> Result<&'a CustomField, String> {
+    custom_fields
+        .iter()
+        .find(|custom_field| custom_field.name.eq_ignore_ascii_case(name.trim()))
+        .ok_or(format!("Custom field {} not found", name.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn custom_field(id: u64, name: &str, field_type: CustomFieldType) -> CustomField {
+        CustomField {
+            id: Some(id),
+            name: name.to_string(),
+            field_type,
+            options: vec!["Hardcover".to_string(), "Paperback".to_string()],
+            user_id: 1,
+        }
+    }
+
+    #[test]
+    fn json_values_must_match_the_field_type() {
+        let pages = custom_field(1, "Pages", CustomFieldType::Number);
+        let read_on = custom_field(2, "Read on", CustomFieldType::Date);
+        let signed = custom_field(3, "Signed", CustomFieldType::Boolean);
+        let binding = custom_field(4, "Binding", CustomFieldType::Enum);
+        let notes = custom_field(5, "Notes", CustomFieldType::Text);
+
+        assert_eq!(
+            Ok(Some(CustomFieldValue::Number(256.0))),
+            pages.value_from_json(&json!(256))
+        );
+        assert!(pages.value_from_json(&json!("256")).is_err());
+        assert_eq!(
+            Ok(Some(CustomFieldValue::Date(
+                NaiveDate::from_ymd_opt(2026, 10, 19).unwrap()
+            ))),
+            read_on.value_from_json(&json!("2026-10-19"))
+        );
+        assert!(read_on.value_from_json(&json!("19/10/2026")).is_err());
+        assert!(read_on.value_from_json(&json!(20261019)).is_err());
+        assert_eq!(
+            Ok(Some(CustomFieldValue::Boolean(true))),
+            signed.value_from_json(&json!(true))
+        );
+        assert!(signed.value_from_json(&json!("true")).is_err());
+        assert_eq!(
+            Ok(Some(CustomFieldValue::Text("Paperback".to_string()))),
+            binding.value_from_json(&json!("paperback"))
+        );
+        assert!(binding.value_from_json(&json!("Leather")).is_err());
+        assert!(notes.value_from_json(&json!(" ")).is_err());
+        assert!(notes
+            .value_from_json(&json!("x".repeat(MAX_CUSTOM_FIELD_TEXT_LENGTH + 1)))
+            .is_err());
+        assert_eq!(Ok(None), notes.value_from_json(&json!(null)));
+    }
+
+    #[test]
+    fn query_values_are_parsed_by_the_field_type() {
+        let pages = custom_field(1, "Pages", CustomFieldType::Number);
+        let signed = custom_field(3, "Signed", CustomFieldType::Boolean);
+
+        assert_eq!(
+            Ok(CustomFieldValue::Number(1.5)),
+            pages.parse_value(" 1.5 ")
+        );
+        assert!(pages.parse_value("NaN").is_err());
+        assert!(pages.parse_value("inf").is_err());
+        assert_eq!(
+            Ok(CustomFieldValue::Boolean(false)),
+            signed.parse_value("false")
+        );
+        assert!(signed.parse_value("no").is_err());
+    }
+
+    #[test]
+    fn only_ordered_fields_are_filtered_by_range() {
+        let custom_fields = [
+            custom_field(1, "Pages", CustomFieldType::Number),
+            custom_field(4, "Binding", CustomFieldType::Enum),
+        ];
+
+        let filter =
+            CustomFieldFilter::from_params(&custom_fields, Some("pages"), None, Some("100"), None)
+                .unwrap()
+                .unwrap();
+        assert_eq!(1, filter.custom_field_id);
+        assert!(matches!(
+            filter.condition,
+            CustomFieldCondition::Between(Some(CustomFieldValue::Number(_)), None)
+        ));
+        assert!(CustomFieldFilter::from_params(
+            &custom_fields,
+            Some("Binding"),
+            None,
+            Some("a"),
+            None
+        )
+        .is_err());
+        assert!(CustomFieldFilter::from_params(
+            &custom_fields,
+            Some("Pages"),
+            Some("1"),
+            Some("1"),
+            None
+        )
+        .is_err());
+        assert!(
+            CustomFieldFilter::from_params(&custom_fields, None, Some("1"), None, None).is_err()
+        );
+        assert!(CustomFieldFilter::from_params(
+            &custom_fields,
+            Some("Missing"),
+            Some("1"),
+            None,
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn sorts_need_a_known_field_and_direction() {
+        let custom_fields = [custom_field(1, "Pages", CustomFieldType::Number)];
+
+        let sort = CustomFieldSort::from_params(&custom_fields, Some("Pages"), Some("desc"))
+            .unwrap()
+            .unwrap();
+        assert!(sort.descending);
+        assert!(CustomFieldSort::from_params(&custom_fields, None, None)
+            .unwrap()
+            .is_none());
+        assert!(CustomFieldSort::from_params(&custom_fields, Some("Pages"), Some("up")).is_err());
+        assert!(CustomFieldSort::from_params(&custom_fields, Some("Year"), None).is_err());
+    }
+}
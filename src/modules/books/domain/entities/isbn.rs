@@ -0,0 +1,36 @@
+pub fn isbn13_from_ean13(ean13: &str) -> Option<String> {
+    if ean13.len() != 13 || !ean13.chars().all(|character| character.is_ascii_digit()) {
+        return None;
+    }
+    if !ean13.starts_with("978") && !ean13.starts_with("979") {
+        return None;
+    }
+    let digits: Vec<u32> = ean13
+        .chars()
+        .filter_map(|digit| digit.to_digit(10))
+        .collect();
+    let weighted_sum: u32 = digits[..12]
+        .iter()
+        .enumerate()
+        .map(|(index, digit)| if index % 2 == 0 { *digit } else { digit * 3 })
+        .sum();
+    if (10 - weighted_sum % 10) % 10 != digits[12] {
+        return None;
+    }
+    Some(ean13.to_string())
+}
+
+pub fn isbn10_from_isbn13(isbn13: &str) -> Option<String> {
+    let body = isbn13.strip_prefix("978")?.get(..9)?;
+    let weighted_sum: u32 = body
+        .chars()
+        .filter_map(|digit| digit.to_digit(10))
+        .enumerate()
+        .map(|(index, digit)| digit * (10 - index as u32))
+        .sum();
+    let check_digit = match (11 - weighted_sum % 11) % 11 {
+        10 => 'X',
+        digit => char::from_digit(digit, 10)?,
+    };
+    Some(format!("{}{}", body, check_digit))
+}
@@ -0,0 +1,33 @@
+use super::{
+    book::Book, collection::Collection, custom_field::CustomField, location::Location, tag::Tag,
+};
+
+/// Version written to new backups. Restoring accepts every version up to
+/// this one, older manifests are upgraded while being mapped.
+pub const LIBRARY_BACKUP_SCHEMA_VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LibraryRestoreMode {
+    Merge,
+    Replace,
+}
+
+impl LibraryRestoreMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LibraryRestoreMode::Merge => "merge",
+            LibraryRestoreMode::Replace => "replace",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LibraryRestore {
+    pub mode: LibraryRestoreMode,
+    pub schema_version: u32,
+    pub locations: Vec<Location>,
+    pub collections: Vec<Collection>,
+    pub tags: Vec<Tag>,
+    pub custom_fields: Vec<CustomField>,
+    pub books: Vec<Book>,
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+
+pub const MAX_NOTE_TEXT_LENGTH: usize = 5000;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum NoteType {
+    #[default]
+    Note,
+    Quote,
+    Summary,
+}
+
+impl NoteType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoteType::Note => "note",
+            NoteType::Quote => "quote",
+            NoteType::Summary => "summary",
+        }
+    }
+}
+
+impl TryFrom<&str> for NoteType {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "note" => Ok(NoteType::Note),
+            "quote" => Ok(NoteType::Quote),
+            "summary" => Ok(NoteType::Summary),
+            _ => Err("Note type must be one of note, quote or summary".to_string()),
+        }
+    }
+}
+
+/// Personal note, quote or summary written by an user about a book, only
+/// visible to its author.
+#[derive(Debug, Default)]
+pub struct Note {
+    pub id: Option<u64>,
+    pub book_id: u64,
+    pub user_id: u64,
+    pub note_type: NoteType,
+    pub page: Option<u32>,
+    pub text: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
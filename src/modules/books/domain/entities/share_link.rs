@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+
+pub const SHARE_LINK_SLUG_LENGTH: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShareLinkScope {
+    #[default]
+    Library,
+    Location(u64),
+    Collection(u64),
+}
+
+impl ShareLinkScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ShareLinkScope::Library => "library",
+            ShareLinkScope::Location(_) => "location",
+            ShareLinkScope::Collection(_) => "collection",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ShareLink {
+    pub id: Option<u64>,
+    pub slug: String,
+    pub scope: ShareLinkScope,
+    pub user_id: u64,
+    pub library_id: u64,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl ShareLink {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|expires_at| expires_at > now)
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+pub const MAX_TAG_NAME_LENGTH: usize = 50;
+
+pub const MAX_FILTER_TAGS: usize = 20;
+
+#[derive(Debug, Default, Serialize)]
+pub struct Tag {
+    pub id: Option<u64>,
+    pub name: String,
+    pub user_id: u64,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum TagMatch {
+    #[default]
+    Any,
+    All,
+}
+
+impl TagMatch {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TagMatch::Any => "any",
+            TagMatch::All => "all",
+        }
+    }
+}
+
+impl TryFrom<&str> for TagMatch {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "any" => Ok(TagMatch::Any),
+            "all" => Ok(TagMatch::All),
+            _ => Err("Tag match must be either any or all".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TagFilter {
+    pub user_id: u64,
+    pub tag_ids: Vec<u64>,
+    pub tag_match: TagMatch,
+}
+
+impl TagFilter {
+    pub fn from_params(
+        user_id: u64,
+        tag_ids: Option<&str>,
+        tag_match: Option<&str>,
+    ) -> Result<Option<TagFilter>, String> {
+        let tag_match = match tag_match {
+            Some(tag_match) => TagMatch::try_from(tag_match)?,
+            None => TagMatch::default(),
+        };
+        let Some(tag_ids) = tag_ids.filter(|tag_ids| !tag_ids.trim().is_empty()) else {
+            return Ok(None);
+        };
+
+        let mut seen_ids: HashSet<u64> = HashSet::new();
+        let mut unique_ids: Vec<u64> = Vec::new();
+        for tag_id in tag_ids.split(',') {
+            match tag_id.trim().parse::<u64>() {
+                Ok(tag_id) => {
+                    if seen_ids.insert(tag_id) {
+                        unique_ids.push(tag_id);
+                    }
+                }
+                Err(_) => return Err(format!("{} is not a valid tag id", tag_id.trim())),
+            }
+        }
+        if unique_ids.len() > MAX_FILTER_TAGS {
+            return Err(format!(
+                "At most {} tags can be used to filter books",
+                MAX_FILTER_TAGS
+            ));
+        }
+
+        Ok(Some(TagFilter {
+            user_id,
+            tag_ids: unique_ids,
+            tag_match,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_keep_the_first_occurrence_of_each_tag() {
+        let tag_filter = TagFilter::from_params(1, Some(" 3, 1,3 ,2"), Some("all"))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(vec![3, 1, 2], tag_filter.tag_ids);
+        assert_eq!(TagMatch::All, tag_filter.tag_match);
+        assert_eq!(1, tag_filter.user_id);
+    }
+
+    #[test]
+    fn books_arent_filtered_without_tags() {
+        assert!(TagFilter::from_params(1, None, Some("all"))
+            .unwrap()
+            .is_none());
+        assert!(TagFilter::from_params(1, Some(" "), None)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn invalid_filters_are_rejected() {
+        assert!(TagFilter::from_params(1, Some("1,abc"), None).is_err());
+        assert!(TagFilter::from_params(1, Some("1"), Some("some")).is_err());
+        assert!(TagFilter::from_params(1, None, Some("some")).is_err());
+        let too_many_tags = (1..=MAX_FILTER_TAGS as u64 + 1)
+            .map(|tag_id| tag_id.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        assert!(TagFilter::from_params(1, Some(&too_many_tags), None).is_err());
+    }
+}
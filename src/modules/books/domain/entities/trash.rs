@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrashItemType {
+    Book,
+    Collection,
+    Location,
+}
+
+impl TrashItemType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrashItemType::Book => "book",
+            TrashItemType::Collection => "collection",
+            TrashItemType::Location => "location",
+        }
+    }
+}
+
+impl TryFrom<&str> for TrashItemType {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "books" => Ok(TrashItemType::Book),
+            "collections" => Ok(TrashItemType::Collection),
+            "locations" => Ok(TrashItemType::Location),
+            _ => Err("Trash item type must be one of books, collections or locations".to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TrashItem {
+    pub id: u64,
+    pub item_type: TrashItemType,
+    pub name: String,
+    pub library_id: u64,
+    /// Location of a trashed book, a book can only be restored to a
+    /// location which is not in the trash itself.
+    pub location_id: Option<u64>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn item_types_are_parsed_from_their_plural_path_segment() {
+        assert_eq!(Ok(TrashItemType::Book), TrashItemType::try_from("books"));
+        assert_eq!(
+            Ok(TrashItemType::Collection),
+            TrashItemType::try_from("collections")
+        );
+        assert_eq!(
+            Ok(TrashItemType::Location),
+            TrashItemType::try_from("locations")
+        );
+        assert!(TrashItemType::try_from("book").is_err());
+        assert!(TrashItemType::try_from("tags").is_err());
+    }
+}
@@ -1,9 +1,30 @@
+pub mod audit_entry_dto_mapper;
 pub mod author_dto_mapper;
+pub mod backup_book_dto_mapper;
+pub mod book_custom_field_values_mapper;
+pub mod book_merge_patch_mapper;
+pub mod bulk_book_operation_dto_mapper;
 pub mod collection_dto_mapper;
 pub mod complete_book_dto_mapper;
 pub mod create_book_dto_mapper;
 pub mod create_collection_dto_mapper;
+pub mod create_custom_field_dto_mapper;
 pub mod create_location_dto_mapper;
+pub mod create_note_dto_mapper;
+pub mod create_share_link_dto_mapper;
+pub mod create_tag_dto_mapper;
+pub mod custom_field_dto_mapper;
+pub mod export_books_params_mapper;
 pub mod genre_dto_mapper;
+pub mod import_books_csv_dto_mapper;
+pub mod import_goodreads_dto_mapper;
+pub mod import_library_thing_dto_mapper;
 pub mod language_dto_mapper;
 pub mod location_dto_mapper;
+pub mod note_dto_mapper;
+pub mod precondition_failed_api_error_mapper;
+pub mod public_book_dto_mapper;
+pub mod restore_library_dto_mapper;
+pub mod share_link_dto_mapper;
+pub mod tag_dto_mapper;
+pub mod trash_item_dto_mapper;
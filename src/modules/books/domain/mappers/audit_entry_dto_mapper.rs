@@ -0,0 +1,19 @@
+use crate::modules::books::domain::{
+    dtos::audit_entry_dto::AuditEntryDto, entities::audit_entry::AuditEntry,
+};
+
+impl From<AuditEntry> for AuditEntryDto {
+    fn from(entity: AuditEntry) -> Self {
+        AuditEntryDto {
+            id: entity.id.unwrap_or_default(),
+            entity_type: entity.entity_type.as_str().to_string(),
+            entity_id: entity.entity_id,
+            library_id: entity.library_id,
+            action: entity.action.as_str().to_string(),
+            actor_id: entity.actor_id,
+            changes: entity.changes,
+            created_at: entity.created_at.map(|date| date.to_rfc3339()),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::modules::books::domain::{
+    dtos::{
+        author_dto::AuthorDto, backup_book_dto::BackupBookDto, create_book_dto::CreateBookDto,
+        genre_dto::GenreDto, language_dto::LanguageDto,
+    },
+    entities::book::Book,
+};
+
+impl From<Book> for BackupBookDto {
+    fn from(book: Book) -> Self {
+        BackupBookDto {
+            id: book.id.unwrap_or_default(),
+            title: book.title,
+            authors: book.authors.into_iter().map(AuthorDto::from).collect(),
+            publisher: book.publisher,
+            languages: book.languages.into_iter().map(LanguageDto::from).collect(),
+            edition: book.edition,
+            isbn: book.isbn,
+            year: book.year,
+            genres: book
+                .genres
+                .map(|genres| genres.into_iter().map(GenreDto::from).collect()),
+            cover: book.cover,
+            collection_id: book.collection_id,
+            location_id: book.location_id,
+            tag_ids: book.tag_ids.unwrap_or_default(),
+            custom_fields: book.custom_fields.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<BackupBookDto> for CreateBookDto {
+    fn from(dto: BackupBookDto) -> Self {
+        CreateBookDto {
+            title: Some(dto.title),
+            authors: Some(dto.authors),
+            publisher: Some(dto.publisher),
+            languages: Some(dto.languages),
+            edition: dto.edition,
+            isbn: dto.isbn,
+            year: dto.year,
+            genres: dto.genres,
+            cover: dto.cover,
+            collection_id: dto.collection_id,
+            location_id: Some(dto.location_id),
+            user_id: None,
+            tag_ids: Some(dto.tag_ids),
+            custom_fields: Some(dto.custom_fields),
+            version: None,
+        }
+    }
+}
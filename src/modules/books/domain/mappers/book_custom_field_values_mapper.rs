@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::modules::{
+    books::domain::entities::custom_field::{BookCustomFieldValue, CustomField},
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+pub fn book_custom_field_values_from_dto(
+    values: &HashMap<String, Value>,
+    custom_fields: &[CustomField],
+) -> Result<Vec<BookCustomFieldValue>, DetailedAPIError> {
+    let mut validations: HashMap<String, String> = HashMap::default();
+    let mut book_values: Vec<BookCustomFieldValue> = Vec::with_capacity(values.len());
+
+    for (name, value) in values.iter() {
+        let validation_key = format!("custom_fields.{}", name);
+        let Some(custom_field) = custom_fields
+            .iter()
+            .find(|custom_field| custom_field.name.eq_ignore_ascii_case(name.trim()))
+        else {
+            validations.insert(validation_key, "Custom field does not exist".to_string());
+            continue;
+        };
+        if book_values
+            .iter()
+            .any(|book_value| Some(book_value.custom_field_id) == custom_field.id)
+        {
+            validations.insert(
+                validation_key,
+                "Custom field was informed twice".to_string(),
+            );
+            continue;
+        }
+
+        match custom_field.value_from_json(value) {
+            Ok(Some(converted_value)) => book_values.push(BookCustomFieldValue {
+                custom_field_id: custom_field.id.unwrap_or_default(),
+                field_type: custom_field.field_type,
+                value: converted_value,
+            }),
+            Ok(None) => {}
+            Err(error) => {
+                validations.insert(validation_key, error);
+            }
+        }
+    }
+
+    if !validations.is_empty() {
+        return Err(DetailedAPIError {
+            msg: "Request contains invalid data".to_string(),
+            code: 400,
+            field_validations: Some(validations),
+        });
+    }
+
+    Ok(book_values)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::modules::books::domain::entities::custom_field::{
+        CustomFieldType, CustomFieldValue,
+    };
+
+    use super::*;
+
+    fn custom_fields() -> Vec<CustomField> {
+        vec![
+            CustomField {
+                id: Some(1),
+                name: "Pages".to_string(),
+                field_type: CustomFieldType::Number,
+                ..Default::default()
+            },
+            CustomField {
+                id: Some(2),
+                name: "Signed".to_string(),
+                field_type: CustomFieldType::Boolean,
+                ..Default::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn values_are_matched_to_fields_by_name() {
+        let values: HashMap<String, Value> = [
+            ("pages".to_string(), json!(256)),
+            ("Signed".to_string(), json!(null)),
+        ]
+        .into();
+
+        let book_values = book_custom_field_values_from_dto(&values, &custom_fields()).unwrap();
+
+        assert_eq!(1, book_values.len());
+        assert_eq!(1, book_values[0].custom_field_id);
+        assert_eq!(CustomFieldValue::Number(256.0), book_values[0].value);
+    }
+
+    #[test]
+    fn unknown_repeated_and_mistyped_values_are_reported() {
+        let values: HashMap<String, Value> = [
+            ("Pages".to_string(), json!("many")),
+            ("Year".to_string(), json!(1899)),
+            ("Signed".to_string(), json!(true)),
+            ("signed".to_string(), json!(false)),
+        ]
+        .into();
+
+        let validations = book_custom_field_values_from_dto(&values, &custom_fields())
+            .unwrap_err()
+            .field_validations
+            .unwrap();
+
+        assert_eq!(
+            Some(&"Pages must be a number".to_string()),
+            validations.get("custom_fields.Pages")
+        );
+        assert!(validations.contains_key("custom_fields.Year"));
+        // Which spelling is reported depends on the iteration order
+        assert!(
+            validations.contains_key("custom_fields.Signed")
+                || validations.contains_key("custom_fields.signed")
+        );
+    }
+}
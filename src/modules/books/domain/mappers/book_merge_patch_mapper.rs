@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::modules::{
+    books::domain::{
+        entities::book::Book,
+        mappers::create_book_dto_mapper::{
+            book_authors_from_dto, book_cover_from_dto, book_genres_from_dto,
+            book_languages_from_dto, book_location_id_from_dto, book_publisher_from_dto,
+            book_tag_ids_from_dto, book_title_from_dto,
+        },
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+/// Applies a JSON Merge Patch (RFC 7386) to the stored book. Only the fields
+/// of the patch are validated, with the rules of a full update, so stored
+/// values the current rules would reject don't block unrelated changes.
+pub fn book_from_merge_patch(stored_book: Book, patch: &Value) -> Result<Book, DetailedAPIError> {
+    let Value::Object(patch_fields) = patch else {
+        return Err(invalid_data(HashMap::from([(
+            "body".to_string(),
+            "Merge patch must be a JSON object".to_string(),
+        )])));
+    };
+
+    let mut book = stored_book;
+    let mut validations: HashMap<String, String> = HashMap::default();
+    for (field, patch_value) in patch_fields.iter() {
+        match field.as_str() {
+            "title" => {
+                if let Some(title) = patch_field(field, patch_value, &mut validations) {
+                    book.title = book_title_from_dto(title, &mut validations);
+                }
+            }
+            "authors" => {
+                if let Some(authors) = patch_field(field, patch_value, &mut validations) {
+                    book.authors = book_authors_from_dto(authors, &mut validations)?;
+                }
+            }
+            "publisher" => {
+                if let Some(publisher) = patch_field(field, patch_value, &mut validations) {
+                    book.publisher = book_publisher_from_dto(publisher, &mut validations);
+                }
+            }
+            "languages" => {
+                if let Some(languages) = patch_field(field, patch_value, &mut validations) {
+                    book.languages = book_languages_from_dto(languages, &mut validations)?;
+                }
+            }
+            "edition" => {
+                if let Some(edition) = patch_field(field, patch_value, &mut validations) {
+                    book.edition = edition;
+                }
+            }
+            "isbn" => {
+                if let Some(isbn) = patch_field(field, patch_value, &mut validations) {
+                    book.isbn = isbn;
+                }
+            }
+            "year" => {
+                if let Some(year) = patch_field(field, patch_value, &mut validations) {
+                    book.year = year;
+                }
+            }
+            "genres" => {
+                if let Some(genres) = patch_field(field, patch_value, &mut validations) {
+                    book.genres = book_genres_from_dto(genres, &mut validations)?;
+                }
+            }
+            "cover" => {
+                if let Some(cover) = patch_field(field, patch_value, &mut validations) {
+                    book.cover = book_cover_from_dto(cover, &mut validations);
+                }
+            }
+            "collection_id" => {
+                if let Some(collection_id) = patch_field(field, patch_value, &mut validations) {
+                    book.collection_id = collection_id;
+                }
+            }
+            "location_id" => {
+                if let Some(location_id) = patch_field(field, patch_value, &mut validations) {
+                    book.location_id = book_location_id_from_dto(location_id, &mut validations);
+                }
+            }
+            "tag_ids" => {
+                if let Some(tag_ids) = patch_field(field, patch_value, &mut validations) {
+                    book.tag_ids = book_tag_ids_from_dto(tag_ids);
+                }
+            }
+            "custom_fields" => {
+                let mut custom_fields = serde_json::to_value(book.custom_fields.take())
+                    .unwrap_or_else(|_| Value::Object(Map::new()));
+                merge_patch(&mut custom_fields, patch_value);
+                if let Some(custom_fields) = patch_field(field, &custom_fields, &mut validations) {
+                    book.custom_fields = custom_fields;
+                }
+            }
+            // Patches can't move the book to another owner, and the version
+            // they are based on travels in If-Match
+            _ => {}
+        }
+    }
+
+    if !validations.is_empty() {
+        return Err(invalid_data(validations));
+    }
+
+    Ok(book)
+}
+
+/// Reads the patched value of a field, `None` when it has the wrong type.
+fn patch_field<T: DeserializeOwned>(
+    field: &str,
+    value: &Value,
+    validations: &mut HashMap<String, String>,
+) -> Option<T> {
+    match serde_json::from_value(value.clone()) {
+        Ok(value) => Some(value),
+        Err(error) => {
+            validations.insert(field.to_string(), error.to_string());
+            None
+        }
+    }
+}
+
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch_fields) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    if let Value::Object(target_fields) = target {
+        for (field, patch_value) in patch_fields.iter() {
+            if patch_value.is_null() {
+                target_fields.remove(field);
+            } else {
+                merge_patch(
+                    target_fields.entry(field.clone()).or_insert(Value::Null),
+                    patch_value,
+                );
+            }
+        }
+    }
+}
+
+fn invalid_data(validations: HashMap<String, String>) -> DetailedAPIError {
+    DetailedAPIError {
+        msg: "Request contains invalid data".to_string(),
+        code: 400,
+        field_validations: Some(validations),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::modules::books::domain::entities::{author::Author, language::Language};
+
+    fn stored_book() -> Book {
+        Book {
+            id: Some(7),
+            title: "Dom Casmurro".to_string(),
+            authors: vec![Author {
+                name: "Machado de Assis".to_string(),
+                url: None,
+            }],
+            publisher: "Garnier".to_string(),
+            languages: vec![Language {
+                name: "Portuguese".to_string(),
+                code: Some("pt".to_string()),
+            }],
+            edition: Some("1st".to_string()),
+            year: Some("1899".to_string()),
+            location_id: 3,
+            collection_id: Some(5),
+            user_id: 11,
+            library_id: 13,
+            custom_fields: Some(HashMap::from([
+                ("shelf".to_string(), json!("A1")),
+                ("signed".to_string(), json!(true)),
+            ])),
+            ..Default::default()
+        }
+    }
+
+    fn field_validations(error: DetailedAPIError) -> HashMap<String, String> {
+        error.field_validations.unwrap_or_default()
+    }
+
+    #[test]
+    fn absent_fields_keep_their_stored_values() {
+        let book = book_from_merge_patch(stored_book(), &json!({ "year": "1900" })).unwrap();
+
+        assert_eq!(Some(7), book.id);
+        assert_eq!("Dom Casmurro", book.title);
+        assert_eq!("Machado de Assis", book.authors[0].name);
+        assert_eq!(Some("1st".to_string()), book.edition);
+        assert_eq!(Some("1900".to_string()), book.year);
+        assert_eq!(Some(5), book.collection_id);
+        assert_eq!(3, book.location_id);
+    }
+
+    #[test]
+    fn null_removes_optional_fields() {
+        let book = book_from_merge_patch(
+            stored_book(),
+            &json!({ "edition": null, "collection_id": null }),
+        )
+        .unwrap();
+
+        assert_eq!(None, book.edition);
+        assert_eq!(None, book.collection_id);
+        assert_eq!(Some("1899".to_string()), book.year);
+    }
+
+    #[test]
+    fn only_the_patched_fields_are_validated() {
+        let legacy_book = Book {
+            publisher: String::new(),
+            languages: Vec::new(),
+            ..stored_book()
+        };
+
+        let book = book_from_merge_patch(legacy_book, &json!({ "year": "1900" })).unwrap();
+        assert_eq!(Some("1900".to_string()), book.year);
+
+        let error = book_from_merge_patch(stored_book(), &json!({ "publisher": " " })).unwrap_err();
+        let validations = field_validations(error);
+        assert_eq!(1, validations.len());
+        assert!(validations.contains_key("publisher"));
+    }
+
+    #[test]
+    fn null_on_required_fields_is_rejected() {
+        let error = book_from_merge_patch(stored_book(), &json!({ "title": null })).unwrap_err();
+
+        assert_eq!(400, error.code);
+        assert!(field_validations(error).contains_key("title"));
+    }
+
+    #[test]
+    fn arrays_replace_the_stored_ones() {
+        let book = book_from_merge_patch(
+            stored_book(),
+            &json!({ "authors": [{ "name": "José de Alencar" }] }),
+        )
+        .unwrap();
+
+        assert_eq!(1, book.authors.len());
+        assert_eq!("José de Alencar", book.authors[0].name);
+    }
+
+    #[test]
+    fn objects_are_merged_recursively() {
+        let book = book_from_merge_patch(
+            stored_book(),
+            &json!({ "custom_fields": { "shelf": "B2", "signed": null } }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            Some(HashMap::from([("shelf".to_string(), json!("B2"))])),
+            book.custom_fields
+        );
+    }
+
+    #[test]
+    fn owner_cant_be_patched() {
+        let book = book_from_merge_patch(stored_book(), &json!({ "user_id": 99 })).unwrap();
+
+        assert_eq!(11, book.user_id);
+    }
+
+    #[test]
+    fn patches_must_be_objects() {
+        let error = book_from_merge_patch(stored_book(), &json!(["title"])).unwrap_err();
+
+        assert_eq!(400, error.code);
+        assert!(field_validations(error).contains_key("body"));
+    }
+
+    #[test]
+    fn values_of_the_wrong_type_are_rejected() {
+        let error =
+            book_from_merge_patch(stored_book(), &json!({ "location_id": "shelf" })).unwrap_err();
+
+        assert_eq!(400, error.code);
+        assert!(field_validations(error).contains_key("location_id"));
+    }
+}
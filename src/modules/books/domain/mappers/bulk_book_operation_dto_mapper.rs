@@ -0,0 +1,135 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::modules::{
+    books::domain::{
+        dtos::bulk_book_operation_dto::BulkBookOperationDto,
+        entities::bulk_book_operation::{
+            BulkBookAction, BulkBookOperation, BulkBookTarget, MAX_BULK_BOOK_ITEMS,
+        },
+        entities::tag::MAX_FILTER_TAGS,
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<BulkBookOperationDto> for BulkBookOperation {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: BulkBookOperationDto) -> Result<Self, Self::Error> {
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        let action = match dto.action.as_deref().map(str::trim) {
+            Some("move_to_location") => match dto.location_id {
+                Some(location_id) => Some(BulkBookAction::MoveToLocation(location_id)),
+                None => {
+                    validations.insert(
+                        "location_id".to_string(),
+                        "A location must be informed to move books".to_string(),
+                    );
+                    None
+                }
+            },
+            Some("set_collection") => match dto.collection_id {
+                Some(collection_id) => Some(BulkBookAction::SetCollection(collection_id)),
+                None => {
+                    validations.insert(
+                        "collection_id".to_string(),
+                        "A collection must be informed to set it on books".to_string(),
+                    );
+                    None
+                }
+            },
+            Some("clear_collection") => Some(BulkBookAction::ClearCollection),
+            Some(action @ ("add_tags" | "remove_tags")) => {
+                let mut seen_ids: HashSet<u64> = HashSet::new();
+                let tag_ids: Vec<u64> = dto
+                    .tag_ids
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|id| seen_ids.insert(*id))
+                    .collect();
+                if tag_ids.is_empty() {
+                    validations.insert(
+                        "tag_ids".to_string(),
+                        "At least one tag must be informed".to_string(),
+                    );
+                    None
+                } else if tag_ids.len() > MAX_FILTER_TAGS {
+                    validations.insert(
+                        "tag_ids".to_string(),
+                        format!("At most {} tags can be informed", MAX_FILTER_TAGS),
+                    );
+                    None
+                } else if action == "add_tags" {
+                    Some(BulkBookAction::AddTags(tag_ids))
+                } else {
+                    Some(BulkBookAction::RemoveTags(tag_ids))
+                }
+            }
+            Some("delete") => Some(BulkBookAction::Delete),
+            Some(_) => {
+                validations.insert(
+                    "action".to_string(),
+                    "Action must be one of move_to_location, set_collection, clear_collection, add_tags, remove_tags or delete"
+                        .to_string(),
+                );
+                None
+            }
+            None => {
+                validations.insert("action".to_string(), "Action must be informed".to_string());
+                None
+            }
+        };
+
+        let target = match (dto.ids, dto.filter) {
+            (Some(_), Some(_)) => {
+                validations.insert(
+                    "ids".to_string(),
+                    "Either ids or filter must be informed, not both".to_string(),
+                );
+                None
+            }
+            (Some(ids), None) => {
+                let mut seen_ids: HashSet<u64> = HashSet::with_capacity(ids.len());
+                let unique_ids: Vec<u64> =
+                    ids.into_iter().filter(|id| seen_ids.insert(*id)).collect();
+                if unique_ids.is_empty() {
+                    validations.insert(
+                        "ids".to_string(),
+                        "At least one book id must be informed".to_string(),
+                    );
+                    None
+                } else if unique_ids.len() > MAX_BULK_BOOK_ITEMS {
+                    validations.insert(
+                        "ids".to_string(),
+                        format!(
+                            "At most {} books can be changed at once",
+                            MAX_BULK_BOOK_ITEMS
+                        ),
+                    );
+                    None
+                } else {
+                    Some(BulkBookTarget::Ids(unique_ids))
+                }
+            }
+            (None, Some(filter)) => Some(BulkBookTarget::Filter(Box::new(filter))),
+            (None, None) => {
+                validations.insert(
+                    "ids".to_string(),
+                    "Either ids or filter must be informed".to_string(),
+                );
+                None
+            }
+        };
+
+        match (action, target) {
+            (Some(action), Some(target)) if validations.is_empty() => {
+                Ok(BulkBookOperation { action, target })
+            }
+            _ => Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            }),
+        }
+    }
+}
@@ -49,6 +49,8 @@ impl TryFrom<Book> for CompleteBookDto {
         dto.collection = None;
         dto.location = LocationDto::default();
         dto.user_id = entity.user_id;
+        dto.library_id = entity.library_id;
+        dto.version = entity.version;
 
         Ok(dto)
     }
@@ -1,12 +1,15 @@
 use crate::modules::{
     books::domain::{
-        dtos::create_book_dto::CreateBookDto,
+        dtos::{
+            author_dto::AuthorDto, create_book_dto::CreateBookDto, genre_dto::GenreDto,
+            language_dto::LanguageDto,
+        },
         entities::{author::Author, book::Book, genre::Genre, language::Language},
     },
     shared::errors::detailed_api_error::DetailedAPIError,
 };
 use base64::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 impl TryFrom<CreateBookDto> for Book {
     type Error = DetailedAPIError;
@@ -15,180 +18,266 @@ impl TryFrom<CreateBookDto> for Book {
         let mut book = Book::default();
         let mut validations: HashMap<String, String> = HashMap::default();
 
-        match dto.title {
-            Some(title) => {
-                let candidate_title = title.trim();
-                if candidate_title.is_empty() {
-                    validations.insert(
-                        "title".to_string(),
-                        "O título do livro não pode estar vazio".to_string(),
-                    );
-                }
-                book.title = candidate_title.to_string()
-            }
+        book.title = book_title_from_dto(dto.title, &mut validations);
+        book.authors = book_authors_from_dto(dto.authors, &mut validations)?;
+        book.languages = book_languages_from_dto(dto.languages, &mut validations)?;
+        book.publisher = book_publisher_from_dto(dto.publisher, &mut validations);
+        book.edition = dto.edition;
+        book.isbn = dto.isbn;
+        book.year = dto.year;
+        book.genres = book_genres_from_dto(dto.genres, &mut validations)?;
+        book.cover = book_cover_from_dto(dto.cover, &mut validations);
+        book.collection_id = dto.collection_id;
+        book.location_id = book_location_id_from_dto(dto.location_id, &mut validations);
+        book.tag_ids = book_tag_ids_from_dto(dto.tag_ids);
+        book.custom_fields = dto.custom_fields;
+
+        match dto.user_id {
+            Some(user_id) => book.user_id = user_id,
             None => {
                 validations.insert(
-                    "title".to_string(),
-                    "O título do livro deve ser informado".to_string(),
+                    "user_id".to_string(),
+                    "O livro deve pertencer a um usuãrio".to_string(),
                 );
             }
         }
 
-        match dto.authors {
-            Some(authors) => {
-                if authors.is_empty() {
-                    validations.insert(
-                        "authors".to_string(),
-                        "Pelo menos um autor deve ser informado".to_string(),
-                    );
-                } else {
-                    let mut authors_entity: Vec<Author> = Vec::with_capacity(authors.capacity());
-                    for author_dto in authors.into_iter() {
-                        match Author::try_from(author_dto) {
-                            Ok(author) => {
-                                authors_entity.push(author);
-                            }
-                            Err(error) => return Err(error),
-                        }
-                    }
-                    book.authors = authors_entity;
-                }
-            }
-            None => {
+        if !validations.is_empty() {
+            return Err(DetailedAPIError {
+                msg: "Livro contém informações inválidas".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            });
+        }
+
+        Ok(book)
+    }
+}
+
+pub fn book_title_from_dto(
+    title: Option<String>,
+    validations: &mut HashMap<String, String>,
+) -> String {
+    match title {
+        Some(title) => {
+            let candidate_title = title.trim();
+            if candidate_title.is_empty() {
                 validations.insert(
-                    "authors".to_string(),
-                    "Os autores do livro devem ser informados".to_string(),
+                    "title".to_string(),
+                    "O título do livro não pode estar vazio".to_string(),
                 );
             }
+            candidate_title.to_string()
         }
+        None => {
+            validations.insert(
+                "title".to_string(),
+                "O título do livro deve ser informado".to_string(),
+            );
+            String::new()
+        }
+    }
+}
 
-        match dto.languages {
-            Some(languages) => {
-                if languages.is_empty() {
-                    validations.insert(
-                        "languages".to_string(),
-                        "Pelo menos um idioma deve ser informado".to_string(),
-                    );
-                } else {
-                    let mut languages_entity: Vec<Language> =
-                        Vec::with_capacity(languages.capacity());
-                    for language_dto in languages.into_iter() {
-                        match Language::try_from(language_dto) {
-                            Ok(language) => {
-                                languages_entity.push(language);
-                            }
-                            Err(error) => return Err(error),
-                        }
+pub fn book_authors_from_dto(
+    authors: Option<Vec<AuthorDto>>,
+    validations: &mut HashMap<String, String>,
+) -> Result<Vec<Author>, DetailedAPIError> {
+    match authors {
+        Some(authors) => {
+            if authors.is_empty() {
+                validations.insert(
+                    "authors".to_string(),
+                    "Pelo menos um autor deve ser informado".to_string(),
+                );
+                return Ok(Vec::new());
+            }
+            let mut authors_entity: Vec<Author> = Vec::with_capacity(authors.capacity());
+            for author_dto in authors.into_iter() {
+                match Author::try_from(author_dto) {
+                    Ok(author) => {
+                        authors_entity.push(author);
                     }
-                    book.languages = languages_entity;
+                    Err(error) => return Err(error),
                 }
             }
-            None => {
+            Ok(authors_entity)
+        }
+        None => {
+            validations.insert(
+                "authors".to_string(),
+                "Os autores do livro devem ser informados".to_string(),
+            );
+            Ok(Vec::new())
+        }
+    }
+}
+
+pub fn book_languages_from_dto(
+    languages: Option<Vec<LanguageDto>>,
+    validations: &mut HashMap<String, String>,
+) -> Result<Vec<Language>, DetailedAPIError> {
+    match languages {
+        Some(languages) => {
+            if languages.is_empty() {
                 validations.insert(
                     "languages".to_string(),
-                    "Os idiomas do livro devem ser informados".to_string(),
+                    "Pelo menos um idioma deve ser informado".to_string(),
                 );
+                return Ok(Vec::new());
             }
-        }
-        match dto.publisher {
-            Some(publisher) => {
-                let candidate_publisher = publisher.trim();
-                if candidate_publisher.is_empty() {
-                    validations.insert(
-                        "publisher".to_string(),
-                        "O campo editora não pode ser vazio".to_string(),
-                    );
+            let mut languages_entity: Vec<Language> = Vec::with_capacity(languages.capacity());
+            for language_dto in languages.into_iter() {
+                match Language::try_from(language_dto) {
+                    Ok(language) => {
+                        languages_entity.push(language);
+                    }
+                    Err(error) => return Err(error),
                 }
-                book.publisher = candidate_publisher.to_string()
             }
-            None => {
+            Ok(languages_entity)
+        }
+        None => {
+            validations.insert(
+                "languages".to_string(),
+                "Os idiomas do livro devem ser informados".to_string(),
+            );
+            Ok(Vec::new())
+        }
+    }
+}
+
+pub fn book_publisher_from_dto(
+    publisher: Option<String>,
+    validations: &mut HashMap<String, String>,
+) -> String {
+    match publisher {
+        Some(publisher) => {
+            let candidate_publisher = publisher.trim();
+            if candidate_publisher.is_empty() {
                 validations.insert(
                     "publisher".to_string(),
-                    "A editora deve ser informada".to_string(),
+                    "O campo editora não pode ser vazio".to_string(),
                 );
             }
+            candidate_publisher.to_string()
         }
-        book.edition = dto.edition;
-        book.isbn = dto.isbn;
-        book.year = dto.year;
+        None => {
+            validations.insert(
+                "publisher".to_string(),
+                "A editora deve ser informada".to_string(),
+            );
+            String::new()
+        }
+    }
+}
 
-        match dto.genres {
-            Some(genres) => {
-                if genres.is_empty() {
-                    validations.insert(
-                        "genres".to_string(),
-                        "Pelo menos um gênero deve ser informado".to_string(),
-                    );
-                } else {
-                    let mut genres_entity: Vec<Genre> = Vec::with_capacity(genres.capacity());
-                    for genre_dto in genres.into_iter() {
-                        match Genre::try_from(genre_dto) {
-                            Ok(genre) => {
-                                genres_entity.push(genre);
-                            }
-                            Err(error) => return Err(error),
-                        }
+pub fn book_genres_from_dto(
+    genres: Option<Vec<GenreDto>>,
+    validations: &mut HashMap<String, String>,
+) -> Result<Option<Vec<Genre>>, DetailedAPIError> {
+    match genres {
+        Some(genres) => {
+            if genres.is_empty() {
+                validations.insert(
+                    "genres".to_string(),
+                    "Pelo menos um gênero deve ser informado".to_string(),
+                );
+                return Ok(None);
+            }
+            let mut genres_entity: Vec<Genre> = Vec::with_capacity(genres.capacity());
+            for genre_dto in genres.into_iter() {
+                match Genre::try_from(genre_dto) {
+                    Ok(genre) => {
+                        genres_entity.push(genre);
                     }
-                    book.genres = Some(genres_entity);
+                    Err(error) => return Err(error),
                 }
             }
-            None => {
-                book.genres = None;
-            }
+            Ok(Some(genres_entity))
         }
+        None => Ok(None),
+    }
+}
 
-        match dto.cover {
-            None => {
-                book.cover = None;
+pub fn book_cover_from_dto(
+    cover: Option<String>,
+    validations: &mut HashMap<String, String>,
+) -> Option<String> {
+    match cover {
+        None => None,
+        Some(cover_to_be) => {
+            if cover_to_be.starts_with("http://") || cover_to_be.starts_with("https://") {
+                return Some(cover_to_be);
             }
-            Some(cover_to_be) => {
-                if cover_to_be.starts_with("http://") || cover_to_be.starts_with("https://") {
-                    book.cover = Some(cover_to_be)
-                } else {
-                    match BASE64_STANDARD.decode(cover_to_be) {
-                        Ok(_decoded_image) => {
-                            //TODO upload to S3 or something
-                        }
-                        Err(_error) => {
-                            validations.insert(
-                                "cover".to_string(),
-                                "A capa do livro contém uma imagem inválida".to_string(),
-                            );
-                        }
-                    }
+            match BASE64_STANDARD.decode(cover_to_be) {
+                Ok(_decoded_image) => {
+                    //TODO upload to S3 or something
+                }
+                Err(_error) => {
+                    validations.insert(
+                        "cover".to_string(),
+                        "A capa do livro contém uma imagem inválida".to_string(),
+                    );
                 }
             }
+            None
         }
-        book.collection_id = dto.collection_id;
+    }
+}
 
-        match dto.location_id {
-            Some(location_id) => book.location_id = location_id,
-            None => {
-                validations.insert(
-                    "location_id".to_string(),
-                    "O livro deve estar em alguma localização".to_string(),
-                );
-            }
+pub fn book_location_id_from_dto(
+    location_id: Option<u64>,
+    validations: &mut HashMap<String, String>,
+) -> u64 {
+    match location_id {
+        Some(location_id) => location_id,
+        None => {
+            validations.insert(
+                "location_id".to_string(),
+                "O livro deve estar em alguma localização".to_string(),
+            );
+            0
         }
+    }
+}
 
-        match dto.user_id {
-            Some(user_id) => book.user_id = user_id,
-            None => {
-                validations.insert(
-                    "user_id".to_string(),
-                    "O livro deve pertencer a um usuãrio".to_string(),
-                );
-            }
-        }
+pub fn book_tag_ids_from_dto(tag_ids: Option<Vec<u64>>) -> Option<Vec<u64>> {
+    tag_ids.map(|tag_ids| {
+        let mut seen_ids: HashSet<u64> = HashSet::with_capacity(tag_ids.len());
+        tag_ids
+            .into_iter()
+            .filter(|id| seen_ids.insert(*id))
+            .collect()
+    })
+}
 
-        if !validations.is_empty() {
-            return Err(DetailedAPIError {
-                msg: "Livro contém informações inválidas".to_string(),
-                code: 400,
-                field_validations: Some(validations),
-            });
+impl From<Book> for CreateBookDto {
+    fn from(entity: Book) -> Self {
+        CreateBookDto {
+            title: Some(entity.title),
+            authors: Some(entity.authors.into_iter().map(AuthorDto::from).collect()),
+            publisher: Some(entity.publisher),
+            languages: Some(
+                entity
+                    .languages
+                    .into_iter()
+                    .map(LanguageDto::from)
+                    .collect(),
+            ),
+            edition: entity.edition,
+            isbn: entity.isbn,
+            year: entity.year,
+            genres: entity
+                .genres
+                .map(|genres| genres.into_iter().map(GenreDto::from).collect()),
+            cover: entity.cover,
+            collection_id: entity.collection_id,
+            location_id: Some(entity.location_id),
+            user_id: Some(entity.user_id),
+            tag_ids: entity.tag_ids,
+            custom_fields: entity.custom_fields,
+            version: Some(entity.version),
         }
-
-        Ok(book)
     }
 }
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    books::domain::{
+        dtos::create_custom_field_dto::CreateCustomFieldDto,
+        entities::custom_field::{
+            CustomField, CustomFieldType, MAX_CUSTOM_FIELD_NAME_LENGTH, MAX_CUSTOM_FIELD_OPTIONS,
+            MAX_CUSTOM_FIELD_TEXT_LENGTH,
+        },
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<CreateCustomFieldDto> for CustomField {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: CreateCustomFieldDto) -> Result<Self, Self::Error> {
+        let mut custom_field = CustomField::default();
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        match dto.name {
+            Some(name) => {
+                let candidate_name = name.trim();
+                if candidate_name.is_empty() {
+                    validations.insert(
+                        "name".to_string(),
+                        "Custom field name must not be empty".to_string(),
+                    );
+                } else if candidate_name.chars().count() > MAX_CUSTOM_FIELD_NAME_LENGTH {
+                    validations.insert(
+                        "name".to_string(),
+                        format!(
+                            "Custom field name must have at most {} characters",
+                            MAX_CUSTOM_FIELD_NAME_LENGTH
+                        ),
+                    );
+                }
+                custom_field.name = candidate_name.to_string();
+            }
+            None => {
+                validations.insert(
+                    "name".to_string(),
+                    "Custom field name must be informed".to_string(),
+                );
+            }
+        }
+
+        match dto.field_type.as_deref().map(CustomFieldType::try_from) {
+            Some(Ok(field_type)) => custom_field.field_type = field_type,
+            Some(Err(error)) => {
+                validations.insert("field_type".to_string(), error);
+            }
+            None => {
+                validations.insert(
+                    "field_type".to_string(),
+                    "Field type must be informed".to_string(),
+                );
+            }
+        }
+
+        let options: Vec<String> = dto
+            .options
+            .unwrap_or_default()
+            .iter()
+            .map(|option| option.trim().to_string())
+            .filter(|option| !option.is_empty())
+            .collect();
+        if custom_field.field_type == CustomFieldType::Enum {
+            if options.is_empty() {
+                validations.insert(
+                    "options".to_string(),
+                    "Enum fields must have at least one option".to_string(),
+                );
+            } else if options.len() > MAX_CUSTOM_FIELD_OPTIONS {
+                validations.insert(
+                    "options".to_string(),
+                    format!(
+                        "Enum fields can have at most {} options",
+                        MAX_CUSTOM_FIELD_OPTIONS
+                    ),
+                );
+            } else if options
+                .iter()
+                .any(|option| option.chars().count() > MAX_CUSTOM_FIELD_TEXT_LENGTH)
+            {
+                validations.insert(
+                    "options".to_string(),
+                    format!(
+                        "Options must have at most {} characters",
+                        MAX_CUSTOM_FIELD_TEXT_LENGTH
+                    ),
+                );
+            }
+            let mut unique_options: Vec<String> = Vec::with_capacity(options.len());
+            for option in options.into_iter() {
+                if !unique_options
+                    .iter()
+                    .any(|unique| unique.eq_ignore_ascii_case(&option))
+                {
+                    unique_options.push(option);
+                }
+            }
+            custom_field.options = unique_options;
+        } else if !options.is_empty() {
+            validations.insert(
+                "options".to_string(),
+                "Only enum fields accept options".to_string(),
+            );
+        }
+
+        match dto.user_id {
+            Some(user_id) => custom_field.user_id = user_id,
+            None => {
+                validations.insert(
+                    "user_id".to_string(),
+                    "Custom field must be related to an user".to_string(),
+                );
+            }
+        }
+
+        if !validations.is_empty() {
+            return Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            });
+        }
+
+        Ok(custom_field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_field(field_type: &str, options: Option<Vec<&str>>) -> CreateCustomFieldDto {
+        CreateCustomFieldDto {
+            name: Some(" Binding ".to_string()),
+            field_type: Some(field_type.to_string()),
+            options: options.map(|options| options.into_iter().map(str::to_string).collect()),
+            user_id: Some(1),
+        }
+    }
+
+    fn field_validations(error: DetailedAPIError) -> HashMap<String, String> {
+        error.field_validations.unwrap_or_default()
+    }
+
+    #[test]
+    fn enum_options_are_trimmed_and_deduplicated() {
+        let created_field = CustomField::try_from(custom_field(
+            "enum",
+            Some(vec![" Hardcover", "paperback", "", "Paperback "]),
+        ))
+        .unwrap();
+
+        assert_eq!("Binding", created_field.name);
+        assert_eq!(CustomFieldType::Enum, created_field.field_type);
+        assert_eq!(vec!["Hardcover", "paperback"], created_field.options);
+    }
+
+    #[test]
+    fn types_and_options_are_validated() {
+        let validations =
+            field_validations(CustomField::try_from(custom_field("color", None)).unwrap_err());
+        assert!(validations.contains_key("field_type"));
+
+        let validations = field_validations(
+            CustomField::try_from(custom_field("enum", Some(vec![" "]))).unwrap_err(),
+        );
+        assert!(validations.contains_key("options"));
+
+        let validations = field_validations(
+            CustomField::try_from(custom_field("number", Some(vec!["1"]))).unwrap_err(),
+        );
+        assert!(validations.contains_key("options"));
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    books::domain::{
+        dtos::create_note_dto::CreateNoteDto,
+        entities::note::{Note, NoteType, MAX_NOTE_TEXT_LENGTH},
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<CreateNoteDto> for Note {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: CreateNoteDto) -> Result<Self, Self::Error> {
+        let mut note = Note::default();
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        if let Some(note_type) = dto.note_type {
+            match NoteType::try_from(note_type.trim()) {
+                Ok(note_type) => note.note_type = note_type,
+                Err(error) => {
+                    validations.insert("note_type".to_string(), error);
+                }
+            }
+        }
+
+        match dto.page {
+            Some(0) => {
+                validations.insert(
+                    "page".to_string(),
+                    "Page must have a value greater than zero".to_string(),
+                );
+            }
+            page => note.page = page,
+        }
+
+        match dto.text {
+            Some(text) => {
+                let candidate_text = text.trim();
+                if candidate_text.is_empty() {
+                    validations.insert(
+                        "text".to_string(),
+                        "Note text must not be empty".to_string(),
+                    );
+                } else if candidate_text.chars().count() > MAX_NOTE_TEXT_LENGTH {
+                    validations.insert(
+                        "text".to_string(),
+                        format!(
+                            "Note text must have at most {} characters",
+                            MAX_NOTE_TEXT_LENGTH
+                        ),
+                    );
+                }
+                note.text = candidate_text.to_string();
+            }
+            None => {
+                validations.insert("text".to_string(), "Note text must be informed".to_string());
+            }
+        }
+
+        match dto.book_id {
+            Some(book_id) => note.book_id = book_id,
+            None => {
+                validations.insert(
+                    "book_id".to_string(),
+                    "Note must be related to a book".to_string(),
+                );
+            }
+        }
+
+        match dto.user_id {
+            Some(user_id) => note.user_id = user_id,
+            None => {
+                validations.insert(
+                    "user_id".to_string(),
+                    "Note must be related to an user".to_string(),
+                );
+            }
+        }
+
+        if !validations.is_empty() {
+            return Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            });
+        }
+
+        Ok(note)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note_dto(note_type: Option<&str>, page: Option<u32>, text: &str) -> CreateNoteDto {
+        CreateNoteDto {
+            note_type: note_type.map(str::to_string),
+            page,
+            text: Some(text.to_string()),
+            book_id: Some(1),
+            user_id: Some(2),
+        }
+    }
+
+    #[test]
+    fn notes_default_to_the_note_type() {
+        let note = Note::try_from(note_dto(None, None, " Capitu's eyes ")).unwrap();
+
+        assert_eq!(NoteType::Note, note.note_type);
+        assert_eq!("Capitu's eyes", note.text);
+        assert_eq!(None, note.page);
+
+        let quote =
+            Note::try_from(note_dto(Some(" quote "), Some(12), "Olhos de ressaca")).unwrap();
+        assert_eq!(NoteType::Quote, quote.note_type);
+        assert_eq!(Some(12), quote.page);
+    }
+
+    #[test]
+    fn types_pages_and_texts_are_validated() {
+        let validations = Note::try_from(note_dto(Some("highlight"), Some(0), " "))
+            .unwrap_err()
+            .field_validations
+            .unwrap();
+
+        assert!(validations.contains_key("note_type"));
+        assert!(validations.contains_key("page"));
+        assert!(validations.contains_key("text"));
+
+        let validations =
+            Note::try_from(note_dto(None, None, &"x".repeat(MAX_NOTE_TEXT_LENGTH + 1)))
+                .unwrap_err()
+                .field_validations
+                .unwrap();
+        assert!(validations.contains_key("text"));
+    }
+}
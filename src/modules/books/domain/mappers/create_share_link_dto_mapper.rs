@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use rand::{distributions::Alphanumeric, Rng};
+
+use crate::modules::{
+    books::domain::{
+        dtos::create_share_link_dto::CreateShareLinkDto,
+        entities::share_link::{ShareLink, ShareLinkScope, SHARE_LINK_SLUG_LENGTH},
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<CreateShareLinkDto> for ShareLink {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: CreateShareLinkDto) -> Result<Self, Self::Error> {
+        let mut share_link = ShareLink::default();
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        match dto.scope.as_deref() {
+            None | Some("library") => share_link.scope = ShareLinkScope::Library,
+            Some("location") => match dto.location_id {
+                Some(location_id) => share_link.scope = ShareLinkScope::Location(location_id),
+                None => {
+                    validations.insert(
+                        "location_id".to_string(),
+                        "Location must be informed when sharing a location".to_string(),
+                    );
+                }
+            },
+            Some("collection") => match dto.collection_id {
+                Some(collection_id) => share_link.scope = ShareLinkScope::Collection(collection_id),
+                None => {
+                    validations.insert(
+                        "collection_id".to_string(),
+                        "Collection must be informed when sharing a collection".to_string(),
+                    );
+                }
+            },
+            Some(_) => {
+                validations.insert(
+                    "scope".to_string(),
+                    "Scope must be one of library, location, collection".to_string(),
+                );
+            }
+        }
+
+        if let Some(expires_at) = dto.expires_at {
+            match DateTime::parse_from_rfc3339(&expires_at) {
+                Ok(expires_at) if expires_at.with_timezone(&Utc) <= Utc::now() => {
+                    validations.insert(
+                        "expires_at".to_string(),
+                        "Expiration must be in the future".to_string(),
+                    );
+                }
+                Ok(expires_at) => share_link.expires_at = Some(expires_at.with_timezone(&Utc)),
+                Err(_) => {
+                    validations.insert(
+                        "expires_at".to_string(),
+                        "Expiration must be a RFC 3339 timestamp".to_string(),
+                    );
+                }
+            }
+        }
+
+        match dto.user_id {
+            Some(user_id) => share_link.user_id = user_id,
+            None => {
+                validations.insert(
+                    "user_id".to_string(),
+                    "Share link must be related to an user".to_string(),
+                );
+            }
+        }
+
+        share_link.slug = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(SHARE_LINK_SLUG_LENGTH)
+            .map(char::from)
+            .collect();
+
+        if !validations.is_empty() {
+            return Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            });
+        }
+        Ok(share_link)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn share(scope: Option<&str>) -> CreateShareLinkDto {
+        CreateShareLinkDto {
+            scope: scope.map(str::to_string),
+            user_id: Some(1),
+            ..Default::default()
+        }
+    }
+
+    fn field_validations(error: DetailedAPIError) -> HashMap<String, String> {
+        error.field_validations.unwrap_or_default()
+    }
+
+    #[test]
+    fn libraries_are_shared_by_default_under_a_random_slug() {
+        let first_link = ShareLink::try_from(share(None)).unwrap();
+        let second_link = ShareLink::try_from(share(Some("library"))).unwrap();
+
+        assert_eq!(ShareLinkScope::Library, first_link.scope);
+        assert_eq!(SHARE_LINK_SLUG_LENGTH, first_link.slug.len());
+        assert!(first_link.slug.chars().all(|c| c.is_ascii_alphanumeric()));
+        assert_ne!(first_link.slug, second_link.slug);
+    }
+
+    #[test]
+    fn scoped_links_need_their_target() {
+        let collection_link = ShareLink::try_from(CreateShareLinkDto {
+            collection_id: Some(2),
+            ..share(Some("collection"))
+        })
+        .unwrap();
+        assert_eq!(ShareLinkScope::Collection(2), collection_link.scope);
+
+        let validations =
+            field_validations(ShareLink::try_from(share(Some("location"))).unwrap_err());
+        assert!(validations.contains_key("location_id"));
+        let validations =
+            field_validations(ShareLink::try_from(share(Some("collection"))).unwrap_err());
+        assert!(validations.contains_key("collection_id"));
+        let validations = field_validations(ShareLink::try_from(share(Some("book"))).unwrap_err());
+        assert!(validations.contains_key("scope"));
+    }
+
+    #[test]
+    fn expirations_must_be_future_timestamps() {
+        for expires_at in ["2020-01-01T00:00:00Z", "tomorrow"] {
+            let validations = field_validations(
+                ShareLink::try_from(CreateShareLinkDto {
+                    expires_at: Some(expires_at.to_string()),
+                    ..share(None)
+                })
+                .unwrap_err(),
+            );
+            assert!(validations.contains_key("expires_at"), "{}", expires_at);
+        }
+
+        let share_link = ShareLink::try_from(CreateShareLinkDto {
+            expires_at: Some("2999-01-01T00:00:00Z".to_string()),
+            ..share(None)
+        })
+        .unwrap();
+        assert!(share_link.expires_at.is_some());
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    books::domain::{
+        dtos::create_tag_dto::CreateTagDto,
+        entities::tag::{Tag, MAX_TAG_NAME_LENGTH},
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<CreateTagDto> for Tag {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: CreateTagDto) -> Result<Self, Self::Error> {
+        let mut tag = Tag::default();
+        let mut validations: HashMap<String, String> = HashMap::default();
+        match dto.name {
+            Some(name) => {
+                let candidate_name = name.trim();
+                if candidate_name.is_empty() {
+                    validations
+                        .insert("name".to_string(), "Tag name must not be empty".to_string());
+                } else if candidate_name.chars().count() > MAX_TAG_NAME_LENGTH {
+                    validations.insert(
+                        "name".to_string(),
+                        format!(
+                            "Tag name must have at most {} characters",
+                            MAX_TAG_NAME_LENGTH
+                        ),
+                    );
+                }
+                tag.name = candidate_name.to_string();
+            }
+            None => {
+                validations.insert("name".to_string(), "Tag name must be informed".to_string());
+            }
+        }
+
+        match dto.user_id {
+            Some(user_id) => tag.user_id = user_id,
+            None => {
+                validations.insert(
+                    "user_id".to_string(),
+                    "Tag must be related to an user".to_string(),
+                );
+            }
+        }
+
+        if !validations.is_empty() {
+            return Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            });
+        }
+
+        Ok(tag)
+    }
+}
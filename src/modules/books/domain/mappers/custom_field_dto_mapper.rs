@@ -0,0 +1,14 @@
+use crate::modules::books::domain::{
+    dtos::custom_field_dto::CustomFieldDto, entities::custom_field::CustomField,
+};
+
+impl From<CustomField> for CustomFieldDto {
+    fn from(entity: CustomField) -> Self {
+        CustomFieldDto {
+            id: entity.id.unwrap_or_default(),
+            name: entity.name,
+            field_type: entity.field_type.as_str().to_string(),
+            options: entity.options,
+        }
+    }
+}
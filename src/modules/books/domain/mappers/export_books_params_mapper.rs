@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    books::domain::{
+        dtos::{export_books_params::ExportBooksParams, get_all_books_params::GetAllBooksParams},
+        entities::book_export::{BookExport, BookExportFormat},
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<ExportBooksParams> for BookExport {
+    type Error = DetailedAPIError;
+
+    fn try_from(params: ExportBooksParams) -> Result<Self, Self::Error> {
+        let format = match params.format.as_deref().map(str::to_lowercase).as_deref() {
+            None | Some("csv") => BookExportFormat::Csv,
+            Some("json") => BookExportFormat::Json,
+            Some("xlsx") => BookExportFormat::Xlsx,
+            Some(_) => {
+                let mut validations: HashMap<String, String> = HashMap::default();
+                validations.insert(
+                    "format".to_string(),
+                    "Format must be one of csv, json, xlsx".to_string(),
+                );
+                return Err(DetailedAPIError {
+                    msg: "Request contains invalid data".to_string(),
+                    code: 400,
+                    field_validations: Some(validations),
+                });
+            }
+        };
+
+        Ok(BookExport {
+            format,
+            listing_params: GetAllBooksParams {
+                collection_id: params.collection_id,
+                location_id: params.location_id,
+                query: params.query,
+                tag_ids: params.tag_ids,
+                tag_match: params.tag_match,
+                custom_field: params.custom_field,
+                custom_field_value: params.custom_field_value,
+                custom_field_min: params.custom_field_min,
+                custom_field_max: params.custom_field_max,
+                sort_custom_field: params.sort_custom_field,
+                sort_direction: params.sort_direction,
+                ..Default::default()
+            },
+        })
+    }
+}
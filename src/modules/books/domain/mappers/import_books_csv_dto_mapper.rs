@@ -0,0 +1,432 @@
+use std::collections::HashMap;
+
+use csv::{ReaderBuilder, StringRecord};
+
+use crate::modules::{
+    books::domain::{
+        dtos::{
+            author_dto::AuthorDto, create_book_dto::CreateBookDto, genre_dto::GenreDto,
+            import_books_csv_dto::ImportBooksCsvDto, language_dto::LanguageDto,
+        },
+        entities::{
+            book_export::unescape_csv_formula,
+            book_import::{BookImport, BookImportCandidate, MAX_IMPORT_ROWS},
+        },
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+const IMPORTABLE_FIELDS: [&str; 11] = [
+    "title",
+    "authors",
+    "publisher",
+    "languages",
+    "edition",
+    "isbn",
+    "year",
+    "genres",
+    "cover",
+    "location",
+    "collection",
+];
+
+impl TryFrom<ImportBooksCsvDto> for BookImport {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: ImportBooksCsvDto) -> Result<Self, Self::Error> {
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        let delimiter = match dto.delimiter {
+            None => b',',
+            Some(delimiter) if delimiter.len() == 1 && delimiter.is_ascii() => {
+                delimiter.as_bytes()[0]
+            }
+            Some(_) => {
+                validations.insert(
+                    "delimiter".to_string(),
+                    "Delimiter must be a single ASCII character".to_string(),
+                );
+                b','
+            }
+        };
+
+        let list_separator = match dto.list_separator {
+            None => ";".to_string(),
+            Some(separator) if !separator.is_empty() => separator,
+            Some(_) => {
+                validations.insert(
+                    "list_separator".to_string(),
+                    "List separator must not be empty".to_string(),
+                );
+                ";".to_string()
+            }
+        };
+
+        let mapping = dto.mapping.unwrap_or_default();
+        for field in mapping.keys() {
+            if !IMPORTABLE_FIELDS.contains(&field.as_str()) {
+                validations.insert(
+                    format!("mapping.{}", field),
+                    format!("Field must be one of {}", IMPORTABLE_FIELDS.join(", ")),
+                );
+            }
+        }
+
+        let content = match dto.content {
+            Some(content) if !content.trim().is_empty() => content,
+            _ => {
+                validations.insert(
+                    "content".to_string(),
+                    "CSV content must be informed".to_string(),
+                );
+                String::new()
+            }
+        };
+
+        if !validations.is_empty() {
+            return Err(invalid_data(validations));
+        }
+
+        let mut reader = ReaderBuilder::new()
+            .delimiter(delimiter)
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(content.as_bytes());
+
+        let headers = match reader.headers() {
+            Ok(headers) => headers.clone(),
+            Err(error) => {
+                validations.insert("content".to_string(), error.to_string());
+                return Err(invalid_data(validations));
+            }
+        };
+
+        let mut column_by_field: HashMap<&str, usize> = HashMap::default();
+        for field in IMPORTABLE_FIELDS {
+            let header = mapping
+                .get(field)
+                .map(|header| header.trim().to_lowercase())
+                .unwrap_or(field.to_string());
+            match headers
+                .iter()
+                .position(|candidate| candidate.to_lowercase() == header)
+            {
+                Some(column) => {
+                    column_by_field.insert(field, column);
+                }
+                None if mapping.contains_key(field) => {
+                    validations.insert(
+                        format!("mapping.{}", field),
+                        format!("Column {} was not found in the CSV header", header),
+                    );
+                }
+                None => {}
+            }
+        }
+        if !column_by_field.contains_key("title") && !mapping.contains_key("title") {
+            validations.insert(
+                "mapping.title".to_string(),
+                "A column holding the book title must be present or mapped".to_string(),
+            );
+        }
+        if !validations.is_empty() {
+            return Err(invalid_data(validations));
+        }
+
+        let mut candidates = Vec::new();
+        for (index, record) in reader.records().enumerate() {
+            // Line 1 holds the header, data starts at line 2
+            let row_number = index as u64 + 2;
+            let record = match record {
+                Ok(record) => record,
+                Err(error) => {
+                    validations.insert(
+                        "content".to_string(),
+                        format!("Line {}: {}", row_number, error),
+                    );
+                    return Err(invalid_data(validations));
+                }
+            };
+            if record.iter().all(|value| value.is_empty()) {
+                continue;
+            }
+            if candidates.len() == MAX_IMPORT_ROWS {
+                validations.insert(
+                    "content".to_string(),
+                    format!("At most {} rows can be imported at once", MAX_IMPORT_ROWS),
+                );
+                return Err(invalid_data(validations));
+            }
+
+            let text = |field: &str| -> Option<String> {
+                column_value(&record, column_by_field.get(field).copied())
+            };
+            let list = |field: &str| -> Option<Vec<String>> {
+                text(field).map(|value| {
+                    value
+                        .split(list_separator.as_str())
+                        .map(|item| item.trim().to_string())
+                        .filter(|item| !item.is_empty())
+                        .collect()
+                })
+            };
+
+            let book_dto = CreateBookDto {
+                title: text("title"),
+                authors: list("authors").map(|names| {
+                    names
+                        .into_iter()
+                        .map(|name| AuthorDto {
+                            name: Some(name),
+                            url: None,
+                        })
+                        .collect()
+                }),
+                publisher: text("publisher"),
+                languages: list("languages").map(|names| {
+                    names
+                        .into_iter()
+                        .map(|name| LanguageDto {
+                            name: Some(name),
+                            code: None,
+                        })
+                        .collect()
+                }),
+                edition: text("edition"),
+                isbn: text("isbn"),
+                year: text("year"),
+                genres: list("genres").map(|names| {
+                    names
+                        .into_iter()
+                        .map(|name| GenreDto { name: Some(name) })
+                        .collect()
+                }),
+                cover: text("cover"),
+                ..Default::default()
+            };
+
+            candidates.push(BookImportCandidate {
+                row_number,
+                book_dto,
+                location_name: text("location"),
+                collection_name: text("collection"),
+            });
+        }
+
+        Ok(BookImport {
+            dry_run: dto.dry_run.unwrap_or(false),
+            default_location_id: dto.default_location_id,
+            candidates,
+        })
+    }
+}
+
+fn column_value(record: &StringRecord, column: Option<usize>) -> Option<String> {
+    column
+        .and_then(|column| record.get(column))
+        .filter(|value| !value.is_empty())
+        .map(|value| unescape_csv_formula(value).to_string())
+}
+
+fn invalid_data(validations: HashMap<String, String>) -> DetailedAPIError {
+    DetailedAPIError {
+        msg: "Request contains invalid data".to_string(),
+        code: 400,
+        field_validations: Some(validations),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn import(content: &str) -> ImportBooksCsvDto {
+        ImportBooksCsvDto {
+            content: Some(content.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn field_validations(error: DetailedAPIError) -> HashMap<String, String> {
+        error.field_validations.unwrap_or_default()
+    }
+
+    #[test]
+    fn columns_named_after_fields_are_read_ignoring_case() {
+        let book_import = BookImport::try_from(import(
+            "Title,Authors,Publisher,Languages,ISBN,Location,Collection\n\
+             Dom Casmurro,Machado de Assis,Garnier,Portuguese,9788535910681,Shelf,Classics\n",
+        ))
+        .unwrap();
+
+        assert_eq!(1, book_import.candidates.len());
+        let candidate = &book_import.candidates[0];
+        assert_eq!(2, candidate.row_number);
+        assert_eq!(Some("Dom Casmurro"), candidate.book_dto.title.as_deref());
+        assert_eq!(
+            Some("Machado de Assis"),
+            candidate.book_dto.authors.as_ref().unwrap()[0]
+                .name
+                .as_deref()
+        );
+        assert_eq!(Some("Garnier"), candidate.book_dto.publisher.as_deref());
+        assert_eq!(Some("9788535910681"), candidate.book_dto.isbn.as_deref());
+        assert_eq!(Some("Shelf"), candidate.location_name.as_deref());
+        assert_eq!(Some("Classics"), candidate.collection_name.as_deref());
+        assert!(!book_import.dry_run);
+    }
+
+    #[test]
+    fn mapped_columns_replace_the_field_names() {
+        let mut dto = import("Name,Writer\nHelena,Machado de Assis\n");
+        dto.mapping = Some(HashMap::from([
+            ("title".to_string(), "name".to_string()),
+            ("authors".to_string(), " Writer ".to_string()),
+        ]));
+
+        let book_import = BookImport::try_from(dto).unwrap();
+
+        let candidate = &book_import.candidates[0];
+        assert_eq!(Some("Helena"), candidate.book_dto.title.as_deref());
+        assert_eq!(1, candidate.book_dto.authors.as_ref().unwrap().len());
+    }
+
+    #[test]
+    fn mappings_to_unknown_fields_are_rejected() {
+        let mut dto = import("Name\nHelena\n");
+        dto.mapping = Some(HashMap::from([("rating".to_string(), "Name".to_string())]));
+
+        let validations = field_validations(BookImport::try_from(dto).unwrap_err());
+
+        assert!(validations.contains_key("mapping.rating"));
+    }
+
+    #[test]
+    fn mapped_columns_must_exist_in_the_header() {
+        let mut dto = import("Name\nHelena\n");
+        dto.mapping = Some(HashMap::from([(
+            "title".to_string(),
+            "Book Title".to_string(),
+        )]));
+
+        let validations = field_validations(BookImport::try_from(dto).unwrap_err());
+
+        assert_eq!(
+            Some(&"Column book title was not found in the CSV header".to_string()),
+            validations.get("mapping.title")
+        );
+    }
+
+    #[test]
+    fn a_title_column_is_required() {
+        let validations =
+            field_validations(BookImport::try_from(import("authors\nMachado\n")).unwrap_err());
+
+        assert!(validations.contains_key("mapping.title"));
+    }
+
+    #[test]
+    fn quoted_fields_keep_delimiters_and_quotes() {
+        let book_import = BookImport::try_from(import(
+            "title,authors,publisher\n\
+             \"Memórias Póstumas, de Brás Cubas\",\"Machado de Assis; \"\"Bruxo\"\"\",Garnier\n",
+        ))
+        .unwrap();
+
+        let book_dto = &book_import.candidates[0].book_dto;
+        assert_eq!(
+            Some("Memórias Póstumas, de Brás Cubas"),
+            book_dto.title.as_deref()
+        );
+        let authors: Vec<&str> = book_dto
+            .authors
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|author| author.name.as_deref().unwrap())
+            .collect();
+        assert_eq!(vec!["Machado de Assis", "\"Bruxo\""], authors);
+    }
+
+    #[test]
+    fn formulas_escaped_by_the_export_are_read_back() {
+        let book_import = BookImport::try_from(import(
+            "title,publisher,edition
+'=HYPERLINK(1),'O'Reilly,'-1
+",
+        ))
+        .unwrap();
+
+        let book_dto = &book_import.candidates[0].book_dto;
+        assert_eq!(Some("=HYPERLINK(1)"), book_dto.title.as_deref());
+        assert_eq!(Some("'O'Reilly"), book_dto.publisher.as_deref());
+        assert_eq!(Some("-1"), book_dto.edition.as_deref());
+    }
+
+    #[test]
+    fn a_leading_byte_order_mark_is_ignored() {
+        let book_import =
+            BookImport::try_from(import("\u{feff}title,year\nHelena,1876\n")).unwrap();
+
+        let book_dto = &book_import.candidates[0].book_dto;
+        assert_eq!(Some("Helena"), book_dto.title.as_deref());
+        assert_eq!(Some("1876"), book_dto.year.as_deref());
+    }
+
+    #[test]
+    fn empty_fields_and_rows_are_left_out() {
+        let book_import = BookImport::try_from(import(
+            "title,authors,edition,genres\n\
+             Helena,,  ,Romance;;\n\
+             ,,,\n\
+             Iaiá Garcia,Machado de Assis,2nd,\n",
+        ))
+        .unwrap();
+
+        assert_eq!(2, book_import.candidates.len());
+        let first = &book_import.candidates[0];
+        assert!(first.book_dto.authors.is_none());
+        assert!(first.book_dto.edition.is_none());
+        assert_eq!(1, first.book_dto.genres.as_ref().unwrap().len());
+        let second = &book_import.candidates[1];
+        assert_eq!(4, second.row_number);
+        assert!(second.book_dto.genres.is_none());
+    }
+
+    #[test]
+    fn custom_delimiters_and_list_separators_are_used() {
+        let mut dto = import("title;authors;dry\nHelena;Machado|Assis;x\n");
+        dto.delimiter = Some(";".to_string());
+        dto.list_separator = Some("|".to_string());
+        dto.dry_run = Some(true);
+
+        let book_import = BookImport::try_from(dto).unwrap();
+
+        assert!(book_import.dry_run);
+        assert_eq!(
+            2,
+            book_import.candidates[0]
+                .book_dto
+                .authors
+                .as_ref()
+                .unwrap()
+                .len()
+        );
+    }
+
+    #[test]
+    fn invalid_options_are_reported() {
+        let dto = ImportBooksCsvDto {
+            content: Some("   ".to_string()),
+            delimiter: Some("::".to_string()),
+            list_separator: Some(String::new()),
+            ..Default::default()
+        };
+
+        let validations = field_validations(BookImport::try_from(dto).unwrap_err());
+
+        assert!(validations.contains_key("content"));
+        assert!(validations.contains_key("delimiter"));
+        assert!(validations.contains_key("list_separator"));
+    }
+}
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+
+use csv::{ReaderBuilder, StringRecord};
+
+use crate::modules::{
+    books::domain::{
+        dtos::{
+            author_dto::AuthorDto, create_book_dto::CreateBookDto,
+            import_goodreads_dto::ImportGoodreadsDto, language_dto::LanguageDto,
+        },
+        entities::book_import::{
+            extract_year, normalize_isbn, BookImport, BookImportCandidate, MAX_IMPORT_ROWS,
+        },
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+/// Goodreads puts every book in exactly one of these shelves, custom shelves
+/// are preferred when choosing the collection.
+const EXCLUSIVE_SHELVES: [&str; 3] = ["read", "currently-reading", "to-read"];
+
+impl TryFrom<ImportGoodreadsDto> for BookImport {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: ImportGoodreadsDto) -> Result<Self, Self::Error> {
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        let content = match dto.content {
+            Some(content) if !content.trim().is_empty() => content,
+            _ => {
+                validations.insert(
+                    "content".to_string(),
+                    "Goodreads export content must be informed".to_string(),
+                );
+                return Err(invalid_data(validations));
+            }
+        };
+
+        let mut reader = ReaderBuilder::new()
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(content.as_bytes());
+        let headers = match reader.headers() {
+            Ok(headers) => headers.clone(),
+            Err(error) => {
+                validations.insert("content".to_string(), error.to_string());
+                return Err(invalid_data(validations));
+            }
+        };
+        if !headers.iter().any(|header| header == "Title")
+            || !headers.iter().any(|header| header == "Author")
+        {
+            validations.insert(
+                "content".to_string(),
+                "Content is not a Goodreads library export".to_string(),
+            );
+            return Err(invalid_data(validations));
+        }
+
+        let mut candidates = Vec::new();
+        for (index, record) in reader.records().enumerate() {
+            let row_number = index as u64 + 2;
+            let record = match record {
+                Ok(record) => record,
+                Err(error) => {
+                    validations.insert(
+                        "content".to_string(),
+                        format!("Line {}: {}", row_number, error),
+                    );
+                    return Err(invalid_data(validations));
+                }
+            };
+            if candidates.len() == MAX_IMPORT_ROWS {
+                validations.insert(
+                    "content".to_string(),
+                    format!("At most {} rows can be imported at once", MAX_IMPORT_ROWS),
+                );
+                return Err(invalid_data(validations));
+            }
+
+            let column = |header: &str| column_value(&headers, &record, header);
+
+            let mut authors: Vec<AuthorDto> = Vec::new();
+            if let Some(author) = column("Author") {
+                authors.push(AuthorDto {
+                    name: Some(author),
+                    url: None,
+                });
+            }
+            if let Some(additional_authors) = column("Additional Authors") {
+                for author in additional_authors.split(',') {
+                    let author = author.trim();
+                    if !author.is_empty() {
+                        authors.push(AuthorDto {
+                            name: Some(author.to_string()),
+                            url: None,
+                        });
+                    }
+                }
+            }
+
+            let isbn = column("ISBN13")
+                .and_then(|isbn| normalize_isbn(&isbn))
+                .or_else(|| column("ISBN").and_then(|isbn| normalize_isbn(&isbn)));
+            let year = column("Year Published")
+                .or_else(|| column("Original Publication Year"))
+                .and_then(|year| extract_year(&year));
+
+            let custom_shelf = column("Bookshelves").and_then(|shelves| {
+                shelves
+                    .split(',')
+                    .map(str::trim)
+                    .find(|shelf| !shelf.is_empty() && !EXCLUSIVE_SHELVES.contains(shelf))
+                    .map(str::to_string)
+            });
+
+            let book_dto = CreateBookDto {
+                title: column("Title"),
+                authors: if authors.is_empty() {
+                    None
+                } else {
+                    Some(authors)
+                },
+                publisher: column("Publisher"),
+                languages: dto.default_language.as_ref().map(|language| {
+                    vec![LanguageDto {
+                        name: language.name.clone(),
+                        code: language.code.clone(),
+                    }]
+                }),
+                isbn,
+                year,
+                ..Default::default()
+            };
+
+            candidates.push(BookImportCandidate {
+                row_number,
+                book_dto,
+                location_name: None,
+                collection_name: custom_shelf.or_else(|| column("Exclusive Shelf")),
+            });
+        }
+
+        Ok(BookImport {
+            dry_run: dto.dry_run.unwrap_or(false),
+            default_location_id: dto.default_location_id,
+            candidates,
+        })
+    }
+}
+
+fn column_value(headers: &StringRecord, record: &StringRecord, header: &str) -> Option<String> {
+    headers
+        .iter()
+        .position(|candidate| candidate == header)
+        .and_then(|column| record.get(column))
+        .map(|value| value.trim_start_matches('=').trim_matches('"').trim())
+        .filter(|value| !value.is_empty())
+        .map(|value| value.to_string())
+}
+
+fn invalid_data(validations: HashMap<String, String>) -> DetailedAPIError {
+    DetailedAPIError {
+        msg: "Request contains invalid data".to_string(),
+        code: 400,
+        field_validations: Some(validations),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = "Book Id,Title,Author,Additional Authors,ISBN,ISBN13,Publisher,Year Published,Original Publication Year,Bookshelves,Exclusive Shelf";
+
+    fn export(rows: &[&str]) -> ImportGoodreadsDto {
+        ImportGoodreadsDto {
+            content: Some(format!("{}\n{}\n", HEADER, rows.join("\n"))),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn custom_shelves_become_the_collection() {
+        let book_import = BookImport::try_from(export(&[
+            "1,The Hobbit,J.R.R. Tolkien,,,,,,,\"to-read, fantasy, classics\",to-read",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            Some("fantasy"),
+            book_import.candidates[0].collection_name.as_deref()
+        );
+    }
+
+    #[test]
+    fn the_exclusive_shelf_is_the_collection_without_custom_shelves() {
+        let book_import = BookImport::try_from(export(&[
+            "1,The Hobbit,J.R.R. Tolkien,,,,,,,read,read",
+            "2,Emma,Jane Austen,,,,,,,,currently-reading",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            Some("read"),
+            book_import.candidates[0].collection_name.as_deref()
+        );
+        assert_eq!(
+            Some("currently-reading"),
+            book_import.candidates[1].collection_name.as_deref()
+        );
+    }
+
+    #[test]
+    fn books_are_filled_from_the_export_columns() {
+        let mut dto = export(&[
+            "1,Good Omens,Terry Pratchett,\"Neil Gaiman, \",=\"0060853980\",=\"9780060853983\",William Morrow,2006,1990,,read",
+        ]);
+        dto.default_language = Some(LanguageDto {
+            name: Some("English".to_string()),
+            code: Some("en".to_string()),
+        });
+
+        let book_import = BookImport::try_from(dto).unwrap();
+
+        let book_dto = &book_import.candidates[0].book_dto;
+        assert_eq!(Some("Good Omens"), book_dto.title.as_deref());
+        let authors: Vec<&str> = book_dto
+            .authors
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|author| author.name.as_deref().unwrap())
+            .collect();
+        assert_eq!(vec!["Terry Pratchett", "Neil Gaiman"], authors);
+        assert_eq!(Some("9780060853983"), book_dto.isbn.as_deref());
+        assert_eq!(Some("William Morrow"), book_dto.publisher.as_deref());
+        assert_eq!(Some("2006"), book_dto.year.as_deref());
+        assert_eq!(
+            Some("en"),
+            book_dto.languages.as_ref().unwrap()[0].code.as_deref()
+        );
+        assert!(book_import.candidates[0].location_name.is_none());
+    }
+
+    #[test]
+    fn empty_isbn_and_year_fall_back_to_the_other_columns() {
+        let book_import = BookImport::try_from(export(&[
+            "1,Emma,Jane Austen,,=\"0141439580\",=\"\",,,1815,,read",
+        ]))
+        .unwrap();
+
+        let book_dto = &book_import.candidates[0].book_dto;
+        assert_eq!(Some("0141439580"), book_dto.isbn.as_deref());
+        assert_eq!(Some("1815"), book_dto.year.as_deref());
+        assert!(book_dto.languages.is_none());
+    }
+
+    #[test]
+    fn other_csv_files_are_rejected() {
+        let dto = ImportGoodreadsDto {
+            content: Some("title,authors\nEmma,Jane Austen\n".to_string()),
+            ..Default::default()
+        };
+
+        let error = BookImport::try_from(dto).unwrap_err();
+
+        assert!(error.field_validations.unwrap().contains_key("content"));
+    }
+}
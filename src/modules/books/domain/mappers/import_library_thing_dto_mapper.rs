@@ -0,0 +1,337 @@
+use std::collections::HashMap;
+
+use csv::{ReaderBuilder, StringRecord};
+use serde_json::Value;
+
+use crate::modules::{
+    books::domain::{
+        dtos::{
+            author_dto::AuthorDto, create_book_dto::CreateBookDto, genre_dto::GenreDto,
+            import_library_thing_dto::ImportLibraryThingDto, language_dto::LanguageDto,
+        },
+        entities::book_import::{
+            extract_year, normalize_isbn, BookImport, BookImportCandidate, MAX_IMPORT_ROWS,
+        },
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<ImportLibraryThingDto> for BookImport {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: ImportLibraryThingDto) -> Result<Self, Self::Error> {
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        let content = match dto.content {
+            Some(content) if !content.trim().is_empty() => content,
+            _ => {
+                validations.insert(
+                    "content".to_string(),
+                    "LibraryThing export content must be informed".to_string(),
+                );
+                return Err(invalid_data(validations));
+            }
+        };
+
+        let candidates = match dto.format.as_deref().unwrap_or("tsv") {
+            "tsv" => candidates_from_tsv(&content, dto.default_language.as_ref()),
+            "json" => candidates_from_json(&content, dto.default_language.as_ref()),
+            _ => {
+                validations.insert(
+                    "format".to_string(),
+                    "Format must be either tsv or json".to_string(),
+                );
+                return Err(invalid_data(validations));
+            }
+        };
+
+        match candidates {
+            Ok(candidates) if candidates.len() > MAX_IMPORT_ROWS => {
+                validations.insert(
+                    "content".to_string(),
+                    format!("At most {} rows can be imported at once", MAX_IMPORT_ROWS),
+                );
+                Err(invalid_data(validations))
+            }
+            Ok(candidates) => Ok(BookImport {
+                dry_run: dto.dry_run.unwrap_or(false),
+                default_location_id: dto.default_location_id,
+                candidates,
+            }),
+            Err(error) => {
+                validations.insert("content".to_string(), error);
+                Err(invalid_data(validations))
+            }
+        }
+    }
+}
+
+fn candidates_from_tsv(
+    content: &str,
+    default_language: Option<&LanguageDto>,
+) -> Result<Vec<BookImportCandidate>, String> {
+    let mut reader = ReaderBuilder::new()
+        .delimiter(b'\t')
+        .quoting(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+    let headers = match reader.headers() {
+        Ok(headers) => headers.clone(),
+        Err(error) => return Err(error.to_string()),
+    };
+    if !headers.iter().any(|header| header == "Title")
+        || !headers.iter().any(|header| header == "Primary Author")
+    {
+        return Err("Content is not a LibraryThing tab-delimited export".to_string());
+    }
+
+    let mut candidates = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let row_number = index as u64 + 2;
+        let record = match record {
+            Ok(record) => record,
+            Err(error) => return Err(format!("Line {}: {}", row_number, error)),
+        };
+        let column = |header: &str| column_value(&headers, &record, header);
+
+        let mut authors = Vec::new();
+        for header in ["Primary Author", "Secondary Author"] {
+            if let Some(author) = column(header) {
+                authors.push(inverted_name_to_natural(&author));
+            }
+        }
+        let isbn = column("ISBN")
+            .and_then(|isbn| normalize_isbn(&isbn))
+            .or_else(|| {
+                column("ISBNs").and_then(|isbns| isbns.split(',').find_map(normalize_isbn))
+            });
+        let languages = column("Languages").map(|languages| {
+            languages
+                .split(',')
+                .map(|language| language.trim().to_string())
+                .filter(|language| !language.is_empty())
+                .collect()
+        });
+
+        candidates.push(candidate(
+            row_number,
+            column("Title"),
+            authors,
+            column("Publication").map(|publication| publisher_from_publication(&publication)),
+            languages,
+            default_language,
+            isbn,
+            column("Date").and_then(|date| extract_year(&date)),
+            Vec::new(),
+            column("Collections").and_then(|collections| first_item(&collections)),
+        ));
+    }
+    Ok(candidates)
+}
+
+fn candidates_from_json(
+    content: &str,
+    default_language: Option<&LanguageDto>,
+) -> Result<Vec<BookImportCandidate>, String> {
+    let books: Vec<Value> = match serde_json::from_str::<Value>(content) {
+        Ok(Value::Object(books_by_id)) => books_by_id.into_iter().map(|(_, book)| book).collect(),
+        Ok(Value::Array(books)) => books,
+        Ok(_) => return Err("Content is not a LibraryThing JSON export".to_string()),
+        Err(error) => return Err(error.to_string()),
+    };
+
+    let mut candidates = Vec::with_capacity(books.len());
+    for (index, book) in books.iter().enumerate() {
+        let text = |field: &str| {
+            book.get(field)
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+
+        let mut authors: Vec<String> = book
+            .get("authors")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|author| match author {
+                Value::String(name) => Some(inverted_name_to_natural(name)),
+                _ => author
+                    .get("fl")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+                    .or_else(|| {
+                        author
+                            .get("lf")
+                            .and_then(Value::as_str)
+                            .map(inverted_name_to_natural)
+                    }),
+            })
+            .filter(|author| !author.trim().is_empty())
+            .collect();
+        if authors.is_empty() {
+            if let Some(primary_author) = text("primaryauthor") {
+                authors.push(inverted_name_to_natural(&primary_author));
+            }
+        }
+
+        let isbn = strings(book.get("isbn"))
+            .iter()
+            .find_map(|isbn| normalize_isbn(isbn))
+            .or_else(|| text("originalisbn").and_then(|isbn| normalize_isbn(&isbn)));
+        let languages = strings(book.get("language"));
+
+        candidates.push(candidate(
+            index as u64 + 1,
+            text("title"),
+            authors,
+            text("publication").map(|publication| publisher_from_publication(&publication)),
+            if languages.is_empty() {
+                None
+            } else {
+                Some(languages)
+            },
+            default_language,
+            isbn,
+            text("date").and_then(|date| extract_year(&date)),
+            strings(book.get("genre")),
+            strings(book.get("collections")).into_iter().next(),
+        ));
+    }
+    Ok(candidates)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn candidate(
+    row_number: u64,
+    title: Option<String>,
+    authors: Vec<String>,
+    publisher: Option<String>,
+    languages: Option<Vec<String>>,
+    default_language: Option<&LanguageDto>,
+    isbn: Option<String>,
+    year: Option<String>,
+    genres: Vec<String>,
+    collection_name: Option<String>,
+) -> BookImportCandidate {
+    let languages = match languages {
+        Some(languages) => Some(
+            languages
+                .into_iter()
+                .map(|name| LanguageDto {
+                    name: Some(name),
+                    code: None,
+                })
+                .collect(),
+        ),
+        None => default_language.map(|language| {
+            vec![LanguageDto {
+                name: language.name.clone(),
+                code: language.code.clone(),
+            }]
+        }),
+    };
+
+    BookImportCandidate {
+        row_number,
+        book_dto: CreateBookDto {
+            title,
+            authors: if authors.is_empty() {
+                None
+            } else {
+                Some(
+                    authors
+                        .into_iter()
+                        .map(|name| AuthorDto {
+                            name: Some(name),
+                            url: None,
+                        })
+                        .collect(),
+                )
+            },
+            publisher: publisher.filter(|publisher| !publisher.is_empty()),
+            languages,
+            isbn,
+            year,
+            genres: if genres.is_empty() {
+                None
+            } else {
+                Some(
+                    genres
+                        .into_iter()
+                        .map(|name| GenreDto { name: Some(name) })
+                        .collect(),
+                )
+            },
+            ..Default::default()
+        },
+        location_name: None,
+        collection_name,
+    }
+}
+
+/// LibraryThing exports lists either as JSON arrays, as objects keyed by
+/// position or as plain strings, all of them are flattened to strings.
+fn strings(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::String(single)) if !single.trim().is_empty() => vec![single.trim().to_string()],
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(Value::as_str)
+            .map(|item| item.trim().to_string())
+            .filter(|item| !item.is_empty())
+            .collect(),
+        Some(Value::Object(items)) => items
+            .values()
+            .filter_map(Value::as_str)
+            .map(|item| item.trim().to_string())
+            .filter(|item| !item.is_empty())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn inverted_name_to_natural(name: &str) -> String {
+    match name.split_once(',') {
+        Some((last_name, first_name)) if !first_name.trim().is_empty() => {
+            format!("{} {}", first_name.trim(), last_name.trim())
+        }
+        _ => name.trim().to_string(),
+    }
+}
+
+fn publisher_from_publication(publication: &str) -> String {
+    publication
+        .split(['(', ','])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+fn first_item(list: &str) -> Option<String> {
+    list.split(',')
+        .map(str::trim)
+        .find(|item| !item.is_empty())
+        .map(str::to_string)
+}
+
+fn column_value(headers: &StringRecord, record: &StringRecord, header: &str) -> Option<String> {
+    headers
+        .iter()
+        .position(|candidate| candidate == header)
+        .and_then(|column| record.get(column))
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| value.to_string())
+}
+
+fn invalid_data(validations: HashMap<String, String>) -> DetailedAPIError {
+    DetailedAPIError {
+        msg: "Request contains invalid data".to_string(),
+        code: 400,
+        field_validations: Some(validations),
+    }
+}
@@ -8,6 +8,8 @@ impl From<Location> for LocationDto {
             id: entity.id,
             name: entity.name,
             user_id: entity.user_id,
+            library_id: entity.library_id,
+            version: entity.version,
         }
     }
 }
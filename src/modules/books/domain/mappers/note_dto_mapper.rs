@@ -0,0 +1,16 @@
+use crate::modules::books::domain::{dtos::note_dto::NoteDto, entities::note::Note};
+
+impl From<Note> for NoteDto {
+    fn from(entity: Note) -> Self {
+        NoteDto {
+            id: entity.id.unwrap_or_default(),
+            book_id: entity.book_id,
+            note_type: entity.note_type.as_str().to_string(),
+            page: entity.page,
+            text: entity.text,
+            created_at: entity.created_at.map(|date| date.to_rfc3339()),
+            updated_at: entity.updated_at.map(|date| date.to_rfc3339()),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::modules::{
+    books::domain::dtos::{
+        collection_dto::CollectionDto, complete_book_dto::CompleteBookDto,
+        location_dto::LocationDto,
+    },
+    shared::errors::precondition_failed_api_error::PreconditionFailedAPIError,
+};
+
+impl From<CompleteBookDto> for PreconditionFailedAPIError {
+    fn from(current: CompleteBookDto) -> Self {
+        let version = current.version;
+        PreconditionFailedAPIError::new(serde_json::to_value(current).unwrap_or_default(), version)
+    }
+}
+
+impl From<CollectionDto> for PreconditionFailedAPIError {
+    fn from(current: CollectionDto) -> Self {
+        let version = current.version;
+        PreconditionFailedAPIError::new(serde_json::to_value(current).unwrap_or_default(), version)
+    }
+}
+
+impl From<LocationDto> for PreconditionFailedAPIError {
+    fn from(current: LocationDto) -> Self {
+        let version = current.version;
+        PreconditionFailedAPIError::new(serde_json::to_value(current).unwrap_or_default(), version)
+    }
+}
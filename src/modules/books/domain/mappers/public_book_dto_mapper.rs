@@ -0,0 +1,92 @@
+use crate::modules::books::domain::dtos::{
+    complete_book_dto::CompleteBookDto, public_book_dto::PublicBookDto,
+    public_collection_dto::PublicCollectionDto, public_location_dto::PublicLocationDto,
+};
+
+impl From<CompleteBookDto> for PublicBookDto {
+    fn from(book: CompleteBookDto) -> Self {
+        PublicBookDto {
+            id: book.id,
+            title: book.title,
+            authors: book.authors,
+            publisher: book.publisher,
+            languages: book.languages,
+            edition: book.edition,
+            isbn: book.isbn,
+            year: book.year,
+            genres: book.genres,
+            cover: book.cover,
+            collection: book.collection.map(|collection| PublicCollectionDto {
+                id: collection.id,
+                name: collection.name,
+            }),
+            location: PublicLocationDto {
+                id: book.location.id,
+                name: book.location.name,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::modules::books::domain::dtos::{
+        collection_dto::CollectionDto, location_dto::LocationDto, tag_dto::TagDto,
+    };
+
+    use super::*;
+
+    #[test]
+    fn owners_and_private_data_are_left_out() {
+        let book = CompleteBookDto {
+            id: 7,
+            title: "Dom Casmurro".to_string(),
+            collection: Some(CollectionDto {
+                id: Some(2),
+                name: "Classics".to_string(),
+                user_id: 40,
+                library_id: 50,
+                version: 3,
+            }),
+            location: LocationDto {
+                id: Some(1),
+                name: "Shelf".to_string(),
+                user_id: 40,
+                library_id: 50,
+                version: 4,
+            },
+            user_id: 40,
+            library_id: 50,
+            version: 5,
+            tags: vec![TagDto::default()],
+            custom_fields: [("Pages".to_string(), json!(256))].into(),
+            note_count: 2,
+            ..Default::default()
+        };
+
+        let public_book = serde_json::to_value(PublicBookDto::from(book)).unwrap();
+
+        assert_eq!(json!("Dom Casmurro"), public_book["title"]);
+        assert_eq!(
+            json!({ "id": 2, "name": "Classics" }),
+            public_book["collection"]
+        );
+        assert_eq!(json!({ "id": 1, "name": "Shelf" }), public_book["location"]);
+        for private_field in [
+            "user_id",
+            "library_id",
+            "version",
+            "tags",
+            "custom_fields",
+            "note_count",
+        ] {
+            assert!(
+                public_book.get(private_field).is_none(),
+                "{} is public",
+                private_field
+            );
+        }
+    }
+}
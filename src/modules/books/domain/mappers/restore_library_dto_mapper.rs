@@ -0,0 +1,463 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::modules::{
+    books::domain::{
+        dtos::{
+            create_book_dto::CreateBookDto, create_custom_field_dto::CreateCustomFieldDto,
+            create_tag_dto::CreateTagDto, restore_library_dto::RestoreLibraryDto,
+        },
+        entities::{
+            book::Book,
+            collection::Collection,
+            custom_field::CustomField,
+            library_backup::{LibraryRestore, LibraryRestoreMode, LIBRARY_BACKUP_SCHEMA_VERSION},
+            location::Location,
+            tag::Tag,
+        },
+        mappers::book_custom_field_values_mapper::book_custom_field_values_from_dto,
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<RestoreLibraryDto> for LibraryRestore {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: RestoreLibraryDto) -> Result<Self, Self::Error> {
+        let mut validations: HashMap<String, String> = HashMap::default();
+        let manifest = dto.manifest;
+
+        if manifest.schema_version > LIBRARY_BACKUP_SCHEMA_VERSION {
+            validations.insert(
+                "schema_version".to_string(),
+                format!(
+                    "Backup schema version {} is newer than the supported version {}",
+                    manifest.schema_version, LIBRARY_BACKUP_SCHEMA_VERSION
+                ),
+            );
+            return Err(DetailedAPIError {
+                msg: "Backup was created by a newer version of the application".to_string(),
+                code: 422,
+                field_validations: Some(validations),
+            });
+        }
+        if manifest.schema_version == 0 {
+            validations.insert(
+                "schema_version".to_string(),
+                "Backup schema version must be informed".to_string(),
+            );
+        }
+
+        let mode = match dto.mode.as_deref() {
+            None | Some("merge") => LibraryRestoreMode::Merge,
+            Some("replace") => LibraryRestoreMode::Replace,
+            Some(_) => {
+                validations.insert(
+                    "mode".to_string(),
+                    "Mode must be either merge or replace".to_string(),
+                );
+                LibraryRestoreMode::Merge
+            }
+        };
+
+        let mut location_ids: HashSet<u64> = HashSet::new();
+        let mut locations = Vec::with_capacity(manifest.locations.len());
+        for (index, location) in manifest.locations.into_iter().enumerate() {
+            if !location_ids.insert(location.id) {
+                validations.insert(
+                    format!("locations[{}].id", index),
+                    "Location id is repeated in the backup".to_string(),
+                );
+            }
+            if location.name.trim().is_empty() {
+                validations.insert(
+                    format!("locations[{}].name", index),
+                    "Location name must not be empty".to_string(),
+                );
+            }
+            locations.push(Location {
+                id: Some(location.id),
+                name: location.name.trim().to_string(),
+                user_id: 0,
+                library_id: 0,
+                version: 0,
+            });
+        }
+
+        let mut collection_ids: HashSet<u64> = HashSet::new();
+        let mut collections = Vec::with_capacity(manifest.collections.len());
+        for (index, collection) in manifest.collections.into_iter().enumerate() {
+            if !collection_ids.insert(collection.id) {
+                validations.insert(
+                    format!("collections[{}].id", index),
+                    "Collection id is repeated in the backup".to_string(),
+                );
+            }
+            if collection.name.trim().is_empty() {
+                validations.insert(
+                    format!("collections[{}].name", index),
+                    "Collection name must not be empty".to_string(),
+                );
+            }
+            collections.push(Collection {
+                id: Some(collection.id),
+                name: collection.name.trim().to_string(),
+                user_id: 0,
+                library_id: 0,
+                version: 0,
+            });
+        }
+
+        let mut tag_ids: HashSet<u64> = HashSet::new();
+        let mut tags = Vec::with_capacity(manifest.tags.len());
+        for (index, backup_tag) in manifest.tags.into_iter().enumerate() {
+            if !tag_ids.insert(backup_tag.id) {
+                validations.insert(
+                    format!("tags[{}].id", index),
+                    "Tag id is repeated in the backup".to_string(),
+                );
+            }
+            let tag_dto = CreateTagDto {
+                name: Some(backup_tag.name),
+                user_id: Some(0),
+            };
+            match Tag::try_from(tag_dto) {
+                Ok(mut tag) => {
+                    tag.id = Some(backup_tag.id);
+                    tags.push(tag);
+                }
+                Err(error) => {
+                    for (field, validation) in error.field_validations.unwrap_or_default() {
+                        validations.insert(format!("tags[{}].{}", index, field), validation);
+                    }
+                }
+            }
+        }
+
+        let mut custom_field_ids: HashSet<u64> = HashSet::new();
+        let mut custom_fields = Vec::with_capacity(manifest.custom_fields.len());
+        for (index, backup_custom_field) in manifest.custom_fields.into_iter().enumerate() {
+            if !custom_field_ids.insert(backup_custom_field.id) {
+                validations.insert(
+                    format!("custom_fields[{}].id", index),
+                    "Custom field id is repeated in the backup".to_string(),
+                );
+            }
+            let custom_field_dto = CreateCustomFieldDto {
+                name: Some(backup_custom_field.name),
+                field_type: Some(backup_custom_field.field_type),
+                options: Some(backup_custom_field.options),
+                user_id: Some(0),
+            };
+            match CustomField::try_from(custom_field_dto) {
+                Ok(mut custom_field) => {
+                    custom_field.id = Some(backup_custom_field.id);
+                    custom_fields.push(custom_field);
+                }
+                Err(error) => {
+                    for (field, validation) in error.field_validations.unwrap_or_default() {
+                        validations
+                            .insert(format!("custom_fields[{}].{}", index, field), validation);
+                    }
+                }
+            }
+        }
+
+        let mut books = Vec::with_capacity(manifest.books.len());
+        for (index, backup_book) in manifest.books.into_iter().enumerate() {
+            if !location_ids.contains(&backup_book.location_id) {
+                validations.insert(
+                    format!("books[{}].location_id", index),
+                    "Book references a location missing from the backup".to_string(),
+                );
+            }
+            if let Some(collection_id) = backup_book.collection_id {
+                if !collection_ids.contains(&collection_id) {
+                    validations.insert(
+                        format!("books[{}].collection_id", index),
+                        "Book references a collection missing from the backup".to_string(),
+                    );
+                }
+            }
+
+            if backup_book
+                .tag_ids
+                .iter()
+                .any(|tag_id| !tag_ids.contains(tag_id))
+            {
+                validations.insert(
+                    format!("books[{}].tag_ids", index),
+                    "Book references a tag missing from the backup".to_string(),
+                );
+            }
+
+            if let Err(error) =
+                book_custom_field_values_from_dto(&backup_book.custom_fields, &custom_fields)
+            {
+                for (field, validation) in error.field_validations.unwrap_or_default() {
+                    validations.insert(format!("books[{}].{}", index, field), validation);
+                }
+            }
+
+            let mut book_dto = CreateBookDto::from(backup_book);
+            // The owner is only known when the backup is restored
+            book_dto.user_id = Some(0);
+            match Book::try_from(book_dto) {
+                Ok(book) => books.push(book),
+                Err(error) => {
+                    for (field, validation) in error.field_validations.unwrap_or_default() {
+                        validations.insert(format!("books[{}].{}", index, field), validation);
+                    }
+                }
+            }
+        }
+
+        if !validations.is_empty() {
+            return Err(DetailedAPIError {
+                msg: "Backup contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            });
+        }
+
+        Ok(LibraryRestore {
+            mode,
+            schema_version: manifest.schema_version,
+            locations,
+            collections,
+            tags,
+            custom_fields,
+            books,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::modules::books::domain::dtos::{
+        author_dto::AuthorDto, backup_book_dto::BackupBookDto,
+        backup_collection_dto::BackupCollectionDto, backup_custom_field_dto::BackupCustomFieldDto,
+        backup_location_dto::BackupLocationDto, backup_tag_dto::BackupTagDto,
+        language_dto::LanguageDto, library_backup_manifest_dto::LibraryBackupManifestDto,
+    };
+    use crate::modules::books::domain::entities::tag::MAX_TAG_NAME_LENGTH;
+
+    use super::*;
+
+    fn backup_book(id: u64, title: &str, location_id: u64) -> BackupBookDto {
+        BackupBookDto {
+            id,
+            title: title.to_string(),
+            authors: vec![AuthorDto {
+                name: Some("Machado de Assis".to_string()),
+                url: None,
+            }],
+            publisher: "Garnier".to_string(),
+            languages: vec![LanguageDto {
+                name: Some("Portuguese".to_string()),
+                code: Some("pt".to_string()),
+            }],
+            location_id,
+            ..Default::default()
+        }
+    }
+
+    fn manifest() -> LibraryBackupManifestDto {
+        LibraryBackupManifestDto {
+            schema_version: LIBRARY_BACKUP_SCHEMA_VERSION,
+            created_at: "2026-10-19T12:00:00Z".to_string(),
+            locations: vec![BackupLocationDto {
+                id: 10,
+                name: " Shelf ".to_string(),
+            }],
+            collections: vec![BackupCollectionDto {
+                id: 20,
+                name: "Classics".to_string(),
+            }],
+            books: vec![BackupBookDto {
+                collection_id: Some(20),
+                ..backup_book(30, "Dom Casmurro", 10)
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn restore(manifest: LibraryBackupManifestDto, mode: Option<&str>) -> RestoreLibraryDto {
+        RestoreLibraryDto {
+            manifest,
+            mode: mode.map(str::to_string),
+        }
+    }
+
+    fn field_validations(error: DetailedAPIError) -> HashMap<String, String> {
+        error.field_validations.unwrap_or_default()
+    }
+
+    #[test]
+    fn manifests_keep_their_ids_for_the_restore() {
+        let library_restore = LibraryRestore::try_from(restore(manifest(), None)).unwrap();
+
+        assert_eq!(LibraryRestoreMode::Merge, library_restore.mode);
+        assert_eq!(Some(10), library_restore.locations[0].id);
+        assert_eq!("Shelf", library_restore.locations[0].name);
+        assert_eq!(Some(20), library_restore.collections[0].id);
+        assert_eq!("Dom Casmurro", library_restore.books[0].title);
+        assert_eq!(Some(20), library_restore.books[0].collection_id);
+        assert_eq!(10, library_restore.books[0].location_id);
+    }
+
+    #[test]
+    fn newer_schema_versions_are_unprocessable() {
+        let error = LibraryRestore::try_from(restore(
+            LibraryBackupManifestDto {
+                schema_version: LIBRARY_BACKUP_SCHEMA_VERSION + 1,
+                ..manifest()
+            },
+            None,
+        ))
+        .unwrap_err();
+
+        assert_eq!(422, error.code);
+        assert!(field_validations(error).contains_key("schema_version"));
+    }
+
+    #[test]
+    fn invalid_modes_and_missing_versions_are_reported() {
+        let error = LibraryRestore::try_from(restore(
+            LibraryBackupManifestDto {
+                schema_version: 0,
+                ..manifest()
+            },
+            Some("overwrite"),
+        ))
+        .unwrap_err();
+
+        assert_eq!(400, error.code);
+        let validations = field_validations(error);
+        assert!(validations.contains_key("schema_version"));
+        assert!(validations.contains_key("mode"));
+    }
+
+    #[test]
+    fn references_must_point_into_the_backup() {
+        let mut manifest = manifest();
+        manifest.locations.push(BackupLocationDto {
+            id: 10,
+            name: "Box".to_string(),
+        });
+        manifest.books.push(BackupBookDto {
+            collection_id: Some(21),
+            ..backup_book(31, "Helena", 11)
+        });
+        manifest.books.push(BackupBookDto {
+            authors: Vec::new(),
+            ..backup_book(32, "Iaiá Garcia", 10)
+        });
+
+        let validations =
+            field_validations(LibraryRestore::try_from(restore(manifest, None)).unwrap_err());
+
+        assert!(validations.contains_key("locations[1].id"));
+        assert!(validations.contains_key("books[1].location_id"));
+        assert!(validations.contains_key("books[1].collection_id"));
+        assert!(validations.contains_key("books[2].authors"));
+        assert!(!validations.contains_key("books[0].location_id"));
+    }
+
+    #[test]
+    fn tags_are_validated_and_referenced_by_their_backup_ids() {
+        let mut manifest = manifest();
+        manifest.tags = vec![
+            BackupTagDto {
+                id: 40,
+                name: " favourite ".to_string(),
+            },
+            BackupTagDto {
+                id: 41,
+                name: "x".repeat(MAX_TAG_NAME_LENGTH + 1),
+            },
+            BackupTagDto {
+                id: 40,
+                name: "to read".to_string(),
+            },
+        ];
+        manifest.books[0].tag_ids = vec![40];
+        manifest.books.push(BackupBookDto {
+            tag_ids: vec![40, 42],
+            ..backup_book(31, "Helena", 10)
+        });
+
+        let validations =
+            field_validations(LibraryRestore::try_from(restore(manifest, None)).unwrap_err());
+
+        assert!(validations.contains_key("tags[1].name"));
+        assert!(validations.contains_key("tags[2].id"));
+        assert!(validations.contains_key("books[1].tag_ids"));
+        assert!(!validations.contains_key("books[0].tag_ids"));
+    }
+
+    #[test]
+    fn custom_fields_are_validated_and_values_checked_against_their_type() {
+        let mut manifest = manifest();
+        manifest.custom_fields = vec![
+            BackupCustomFieldDto {
+                id: 50,
+                name: "Pages".to_string(),
+                field_type: "number".to_string(),
+                options: Vec::new(),
+            },
+            BackupCustomFieldDto {
+                id: 51,
+                name: "Binding".to_string(),
+                field_type: "enum".to_string(),
+                options: Vec::new(),
+            },
+            BackupCustomFieldDto {
+                id: 50,
+                name: "Signed".to_string(),
+                field_type: "boolean".to_string(),
+                options: Vec::new(),
+            },
+        ];
+        manifest.books[0].custom_fields = [("Pages".to_string(), serde_json::json!(256))].into();
+        manifest.books.push(BackupBookDto {
+            custom_fields: [
+                ("Pages".to_string(), serde_json::json!("many")),
+                ("Year".to_string(), serde_json::json!(1899)),
+            ]
+            .into(),
+            ..backup_book(31, "Helena", 10)
+        });
+
+        let validations =
+            field_validations(LibraryRestore::try_from(restore(manifest, None)).unwrap_err());
+
+        assert!(validations.contains_key("custom_fields[1].options"));
+        assert!(validations.contains_key("custom_fields[2].id"));
+        assert!(validations.contains_key("books[1].custom_fields.Pages"));
+        assert!(validations.contains_key("books[1].custom_fields.Year"));
+        assert!(!validations.contains_key("books[0].custom_fields.Pages"));
+    }
+
+    #[test]
+    fn backups_without_tags_are_still_restored() {
+        let manifest: LibraryBackupManifestDto = serde_json::from_value(serde_json::json!({
+            "schema_version": 1,
+            "created_at": "2026-10-19T12:00:00Z",
+            "locations": [{ "id": 10, "name": "Shelf" }],
+            "books": [{
+                "id": 30,
+                "title": "Dom Casmurro",
+                "authors": [{ "name": "Machado de Assis" }],
+                "publisher": "Garnier",
+                "languages": [{ "name": "Portuguese" }],
+                "location_id": 10
+            }]
+        }))
+        .unwrap();
+
+        let library_restore = LibraryRestore::try_from(restore(manifest, None)).unwrap();
+
+        assert!(library_restore.tags.is_empty());
+        assert_eq!(1, library_restore.books.len());
+    }
+}
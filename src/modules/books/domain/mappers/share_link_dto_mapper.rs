@@ -0,0 +1,24 @@
+use crate::modules::books::domain::{
+    dtos::share_link_dto::ShareLinkDto,
+    entities::share_link::{ShareLink, ShareLinkScope},
+};
+
+impl From<ShareLink> for ShareLinkDto {
+    fn from(share_link: ShareLink) -> Self {
+        let (location_id, collection_id) = match share_link.scope {
+            ShareLinkScope::Library => (None, None),
+            ShareLinkScope::Location(location_id) => (Some(location_id), None),
+            ShareLinkScope::Collection(collection_id) => (None, Some(collection_id)),
+        };
+        ShareLinkDto {
+            id: share_link.id.unwrap_or_default(),
+            slug: share_link.slug,
+            scope: share_link.scope.as_str().to_string(),
+            location_id,
+            collection_id,
+            expires_at: share_link.expires_at.map(|date| date.to_rfc3339()),
+            revoked_at: share_link.revoked_at.map(|date| date.to_rfc3339()),
+            created_at: share_link.created_at.map(|date| date.to_rfc3339()),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::modules::books::domain::{dtos::tag_dto::TagDto, entities::tag::Tag};
+
+impl From<Tag> for TagDto {
+    fn from(entity: Tag) -> Self {
+        TagDto {
+            id: entity.id.unwrap_or_default(),
+            name: entity.name,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::modules::books::domain::{
+    dtos::trash_item_dto::TrashItemDto, entities::trash::TrashItem,
+};
+
+impl From<TrashItem> for TrashItemDto {
+    fn from(entity: TrashItem) -> Self {
+        TrashItemDto {
+            id: entity.id,
+            item_type: entity.item_type.as_str().to_string(),
+            name: entity.name,
+            deleted_at: entity.deleted_at.map(|date| date.to_rfc3339()),
+        }
+    }
+}
@@ -1,2 +1,6 @@
+pub mod archives;
+pub mod barcodes;
 pub mod controllers;
+pub mod exporters;
+pub mod jobs;
 pub mod repositories;
@@ -0,0 +1 @@
+pub mod library_backup_archive;
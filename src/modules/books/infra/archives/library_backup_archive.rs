@@ -0,0 +1,43 @@
+use std::io::{self, Cursor, Read};
+
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::modules::books::domain::dtos::library_backup_manifest_dto::LibraryBackupManifestDto;
+
+pub const LIBRARY_BACKUP_MANIFEST_FILE: &str = "manifest.json";
+
+const MAX_MANIFEST_SIZE: u64 = 64 * 1024 * 1024;
+
+pub fn write_library_backup_archive(manifest: &LibraryBackupManifestDto) -> io::Result<Vec<u8>> {
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    writer.start_file(
+        LIBRARY_BACKUP_MANIFEST_FILE,
+        SimpleFileOptions::default().compression_method(CompressionMethod::Deflated),
+    )?;
+    serde_json::to_writer_pretty(&mut writer, manifest)?;
+    Ok(writer.finish()?.into_inner())
+}
+
+pub fn read_library_backup_archive(archive: &[u8]) -> Result<LibraryBackupManifestDto, String> {
+    let mut archive = match ZipArchive::new(Cursor::new(archive)) {
+        Ok(archive) => archive,
+        Err(error) => return Err(format!("Backup archive could not be read: {}", error)),
+    };
+    let manifest_file = match archive.by_name(LIBRARY_BACKUP_MANIFEST_FILE) {
+        Ok(manifest_file) => manifest_file,
+        Err(_) => {
+            return Err(format!(
+                "Backup archive doesn't contain a {} file",
+                LIBRARY_BACKUP_MANIFEST_FILE
+            ))
+        }
+    };
+    if manifest_file.size() > MAX_MANIFEST_SIZE {
+        return Err("Backup manifest is too large".to_string());
+    }
+
+    match serde_json::from_reader(manifest_file.take(MAX_MANIFEST_SIZE)) {
+        Ok(manifest) => Ok(manifest),
+        Err(error) => Err(format!("Backup manifest is invalid: {}", error)),
+    }
+}
@@ -0,0 +1 @@
+pub mod ean13_decoder;
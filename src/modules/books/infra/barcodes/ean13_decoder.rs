@@ -0,0 +1,256 @@
+use image::{imageops, GrayImage};
+
+/// Widths of the space, bar, space, bar runs of each left hand "L" digit,
+/// right hand digits use the same widths starting with a bar.
+const L_PATTERNS: [[u32; 4]; 10] = [
+    [3, 2, 1, 1],
+    [2, 2, 2, 1],
+    [2, 1, 2, 2],
+    [1, 4, 1, 1],
+    [1, 1, 3, 2],
+    [1, 2, 3, 1],
+    [1, 1, 1, 4],
+    [1, 3, 1, 2],
+    [1, 2, 1, 3],
+    [3, 1, 1, 2],
+];
+
+/// Parity of the six left hand digits, `true` meaning an even "G" digit,
+/// which encodes the first digit of the code.
+const FIRST_DIGIT_PARITIES: [[bool; 6]; 10] = [
+    [false, false, false, false, false, false],
+    [false, false, true, false, true, true],
+    [false, false, true, true, false, true],
+    [false, false, true, true, true, false],
+    [false, true, false, false, true, true],
+    [false, true, true, false, false, true],
+    [false, true, true, true, false, false],
+    [false, true, false, true, false, true],
+    [false, true, false, true, true, false],
+    [false, true, true, false, true, false],
+];
+
+const EAN13_RUNS: usize = 3 + 6 * 4 + 5 + 6 * 4 + 3;
+const EAN13_MODULES: u32 = 95;
+
+const MAX_DIGIT_VARIANCE: f32 = 1.6;
+
+const SCANLINES: u32 = 48;
+
+pub fn decode_ean13_from_image(image_bytes: &[u8]) -> Result<Option<String>, String> {
+    let image = match image::load_from_memory(image_bytes) {
+        Ok(image) => image.to_luma8(),
+        Err(error) => return Err(format!("Image could not be read: {}", error)),
+    };
+
+    for oriented_image in [image.clone(), imageops::rotate90(&image)] {
+        if let Some(code) = scan_image(&oriented_image) {
+            return Ok(Some(code));
+        }
+    }
+    Ok(None)
+}
+
+fn scan_image(image: &GrayImage) -> Option<String> {
+    let (width, height) = image.dimensions();
+    if width < EAN13_MODULES || height == 0 {
+        return None;
+    }
+
+    // Lines closer to the middle come first, that is where the barcode
+    // usually is when the photo is framed by hand
+    let middle = height / 2;
+    let step = (height / SCANLINES).max(1);
+    let mut offsets = vec![0];
+    for index in 1..=SCANLINES / 2 {
+        offsets.push(index * step);
+    }
+
+    for offset in offsets {
+        for y in [middle.checked_sub(offset), Some(middle + offset)] {
+            let Some(y) = y.filter(|y| *y < height) else {
+                continue;
+            };
+            let row: Vec<u8> = (0..width).map(|x| image.get_pixel(x, y).0[0]).collect();
+            let mut runs = runs_from_row(&binarize(&row));
+            if let Some(code) = decode_runs(&runs) {
+                return Some(code);
+            }
+            runs.reverse();
+            if let Some(code) = decode_runs(&runs) {
+                return Some(code);
+            }
+        }
+    }
+    None
+}
+
+fn binarize(row: &[u8]) -> Vec<bool> {
+    let window = (row.len() / 8).max(15);
+    let mut prefix_sums = Vec::with_capacity(row.len() + 1);
+    prefix_sums.push(0u64);
+    for pixel in row.iter() {
+        prefix_sums.push(prefix_sums.last().unwrap() + *pixel as u64);
+    }
+
+    (0..row.len())
+        .map(|index| {
+            let start = index.saturating_sub(window / 2);
+            let end = (index + window / 2 + 1).min(row.len());
+            let sum = prefix_sums[end] - prefix_sums[start];
+            (row[index] as u64) * ((end - start) as u64) < sum
+        })
+        .collect()
+}
+
+fn runs_from_row(pixels: &[bool]) -> Vec<(bool, u32)> {
+    let mut runs: Vec<(bool, u32)> = Vec::new();
+    for pixel in pixels.iter() {
+        match runs.last_mut() {
+            Some((is_black, width)) if is_black == pixel => *width += 1,
+            _ => runs.push((*pixel, 1)),
+        }
+    }
+    runs
+}
+
+fn decode_runs(runs: &[(bool, u32)]) -> Option<String> {
+    if runs.len() < EAN13_RUNS {
+        return None;
+    }
+    (0..=runs.len() - EAN13_RUNS)
+        .filter(|start| runs[*start].0)
+        .find_map(|start| decode_at(runs, start))
+}
+
+fn decode_at(runs: &[(bool, u32)], start: usize) -> Option<String> {
+    let widths: Vec<u32> = runs[start..start + EAN13_RUNS]
+        .iter()
+        .map(|(_, width)| *width)
+        .collect();
+    let module = widths.iter().sum::<u32>() as f32 / EAN13_MODULES as f32;
+
+    let is_guard = |guard: &[u32]| {
+        guard
+            .iter()
+            .all(|width| (*width as f32 - module).abs() <= module * 0.7 + 0.5)
+    };
+    if !is_guard(&widths[0..3]) || !is_guard(&widths[27..32]) || !is_guard(&widths[56..59]) {
+        return None;
+    }
+    // Some quiet zone must precede the start guard
+    if start > 0 && (runs[start - 1].1 as f32) < module * 3.0 {
+        return None;
+    }
+
+    let mut digits: Vec<u32> = Vec::with_capacity(13);
+    let mut parities = [false; 6];
+    for (index, parity) in parities.iter_mut().enumerate() {
+        let digit_runs = &widths[3 + index * 4..3 + index * 4 + 4];
+        let (digit, is_even) = decode_digit(digit_runs, true)?;
+        digits.push(digit);
+        *parity = is_even;
+    }
+    for index in 0..6 {
+        let digit_runs = &widths[32 + index * 4..32 + index * 4 + 4];
+        let (digit, _) = decode_digit(digit_runs, false)?;
+        digits.push(digit);
+    }
+
+    let first_digit = FIRST_DIGIT_PARITIES
+        .iter()
+        .position(|candidate| *candidate == parities)? as u32;
+    digits.insert(0, first_digit);
+
+    let weighted_sum: u32 = digits[..12]
+        .iter()
+        .enumerate()
+        .map(|(index, digit)| if index % 2 == 0 { *digit } else { digit * 3 })
+        .sum();
+    if (10 - weighted_sum % 10) % 10 != digits[12] {
+        return None;
+    }
+
+    Some(
+        digits
+            .into_iter()
+            .filter_map(|digit| char::from_digit(digit, 10))
+            .collect(),
+    )
+}
+
+fn decode_digit(runs: &[u32], accept_even_parity: bool) -> Option<(u32, bool)> {
+    let total = runs.iter().sum::<u32>() as f32;
+    let normalized: Vec<f32> = runs
+        .iter()
+        .map(|width| *width as f32 * 7.0 / total)
+        .collect();
+    let variance = |pattern: &[u32]| -> f32 {
+        normalized
+            .iter()
+            .zip(pattern.iter())
+            .map(|(measured, expected)| (measured - *expected as f32).abs())
+            .sum()
+    };
+
+    let mut best: Option<(u32, bool, f32)> = None;
+    for (digit, pattern) in L_PATTERNS.iter().enumerate() {
+        let mut candidates = vec![(false, variance(pattern))];
+        if accept_even_parity {
+            let reversed: Vec<u32> = pattern.iter().rev().copied().collect();
+            candidates.push((true, variance(&reversed)));
+        }
+        for (is_even, candidate_variance) in candidates {
+            if best.is_none_or(|(_, _, best_variance)| candidate_variance < best_variance) {
+                best = Some((digit as u32, is_even, candidate_variance));
+            }
+        }
+    }
+
+    match best {
+        Some((digit, is_even, variance)) if variance <= MAX_DIGIT_VARIANCE => {
+            Some((digit, is_even))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn barcodes_are_decoded_from_photos() {
+        let image = include_bytes!("fixtures/ean13_9780306406157.png");
+
+        assert_eq!(
+            Ok(Some("9780306406157".to_string())),
+            decode_ean13_from_image(image)
+        );
+    }
+
+    #[test]
+    fn rotated_barcodes_are_decoded() {
+        for image in [
+            include_bytes!("fixtures/ean13_9780306406157_rotated.png").as_slice(),
+            include_bytes!("fixtures/ean13_9780306406157_upside_down.png").as_slice(),
+        ] {
+            assert_eq!(
+                Ok(Some("9780306406157".to_string())),
+                decode_ean13_from_image(image)
+            );
+        }
+    }
+
+    #[test]
+    fn barcodes_with_a_wrong_check_digit_are_not_decoded() {
+        let image = include_bytes!("fixtures/ean13_bad_checksum.png");
+
+        assert_eq!(Ok(None), decode_ean13_from_image(image));
+    }
+
+    #[test]
+    fn files_that_are_not_images_are_rejected() {
+        assert!(decode_ean13_from_image(b"not an image").is_err());
+    }
+}
@@ -1,3 +1,13 @@
+pub mod backup_controller_v1;
 pub mod book_controller_v1;
+pub mod book_history_controller_v1;
 pub mod collection_controller_v1;
+pub mod custom_field_controller_v1;
+pub mod export_controller_v1;
+pub mod import_controller_v1;
 pub mod location_controller_v1;
+pub mod note_controller_v1;
+pub mod public_controller_v1;
+pub mod share_link_controller_v1;
+pub mod tag_controller_v1;
+pub mod trash_controller_v1;
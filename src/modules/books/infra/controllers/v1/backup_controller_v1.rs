@@ -0,0 +1,204 @@
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::{
+                library_restore_report_dto::LibraryRestoreReportDto,
+                restore_library_dto::RestoreLibraryDto,
+                restore_library_params::RestoreLibraryParams,
+            },
+            entities::library_backup::LibraryRestore,
+        },
+        infra::{
+            archives::library_backup_archive::read_library_backup_archive,
+            repositories::{
+                audit_repository_mysql::AuditRepositoryMySQL,
+                book_repository_mysql::BookRepositoryMySQL,
+                collection_repository_mysql::CollectionRepositoryMySQL,
+                custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+                location_repository_mysql::LocationRepositoryMySQL,
+                tag_repository_mysql::TagRepositoryMySQL,
+            },
+        },
+        usecases::v1::{
+            backup_library_usecase::BackupLibraryUseCaseV1,
+            restore_library_usecase::RestoreLibraryUseCaseV1,
+        },
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::dtos::library_scope_params::LibraryScopeParams,
+        infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    },
+    shared::{
+        domain::dtos::binary_dto::BinaryDto,
+        errors::{simple_api_error::SimpleAPIError, APIError},
+    },
+    users::domain::dtos::authed_user::AuthedUser,
+};
+use actix_web::{
+    get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    post, web, HttpResponse, Scope,
+};
+use utoipa::OpenApi;
+
+const MAX_BACKUP_ARCHIVE_SIZE: usize = 50 * 1024 * 1024;
+
+pub struct BackupControllerV1 {
+    backup_library_usecase: BackupLibraryUseCaseV1<
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+    >,
+    restore_library_usecase:
+        RestoreLibraryUseCaseV1<BookRepositoryMySQL, LibraryRepositoryMySQL, AuditRepositoryMySQL>,
+}
+
+impl BackupControllerV1 {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        BackupControllerV1 {
+            backup_library_usecase: BackupLibraryUseCaseV1::new(
+                book_repository.clone(),
+                collection_repository.clone(),
+                location_repository.clone(),
+                library_repository.clone(),
+                tag_repository.clone(),
+                custom_field_repository.clone(),
+            ),
+            restore_library_usecase: RestoreLibraryUseCaseV1::new(
+                book_repository.clone(),
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "backup",
+    summary = "Download a backup archive of the library",
+    params(
+        LibraryScopeParams,
+    ),
+    responses(
+        (status = 200, description = "Backup archive", body = BinaryDto, content_type = "application/zip"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn backup_library(
+    backup_controller: web::Data<BackupControllerV1>,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match backup_controller
+        .backup_library_usecase
+        .backup_library(authed_user.id.unwrap(), library_scope_params.library_id)
+        .await
+    {
+        Ok(archive) => HttpResponse::Ok()
+            .content_type("application/zip")
+            .insert_header(ContentDisposition {
+                disposition: DispositionType::Attachment,
+                parameters: vec![DispositionParam::Filename(format!(
+                    "library-backup-{}.zip",
+                    chrono::Utc::now().format("%Y%m%d")
+                ))],
+            })
+            .body(archive),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "backup",
+    summary = "Restore a backup archive into the library",
+    params(
+        RestoreLibraryParams,
+        LibraryScopeParams,
+    ),
+    request_body(content = BinaryDto, content_type = "application/zip"),
+    responses(
+        (status = 200, description = "Restore report", body = LibraryRestoreReportDto),
+        (status = 400, description = "Invalid parameters", body = SimpleAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[post("/restore")]
+async fn restore_library(
+    backup_controller: web::Data<BackupControllerV1>,
+    params: web::Query<RestoreLibraryParams>,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    archive: web::Bytes,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let manifest = match read_library_backup_archive(&archive) {
+        Ok(manifest) => manifest,
+        Err(msg) => {
+            return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(msg, 400)));
+        }
+    };
+    let library_restore = match LibraryRestore::try_from(RestoreLibraryDto {
+        manifest,
+        mode: params.into_inner().mode,
+    }) {
+        Ok(converted_restore) => converted_restore,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match backup_controller
+        .restore_library_usecase
+        .restore_library(
+            authed_user.id.unwrap(),
+            library_scope_params.library_id,
+            library_restore,
+        )
+        .await
+    {
+        Ok(report) => HttpResponse::Ok().json(web::Json(report)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(backup_library, restore_library))]
+pub struct BackupApiV1;
+
+pub fn get_backup_scope() -> Scope {
+    web::scope("/v1/backup")
+        .app_data(web::PayloadConfig::default().limit(MAX_BACKUP_ARCHIVE_SIZE))
+        .service(backup_library)
+        .service(restore_library)
+}
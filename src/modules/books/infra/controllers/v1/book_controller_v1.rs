@@ -1,56 +1,189 @@
 use crate::modules::{
     books::{
-        domain::{dtos::create_book_dto::CreateBookDto, entities::book::Book},
+        domain::{
+            dtos::{
+                barcode_lookup_dto::BarcodeLookupDto,
+                bulk_book_operation_dto::BulkBookOperationDto,
+                bulk_book_operation_result_dto::BulkBookOperationResultDto,
+                complete_book_dto::CompleteBookDto, create_book_dto::CreateBookDto,
+                get_all_books_params::GetAllBooksParams,
+            },
+            entities::{book::Book, bulk_book_operation::BulkBookOperation},
+        },
         infra::repositories::{
+            audit_repository_mysql::AuditRepositoryMySQL,
             book_repository_mysql::BookRepositoryMySQL,
             collection_repository_mysql::CollectionRepositoryMySQL,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
             location_repository_mysql::LocationRepositoryMySQL,
+            note_repository_mysql::NoteRepositoryMySQL, tag_repository_mysql::TagRepositoryMySQL,
         },
         usecases::v1::{
+            bulk_book_operation_usecase::BulkBookOperationUseCaseV1,
             create_update_book_usecase::CreateUpdateBookUseCaseV1,
             delete_book_usecase::DeleteBookUseCaseV1,
             find_all_books_from_user_usecase::FindAllBooksFromUserUseCaseV1,
-            find_book_by_id_usecase::FindBookByIDUseCaseV1,
+            find_book_by_barcode_usecase::FindBookByBarcodeUseCaseV1,
+            find_book_by_id_usecase::FindBookByIDUseCaseV1, patch_book_usecase::PatchBookUseCaseV1,
+        },
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::dtos::library_scope_params::LibraryScopeParams,
+        infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    },
+    shared::{
+        domain::dtos::{
+            binary_dto::BinaryDto,
+            if_match::{etag_from_version, IfMatch},
+            paginated_dto::PaginatedDto,
+            version_dto::VersionDto,
+        },
+        errors::{
+            detailed_api_error::DetailedAPIError,
+            precondition_failed_api_error::PreconditionFailedAPIError,
+            simple_api_error::SimpleAPIError, APIError,
         },
     },
-    shared::errors::{simple_api_error::SimpleAPIError, APIError},
     users::domain::dtos::authed_user::AuthedUser,
 };
-use actix_web::{delete, get, post, put, web, HttpResponse, Scope};
-use serde::Deserialize;
+use actix_web::{delete, get, http::header, patch, post, put, web, HttpResponse, Scope};
+use serde_json::Value;
+use utoipa::OpenApi;
+
+const MAX_BARCODE_IMAGE_SIZE: usize = 10 * 1024 * 1024;
 
 pub struct BookControllerV1 {
     create_update_book_usecase: CreateUpdateBookUseCaseV1<
         BookRepositoryMySQL,
         CollectionRepositoryMySQL,
         LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >,
+    get_all_books_from_user_usecase: FindAllBooksFromUserUseCaseV1<
+        BookRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        NoteRepositoryMySQL,
+    >,
+    find_book_by_id_usecase: FindBookByIDUseCaseV1<
+        BookRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        NoteRepositoryMySQL,
+    >,
+    delete_book_by_id_usecase:
+        DeleteBookUseCaseV1<BookRepositoryMySQL, LibraryRepositoryMySQL, AuditRepositoryMySQL>,
+    bulk_book_operation_usecase: BulkBookOperationUseCaseV1<
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >,
+    find_book_by_barcode_usecase:
+        FindBookByBarcodeUseCaseV1<BookRepositoryMySQL, LibraryRepositoryMySQL>,
+    patch_book_usecase: PatchBookUseCaseV1<
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        AuditRepositoryMySQL,
     >,
-    get_all_books_from_user_usecase: FindAllBooksFromUserUseCaseV1<BookRepositoryMySQL>,
-    find_book_by_id_usecase: FindBookByIDUseCaseV1<BookRepositoryMySQL>,
-    delete_book_by_id_usecase: DeleteBookUseCaseV1<BookRepositoryMySQL>,
 }
 
 impl BookControllerV1 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         book_repository: BookRepositoryMySQL,
         collection_repository: CollectionRepositoryMySQL,
         location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        note_repository: NoteRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
     ) -> Self {
         BookControllerV1 {
             create_update_book_usecase: CreateUpdateBookUseCaseV1::new(
                 book_repository.clone(),
                 collection_repository.clone(),
                 location_repository.clone(),
+                library_repository.clone(),
+                tag_repository.clone(),
+                custom_field_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
             ),
             get_all_books_from_user_usecase: FindAllBooksFromUserUseCaseV1::new(
                 book_repository.clone(),
+                library_repository.clone(),
+                tag_repository.clone(),
+                custom_field_repository.clone(),
+                note_repository.clone(),
+            ),
+            find_book_by_id_usecase: FindBookByIDUseCaseV1::new(
+                book_repository.clone(),
+                library_repository.clone(),
+                tag_repository.clone(),
+                custom_field_repository.clone(),
+                note_repository.clone(),
+            ),
+            delete_book_by_id_usecase: DeleteBookUseCaseV1::new(
+                book_repository.clone(),
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
+            bulk_book_operation_usecase: BulkBookOperationUseCaseV1::new(
+                book_repository.clone(),
+                collection_repository.clone(),
+                location_repository.clone(),
+                library_repository.clone(),
+                tag_repository.clone(),
+                custom_field_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
+            find_book_by_barcode_usecase: FindBookByBarcodeUseCaseV1::new(
+                book_repository.clone(),
+                library_repository.clone(),
+            ),
+            patch_book_usecase: PatchBookUseCaseV1::new(
+                book_repository,
+                collection_repository,
+                location_repository,
+                library_repository,
+                tag_repository,
+                custom_field_repository,
+                audit_repository,
+                change_event_bus,
             ),
-            find_book_by_id_usecase: FindBookByIDUseCaseV1::new(book_repository.clone()),
-            delete_book_by_id_usecase: DeleteBookUseCaseV1::new(book_repository.clone()),
         }
     }
 }
 
+#[utoipa::path(
+    tag = "books",
+    summary = "Create a book",
+    request_body = CreateBookDto,
+    responses(
+        (status = 201, description = "Book created", body = CompleteBookDto, headers(("ETag" = String, description = "Version of the resource"))),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
 #[post("")]
 async fn create_book(
     book_controller: web::Data<BookControllerV1>,
@@ -80,19 +213,40 @@ async fn create_book(
 
     match book_controller
         .create_update_book_usecase
-        .create_update_book(book)
+        .create_update_book(authed_user.id.unwrap(), book, None)
         .await
     {
-        Ok(book) => HttpResponse::Created().json(web::Json(book)),
+        Ok(book) => HttpResponse::Created()
+            .insert_header((header::ETAG, etag_from_version(book.version)))
+            .json(web::Json(book)),
         Err(error) => HttpResponse::from(error),
     }
 }
 
+#[utoipa::path(
+    tag = "books",
+    summary = "Replace a book",
+    params(
+        ("book_id" = u64, Path, description = "Id of the book"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the version the change is based on, `*` accepts any version"),
+    ),
+    request_body = CreateBookDto,
+    responses(
+        (status = 201, description = "Book updated", body = CompleteBookDto, headers(("ETag" = String, description = "Version of the resource"))),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Book not found", body = SimpleAPIError),
+        (status = 412, description = "The resource changed since the informed version", body = PreconditionFailedAPIError, headers(("ETag" = String, description = "Version of the resource"))),
+        (status = 428, description = "The resource version wasn't informed", body = SimpleAPIError),
+    ),
+)]
 #[put("/{book_id}")]
 async fn update_book(
     book_controller: web::Data<BookControllerV1>,
     path: web::Path<(u64,)>,
     update_book_dto: web::Json<CreateBookDto>,
+    if_match: IfMatch,
     authed_user: AuthedUser,
 ) -> HttpResponse {
     if authed_user.id.is_none() {
@@ -102,6 +256,11 @@ async fn update_book(
         )));
     }
 
+    let expected_version = match if_match.expected_version(update_book_dto.version) {
+        Ok(expected_version) => expected_version,
+        Err(error) => return HttpResponse::from(error),
+    };
+
     let mut book = match Book::try_from(update_book_dto.0) {
         Ok(converted_book) => converted_book,
         Err(e) => {
@@ -120,27 +279,92 @@ async fn update_book(
 
     match book_controller
         .create_update_book_usecase
-        .create_update_book(book)
+        .create_update_book(authed_user.id.unwrap(), book, expected_version)
         .await
     {
-        Ok(book) => HttpResponse::Created().json(web::Json(book)),
+        Ok(book) => HttpResponse::Created()
+            .insert_header((header::ETAG, etag_from_version(book.version)))
+            .json(web::Json(book)),
         Err(error) => HttpResponse::from(error),
     }
 }
 
-#[derive(Deserialize)]
-pub struct GetAllBooksParams {
-    page: Option<i64>,
-    page_size: Option<i64>,
-    collection_id: Option<i64>,
-    location_id: Option<i64>,
-    query: Option<String>,
+#[utoipa::path(
+    tag = "books",
+    summary = "Change some fields of a book with a JSON Merge Patch",
+    description = "Only the informed fields are changed, `null` clears an optional one.",
+    params(
+        ("book_id" = u64, Path, description = "Id of the book"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the version the change is based on, `*` accepts any version"),
+    ),
+    request_body(content = Object, content_type = "application/merge-patch+json"),
+    responses(
+        (status = 200, description = "Book updated", body = CompleteBookDto, headers(("ETag" = String, description = "Version of the resource"))),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Book not found", body = SimpleAPIError),
+        (status = 412, description = "The resource changed since the informed version", body = PreconditionFailedAPIError, headers(("ETag" = String, description = "Version of the resource"))),
+        (status = 428, description = "The resource version wasn't informed", body = SimpleAPIError),
+    ),
+)]
+#[patch("/{book_id}")]
+async fn patch_book(
+    book_controller: web::Data<BookControllerV1>,
+    path: web::Path<(u64,)>,
+    patch: web::Json<Value>,
+    if_match: IfMatch,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let expected_version =
+        match if_match.expected_version(patch.get("version").and_then(Value::as_u64)) {
+            Ok(expected_version) => expected_version,
+            Err(error) => return HttpResponse::from(error),
+        };
+
+    match book_controller
+        .patch_book_usecase
+        .patch_book(
+            authed_user.id.unwrap(),
+            path.into_inner().0,
+            patch.0,
+            expected_version,
+        )
+        .await
+    {
+        Ok(book) => HttpResponse::Ok()
+            .insert_header((header::ETAG, etag_from_version(book.version)))
+            .json(web::Json(book)),
+        Err(error) => HttpResponse::from(error),
+    }
 }
 
+#[utoipa::path(
+    tag = "books",
+    summary = "List the books of a library, by page number or by cursor",
+    params(
+        GetAllBooksParams,
+        LibraryScopeParams,
+    ),
+    responses(
+        (status = 200, description = "Page of books", body = PaginatedDto<CompleteBookDto>),
+        (status = 400, description = "Invalid parameters", body = SimpleAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
 #[get("")]
 async fn get_all_books_paginated(
     book_controller: web::Data<BookControllerV1>,
     params: web::Query<GetAllBooksParams>,
+    library_scope_params: web::Query<LibraryScopeParams>,
     authed_user: AuthedUser,
 ) -> HttpResponse {
     if authed_user.id.is_none() {
@@ -154,11 +378,8 @@ async fn get_all_books_paginated(
         .get_all_books_from_user_usecase
         .find_all_from_user(
             authed_user.id.unwrap(),
-            params.page,
-            params.page_size,
-            params.collection_id,
-            params.location_id,
-            params.query.clone(),
+            library_scope_params.library_id,
+            params.into_inner(),
         )
         .await
     {
@@ -167,6 +388,19 @@ async fn get_all_books_paginated(
     }
 }
 
+#[utoipa::path(
+    tag = "books",
+    summary = "Find a book",
+    params(
+        ("book_id" = u64, Path, description = "Id of the book"),
+    ),
+    responses(
+        (status = 200, description = "Book found", body = CompleteBookDto, headers(("ETag" = String, description = "Version of the resource"))),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Book not found", body = SimpleAPIError),
+    ),
+)]
 #[get("/{book_id}")]
 async fn get_book_by_id(
     book_controller: web::Data<BookControllerV1>,
@@ -185,15 +419,36 @@ async fn get_book_by_id(
         .find_book_by_id(authed_user.id.unwrap(), path.into_inner().0)
         .await
     {
-        Ok(books_page) => HttpResponse::Ok().json(web::Json(books_page)),
+        Ok(book) => HttpResponse::Ok()
+            .insert_header((header::ETAG, etag_from_version(book.version)))
+            .json(web::Json(book)),
         Err(error) => HttpResponse::from(error),
     }
 }
 
+#[utoipa::path(
+    tag = "books",
+    summary = "Move a book to the trash",
+    params(
+        ("book_id" = u64, Path, description = "Id of the book"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the version the change is based on, `*` accepts any version"),
+    ),
+    request_body(content = Option<VersionDto>),
+    responses(
+        (status = 200, description = "Book deleted"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Book not found", body = SimpleAPIError),
+        (status = 412, description = "The resource changed since the informed version", body = PreconditionFailedAPIError, headers(("ETag" = String, description = "Version of the resource"))),
+        (status = 428, description = "The resource version wasn't informed", body = SimpleAPIError),
+    ),
+)]
 #[delete("/{book_id}")]
 async fn delete_book_by_id(
     book_controller: web::Data<BookControllerV1>,
     path: web::Path<(u64,)>,
+    if_match: IfMatch,
+    version_dto: Option<web::Json<VersionDto>>,
     authed_user: AuthedUser,
 ) -> HttpResponse {
     if authed_user.id.is_none() {
@@ -203,9 +458,19 @@ async fn delete_book_by_id(
         )));
     }
 
+    let expected_version = match if_match.expected_version(version_dto.and_then(|dto| dto.version))
+    {
+        Ok(expected_version) => expected_version,
+        Err(error) => return HttpResponse::from(error),
+    };
+
     match book_controller
         .delete_book_by_id_usecase
-        .delete_book_by_id(authed_user.id.unwrap(), path.into_inner().0)
+        .delete_book_by_id(
+            authed_user.id.unwrap(),
+            path.into_inner().0,
+            expected_version,
+        )
         .await
     {
         Ok(_) => HttpResponse::Ok().finish(),
@@ -213,11 +478,125 @@ async fn delete_book_by_id(
     }
 }
 
+#[utoipa::path(
+    tag = "books",
+    summary = "Apply an action to many books at once",
+    params(
+        LibraryScopeParams,
+    ),
+    request_body = BulkBookOperationDto,
+    responses(
+        (status = 200, description = "Every book was changed", body = BulkBookOperationResultDto),
+        (status = 400, description = "Invalid parameters", body = SimpleAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 422, description = "No book was changed since some of them failed", body = BulkBookOperationResultDto),
+    ),
+)]
+#[post("/bulk")]
+async fn execute_bulk_operation(
+    book_controller: web::Data<BookControllerV1>,
+    bulk_book_operation_dto: web::Json<BulkBookOperationDto>,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let operation = match BulkBookOperation::try_from(bulk_book_operation_dto.0) {
+        Ok(converted_operation) => converted_operation,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match book_controller
+        .bulk_book_operation_usecase
+        .execute_bulk_operation(
+            authed_user.id.unwrap(),
+            library_scope_params.library_id,
+            operation,
+        )
+        .await
+    {
+        Ok(result) if result.committed => HttpResponse::Ok().json(web::Json(result)),
+        Ok(result) => HttpResponse::UnprocessableEntity().json(web::Json(result)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "books",
+    summary = "Find a book by a photo of its EAN-13 barcode",
+    params(
+        LibraryScopeParams,
+    ),
+    request_body(content = BinaryDto, content_type = "image/jpeg"),
+    responses(
+        (status = 200, description = "Barcode lookup", body = BarcodeLookupDto),
+        (status = 400, description = "Invalid parameters", body = SimpleAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[post("/barcode")]
+async fn find_book_by_barcode(
+    book_controller: web::Data<BookControllerV1>,
+    image: web::Bytes,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match book_controller
+        .find_book_by_barcode_usecase
+        .find_by_barcode(
+            authed_user.id.unwrap(),
+            library_scope_params.library_id,
+            image.to_vec(),
+        )
+        .await
+    {
+        Ok(lookup) => HttpResponse::Ok().json(web::Json(lookup)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    execute_bulk_operation,
+    find_book_by_barcode,
+    create_book,
+    get_all_books_paginated,
+    get_book_by_id,
+    update_book,
+    patch_book,
+    delete_book_by_id
+))]
+pub struct BookApiV1;
+
 pub fn get_book_scope() -> Scope {
     web::scope("/v1/books")
+        .app_data(web::PayloadConfig::default().limit(MAX_BARCODE_IMAGE_SIZE))
+        .app_data(web::JsonConfig::default().content_type(|content_type| {
+            content_type.essence_str() == "application/json"
+                || content_type.essence_str() == "application/merge-patch+json"
+        }))
+        .service(execute_bulk_operation)
+        .service(find_book_by_barcode)
         .service(create_book)
         .service(get_all_books_paginated)
         .service(get_book_by_id)
         .service(update_book)
+        .service(patch_book)
         .service(delete_book_by_id)
 }
@@ -0,0 +1,162 @@
+use crate::modules::{
+    books::{
+        domain::dtos::{
+            complete_book_dto::CompleteBookDto, find_book_history_dto::FindBookHistoryDto,
+        },
+        infra::repositories::{
+            audit_repository_mysql::AuditRepositoryMySQL,
+            book_repository_mysql::BookRepositoryMySQL,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+            location_repository_mysql::LocationRepositoryMySQL,
+            tag_repository_mysql::TagRepositoryMySQL,
+        },
+        usecases::v1::{
+            find_book_history_usecase::FindBookHistoryUseCaseV1,
+            revert_book_usecase::RevertBookUseCaseV1,
+        },
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    shared::{
+        domain::dtos::if_match::etag_from_version,
+        errors::{
+            detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+        },
+    },
+    users::domain::dtos::authed_user::AuthedUser,
+};
+use actix_web::{get, http::header, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct BookHistoryControllerV1 {
+    find_book_history_usecase:
+        FindBookHistoryUseCaseV1<AuditRepositoryMySQL, LibraryRepositoryMySQL>,
+    revert_book_usecase: RevertBookUseCaseV1<
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >,
+}
+
+impl BookHistoryControllerV1 {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        BookHistoryControllerV1 {
+            find_book_history_usecase: FindBookHistoryUseCaseV1::new(
+                audit_repository.clone(),
+                library_repository.clone(),
+            ),
+            revert_book_usecase: RevertBookUseCaseV1::new(
+                book_repository.clone(),
+                collection_repository.clone(),
+                location_repository.clone(),
+                library_repository.clone(),
+                tag_repository.clone(),
+                custom_field_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "books",
+    summary = "List the changes made to a book",
+    params(
+        ("book_id" = u64, Path, description = "Id of the book"),
+    ),
+    responses(
+        (status = 200, description = "Book history", body = FindBookHistoryDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Book history not found", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn get_book_history(
+    book_history_controller: web::Data<BookHistoryControllerV1>,
+    path: web::Path<(u64,)>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match book_history_controller
+        .find_book_history_usecase
+        .find_book_history(authed_user.id.unwrap(), path.into_inner().0)
+        .await
+    {
+        Ok(history) => HttpResponse::Ok().json(web::Json(history)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "books",
+    summary = "Revert a book to the state recorded by a history entry",
+    params(
+        ("book_id" = u64, Path, description = "Id of the book"),
+        ("entry_id" = u64, Path, description = "Id of the history entry"),
+    ),
+    responses(
+        (status = 200, description = "Book reverted", body = CompleteBookDto, headers(("ETag" = String, description = "Version of the resource"))),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "History entry not found", body = SimpleAPIError),
+    ),
+)]
+#[post("/{entry_id}/revert")]
+async fn revert_book(
+    book_history_controller: web::Data<BookHistoryControllerV1>,
+    path: web::Path<(u64, u64)>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let (book_id, entry_id) = path.into_inner();
+    match book_history_controller
+        .revert_book_usecase
+        .revert_book(authed_user.id.unwrap(), book_id, entry_id)
+        .await
+    {
+        Ok(book) => HttpResponse::Ok()
+            .insert_header((header::ETAG, etag_from_version(book.version)))
+            .json(web::Json(book)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(get_book_history, revert_book))]
+pub struct BookHistoryApiV1;
+
+pub fn get_book_history_scope() -> Scope {
+    web::scope("/v1/books/{book_id}/history")
+        .service(get_book_history)
+        .service(revert_book)
+}
@@ -2,46 +2,96 @@ use crate::modules::{
     books::{
         domain::{
             dtos::{
-                create_collection_dto::CreateCollectionDto,
+                collection_dto::CollectionDto, create_collection_dto::CreateCollectionDto,
                 find_all_collections_from_user_dto::FindAllCollectionsFromUserDto,
             },
             entities::collection::Collection,
         },
-        infra::repositories::collection_repository_mysql::CollectionRepositoryMySQL,
+        infra::repositories::{
+            audit_repository_mysql::AuditRepositoryMySQL,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+        },
         usecases::v1::{
             create_collection_usecase::CreateCollectionUseCaseV1,
             delete_collection_usecase::DeleteCollectionUseCaseV1,
             find_all_collection_from_user_usecase::FindAllCollectionFromUserUseCaseV1,
         },
     },
-    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::dtos::library_scope_params::LibraryScopeParams,
+        infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    },
+    shared::{
+        domain::dtos::{
+            if_match::{etag_from_version, IfMatch},
+            version_dto::VersionDto,
+        },
+        errors::{
+            detailed_api_error::DetailedAPIError,
+            precondition_failed_api_error::PreconditionFailedAPIError,
+            simple_api_error::SimpleAPIError, APIError,
+        },
+    },
     users::domain::dtos::authed_user::AuthedUser,
 };
-use actix_web::{delete, get, post, web, HttpResponse, Scope};
+use actix_web::{delete, get, http::header, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
 
 pub struct CollectionControllerV1 {
-    create_collection_usecase: CreateCollectionUseCaseV1<CollectionRepositoryMySQL>,
-    delete_collection_usecase: DeleteCollectionUseCaseV1<CollectionRepositoryMySQL>,
+    create_collection_usecase: CreateCollectionUseCaseV1<
+        CollectionRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >,
+    delete_collection_usecase: DeleteCollectionUseCaseV1<
+        CollectionRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >,
     find_all_collection_from_user_usecase:
-        FindAllCollectionFromUserUseCaseV1<CollectionRepositoryMySQL>,
+        FindAllCollectionFromUserUseCaseV1<CollectionRepositoryMySQL, LibraryRepositoryMySQL>,
 }
 
 impl CollectionControllerV1 {
-    pub fn new(collection_repository: CollectionRepositoryMySQL) -> Self {
+    pub fn new(
+        collection_repository: CollectionRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
         CollectionControllerV1 {
             create_collection_usecase: CreateCollectionUseCaseV1::new(
                 collection_repository.clone(),
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
             ),
             delete_collection_usecase: DeleteCollectionUseCaseV1::new(
                 collection_repository.clone(),
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
             ),
             find_all_collection_from_user_usecase: FindAllCollectionFromUserUseCaseV1::new(
                 collection_repository.clone(),
+                library_repository.clone(),
             ),
         }
     }
 }
 
+#[utoipa::path(
+    tag = "collections",
+    summary = "Create a collection",
+    request_body = CreateCollectionDto,
+    responses(
+        (status = 201, description = "Collection created", body = CollectionDto, headers(("ETag" = String, description = "Version of the resource"))),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
 #[post("")]
 async fn create_collection(
     collection_controller: web::Data<CollectionControllerV1>,
@@ -55,6 +105,7 @@ async fn create_collection(
         )));
     }
 
+    let library_id = create_collection_dto.library_id;
     let collection = match Collection::try_from(create_collection_dto.0) {
         Ok(converted_collection) => converted_collection,
         Err(e) => {
@@ -71,17 +122,32 @@ async fn create_collection(
 
     match collection_controller
         .create_collection_usecase
-        .create_collection(collection)
+        .create_collection(collection, library_id)
         .await
     {
-        Ok(collection) => HttpResponse::Created().json(web::Json(collection)),
+        Ok(collection) => HttpResponse::Created()
+            .insert_header((header::ETAG, etag_from_version(collection.version)))
+            .json(web::Json(collection)),
         Err(error) => HttpResponse::from(error),
     }
 }
 
+#[utoipa::path(
+    tag = "collections",
+    summary = "List the collections of a library",
+    params(
+        LibraryScopeParams,
+    ),
+    responses(
+        (status = 200, description = "Collections", body = FindAllCollectionsFromUserDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
 #[get("")]
 async fn get_all_collections_from_user(
     collection_controller: web::Data<CollectionControllerV1>,
+    library_scope_params: web::Query<LibraryScopeParams>,
     authed_user: AuthedUser,
 ) -> HttpResponse {
     if authed_user.id.is_none() {
@@ -93,7 +159,7 @@ async fn get_all_collections_from_user(
 
     match collection_controller
         .find_all_collection_from_user_usecase
-        .find_all_collection_from_user(authed_user.id.unwrap())
+        .find_all_collection_from_user(authed_user.id.unwrap(), library_scope_params.library_id)
         .await
     {
         Ok(collections) => {
@@ -103,10 +169,29 @@ async fn get_all_collections_from_user(
     }
 }
 
+#[utoipa::path(
+    tag = "collections",
+    summary = "Move a collection to the trash",
+    params(
+        ("collection_id" = u64, Path, description = "Id of the collection"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the version the change is based on, `*` accepts any version"),
+    ),
+    request_body(content = Option<VersionDto>),
+    responses(
+        (status = 200, description = "Collection deleted"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Collection not found", body = SimpleAPIError),
+        (status = 412, description = "The resource changed since the informed version", body = PreconditionFailedAPIError, headers(("ETag" = String, description = "Version of the resource"))),
+        (status = 428, description = "The resource version wasn't informed", body = SimpleAPIError),
+    ),
+)]
 #[delete("/{collection_id}")]
 async fn delete_collection(
     collection_controller: web::Data<CollectionControllerV1>,
     path_variables: web::Path<u64>,
+    if_match: IfMatch,
+    version_dto: Option<web::Json<VersionDto>>,
     authed_user: AuthedUser,
 ) -> HttpResponse {
     let path_collection_id = path_variables.into_inner();
@@ -118,9 +203,19 @@ async fn delete_collection(
         )));
     }
 
+    let expected_version = match if_match.expected_version(version_dto.and_then(|dto| dto.version))
+    {
+        Ok(expected_version) => expected_version,
+        Err(error) => return HttpResponse::from(error),
+    };
+
     match collection_controller
         .delete_collection_usecase
-        .delete_collection(path_collection_id, authed_user.id.unwrap())
+        .delete_collection(
+            path_collection_id,
+            authed_user.id.unwrap(),
+            expected_version,
+        )
         .await
     {
         Ok(_) => HttpResponse::Ok().finish(),
@@ -128,6 +223,10 @@ async fn delete_collection(
     }
 }
 
+#[derive(OpenApi)]
+#[openapi(paths(create_collection, delete_collection, get_all_collections_from_user))]
+pub struct CollectionApiV1;
+
 pub fn get_collection_scope() -> Scope {
     web::scope("/v1/collections")
         .service(create_collection)
@@ -0,0 +1,176 @@
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::{
+                create_custom_field_dto::CreateCustomFieldDto, custom_field_dto::CustomFieldDto,
+                find_all_custom_fields_from_user_dto::FindAllCustomFieldsFromUserDto,
+            },
+            entities::custom_field::CustomField,
+        },
+        infra::repositories::custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+        usecases::v1::{
+            create_custom_field_usecase::CreateCustomFieldUseCaseV1,
+            delete_custom_field_usecase::DeleteCustomFieldUseCaseV1,
+            find_all_custom_fields_from_user_usecase::FindAllCustomFieldsFromUserUseCaseV1,
+        },
+    },
+    shared::errors::{
+        detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+    },
+    users::domain::dtos::authed_user::AuthedUser,
+};
+use actix_web::{delete, get, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct CustomFieldControllerV1 {
+    create_custom_field_usecase: CreateCustomFieldUseCaseV1<CustomFieldRepositoryMySQL>,
+    delete_custom_field_usecase: DeleteCustomFieldUseCaseV1<CustomFieldRepositoryMySQL>,
+    find_all_custom_fields_from_user_usecase:
+        FindAllCustomFieldsFromUserUseCaseV1<CustomFieldRepositoryMySQL>,
+}
+
+impl CustomFieldControllerV1 {
+    pub fn new(custom_field_repository: CustomFieldRepositoryMySQL) -> Self {
+        CustomFieldControllerV1 {
+            create_custom_field_usecase: CreateCustomFieldUseCaseV1::new(
+                custom_field_repository.clone(),
+            ),
+            delete_custom_field_usecase: DeleteCustomFieldUseCaseV1::new(
+                custom_field_repository.clone(),
+            ),
+            find_all_custom_fields_from_user_usecase: FindAllCustomFieldsFromUserUseCaseV1::new(
+                custom_field_repository.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "custom-fields",
+    summary = "Create a custom field",
+    request_body = CreateCustomFieldDto,
+    responses(
+        (status = 201, description = "Custom field created", body = CustomFieldDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+    ),
+)]
+#[post("")]
+async fn create_custom_field(
+    custom_field_controller: web::Data<CustomFieldControllerV1>,
+    create_custom_field_dto: web::Json<CreateCustomFieldDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let mut dto = create_custom_field_dto.into_inner();
+    dto.user_id = authed_user.id;
+    let custom_field = match CustomField::try_from(dto) {
+        Ok(converted_custom_field) => converted_custom_field,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match custom_field_controller
+        .create_custom_field_usecase
+        .create_custom_field(custom_field)
+        .await
+    {
+        Ok(custom_field) => {
+            HttpResponse::Created().json(web::Json(CustomFieldDto::from(custom_field)))
+        }
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "custom-fields",
+    summary = "List the user's custom fields",
+    responses(
+        (status = 200, description = "Custom fields", body = FindAllCustomFieldsFromUserDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn get_all_custom_fields_from_user(
+    custom_field_controller: web::Data<CustomFieldControllerV1>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match custom_field_controller
+        .find_all_custom_fields_from_user_usecase
+        .find_all_custom_fields_from_user(authed_user.id.unwrap())
+        .await
+    {
+        Ok(custom_fields) => HttpResponse::Ok().json(web::Json(FindAllCustomFieldsFromUserDto {
+            custom_fields: custom_fields
+                .into_iter()
+                .map(CustomFieldDto::from)
+                .collect(),
+        })),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "custom-fields",
+    summary = "Delete a custom field and its values",
+    params(
+        ("custom_field_id" = u64, Path, description = "Id of the custom field"),
+    ),
+    responses(
+        (status = 200, description = "Custom field deleted"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Custom field not found", body = SimpleAPIError),
+    ),
+)]
+#[delete("/{custom_field_id}")]
+async fn delete_custom_field(
+    custom_field_controller: web::Data<CustomFieldControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match custom_field_controller
+        .delete_custom_field_usecase
+        .delete_custom_field(path_variables.into_inner(), authed_user.id.unwrap())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    create_custom_field,
+    get_all_custom_fields_from_user,
+    delete_custom_field
+))]
+pub struct CustomFieldApiV1;
+
+pub fn get_custom_field_scope() -> Scope {
+    web::scope("/v1/custom-fields")
+        .service(create_custom_field)
+        .service(get_all_custom_fields_from_user)
+        .service(delete_custom_field)
+}
@@ -0,0 +1,128 @@
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::{complete_book_dto::CompleteBookDto, export_books_params::ExportBooksParams},
+            entities::book_export::BookExport,
+        },
+        infra::repositories::{
+            book_repository_mysql::BookRepositoryMySQL,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+        },
+        usecases::v1::export_books_usecase::ExportBooksUseCaseV1,
+    },
+    libraries::{
+        domain::dtos::library_scope_params::LibraryScopeParams,
+        infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    },
+    shared::{
+        domain::dtos::binary_dto::BinaryDto,
+        errors::{simple_api_error::SimpleAPIError, APIError},
+    },
+    users::domain::dtos::authed_user::AuthedUser,
+};
+use actix_web::{
+    get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    web, HttpResponse, Scope,
+};
+use futures_util::TryStreamExt;
+use utoipa::OpenApi;
+
+pub struct ExportControllerV1 {
+    export_books_usecase: ExportBooksUseCaseV1<
+        BookRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+    >,
+}
+
+impl ExportControllerV1 {
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+    ) -> Self {
+        ExportControllerV1 {
+            export_books_usecase: ExportBooksUseCaseV1::new(
+                book_repository.clone(),
+                library_repository.clone(),
+                custom_field_repository.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "export",
+    summary = "Export the books of a library as CSV, JSON or XLSX",
+    params(
+        ExportBooksParams,
+        LibraryScopeParams,
+    ),
+    responses(
+        (status = 200, description = "Exported books", content(
+            (String = "text/csv"),
+            (Vec<CompleteBookDto> = "application/json"),
+            (BinaryDto = "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+        )),
+        (status = 400, description = "Invalid parameters", body = SimpleAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn export_books(
+    export_controller: web::Data<ExportControllerV1>,
+    params: web::Query<ExportBooksParams>,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let book_export = match BookExport::try_from(params.into_inner()) {
+        Ok(converted_export) => converted_export,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+    let format = book_export.format;
+
+    let export_stream = match export_controller
+        .export_books_usecase
+        .export_books(
+            authed_user.id.unwrap(),
+            library_scope_params.library_id,
+            book_export,
+        )
+        .await
+    {
+        Ok(export_stream) => export_stream
+            .map_ok(web::Bytes::from)
+            .inspect_err(|error| tracing::error!("Library export interrupted: {}", error)),
+        Err(error) => return HttpResponse::from(error),
+    };
+
+    HttpResponse::Ok()
+        .content_type(format.content_type())
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!(
+                "library.{}",
+                format.file_extension()
+            ))],
+        })
+        .streaming(export_stream)
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(export_books))]
+pub struct ExportApiV1;
+
+pub fn get_export_scope() -> Scope {
+    web::scope("/v1/export").service(export_books)
+}
@@ -0,0 +1,237 @@
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::{
+                book_import_report_dto::BookImportReportDto,
+                import_books_csv_dto::ImportBooksCsvDto, import_goodreads_dto::ImportGoodreadsDto,
+                import_library_thing_dto::ImportLibraryThingDto,
+            },
+            entities::book_import::BookImport,
+        },
+        infra::repositories::{
+            audit_repository_mysql::AuditRepositoryMySQL,
+            book_repository_mysql::BookRepositoryMySQL,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            location_repository_mysql::LocationRepositoryMySQL,
+        },
+        usecases::v1::import_books_usecase::ImportBooksUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::dtos::library_scope_params::LibraryScopeParams,
+        infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    },
+    shared::errors::{
+        detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+    },
+    users::domain::dtos::authed_user::AuthedUser,
+    webhooks::infra::repositories::webhook_repository_mysql::WebhookRepositoryMySQL,
+};
+use actix_web::{post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+const MAX_IMPORT_PAYLOAD_SIZE: usize = 10 * 1024 * 1024;
+
+pub struct ImportControllerV1 {
+    import_books_usecase: ImportBooksUseCaseV1<
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        AuditRepositoryMySQL,
+        WebhookRepositoryMySQL,
+    >,
+}
+
+impl ImportControllerV1 {
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+        webhook_repository: WebhookRepositoryMySQL,
+    ) -> Self {
+        ImportControllerV1 {
+            import_books_usecase: ImportBooksUseCaseV1::new(
+                book_repository.clone(),
+                collection_repository.clone(),
+                location_repository.clone(),
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+                webhook_repository.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "imports",
+    summary = "Import books from a CSV file",
+    params(
+        LibraryScopeParams,
+    ),
+    request_body = ImportBooksCsvDto,
+    responses(
+        (status = 200, description = "Dry run or nothing imported", body = BookImportReportDto),
+        (status = 201, description = "Books imported", body = BookImportReportDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[post("/csv")]
+async fn import_books_from_csv(
+    import_controller: web::Data<ImportControllerV1>,
+    import_books_csv_dto: web::Json<ImportBooksCsvDto>,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let book_import = match BookImport::try_from(import_books_csv_dto.0) {
+        Ok(converted_import) => converted_import,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match import_controller
+        .import_books_usecase
+        .import_books(
+            authed_user.id.unwrap(),
+            library_scope_params.library_id,
+            book_import,
+        )
+        .await
+    {
+        Ok(report) if report.imported_rows > 0 => HttpResponse::Created().json(web::Json(report)),
+        Ok(report) => HttpResponse::Ok().json(web::Json(report)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "imports",
+    summary = "Import books from a Goodreads export",
+    params(
+        LibraryScopeParams,
+    ),
+    request_body = ImportGoodreadsDto,
+    responses(
+        (status = 200, description = "Dry run or nothing imported", body = BookImportReportDto),
+        (status = 201, description = "Books imported", body = BookImportReportDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[post("/goodreads")]
+async fn import_books_from_goodreads(
+    import_controller: web::Data<ImportControllerV1>,
+    import_goodreads_dto: web::Json<ImportGoodreadsDto>,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let book_import = match BookImport::try_from(import_goodreads_dto.0) {
+        Ok(converted_import) => converted_import,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match import_controller
+        .import_books_usecase
+        .import_books(
+            authed_user.id.unwrap(),
+            library_scope_params.library_id,
+            book_import,
+        )
+        .await
+    {
+        Ok(report) if report.imported_rows > 0 => HttpResponse::Created().json(web::Json(report)),
+        Ok(report) => HttpResponse::Ok().json(web::Json(report)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "imports",
+    summary = "Import books from a LibraryThing export",
+    params(
+        LibraryScopeParams,
+    ),
+    request_body = ImportLibraryThingDto,
+    responses(
+        (status = 200, description = "Dry run or nothing imported", body = BookImportReportDto),
+        (status = 201, description = "Books imported", body = BookImportReportDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[post("/librarything")]
+async fn import_books_from_library_thing(
+    import_controller: web::Data<ImportControllerV1>,
+    import_library_thing_dto: web::Json<ImportLibraryThingDto>,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let book_import = match BookImport::try_from(import_library_thing_dto.0) {
+        Ok(converted_import) => converted_import,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match import_controller
+        .import_books_usecase
+        .import_books(
+            authed_user.id.unwrap(),
+            library_scope_params.library_id,
+            book_import,
+        )
+        .await
+    {
+        Ok(report) if report.imported_rows > 0 => HttpResponse::Created().json(web::Json(report)),
+        Ok(report) => HttpResponse::Ok().json(web::Json(report)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    import_books_from_csv,
+    import_books_from_goodreads,
+    import_books_from_library_thing
+))]
+pub struct ImportApiV1;
+
+pub fn get_import_scope() -> Scope {
+    web::scope("/v1/imports")
+        .app_data(web::JsonConfig::default().limit(MAX_IMPORT_PAYLOAD_SIZE))
+        .service(import_books_from_csv)
+        .service(import_books_from_goodreads)
+        .service(import_books_from_library_thing)
+}
@@ -4,39 +4,95 @@ use crate::modules::{
             dtos::{
                 create_location_dto::CreateLocationDto,
                 find_all_locations_from_user_dto::FindAllLocationsFromUserDto,
+                location_dto::LocationDto,
             },
             entities::location::Location,
         },
-        infra::repositories::location_repository_mysql::LocationRepositoryMySQL,
+        infra::repositories::{
+            audit_repository_mysql::AuditRepositoryMySQL,
+            location_repository_mysql::LocationRepositoryMySQL,
+        },
         usecases::v1::{
             create_location_usecase::CreateLocationUseCaseV1,
             delete_location_usecase::DeleteLocationUseCaseV1,
             find_all_location_from_user_usecase::FindAllLocationFromUserUseCaseV1,
         },
     },
-    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::dtos::library_scope_params::LibraryScopeParams,
+        infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    },
+    shared::{
+        domain::dtos::{
+            if_match::{etag_from_version, IfMatch},
+            version_dto::VersionDto,
+        },
+        errors::{
+            detailed_api_error::DetailedAPIError,
+            precondition_failed_api_error::PreconditionFailedAPIError,
+            simple_api_error::SimpleAPIError, APIError,
+        },
+    },
     users::domain::dtos::authed_user::AuthedUser,
 };
-use actix_web::{delete, get, post, web, HttpResponse, Scope};
+use actix_web::{delete, get, http::header, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
 
 pub struct LocationControllerV1 {
-    create_location_usecase: CreateLocationUseCaseV1<LocationRepositoryMySQL>,
-    delete_location_usecase: DeleteLocationUseCaseV1<LocationRepositoryMySQL>,
-    find_all_location_from_user_usecase: FindAllLocationFromUserUseCaseV1<LocationRepositoryMySQL>,
+    create_location_usecase: CreateLocationUseCaseV1<
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >,
+    delete_location_usecase: DeleteLocationUseCaseV1<
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >,
+    find_all_location_from_user_usecase:
+        FindAllLocationFromUserUseCaseV1<LocationRepositoryMySQL, LibraryRepositoryMySQL>,
 }
 
 impl LocationControllerV1 {
-    pub fn new(location_repository: LocationRepositoryMySQL) -> Self {
+    pub fn new(
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
         LocationControllerV1 {
-            create_location_usecase: CreateLocationUseCaseV1::new(location_repository.clone()),
-            delete_location_usecase: DeleteLocationUseCaseV1::new(location_repository.clone()),
+            create_location_usecase: CreateLocationUseCaseV1::new(
+                location_repository.clone(),
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
+            delete_location_usecase: DeleteLocationUseCaseV1::new(
+                location_repository.clone(),
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
             find_all_location_from_user_usecase: FindAllLocationFromUserUseCaseV1::new(
                 location_repository.clone(),
+                library_repository.clone(),
             ),
         }
     }
 }
 
+#[utoipa::path(
+    tag = "locations",
+    summary = "Create a location",
+    request_body = CreateLocationDto,
+    responses(
+        (status = 201, description = "Location created", body = LocationDto, headers(("ETag" = String, description = "Version of the resource"))),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
 #[post("")]
 async fn create_location(
     location_controller: web::Data<LocationControllerV1>,
@@ -50,6 +106,7 @@ async fn create_location(
         )));
     }
 
+    let library_id = create_location_dto.library_id;
     let location = match Location::try_from(create_location_dto.0) {
         Ok(converted_location) => converted_location,
         Err(e) => {
@@ -66,17 +123,32 @@ async fn create_location(
 
     match location_controller
         .create_location_usecase
-        .create_location(location)
+        .create_location(location, library_id)
         .await
     {
-        Ok(location) => HttpResponse::Created().json(web::Json(location)),
+        Ok(location) => HttpResponse::Created()
+            .insert_header((header::ETAG, etag_from_version(location.version)))
+            .json(web::Json(location)),
         Err(error) => HttpResponse::from(error),
     }
 }
 
+#[utoipa::path(
+    tag = "locations",
+    summary = "List the locations of a library",
+    params(
+        LibraryScopeParams,
+    ),
+    responses(
+        (status = 200, description = "Locations", body = FindAllLocationsFromUserDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
 #[get("")]
 async fn get_all_locations_from_user(
     location_controller: web::Data<LocationControllerV1>,
+    library_scope_params: web::Query<LibraryScopeParams>,
     authed_user: AuthedUser,
 ) -> HttpResponse {
     if authed_user.id.is_none() {
@@ -88,7 +160,7 @@ async fn get_all_locations_from_user(
 
     match location_controller
         .find_all_location_from_user_usecase
-        .find_all_location_from_user(authed_user.id.unwrap())
+        .find_all_location_from_user(authed_user.id.unwrap(), library_scope_params.library_id)
         .await
     {
         Ok(locations) => {
@@ -98,10 +170,29 @@ async fn get_all_locations_from_user(
     }
 }
 
+#[utoipa::path(
+    tag = "locations",
+    summary = "Move a location to the trash",
+    params(
+        ("location_id" = u64, Path, description = "Id of the location"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the version the change is based on, `*` accepts any version"),
+    ),
+    request_body(content = Option<VersionDto>),
+    responses(
+        (status = 200, description = "Location deleted"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Location not found", body = SimpleAPIError),
+        (status = 412, description = "The resource changed since the informed version", body = PreconditionFailedAPIError, headers(("ETag" = String, description = "Version of the resource"))),
+        (status = 428, description = "The resource version wasn't informed", body = SimpleAPIError),
+    ),
+)]
 #[delete("/{location_id}")]
 async fn delete_location(
     location_controller: web::Data<LocationControllerV1>,
     path_variables: web::Path<u64>,
+    if_match: IfMatch,
+    version_dto: Option<web::Json<VersionDto>>,
     authed_user: AuthedUser,
 ) -> HttpResponse {
     let path_location_id = path_variables.into_inner();
@@ -113,9 +204,15 @@ async fn delete_location(
         )));
     }
 
+    let expected_version = match if_match.expected_version(version_dto.and_then(|dto| dto.version))
+    {
+        Ok(expected_version) => expected_version,
+        Err(error) => return HttpResponse::from(error),
+    };
+
     match location_controller
         .delete_location_usecase
-        .delete_location(path_location_id, authed_user.id.unwrap())
+        .delete_location(path_location_id, authed_user.id.unwrap(), expected_version)
         .await
     {
         Ok(_) => HttpResponse::Ok().finish(),
@@ -123,6 +220,10 @@ async fn delete_location(
     }
 }
 
+#[derive(OpenApi)]
+#[openapi(paths(create_location, delete_location, get_all_locations_from_user))]
+pub struct LocationApiV1;
+
 pub fn get_location_scope() -> Scope {
     web::scope("/v1/locations")
         .service(create_location)
@@ -0,0 +1,296 @@
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::{
+                create_note_dto::CreateNoteDto,
+                find_all_notes_from_book_dto::FindAllNotesFromBookDto, note_dto::NoteDto,
+                search_notes_params::SearchNotesParams,
+            },
+            entities::note::Note,
+        },
+        infra::repositories::{
+            book_repository_mysql::BookRepositoryMySQL, note_repository_mysql::NoteRepositoryMySQL,
+        },
+        usecases::v1::{
+            create_update_note_usecase::CreateUpdateNoteUseCaseV1,
+            delete_note_usecase::DeleteNoteUseCaseV1,
+            find_all_notes_from_book_usecase::FindAllNotesFromBookUseCaseV1,
+            search_notes_usecase::SearchNotesUseCaseV1,
+        },
+    },
+    libraries::infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    shared::{
+        domain::dtos::paginated_dto::PaginatedDto,
+        errors::{
+            detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+        },
+    },
+    users::domain::dtos::authed_user::AuthedUser,
+};
+use actix_web::{delete, get, post, put, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct NoteControllerV1 {
+    create_update_note_usecase:
+        CreateUpdateNoteUseCaseV1<NoteRepositoryMySQL, BookRepositoryMySQL, LibraryRepositoryMySQL>,
+    delete_note_usecase: DeleteNoteUseCaseV1<NoteRepositoryMySQL>,
+    find_all_notes_from_book_usecase: FindAllNotesFromBookUseCaseV1<
+        NoteRepositoryMySQL,
+        BookRepositoryMySQL,
+        LibraryRepositoryMySQL,
+    >,
+    search_notes_usecase: SearchNotesUseCaseV1<NoteRepositoryMySQL>,
+}
+
+impl NoteControllerV1 {
+    pub fn new(
+        note_repository: NoteRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
+        NoteControllerV1 {
+            create_update_note_usecase: CreateUpdateNoteUseCaseV1::new(
+                note_repository.clone(),
+                book_repository.clone(),
+                library_repository.clone(),
+            ),
+            delete_note_usecase: DeleteNoteUseCaseV1::new(note_repository.clone()),
+            find_all_notes_from_book_usecase: FindAllNotesFromBookUseCaseV1::new(
+                note_repository.clone(),
+                book_repository.clone(),
+                library_repository.clone(),
+            ),
+            search_notes_usecase: SearchNotesUseCaseV1::new(note_repository.clone()),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "notes",
+    summary = "Write a note about a book",
+    params(
+        ("book_id" = u64, Path, description = "Id of the book"),
+    ),
+    request_body = CreateNoteDto,
+    responses(
+        (status = 201, description = "Note created", body = NoteDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Book not found", body = SimpleAPIError),
+    ),
+)]
+#[post("")]
+async fn create_note(
+    note_controller: web::Data<NoteControllerV1>,
+    path: web::Path<(u64,)>,
+    create_note_dto: web::Json<CreateNoteDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let mut dto = create_note_dto.into_inner();
+    dto.book_id = Some(path.into_inner().0);
+    dto.user_id = authed_user.id;
+    let note = match Note::try_from(dto) {
+        Ok(converted_note) => converted_note,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match note_controller
+        .create_update_note_usecase
+        .create_update_note(note)
+        .await
+    {
+        Ok(note) => HttpResponse::Created().json(web::Json(note)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "notes",
+    summary = "List the user's notes about a book",
+    params(
+        ("book_id" = u64, Path, description = "Id of the book"),
+    ),
+    responses(
+        (status = 200, description = "Notes", body = FindAllNotesFromBookDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Book not found", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn get_all_notes_from_book(
+    note_controller: web::Data<NoteControllerV1>,
+    path: web::Path<(u64,)>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match note_controller
+        .find_all_notes_from_book_usecase
+        .find_all_notes_from_book(authed_user.id.unwrap(), path.into_inner().0)
+        .await
+    {
+        Ok(notes) => HttpResponse::Ok().json(web::Json(FindAllNotesFromBookDto { notes })),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "notes",
+    summary = "Change a note",
+    params(
+        ("book_id" = u64, Path, description = "Id of the book"),
+        ("note_id" = u64, Path, description = "Id of the note"),
+    ),
+    request_body = CreateNoteDto,
+    responses(
+        (status = 200, description = "Note updated", body = NoteDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Note not found", body = SimpleAPIError),
+    ),
+)]
+#[put("/{note_id}")]
+async fn update_note(
+    note_controller: web::Data<NoteControllerV1>,
+    path: web::Path<(u64, u64)>,
+    update_note_dto: web::Json<CreateNoteDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let (book_id, note_id) = path.into_inner();
+    let mut dto = update_note_dto.into_inner();
+    dto.book_id = Some(book_id);
+    dto.user_id = authed_user.id;
+    let mut note = match Note::try_from(dto) {
+        Ok(converted_note) => converted_note,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+    note.id = Some(note_id);
+
+    match note_controller
+        .create_update_note_usecase
+        .create_update_note(note)
+        .await
+    {
+        Ok(note) => HttpResponse::Ok().json(web::Json(note)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "notes",
+    summary = "Delete a note",
+    params(
+        ("book_id" = u64, Path, description = "Id of the book"),
+        ("note_id" = u64, Path, description = "Id of the note"),
+    ),
+    responses(
+        (status = 200, description = "Note deleted"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Note not found", body = SimpleAPIError),
+    ),
+)]
+#[delete("/{note_id}")]
+async fn delete_note(
+    note_controller: web::Data<NoteControllerV1>,
+    path: web::Path<(u64, u64)>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let (book_id, note_id) = path.into_inner();
+    match note_controller
+        .delete_note_usecase
+        .delete_note(book_id, note_id, authed_user.id.unwrap())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "notes",
+    summary = "Search the user's notes",
+    params(
+        SearchNotesParams,
+    ),
+    responses(
+        (status = 200, description = "Page of notes", body = PaginatedDto<NoteDto>),
+        (status = 400, description = "Invalid parameters", body = SimpleAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn search_notes(
+    note_controller: web::Data<NoteControllerV1>,
+    params: web::Query<SearchNotesParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match note_controller
+        .search_notes_usecase
+        .search_notes(authed_user.id.unwrap(), params.into_inner())
+        .await
+    {
+        Ok(notes_page) => HttpResponse::Ok().json(web::Json(notes_page)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(create_note, get_all_notes_from_book, update_note, delete_note))]
+pub struct BookNoteApiV1;
+
+pub fn get_book_note_scope() -> Scope {
+    web::scope("/v1/books/{book_id}/notes")
+        .service(create_note)
+        .service(get_all_notes_from_book)
+        .service(update_note)
+        .service(delete_note)
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(search_notes))]
+pub struct NoteApiV1;
+
+pub fn get_note_scope() -> Scope {
+    web::scope("/v1/notes").service(search_notes)
+}
@@ -0,0 +1,80 @@
+use crate::modules::{
+    books::{
+        domain::dtos::{
+            get_shared_books_params::GetSharedBooksParams, public_book_dto::PublicBookDto,
+        },
+        infra::repositories::{
+            book_repository_mysql::BookRepositoryMySQL,
+            share_link_repository_mysql::ShareLinkRepositoryMySQL,
+        },
+        usecases::v1::find_all_shared_books_usecase::FindAllSharedBooksUseCaseV1,
+    },
+    shared::{domain::dtos::paginated_dto::PaginatedDto, errors::simple_api_error::SimpleAPIError},
+};
+use actix_web::{get, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+/// Endpoints reachable without authentication, access is granted by the
+/// share link slug in the path.
+pub struct PublicControllerV1 {
+    find_all_shared_books_usecase:
+        FindAllSharedBooksUseCaseV1<ShareLinkRepositoryMySQL, BookRepositoryMySQL>,
+}
+
+impl PublicControllerV1 {
+    pub fn new(
+        share_link_repository: ShareLinkRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+    ) -> Self {
+        PublicControllerV1 {
+            find_all_shared_books_usecase: FindAllSharedBooksUseCaseV1::new(
+                share_link_repository.clone(),
+                book_repository.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "public",
+    summary = "List the books shared by a link",
+    params(
+        ("slug" = String, Path, description = "Slug of the share link"),
+        GetSharedBooksParams,
+    ),
+    responses(
+        (status = 200, description = "Page of shared books", body = PaginatedDto<PublicBookDto>),
+        (status = 400, description = "Invalid parameters", body = SimpleAPIError),
+        (status = 404, description = "Share link not found", body = SimpleAPIError),
+    ),
+    security(()),
+)]
+#[get("/{slug}")]
+async fn get_shared_books(
+    public_controller: web::Data<PublicControllerV1>,
+    path_variables: web::Path<String>,
+    params: web::Query<GetSharedBooksParams>,
+) -> HttpResponse {
+    let params = params.into_inner();
+    match public_controller
+        .find_all_shared_books_usecase
+        .find_all_shared_books(
+            &path_variables.into_inner(),
+            params.page,
+            params.page_size,
+            params.query,
+        )
+        .await
+    {
+        Ok(books_page) => HttpResponse::Ok().json(web::Json(books_page)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(get_shared_books))]
+pub struct PublicApiV1;
+
+pub fn get_public_scope() -> Scope {
+    web::scope("/v1/public").service(get_shared_books)
+}
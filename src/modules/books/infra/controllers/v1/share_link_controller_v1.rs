@@ -0,0 +1,202 @@
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::{
+                create_share_link_dto::CreateShareLinkDto,
+                find_all_share_links_from_user_dto::FindAllShareLinksFromUserDto,
+                share_link_dto::ShareLinkDto,
+            },
+            entities::share_link::ShareLink,
+        },
+        infra::repositories::{
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            location_repository_mysql::LocationRepositoryMySQL,
+            share_link_repository_mysql::ShareLinkRepositoryMySQL,
+        },
+        usecases::v1::{
+            create_share_link_usecase::CreateShareLinkUseCaseV1,
+            find_all_share_links_from_user_usecase::FindAllShareLinksFromUserUseCaseV1,
+            revoke_share_link_usecase::RevokeShareLinkUseCaseV1,
+        },
+    },
+    libraries::{
+        domain::dtos::library_scope_params::LibraryScopeParams,
+        infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    },
+    shared::errors::{
+        detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+    },
+    users::domain::dtos::authed_user::AuthedUser,
+};
+use actix_web::{delete, get, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct ShareLinkControllerV1 {
+    create_share_link_usecase: CreateShareLinkUseCaseV1<
+        ShareLinkRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+    >,
+    find_all_share_links_from_user_usecase:
+        FindAllShareLinksFromUserUseCaseV1<ShareLinkRepositoryMySQL, LibraryRepositoryMySQL>,
+    revoke_share_link_usecase:
+        RevokeShareLinkUseCaseV1<ShareLinkRepositoryMySQL, LibraryRepositoryMySQL>,
+}
+
+impl ShareLinkControllerV1 {
+    pub fn new(
+        share_link_repository: ShareLinkRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
+        ShareLinkControllerV1 {
+            create_share_link_usecase: CreateShareLinkUseCaseV1::new(
+                share_link_repository.clone(),
+                collection_repository.clone(),
+                location_repository.clone(),
+                library_repository.clone(),
+            ),
+            find_all_share_links_from_user_usecase: FindAllShareLinksFromUserUseCaseV1::new(
+                share_link_repository.clone(),
+                library_repository.clone(),
+            ),
+            revoke_share_link_usecase: RevokeShareLinkUseCaseV1::new(
+                share_link_repository.clone(),
+                library_repository.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "share-links",
+    summary = "Share a collection or location through a public link",
+    request_body = CreateShareLinkDto,
+    responses(
+        (status = 201, description = "Share link created", body = ShareLinkDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Shared collection or location not found", body = SimpleAPIError),
+    ),
+)]
+#[post("")]
+async fn create_share_link(
+    share_link_controller: web::Data<ShareLinkControllerV1>,
+    create_share_link_dto: web::Json<CreateShareLinkDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let mut create_share_link_dto = create_share_link_dto.0;
+    create_share_link_dto.user_id = authed_user.id;
+    let library_id = create_share_link_dto.library_id;
+    let share_link = match ShareLink::try_from(create_share_link_dto) {
+        Ok(converted_share_link) => converted_share_link,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match share_link_controller
+        .create_share_link_usecase
+        .create_share_link(share_link, library_id)
+        .await
+    {
+        Ok(share_link) => HttpResponse::Created().json(web::Json(share_link)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "share-links",
+    summary = "List the share links of a library",
+    params(
+        LibraryScopeParams,
+    ),
+    responses(
+        (status = 200, description = "Share links", body = FindAllShareLinksFromUserDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn get_all_share_links_from_user(
+    share_link_controller: web::Data<ShareLinkControllerV1>,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match share_link_controller
+        .find_all_share_links_from_user_usecase
+        .find_all_share_links_from_user(authed_user.id.unwrap(), library_scope_params.library_id)
+        .await
+    {
+        Ok(share_links) => {
+            HttpResponse::Ok().json(web::Json(FindAllShareLinksFromUserDto { share_links }))
+        }
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "share-links",
+    summary = "Revoke a share link",
+    params(
+        ("share_link_id" = u64, Path, description = "Id of the share link"),
+    ),
+    responses(
+        (status = 200, description = "Share link revoked"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Share link not found", body = SimpleAPIError),
+    ),
+)]
+#[delete("/{share_link_id}")]
+async fn revoke_share_link(
+    share_link_controller: web::Data<ShareLinkControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    let path_share_link_id = path_variables.into_inner();
+
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match share_link_controller
+        .revoke_share_link_usecase
+        .revoke_share_link(path_share_link_id, authed_user.id.unwrap())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(create_share_link, get_all_share_links_from_user, revoke_share_link))]
+pub struct ShareLinkApiV1;
+
+pub fn get_share_link_scope() -> Scope {
+    web::scope("/v1/share-links")
+        .service(create_share_link)
+        .service(get_all_share_links_from_user)
+        .service(revoke_share_link)
+}
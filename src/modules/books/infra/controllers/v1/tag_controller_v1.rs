@@ -0,0 +1,209 @@
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::{
+                create_tag_dto::CreateTagDto, find_all_tags_from_user_dto::FindAllTagsFromUserDto,
+                tag_dto::TagDto,
+            },
+            entities::tag::Tag,
+        },
+        infra::repositories::tag_repository_mysql::TagRepositoryMySQL,
+        usecases::v1::{
+            create_tag_usecase::CreateTagUseCaseV1, delete_tag_usecase::DeleteTagUseCaseV1,
+            find_all_tags_from_user_usecase::FindAllTagsFromUserUseCaseV1,
+            rename_tag_usecase::RenameTagUseCaseV1,
+        },
+    },
+    shared::errors::{
+        detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+    },
+    users::domain::dtos::authed_user::AuthedUser,
+};
+use actix_web::{delete, get, post, put, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct TagControllerV1 {
+    create_tag_usecase: CreateTagUseCaseV1<TagRepositoryMySQL>,
+    rename_tag_usecase: RenameTagUseCaseV1<TagRepositoryMySQL>,
+    delete_tag_usecase: DeleteTagUseCaseV1<TagRepositoryMySQL>,
+    find_all_tags_from_user_usecase: FindAllTagsFromUserUseCaseV1<TagRepositoryMySQL>,
+}
+
+impl TagControllerV1 {
+    pub fn new(tag_repository: TagRepositoryMySQL) -> Self {
+        TagControllerV1 {
+            create_tag_usecase: CreateTagUseCaseV1::new(tag_repository.clone()),
+            rename_tag_usecase: RenameTagUseCaseV1::new(tag_repository.clone()),
+            delete_tag_usecase: DeleteTagUseCaseV1::new(tag_repository.clone()),
+            find_all_tags_from_user_usecase: FindAllTagsFromUserUseCaseV1::new(
+                tag_repository.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "tags",
+    summary = "Create a tag",
+    request_body = CreateTagDto,
+    responses(
+        (status = 201, description = "Tag created", body = TagDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 409, description = "Tag already exists", body = SimpleAPIError),
+    ),
+)]
+#[post("")]
+async fn create_tag(
+    tag_controller: web::Data<TagControllerV1>,
+    create_tag_dto: web::Json<CreateTagDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let mut dto = create_tag_dto.into_inner();
+    dto.user_id = authed_user.id;
+    let tag = match Tag::try_from(dto) {
+        Ok(converted_tag) => converted_tag,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match tag_controller.create_tag_usecase.create_tag(tag).await {
+        Ok(tag) => HttpResponse::Created().json(web::Json(TagDto::from(tag))),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "tags",
+    summary = "List the user's tags with their usage",
+    responses(
+        (status = 200, description = "Tags", body = FindAllTagsFromUserDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn get_all_tags_from_user(
+    tag_controller: web::Data<TagControllerV1>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match tag_controller
+        .find_all_tags_from_user_usecase
+        .find_all_tags_from_user(authed_user.id.unwrap())
+        .await
+    {
+        Ok(tags) => HttpResponse::Ok().json(web::Json(FindAllTagsFromUserDto { tags })),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "tags",
+    summary = "Rename a tag",
+    params(
+        ("tag_id" = u64, Path, description = "Id of the tag"),
+    ),
+    request_body = CreateTagDto,
+    responses(
+        (status = 200, description = "Tag renamed", body = TagDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Tag not found", body = SimpleAPIError),
+        (status = 409, description = "Tag already exists", body = SimpleAPIError),
+    ),
+)]
+#[put("/{tag_id}")]
+async fn rename_tag(
+    tag_controller: web::Data<TagControllerV1>,
+    path_variables: web::Path<u64>,
+    rename_tag_dto: web::Json<CreateTagDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let mut dto = rename_tag_dto.into_inner();
+    dto.user_id = authed_user.id;
+    let tag = match Tag::try_from(dto) {
+        Ok(converted_tag) => converted_tag,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match tag_controller
+        .rename_tag_usecase
+        .rename_tag(path_variables.into_inner(), tag)
+        .await
+    {
+        Ok(tag) => HttpResponse::Ok().json(web::Json(TagDto::from(tag))),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "tags",
+    summary = "Delete a tag",
+    params(
+        ("tag_id" = u64, Path, description = "Id of the tag"),
+    ),
+    responses(
+        (status = 200, description = "Tag deleted"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Tag not found", body = SimpleAPIError),
+    ),
+)]
+#[delete("/{tag_id}")]
+async fn delete_tag(
+    tag_controller: web::Data<TagControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match tag_controller
+        .delete_tag_usecase
+        .delete_tag(path_variables.into_inner(), authed_user.id.unwrap())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(create_tag, get_all_tags_from_user, rename_tag, delete_tag))]
+pub struct TagApiV1;
+
+pub fn get_tag_scope() -> Scope {
+    web::scope("/v1/tags")
+        .service(create_tag)
+        .service(get_all_tags_from_user)
+        .service(rename_tag)
+        .service(delete_tag)
+}
@@ -0,0 +1,216 @@
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::find_all_trash_items_dto::FindAllTrashItemsDto, entities::trash::TrashItemType,
+        },
+        infra::repositories::{
+            audit_repository_mysql::AuditRepositoryMySQL,
+            book_repository_mysql::BookRepositoryMySQL,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            location_repository_mysql::LocationRepositoryMySQL,
+            trash_repository_mysql::TrashRepositoryMySQL,
+        },
+        usecases::v1::{
+            find_all_trash_items_usecase::FindAllTrashItemsUseCaseV1,
+            purge_trash_item_usecase::PurgeTrashItemUseCaseV1,
+            restore_trash_item_usecase::RestoreTrashItemUseCaseV1,
+        },
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::dtos::library_scope_params::LibraryScopeParams,
+        infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::domain::dtos::authed_user::AuthedUser,
+};
+use actix_web::{delete, get, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct TrashControllerV1 {
+    find_all_trash_items_usecase:
+        FindAllTrashItemsUseCaseV1<TrashRepositoryMySQL, LibraryRepositoryMySQL>,
+    restore_trash_item_usecase: RestoreTrashItemUseCaseV1<
+        TrashRepositoryMySQL,
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >,
+    purge_trash_item_usecase:
+        PurgeTrashItemUseCaseV1<TrashRepositoryMySQL, LibraryRepositoryMySQL, AuditRepositoryMySQL>,
+}
+
+impl TrashControllerV1 {
+    pub fn new(
+        trash_repository: TrashRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        TrashControllerV1 {
+            find_all_trash_items_usecase: FindAllTrashItemsUseCaseV1::new(
+                trash_repository.clone(),
+                library_repository.clone(),
+            ),
+            restore_trash_item_usecase: RestoreTrashItemUseCaseV1::new(
+                trash_repository.clone(),
+                book_repository,
+                collection_repository,
+                location_repository,
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
+            purge_trash_item_usecase: PurgeTrashItemUseCaseV1::new(
+                trash_repository.clone(),
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "trash",
+    summary = "List the trashed items of a library",
+    params(
+        LibraryScopeParams,
+    ),
+    responses(
+        (status = 200, description = "Trashed items", body = FindAllTrashItemsDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn get_all_trash_items(
+    trash_controller: web::Data<TrashControllerV1>,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match trash_controller
+        .find_all_trash_items_usecase
+        .find_all_trash_items(authed_user.id.unwrap(), library_scope_params.library_id)
+        .await
+    {
+        Ok(items) => HttpResponse::Ok().json(web::Json(FindAllTrashItemsDto { items })),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "trash",
+    summary = "Restore a trashed item",
+    params(
+        ("item_type" = String, Path, description = "Either book, collection or location"),
+        ("item_id" = u64, Path, description = "Id of the item"),
+    ),
+    responses(
+        (status = 200, description = "Item restored"),
+        (status = 400, description = "Invalid parameters", body = SimpleAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Trashed item not found", body = SimpleAPIError),
+        (status = 409, description = "Item can't be restored", body = SimpleAPIError),
+    ),
+)]
+#[post("/{item_type}/{item_id}/restore")]
+async fn restore_trash_item(
+    trash_controller: web::Data<TrashControllerV1>,
+    path: web::Path<(String, u64)>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let (item_type, item_id) = path.into_inner();
+    let item_type = match TrashItemType::try_from(item_type.as_str()) {
+        Ok(item_type) => item_type,
+        Err(error) => {
+            return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(error, 400)));
+        }
+    };
+
+    match trash_controller
+        .restore_trash_item_usecase
+        .restore_trash_item(authed_user.id.unwrap(), item_type, item_id)
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "trash",
+    summary = "Delete a trashed item for good",
+    params(
+        ("item_type" = String, Path, description = "Either book, collection or location"),
+        ("item_id" = u64, Path, description = "Id of the item"),
+    ),
+    responses(
+        (status = 200, description = "Item purged"),
+        (status = 400, description = "Invalid parameters", body = SimpleAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Trashed item not found", body = SimpleAPIError),
+    ),
+)]
+#[delete("/{item_type}/{item_id}")]
+async fn purge_trash_item(
+    trash_controller: web::Data<TrashControllerV1>,
+    path: web::Path<(String, u64)>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let (item_type, item_id) = path.into_inner();
+    let item_type = match TrashItemType::try_from(item_type.as_str()) {
+        Ok(item_type) => item_type,
+        Err(error) => {
+            return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(error, 400)));
+        }
+    };
+
+    match trash_controller
+        .purge_trash_item_usecase
+        .purge_trash_item(authed_user.id.unwrap(), item_type, item_id)
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(get_all_trash_items, restore_trash_item, purge_trash_item))]
+pub struct TrashApiV1;
+
+pub fn get_trash_scope() -> Scope {
+    web::scope("/v1/trash")
+        .service(get_all_trash_items)
+        .service(restore_trash_item)
+        .service(purge_trash_item)
+}
@@ -0,0 +1,4 @@
+pub mod book_csv_exporter;
+pub mod book_exporter;
+pub mod book_json_exporter;
+pub mod book_xlsx_exporter;
@@ -0,0 +1,125 @@
+use std::io;
+
+use csv::Writer;
+
+use crate::modules::books::domain::{
+    dtos::complete_book_dto::CompleteBookDto, entities::book_export::escape_csv_formula,
+};
+
+use super::book_exporter::{book_export_columns, BookExporter, SharedBuffer, BOOK_EXPORT_COLUMNS};
+
+pub struct BookCsvExporter {
+    buffer: SharedBuffer,
+    writer: Writer<SharedBuffer>,
+}
+
+impl BookCsvExporter {
+    pub fn new() -> Self {
+        let buffer = SharedBuffer::default();
+        BookCsvExporter {
+            writer: Writer::from_writer(buffer.clone()),
+            buffer,
+        }
+    }
+}
+
+impl Default for BookCsvExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BookExporter for BookCsvExporter {
+    fn start(&mut self) -> io::Result<Vec<u8>> {
+        self.writer.write_record(BOOK_EXPORT_COLUMNS)?;
+        self.writer.flush()?;
+        Ok(self.buffer.take())
+    }
+
+    fn write_book(&mut self, book: &CompleteBookDto) -> io::Result<Vec<u8>> {
+        self.writer
+            .write_record(book_export_columns(book).map(escape_csv_formula))?;
+        self.writer.flush()?;
+        Ok(self.buffer.take())
+    }
+
+    fn finish(&mut self) -> io::Result<Vec<u8>> {
+        self.writer.flush()?;
+        Ok(self.buffer.take())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::modules::books::domain::dtos::{author_dto::AuthorDto, location_dto::LocationDto};
+
+    use super::*;
+
+    fn export(books: &[CompleteBookDto]) -> String {
+        let mut exporter = BookCsvExporter::new();
+        let mut content = exporter.start().unwrap();
+        for book in books {
+            content.extend(exporter.write_book(book).unwrap());
+        }
+        content.extend(exporter.finish().unwrap());
+        String::from_utf8(content).unwrap()
+    }
+
+    #[test]
+    fn books_are_written_after_the_header() {
+        let book = CompleteBookDto {
+            id: 7,
+            title: "Memórias Póstumas, de Brás Cubas".to_string(),
+            authors: vec![
+                AuthorDto {
+                    name: Some("Machado de Assis".to_string()),
+                    url: None,
+                },
+                AuthorDto {
+                    name: Some("Bruxo".to_string()),
+                    url: None,
+                },
+            ],
+            publisher: "Garnier".to_string(),
+            location: LocationDto {
+                name: "Shelf".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "id,title,authors,publisher,languages,edition,isbn,year,genres,cover,location,collection\n\
+             7,\"Memórias Póstumas, de Brás Cubas\",Machado de Assis; Bruxo,Garnier,,,,,,,Shelf,\n",
+            export(&[book])
+        );
+    }
+
+    #[test]
+    fn cells_read_as_formulas_are_escaped() {
+        let book = CompleteBookDto {
+            title: "=HYPERLINK(\"http://evil.test\")".to_string(),
+            publisher: "@SUM(1)".to_string(),
+            edition: Some("+1".to_string()),
+            isbn: Some("-1".to_string()),
+            year: Some("\t1899".to_string()),
+            cover: Some("\r1899".to_string()),
+            location: LocationDto {
+                name: "Shelf - 2".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let content = export(&[book]);
+        let mut reader = csv::Reader::from_reader(content.as_bytes());
+        let record = reader.records().next().unwrap().unwrap();
+        assert_eq!("'=HYPERLINK(\"http://evil.test\")", &record[1]);
+        assert_eq!("'@SUM(1)", &record[3]);
+        assert_eq!("'+1", &record[5]);
+        assert_eq!("'-1", &record[6]);
+        assert_eq!("'\t1899", &record[7]);
+        assert_eq!("'\r1899", &record[9]);
+        assert_eq!("Shelf - 2", &record[10]);
+    }
+}
@@ -0,0 +1,96 @@
+use std::{cell::RefCell, io, io::Write, rc::Rc};
+
+use crate::modules::books::domain::{
+    dtos::complete_book_dto::CompleteBookDto, entities::book_export::BookExportFormat,
+};
+
+use super::{
+    book_csv_exporter::BookCsvExporter, book_json_exporter::BookJsonExporter,
+    book_xlsx_exporter::BookXlsxExporter,
+};
+
+/// Column names match the fields accepted by the CSV import, so an exported
+/// file can be imported back as is.
+pub const BOOK_EXPORT_COLUMNS: [&str; 12] = [
+    "id",
+    "title",
+    "authors",
+    "publisher",
+    "languages",
+    "edition",
+    "isbn",
+    "year",
+    "genres",
+    "cover",
+    "location",
+    "collection",
+];
+
+pub const BOOK_EXPORT_LIST_SEPARATOR: &str = "; ";
+
+pub trait BookExporter {
+    fn start(&mut self) -> io::Result<Vec<u8>>;
+    fn write_book(&mut self, book: &CompleteBookDto) -> io::Result<Vec<u8>>;
+    fn finish(&mut self) -> io::Result<Vec<u8>>;
+}
+
+pub fn book_exporter_for(format: BookExportFormat) -> Box<dyn BookExporter> {
+    match format {
+        BookExportFormat::Csv => Box::new(BookCsvExporter::new()),
+        BookExportFormat::Json => Box::new(BookJsonExporter::new()),
+        BookExportFormat::Xlsx => Box::new(BookXlsxExporter::new()),
+    }
+}
+
+pub fn book_export_columns(book: &CompleteBookDto) -> [String; 12] {
+    [
+        book.id.to_string(),
+        book.title.clone(),
+        book.authors
+            .iter()
+            .filter_map(|author| author.name.clone())
+            .collect::<Vec<String>>()
+            .join(BOOK_EXPORT_LIST_SEPARATOR),
+        book.publisher.clone(),
+        book.languages
+            .iter()
+            .filter_map(|language| language.name.clone().or(language.code.clone()))
+            .collect::<Vec<String>>()
+            .join(BOOK_EXPORT_LIST_SEPARATOR),
+        book.edition.clone().unwrap_or_default(),
+        book.isbn.clone().unwrap_or_default(),
+        book.year.clone().unwrap_or_default(),
+        book.genres
+            .iter()
+            .flatten()
+            .filter_map(|genre| genre.name.clone())
+            .collect::<Vec<String>>()
+            .join(BOOK_EXPORT_LIST_SEPARATOR),
+        book.cover.clone().unwrap_or_default(),
+        book.location.name.clone(),
+        book.collection
+            .as_ref()
+            .map(|collection| collection.name.clone())
+            .unwrap_or_default(),
+    ]
+}
+
+#[derive(Clone, Default)]
+pub struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl SharedBuffer {
+    pub fn take(&self) -> Vec<u8> {
+        std::mem::take(&mut *self.0.borrow_mut())
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
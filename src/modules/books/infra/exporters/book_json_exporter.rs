@@ -0,0 +1,75 @@
+use std::io;
+
+use crate::modules::books::domain::dtos::complete_book_dto::CompleteBookDto;
+
+use super::book_exporter::BookExporter;
+
+#[derive(Default)]
+pub struct BookJsonExporter {
+    written_books: u64,
+}
+
+impl BookJsonExporter {
+    pub fn new() -> Self {
+        BookJsonExporter { written_books: 0 }
+    }
+}
+
+impl BookExporter for BookJsonExporter {
+    fn start(&mut self) -> io::Result<Vec<u8>> {
+        Ok(b"[".to_vec())
+    }
+
+    fn write_book(&mut self, book: &CompleteBookDto) -> io::Result<Vec<u8>> {
+        let mut chunk = if self.written_books > 0 {
+            b",".to_vec()
+        } else {
+            Vec::new()
+        };
+        serde_json::to_writer(&mut chunk, book)?;
+        self.written_books += 1;
+        Ok(chunk)
+    }
+
+    fn finish(&mut self) -> io::Result<Vec<u8>> {
+        Ok(b"]".to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    #[test]
+    fn books_are_written_as_an_array() {
+        let mut exporter = BookJsonExporter::new();
+        let mut content = exporter.start().unwrap();
+        for (id, title) in [(1, "Helena"), (2, "=Iaiá Garcia")] {
+            let book = CompleteBookDto {
+                id,
+                title: title.to_string(),
+                ..Default::default()
+            };
+            content.extend(exporter.write_book(&book).unwrap());
+        }
+        content.extend(exporter.finish().unwrap());
+
+        let books: Value = serde_json::from_slice(&content).unwrap();
+        assert_eq!(
+            json!(["Helena", "=Iaiá Garcia"]),
+            json!([books[0]["title"], books[1]["title"]])
+        );
+        assert_eq!(2, books.as_array().unwrap().len());
+    }
+
+    #[test]
+    fn an_empty_export_is_an_empty_array() {
+        let mut exporter = BookJsonExporter::new();
+        let mut content = exporter.start().unwrap();
+        content.extend(exporter.finish().unwrap());
+
+        assert_eq!(b"[]".to_vec(), content);
+    }
+}
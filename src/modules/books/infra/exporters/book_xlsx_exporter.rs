@@ -0,0 +1,180 @@
+use std::io::{self, Write};
+
+use zip::{
+    write::{SimpleFileOptions, StreamWriter},
+    CompressionMethod, ZipWriter,
+};
+
+use crate::modules::books::domain::dtos::complete_book_dto::CompleteBookDto;
+
+use super::book_exporter::{book_export_columns, BookExporter, SharedBuffer, BOOK_EXPORT_COLUMNS};
+
+const CONTENT_TYPES_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/></Types>"#;
+
+const ROOT_RELS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+
+const WORKBOOK_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="Books" sheetId="1" r:id="rId1"/></sheets></workbook>"#;
+
+const WORKBOOK_RELS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/></Relationships>"#;
+
+const SHEET_START_XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>"#;
+
+const SHEET_END_XML: &str = "</sheetData></worksheet>";
+
+pub struct BookXlsxExporter {
+    buffer: SharedBuffer,
+    writer: Option<ZipWriter<StreamWriter<SharedBuffer>>>,
+}
+
+impl BookXlsxExporter {
+    pub fn new() -> Self {
+        let buffer = SharedBuffer::default();
+        BookXlsxExporter {
+            writer: Some(ZipWriter::new_stream(buffer.clone())),
+            buffer,
+        }
+    }
+
+    fn writer(&mut self) -> io::Result<&mut ZipWriter<StreamWriter<SharedBuffer>>> {
+        self.writer
+            .as_mut()
+            .ok_or_else(|| io::Error::other("The export was already finished"))
+    }
+}
+
+impl Default for BookXlsxExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BookExporter for BookXlsxExporter {
+    fn start(&mut self) -> io::Result<Vec<u8>> {
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        let writer = self.writer()?;
+        for (name, content) in [
+            ("[Content_Types].xml", CONTENT_TYPES_XML),
+            ("_rels/.rels", ROOT_RELS_XML),
+            ("xl/workbook.xml", WORKBOOK_XML),
+            ("xl/_rels/workbook.xml.rels", WORKBOOK_RELS_XML),
+        ] {
+            writer.start_file(name, options)?;
+            writer.write_all(content.as_bytes())?;
+        }
+        writer.start_file("xl/worksheets/sheet1.xml", options)?;
+        writer.write_all(SHEET_START_XML.as_bytes())?;
+        writer.write_all(row_xml(&BOOK_EXPORT_COLUMNS.map(str::to_string)).as_bytes())?;
+        Ok(self.buffer.take())
+    }
+
+    fn write_book(&mut self, book: &CompleteBookDto) -> io::Result<Vec<u8>> {
+        let row = row_xml(&book_export_columns(book));
+        self.writer()?.write_all(row.as_bytes())?;
+        Ok(self.buffer.take())
+    }
+
+    fn finish(&mut self) -> io::Result<Vec<u8>> {
+        let mut writer = match self.writer.take() {
+            Some(writer) => writer,
+            None => return Ok(Vec::new()),
+        };
+        writer.write_all(SHEET_END_XML.as_bytes())?;
+        writer.finish()?;
+        Ok(self.buffer.take())
+    }
+}
+
+fn row_xml(columns: &[String]) -> String {
+    let mut row = String::from("<row>");
+    for value in columns.iter() {
+        row.push_str(r#"<c t="inlineStr"><is><t xml:space="preserve">"#);
+        row.push_str(&escape_xml(value));
+        row.push_str("</t></is></c>");
+    }
+    row.push_str("</row>");
+    row
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(character),
+            character if character.is_control() => {}
+            character => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use zip::ZipArchive;
+
+    use super::*;
+
+    fn export(books: &[CompleteBookDto]) -> ZipArchive<Cursor<Vec<u8>>> {
+        let mut exporter = BookXlsxExporter::new();
+        let mut content = exporter.start().unwrap();
+        for book in books {
+            content.extend(exporter.write_book(book).unwrap());
+        }
+        content.extend(exporter.finish().unwrap());
+        ZipArchive::new(Cursor::new(content)).unwrap()
+    }
+
+    fn sheet(archive: &mut ZipArchive<Cursor<Vec<u8>>>) -> String {
+        let mut sheet = String::new();
+        archive
+            .by_name("xl/worksheets/sheet1.xml")
+            .unwrap()
+            .read_to_string(&mut sheet)
+            .unwrap();
+        sheet
+    }
+
+    #[test]
+    fn the_workbook_parts_are_written() {
+        let mut archive = export(&[]);
+
+        for name in [
+            "[Content_Types].xml",
+            "_rels/.rels",
+            "xl/workbook.xml",
+            "xl/_rels/workbook.xml.rels",
+        ] {
+            assert!(archive.by_name(name).is_ok(), "{} is missing", name);
+        }
+        let sheet = sheet(&mut archive);
+        assert!(sheet.contains(r#"<t xml:space="preserve">collection</t>"#));
+        assert!(sheet.ends_with(SHEET_END_XML));
+    }
+
+    #[test]
+    fn cells_are_inline_strings_with_escaped_markup() {
+        let book = CompleteBookDto {
+            title: "<b>Dom & \"Casmurro\"</b>\u{0}".to_string(),
+            publisher: "=SUM(1)".to_string(),
+            ..Default::default()
+        };
+
+        let sheet = sheet(&mut export(&[book]));
+        assert!(sheet.contains(
+            r#"<t xml:space="preserve">&lt;b&gt;Dom &amp; &quot;Casmurro&quot;&lt;/b&gt;</t>"#
+        ));
+        // Inline strings are never evaluated, formulas are kept as written
+        assert!(sheet.contains(r#"<t xml:space="preserve">=SUM(1)</t>"#));
+        assert!(!sheet.contains("<f>"));
+    }
+}
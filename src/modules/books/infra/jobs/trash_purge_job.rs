@@ -0,0 +1,57 @@
+use std::{sync::Arc, time::Duration};
+
+use sqlx::MySqlPool;
+use tokio::task::JoinHandle;
+
+use crate::{
+    configuration::TrashSettings,
+    modules::{
+        books::{
+            infra::repositories::{
+                audit_repository_mysql::AuditRepositoryMySQL,
+                trash_repository_mysql::TrashRepositoryMySQL,
+            },
+            usecases::v1::purge_expired_trash_usecase::PurgeExpiredTrashUseCaseV1,
+        },
+        events::infra::buses::change_event_bus::ChangeEventBus,
+        shared::errors::APIError,
+    },
+};
+
+pub fn spawn_trash_purge_job(
+    db_pool: Arc<MySqlPool>,
+    trash_settings: TrashSettings,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        // The job runs apart from the server, so nobody streams from its bus
+        let purge_expired_trash_usecase = PurgeExpiredTrashUseCaseV1::new(
+            TrashRepositoryMySQL::new(db_pool.clone()),
+            AuditRepositoryMySQL::new(db_pool),
+            ChangeEventBus::new(),
+        );
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(trash_settings.purge_interval_seconds));
+
+        loop {
+            interval.tick().await;
+            match purge_expired_trash_usecase
+                .purge_expired_trash(trash_settings.retention_days)
+                .await
+            {
+                Ok(0) => {}
+                Ok(n_of_purged_items) => {
+                    tracing::info!("{} expired items purged from the trash", n_of_purged_items)
+                }
+                Err(APIError::SimpleAPIError(error)) => {
+                    tracing::error!("Failed to purge the trash: {}", error.msg)
+                }
+                Err(APIError::DetailedAPIError(error)) => {
+                    tracing::error!("Failed to purge the trash: {}", error.msg)
+                }
+                Err(APIError::PreconditionFailedAPIError(error)) => {
+                    tracing::error!("Failed to purge the trash: {}", error.msg)
+                }
+            }
+        }
+    })
+}
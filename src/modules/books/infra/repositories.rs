@@ -1,6 +1,18 @@
+pub mod audit_repository;
+pub mod audit_repository_mysql;
 pub mod book_repository;
 pub mod book_repository_mysql;
 pub mod collection_repository;
 pub mod collection_repository_mysql;
+pub mod custom_field_repository;
+pub mod custom_field_repository_mysql;
 pub mod location_repository;
 pub mod location_repository_mysql;
+pub mod note_repository;
+pub mod note_repository_mysql;
+pub mod share_link_repository;
+pub mod share_link_repository_mysql;
+pub mod tag_repository;
+pub mod tag_repository_mysql;
+pub mod trash_repository;
+pub mod trash_repository_mysql;
@@ -0,0 +1,22 @@
+use crate::modules::books::domain::{
+    dtos::audit_entry_dto::AuditEntryDto,
+    entities::audit_entry::{AuditEntityType, AuditEntry},
+};
+use sqlx::Error;
+use std::future::Future;
+
+pub trait AuditRepository {
+    fn save_all(&self, entries: &[AuditEntry]) -> impl Future<Output = Result<(), Error>> + Send;
+    fn find_by_id(&self, id: u64)
+        -> impl Future<Output = Result<Option<AuditEntry>, Error>> + Send;
+    fn find_all_by_entity(
+        &self,
+        entity_type: AuditEntityType,
+        entity_id: u64,
+    ) -> impl Future<Output = Result<Vec<AuditEntryDto>, Error>> + Send;
+    fn find_all_unpublished(
+        &self,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<AuditEntry>, Error>> + Send;
+    fn mark_published(&self, ids: &[u64]) -> impl Future<Output = Result<(), Error>> + Send;
+}
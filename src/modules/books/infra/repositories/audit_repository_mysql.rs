@@ -0,0 +1,160 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{mysql::MySqlRow, MySqlConnection, MySqlPool, Row};
+use std::sync::Arc;
+
+use crate::modules::books::domain::{
+    dtos::audit_entry_dto::AuditEntryDto,
+    entities::audit_entry::{AuditAction, AuditEntityType, AuditEntry},
+};
+
+use super::audit_repository::AuditRepository;
+
+#[derive(Clone)]
+pub struct AuditRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl AuditRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        AuditRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn in_clause_placeholders(n_of_items: usize) -> String {
+    vec!["?"; n_of_items].join(", ")
+}
+
+pub async fn insert_audit_entries(
+    connection: &mut MySqlConnection,
+    entries: &[AuditEntry],
+) -> Result<(), sqlx::Error> {
+    for entry in entries.iter() {
+        sqlx::query(
+            r#"
+            INSERT INTO audit_entries
+                (id, entity_type, entity_id, library_id, actor_id, action, changes, snapshot)
+            VALUES (DEFAULT, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(entry.entity_type.as_str())
+        .bind(entry.entity_id)
+        .bind(entry.library_id)
+        .bind(entry.actor_id)
+        .bind(entry.action.as_str())
+        .bind(Value::Object(entry.changes.clone()))
+        .bind(&entry.snapshot)
+        .execute(&mut *connection)
+        .await?;
+    }
+    Ok(())
+}
+
+fn audit_entry_from_row(row: &MySqlRow) -> AuditEntry {
+    let entity_type: String = row.get("entity_type");
+    let action: String = row.get("action");
+    let changes: Value = row.get("changes");
+    AuditEntry {
+        id: Some(row.get("id")),
+        entity_type: AuditEntityType::try_from(entity_type.as_str())
+            .unwrap_or(AuditEntityType::Book),
+        entity_id: row.get("entity_id"),
+        library_id: row.get("library_id"),
+        actor_id: row.get("actor_id"),
+        action: AuditAction::try_from(action.as_str()).unwrap_or(AuditAction::Update),
+        changes: match changes {
+            Value::Object(changes) => changes,
+            _ => Default::default(),
+        },
+        snapshot: row.get("snapshot"),
+        created_at: Some(row.get::<DateTime<Utc>, &str>("created_at")),
+    }
+}
+
+impl AuditRepository for AuditRepositoryMySQL {
+    async fn save_all(&self, entries: &[AuditEntry]) -> Result<(), sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+        insert_audit_entries(&mut transaction, entries).await?;
+        transaction.commit().await
+    }
+
+    async fn find_by_id(&self, id: u64) -> Result<Option<AuditEntry>, sqlx::Error> {
+        let query_result = sqlx::query("SELECT * FROM audit_entries a WHERE a.id = ?")
+            .bind(id)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(audit_entry_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_entity(
+        &self,
+        entity_type: AuditEntityType,
+        entity_id: u64,
+    ) -> Result<Vec<AuditEntryDto>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT a.*, COALESCE(u.name, '') AS actor_name
+            FROM audit_entries a
+            LEFT JOIN users u ON u.id = a.actor_id
+            WHERE a.entity_type = ? AND a.entity_id = ?
+            ORDER BY a.created_at DESC, a.id DESC
+            "#,
+        )
+        .bind(entity_type.as_str())
+        .bind(entity_id)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows
+                .iter()
+                .map(|row| AuditEntryDto {
+                    actor_name: row.get("actor_name"),
+                    ..AuditEntryDto::from(audit_entry_from_row(row))
+                })
+                .collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_unpublished(&self, limit: u64) -> Result<Vec<AuditEntry>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT *
+            FROM audit_entries a
+            WHERE a.published_at IS NULL
+            ORDER BY a.id ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(audit_entry_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn mark_published(&self, ids: &[u64]) -> Result<(), sqlx::Error> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let query = format!(
+            "UPDATE audit_entries SET published_at = CURRENT_TIMESTAMP WHERE id IN ({})",
+            in_clause_placeholders(ids.len())
+        );
+        let mut query_builder = sqlx::query(&query);
+        for id in ids.iter() {
+            query_builder = query_builder.bind(id);
+        }
+        match query_builder.execute(self.connection.as_ref()).await {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
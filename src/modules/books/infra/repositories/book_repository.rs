@@ -1,35 +1,132 @@
-use futures_util::Future;
+use futures_util::{stream::BoxStream, Future};
 use sqlx::Error;
+use std::collections::HashMap;
 
 use crate::modules::{
-    books::domain::{dtos::complete_book_dto::CompleteBookDto, entities::book::Book},
+    books::domain::{
+        dtos::{
+            bulk_book_operation_result_dto::BulkBookOperationResultDto,
+            complete_book_dto::CompleteBookDto,
+            library_restore_report_dto::LibraryRestoreReportDto,
+        },
+        entities::{
+            audit_entry::AuditEntry, book::Book, book_cursor::BookCursor,
+            book_import::BookImportRow, book_listing::BookListingOptions,
+            bulk_book_operation::BulkBookAction, library_backup::LibraryRestore,
+        },
+    },
     shared::domain::dtos::paginated_dto::PaginatedDto,
 };
 
 pub trait BookRepository {
+    /// Updates are only saved while the book is at `book.version`, `None`
+    /// is returned otherwise.
     fn save(&self, location: &Book) -> impl Future<Output = Result<Option<Book>, Error>> + Send;
     fn find_by_id(&self, id: u64) -> impl Future<Output = Result<Option<Book>, Error>> + Send;
-    fn find_by_title(
+    fn find_by_title_and_library_id(
         &self,
         title: &str,
+        library_id: u64,
     ) -> impl Future<Output = Result<Option<Book>, Error>> + Send;
-    fn find_all_by_user_id_as_complete_book_dto(
+    #[allow(clippy::too_many_arguments)]
+    fn find_all_by_library_id_as_complete_book_dto(
         &self,
-        user_id: u64,
+        library_id: u64,
         page: u64,
         page_size: u64,
         collection_id: Option<i64>,
         location_id: Option<i64>,
         query: Option<String>,
+        listing_options: &BookListingOptions,
+    ) -> impl Future<Output = Result<PaginatedDto<CompleteBookDto>, Error>> + Send;
+    /// Pages through the books ordered by title and id, starting right after
+    /// or right before the cursor, or at the first book when there is none.
+    /// Ordering by custom fields isn't supported in this mode.
+    #[allow(clippy::too_many_arguments)]
+    fn find_all_by_library_id_as_complete_book_dto_by_cursor(
+        &self,
+        library_id: u64,
+        cursor: Option<&BookCursor>,
+        page_size: u64,
+        collection_id: Option<i64>,
+        location_id: Option<i64>,
+        query: Option<String>,
+        listing_options: &BookListingOptions,
+        count_total: bool,
     ) -> impl Future<Output = Result<PaginatedDto<CompleteBookDto>, Error>> + Send;
     fn find_by_id_as_complete_book_dto(
         &self,
-        user_id: u64,
+        library_id: u64,
         book_id: u64,
     ) -> impl Future<Output = Result<Option<CompleteBookDto>, Error>> + Send;
+    /// Returns `false` when the book doesn't exist or is no longer at the
+    /// informed version.
     fn delete_by_id(
         &self,
-        user_id: u64,
+        library_id: u64,
         book_id: u64,
+        version: u64,
     ) -> impl Future<Output = Result<bool, Error>> + Send;
+    fn find_all_ids_by_library_id(
+        &self,
+        library_id: u64,
+        pagination: Option<(u64, u64)>,
+        collection_id: Option<i64>,
+        location_id: Option<i64>,
+        query: Option<String>,
+        listing_options: &BookListingOptions,
+    ) -> impl Future<Output = Result<Vec<u64>, Error>> + Send;
+    fn execute_bulk_operation(
+        &self,
+        library_id: u64,
+        book_ids: &[u64],
+        action: &BulkBookAction,
+    ) -> impl Future<Output = Result<BulkBookOperationResultDto, Error>> + Send;
+    fn find_all_by_library_id(
+        &self,
+        library_id: u64,
+    ) -> impl Future<Output = Result<Vec<Book>, Error>> + Send;
+    fn find_all_by_ids(
+        &self,
+        library_id: u64,
+        book_ids: &[u64],
+    ) -> impl Future<Output = Result<Vec<Book>, Error>> + Send;
+    fn import_books(
+        &self,
+        library_id: u64,
+        user_id: u64,
+        rows: &[BookImportRow],
+    ) -> impl Future<Output = Result<Vec<u64>, Error>> + Send;
+    fn stream_all_by_library_id_as_complete_book_dto(
+        &self,
+        library_id: u64,
+        collection_id: Option<i64>,
+        location_id: Option<i64>,
+        query: Option<String>,
+        listing_options: BookListingOptions,
+    ) -> BoxStream<'static, Result<CompleteBookDto, Error>>;
+    fn restore_library(
+        &self,
+        library_id: u64,
+        user_id: u64,
+        library_restore: &LibraryRestore,
+    ) -> impl Future<Output = Result<(LibraryRestoreReportDto, Vec<AuditEntry>), Error>> + Send;
+    fn find_all_by_ids_as_complete_book_dto(
+        &self,
+        library_id: u64,
+        book_ids: &[u64],
+    ) -> impl Future<Output = Result<Vec<CompleteBookDto>, Error>> + Send;
+    fn find_by_isbns_as_complete_book_dto(
+        &self,
+        library_id: u64,
+        isbns: &[String],
+    ) -> impl Future<Output = Result<Option<CompleteBookDto>, Error>> + Send;
+    fn count_by_collection_ids(
+        &self,
+        collection_ids: &[u64],
+    ) -> impl Future<Output = Result<HashMap<u64, u64>, Error>> + Send;
+    fn count_by_location_ids(
+        &self,
+        location_ids: &[u64],
+    ) -> impl Future<Output = Result<HashMap<u64, u64>, Error>> + Send;
 }
@@ -1,20 +1,52 @@
-use serde_json::Value;
-use sqlx::{MySqlPool, Row};
-use std::sync::Arc;
+use async_stream::try_stream;
+use futures_util::{stream::BoxStream, TryStreamExt};
+use serde_json::{json, Value};
+use sqlx::{
+    mysql::{MySqlArguments, MySqlRow},
+    query::Query,
+    MySql, MySqlPool, Row,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 use tracing::info;
 
 use crate::modules::{
     books::domain::{
         dtos::{
+            bulk_book_item_result_dto::BulkBookItemResultDto,
+            bulk_book_operation_result_dto::BulkBookOperationResultDto,
             collection_dto::CollectionDto, complete_book_dto::CompleteBookDto, genre_dto::GenreDto,
-            location_dto::LocationDto,
+            library_restore_report_dto::LibraryRestoreReportDto, location_dto::LocationDto,
+        },
+        entities::{
+            audit_entry::{
+                book_audit_snapshot, collection_audit_snapshot, location_audit_snapshot,
+                AuditAction, AuditEntityType, AuditEntry,
+            },
+            book::Book,
+            book_cursor::BookCursor,
+            book_import::{normalize_isbn, BookImportRow},
+            book_listing::BookListingOptions,
+            bulk_book_operation::BulkBookAction,
+            custom_field::{
+                BookCustomFieldValue, CustomField, CustomFieldCondition, CustomFieldSort,
+                CustomFieldType, CustomFieldValue,
+            },
+            genre::Genre,
+            library_backup::{LibraryRestore, LibraryRestoreMode},
+            tag::TagMatch,
         },
-        entities::{book::Book, genre::Genre},
     },
     shared::domain::dtos::paginated_dto::PaginatedDto,
 };
 
-use super::book_repository::BookRepository;
+use super::{
+    audit_repository_mysql::insert_audit_entries,
+    book_repository::BookRepository,
+    custom_field_repository_mysql::{custom_field_from_row, insert_book_custom_field_value},
+};
 
 #[derive(Clone)]
 pub struct BookRepositoryMySQL {
@@ -27,6 +59,93 @@ impl BookRepositoryMySQL {
             connection: db_pool.clone(),
         }
     }
+
+    /// Counts the books grouped by one of their foreign keys, `column` is
+    /// never user input.
+    async fn count_grouped_by(
+        &self,
+        column: &str,
+        ids: &[u64],
+    ) -> Result<HashMap<u64, u64>, sqlx::Error> {
+        let mut counts = HashMap::new();
+        if ids.is_empty() {
+            return Ok(counts);
+        }
+        let query = format!(
+            r#"
+            SELECT b.{column} 'id', COUNT(*) as n_books
+            FROM books b
+            WHERE b.deleted_at IS NULL AND b.{column} IN ({})
+            GROUP BY b.{column}
+            "#,
+            in_clause_placeholders(ids.len())
+        );
+        let mut query_builder = sqlx::query(&query);
+        for id in ids.iter() {
+            query_builder = query_builder.bind(id);
+        }
+        match query_builder.fetch_all(self.connection.as_ref()).await {
+            Ok(rows) => {
+                for row in rows.iter() {
+                    let n_books: i64 = row.get("n_books");
+                    counts.insert(row.get("id"), u64::from_ne_bytes(n_books.to_ne_bytes()));
+                }
+                Ok(counts)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn count_by_library_id(
+        &self,
+        library_id: u64,
+        collection_id: Option<i64>,
+        location_id: Option<i64>,
+        query: Option<&str>,
+        listing_options: &BookListingOptions,
+    ) -> Result<u64, sqlx::Error> {
+        let mut count_query = r#"
+        SELECT COUNT(*) as n_books
+            FROM books b
+                INNER JOIN ( 
+                    SELECT u.id
+                        FROM books u
+                        WHERE u.library_id = ? AND u.deleted_at IS NULL
+                                "#
+        .to_string();
+        count_query.push_str(&book_listing_conditional(
+            collection_id,
+            location_id,
+            query,
+            listing_options,
+        ));
+        count_query.push_str(
+            r#"
+                ) as p USING (id)
+                    INNER JOIN locations as l
+                        ON l.id = b.location_id
+                    LEFT JOIN collections as c
+                        ON c.id = b.collection_id AND c.deleted_at IS NULL
+        "#,
+        );
+
+        let count_query_ps = bind_book_listing(
+            sqlx::query(&count_query),
+            library_id,
+            collection_id,
+            location_id,
+            query,
+            listing_options,
+        );
+        match count_query_ps.fetch_one(self.connection.as_ref()).await {
+            Ok(counting_query_result_value) => {
+                let n_books: i64 = counting_query_result_value.get(0);
+                info!("{} books returned", n_books);
+                Ok(u64::from_ne_bytes(n_books.to_ne_bytes()))
+            }
+            Err(e) => Err(e),
+        }
+    }
 }
 
 const COLLECTION_ID_CONDITIONAL: &str = "AND u.collection_id = ? \n";
@@ -41,13 +160,302 @@ const QUERY_CONDTIONAL: &str = r#"
     )
 "#;
 
+/// Column order matters, rows are mapped by index in `complete_book_dto_from_row`.
+const COMPLETE_BOOK_DTO_SELECT: &str = r#"
+        SELECT 
+            b.id 'book_id',
+            b.title 'book_title',
+            b.authors 'book_authors',
+            b.publisher 'book_publisher',
+            b.languages 'book_languages',
+            b.edition 'book_edition',
+            b.isbn 'book_isbn',
+            b.year 'book_year',
+            b.genres 'book_genres',
+            b.cover 'book_cover',
+            b.user_id 'book_user_id',
+            l.id 'location_id',
+            l.name 'location_name',
+            l.user_id 'location_user_id',
+            c.id 'collection_id',
+            c.name 'collection_name',
+            c.user_id 'collection_user_id',
+            b.library_id 'book_library_id',
+            l.library_id 'location_library_id',
+            c.library_id 'collection_library_id',
+            b.version 'book_version',
+            l.version 'location_version',
+            c.version 'collection_version'
+            FROM books b
+                INNER JOIN ( 
+                    SELECT u.id
+                        FROM books u
+                        WHERE u.library_id = ? AND u.deleted_at IS NULL
+"#;
+
+/// Conditionals shared by every book listing, their values are bound in the
+/// same order by `bind_book_listing`.
+fn book_listing_conditional(
+    collection_id: Option<i64>,
+    location_id: Option<i64>,
+    query: Option<&str>,
+    listing_options: &BookListingOptions,
+) -> String {
+    let mut conditional = String::new();
+    if query.is_some() {
+        conditional.push_str(QUERY_CONDTIONAL);
+    }
+    if location_id.is_some() {
+        conditional.push_str(LOCATION_ID_CONDITIONAL);
+    }
+    if collection_id.is_some() {
+        conditional.push_str(COLLECTION_ID_CONDITIONAL);
+    }
+    conditional.push_str(&listing_filters_conditional(listing_options));
+    conditional
+}
+
+fn bind_book_listing<'q>(
+    mut query_ps: Query<'q, MySql, MySqlArguments>,
+    library_id: u64,
+    collection_id: Option<i64>,
+    location_id: Option<i64>,
+    query: Option<&str>,
+    listing_options: &BookListingOptions,
+) -> Query<'q, MySql, MySqlArguments> {
+    query_ps = query_ps.bind(library_id);
+    if let Some(query) = query {
+        let lowercase_query = query.to_lowercase();
+        query_ps = query_ps
+            .bind(lowercase_query.clone())
+            .bind(lowercase_query.clone())
+            .bind(lowercase_query.clone())
+            .bind(lowercase_query.clone())
+            .bind(lowercase_query);
+    }
+    if let Some(location_id) = location_id {
+        query_ps = query_ps.bind(location_id);
+    }
+    if let Some(collection_id) = collection_id {
+        query_ps = query_ps.bind(collection_id);
+    }
+    bind_listing_filters(query_ps, listing_options)
+}
+
+fn in_clause_placeholders(n_of_items: usize) -> String {
+    vec!["?"; n_of_items].join(", ")
+}
+
+/// Conditionals of the tag and custom field filters, their values are bound
+/// in the same order by `bind_listing_filters`.
+fn listing_filters_conditional(listing_options: &BookListingOptions) -> String {
+    let mut conditional = String::new();
+    // Books carrying any of the tags, or all of them when the matching book
+    // must be grouped back to the number of informed tags
+    if let Some(tag_filter) = &listing_options.tag_filter {
+        conditional.push_str(&format!(
+            r#"
+    AND u.id IN (
+        SELECT bt.book_id
+            FROM book_tags bt
+                INNER JOIN tags t
+                    ON t.id = bt.tag_id
+            WHERE t.user_id = ?
+                AND bt.tag_id IN ({})
+"#,
+            in_clause_placeholders(tag_filter.tag_ids.len())
+        ));
+        if tag_filter.tag_match == TagMatch::All {
+            conditional.push_str("GROUP BY bt.book_id HAVING COUNT(DISTINCT bt.tag_id) = ? \n");
+        }
+        conditional.push_str(")\n");
+    }
+    if let Some(custom_field_filter) = &listing_options.custom_field_filter {
+        let column = custom_field_filter.field_type.value_column();
+        conditional.push_str(
+            r#"
+    AND u.id IN (
+        SELECT fv.book_id
+            FROM book_custom_field_values fv
+            WHERE fv.custom_field_id = ?
+"#,
+        );
+        match &custom_field_filter.condition {
+            CustomFieldCondition::Equals(_) => {
+                conditional.push_str(&format!("AND fv.{} = ? \n", column));
+            }
+            CustomFieldCondition::Between(min, max) => {
+                if min.is_some() {
+                    conditional.push_str(&format!("AND fv.{} >= ? \n", column));
+                }
+                if max.is_some() {
+                    conditional.push_str(&format!("AND fv.{} <= ? \n", column));
+                }
+            }
+        }
+        conditional.push_str(")\n");
+    }
+    conditional
+}
+
+fn bind_listing_filters<'q>(
+    mut query: Query<'q, MySql, MySqlArguments>,
+    listing_options: &BookListingOptions,
+) -> Query<'q, MySql, MySqlArguments> {
+    if let Some(tag_filter) = &listing_options.tag_filter {
+        query = query.bind(tag_filter.user_id);
+        for tag_id in tag_filter.tag_ids.iter() {
+            query = query.bind(*tag_id);
+        }
+        if tag_filter.tag_match == TagMatch::All {
+            query = query.bind(tag_filter.tag_ids.len() as u64);
+        }
+    }
+    if let Some(custom_field_filter) = &listing_options.custom_field_filter {
+        query = query.bind(custom_field_filter.custom_field_id);
+        match &custom_field_filter.condition {
+            CustomFieldCondition::Equals(value) => {
+                query = bind_custom_field_value(query, value);
+            }
+            CustomFieldCondition::Between(min, max) => {
+                for value in [min, max].into_iter().flatten() {
+                    query = bind_custom_field_value(query, value);
+                }
+            }
+        }
+    }
+    query
+}
+
+fn bind_custom_field_value<'q>(
+    query: Query<'q, MySql, MySqlArguments>,
+    value: &CustomFieldValue,
+) -> Query<'q, MySql, MySqlArguments> {
+    match value {
+        CustomFieldValue::Text(text) => query.bind(text.clone()),
+        CustomFieldValue::Number(number) => query.bind(*number),
+        CustomFieldValue::Date(date) => query.bind(*date),
+        CustomFieldValue::Boolean(boolean) => query.bind(*boolean),
+    }
+}
+
+/// Books are ordered by title unless a custom field is informed, then books
+/// without a value for it come last. The custom field id is bound twice by
+/// `bind_listing_order`.
+fn listing_order_clause(book_alias: &str, custom_field_sort: Option<&CustomFieldSort>) -> String {
+    match custom_field_sort {
+        Some(custom_field_sort) => {
+            let sort_value = format!(
+                "(SELECT sv.{} FROM book_custom_field_values sv WHERE sv.book_id = {}.id AND sv.custom_field_id = ?)",
+                custom_field_sort.field_type.value_column(),
+                book_alias
+            );
+            format!(
+                "ORDER BY {} IS NULL, {} {}, {}.title ASC \n",
+                sort_value,
+                sort_value,
+                if custom_field_sort.descending {
+                    "DESC"
+                } else {
+                    "ASC"
+                },
+                book_alias
+            )
+        }
+        None => format!("ORDER BY {}.title ASC \n", book_alias),
+    }
+}
+
+fn bind_listing_order<'q>(
+    query: Query<'q, MySql, MySqlArguments>,
+    custom_field_sort: Option<&CustomFieldSort>,
+) -> Query<'q, MySql, MySqlArguments> {
+    match custom_field_sort {
+        Some(custom_field_sort) => query
+            .bind(custom_field_sort.custom_field_id)
+            .bind(custom_field_sort.custom_field_id),
+        None => query,
+    }
+}
+
+fn book_from_row(row: &MySqlRow) -> Book {
+    let genres: Option<Value> = row.get("genres");
+    Book {
+        id: Some(row.get("id")),
+        title: row.get("title"),
+        authors: serde_json::from_value(row.get("authors")).unwrap(),
+        publisher: row.get("publisher"),
+        languages: serde_json::from_value(row.get("languages")).unwrap(),
+        edition: row.get("edition"),
+        isbn: row.get("isbn"),
+        year: row.get("year"),
+        genres: genres.map(|genre_value| serde_json::from_value(genre_value).unwrap()),
+        cover: row.get("cover"),
+        collection_id: row.get("collection_id"),
+        location_id: row.get("location_id"),
+        user_id: row.get("user_id"),
+        library_id: row.get("library_id"),
+        version: row.get("version"),
+        tag_ids: None,
+        custom_fields: None,
+    }
+}
+
+fn complete_book_dto_from_row(item: &MySqlRow) -> CompleteBookDto {
+    let mut genres: Option<Vec<GenreDto>> = None;
+    let book_genre: Option<Value> = item.get(8);
+    if let Some(genre_value) = book_genre {
+        let genre_vec: Vec<Genre> = serde_json::from_value(genre_value).unwrap();
+        let genre_dto_vec: Vec<GenreDto> = genre_vec.into_iter().map(GenreDto::from).collect();
+        genres = Some(genre_dto_vec);
+    }
+    let mut collection: Option<CollectionDto> = None;
+    let book_collection_id: Option<u64> = item.get(14);
+    if book_collection_id.is_some() {
+        collection = Some(CollectionDto {
+            id: item.get(14),
+            name: item
+                .get::<Option<String>, usize>(15)
+                .unwrap_or("".to_string()),
+            user_id: item.get::<Option<u64>, usize>(16).unwrap(),
+            library_id: item.get::<Option<u64>, usize>(19).unwrap(),
+            version: item.get::<Option<u64>, usize>(22).unwrap(),
+        })
+    }
+    CompleteBookDto {
+        id: item.get(0),
+        title: item.get(1),
+        authors: serde_json::from_value(item.get(2)).unwrap(),
+        publisher: item.get(3),
+        languages: serde_json::from_value(item.get(4)).unwrap(),
+        edition: item.get(5),
+        isbn: item.get(6),
+        year: item.get(7),
+        genres,
+        cover: item.get(9),
+        collection,
+        location: LocationDto {
+            id: Some(item.get(11)),
+            name: item.get(12),
+            user_id: item.get(13),
+            library_id: item.get(18),
+            version: item.get(21),
+        },
+        user_id: item.get(10),
+        library_id: item.get(17),
+        version: item.get(20),
+        tags: Vec::new(),
+        custom_fields: HashMap::new(),
+        note_count: 0,
+    }
+}
+
 impl BookRepository for BookRepositoryMySQL {
     async fn save(&self, book: &Book) -> Result<Option<Book>, sqlx::Error> {
         let mut genres_string = String::new();
 
-        match &book.genres {
-            Some(book_genres) => genres_string = serde_json::to_string(&book_genres).unwrap(),
-            None => {}
+        if let Some(book_genres) = &book.genres {
+            genres_string = serde_json::to_string(&book_genres).unwrap();
         }
         match book.id {
             Some(_) => {
@@ -64,8 +472,9 @@ impl BookRepository for BookRepositoryMySQL {
                         genres = ?, 
                         cover = ?, 
                         collection_id = ?, 
-                        location_id = ?
-                    WHERE id = ? AND user_id = ?
+                        location_id = ?,
+                        version = version + 1
+                    WHERE id = ? AND library_id = ? AND version = ?
                     "#,
                     book.title,
                     serde_json::to_string(&book.authors).unwrap(),
@@ -79,11 +488,13 @@ impl BookRepository for BookRepositoryMySQL {
                     book.collection_id,
                     book.location_id,
                     book.id,
-                    book.user_id
+                    book.library_id,
+                    book.version
                 )
                 .execute(self.connection.as_ref())
                 .await;
                 match update_result {
+                    Ok(result) if result.rows_affected() == 0 => Ok(None),
                     Ok(_) => match self.find_by_id(book.id.unwrap()).await {
                         Ok(book_option) => match book_option {
                             Some(book) => Ok(Some(book)),
@@ -110,8 +521,9 @@ impl BookRepository for BookRepositoryMySQL {
                         cover, 
                         collection_id, 
                         location_id, 
-                        user_id)
-                    VALUES (DEFAULT, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                        user_id,
+                        library_id)
+                    VALUES (DEFAULT, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                     "#,
                     book.title,
                     serde_json::to_string(&book.authors).unwrap(),
@@ -124,7 +536,8 @@ impl BookRepository for BookRepositoryMySQL {
                     book.cover,
                     book.collection_id,
                     book.location_id,
-                    book.user_id
+                    book.user_id,
+                    book.library_id
                 )
                 .execute(self.connection.as_ref())
                 .await;
@@ -151,19 +564,18 @@ impl BookRepository for BookRepositoryMySQL {
             r#"
             SELECT *
             FROM books u
-            WHERE u.id = ?
+            WHERE u.id = ? AND u.deleted_at IS NULL
             "#,
             id
         )
-        .fetch_one(self.connection.as_ref())
+        .fetch_optional(self.connection.as_ref())
         .await;
         match query_result {
-            Ok(result) => {
-                let mut genres: Option<Vec<Genre>> = None;
-                if let Some(genre_value) = result.genres {
-                    genres = Some(serde_json::from_value(genre_value).unwrap());
-                }
-                Ok(Some(Book {
+            Ok(result) => Ok(result.map(|result| {
+                let genres: Option<Vec<Genre>> = result
+                    .genres
+                    .map(|genre_value| serde_json::from_value(genre_value).unwrap());
+                Book {
                     id: Some(result.id),
                     title: result.title,
                     authors: serde_json::from_value(result.authors).unwrap(),
@@ -177,33 +589,41 @@ impl BookRepository for BookRepositoryMySQL {
                     collection_id: result.collection_id,
                     location_id: result.location_id,
                     user_id: result.user_id,
-                }))
-            }
-            Err(error) => match error {
-                sqlx::Error::RowNotFound => Ok(None),
-                _ => Err(error),
-            },
+                    library_id: result.library_id,
+                    version: result.version,
+                    tag_ids: None,
+                    custom_fields: None,
+                }
+            })),
+            Err(error) => Err(error),
         }
     }
 
-    async fn find_by_title(&self, title: &str) -> Result<Option<Book>, sqlx::Error> {
+    async fn find_by_title_and_library_id(
+        &self,
+        title: &str,
+        library_id: u64,
+    ) -> Result<Option<Book>, sqlx::Error> {
         let query_result = sqlx::query!(
             r#"
             SELECT *
             FROM books u
             WHERE u.title = ?
+                AND u.library_id = ?
+                AND u.deleted_at IS NULL
+            LIMIT 1
             "#,
-            title
+            title,
+            library_id
         )
-        .fetch_one(self.connection.as_ref())
+        .fetch_optional(self.connection.as_ref())
         .await;
         match query_result {
-            Ok(result) => {
-                let mut genres: Option<Vec<Genre>> = None;
-                if let Some(genre_value) = result.genres {
-                    genres = Some(serde_json::from_value(genre_value).unwrap());
-                }
-                Ok(Some(Book {
+            Ok(result) => Ok(result.map(|result| {
+                let genres: Option<Vec<Genre>> = result
+                    .genres
+                    .map(|genre_value| serde_json::from_value(genre_value).unwrap());
+                Book {
                     id: Some(result.id),
                     title: result.title,
                     authors: serde_json::from_value(result.authors).unwrap(),
@@ -217,214 +637,215 @@ impl BookRepository for BookRepositoryMySQL {
                     collection_id: result.collection_id,
                     location_id: result.location_id,
                     user_id: result.user_id,
-                }))
-            }
-            Err(error) => match error {
-                sqlx::Error::RowNotFound => Ok(None),
-                _ => Err(error),
-            },
+                    library_id: result.library_id,
+                    version: result.version,
+                    tag_ids: None,
+                    custom_fields: None,
+                }
+            })),
+            Err(error) => Err(error),
         }
     }
 
-    async fn find_all_by_user_id_as_complete_book_dto(
+    async fn find_all_by_library_id_as_complete_book_dto(
         &self,
-        user_id: u64,
+        library_id: u64,
         page: u64,
         page_size: u64,
         collection_id: Option<i64>,
         location_id: Option<i64>,
         query: Option<String>,
+        listing_options: &BookListingOptions,
     ) -> Result<PaginatedDto<CompleteBookDto>, sqlx::Error> {
-        let query_hook = match query {
-            Some(_) => QUERY_CONDTIONAL,
-            None => "",
-        };
-        let collection_id_hook = match collection_id {
-            Some(_) => COLLECTION_ID_CONDITIONAL,
-            None => "",
-        };
-        let location_id_hook = match location_id {
-            Some(_) => LOCATION_ID_CONDITIONAL,
-            None => "",
-        };
-
-        let mut count_query = r#"
-        SELECT COUNT(*) as n_books
-            FROM books b
-                INNER JOIN ( 
-                    SELECT u.id
-                        FROM books u
-                        WHERE u.user_id = ?
-                                "#
-        .to_string();
-        count_query.push_str(query_hook);
-        count_query.push_str(location_id_hook);
-        count_query.push_str(collection_id_hook);
-        count_query.push_str(
-            r#"
-                ) as p USING (id)
-                    INNER JOIN locations as l
-                        ON l.id = b.location_id
-                    LEFT JOIN collections as c
-                        ON c.id = b.collection_id
-        "#,
-        );
-
-        let mut count_query_ps = sqlx::query(&count_query).bind(user_id);
-        if query.is_some() {
-            let lowercase_query = query.clone().unwrap().to_lowercase();
-            count_query_ps = count_query_ps
-                .bind(lowercase_query.clone())
-                .bind(lowercase_query.clone())
-                .bind(lowercase_query.clone())
-                .bind(lowercase_query.clone())
-                .bind(lowercase_query.clone());
-        }
-        if location_id.is_some() {
-            count_query_ps = count_query_ps.bind(location_id.unwrap());
-        }
-        if collection_id.is_some() {
-            count_query_ps = count_query_ps.bind(collection_id.unwrap());
-        }
-
-        let couting_query_result = count_query_ps.fetch_one(self.connection.as_ref()).await;
-        let n_of_books: u64 = match couting_query_result {
-            Ok(counting_query_result_value) => {
-                let n_books: i64 = counting_query_result_value.get(0);
-                info!("{} books returned", n_books);
-                u64::from_ne_bytes(n_books.to_ne_bytes())
-            }
-            Err(e) => return Err(e),
-        };
+        let custom_field_sort = listing_options.custom_field_sort.as_ref();
+        let n_of_books = self
+            .count_by_library_id(
+                library_id,
+                collection_id,
+                location_id,
+                query.as_deref(),
+                listing_options,
+            )
+            .await?;
 
-        let mut main_query = r#"
-        SELECT 
-            b.id 'book_id',
-            b.title 'book_title',
-            b.authors 'book_authors',
-            b.publisher 'book_publisher',
-            b.languages 'book_languages',
-            b.edition 'book_edition',
-            b.isbn 'book_isbn',
-            b.year 'book_year',
-            b.genres 'book_genres',
-            b.cover 'book_cover',
-            b.user_id 'book_user_id',
-            l.id 'location_id',
-            l.name 'location_name',
-            l.user_id 'location_user_id',
-            c.id 'collection_id',
-            c.name 'collection_name',
-            c.user_id 'collection_user_id'
-            FROM books b
-                INNER JOIN ( 
-                    SELECT u.id
-                        FROM books u
-                        WHERE u.user_id = ?
-                                "#
-        .to_string();
-        main_query.push_str(query_hook);
-        main_query.push_str(location_id_hook);
-        main_query.push_str(collection_id_hook);
+        let mut main_query = COMPLETE_BOOK_DTO_SELECT.to_string();
+        main_query.push_str(&book_listing_conditional(
+            collection_id,
+            location_id,
+            query.as_deref(),
+            listing_options,
+        ));
+        main_query.push_str(&listing_order_clause("u", custom_field_sort));
         main_query.push_str(
             r#"
-                ORDER BY u.title ASC
                         LIMIT ? OFFSET ?
                 ) as p USING (id)
             INNER JOIN locations as l
                 ON l.id = b.location_id
             LEFT JOIN collections as c
-                ON c.id = b.collection_id
+                ON c.id = b.collection_id AND c.deleted_at IS NULL
 
         "#,
         );
-
-        let mut query_ps = sqlx::query(&main_query).bind(user_id);
-
-        if query.is_some() {
-            let lowercase_query = query.clone().unwrap().to_lowercase();
-            query_ps = query_ps
-                .bind(lowercase_query.clone())
-                .bind(lowercase_query.clone())
-                .bind(lowercase_query.clone())
-                .bind(lowercase_query.clone())
-                .bind(lowercase_query.clone());
-        }
-        if location_id.is_some() {
-            query_ps = query_ps.bind(location_id.unwrap());
-        }
-        if collection_id.is_some() {
-            query_ps = query_ps.bind(collection_id.unwrap());
+        // The outer join doesn't keep the order of the paginated ids
+        if custom_field_sort.is_some() {
+            main_query.push_str(&listing_order_clause("b", custom_field_sort));
         }
+
+        let mut query_ps = bind_book_listing(
+            sqlx::query(&main_query),
+            library_id,
+            collection_id,
+            location_id,
+            query.as_deref(),
+            listing_options,
+        );
+        query_ps = bind_listing_order(query_ps, custom_field_sort);
         query_ps = query_ps.bind(page_size).bind((page - 1) * page_size);
+        query_ps = bind_listing_order(query_ps, custom_field_sort);
 
         let query_result = query_ps.fetch_all(self.connection.as_ref()).await;
         match query_result {
             Ok(result) => {
-                let items_vec = result
-                    .into_iter()
-                    .map(|item| {
-                        let mut genres: Option<Vec<GenreDto>> = None;
-                        let book_genre: Option<Value> = item.get(8);
-                        if let Some(genre_value) = book_genre {
-                            let genre_vec: Vec<Genre> =
-                                serde_json::from_value(genre_value).unwrap();
-                            let genre_dto_vec: Vec<GenreDto> =
-                                genre_vec.into_iter().map(GenreDto::from).collect();
-                            genres = Some(genre_dto_vec);
-                        }
-                        let mut collection: Option<CollectionDto> = None;
-                        let book_collection_id: Option<u64> = item.get(14);
-                        if book_collection_id.is_some() {
-                            collection = Some(CollectionDto {
-                                id: item.get(14),
-                                name: item
-                                    .get::<Option<String>, usize>(15)
-                                    .unwrap_or("".to_string()),
-                                user_id: item.get::<Option<u64>, usize>(16).unwrap(),
-                            })
-                        }
-                        CompleteBookDto {
-                            id: item.get(0),
-                            title: item.get(1),
-                            authors: serde_json::from_value(item.get(2)).unwrap(),
-                            publisher: item.get(3),
-                            languages: serde_json::from_value(item.get(4)).unwrap(),
-                            edition: item.get(5),
-                            isbn: item.get(6),
-                            year: item.get(7),
-                            genres,
-                            cover: item.get(9),
-                            collection,
-                            location: LocationDto {
-                                id: Some(item.get(11)),
-                                name: item.get(12),
-                                user_id: item.get(13),
-                            },
-                            user_id: item.get(10),
-                        }
-                    })
-                    .collect();
+                let items_vec = result.iter().map(complete_book_dto_from_row).collect();
                 Ok(PaginatedDto {
-                    page,
+                    page: Some(page),
                     page_size,
-                    total_items: n_of_books,
+                    total_items: Some(n_of_books),
                     items: items_vec,
+                    ..Default::default()
                 })
             }
             Err(error) => Err(error),
         }
     }
 
-    async fn delete_by_id(&self, user_id: u64, book_id: u64) -> Result<bool, sqlx::Error> {
+    async fn find_all_by_library_id_as_complete_book_dto_by_cursor(
+        &self,
+        library_id: u64,
+        cursor: Option<&BookCursor>,
+        page_size: u64,
+        collection_id: Option<i64>,
+        location_id: Option<i64>,
+        query: Option<String>,
+        listing_options: &BookListingOptions,
+        count_total: bool,
+    ) -> Result<PaginatedDto<CompleteBookDto>, sqlx::Error> {
+        let total_items = if count_total {
+            Some(
+                self.count_by_library_id(
+                    library_id,
+                    collection_id,
+                    location_id,
+                    query.as_deref(),
+                    listing_options,
+                )
+                .await?,
+            )
+        } else {
+            None
+        };
+        let backwards = cursor.is_some_and(|cursor| cursor.backwards);
+        let (comparison, direction) = if backwards {
+            ("<", "DESC")
+        } else {
+            (">", "ASC")
+        };
+
+        let mut main_query = COMPLETE_BOOK_DTO_SELECT.to_string();
+        main_query.push_str(&book_listing_conditional(
+            collection_id,
+            location_id,
+            query.as_deref(),
+            listing_options,
+        ));
+        if cursor.is_some() {
+            main_query.push_str(&format!(
+                "AND (u.title {0} ? OR (u.title = ? AND u.id {0} ?)) \n",
+                comparison
+            ));
+        }
+        // One more book than asked for tells whether there is another page
+        main_query.push_str(&format!(
+            r#"
+                        ORDER BY u.title {0}, u.id {0}
+                        LIMIT ?
+                ) as p USING (id)
+            INNER JOIN locations as l
+                ON l.id = b.location_id
+            LEFT JOIN collections as c
+                ON c.id = b.collection_id AND c.deleted_at IS NULL
+            ORDER BY b.title {0}, b.id {0}
+        "#,
+            direction
+        ));
+
+        let mut query_ps = bind_book_listing(
+            sqlx::query(&main_query),
+            library_id,
+            collection_id,
+            location_id,
+            query.as_deref(),
+            listing_options,
+        );
+        if let Some(cursor) = cursor {
+            query_ps = query_ps
+                .bind(cursor.title.clone())
+                .bind(cursor.title.clone())
+                .bind(cursor.book_id);
+        }
+        query_ps = query_ps.bind(page_size + 1);
+
+        let mut items: Vec<CompleteBookDto> =
+            match query_ps.fetch_all(self.connection.as_ref()).await {
+                Ok(result) => result.iter().map(complete_book_dto_from_row).collect(),
+                Err(error) => return Err(error),
+            };
+        let has_more = items.len() as u64 > page_size;
+        items.truncate(page_size as usize);
+        if backwards {
+            items.reverse();
+        }
+
+        let first_cursor = items
+            .first()
+            .map(|book| BookCursor::before(&book.title, book.id).encode());
+        let last_cursor = items
+            .last()
+            .map(|book| BookCursor::after(&book.title, book.id).encode());
+        let (next_cursor, prev_cursor) = if backwards {
+            (last_cursor, first_cursor.filter(|_| has_more))
+        } else {
+            (
+                last_cursor.filter(|_| has_more),
+                first_cursor.filter(|_| cursor.is_some()),
+            )
+        };
+
+        Ok(PaginatedDto {
+            page: None,
+            page_size,
+            total_items,
+            next_cursor,
+            prev_cursor,
+            items,
+        })
+    }
+
+    async fn delete_by_id(
+        &self,
+        library_id: u64,
+        book_id: u64,
+        version: u64,
+    ) -> Result<bool, sqlx::Error> {
         let query_result = sqlx::query!(
             r#"
-            DELETE
-            FROM books u
-            WHERE u.id = ? and u.user_id = ?
+            UPDATE books u SET u.deleted_at = CURRENT_TIMESTAMP, u.version = u.version + 1
+            WHERE u.id = ? AND u.library_id = ? AND u.version = ? AND u.deleted_at IS NULL
             "#,
             book_id,
-            user_id
+            library_id,
+            version
         )
         .execute(self.connection.as_ref())
         .await;
@@ -445,84 +866,908 @@ impl BookRepository for BookRepositoryMySQL {
 
     async fn find_by_id_as_complete_book_dto(
         &self,
-        user_id: u64,
+        library_id: u64,
         book_id: u64,
     ) -> Result<Option<CompleteBookDto>, sqlx::Error> {
-        let query_result = sqlx::query!(
+        let mut book_query = COMPLETE_BOOK_DTO_SELECT.to_string();
+        book_query.push_str(
             r#"
-            SELECT 
-            b.id 'book_id',
-            b.title 'book_title',
-            b.authors 'book_authors',
-            b.publisher 'book_publisher',
-            b.languages 'book_languages',
-            b.edition 'book_edition',
-            b.isbn 'book_isbn',
-            b.year 'book_year',
-            b.genres 'book_genres',
-            b.cover 'book_cover',
-            b.user_id 'book_user_id',
-            l.id 'location_id',
-            l.name 'location_name',
-            l.user_id 'location_user_id',
-            c.id 'collection_id',
-            c.name 'collection_name',
-            c.user_id 'collection_user_id'
-            FROM books b
+                    AND u.id = ?
+                ) as p USING (id)
             INNER JOIN locations as l
                 ON l.id = b.location_id
             LEFT JOIN collections as c
-                ON c.id = b.collection_id
-            WHERE 
-                b.user_id = ?
-                AND b.id = ?
+                ON c.id = b.collection_id AND c.deleted_at IS NULL
+        "#,
+        );
+
+        let query_result = sqlx::query(&book_query)
+            .bind(library_id)
+            .bind(book_id)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(complete_book_dto_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_ids_by_library_id(
+        &self,
+        library_id: u64,
+        pagination: Option<(u64, u64)>,
+        collection_id: Option<i64>,
+        location_id: Option<i64>,
+        query: Option<String>,
+        listing_options: &BookListingOptions,
+    ) -> Result<Vec<u64>, sqlx::Error> {
+        let mut ids_query = r#"
+        SELECT u.id
+            FROM books u
+            WHERE u.library_id = ? AND u.deleted_at IS NULL
+        "#
+        .to_string();
+        if query.is_some() {
+            ids_query.push_str(QUERY_CONDTIONAL);
+        }
+        if location_id.is_some() {
+            ids_query.push_str(LOCATION_ID_CONDITIONAL);
+        }
+        if collection_id.is_some() {
+            ids_query.push_str(COLLECTION_ID_CONDITIONAL);
+        }
+        ids_query.push_str(&listing_filters_conditional(listing_options));
+        ids_query.push_str(&listing_order_clause(
+            "u",
+            listing_options.custom_field_sort.as_ref(),
+        ));
+        if pagination.is_some() {
+            ids_query.push_str("LIMIT ? OFFSET ? \n");
+        }
+
+        let mut ids_query_ps = sqlx::query(&ids_query).bind(library_id);
+        if let Some(query) = query {
+            let lowercase_query = query.to_lowercase();
+            ids_query_ps = ids_query_ps
+                .bind(lowercase_query.clone())
+                .bind(lowercase_query.clone())
+                .bind(lowercase_query.clone())
+                .bind(lowercase_query.clone())
+                .bind(lowercase_query.clone());
+        }
+        if let Some(location_id) = location_id {
+            ids_query_ps = ids_query_ps.bind(location_id);
+        }
+        if let Some(collection_id) = collection_id {
+            ids_query_ps = ids_query_ps.bind(collection_id);
+        }
+        ids_query_ps = bind_listing_filters(ids_query_ps, listing_options);
+        ids_query_ps = bind_listing_order(ids_query_ps, listing_options.custom_field_sort.as_ref());
+        if let Some((page, page_size)) = pagination {
+            ids_query_ps = ids_query_ps.bind(page_size).bind((page - 1) * page_size);
+        }
+
+        match ids_query_ps.fetch_all(self.connection.as_ref()).await {
+            Ok(result) => Ok(result.into_iter().map(|item| item.get(0)).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn execute_bulk_operation(
+        &self,
+        library_id: u64,
+        book_ids: &[u64],
+        action: &BulkBookAction,
+    ) -> Result<BulkBookOperationResultDto, sqlx::Error> {
+        let mut result_dto = BulkBookOperationResultDto {
+            action: action.as_str().to_string(),
+            total_items: book_ids.len() as u64,
+            ..Default::default()
+        };
+        if book_ids.is_empty() {
+            result_dto.committed = true;
+            return Ok(result_dto);
+        }
+
+        let placeholders = in_clause_placeholders(book_ids.len());
+        let mut transaction = self.connection.begin().await?;
+
+        // Locking the rows up front guarantees every book still belongs to
+        // the library when the statement below runs.
+        let lock_query = format!(
+            r#"
+            SELECT u.id
+                FROM books u
+                WHERE u.library_id = ? AND u.deleted_at IS NULL
+                    AND u.id IN ({})
+                FOR UPDATE
             "#,
-            user_id,
-            book_id
+            placeholders
+        );
+        let mut lock_query_ps = sqlx::query(&lock_query).bind(library_id);
+        for book_id in book_ids {
+            lock_query_ps = lock_query_ps.bind(book_id);
+        }
+        let owned_ids: HashSet<u64> = lock_query_ps
+            .fetch_all(&mut *transaction)
+            .await?
+            .into_iter()
+            .map(|item| item.get(0))
+            .collect();
+
+        if owned_ids.len() != book_ids.len() {
+            transaction.rollback().await?;
+            for book_id in book_ids {
+                let item = if owned_ids.contains(book_id) {
+                    BulkBookItemResultDto {
+                        book_id: *book_id,
+                        status: "rolled_back".to_string(),
+                        msg: Some("Operation aborted because other books failed".to_string()),
+                    }
+                } else {
+                    result_dto.failed_items += 1;
+                    BulkBookItemResultDto {
+                        book_id: *book_id,
+                        status: "not_found".to_string(),
+                        msg: Some("Book not found".to_string()),
+                    }
+                };
+                result_dto.items.push(item);
+            }
+            return Ok(result_dto);
+        }
+
+        match action {
+            BulkBookAction::AddTags(tag_ids) => {
+                let tags_statement = format!(
+                    r#"
+                    INSERT IGNORE INTO book_tags (book_id, tag_id)
+                    SELECT b.id, t.id
+                        FROM books b
+                        JOIN tags t ON t.id IN ({})
+                        WHERE b.library_id = ? AND b.id IN ({})
+                    "#,
+                    in_clause_placeholders(tag_ids.len()),
+                    placeholders
+                );
+                let mut tags_statement_ps = sqlx::query(&tags_statement);
+                for tag_id in tag_ids {
+                    tags_statement_ps = tags_statement_ps.bind(tag_id);
+                }
+                tags_statement_ps = tags_statement_ps.bind(library_id);
+                for book_id in book_ids {
+                    tags_statement_ps = tags_statement_ps.bind(book_id);
+                }
+                tags_statement_ps.execute(&mut *transaction).await?;
+            }
+            BulkBookAction::RemoveTags(tag_ids) => {
+                let tags_statement = format!(
+                    "DELETE FROM book_tags WHERE book_id IN ({}) AND tag_id IN ({})",
+                    placeholders,
+                    in_clause_placeholders(tag_ids.len())
+                );
+                let mut tags_statement_ps = sqlx::query(&tags_statement);
+                for book_id in book_ids {
+                    tags_statement_ps = tags_statement_ps.bind(book_id);
+                }
+                for tag_id in tag_ids {
+                    tags_statement_ps = tags_statement_ps.bind(tag_id);
+                }
+                tags_statement_ps.execute(&mut *transaction).await?;
+            }
+            _ => {}
+        }
+
+        let statement = match action {
+            BulkBookAction::MoveToLocation(_) => format!(
+                "UPDATE books SET location_id = ?, version = version + 1 WHERE library_id = ? AND id IN ({})",
+                placeholders
+            ),
+            BulkBookAction::SetCollection(_) => format!(
+                "UPDATE books SET collection_id = ?, version = version + 1 WHERE library_id = ? AND id IN ({})",
+                placeholders
+            ),
+            BulkBookAction::ClearCollection => format!(
+                "UPDATE books SET collection_id = NULL, version = version + 1 WHERE library_id = ? AND id IN ({})",
+                placeholders
+            ),
+            BulkBookAction::AddTags(_) | BulkBookAction::RemoveTags(_) => format!(
+                "UPDATE books SET version = version + 1 WHERE library_id = ? AND id IN ({})",
+                placeholders
+            ),
+            BulkBookAction::Delete => format!(
+                "UPDATE books SET deleted_at = CURRENT_TIMESTAMP, version = version + 1 WHERE library_id = ? AND id IN ({})",
+                placeholders
+            ),
+        };
+        let mut statement_ps = sqlx::query(&statement);
+        match action {
+            BulkBookAction::MoveToLocation(target_id)
+            | BulkBookAction::SetCollection(target_id) => {
+                statement_ps = statement_ps.bind(target_id);
+            }
+            BulkBookAction::ClearCollection
+            | BulkBookAction::AddTags(_)
+            | BulkBookAction::RemoveTags(_)
+            | BulkBookAction::Delete => {}
+        }
+        statement_ps = statement_ps.bind(library_id);
+        for book_id in book_ids {
+            statement_ps = statement_ps.bind(book_id);
+        }
+        statement_ps.execute(&mut *transaction).await?;
+        transaction.commit().await?;
+
+        let status = match action {
+            BulkBookAction::Delete => "deleted",
+            _ => "updated",
+        };
+        result_dto.committed = true;
+        result_dto.succeeded_items = book_ids.len() as u64;
+        result_dto.items = book_ids
+            .iter()
+            .map(|book_id| BulkBookItemResultDto {
+                book_id: *book_id,
+                status: status.to_string(),
+                msg: None,
+            })
+            .collect();
+        info!(
+            "Bulk {} applied to {} books",
+            result_dto.action, result_dto.succeeded_items
+        );
+        Ok(result_dto)
+    }
+
+    async fn find_all_by_library_id(&self, library_id: u64) -> Result<Vec<Book>, sqlx::Error> {
+        let query_result = sqlx::query!(
+            r#"
+            SELECT *
+            FROM books u
+            WHERE u.library_id = ? AND u.deleted_at IS NULL
+            ORDER BY u.title ASC
+            "#,
+            library_id
         )
-        .fetch_one(self.connection.as_ref())
+        .fetch_all(self.connection.as_ref())
         .await;
         match query_result {
-            Ok(result) => {
-                let mut genres: Option<Vec<GenreDto>> = None;
-                if let Some(genre_value) = result.book_genres {
-                    let genre_vec: Vec<Genre> = serde_json::from_value(genre_value).unwrap();
-                    let genre_dto_vec: Vec<GenreDto> =
-                        genre_vec.into_iter().map(GenreDto::from).collect();
-                    genres = Some(genre_dto_vec);
+            Ok(result) => Ok(result
+                .into_iter()
+                .map(|item| {
+                    let genres: Option<Vec<Genre>> = item
+                        .genres
+                        .map(|genre_value| serde_json::from_value(genre_value).unwrap());
+                    Book {
+                        id: Some(item.id),
+                        title: item.title,
+                        authors: serde_json::from_value(item.authors).unwrap(),
+                        publisher: item.publisher,
+                        languages: serde_json::from_value(item.languages).unwrap(),
+                        edition: item.edition,
+                        isbn: item.isbn,
+                        year: item.year,
+                        genres,
+                        cover: item.cover,
+                        collection_id: item.collection_id,
+                        location_id: item.location_id,
+                        user_id: item.user_id,
+                        library_id: item.library_id,
+                        version: item.version,
+                        tag_ids: None,
+                        custom_fields: None,
+                    }
+                })
+                .collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_ids(
+        &self,
+        library_id: u64,
+        book_ids: &[u64],
+    ) -> Result<Vec<Book>, sqlx::Error> {
+        if book_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let query = format!(
+            r#"
+            SELECT *
+            FROM books u
+            WHERE u.library_id = ? AND u.deleted_at IS NULL AND u.id IN ({})
+            "#,
+            in_clause_placeholders(book_ids.len())
+        );
+        let mut query_builder = sqlx::query(&query).bind(library_id);
+        for book_id in book_ids.iter() {
+            query_builder = query_builder.bind(book_id);
+        }
+        match query_builder.fetch_all(self.connection.as_ref()).await {
+            Ok(result) => Ok(result.iter().map(book_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn import_books(
+        &self,
+        library_id: u64,
+        user_id: u64,
+        rows: &[BookImportRow],
+    ) -> Result<Vec<u64>, sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+        let mut created_location_ids: HashMap<String, u64> = HashMap::new();
+        let mut created_collection_ids: HashMap<String, u64> = HashMap::new();
+        let mut imported_ids = Vec::with_capacity(rows.len());
+
+        for row in rows {
+            let mut location_id = row.book.location_id;
+            if let Some(location_name) = &row.new_location_name {
+                location_id = match created_location_ids.get(&location_name.to_lowercase()) {
+                    Some(created_id) => *created_id,
+                    None => {
+                        let created_id = sqlx::query(
+                            "INSERT INTO locations (id, name, user_id, library_id) VALUES (DEFAULT, ?, ?, ?)",
+                        )
+                        .bind(location_name)
+                        .bind(user_id)
+                        .bind(library_id)
+                        .execute(&mut *transaction)
+                        .await?
+                        .last_insert_id();
+                        created_location_ids.insert(location_name.to_lowercase(), created_id);
+                        created_id
+                    }
+                };
+            }
+
+            let mut collection_id = row.book.collection_id;
+            if let Some(collection_name) = &row.new_collection_name {
+                collection_id = match created_collection_ids.get(&collection_name.to_lowercase()) {
+                    Some(created_id) => Some(*created_id),
+                    None => {
+                        let created_id = sqlx::query(
+                            "INSERT INTO collections (id, name, user_id, library_id) VALUES (DEFAULT, ?, ?, ?)",
+                        )
+                        .bind(collection_name)
+                        .bind(user_id)
+                        .bind(library_id)
+                        .execute(&mut *transaction)
+                        .await?
+                        .last_insert_id();
+                        created_collection_ids.insert(collection_name.to_lowercase(), created_id);
+                        Some(created_id)
+                    }
+                };
+            }
+
+            let genres_string = row
+                .book
+                .genres
+                .as_ref()
+                .map(|book_genres| serde_json::to_string(book_genres).unwrap());
+            let insert_result = sqlx::query(
+                r#"
+                INSERT INTO books (
+                    id,
+                    title,
+                    authors,
+                    publisher,
+                    languages,
+                    edition,
+                    isbn,
+                    year,
+                    genres,
+                    cover,
+                    collection_id,
+                    location_id,
+                    user_id,
+                    library_id)
+                VALUES (DEFAULT, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&row.book.title)
+            .bind(serde_json::to_string(&row.book.authors).unwrap())
+            .bind(&row.book.publisher)
+            .bind(serde_json::to_string(&row.book.languages).unwrap())
+            .bind(&row.book.edition)
+            .bind(&row.book.isbn)
+            .bind(&row.book.year)
+            .bind(genres_string)
+            .bind(&row.book.cover)
+            .bind(collection_id)
+            .bind(location_id)
+            .bind(user_id)
+            .bind(library_id)
+            .execute(&mut *transaction)
+            .await?;
+            imported_ids.push(insert_result.last_insert_id());
+        }
+
+        transaction.commit().await?;
+        info!(
+            "{} books imported by user {} into library {}",
+            imported_ids.len(),
+            user_id,
+            library_id
+        );
+        Ok(imported_ids)
+    }
+
+    async fn restore_library(
+        &self,
+        library_id: u64,
+        user_id: u64,
+        library_restore: &LibraryRestore,
+    ) -> Result<(LibraryRestoreReportDto, Vec<AuditEntry>), sqlx::Error> {
+        let mut audit_entries = Vec::new();
+        let mut report = LibraryRestoreReportDto {
+            schema_version: library_restore.schema_version,
+            mode: library_restore.mode.as_str().to_string(),
+            ..Default::default()
+        };
+        let mut transaction = self.connection.begin().await?;
+
+        if library_restore.mode == LibraryRestoreMode::Replace {
+            for (entity_type, table) in [
+                (AuditEntityType::Book, "books"),
+                (AuditEntityType::Collection, "collections"),
+                (AuditEntityType::Location, "locations"),
+            ] {
+                let removed_ids: Vec<u64> = sqlx::query_scalar(&format!(
+                    "SELECT id FROM {} WHERE library_id = ? FOR UPDATE",
+                    table
+                ))
+                .bind(library_id)
+                .fetch_all(&mut *transaction)
+                .await?;
+                audit_entries.extend(removed_ids.into_iter().map(|removed_id| {
+                    AuditEntry::new(
+                        entity_type,
+                        removed_id,
+                        library_id,
+                        user_id,
+                        AuditAction::Purge,
+                        None,
+                        None,
+                    )
+                }));
+            }
+            report.removed_books = sqlx::query("DELETE FROM books WHERE library_id = ?")
+                .bind(library_id)
+                .execute(&mut *transaction)
+                .await?
+                .rows_affected();
+            sqlx::query("DELETE FROM collections WHERE library_id = ?")
+                .bind(library_id)
+                .execute(&mut *transaction)
+                .await?;
+            sqlx::query("DELETE FROM locations WHERE library_id = ?")
+                .bind(library_id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        let mut location_ids: HashMap<u64, u64> = HashMap::new();
+        for location in library_restore.locations.iter() {
+            let existing_location = sqlx::query(
+                "SELECT id FROM locations WHERE library_id = ? AND name = ? AND deleted_at IS NULL FOR UPDATE",
+            )
+            .bind(library_id)
+            .bind(&location.name)
+            .fetch_optional(&mut *transaction)
+            .await?;
+            let restored_id = match existing_location {
+                Some(row) => {
+                    report.reused_locations += 1;
+                    row.get(0)
+                }
+                None => {
+                    report.created_locations += 1;
+                    let created_id = sqlx::query(
+                        "INSERT INTO locations (id, name, user_id, library_id) VALUES (DEFAULT, ?, ?, ?)",
+                    )
+                    .bind(&location.name)
+                    .bind(user_id)
+                    .bind(library_id)
+                    .execute(&mut *transaction)
+                    .await?
+                    .last_insert_id();
+                    audit_entries.push(AuditEntry::new(
+                        AuditEntityType::Location,
+                        created_id,
+                        library_id,
+                        user_id,
+                        AuditAction::Create,
+                        None,
+                        Some(location_audit_snapshot(location)),
+                    ));
+                    created_id
+                }
+            };
+            location_ids.insert(location.id.unwrap_or_default(), restored_id);
+        }
+
+        let mut collection_ids: HashMap<u64, u64> = HashMap::new();
+        for collection in library_restore.collections.iter() {
+            let existing_collection = sqlx::query(
+                "SELECT id FROM collections WHERE library_id = ? AND name = ? AND deleted_at IS NULL FOR UPDATE",
+            )
+            .bind(library_id)
+            .bind(&collection.name)
+            .fetch_optional(&mut *transaction)
+            .await?;
+            let restored_id = match existing_collection {
+                Some(row) => {
+                    report.reused_collections += 1;
+                    row.get(0)
+                }
+                None => {
+                    report.created_collections += 1;
+                    let created_id = sqlx::query(
+                        "INSERT INTO collections (id, name, user_id, library_id) VALUES (DEFAULT, ?, ?, ?)",
+                    )
+                    .bind(&collection.name)
+                    .bind(user_id)
+                    .bind(library_id)
+                    .execute(&mut *transaction)
+                    .await?
+                    .last_insert_id();
+                    audit_entries.push(AuditEntry::new(
+                        AuditEntityType::Collection,
+                        created_id,
+                        library_id,
+                        user_id,
+                        AuditAction::Create,
+                        None,
+                        Some(collection_audit_snapshot(collection)),
+                    ));
+                    created_id
+                }
+            };
+            collection_ids.insert(collection.id.unwrap_or_default(), restored_id);
+        }
+
+        // Tags belong to the user restoring the backup, not to the library
+        let mut tag_ids: HashMap<u64, u64> = HashMap::new();
+        for tag in library_restore.tags.iter() {
+            let existing_tag =
+                sqlx::query("SELECT id FROM tags WHERE user_id = ? AND name = ? FOR UPDATE")
+                    .bind(user_id)
+                    .bind(&tag.name)
+                    .fetch_optional(&mut *transaction)
+                    .await?;
+            let restored_id = match existing_tag {
+                Some(row) => {
+                    report.reused_tags += 1;
+                    row.get(0)
+                }
+                None => {
+                    report.created_tags += 1;
+                    sqlx::query("INSERT INTO tags (id, name, user_id) VALUES (DEFAULT, ?, ?)")
+                        .bind(&tag.name)
+                        .bind(user_id)
+                        .execute(&mut *transaction)
+                        .await?
+                        .last_insert_id()
+                }
+            };
+            tag_ids.insert(tag.id.unwrap_or_default(), restored_id);
+        }
+
+        // Values are checked again against the fields they end up in, an
+        // existing field may have the same name but another type or options
+        let mut custom_fields: Vec<CustomField> = Vec::new();
+        for custom_field in library_restore.custom_fields.iter() {
+            let existing_custom_field = sqlx::query(
+                "SELECT * FROM custom_fields WHERE user_id = ? AND name = ? FOR UPDATE",
+            )
+            .bind(user_id)
+            .bind(&custom_field.name)
+            .fetch_optional(&mut *transaction)
+            .await?;
+            match existing_custom_field {
+                Some(row) => {
+                    report.reused_custom_fields += 1;
+                    custom_fields.push(custom_field_from_row(&row));
                 }
-                let mut collection: Option<CollectionDto> = None;
-                if result.collection_id.is_some() {
-                    collection = Some(CollectionDto {
-                        id: result.collection_id,
-                        name: result.collection_name.unwrap_or("".to_string()),
-                        user_id: result.collection_user_id.unwrap(),
-                    })
+                None => {
+                    report.created_custom_fields += 1;
+                    let options = match custom_field.field_type {
+                        CustomFieldType::Enum => {
+                            Some(serde_json::to_string(&custom_field.options).unwrap())
+                        }
+                        _ => None,
+                    };
+                    let created_id = sqlx::query(
+                        "INSERT INTO custom_fields (id, name, field_type, options, user_id) VALUES (DEFAULT, ?, ?, ?, ?)",
+                    )
+                    .bind(&custom_field.name)
+                    .bind(custom_field.field_type.as_str())
+                    .bind(options)
+                    .bind(user_id)
+                    .execute(&mut *transaction)
+                    .await?
+                    .last_insert_id();
+                    custom_fields.push(CustomField {
+                        id: Some(created_id),
+                        user_id,
+                        ..custom_field.clone()
+                    });
                 }
-                Ok(Some(CompleteBookDto {
-                    id: result.book_id,
-                    title: result.book_title,
-                    authors: serde_json::from_value(result.book_authors).unwrap(),
-                    publisher: result.book_publisher,
-                    languages: serde_json::from_value(result.book_languages).unwrap(),
-                    edition: result.book_edition,
-                    isbn: result.book_isbn,
-                    year: result.book_year,
+            }
+        }
+
+        let mut known_titles: HashSet<String> = HashSet::new();
+        let mut known_isbns: HashSet<String> = HashSet::new();
+        let existing_books = sqlx::query(
+            "SELECT title, isbn FROM books WHERE library_id = ? AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(library_id)
+        .fetch_all(&mut *transaction)
+        .await?;
+        for row in existing_books.iter() {
+            known_titles.insert(row.get::<String, usize>(0).to_lowercase());
+            if let Some(isbn) = row
+                .get::<Option<String>, usize>(1)
+                .as_deref()
+                .and_then(normalize_isbn)
+            {
+                known_isbns.insert(isbn);
+            }
+        }
+
+        for book in library_restore.books.iter() {
+            let duplicated_isbn = book
+                .isbn
+                .as_deref()
+                .and_then(normalize_isbn)
+                .is_some_and(|isbn| !known_isbns.insert(isbn));
+            let duplicated_title = !known_titles.insert(book.title.to_lowercase());
+            if duplicated_isbn || duplicated_title {
+                report.skipped_books += 1;
+                continue;
+            }
+
+            let genres_string = book
+                .genres
+                .as_ref()
+                .map(|book_genres| serde_json::to_string(book_genres).unwrap());
+            let restored_collection_id = book
+                .collection_id
+                .and_then(|collection_id| collection_ids.get(&collection_id).copied());
+            let restored_location_id = location_ids.get(&book.location_id).copied();
+            let restored_id = sqlx::query(
+                r#"
+                INSERT INTO books (
+                    id,
+                    title,
+                    authors,
+                    publisher,
+                    languages,
+                    edition,
+                    isbn,
+                    year,
                     genres,
-                    cover: result.book_cover,
-                    collection,
-                    location: LocationDto {
-                        id: Some(result.location_id),
-                        name: result.location_name,
-                        user_id: result.location_user_id,
-                    },
-                    user_id: result.book_user_id,
-                }))
+                    cover,
+                    collection_id,
+                    location_id,
+                    user_id,
+                    library_id)
+                VALUES (DEFAULT, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&book.title)
+            .bind(serde_json::to_string(&book.authors).unwrap())
+            .bind(&book.publisher)
+            .bind(serde_json::to_string(&book.languages).unwrap())
+            .bind(&book.edition)
+            .bind(&book.isbn)
+            .bind(&book.year)
+            .bind(genres_string)
+            .bind(&book.cover)
+            .bind(restored_collection_id)
+            .bind(restored_location_id)
+            .bind(user_id)
+            .bind(library_id)
+            .execute(&mut *transaction)
+            .await?
+            .last_insert_id();
+            report.restored_books += 1;
+
+            let restored_tag_ids: HashSet<u64> = book
+                .tag_ids
+                .iter()
+                .flatten()
+                .filter_map(|tag_id| tag_ids.get(tag_id).copied())
+                .collect();
+            for restored_tag_id in restored_tag_ids {
+                sqlx::query("INSERT INTO book_tags (book_id, tag_id) VALUES (?, ?)")
+                    .bind(restored_id)
+                    .bind(restored_tag_id)
+                    .execute(&mut *transaction)
+                    .await?;
             }
-            Err(error) => match error {
-                sqlx::Error::RowNotFound => Ok(None),
-                _ => Err(error),
-            },
+
+            for (name, value) in book.custom_fields.iter().flatten() {
+                let Some(custom_field) = custom_fields
+                    .iter()
+                    .find(|custom_field| custom_field.name.eq_ignore_ascii_case(name.trim()))
+                else {
+                    report.skipped_custom_field_values += 1;
+                    continue;
+                };
+                match custom_field.value_from_json(value) {
+                    Ok(Some(converted_value)) => {
+                        let book_value = BookCustomFieldValue {
+                            custom_field_id: custom_field.id.unwrap_or_default(),
+                            field_type: custom_field.field_type,
+                            value: converted_value,
+                        };
+                        insert_book_custom_field_value(&mut transaction, restored_id, &book_value)
+                            .await?;
+                    }
+                    Ok(None) => {}
+                    Err(_) => report.skipped_custom_field_values += 1,
+                }
+            }
+
+            let mut snapshot = book_audit_snapshot(book);
+            snapshot["collection_id"] = json!(restored_collection_id);
+            snapshot["location_id"] = json!(restored_location_id);
+            audit_entries.push(AuditEntry::new(
+                AuditEntityType::Book,
+                restored_id,
+                library_id,
+                user_id,
+                AuditAction::Create,
+                None,
+                Some(snapshot),
+            ));
+        }
+
+        insert_audit_entries(&mut transaction, &audit_entries).await?;
+        transaction.commit().await?;
+        info!(
+            "{} books restored by user {} into library {} ({} skipped)",
+            report.restored_books, user_id, library_id, report.skipped_books
+        );
+        Ok((report, audit_entries))
+    }
+
+    async fn find_all_by_ids_as_complete_book_dto(
+        &self,
+        library_id: u64,
+        book_ids: &[u64],
+    ) -> Result<Vec<CompleteBookDto>, sqlx::Error> {
+        if book_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut books_query = COMPLETE_BOOK_DTO_SELECT.to_string();
+        books_query.push_str(&format!(
+            "AND u.id IN ({}) \n",
+            in_clause_placeholders(book_ids.len())
+        ));
+        books_query.push_str(
+            r#"
+                ) as p USING (id)
+            INNER JOIN locations as l
+                ON l.id = b.location_id
+            LEFT JOIN collections as c
+                ON c.id = b.collection_id AND c.deleted_at IS NULL
+            ORDER BY b.id ASC
+        "#,
+        );
+
+        let mut books_query_ps = sqlx::query(&books_query).bind(library_id);
+        for book_id in book_ids.iter() {
+            books_query_ps = books_query_ps.bind(book_id);
+        }
+
+        match books_query_ps.fetch_all(self.connection.as_ref()).await {
+            Ok(rows) => Ok(rows.iter().map(complete_book_dto_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_by_isbns_as_complete_book_dto(
+        &self,
+        library_id: u64,
+        isbns: &[String],
+    ) -> Result<Option<CompleteBookDto>, sqlx::Error> {
+        if isbns.is_empty() {
+            return Ok(None);
+        }
+        let mut isbn_query = COMPLETE_BOOK_DTO_SELECT.to_string();
+        isbn_query.push_str(&format!(
+            "AND REPLACE(u.isbn, '-', '') IN ({}) \n",
+            in_clause_placeholders(isbns.len())
+        ));
+        isbn_query.push_str(
+            r#"
+                ) as p USING (id)
+            INNER JOIN locations as l
+                ON l.id = b.location_id
+            LEFT JOIN collections as c
+                ON c.id = b.collection_id AND c.deleted_at IS NULL
+            ORDER BY b.id ASC
+            LIMIT 1
+        "#,
+        );
+
+        let mut isbn_query_ps = sqlx::query(&isbn_query).bind(library_id);
+        for isbn in isbns.iter() {
+            isbn_query_ps = isbn_query_ps.bind(isbn);
         }
+
+        match isbn_query_ps.fetch_optional(self.connection.as_ref()).await {
+            Ok(row) => Ok(row.as_ref().map(complete_book_dto_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn stream_all_by_library_id_as_complete_book_dto(
+        &self,
+        library_id: u64,
+        collection_id: Option<i64>,
+        location_id: Option<i64>,
+        query: Option<String>,
+        listing_options: BookListingOptions,
+    ) -> BoxStream<'static, Result<CompleteBookDto, sqlx::Error>> {
+        let connection = self.connection.clone();
+        Box::pin(try_stream! {
+            let mut export_query = COMPLETE_BOOK_DTO_SELECT.to_string();
+            export_query.push_str(&book_listing_conditional(
+                collection_id,
+                location_id,
+                query.as_deref(),
+                &listing_options,
+            ));
+            export_query.push_str(
+                r#"
+                ) as p USING (id)
+            INNER JOIN locations as l
+                ON l.id = b.location_id
+            LEFT JOIN collections as c
+                ON c.id = b.collection_id AND c.deleted_at IS NULL
+        "#,
+            );
+            let custom_field_sort = listing_options.custom_field_sort.as_ref();
+            export_query.push_str(&listing_order_clause("b", custom_field_sort));
+
+            let export_query_ps = bind_listing_order(
+                bind_book_listing(
+                    sqlx::query(&export_query),
+                    library_id,
+                    collection_id,
+                    location_id,
+                    query.as_deref(),
+                    &listing_options,
+                ),
+                custom_field_sort,
+            );
+
+            // Rows are mapped as they arrive, the result set is never fully
+            // held in memory
+            let mut rows = export_query_ps.fetch(connection.as_ref());
+            while let Some(row) = rows.try_next().await? {
+                yield complete_book_dto_from_row(&row);
+            }
+        })
+    }
+
+    async fn count_by_collection_ids(
+        &self,
+        collection_ids: &[u64],
+    ) -> Result<HashMap<u64, u64>, sqlx::Error> {
+        self.count_grouped_by("collection_id", collection_ids).await
+    }
+
+    async fn count_by_location_ids(
+        &self,
+        location_ids: &[u64],
+    ) -> Result<HashMap<u64, u64>, sqlx::Error> {
+        self.count_grouped_by("location_id", location_ids).await
     }
 }
@@ -9,14 +9,18 @@ pub trait CollectionRepository {
     ) -> impl Future<Output = Result<Option<Collection>, Error>> + Send;
     fn find_by_id(&self, id: u64)
         -> impl Future<Output = Result<Option<Collection>, Error>> + Send;
-    fn find_by_name_and_user_id(
+    fn find_by_name_and_library_id(
         &self,
         name: &str,
-        user_id: u64,
+        library_id: u64,
     ) -> impl Future<Output = Result<Option<Collection>, Error>> + Send;
-    fn find_all_by_user_id(
+    fn find_all_by_library_id(
         &self,
-        user_id: u64,
+        library_id: u64,
     ) -> impl Future<Output = Result<Vec<Collection>, Error>> + Send;
-    fn delete_by_id(&self, id: u64) -> impl Future<Output = Result<(), Error>> + Send;
+    fn delete_by_id(
+        &self,
+        id: u64,
+        version: u64,
+    ) -> impl Future<Output = Result<bool, Error>> + Send;
 }
@@ -19,27 +19,28 @@ impl CollectionRepositoryMySQL {
 }
 
 impl CollectionRepository for CollectionRepositoryMySQL {
-    async fn save(&self, location: &Collection) -> Result<Option<Collection>, sqlx::Error> {
-        match location.id {
+    async fn save(&self, collection: &Collection) -> Result<Option<Collection>, sqlx::Error> {
+        match collection.id {
             Some(_) => todo!(),
             None => {
                 let insert_result = sqlx::query!(
                     r#"
-                    INSERT INTO collections (id, name, user_id)
-                    VALUES (DEFAULT, ?, ?)
+                    INSERT INTO collections (id, name, user_id, library_id)
+                    VALUES (DEFAULT, ?, ?, ?)
                     "#,
-                    location.name,
-                    location.user_id,
+                    collection.name,
+                    collection.user_id,
+                    collection.library_id
                 )
                 .execute(self.connection.as_ref())
                 .await;
                 match insert_result {
                     Ok(result) => {
-                        let new_location_id = result.last_insert_id();
-                        tracing::info!("Generated location ID: {}", new_location_id);
-                        match self.find_by_id(new_location_id).await {
-                            Ok(location_option) => match location_option {
-                                Some(location) => Ok(Some(location)),
+                        let new_collection_id = result.last_insert_id();
+                        tracing::info!("Generated collection ID: {}", new_collection_id);
+                        match self.find_by_id(new_collection_id).await {
+                            Ok(collection_option) => match collection_option {
+                                Some(collection) => Ok(Some(collection)),
                                 None => Ok(None),
                             },
                             Err(error) => Err(error),
@@ -56,63 +57,65 @@ impl CollectionRepository for CollectionRepositoryMySQL {
             r#"
             SELECT *
             FROM collections u
-            WHERE u.id = ?
+            WHERE u.id = ? AND u.deleted_at IS NULL
             "#,
             id
         )
-        .fetch_one(self.connection.as_ref())
+        .fetch_optional(self.connection.as_ref())
         .await;
         match query_result {
-            Ok(result) => Ok(Some(Collection {
+            Ok(result) => Ok(result.map(|result| Collection {
                 id: Some(result.id),
                 name: result.name,
                 user_id: result.user_id,
+                library_id: result.library_id,
+                version: result.version,
             })),
-            Err(error) => match error {
-                sqlx::Error::RowNotFound => Ok(None),
-                _ => Err(error),
-            },
+            Err(error) => Err(error),
         }
     }
 
-    async fn find_by_name_and_user_id(
+    async fn find_by_name_and_library_id(
         &self,
         name: &str,
-        user_id: u64,
+        library_id: u64,
     ) -> Result<Option<Collection>, sqlx::Error> {
         let query_result = sqlx::query!(
             r#"
             SELECT *
             FROM collections u
-            WHERE u.user_id = ?
+            WHERE u.library_id = ?
                 AND u.name = ?
+                AND u.deleted_at IS NULL
             "#,
-            user_id,
+            library_id,
             name
         )
-        .fetch_one(self.connection.as_ref())
+        .fetch_optional(self.connection.as_ref())
         .await;
         match query_result {
-            Ok(result) => Ok(Some(Collection {
+            Ok(result) => Ok(result.map(|result| Collection {
                 id: Some(result.id),
                 name: result.name,
                 user_id: result.user_id,
+                library_id: result.library_id,
+                version: result.version,
             })),
-            Err(error) => match error {
-                sqlx::Error::RowNotFound => Ok(None),
-                _ => Err(error),
-            },
+            Err(error) => Err(error),
         }
     }
 
-    async fn find_all_by_user_id(&self, user_id: u64) -> Result<Vec<Collection>, sqlx::Error> {
+    async fn find_all_by_library_id(
+        &self,
+        library_id: u64,
+    ) -> Result<Vec<Collection>, sqlx::Error> {
         let query_result = sqlx::query!(
             r#"
             SELECT *
             FROM collections u
-            WHERE u.user_id = ?
+            WHERE u.library_id = ? AND u.deleted_at IS NULL
             "#,
-            user_id,
+            library_id
         )
         .fetch_all(self.connection.as_ref())
         .await;
@@ -123,29 +126,28 @@ impl CollectionRepository for CollectionRepositoryMySQL {
                     id: Some(item.id),
                     name: item.name,
                     user_id: item.user_id,
+                    library_id: item.library_id,
+                    version: item.version,
                 })
                 .collect()),
             Err(error) => Err(error),
         }
     }
 
-    async fn delete_by_id(&self, id: u64) -> Result<(), sqlx::Error> {
+    async fn delete_by_id(&self, id: u64, version: u64) -> Result<bool, sqlx::Error> {
         let query_result = sqlx::query!(
             r#"
-            DELETE
-            FROM collections u
-            WHERE u.id = ?
+            UPDATE collections u SET u.deleted_at = CURRENT_TIMESTAMP, u.version = u.version + 1
+            WHERE u.id = ? AND u.version = ? AND u.deleted_at IS NULL
             "#,
             id,
+            version
         )
         .execute(self.connection.as_ref())
         .await;
         match query_result {
-            Ok(_result) => Ok(()),
-            Err(error) => match error {
-                sqlx::Error::RowNotFound => Ok(()),
-                _ => Err(error),
-            },
+            Ok(result) => Ok(result.rows_affected() > 0),
+            Err(error) => Err(error),
         }
     }
 }
@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use crate::modules::books::domain::entities::custom_field::{BookCustomFieldValue, CustomField};
+use serde_json::Value;
+use sqlx::Error;
+use std::future::Future;
+
+pub trait CustomFieldRepository {
+    fn save(
+        &self,
+        custom_field: &CustomField,
+    ) -> impl Future<Output = Result<Option<CustomField>, Error>> + Send;
+    fn find_by_id(
+        &self,
+        id: u64,
+    ) -> impl Future<Output = Result<Option<CustomField>, Error>> + Send;
+    fn find_by_name_and_user_id(
+        &self,
+        name: &str,
+        user_id: u64,
+    ) -> impl Future<Output = Result<Option<CustomField>, Error>> + Send;
+    fn find_all_by_user_id(
+        &self,
+        user_id: u64,
+    ) -> impl Future<Output = Result<Vec<CustomField>, Error>> + Send;
+    fn find_all_values_by_book_ids_and_user_id(
+        &self,
+        book_ids: &[u64],
+        user_id: u64,
+    ) -> impl Future<Output = Result<HashMap<u64, HashMap<String, Value>>, Error>> + Send;
+    fn replace_book_values(
+        &self,
+        book_id: u64,
+        user_id: u64,
+        values: &[BookCustomFieldValue],
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn delete_by_id(&self, id: u64) -> impl Future<Output = Result<(), Error>> + Send;
+}
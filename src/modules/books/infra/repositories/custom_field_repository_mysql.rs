@@ -0,0 +1,280 @@
+use chrono::NaiveDate;
+use serde_json::Value;
+use sqlx::{mysql::MySqlRow, MySqlConnection, MySqlPool, Row};
+use std::{collections::HashMap, sync::Arc};
+
+use crate::modules::books::domain::entities::custom_field::{
+    BookCustomFieldValue, CustomField, CustomFieldType, CustomFieldValue,
+};
+
+use super::custom_field_repository::CustomFieldRepository;
+
+#[derive(Clone)]
+pub struct CustomFieldRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl CustomFieldRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        CustomFieldRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn in_clause_placeholders(n_of_items: usize) -> String {
+    vec!["?"; n_of_items].join(", ")
+}
+
+pub fn custom_field_from_row(row: &MySqlRow) -> CustomField {
+    let field_type: String = row.get("field_type");
+    let options: Option<Value> = row.get("options");
+    CustomField {
+        id: Some(row.get("id")),
+        name: row.get("name"),
+        field_type: CustomFieldType::try_from(field_type.as_str()).unwrap_or_default(),
+        options: options
+            .map(|options| serde_json::from_value(options).unwrap_or_default())
+            .unwrap_or_default(),
+        user_id: row.get("user_id"),
+    }
+}
+
+pub async fn insert_book_custom_field_value(
+    connection: &mut MySqlConnection,
+    book_id: u64,
+    book_value: &BookCustomFieldValue,
+) -> Result<(), sqlx::Error> {
+    let (text, number, date, boolean) = match &book_value.value {
+        CustomFieldValue::Text(text) => (Some(text.clone()), None, None, None),
+        CustomFieldValue::Number(number) => (None, Some(*number), None, None),
+        CustomFieldValue::Date(date) => (None, None, Some(*date), None),
+        CustomFieldValue::Boolean(boolean) => (None, None, None, Some(*boolean)),
+    };
+    sqlx::query(
+        r#"
+        INSERT INTO book_custom_field_values (
+            book_id,
+            custom_field_id,
+            value_text,
+            value_number,
+            value_date,
+            value_boolean)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(book_id)
+    .bind(book_value.custom_field_id)
+    .bind(text)
+    .bind(number)
+    .bind(date)
+    .bind(boolean)
+    .execute(connection)
+    .await?;
+    Ok(())
+}
+
+fn custom_field_value_from_row(row: &MySqlRow, field_type: CustomFieldType) -> Option<Value> {
+    let value = match field_type {
+        CustomFieldType::Text | CustomFieldType::Enum => row
+            .get::<Option<String>, &str>("value_text")
+            .map(CustomFieldValue::Text),
+        CustomFieldType::Number => row
+            .get::<Option<f64>, &str>("value_number")
+            .map(CustomFieldValue::Number),
+        CustomFieldType::Date => row
+            .get::<Option<NaiveDate>, &str>("value_date")
+            .map(CustomFieldValue::Date),
+        CustomFieldType::Boolean => row
+            .get::<Option<bool>, &str>("value_boolean")
+            .map(CustomFieldValue::Boolean),
+    };
+    value.map(|value| value.to_json())
+}
+
+impl CustomFieldRepository for CustomFieldRepositoryMySQL {
+    async fn save(&self, custom_field: &CustomField) -> Result<Option<CustomField>, sqlx::Error> {
+        let options = match custom_field.field_type {
+            CustomFieldType::Enum => Some(serde_json::to_string(&custom_field.options).unwrap()),
+            _ => None,
+        };
+        let insert_result = sqlx::query(
+            r#"
+            INSERT INTO custom_fields (id, name, field_type, options, user_id)
+            VALUES (DEFAULT, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&custom_field.name)
+        .bind(custom_field.field_type.as_str())
+        .bind(options)
+        .bind(custom_field.user_id)
+        .execute(self.connection.as_ref())
+        .await;
+        match insert_result {
+            Ok(result) => {
+                let new_custom_field_id = result.last_insert_id();
+                tracing::info!("Generated custom field ID: {}", new_custom_field_id);
+                self.find_by_id(new_custom_field_id).await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn find_by_id(&self, id: u64) -> Result<Option<CustomField>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT *
+            FROM custom_fields u
+            WHERE u.id = ?
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(custom_field_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_by_name_and_user_id(
+        &self,
+        name: &str,
+        user_id: u64,
+    ) -> Result<Option<CustomField>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT *
+            FROM custom_fields u
+            WHERE u.user_id = ?
+                AND u.name = ?
+            "#,
+        )
+        .bind(user_id)
+        .bind(name)
+        .fetch_optional(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(custom_field_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_user_id(&self, user_id: u64) -> Result<Vec<CustomField>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT *
+            FROM custom_fields u
+            WHERE u.user_id = ?
+            ORDER BY u.name ASC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(custom_field_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_values_by_book_ids_and_user_id(
+        &self,
+        book_ids: &[u64],
+        user_id: u64,
+    ) -> Result<HashMap<u64, HashMap<String, Value>>, sqlx::Error> {
+        let mut values_by_book: HashMap<u64, HashMap<String, Value>> = HashMap::new();
+        if book_ids.is_empty() {
+            return Ok(values_by_book);
+        }
+        let query = format!(
+            r#"
+            SELECT
+                v.book_id,
+                f.name,
+                f.field_type,
+                v.value_text,
+                v.value_number,
+                v.value_date,
+                v.value_boolean
+            FROM book_custom_field_values v
+                INNER JOIN custom_fields f
+                    ON f.id = v.custom_field_id
+            WHERE f.user_id = ?
+                AND v.book_id IN ({})
+            "#,
+            in_clause_placeholders(book_ids.len())
+        );
+        let mut query_ps = sqlx::query(&query).bind(user_id);
+        for book_id in book_ids.iter() {
+            query_ps = query_ps.bind(book_id);
+        }
+        match query_ps.fetch_all(self.connection.as_ref()).await {
+            Ok(rows) => {
+                for row in rows.iter() {
+                    let field_type: String = row.get("field_type");
+                    let field_type =
+                        CustomFieldType::try_from(field_type.as_str()).unwrap_or_default();
+                    if let Some(value) = custom_field_value_from_row(row, field_type) {
+                        values_by_book
+                            .entry(row.get("book_id"))
+                            .or_default()
+                            .insert(row.get("name"), value);
+                    }
+                }
+                Ok(values_by_book)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Only the values of the given user's fields are replaced, the ones
+    /// other library members set on the same book are kept.
+    async fn replace_book_values(
+        &self,
+        book_id: u64,
+        user_id: u64,
+        values: &[BookCustomFieldValue],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+
+        sqlx::query(
+            r#"
+            DELETE v
+            FROM book_custom_field_values v
+                INNER JOIN custom_fields f
+                    ON f.id = v.custom_field_id
+            WHERE v.book_id = ?
+                AND f.user_id = ?
+            "#,
+        )
+        .bind(book_id)
+        .bind(user_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        for book_value in values.iter() {
+            insert_book_custom_field_value(&mut transaction, book_id, book_value).await?;
+        }
+
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn delete_by_id(&self, id: u64) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            DELETE
+            FROM custom_fields u
+            WHERE u.id = ?
+            "#,
+        )
+        .bind(id)
+        .execute(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(_result) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
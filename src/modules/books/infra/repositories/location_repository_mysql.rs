@@ -25,11 +25,12 @@ impl LocationRepository for LocationRepositoryMySQL {
             None => {
                 let insert_result = sqlx::query!(
                     r#"
-                    INSERT INTO locations (id, name, user_id)
-                    VALUES (DEFAULT, ?, ?)
+                    INSERT INTO locations (id, name, user_id, library_id)
+                    VALUES (DEFAULT, ?, ?, ?)
                     "#,
                     location.name,
                     location.user_id,
+                    location.library_id
                 )
                 .execute(self.connection.as_ref())
                 .await;
@@ -56,63 +57,62 @@ impl LocationRepository for LocationRepositoryMySQL {
             r#"
             SELECT *
             FROM locations u
-            WHERE u.id = ?
+            WHERE u.id = ? AND u.deleted_at IS NULL
             "#,
             id
         )
-        .fetch_one(self.connection.as_ref())
+        .fetch_optional(self.connection.as_ref())
         .await;
         match query_result {
-            Ok(result) => Ok(Some(Location {
+            Ok(result) => Ok(result.map(|result| Location {
                 id: Some(result.id),
                 name: result.name,
                 user_id: result.user_id,
+                library_id: result.library_id,
+                version: result.version,
             })),
-            Err(error) => match error {
-                sqlx::Error::RowNotFound => Ok(None),
-                _ => Err(error),
-            },
+            Err(error) => Err(error),
         }
     }
 
-    async fn find_by_name_and_user_id(
+    async fn find_by_name_and_library_id(
         &self,
         name: &str,
-        user_id: u64,
+        library_id: u64,
     ) -> Result<Option<Location>, sqlx::Error> {
         let query_result = sqlx::query!(
             r#"
             SELECT *
             FROM locations u
-            WHERE u.user_id = ?
+            WHERE u.library_id = ?
                 AND u.name = ?
+                AND u.deleted_at IS NULL
             "#,
-            user_id,
+            library_id,
             name
         )
-        .fetch_one(self.connection.as_ref())
+        .fetch_optional(self.connection.as_ref())
         .await;
         match query_result {
-            Ok(result) => Ok(Some(Location {
+            Ok(result) => Ok(result.map(|result| Location {
                 id: Some(result.id),
                 name: result.name,
                 user_id: result.user_id,
+                library_id: result.library_id,
+                version: result.version,
             })),
-            Err(error) => match error {
-                sqlx::Error::RowNotFound => Ok(None),
-                _ => Err(error),
-            },
+            Err(error) => Err(error),
         }
     }
 
-    async fn find_all_by_user_id(&self, user_id: u64) -> Result<Vec<Location>, sqlx::Error> {
+    async fn find_all_by_library_id(&self, library_id: u64) -> Result<Vec<Location>, sqlx::Error> {
         let query_result = sqlx::query!(
             r#"
             SELECT *
             FROM locations u
-            WHERE u.user_id = ?
+            WHERE u.library_id = ? AND u.deleted_at IS NULL
             "#,
-            user_id,
+            library_id
         )
         .fetch_all(self.connection.as_ref())
         .await;
@@ -123,29 +123,45 @@ impl LocationRepository for LocationRepositoryMySQL {
                     id: Some(item.id),
                     name: item.name,
                     user_id: item.user_id,
+                    library_id: item.library_id,
+                    version: item.version,
                 })
                 .collect()),
             Err(error) => Err(error),
         }
     }
 
-    async fn delete_by_id(&self, id: u64) -> Result<(), sqlx::Error> {
+    async fn count_books_by_id(&self, id: u64) -> Result<u64, sqlx::Error> {
         let query_result = sqlx::query!(
             r#"
-            DELETE
-            FROM locations u
-            WHERE u.id = ?
+            SELECT COUNT(*) as n_books
+            FROM books b
+            WHERE b.location_id = ? AND b.deleted_at IS NULL
+            "#,
+            id
+        )
+        .fetch_one(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(result) => Ok(u64::from_ne_bytes(result.n_books.to_ne_bytes())),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn delete_by_id(&self, id: u64, version: u64) -> Result<bool, sqlx::Error> {
+        let query_result = sqlx::query!(
+            r#"
+            UPDATE locations u SET u.deleted_at = CURRENT_TIMESTAMP, u.version = u.version + 1
+            WHERE u.id = ? AND u.version = ? AND u.deleted_at IS NULL
             "#,
             id,
+            version
         )
         .execute(self.connection.as_ref())
         .await;
         match query_result {
-            Ok(_result) => Ok(()),
-            Err(error) => match error {
-                sqlx::Error::RowNotFound => Ok(()),
-                _ => Err(error),
-            },
+            Ok(result) => Ok(result.rows_affected() > 0),
+            Err(error) => Err(error),
         }
     }
 }
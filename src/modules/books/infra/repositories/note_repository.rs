@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    books::domain::{dtos::note_dto::NoteDto, entities::note::Note},
+    shared::domain::dtos::paginated_dto::PaginatedDto,
+};
+use sqlx::Error;
+use std::future::Future;
+
+pub trait NoteRepository {
+    fn save(&self, note: &Note) -> impl Future<Output = Result<Option<Note>, Error>> + Send;
+    fn find_by_id(&self, id: u64) -> impl Future<Output = Result<Option<Note>, Error>> + Send;
+    fn find_all_by_book_id_and_user_id(
+        &self,
+        book_id: u64,
+        user_id: u64,
+    ) -> impl Future<Output = Result<Vec<NoteDto>, Error>> + Send;
+    fn search_by_user_id(
+        &self,
+        user_id: u64,
+        page: u64,
+        page_size: u64,
+        query: Option<String>,
+        note_type: Option<&str>,
+    ) -> impl Future<Output = Result<PaginatedDto<NoteDto>, Error>> + Send;
+    fn count_by_book_ids_and_user_id(
+        &self,
+        book_ids: &[u64],
+        user_id: u64,
+    ) -> impl Future<Output = Result<HashMap<u64, u64>, Error>> + Send;
+    fn delete_by_id(&self, id: u64) -> impl Future<Output = Result<(), Error>> + Send;
+}
@@ -0,0 +1,265 @@
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, MySqlPool, Row};
+use std::{collections::HashMap, sync::Arc};
+
+use crate::modules::{
+    books::domain::{
+        dtos::note_dto::NoteDto,
+        entities::note::{Note, NoteType},
+    },
+    shared::domain::dtos::paginated_dto::PaginatedDto,
+};
+
+use super::note_repository::NoteRepository;
+
+const QUERY_CONDITIONAL: &str = r#"
+    AND lower(n.text) LIKE CONCAT('%', ?, '%')
+"#;
+
+const NOTE_TYPE_CONDITIONAL: &str = r#"
+    AND n.note_type = ?
+"#;
+
+#[derive(Clone)]
+pub struct NoteRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl NoteRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        NoteRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn in_clause_placeholders(n_of_items: usize) -> String {
+    vec!["?"; n_of_items].join(", ")
+}
+
+fn note_from_row(row: &MySqlRow) -> Note {
+    let note_type: String = row.get("note_type");
+    Note {
+        id: Some(row.get("id")),
+        book_id: row.get("book_id"),
+        user_id: row.get("user_id"),
+        note_type: NoteType::try_from(note_type.as_str()).unwrap_or_default(),
+        page: row.get("page"),
+        text: row.get("text"),
+        created_at: Some(row.get::<DateTime<Utc>, &str>("created_at")),
+        updated_at: Some(row.get::<DateTime<Utc>, &str>("updated_at")),
+    }
+}
+
+fn note_dto_from_row(row: &MySqlRow) -> NoteDto {
+    NoteDto {
+        book_title: row.get("book_title"),
+        ..NoteDto::from(note_from_row(row))
+    }
+}
+
+impl NoteRepository for NoteRepositoryMySQL {
+    async fn save(&self, note: &Note) -> Result<Option<Note>, sqlx::Error> {
+        match note.id {
+            Some(note_id) => {
+                let update_result = sqlx::query(
+                    r#"
+                    UPDATE notes SET note_type = ?, page = ?, text = ?
+                    WHERE id = ? AND user_id = ?
+                    "#,
+                )
+                .bind(note.note_type.as_str())
+                .bind(note.page)
+                .bind(&note.text)
+                .bind(note_id)
+                .bind(note.user_id)
+                .execute(self.connection.as_ref())
+                .await;
+                match update_result {
+                    Ok(_) => self.find_by_id(note_id).await,
+                    Err(e) => Err(e),
+                }
+            }
+            None => {
+                let insert_result = sqlx::query(
+                    r#"
+                    INSERT INTO notes (id, book_id, user_id, note_type, page, text)
+                    VALUES (DEFAULT, ?, ?, ?, ?, ?)
+                    "#,
+                )
+                .bind(note.book_id)
+                .bind(note.user_id)
+                .bind(note.note_type.as_str())
+                .bind(note.page)
+                .bind(&note.text)
+                .execute(self.connection.as_ref())
+                .await;
+                match insert_result {
+                    Ok(result) => {
+                        let new_note_id = result.last_insert_id();
+                        tracing::info!("Generated note ID: {}", new_note_id);
+                        self.find_by_id(new_note_id).await
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        }
+    }
+
+    async fn find_by_id(&self, id: u64) -> Result<Option<Note>, sqlx::Error> {
+        let query_result = sqlx::query("SELECT * FROM notes n WHERE n.id = ?")
+            .bind(id)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(note_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_book_id_and_user_id(
+        &self,
+        book_id: u64,
+        user_id: u64,
+    ) -> Result<Vec<NoteDto>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT n.*, b.title 'book_title'
+            FROM notes n
+            INNER JOIN books b ON b.id = n.book_id
+            WHERE n.book_id = ? AND n.user_id = ?
+            ORDER BY n.page IS NULL, n.page, n.created_at, n.id
+            "#,
+        )
+        .bind(book_id)
+        .bind(user_id)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(note_dto_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn search_by_user_id(
+        &self,
+        user_id: u64,
+        page: u64,
+        page_size: u64,
+        query: Option<String>,
+        note_type: Option<&str>,
+    ) -> Result<PaginatedDto<NoteDto>, sqlx::Error> {
+        let mut conditionals = String::new();
+        if query.is_some() {
+            conditionals.push_str(QUERY_CONDITIONAL);
+        }
+        if note_type.is_some() {
+            conditionals.push_str(NOTE_TYPE_CONDITIONAL);
+        }
+        let lowercase_query = query.map(|query| query.to_lowercase());
+
+        let count_query = format!(
+            r#"
+            SELECT COUNT(*) as n_notes
+            FROM notes n
+            INNER JOIN books b ON b.id = n.book_id AND b.deleted_at IS NULL
+            WHERE n.user_id = ? {}
+            "#,
+            conditionals
+        );
+        let mut count_query_ps = sqlx::query(&count_query).bind(user_id);
+        if let Some(lowercase_query) = &lowercase_query {
+            count_query_ps = count_query_ps.bind(lowercase_query);
+        }
+        if let Some(note_type) = note_type {
+            count_query_ps = count_query_ps.bind(note_type);
+        }
+        let n_of_notes: u64 = match count_query_ps.fetch_one(self.connection.as_ref()).await {
+            Ok(row) => {
+                let n_notes: i64 = row.get(0);
+                u64::from_ne_bytes(n_notes.to_ne_bytes())
+            }
+            Err(e) => return Err(e),
+        };
+
+        let main_query = format!(
+            r#"
+            SELECT n.*, b.title 'book_title'
+            FROM notes n
+            INNER JOIN books b ON b.id = n.book_id AND b.deleted_at IS NULL
+            WHERE n.user_id = ? {}
+            ORDER BY n.updated_at DESC, n.id DESC
+            LIMIT ? OFFSET ?
+            "#,
+            conditionals
+        );
+        let mut query_ps = sqlx::query(&main_query).bind(user_id);
+        if let Some(lowercase_query) = &lowercase_query {
+            query_ps = query_ps.bind(lowercase_query);
+        }
+        if let Some(note_type) = note_type {
+            query_ps = query_ps.bind(note_type);
+        }
+        query_ps = query_ps.bind(page_size).bind((page - 1) * page_size);
+
+        match query_ps.fetch_all(self.connection.as_ref()).await {
+            Ok(rows) => Ok(PaginatedDto {
+                page: Some(page),
+                page_size,
+                total_items: Some(n_of_notes),
+                items: rows.iter().map(note_dto_from_row).collect(),
+                ..Default::default()
+            }),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn count_by_book_ids_and_user_id(
+        &self,
+        book_ids: &[u64],
+        user_id: u64,
+    ) -> Result<HashMap<u64, u64>, sqlx::Error> {
+        if book_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let query = format!(
+            r#"
+            SELECT n.book_id, COUNT(*) as n_notes
+            FROM notes n
+            WHERE n.user_id = ? AND n.book_id IN ({})
+            GROUP BY n.book_id
+            "#,
+            in_clause_placeholders(book_ids.len())
+        );
+        let mut query_ps = sqlx::query(&query).bind(user_id);
+        for book_id in book_ids.iter() {
+            query_ps = query_ps.bind(book_id);
+        }
+
+        match query_ps.fetch_all(self.connection.as_ref()).await {
+            Ok(rows) => Ok(rows
+                .iter()
+                .map(|row| {
+                    let n_notes: i64 = row.get("n_notes");
+                    (
+                        row.get("book_id"),
+                        u64::from_ne_bytes(n_notes.to_ne_bytes()),
+                    )
+                })
+                .collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn delete_by_id(&self, id: u64) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query("DELETE FROM notes WHERE id = ?")
+            .bind(id)
+            .execute(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use crate::modules::books::domain::entities::share_link::ShareLink;
+use sqlx::Error;
+use std::future::Future;
+
+pub trait ShareLinkRepository {
+    fn save(
+        &self,
+        share_link: &ShareLink,
+    ) -> impl Future<Output = Result<Option<ShareLink>, Error>> + Send;
+    fn find_by_id(&self, id: u64) -> impl Future<Output = Result<Option<ShareLink>, Error>> + Send;
+    fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> impl Future<Output = Result<Option<ShareLink>, Error>> + Send;
+    fn find_all_by_library_id(
+        &self,
+        library_id: u64,
+    ) -> impl Future<Output = Result<Vec<ShareLink>, Error>> + Send;
+    fn revoke_by_id(&self, id: u64) -> impl Future<Output = Result<(), Error>> + Send;
+}
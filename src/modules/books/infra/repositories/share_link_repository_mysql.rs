@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, MySqlPool, Row};
+use std::sync::Arc;
+
+use crate::modules::books::domain::entities::share_link::{ShareLink, ShareLinkScope};
+
+use super::share_link_repository::ShareLinkRepository;
+
+#[derive(Clone)]
+pub struct ShareLinkRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl ShareLinkRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        ShareLinkRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn share_link_from_row(row: &MySqlRow) -> ShareLink {
+    let scope = match row.get::<String, &str>("scope").as_str() {
+        "location" => {
+            ShareLinkScope::Location(row.get::<Option<u64>, &str>("location_id").unwrap())
+        }
+        "collection" => {
+            ShareLinkScope::Collection(row.get::<Option<u64>, &str>("collection_id").unwrap())
+        }
+        _ => ShareLinkScope::Library,
+    };
+    ShareLink {
+        id: Some(row.get("id")),
+        slug: row.get("slug"),
+        scope,
+        user_id: row.get("user_id"),
+        library_id: row.get("library_id"),
+        expires_at: row.get::<Option<DateTime<Utc>>, &str>("expires_at"),
+        revoked_at: row.get::<Option<DateTime<Utc>>, &str>("revoked_at"),
+        created_at: Some(row.get::<DateTime<Utc>, &str>("created_at")),
+    }
+}
+
+impl ShareLinkRepository for ShareLinkRepositoryMySQL {
+    async fn save(&self, share_link: &ShareLink) -> Result<Option<ShareLink>, sqlx::Error> {
+        let (location_id, collection_id) = match share_link.scope {
+            ShareLinkScope::Library => (None, None),
+            ShareLinkScope::Location(location_id) => (Some(location_id), None),
+            ShareLinkScope::Collection(collection_id) => (None, Some(collection_id)),
+        };
+        let insert_result = sqlx::query(
+            r#"
+            INSERT INTO share_links (id, slug, scope, location_id, collection_id, user_id, library_id, expires_at)
+            VALUES (DEFAULT, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&share_link.slug)
+        .bind(share_link.scope.as_str())
+        .bind(location_id)
+        .bind(collection_id)
+        .bind(share_link.user_id)
+        .bind(share_link.library_id)
+        .bind(share_link.expires_at)
+        .execute(self.connection.as_ref())
+        .await;
+        match insert_result {
+            Ok(result) => {
+                let new_share_link_id = result.last_insert_id();
+                tracing::info!("Generated share link ID: {}", new_share_link_id);
+                self.find_by_id(new_share_link_id).await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn find_by_id(&self, id: u64) -> Result<Option<ShareLink>, sqlx::Error> {
+        let query_result = sqlx::query("SELECT * FROM share_links s WHERE s.id = ?")
+            .bind(id)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(share_link_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<ShareLink>, sqlx::Error> {
+        let query_result = sqlx::query("SELECT * FROM share_links s WHERE s.slug = ?")
+            .bind(slug)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(share_link_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_library_id(&self, library_id: u64) -> Result<Vec<ShareLink>, sqlx::Error> {
+        let query_result =
+            sqlx::query("SELECT * FROM share_links s WHERE s.library_id = ? ORDER BY s.id DESC")
+                .bind(library_id)
+                .fetch_all(self.connection.as_ref())
+                .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(share_link_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn revoke_by_id(&self, id: u64) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query(
+            "UPDATE share_links SET revoked_at = CURRENT_TIMESTAMP WHERE id = ? AND revoked_at IS NULL",
+        )
+        .bind(id)
+        .execute(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use crate::modules::books::domain::{
+    dtos::{tag_dto::TagDto, tag_usage_dto::TagUsageDto},
+    entities::tag::Tag,
+};
+use sqlx::Error;
+use std::future::Future;
+
+pub trait TagRepository {
+    fn save(&self, tag: &Tag) -> impl Future<Output = Result<Option<Tag>, Error>> + Send;
+    fn find_by_id(&self, id: u64) -> impl Future<Output = Result<Option<Tag>, Error>> + Send;
+    fn find_by_name_and_user_id(
+        &self,
+        name: &str,
+        user_id: u64,
+    ) -> impl Future<Output = Result<Option<Tag>, Error>> + Send;
+    fn find_all_by_ids(&self, ids: &[u64]) -> impl Future<Output = Result<Vec<Tag>, Error>> + Send;
+    fn find_all_by_user_id_with_usage(
+        &self,
+        user_id: u64,
+    ) -> impl Future<Output = Result<Vec<TagUsageDto>, Error>> + Send;
+    fn find_all_by_book_ids_and_user_id(
+        &self,
+        book_ids: &[u64],
+        user_id: u64,
+    ) -> impl Future<Output = Result<HashMap<u64, Vec<TagDto>>, Error>> + Send;
+    fn replace_book_tags(
+        &self,
+        book_id: u64,
+        user_id: u64,
+        tag_ids: &[u64],
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn delete_by_id(&self, id: u64) -> impl Future<Output = Result<(), Error>> + Send;
+}
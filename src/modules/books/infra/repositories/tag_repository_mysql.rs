@@ -0,0 +1,277 @@
+use sqlx::{mysql::MySqlRow, MySqlPool, Row};
+use std::{collections::HashMap, sync::Arc};
+
+use crate::modules::books::domain::{
+    dtos::{tag_dto::TagDto, tag_usage_dto::TagUsageDto},
+    entities::tag::Tag,
+};
+
+use super::tag_repository::TagRepository;
+
+#[derive(Clone)]
+pub struct TagRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl TagRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        TagRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn in_clause_placeholders(n_of_items: usize) -> String {
+    vec!["?"; n_of_items].join(", ")
+}
+
+fn tag_from_row(row: &MySqlRow) -> Tag {
+    Tag {
+        id: Some(row.get("id")),
+        name: row.get("name"),
+        user_id: row.get("user_id"),
+    }
+}
+
+impl TagRepository for TagRepositoryMySQL {
+    async fn save(&self, tag: &Tag) -> Result<Option<Tag>, sqlx::Error> {
+        match tag.id {
+            Some(tag_id) => {
+                let update_result = sqlx::query(
+                    r#"
+                    UPDATE tags SET name = ?
+                    WHERE id = ? AND user_id = ?
+                    "#,
+                )
+                .bind(&tag.name)
+                .bind(tag_id)
+                .bind(tag.user_id)
+                .execute(self.connection.as_ref())
+                .await;
+                match update_result {
+                    Ok(_) => self.find_by_id(tag_id).await,
+                    Err(e) => Err(e),
+                }
+            }
+            None => {
+                let insert_result = sqlx::query(
+                    r#"
+                    INSERT INTO tags (id, name, user_id)
+                    VALUES (DEFAULT, ?, ?)
+                    "#,
+                )
+                .bind(&tag.name)
+                .bind(tag.user_id)
+                .execute(self.connection.as_ref())
+                .await;
+                match insert_result {
+                    Ok(result) => {
+                        let new_tag_id = result.last_insert_id();
+                        tracing::info!("Generated tag ID: {}", new_tag_id);
+                        self.find_by_id(new_tag_id).await
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        }
+    }
+
+    async fn find_by_id(&self, id: u64) -> Result<Option<Tag>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT *
+            FROM tags u
+            WHERE u.id = ?
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(tag_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_by_name_and_user_id(
+        &self,
+        name: &str,
+        user_id: u64,
+    ) -> Result<Option<Tag>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT *
+            FROM tags u
+            WHERE u.user_id = ?
+                AND u.name = ?
+            "#,
+        )
+        .bind(user_id)
+        .bind(name)
+        .fetch_optional(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(tag_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_ids(&self, ids: &[u64]) -> Result<Vec<Tag>, sqlx::Error> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let query = format!(
+            r#"
+            SELECT *
+            FROM tags u
+            WHERE u.id IN ({})
+            "#,
+            in_clause_placeholders(ids.len())
+        );
+        let mut query_ps = sqlx::query(&query);
+        for id in ids.iter() {
+            query_ps = query_ps.bind(id);
+        }
+        match query_ps.fetch_all(self.connection.as_ref()).await {
+            Ok(rows) => Ok(rows.iter().map(tag_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_user_id_with_usage(
+        &self,
+        user_id: u64,
+    ) -> Result<Vec<TagUsageDto>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT t.id, t.name, COUNT(b.id) 'usage_count'
+            FROM tags t
+                LEFT JOIN book_tags bt
+                    ON bt.tag_id = t.id
+                LEFT JOIN books b
+                    ON b.id = bt.book_id AND b.deleted_at IS NULL
+            WHERE t.user_id = ?
+            GROUP BY t.id, t.name
+            ORDER BY t.name ASC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows
+                .iter()
+                .map(|row| {
+                    let usage_count: i64 = row.get("usage_count");
+                    TagUsageDto {
+                        id: row.get("id"),
+                        name: row.get("name"),
+                        usage_count: u64::from_ne_bytes(usage_count.to_ne_bytes()),
+                    }
+                })
+                .collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_book_ids_and_user_id(
+        &self,
+        book_ids: &[u64],
+        user_id: u64,
+    ) -> Result<HashMap<u64, Vec<TagDto>>, sqlx::Error> {
+        let mut tags_by_book: HashMap<u64, Vec<TagDto>> = HashMap::new();
+        if book_ids.is_empty() {
+            return Ok(tags_by_book);
+        }
+        let query = format!(
+            r#"
+            SELECT bt.book_id, t.id, t.name
+            FROM book_tags bt
+                INNER JOIN tags t
+                    ON t.id = bt.tag_id
+            WHERE t.user_id = ?
+                AND bt.book_id IN ({})
+            ORDER BY t.name ASC
+            "#,
+            in_clause_placeholders(book_ids.len())
+        );
+        let mut query_ps = sqlx::query(&query).bind(user_id);
+        for book_id in book_ids.iter() {
+            query_ps = query_ps.bind(book_id);
+        }
+        match query_ps.fetch_all(self.connection.as_ref()).await {
+            Ok(rows) => {
+                for row in rows.iter() {
+                    tags_by_book
+                        .entry(row.get("book_id"))
+                        .or_default()
+                        .push(TagDto {
+                            id: row.get("id"),
+                            name: row.get("name"),
+                        });
+                }
+                Ok(tags_by_book)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Only the tags of the given user are replaced, the ones other library
+    /// members put on the same book are kept.
+    async fn replace_book_tags(
+        &self,
+        book_id: u64,
+        user_id: u64,
+        tag_ids: &[u64],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+
+        sqlx::query(
+            r#"
+            DELETE bt
+            FROM book_tags bt
+                INNER JOIN tags t
+                    ON t.id = bt.tag_id
+            WHERE bt.book_id = ?
+                AND t.user_id = ?
+            "#,
+        )
+        .bind(book_id)
+        .bind(user_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        for tag_id in tag_ids.iter() {
+            sqlx::query(
+                r#"
+                INSERT INTO book_tags (book_id, tag_id)
+                VALUES (?, ?)
+                "#,
+            )
+            .bind(book_id)
+            .bind(tag_id)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn delete_by_id(&self, id: u64) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            DELETE
+            FROM tags u
+            WHERE u.id = ?
+            "#,
+        )
+        .bind(id)
+        .execute(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(_result) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
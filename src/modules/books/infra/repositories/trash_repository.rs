@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+
+use crate::modules::books::domain::entities::{
+    audit_entry::AuditEntry,
+    trash::{TrashItem, TrashItemType},
+};
+use sqlx::Error;
+use std::future::Future;
+
+pub trait TrashRepository {
+    fn find_all_by_library_id(
+        &self,
+        library_id: u64,
+    ) -> impl Future<Output = Result<Vec<TrashItem>, Error>> + Send;
+    fn find_by_id(
+        &self,
+        item_type: TrashItemType,
+        id: u64,
+    ) -> impl Future<Output = Result<Option<TrashItem>, Error>> + Send;
+    fn restore(
+        &self,
+        item_type: TrashItemType,
+        id: u64,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn purge(
+        &self,
+        item_type: TrashItemType,
+        id: u64,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn purge_trashed_before(
+        &self,
+        deleted_before: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<AuditEntry>, Error>> + Send;
+}
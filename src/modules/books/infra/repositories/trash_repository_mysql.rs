@@ -0,0 +1,294 @@
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, MySqlConnection, MySqlPool, Row};
+use std::sync::Arc;
+
+use crate::modules::books::domain::entities::{
+    audit_entry::{AuditAction, AuditEntityType, AuditEntry, SYSTEM_ACTOR_ID},
+    trash::{TrashItem, TrashItemType},
+};
+
+use super::{audit_repository_mysql::insert_audit_entries, trash_repository::TrashRepository};
+
+const PURGE_BATCH_SIZE: usize = 1000;
+
+#[derive(Clone)]
+pub struct TrashRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl TrashRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        TrashRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn in_clause_placeholders(n_of_items: usize) -> String {
+    vec!["?"; n_of_items].join(", ")
+}
+
+/// Deletes the rows selected by `select_query`, which must return their
+/// `id` and `library_id`, and audits each of them as purged by the system.
+async fn purge_selected(
+    connection: &mut MySqlConnection,
+    entity_type: AuditEntityType,
+    table: &str,
+    select_query: &str,
+    deleted_before: DateTime<Utc>,
+    audit_entries: &mut Vec<AuditEntry>,
+) -> Result<(), sqlx::Error> {
+    let rows = sqlx::query(select_query)
+        .bind(deleted_before)
+        .fetch_all(&mut *connection)
+        .await?;
+    let ids: Vec<u64> = rows.iter().map(|row| row.get("id")).collect();
+    for ids_batch in ids.chunks(PURGE_BATCH_SIZE) {
+        let delete_query = format!(
+            "DELETE FROM {} WHERE id IN ({})",
+            table,
+            in_clause_placeholders(ids_batch.len())
+        );
+        let mut delete_query_ps = sqlx::query(&delete_query);
+        for id in ids_batch.iter() {
+            delete_query_ps = delete_query_ps.bind(id);
+        }
+        delete_query_ps.execute(&mut *connection).await?;
+    }
+
+    audit_entries.extend(rows.iter().map(|row| {
+        AuditEntry::new(
+            entity_type,
+            row.get("id"),
+            row.get("library_id"),
+            SYSTEM_ACTOR_ID,
+            AuditAction::Purge,
+            None,
+            None,
+        )
+    }));
+    Ok(())
+}
+
+/// Table and name column of each kind of item, selected with the same
+/// aliases so rows are mapped by `trash_item_from_row`.
+fn trash_item_select(item_type: TrashItemType) -> &'static str {
+    match item_type {
+        TrashItemType::Book => {
+            r#"
+            SELECT 'books' 'item_type', t.id, t.title 'name', t.library_id,
+                t.location_id, t.deleted_at
+            FROM books t
+            "#
+        }
+        TrashItemType::Collection => {
+            r#"
+            SELECT 'collections' 'item_type', t.id, t.name, t.library_id,
+                NULL 'location_id', t.deleted_at
+            FROM collections t
+            "#
+        }
+        TrashItemType::Location => {
+            r#"
+            SELECT 'locations' 'item_type', t.id, t.name, t.library_id,
+                NULL 'location_id', t.deleted_at
+            FROM locations t
+            "#
+        }
+    }
+}
+
+fn table_name(item_type: TrashItemType) -> &'static str {
+    match item_type {
+        TrashItemType::Book => "books",
+        TrashItemType::Collection => "collections",
+        TrashItemType::Location => "locations",
+    }
+}
+
+fn trash_item_from_row(row: &MySqlRow) -> TrashItem {
+    let item_type: String = row.get("item_type");
+    TrashItem {
+        id: row.get("id"),
+        item_type: TrashItemType::try_from(item_type.as_str()).unwrap_or(TrashItemType::Book),
+        name: row.get("name"),
+        library_id: row.get("library_id"),
+        location_id: row.get("location_id"),
+        deleted_at: row.get::<Option<DateTime<Utc>>, &str>("deleted_at"),
+    }
+}
+
+impl TrashRepository for TrashRepositoryMySQL {
+    async fn find_all_by_library_id(&self, library_id: u64) -> Result<Vec<TrashItem>, sqlx::Error> {
+        let query = [
+            TrashItemType::Book,
+            TrashItemType::Collection,
+            TrashItemType::Location,
+        ]
+        .iter()
+        .map(|item_type| {
+            format!(
+                "({} WHERE t.library_id = ? AND t.deleted_at IS NOT NULL)",
+                trash_item_select(*item_type)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(" UNION ALL ")
+            + " ORDER BY deleted_at DESC, id DESC";
+
+        let query_result = sqlx::query(&query)
+            .bind(library_id)
+            .bind(library_id)
+            .bind(library_id)
+            .fetch_all(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(trash_item_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        item_type: TrashItemType,
+        id: u64,
+    ) -> Result<Option<TrashItem>, sqlx::Error> {
+        let query = format!(
+            "{} WHERE t.id = ? AND t.deleted_at IS NOT NULL",
+            trash_item_select(item_type)
+        );
+        let query_result = sqlx::query(&query)
+            .bind(id)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(trash_item_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn restore(&self, item_type: TrashItemType, id: u64) -> Result<(), sqlx::Error> {
+        let query = format!(
+            "UPDATE {} SET deleted_at = NULL, version = version + 1 WHERE id = ?",
+            table_name(item_type)
+        );
+        let query_result = sqlx::query(&query)
+            .bind(id)
+            .execute(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Books referencing a purged collection lose it, trashed books of a
+    /// purged location are purged along with it.
+    async fn purge(&self, item_type: TrashItemType, id: u64) -> Result<(), sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+        match item_type {
+            TrashItemType::Book => {}
+            TrashItemType::Collection => {
+                sqlx::query(
+                    "UPDATE books SET collection_id = NULL, version = version + 1 WHERE collection_id = ?",
+                )
+                    .bind(id)
+                    .execute(&mut *transaction)
+                    .await?;
+            }
+            TrashItemType::Location => {
+                sqlx::query("DELETE FROM books WHERE location_id = ? AND deleted_at IS NOT NULL")
+                    .bind(id)
+                    .execute(&mut *transaction)
+                    .await?;
+            }
+        }
+        let query = format!(
+            "DELETE FROM {} WHERE id = ? AND deleted_at IS NOT NULL",
+            table_name(item_type)
+        );
+        sqlx::query(&query)
+            .bind(id)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+
+        tracing::info!("Purged {} {} from the trash", item_type.as_str(), id);
+        Ok(())
+    }
+
+    async fn purge_trashed_before(
+        &self,
+        deleted_before: DateTime<Utc>,
+    ) -> Result<Vec<AuditEntry>, sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+        let mut audit_entries = Vec::new();
+
+        purge_selected(
+            &mut transaction,
+            AuditEntityType::Book,
+            "books",
+            r#"
+            SELECT b.id, b.library_id
+            FROM books b
+                INNER JOIN locations l
+                    ON l.id = b.location_id
+            WHERE l.deleted_at < ? AND b.deleted_at IS NOT NULL
+            FOR UPDATE
+            "#,
+            deleted_before,
+            &mut audit_entries,
+        )
+        .await?;
+        purge_selected(
+            &mut transaction,
+            AuditEntityType::Book,
+            "books",
+            "SELECT id, library_id FROM books WHERE deleted_at < ? FOR UPDATE",
+            deleted_before,
+            &mut audit_entries,
+        )
+        .await?;
+        sqlx::query(
+            r#"
+            UPDATE books b
+                INNER JOIN collections c
+                    ON c.id = b.collection_id
+            SET b.collection_id = NULL, b.version = b.version + 1
+            WHERE c.deleted_at < ?
+            "#,
+        )
+        .bind(deleted_before)
+        .execute(&mut *transaction)
+        .await?;
+        purge_selected(
+            &mut transaction,
+            AuditEntityType::Collection,
+            "collections",
+            "SELECT id, library_id FROM collections WHERE deleted_at < ? FOR UPDATE",
+            deleted_before,
+            &mut audit_entries,
+        )
+        .await?;
+        // Locations still holding books are kept until those are gone
+        purge_selected(
+            &mut transaction,
+            AuditEntityType::Location,
+            "locations",
+            r#"
+            SELECT l.id, l.library_id
+            FROM locations l
+            WHERE l.deleted_at < ?
+                AND NOT EXISTS (SELECT 1 FROM books b WHERE b.location_id = l.id)
+            FOR UPDATE
+            "#,
+            deleted_before,
+            &mut audit_entries,
+        )
+        .await?;
+
+        insert_audit_entries(&mut transaction, &audit_entries).await?;
+        transaction.commit().await?;
+        Ok(audit_entries)
+    }
+}
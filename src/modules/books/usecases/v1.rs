@@ -1,10 +1,41 @@
+pub mod authorize_tag_owner_usecase;
+pub mod backup_library_usecase;
+pub mod bulk_book_operation_usecase;
 pub mod create_collection_usecase;
+pub mod create_custom_field_usecase;
 pub mod create_location_usecase;
+pub mod create_share_link_usecase;
+pub mod create_tag_usecase;
 pub mod create_update_book_usecase;
+pub mod create_update_note_usecase;
 pub mod delete_book_usecase;
 pub mod delete_collection_usecase;
+pub mod delete_custom_field_usecase;
 pub mod delete_location_usecase;
+pub mod delete_note_usecase;
+pub mod delete_tag_usecase;
+pub mod export_books_usecase;
 pub mod find_all_books_from_user_usecase;
 pub mod find_all_collection_from_user_usecase;
+pub mod find_all_custom_fields_from_user_usecase;
 pub mod find_all_location_from_user_usecase;
+pub mod find_all_notes_from_book_usecase;
+pub mod find_all_share_links_from_user_usecase;
+pub mod find_all_shared_books_usecase;
+pub mod find_all_tags_from_user_usecase;
+pub mod find_all_trash_items_usecase;
+pub mod find_book_by_barcode_usecase;
 pub mod find_book_by_id_usecase;
+pub mod find_book_history_usecase;
+pub mod import_books_usecase;
+pub mod patch_book_usecase;
+pub mod purge_expired_trash_usecase;
+pub mod purge_trash_item_usecase;
+pub mod record_audit_entries_usecase;
+pub mod rename_tag_usecase;
+pub mod resolve_book_listing_options_usecase;
+pub mod restore_library_usecase;
+pub mod restore_trash_item_usecase;
+pub mod revert_book_usecase;
+pub mod revoke_share_link_usecase;
+pub mod search_notes_usecase;
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::infra::repositories::{
+        tag_repository::TagRepository, tag_repository_mysql::TagRepositoryMySQL,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct AuthorizeTagOwnerUseCaseV1<T>
+where
+    T: TagRepository,
+{
+    tag_repository: Arc<T>,
+}
+
+impl AuthorizeTagOwnerUseCaseV1<TagRepositoryMySQL> {
+    pub fn new(tag_repository: TagRepositoryMySQL) -> Self {
+        Self {
+            tag_repository: Arc::new(tag_repository),
+        }
+    }
+
+    pub async fn authorize(&self, user_id: u64, tag_ids: &[u64]) -> Result<(), APIError> {
+        match self.tag_repository.find_all_by_ids(tag_ids).await {
+            Ok(found_tags) => {
+                let owned_tags = found_tags
+                    .iter()
+                    .filter(|tag| tag.user_id == user_id)
+                    .count();
+                if owned_tags == tag_ids.len() {
+                    Ok(())
+                } else {
+                    Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "Tag not found".to_string(),
+                        404,
+                    )))
+                }
+            }
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::{
+                backup_book_dto::BackupBookDto, backup_collection_dto::BackupCollectionDto,
+                backup_custom_field_dto::BackupCustomFieldDto,
+                backup_location_dto::BackupLocationDto, backup_tag_dto::BackupTagDto,
+                library_backup_manifest_dto::LibraryBackupManifestDto, tag_dto::TagDto,
+            },
+            entities::library_backup::LIBRARY_BACKUP_SCHEMA_VERSION,
+        },
+        infra::{
+            archives::library_backup_archive::write_library_backup_archive,
+            repositories::{
+                book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+                collection_repository::CollectionRepository,
+                collection_repository_mysql::CollectionRepositoryMySQL,
+                custom_field_repository::CustomFieldRepository,
+                custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+                location_repository::LocationRepository,
+                location_repository_mysql::LocationRepositoryMySQL, tag_repository::TagRepository,
+                tag_repository_mysql::TagRepositoryMySQL,
+            },
+        },
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct BackupLibraryUseCaseV1<T, U, V, W, X, Y>
+where
+    T: BookRepository,
+    U: CollectionRepository,
+    V: LocationRepository,
+    W: LibraryRepository,
+    X: TagRepository,
+    Y: CustomFieldRepository,
+{
+    book_repository: Arc<T>,
+    collection_repository: Arc<U>,
+    location_repository: Arc<V>,
+    tag_repository: Arc<X>,
+    custom_field_repository: Arc<Y>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<W>,
+}
+
+impl
+    BackupLibraryUseCaseV1<
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+    >
+{
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+    ) -> Self {
+        Self {
+            book_repository: Arc::new(book_repository),
+            collection_repository: Arc::new(collection_repository),
+            location_repository: Arc::new(location_repository),
+            tag_repository: Arc::new(tag_repository),
+            custom_field_repository: Arc::new(custom_field_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn backup_library(
+        &self,
+        user_id: u64,
+        library_id: Option<u64>,
+    ) -> Result<Vec<u8>, APIError> {
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(user_id, library_id, LibraryRole::Viewer)
+            .await?;
+
+        let locations = match self
+            .location_repository
+            .find_all_by_library_id(library_id)
+            .await
+        {
+            Ok(locations) => locations,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let collections = match self
+            .collection_repository
+            .find_all_by_library_id(library_id)
+            .await
+        {
+            Ok(collections) => collections,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let mut books = match self
+            .book_repository
+            .find_all_by_library_id(library_id)
+            .await
+        {
+            Ok(books) => books,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        // Tags and custom fields are personal, the backup carries the ones of
+        // the user taking it
+        let tags = match self
+            .tag_repository
+            .find_all_by_user_id_with_usage(user_id)
+            .await
+        {
+            Ok(tags) => tags,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let book_ids: Vec<u64> = books.iter().filter_map(|book| book.id).collect();
+        let mut book_tags: HashMap<u64, Vec<TagDto>> = match self
+            .tag_repository
+            .find_all_by_book_ids_and_user_id(&book_ids, user_id)
+            .await
+        {
+            Ok(book_tags) => book_tags,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let custom_fields = match self
+            .custom_field_repository
+            .find_all_by_user_id(user_id)
+            .await
+        {
+            Ok(custom_fields) => custom_fields,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let mut book_custom_fields = match self
+            .custom_field_repository
+            .find_all_values_by_book_ids_and_user_id(&book_ids, user_id)
+            .await
+        {
+            Ok(book_custom_fields) => book_custom_fields,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        for book in books.iter_mut() {
+            let Some(book_id) = book.id else {
+                continue;
+            };
+            book.tag_ids = book_tags
+                .remove(&book_id)
+                .map(|tags| tags.into_iter().map(|tag| tag.id).collect());
+            book.custom_fields = book_custom_fields.remove(&book_id);
+        }
+
+        let manifest = LibraryBackupManifestDto {
+            schema_version: LIBRARY_BACKUP_SCHEMA_VERSION,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            locations: locations
+                .into_iter()
+                .map(|location| BackupLocationDto {
+                    id: location.id.unwrap_or_default(),
+                    name: location.name,
+                })
+                .collect(),
+            collections: collections
+                .into_iter()
+                .map(|collection| BackupCollectionDto {
+                    id: collection.id.unwrap_or_default(),
+                    name: collection.name,
+                })
+                .collect(),
+            tags: tags
+                .into_iter()
+                .map(|tag| BackupTagDto {
+                    id: tag.id,
+                    name: tag.name,
+                })
+                .collect(),
+            custom_fields: custom_fields
+                .into_iter()
+                .map(|custom_field| BackupCustomFieldDto {
+                    id: custom_field.id.unwrap_or_default(),
+                    name: custom_field.name,
+                    field_type: custom_field.field_type.as_str().to_string(),
+                    options: custom_field.options,
+                })
+                .collect(),
+            books: books.into_iter().map(BackupBookDto::from).collect(),
+        };
+
+        match write_library_backup_archive(&manifest) {
+            Ok(archive) => Ok(archive),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                error.to_string(),
+                500,
+            ))),
+        }
+    }
+}
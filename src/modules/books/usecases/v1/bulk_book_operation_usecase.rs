@@ -0,0 +1,305 @@
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::bulk_book_operation_result_dto::BulkBookOperationResultDto,
+            entities::{
+                audit_entry::{book_audit_snapshot, AuditAction, AuditEntityType, AuditEntry},
+                bulk_book_operation::{
+                    BulkBookAction, BulkBookOperation, BulkBookTarget, MAX_BULK_BOOK_ITEMS,
+                },
+            },
+        },
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+            book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+            collection_repository::CollectionRepository,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            custom_field_repository::CustomFieldRepository,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+            location_repository::LocationRepository,
+            location_repository_mysql::LocationRepositoryMySQL, tag_repository::TagRepository,
+            tag_repository_mysql::TagRepositoryMySQL,
+        },
+        usecases::v1::{
+            authorize_tag_owner_usecase::AuthorizeTagOwnerUseCaseV1,
+            record_audit_entries_usecase::RecordAuditEntriesUseCaseV1,
+            resolve_book_listing_options_usecase::ResolveBookListingOptionsUseCaseV1,
+        },
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct BulkBookOperationUseCaseV1<T, U, V, W, Z, X, Y>
+where
+    T: BookRepository,
+    U: CollectionRepository,
+    V: LocationRepository,
+    W: LibraryRepository,
+    Z: TagRepository,
+    X: CustomFieldRepository,
+    Y: AuditRepository,
+{
+    book_repository: Arc<T>,
+    collection_repository: Arc<U>,
+    location_repository: Arc<V>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<W>,
+    authorize_tag_owner_usecase: AuthorizeTagOwnerUseCaseV1<Z>,
+    resolve_book_listing_options_usecase: ResolveBookListingOptionsUseCaseV1<X>,
+    record_audit_entries_usecase: RecordAuditEntriesUseCaseV1<Y>,
+}
+
+impl
+    BulkBookOperationUseCaseV1<
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        Self {
+            book_repository: Arc::new(book_repository),
+            collection_repository: Arc::new(collection_repository),
+            location_repository: Arc::new(location_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            authorize_tag_owner_usecase: AuthorizeTagOwnerUseCaseV1::new(tag_repository),
+            resolve_book_listing_options_usecase: ResolveBookListingOptionsUseCaseV1::new(
+                custom_field_repository,
+            ),
+            record_audit_entries_usecase: RecordAuditEntriesUseCaseV1::new(
+                audit_repository,
+                change_event_bus,
+            ),
+        }
+    }
+
+    pub async fn execute_bulk_operation(
+        &self,
+        user_id: u64,
+        library_id: Option<u64>,
+        operation: BulkBookOperation,
+    ) -> Result<BulkBookOperationResultDto, APIError> {
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(user_id, library_id, LibraryRole::Editor)
+            .await?;
+
+        match &operation.action {
+            BulkBookAction::MoveToLocation(location_id) => {
+                match self.location_repository.find_by_id(*location_id).await {
+                    Ok(maybe_a_location) => match maybe_a_location {
+                        None => {
+                            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                                "The informed location does not exist".to_string(),
+                                404,
+                            )));
+                        }
+                        Some(location) if location.library_id != library_id => {
+                            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                                "The informed location belongs to another library".to_string(),
+                                403,
+                            )));
+                        }
+                        Some(_) => {}
+                    },
+                    Err(error) => {
+                        return Err(APIError::SimpleAPIError(SimpleAPIError {
+                            msg: error.to_string(),
+                            code: 500,
+                        }))
+                    }
+                }
+            }
+            BulkBookAction::SetCollection(collection_id) => {
+                match self.collection_repository.find_by_id(*collection_id).await {
+                    Ok(maybe_a_collection) => match maybe_a_collection {
+                        None => {
+                            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                                "The informed collection does not exist".to_string(),
+                                404,
+                            )));
+                        }
+                        Some(collection) if collection.library_id != library_id => {
+                            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                                "The informed collection belongs to another library".to_string(),
+                                403,
+                            )));
+                        }
+                        Some(_) => {}
+                    },
+                    Err(error) => {
+                        return Err(APIError::SimpleAPIError(SimpleAPIError {
+                            msg: error.to_string(),
+                            code: 500,
+                        }))
+                    }
+                }
+            }
+            BulkBookAction::AddTags(tag_ids) | BulkBookAction::RemoveTags(tag_ids) => {
+                self.authorize_tag_owner_usecase
+                    .authorize(user_id, tag_ids)
+                    .await?;
+            }
+            BulkBookAction::ClearCollection | BulkBookAction::Delete => {}
+        }
+
+        let book_ids = match operation.target {
+            BulkBookTarget::Ids(ids) => ids,
+            BulkBookTarget::Filter(filter) => {
+                let listing_options = self
+                    .resolve_book_listing_options_usecase
+                    .resolve(user_id, &filter)
+                    .await?;
+                let pagination = match (filter.page, filter.page_size) {
+                    (None, None) => None,
+                    (page, page_size) => {
+                        let converted_page = match page {
+                            Some(page) if page < 1 => {
+                                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                                    msg: "Requested page must have a value greater than one"
+                                        .to_string(),
+                                    code: 400,
+                                }));
+                            }
+                            Some(page) => u64::from_ne_bytes(page.to_ne_bytes()),
+                            None => 1,
+                        };
+                        let converted_page_size = match page_size {
+                            Some(page_size) if page_size < 1 => {
+                                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                                    msg: "Requested page size must have a value greater than one"
+                                        .to_string(),
+                                    code: 400,
+                                }));
+                            }
+                            Some(page_size) => u64::from_ne_bytes(page_size.to_ne_bytes()),
+                            None => 10,
+                        };
+                        Some((converted_page, converted_page_size))
+                    }
+                };
+
+                match self
+                    .book_repository
+                    .find_all_ids_by_library_id(
+                        library_id,
+                        pagination,
+                        filter.collection_id,
+                        filter.location_id,
+                        filter.query,
+                        &listing_options,
+                    )
+                    .await
+                {
+                    Ok(found_ids) => found_ids,
+                    Err(error) => {
+                        return Err(APIError::SimpleAPIError(SimpleAPIError {
+                            msg: error.to_string(),
+                            code: 500,
+                        }))
+                    }
+                }
+            }
+        };
+
+        if book_ids.len() > MAX_BULK_BOOK_ITEMS {
+            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                format!(
+                    "The informed filter matches {} books, at most {} can be changed at once",
+                    book_ids.len(),
+                    MAX_BULK_BOOK_ITEMS
+                ),
+                400,
+            )));
+        }
+
+        let books_before = match self
+            .book_repository
+            .find_all_by_ids(library_id, &book_ids)
+            .await
+        {
+            Ok(books) => books,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        match self
+            .book_repository
+            .execute_bulk_operation(library_id, &book_ids, &operation.action)
+            .await
+        {
+            Ok(result) => {
+                if result.committed {
+                    let entries = books_before
+                        .iter()
+                        .map(|book| {
+                            let before = book_audit_snapshot(book);
+                            let (action, after) = snapshot_after_action(&before, &operation.action);
+                            AuditEntry::new(
+                                AuditEntityType::Book,
+                                book.id.unwrap_or_default(),
+                                library_id,
+                                user_id,
+                                action,
+                                Some(before),
+                                after,
+                            )
+                        })
+                        .collect();
+                    self.record_audit_entries_usecase.record(entries).await;
+                }
+                Ok(result)
+            }
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
+
+fn snapshot_after_action(before: &Value, action: &BulkBookAction) -> (AuditAction, Option<Value>) {
+    let mut after = before.clone();
+    match action {
+        BulkBookAction::MoveToLocation(location_id) => after["location_id"] = json!(location_id),
+        BulkBookAction::SetCollection(collection_id) => {
+            after["collection_id"] = json!(collection_id)
+        }
+        BulkBookAction::ClearCollection => after["collection_id"] = Value::Null,
+        BulkBookAction::AddTags(tag_ids) => after["added_tag_ids"] = json!(tag_ids),
+        BulkBookAction::RemoveTags(tag_ids) => after["removed_tag_ids"] = json!(tag_ids),
+        BulkBookAction::Delete => return (AuditAction::Delete, None),
+    }
+    (AuditAction::Update, Some(after))
+}
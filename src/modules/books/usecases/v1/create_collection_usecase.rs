@@ -2,37 +2,82 @@ use std::sync::Arc;
 
 use crate::modules::{
     books::{
-        domain::entities::collection::Collection,
+        domain::entities::{
+            audit_entry::{collection_audit_snapshot, AuditAction, AuditEntityType, AuditEntry},
+            collection::Collection,
+        },
         infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
             collection_repository::CollectionRepository,
             collection_repository_mysql::CollectionRepositoryMySQL,
         },
+        usecases::v1::record_audit_entries_usecase::RecordAuditEntriesUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
     },
     shared::errors::{simple_api_error::SimpleAPIError, APIError},
 };
-pub struct CreateCollectionUseCaseV1<T>
+pub struct CreateCollectionUseCaseV1<T, U, V>
 where
     T: CollectionRepository,
+    U: LibraryRepository,
+    V: AuditRepository,
 {
     collection_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+    record_audit_entries_usecase: RecordAuditEntriesUseCaseV1<V>,
 }
 
-impl CreateCollectionUseCaseV1<CollectionRepositoryMySQL> {
-    pub fn new(collection_repository: CollectionRepositoryMySQL) -> Self {
+impl
+    CreateCollectionUseCaseV1<
+        CollectionRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >
+{
+    pub fn new(
+        collection_repository: CollectionRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
         Self {
             collection_repository: Arc::new(collection_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            record_audit_entries_usecase: RecordAuditEntriesUseCaseV1::new(
+                audit_repository,
+                change_event_bus,
+            ),
         }
     }
 
     pub async fn create_collection(
         &self,
-        collection_to_be_created: Collection,
+        mut collection_to_be_created: Collection,
+        library_id: Option<u64>,
     ) -> Result<Collection, APIError> {
+        collection_to_be_created.library_id = self
+            .authorize_library_member_usecase
+            .authorize(
+                collection_to_be_created.user_id,
+                library_id,
+                LibraryRole::Editor,
+            )
+            .await?;
+
         match self
             .collection_repository
-            .find_by_name_and_user_id(
+            .find_by_name_and_library_id(
                 &collection_to_be_created.name,
-                collection_to_be_created.user_id,
+                collection_to_be_created.library_id,
             )
             .await
         {
@@ -58,7 +103,20 @@ impl CreateCollectionUseCaseV1<CollectionRepositoryMySQL> {
             .await
         {
             Ok(t) => match t {
-                Some(returned_collection) => Ok(returned_collection),
+                Some(returned_collection) => {
+                    self.record_audit_entries_usecase
+                        .record(vec![AuditEntry::new(
+                            AuditEntityType::Collection,
+                            returned_collection.id.unwrap_or_default(),
+                            returned_collection.library_id,
+                            returned_collection.user_id,
+                            AuditAction::Create,
+                            None,
+                            Some(collection_audit_snapshot(&returned_collection)),
+                        )])
+                        .await;
+                    Ok(returned_collection)
+                }
                 None => Err(APIError::SimpleAPIError(SimpleAPIError::new(
                     "Failed to load created collection info".to_string(),
                     500,
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::entities::custom_field::CustomField,
+        infra::repositories::{
+            custom_field_repository::CustomFieldRepository,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct CreateCustomFieldUseCaseV1<T>
+where
+    T: CustomFieldRepository,
+{
+    custom_field_repository: Arc<T>,
+}
+
+impl CreateCustomFieldUseCaseV1<CustomFieldRepositoryMySQL> {
+    pub fn new(custom_field_repository: CustomFieldRepositoryMySQL) -> Self {
+        Self {
+            custom_field_repository: Arc::new(custom_field_repository),
+        }
+    }
+
+    pub async fn create_custom_field(
+        &self,
+        custom_field_to_be_created: CustomField,
+    ) -> Result<CustomField, APIError> {
+        match self
+            .custom_field_repository
+            .find_by_name_and_user_id(
+                &custom_field_to_be_created.name,
+                custom_field_to_be_created.user_id,
+            )
+            .await
+        {
+            Ok(duplicated_custom_field_name) => {
+                if duplicated_custom_field_name.is_some() {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "There is already a custom field with the given name".to_string(),
+                        409,
+                    )));
+                }
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self
+            .custom_field_repository
+            .save(&custom_field_to_be_created)
+            .await
+        {
+            Ok(t) => match t {
+                Some(returned_custom_field) => Ok(returned_custom_field),
+                None => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Failed to load created custom field info".to_string(),
+                    500,
+                ))),
+            },
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
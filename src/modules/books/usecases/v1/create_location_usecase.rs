@@ -2,35 +2,79 @@ use std::sync::Arc;
 
 use crate::modules::{
     books::{
-        domain::entities::location::Location,
+        domain::entities::{
+            audit_entry::{location_audit_snapshot, AuditAction, AuditEntityType, AuditEntry},
+            location::Location,
+        },
         infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
             location_repository::LocationRepository,
             location_repository_mysql::LocationRepositoryMySQL,
         },
+        usecases::v1::record_audit_entries_usecase::RecordAuditEntriesUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
     },
     shared::errors::{simple_api_error::SimpleAPIError, APIError},
 };
-pub struct CreateLocationUseCaseV1<T>
+pub struct CreateLocationUseCaseV1<T, U, V>
 where
     T: LocationRepository,
+    U: LibraryRepository,
+    V: AuditRepository,
 {
     location_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+    record_audit_entries_usecase: RecordAuditEntriesUseCaseV1<V>,
 }
 
-impl CreateLocationUseCaseV1<LocationRepositoryMySQL> {
-    pub fn new(location_repository: LocationRepositoryMySQL) -> Self {
+impl
+    CreateLocationUseCaseV1<LocationRepositoryMySQL, LibraryRepositoryMySQL, AuditRepositoryMySQL>
+{
+    pub fn new(
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
         Self {
             location_repository: Arc::new(location_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            record_audit_entries_usecase: RecordAuditEntriesUseCaseV1::new(
+                audit_repository,
+                change_event_bus,
+            ),
         }
     }
 
     pub async fn create_location(
         &self,
-        location_to_be_created: Location,
+        mut location_to_be_created: Location,
+        library_id: Option<u64>,
     ) -> Result<Location, APIError> {
+        location_to_be_created.library_id = self
+            .authorize_library_member_usecase
+            .authorize(
+                location_to_be_created.user_id,
+                library_id,
+                LibraryRole::Editor,
+            )
+            .await?;
+
         match self
             .location_repository
-            .find_by_name_and_user_id(&location_to_be_created.name, location_to_be_created.user_id)
+            .find_by_name_and_library_id(
+                &location_to_be_created.name,
+                location_to_be_created.library_id,
+            )
             .await
         {
             Ok(duplicated_location_name) => {
@@ -51,7 +95,20 @@ impl CreateLocationUseCaseV1<LocationRepositoryMySQL> {
 
         match self.location_repository.save(&location_to_be_created).await {
             Ok(t) => match t {
-                Some(returned_location) => Ok(returned_location),
+                Some(returned_location) => {
+                    self.record_audit_entries_usecase
+                        .record(vec![AuditEntry::new(
+                            AuditEntityType::Location,
+                            returned_location.id.unwrap_or_default(),
+                            returned_location.library_id,
+                            returned_location.user_id,
+                            AuditAction::Create,
+                            None,
+                            Some(location_audit_snapshot(&returned_location)),
+                        )])
+                        .await;
+                    Ok(returned_location)
+                }
                 None => Err(APIError::SimpleAPIError(SimpleAPIError::new(
                     "Failed to load created location info".to_string(),
                     500,
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::share_link_dto::ShareLinkDto,
+            entities::share_link::{ShareLink, ShareLinkScope},
+        },
+        infra::repositories::{
+            collection_repository::CollectionRepository,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            location_repository::LocationRepository,
+            location_repository_mysql::LocationRepositoryMySQL,
+            share_link_repository::ShareLinkRepository,
+            share_link_repository_mysql::ShareLinkRepositoryMySQL,
+        },
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct CreateShareLinkUseCaseV1<T, U, V, W>
+where
+    T: ShareLinkRepository,
+    U: CollectionRepository,
+    V: LocationRepository,
+    W: LibraryRepository,
+{
+    share_link_repository: Arc<T>,
+    collection_repository: Arc<U>,
+    location_repository: Arc<V>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<W>,
+}
+
+impl
+    CreateShareLinkUseCaseV1<
+        ShareLinkRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+    >
+{
+    pub fn new(
+        share_link_repository: ShareLinkRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
+        Self {
+            share_link_repository: Arc::new(share_link_repository),
+            collection_repository: Arc::new(collection_repository),
+            location_repository: Arc::new(location_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn create_share_link(
+        &self,
+        mut share_link: ShareLink,
+        library_id: Option<u64>,
+    ) -> Result<ShareLinkDto, APIError> {
+        share_link.library_id = self
+            .authorize_library_member_usecase
+            .authorize(share_link.user_id, library_id, LibraryRole::Editor)
+            .await?;
+
+        match share_link.scope {
+            ShareLinkScope::Location(location_id) => {
+                match self.location_repository.find_by_id(location_id).await {
+                    Ok(maybe_a_location) => match maybe_a_location {
+                        None => {
+                            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                                "The informed location does not exist".to_string(),
+                                404,
+                            )));
+                        }
+                        Some(location) if location.library_id != share_link.library_id => {
+                            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                                "The informed location belongs to another library".to_string(),
+                                403,
+                            )));
+                        }
+                        Some(_) => {}
+                    },
+                    Err(error) => {
+                        return Err(APIError::SimpleAPIError(SimpleAPIError {
+                            msg: error.to_string(),
+                            code: 500,
+                        }))
+                    }
+                }
+            }
+            ShareLinkScope::Collection(collection_id) => {
+                match self.collection_repository.find_by_id(collection_id).await {
+                    Ok(maybe_a_collection) => match maybe_a_collection {
+                        None => {
+                            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                                "The informed collection does not exist".to_string(),
+                                404,
+                            )));
+                        }
+                        Some(collection) if collection.library_id != share_link.library_id => {
+                            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                                "The informed collection belongs to another library".to_string(),
+                                403,
+                            )));
+                        }
+                        Some(_) => {}
+                    },
+                    Err(error) => {
+                        return Err(APIError::SimpleAPIError(SimpleAPIError {
+                            msg: error.to_string(),
+                            code: 500,
+                        }))
+                    }
+                }
+            }
+            ShareLinkScope::Library => {}
+        }
+
+        match self.share_link_repository.save(&share_link).await {
+            Ok(Some(created_share_link)) => Ok(ShareLinkDto::from(created_share_link)),
+            Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Share link could not be created".to_string(),
+                500,
+            ))),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::entities::tag::Tag,
+        infra::repositories::{
+            tag_repository::TagRepository, tag_repository_mysql::TagRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct CreateTagUseCaseV1<T>
+where
+    T: TagRepository,
+{
+    tag_repository: Arc<T>,
+}
+
+impl CreateTagUseCaseV1<TagRepositoryMySQL> {
+    pub fn new(tag_repository: TagRepositoryMySQL) -> Self {
+        Self {
+            tag_repository: Arc::new(tag_repository),
+        }
+    }
+
+    pub async fn create_tag(&self, tag_to_be_created: Tag) -> Result<Tag, APIError> {
+        match self
+            .tag_repository
+            .find_by_name_and_user_id(&tag_to_be_created.name, tag_to_be_created.user_id)
+            .await
+        {
+            Ok(duplicated_tag_name) => {
+                if duplicated_tag_name.is_some() {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "There is already a tag with the given name".to_string(),
+                        409,
+                    )));
+                }
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self.tag_repository.save(&tag_to_be_created).await {
+            Ok(t) => match t {
+                Some(returned_tag) => Ok(returned_tag),
+                None => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Failed to load created tag info".to_string(),
+                    500,
+                ))),
+            },
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -7,28 +7,58 @@ use crate::modules::{
                 collection_dto::CollectionDto, complete_book_dto::CompleteBookDto,
                 location_dto::LocationDto,
             },
-            entities::book::{self, Book},
+            entities::{
+                audit_entry::{book_audit_snapshot, AuditAction, AuditEntityType, AuditEntry},
+                book::Book,
+                custom_field::BookCustomFieldValue,
+            },
+            mappers::book_custom_field_values_mapper::book_custom_field_values_from_dto,
         },
         infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
             book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
             collection_repository::CollectionRepository,
             collection_repository_mysql::CollectionRepositoryMySQL,
+            custom_field_repository::CustomFieldRepository,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
             location_repository::LocationRepository,
-            location_repository_mysql::LocationRepositoryMySQL,
+            location_repository_mysql::LocationRepositoryMySQL, tag_repository::TagRepository,
+            tag_repository_mysql::TagRepositoryMySQL,
+        },
+        usecases::v1::{
+            authorize_tag_owner_usecase::AuthorizeTagOwnerUseCaseV1,
+            record_audit_entries_usecase::RecordAuditEntriesUseCaseV1,
         },
     },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
     shared::errors::{simple_api_error::SimpleAPIError, APIError},
 };
 
-pub struct CreateUpdateBookUseCaseV1<T, U, V>
+pub struct CreateUpdateBookUseCaseV1<T, U, V, W, X, Y, Z>
 where
     T: BookRepository,
     U: CollectionRepository,
     V: LocationRepository,
+    W: LibraryRepository,
+    X: TagRepository,
+    Y: CustomFieldRepository,
+    Z: AuditRepository,
 {
     book_repository: Arc<T>,
     collection_repository: Arc<U>,
     location_repository: Arc<V>,
+    tag_repository: Arc<X>,
+    custom_field_repository: Arc<Y>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<W>,
+    authorize_tag_owner_usecase: AuthorizeTagOwnerUseCaseV1<X>,
+    record_audit_entries_usecase: RecordAuditEntriesUseCaseV1<Z>,
 }
 
 impl
@@ -36,38 +66,76 @@ impl
         BookRepositoryMySQL,
         CollectionRepositoryMySQL,
         LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        AuditRepositoryMySQL,
     >
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         book_repository: BookRepositoryMySQL,
         collection_repository: CollectionRepositoryMySQL,
         location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
     ) -> Self {
         Self {
             book_repository: Arc::new(book_repository),
             collection_repository: Arc::new(collection_repository),
             location_repository: Arc::new(location_repository),
+            tag_repository: Arc::new(tag_repository.clone()),
+            custom_field_repository: Arc::new(custom_field_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            authorize_tag_owner_usecase: AuthorizeTagOwnerUseCaseV1::new(tag_repository),
+            record_audit_entries_usecase: RecordAuditEntriesUseCaseV1::new(
+                audit_repository,
+                change_event_bus,
+            ),
         }
     }
 
+    /// The book's library is the one of its location, books can't be moved
+    /// to another library through an update. Updates without an expected
+    /// version are based on the current one. Tags and custom field values
+    /// saved are the ones of `user_id`, who isn't always the book's owner.
+    #[allow(clippy::unnecessary_unwrap)]
     pub async fn create_update_book(
         &self,
-        book_to_be_created: Book,
+        user_id: u64,
+        mut book_to_be_created: Book,
+        expected_version: Option<u64>,
     ) -> Result<CompleteBookDto, APIError> {
+        let mut current_library_id = None;
+        let mut previous_snapshot = None;
         if book_to_be_created.id.is_some() {
             match self
                 .book_repository
                 .find_by_id(book_to_be_created.id.unwrap())
                 .await
             {
-                Ok(maybe_a_book) => {
-                    if maybe_a_book.is_none() {
+                Ok(maybe_a_book) => match maybe_a_book {
+                    Some(current_book) => {
+                        self.authorize_library_member_usecase
+                            .authorize(user_id, Some(current_book.library_id), LibraryRole::Editor)
+                            .await?;
+                        current_library_id = Some(current_book.library_id);
+                        book_to_be_created.version =
+                            expected_version.unwrap_or(current_book.version);
+                        previous_snapshot = Some(book_audit_snapshot(&current_book));
+                    }
+                    None => {
                         return Err(APIError::SimpleAPIError(SimpleAPIError::new(
                             "Book not found".to_string(),
                             404,
                         )));
                     }
-                }
+                },
                 Err(error) => {
                     return Err(APIError::SimpleAPIError(SimpleAPIError {
                         msg: error.to_string(),
@@ -77,9 +145,50 @@ impl
             }
         }
 
+        match self
+            .location_repository
+            .find_by_id(book_to_be_created.location_id)
+            .await
+        {
+            Ok(maybe_a_location) => match maybe_a_location {
+                Some(location) => book_to_be_created.library_id = location.library_id,
+                None => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "The informed book's location does not exist".to_string(),
+                        404,
+                    )));
+                }
+            },
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match current_library_id {
+            Some(current_library_id) if current_library_id != book_to_be_created.library_id => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Books can't be moved to a location of another library".to_string(),
+                    422,
+                )));
+            }
+            Some(_) => {}
+            None => {
+                self.authorize_library_member_usecase
+                    .authorize(
+                        user_id,
+                        Some(book_to_be_created.library_id),
+                        LibraryRole::Editor,
+                    )
+                    .await?;
+            }
+        }
+
         match self
             .book_repository
-            .find_by_title(&book_to_be_created.title)
+            .find_by_title_and_library_id(&book_to_be_created.title, book_to_be_created.library_id)
             .await
         {
             Ok(duplicated_book) => {
@@ -107,7 +216,9 @@ impl
                 .await
             {
                 Ok(maybe_a_collection) => {
-                    if maybe_a_collection.is_none() {
+                    if !maybe_a_collection.is_some_and(|collection| {
+                        collection.library_id == book_to_be_created.library_id
+                    }) {
                         return Err(APIError::SimpleAPIError(SimpleAPIError::new(
                             "The informed book's collection does not exist".to_string(),
                             404,
@@ -123,24 +234,30 @@ impl
             }
         }
 
-        match self
-            .location_repository
-            .find_by_id(book_to_be_created.location_id)
-            .await
-        {
-            Ok(maybe_a_location) => {
-                if maybe_a_location.is_none() {
-                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
-                        "The informed book's location does not exist".to_string(),
-                        404,
-                    )));
+        if let Some(tag_ids) = &book_to_be_created.tag_ids {
+            self.authorize_tag_owner_usecase
+                .authorize(user_id, tag_ids)
+                .await?;
+        }
+
+        let mut custom_field_values: Option<Vec<BookCustomFieldValue>> = None;
+        if let Some(values) = &book_to_be_created.custom_fields {
+            let custom_fields = match self
+                .custom_field_repository
+                .find_all_by_user_id(user_id)
+                .await
+            {
+                Ok(custom_fields) => custom_fields,
+                Err(error) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError {
+                        msg: error.to_string(),
+                        code: 500,
+                    }))
                 }
-            }
-            Err(error) => {
-                return Err(APIError::SimpleAPIError(SimpleAPIError {
-                    msg: error.to_string(),
-                    code: 500,
-                }))
+            };
+            match book_custom_field_values_from_dto(values, &custom_fields) {
+                Ok(values) => custom_field_values = Some(values),
+                Err(error) => return Err(APIError::DetailedAPIError(error)),
             }
         }
 
@@ -149,6 +266,14 @@ impl
         match self.book_repository.save(&book_to_be_created).await {
             Ok(t) => match t {
                 Some(returned_book) => saved_book = returned_book,
+                None if book_to_be_created.id.is_some() => {
+                    return Err(self
+                        .stale_book_error(
+                            book_to_be_created.library_id,
+                            book_to_be_created.id.unwrap_or_default(),
+                        )
+                        .await);
+                }
                 None => {
                     return Err(APIError::SimpleAPIError(SimpleAPIError::new(
                         "Failed to load book info".to_string(),
@@ -164,6 +289,49 @@ impl
             }
         }
 
+        let book_id = saved_book.id.unwrap_or_default();
+        if let Some(tag_ids) = &book_to_be_created.tag_ids {
+            if let Err(e) = self
+                .tag_repository
+                .replace_book_tags(book_id, user_id, tag_ids)
+                .await
+            {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    e.to_string(),
+                    500,
+                )));
+            }
+        }
+
+        if let Some(values) = &custom_field_values {
+            if let Err(e) = self
+                .custom_field_repository
+                .replace_book_values(book_id, user_id, values)
+                .await
+            {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    e.to_string(),
+                    500,
+                )));
+            }
+        }
+
+        self.record_audit_entries_usecase
+            .record(vec![AuditEntry::new(
+                AuditEntityType::Book,
+                book_id,
+                saved_book.library_id,
+                user_id,
+                if previous_snapshot.is_some() {
+                    AuditAction::Update
+                } else {
+                    AuditAction::Create
+                },
+                previous_snapshot,
+                Some(book_audit_snapshot(&saved_book)),
+            )])
+            .await;
+
         let location_id = saved_book.location_id;
         let collection_id = saved_book.collection_id;
 
@@ -218,6 +386,50 @@ impl
             }
         }
 
+        match self
+            .tag_repository
+            .find_all_by_book_ids_and_user_id(&[book_id], user_id)
+            .await
+        {
+            Ok(mut tags_by_book) => dto.tags = tags_by_book.remove(&book_id).unwrap_or_default(),
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    e.to_string(),
+                    500,
+                )))
+            }
+        }
+
+        match self
+            .custom_field_repository
+            .find_all_values_by_book_ids_and_user_id(&[book_id], user_id)
+            .await
+        {
+            Ok(mut custom_fields_by_book) => {
+                dto.custom_fields = custom_fields_by_book.remove(&book_id).unwrap_or_default()
+            }
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    e.to_string(),
+                    500,
+                )))
+            }
+        }
+
         Ok(dto)
     }
+
+    async fn stale_book_error(&self, library_id: u64, book_id: u64) -> APIError {
+        match self
+            .book_repository
+            .find_by_id_as_complete_book_dto(library_id, book_id)
+            .await
+        {
+            Ok(Some(current_book)) => APIError::PreconditionFailedAPIError(current_book.into()),
+            Ok(None) => {
+                APIError::SimpleAPIError(SimpleAPIError::new("Book not found".to_string(), 404))
+            }
+            Err(e) => APIError::SimpleAPIError(SimpleAPIError::new(e.to_string(), 500)),
+        }
+    }
 }
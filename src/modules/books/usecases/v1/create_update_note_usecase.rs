@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::{dtos::note_dto::NoteDto, entities::note::Note},
+        infra::repositories::{
+            book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+            note_repository::NoteRepository, note_repository_mysql::NoteRepositoryMySQL,
+        },
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct CreateUpdateNoteUseCaseV1<T, U, V>
+where
+    T: NoteRepository,
+    U: BookRepository,
+    V: LibraryRepository,
+{
+    note_repository: Arc<T>,
+    book_repository: Arc<U>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<V>,
+}
+
+impl CreateUpdateNoteUseCaseV1<NoteRepositoryMySQL, BookRepositoryMySQL, LibraryRepositoryMySQL> {
+    pub fn new(
+        note_repository: NoteRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
+        Self {
+            note_repository: Arc::new(note_repository),
+            book_repository: Arc::new(book_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn create_update_note(&self, note: Note) -> Result<NoteDto, APIError> {
+        let book = match self.book_repository.find_by_id(note.book_id).await {
+            Ok(Some(book)) => book,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Book not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        self.authorize_library_member_usecase
+            .authorize(note.user_id, Some(book.library_id), LibraryRole::Viewer)
+            .await?;
+
+        if let Some(note_id) = note.id {
+            match self.note_repository.find_by_id(note_id).await {
+                Ok(Some(found_note))
+                    if found_note.user_id == note.user_id && found_note.book_id == note.book_id => {
+                }
+                Ok(_) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "Note not found".to_string(),
+                        404,
+                    )));
+                }
+                Err(error) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError {
+                        msg: error.to_string(),
+                        code: 500,
+                    }))
+                }
+            }
+        }
+
+        match self.note_repository.save(&note).await {
+            Ok(Some(saved_note)) => Ok(NoteDto {
+                book_title: book.title,
+                ..NoteDto::from(saved_note)
+            }),
+            Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Failed to load note info".to_string(),
+                500,
+            ))),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -2,38 +2,104 @@ use std::sync::Arc;
 
 use crate::modules::{
     books::{
-        domain::dtos::complete_book_dto::CompleteBookDto,
+        domain::entities::audit_entry::{
+            book_audit_snapshot, AuditAction, AuditEntityType, AuditEntry,
+        },
         infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
             book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
         },
+        usecases::v1::record_audit_entries_usecase::RecordAuditEntriesUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
     },
     shared::errors::{simple_api_error::SimpleAPIError, APIError},
 };
 
-pub struct DeleteBookUseCaseV1<T>
+pub struct DeleteBookUseCaseV1<T, U, V>
 where
     T: BookRepository,
+    U: LibraryRepository,
+    V: AuditRepository,
 {
     book_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+    record_audit_entries_usecase: RecordAuditEntriesUseCaseV1<V>,
 }
 
-impl DeleteBookUseCaseV1<BookRepositoryMySQL> {
-    pub fn new(book_repository: BookRepositoryMySQL) -> Self {
+impl DeleteBookUseCaseV1<BookRepositoryMySQL, LibraryRepositoryMySQL, AuditRepositoryMySQL> {
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
         Self {
             book_repository: Arc::new(book_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            record_audit_entries_usecase: RecordAuditEntriesUseCaseV1::new(
+                audit_repository,
+                change_event_bus,
+            ),
         }
     }
 
-    pub async fn delete_book_by_id(&self, user_id: u64, book_id: u64) -> Result<(), APIError> {
-        match self.book_repository.delete_by_id(user_id, book_id).await {
+    pub async fn delete_book_by_id(
+        &self,
+        user_id: u64,
+        book_id: u64,
+        expected_version: Option<u64>,
+    ) -> Result<(), APIError> {
+        let found_book = match self.book_repository.find_by_id(book_id).await {
+            Ok(Some(found_book)) => found_book,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: "Book not found".to_string(),
+                    code: 404,
+                }))
+            }
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let library_id = found_book.library_id;
+        self.authorize_library_member_usecase
+            .authorize(user_id, Some(library_id), LibraryRole::Editor)
+            .await?;
+
+        let version = expected_version.unwrap_or(found_book.version);
+        match self
+            .book_repository
+            .delete_by_id(library_id, book_id, version)
+            .await
+        {
             Ok(delete_book) => {
                 if delete_book {
+                    self.record_audit_entries_usecase
+                        .record(vec![AuditEntry::new(
+                            AuditEntityType::Book,
+                            book_id,
+                            library_id,
+                            user_id,
+                            AuditAction::Delete,
+                            Some(book_audit_snapshot(&found_book)),
+                            None,
+                        )])
+                        .await;
                     Ok(())
                 } else {
-                    Err(APIError::SimpleAPIError(SimpleAPIError {
-                        msg: "Book not found".to_string(),
-                        code: 404,
-                    }))
+                    Err(self.stale_book_error(library_id, book_id).await)
                 }
             }
             Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError {
@@ -42,4 +108,22 @@ impl DeleteBookUseCaseV1<BookRepositoryMySQL> {
             })),
         }
     }
+
+    async fn stale_book_error(&self, library_id: u64, book_id: u64) -> APIError {
+        match self
+            .book_repository
+            .find_by_id_as_complete_book_dto(library_id, book_id)
+            .await
+        {
+            Ok(Some(current_book)) => APIError::PreconditionFailedAPIError(current_book.into()),
+            Ok(None) => APIError::SimpleAPIError(SimpleAPIError {
+                msg: "Book not found".to_string(),
+                code: 404,
+            }),
+            Err(e) => APIError::SimpleAPIError(SimpleAPIError {
+                msg: e.to_string(),
+                code: 500,
+            }),
+        }
+    }
 }
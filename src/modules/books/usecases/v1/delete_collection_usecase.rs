@@ -1,24 +1,64 @@
 use std::sync::Arc;
 
 use crate::modules::{
-    books::infra::repositories::{
-        collection_repository::CollectionRepository,
-        collection_repository_mysql::CollectionRepositoryMySQL,
+    books::{
+        domain::{
+            dtos::collection_dto::CollectionDto,
+            entities::audit_entry::{
+                collection_audit_snapshot, AuditAction, AuditEntityType, AuditEntry,
+            },
+        },
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+            collection_repository::CollectionRepository,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+        },
+        usecases::v1::record_audit_entries_usecase::RecordAuditEntriesUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
     },
     shared::errors::{simple_api_error::SimpleAPIError, APIError},
 };
 
-pub struct DeleteCollectionUseCaseV1<T>
+pub struct DeleteCollectionUseCaseV1<T, U, V>
 where
     T: CollectionRepository,
+    U: LibraryRepository,
+    V: AuditRepository,
 {
     collection_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+    record_audit_entries_usecase: RecordAuditEntriesUseCaseV1<V>,
 }
 
-impl DeleteCollectionUseCaseV1<CollectionRepositoryMySQL> {
-    pub fn new(collection_repository: CollectionRepositoryMySQL) -> Self {
+impl
+    DeleteCollectionUseCaseV1<
+        CollectionRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >
+{
+    pub fn new(
+        collection_repository: CollectionRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
         Self {
             collection_repository: Arc::new(collection_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            record_audit_entries_usecase: RecordAuditEntriesUseCaseV1::new(
+                audit_repository,
+                change_event_bus,
+            ),
         }
     }
 
@@ -26,39 +66,77 @@ impl DeleteCollectionUseCaseV1<CollectionRepositoryMySQL> {
         &self,
         collection_to_be_delete: u64,
         authed_user_id: u64,
+        expected_version: Option<u64>,
     ) -> Result<(), APIError> {
-        match self
+        let found_collection = match self
             .collection_repository
             .find_by_id(collection_to_be_delete)
             .await
         {
-            Ok(found_collection_option) => {
-                if found_collection_option.is_none() {
+            Ok(found_collection_option) => match found_collection_option {
+                None => {
                     return Err(APIError::SimpleAPIError(SimpleAPIError::new(
                         "Collection not found".to_string(),
                         404,
                     )));
-                } else if authed_user_id != found_collection_option.unwrap().user_id {
-                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
-                        "User doesn't have permission to delete this resource".to_string(),
-                        403,
-                    )));
                 }
-            }
+                Some(found_collection) => found_collection,
+            },
             Err(error) => {
                 return Err(APIError::SimpleAPIError(SimpleAPIError {
                     msg: error.to_string(),
                     code: 500,
                 }))
             }
-        }
+        };
+
+        self.authorize_library_member_usecase
+            .authorize(
+                authed_user_id,
+                Some(found_collection.library_id),
+                LibraryRole::Editor,
+            )
+            .await?;
 
         match self
             .collection_repository
-            .delete_by_id(collection_to_be_delete)
+            .delete_by_id(
+                collection_to_be_delete,
+                expected_version.unwrap_or(found_collection.version),
+            )
             .await
         {
-            Ok(_) => Ok(()),
+            Ok(false) => match self
+                .collection_repository
+                .find_by_id(collection_to_be_delete)
+                .await
+            {
+                Ok(Some(current_collection)) => Err(APIError::PreconditionFailedAPIError(
+                    CollectionDto::from(current_collection).into(),
+                )),
+                Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Collection not found".to_string(),
+                    404,
+                ))),
+                Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    e.to_string(),
+                    500,
+                ))),
+            },
+            Ok(true) => {
+                self.record_audit_entries_usecase
+                    .record(vec![AuditEntry::new(
+                        AuditEntityType::Collection,
+                        collection_to_be_delete,
+                        found_collection.library_id,
+                        authed_user_id,
+                        AuditAction::Delete,
+                        Some(collection_audit_snapshot(&found_collection)),
+                        None,
+                    )])
+                    .await;
+                Ok(())
+            }
             Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
                 e.to_string(),
                 500,
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::infra::repositories::{
+        custom_field_repository::CustomFieldRepository,
+        custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct DeleteCustomFieldUseCaseV1<T>
+where
+    T: CustomFieldRepository,
+{
+    custom_field_repository: Arc<T>,
+}
+
+impl DeleteCustomFieldUseCaseV1<CustomFieldRepositoryMySQL> {
+    pub fn new(custom_field_repository: CustomFieldRepositoryMySQL) -> Self {
+        Self {
+            custom_field_repository: Arc::new(custom_field_repository),
+        }
+    }
+
+    pub async fn delete_custom_field(
+        &self,
+        custom_field_id: u64,
+        authed_user_id: u64,
+    ) -> Result<(), APIError> {
+        match self
+            .custom_field_repository
+            .find_by_id(custom_field_id)
+            .await
+        {
+            Ok(Some(found_custom_field)) if found_custom_field.user_id == authed_user_id => {}
+            Ok(_) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Custom field not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self
+            .custom_field_repository
+            .delete_by_id(custom_field_id)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -1,23 +1,60 @@
 use std::sync::Arc;
 
 use crate::modules::{
-    books::infra::repositories::{
-        location_repository::LocationRepository, location_repository_mysql::LocationRepositoryMySQL,
+    books::{
+        domain::{
+            dtos::location_dto::LocationDto,
+            entities::audit_entry::{
+                location_audit_snapshot, AuditAction, AuditEntityType, AuditEntry,
+            },
+        },
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+            location_repository::LocationRepository,
+            location_repository_mysql::LocationRepositoryMySQL,
+        },
+        usecases::v1::record_audit_entries_usecase::RecordAuditEntriesUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
     },
     shared::errors::{simple_api_error::SimpleAPIError, APIError},
 };
 
-pub struct DeleteLocationUseCaseV1<T>
+pub struct DeleteLocationUseCaseV1<T, U, V>
 where
     T: LocationRepository,
+    U: LibraryRepository,
+    V: AuditRepository,
 {
     location_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+    record_audit_entries_usecase: RecordAuditEntriesUseCaseV1<V>,
 }
 
-impl DeleteLocationUseCaseV1<LocationRepositoryMySQL> {
-    pub fn new(location_repository: LocationRepositoryMySQL) -> Self {
+impl
+    DeleteLocationUseCaseV1<LocationRepositoryMySQL, LibraryRepositoryMySQL, AuditRepositoryMySQL>
+{
+    pub fn new(
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
         Self {
             location_repository: Arc::new(location_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            record_audit_entries_usecase: RecordAuditEntriesUseCaseV1::new(
+                audit_repository,
+                change_event_bus,
+            ),
         }
     }
 
@@ -25,24 +62,49 @@ impl DeleteLocationUseCaseV1<LocationRepositoryMySQL> {
         &self,
         location_to_be_delete: u64,
         authed_user_id: u64,
+        expected_version: Option<u64>,
     ) -> Result<(), APIError> {
-        match self
+        let found_location = match self
             .location_repository
             .find_by_id(location_to_be_delete)
             .await
         {
-            Ok(found_location_option) => {
-                if found_location_option.is_none() {
+            Ok(found_location_option) => match found_location_option {
+                None => {
                     return Err(APIError::SimpleAPIError(SimpleAPIError::new(
                         "Location not found".to_string(),
                         404,
                     )));
-                } else if authed_user_id != found_location_option.unwrap().user_id {
-                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
-                        "User doesn't have permission to delete this resource".to_string(),
-                        403,
-                    )));
                 }
+                Some(found_location) => found_location,
+            },
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        self.authorize_library_member_usecase
+            .authorize(
+                authed_user_id,
+                Some(found_location.library_id),
+                LibraryRole::Editor,
+            )
+            .await?;
+
+        match self
+            .location_repository
+            .count_books_by_id(location_to_be_delete)
+            .await
+        {
+            Ok(0) => {}
+            Ok(_) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Location still has books, they must be moved or deleted first".to_string(),
+                    409,
+                )));
             }
             Err(error) => {
                 return Err(APIError::SimpleAPIError(SimpleAPIError {
@@ -54,10 +116,43 @@ impl DeleteLocationUseCaseV1<LocationRepositoryMySQL> {
 
         match self
             .location_repository
-            .delete_by_id(location_to_be_delete)
+            .delete_by_id(
+                location_to_be_delete,
+                expected_version.unwrap_or(found_location.version),
+            )
             .await
         {
-            Ok(_) => Ok(()),
+            Ok(false) => match self
+                .location_repository
+                .find_by_id(location_to_be_delete)
+                .await
+            {
+                Ok(Some(current_location)) => Err(APIError::PreconditionFailedAPIError(
+                    LocationDto::from(current_location).into(),
+                )),
+                Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Location not found".to_string(),
+                    404,
+                ))),
+                Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    e.to_string(),
+                    500,
+                ))),
+            },
+            Ok(true) => {
+                self.record_audit_entries_usecase
+                    .record(vec![AuditEntry::new(
+                        AuditEntityType::Location,
+                        location_to_be_delete,
+                        found_location.library_id,
+                        authed_user_id,
+                        AuditAction::Delete,
+                        Some(location_audit_snapshot(&found_location)),
+                        None,
+                    )])
+                    .await;
+                Ok(())
+            }
             Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
                 e.to_string(),
                 500,
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::infra::repositories::{
+        note_repository::NoteRepository, note_repository_mysql::NoteRepositoryMySQL,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct DeleteNoteUseCaseV1<T>
+where
+    T: NoteRepository,
+{
+    note_repository: Arc<T>,
+}
+
+impl DeleteNoteUseCaseV1<NoteRepositoryMySQL> {
+    pub fn new(note_repository: NoteRepositoryMySQL) -> Self {
+        Self {
+            note_repository: Arc::new(note_repository),
+        }
+    }
+
+    pub async fn delete_note(
+        &self,
+        book_id: u64,
+        note_id: u64,
+        authed_user_id: u64,
+    ) -> Result<(), APIError> {
+        match self.note_repository.find_by_id(note_id).await {
+            Ok(Some(found_note))
+                if found_note.user_id == authed_user_id && found_note.book_id == book_id => {}
+            Ok(_) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Note not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self.note_repository.delete_by_id(note_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
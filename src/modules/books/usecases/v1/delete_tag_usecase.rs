@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::infra::repositories::{
+        tag_repository::TagRepository, tag_repository_mysql::TagRepositoryMySQL,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct DeleteTagUseCaseV1<T>
+where
+    T: TagRepository,
+{
+    tag_repository: Arc<T>,
+}
+
+impl DeleteTagUseCaseV1<TagRepositoryMySQL> {
+    pub fn new(tag_repository: TagRepositoryMySQL) -> Self {
+        Self {
+            tag_repository: Arc::new(tag_repository),
+        }
+    }
+
+    pub async fn delete_tag(&self, tag_id: u64, authed_user_id: u64) -> Result<(), APIError> {
+        match self.tag_repository.find_by_id(tag_id).await {
+            Ok(Some(found_tag)) if found_tag.user_id == authed_user_id => {}
+            Ok(_) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Tag not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self.tag_repository.delete_by_id(tag_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::{io, sync::Arc};
+
+use async_stream::try_stream;
+use futures_util::{Stream, TryStreamExt};
+
+use crate::modules::{
+    books::{
+        domain::entities::book_export::BookExport,
+        infra::{
+            exporters::book_exporter::book_exporter_for,
+            repositories::{
+                book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+                custom_field_repository::CustomFieldRepository,
+                custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+            },
+        },
+        usecases::v1::resolve_book_listing_options_usecase::ResolveBookListingOptionsUseCaseV1,
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::APIError,
+};
+
+const EXPORT_CHUNK_SIZE: usize = 32 * 1024;
+
+pub struct ExportBooksUseCaseV1<T, U, V>
+where
+    T: BookRepository,
+    U: LibraryRepository,
+    V: CustomFieldRepository,
+{
+    book_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+    resolve_book_listing_options_usecase: ResolveBookListingOptionsUseCaseV1<V>,
+}
+
+impl ExportBooksUseCaseV1<BookRepositoryMySQL, LibraryRepositoryMySQL, CustomFieldRepositoryMySQL> {
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+    ) -> Self {
+        Self {
+            book_repository: Arc::new(book_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            resolve_book_listing_options_usecase: ResolveBookListingOptionsUseCaseV1::new(
+                custom_field_repository,
+            ),
+        }
+    }
+
+    /// Membership is checked before anything is streamed, so failures can
+    /// still be reported with a proper status code.
+    pub async fn export_books(
+        &self,
+        user_id: u64,
+        library_id: Option<u64>,
+        book_export: BookExport,
+    ) -> Result<impl Stream<Item = Result<Vec<u8>, io::Error>>, APIError> {
+        let listing_params = book_export.listing_params;
+        let listing_options = self
+            .resolve_book_listing_options_usecase
+            .resolve(user_id, &listing_params)
+            .await?;
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(user_id, library_id, LibraryRole::Viewer)
+            .await?;
+
+        let mut exporter = book_exporter_for(book_export.format);
+        let mut books = self
+            .book_repository
+            .stream_all_by_library_id_as_complete_book_dto(
+                library_id,
+                listing_params.collection_id,
+                listing_params.location_id,
+                listing_params.query,
+                listing_options,
+            );
+
+        Ok(try_stream! {
+            let mut chunk = exporter.start()?;
+            while let Some(book) = books.try_next().await.map_err(io::Error::other)? {
+                chunk.append(&mut exporter.write_book(&book)?);
+                if chunk.len() >= EXPORT_CHUNK_SIZE {
+                    yield std::mem::take(&mut chunk);
+                }
+            }
+            chunk.append(&mut exporter.finish()?);
+            yield chunk;
+        })
+    }
+}
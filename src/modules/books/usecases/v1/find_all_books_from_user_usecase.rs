@@ -2,10 +2,25 @@ use std::sync::Arc;
 
 use crate::modules::{
     books::{
-        domain::dtos::complete_book_dto::CompleteBookDto,
+        domain::{
+            dtos::{complete_book_dto::CompleteBookDto, get_all_books_params::GetAllBooksParams},
+            entities::book_cursor::BookCursor,
+        },
         infra::repositories::{
             book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+            custom_field_repository::CustomFieldRepository,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+            note_repository::NoteRepository, note_repository_mysql::NoteRepositoryMySQL,
+            tag_repository::TagRepository, tag_repository_mysql::TagRepositoryMySQL,
+        },
+        usecases::v1::resolve_book_listing_options_usecase::ResolveBookListingOptionsUseCaseV1,
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
         },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
     },
     shared::{
         domain::dtos::paginated_dto::PaginatedDto,
@@ -13,73 +28,200 @@ use crate::modules::{
     },
 };
 
-pub struct FindAllBooksFromUserUseCaseV1<T>
+pub struct FindAllBooksFromUserUseCaseV1<T, U, V, W, X>
 where
     T: BookRepository,
+    U: LibraryRepository,
+    V: TagRepository,
+    W: CustomFieldRepository,
+    X: NoteRepository,
 {
     book_repository: Arc<T>,
+    tag_repository: Arc<V>,
+    custom_field_repository: Arc<W>,
+    note_repository: Arc<X>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+    resolve_book_listing_options_usecase: ResolveBookListingOptionsUseCaseV1<W>,
 }
 
-impl FindAllBooksFromUserUseCaseV1<BookRepositoryMySQL> {
-    pub fn new(book_repository: BookRepositoryMySQL) -> Self {
+impl
+    FindAllBooksFromUserUseCaseV1<
+        BookRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        NoteRepositoryMySQL,
+    >
+{
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        note_repository: NoteRepositoryMySQL,
+    ) -> Self {
         Self {
             book_repository: Arc::new(book_repository),
+            tag_repository: Arc::new(tag_repository),
+            custom_field_repository: Arc::new(custom_field_repository.clone()),
+            note_repository: Arc::new(note_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            resolve_book_listing_options_usecase: ResolveBookListingOptionsUseCaseV1::new(
+                custom_field_repository,
+            ),
         }
     }
 
+    #[allow(clippy::unnecessary_unwrap)]
     pub async fn find_all_from_user(
         &self,
         user_id: u64,
-        page: Option<i64>,
-        page_size: Option<i64>,
-        collection_id: Option<i64>,
-        location_id: Option<i64>,
-        query: Option<String>,
+        library_id: Option<u64>,
+        params: GetAllBooksParams,
     ) -> Result<PaginatedDto<CompleteBookDto>, APIError> {
         let converted_page: u64;
-        if page.is_some() {
-            if page.unwrap() < 1 {
+        if params.page.is_some() {
+            if params.page.unwrap() < 1 {
                 return Err(APIError::SimpleAPIError(SimpleAPIError {
                     msg: "Requested page must have a value greater than one".to_string(),
                     code: 400,
                 }));
             } else {
-                converted_page = u64::from_ne_bytes(page.unwrap().to_ne_bytes());
+                converted_page = u64::from_ne_bytes(params.page.unwrap().to_ne_bytes());
             }
         } else {
             converted_page = 1;
         }
         let converted_page_size: u64;
-        if page_size.is_some() {
-            if page_size.unwrap() < 1 {
+        if params.page_size.is_some() {
+            if params.page_size.unwrap() < 1 {
                 return Err(APIError::SimpleAPIError(SimpleAPIError {
                     msg: "Requested page size must have a value greater than one".to_string(),
                     code: 400,
                 }));
             } else {
-                converted_page_size = u64::from_ne_bytes(page_size.unwrap().to_ne_bytes());
+                converted_page_size = u64::from_ne_bytes(params.page_size.unwrap().to_ne_bytes());
             }
         } else {
             converted_page_size = 10
         }
+        let listing_options = self
+            .resolve_book_listing_options_usecase
+            .resolve(user_id, &params)
+            .await?;
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(user_id, library_id, LibraryRole::Viewer)
+            .await?;
 
-        match self
-            .book_repository
-            .find_all_by_user_id_as_complete_book_dto(
-                user_id,
-                converted_page,
-                converted_page_size,
-                collection_id,
-                location_id,
-                query.clone(),
-            )
+        let found_books = match params.cursor.as_deref() {
+            Some(cursor) => {
+                if params.page.is_some() {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "Page and cursor can't be informed together".to_string(),
+                        400,
+                    )));
+                }
+                if listing_options.custom_field_sort.is_some() {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "Cursor pagination only supports ordering by title".to_string(),
+                        400,
+                    )));
+                }
+                let cursor = match cursor.trim() {
+                    "" => None,
+                    token => match BookCursor::decode(token) {
+                        Ok(cursor) => Some(cursor),
+                        Err(error) => {
+                            return Err(APIError::SimpleAPIError(SimpleAPIError::new(error, 400)))
+                        }
+                    },
+                };
+                self.book_repository
+                    .find_all_by_library_id_as_complete_book_dto_by_cursor(
+                        library_id,
+                        cursor.as_ref(),
+                        converted_page_size,
+                        params.collection_id,
+                        params.location_id,
+                        params.query.clone(),
+                        &listing_options,
+                        params.include_total.unwrap_or(false),
+                    )
+                    .await
+            }
+            None => {
+                self.book_repository
+                    .find_all_by_library_id_as_complete_book_dto(
+                        library_id,
+                        converted_page,
+                        converted_page_size,
+                        params.collection_id,
+                        params.location_id,
+                        params.query.clone(),
+                        &listing_options,
+                    )
+                    .await
+            }
+        };
+        let mut found_books = match found_books {
+            Ok(found_books) => found_books,
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        let book_ids: Vec<u64> = found_books.items.iter().map(|book| book.id).collect();
+        let mut tags_by_book = match self
+            .tag_repository
+            .find_all_by_book_ids_and_user_id(&book_ids, user_id)
             .await
         {
-            Ok(found_books) => Ok(found_books),
-            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError {
-                msg: e.to_string(),
-                code: 500,
-            })),
+            Ok(tags_by_book) => tags_by_book,
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let mut custom_fields_by_book = match self
+            .custom_field_repository
+            .find_all_values_by_book_ids_and_user_id(&book_ids, user_id)
+            .await
+        {
+            Ok(custom_fields_by_book) => custom_fields_by_book,
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let mut note_counts_by_book = match self
+            .note_repository
+            .count_by_book_ids_and_user_id(&book_ids, user_id)
+            .await
+        {
+            Ok(note_counts_by_book) => note_counts_by_book,
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        for book in found_books.items.iter_mut() {
+            book.tags = tags_by_book.remove(&book.id).unwrap_or_default();
+            book.custom_fields = custom_fields_by_book.remove(&book.id).unwrap_or_default();
+            book.note_count = note_counts_by_book.remove(&book.id).unwrap_or_default();
         }
+
+        Ok(found_books)
     }
 }
@@ -8,30 +8,51 @@ use crate::modules::{
             collection_repository_mysql::CollectionRepositoryMySQL,
         },
     },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
     shared::errors::{simple_api_error::SimpleAPIError, APIError},
 };
 
-pub struct FindAllCollectionFromUserUseCaseV1<T>
+pub struct FindAllCollectionFromUserUseCaseV1<T, U>
 where
     T: CollectionRepository,
+    U: LibraryRepository,
 {
     collection_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
 }
 
-impl FindAllCollectionFromUserUseCaseV1<CollectionRepositoryMySQL> {
-    pub fn new(collection_repository: CollectionRepositoryMySQL) -> Self {
+impl FindAllCollectionFromUserUseCaseV1<CollectionRepositoryMySQL, LibraryRepositoryMySQL> {
+    pub fn new(
+        collection_repository: CollectionRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
         Self {
             collection_repository: Arc::new(collection_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
         }
     }
 
     pub async fn find_all_collection_from_user(
         &self,
         authed_user_id: u64,
+        library_id: Option<u64>,
     ) -> Result<Vec<Collection>, APIError> {
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(authed_user_id, library_id, LibraryRole::Viewer)
+            .await?;
+
         match self
             .collection_repository
-            .find_all_by_user_id(authed_user_id)
+            .find_all_by_library_id(library_id)
             .await
         {
             Ok(found_collection_option) => Ok(found_collection_option),
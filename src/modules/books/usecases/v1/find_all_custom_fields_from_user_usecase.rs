@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::entities::custom_field::CustomField,
+        infra::repositories::{
+            custom_field_repository::CustomFieldRepository,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct FindAllCustomFieldsFromUserUseCaseV1<T>
+where
+    T: CustomFieldRepository,
+{
+    custom_field_repository: Arc<T>,
+}
+
+impl FindAllCustomFieldsFromUserUseCaseV1<CustomFieldRepositoryMySQL> {
+    pub fn new(custom_field_repository: CustomFieldRepositoryMySQL) -> Self {
+        Self {
+            custom_field_repository: Arc::new(custom_field_repository),
+        }
+    }
+
+    pub async fn find_all_custom_fields_from_user(
+        &self,
+        authed_user_id: u64,
+    ) -> Result<Vec<CustomField>, APIError> {
+        match self
+            .custom_field_repository
+            .find_all_by_user_id(authed_user_id)
+            .await
+        {
+            Ok(found_custom_fields) => Ok(found_custom_fields),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -8,30 +8,51 @@ use crate::modules::{
             location_repository_mysql::LocationRepositoryMySQL,
         },
     },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
     shared::errors::{simple_api_error::SimpleAPIError, APIError},
 };
 
-pub struct FindAllLocationFromUserUseCaseV1<T>
+pub struct FindAllLocationFromUserUseCaseV1<T, U>
 where
     T: LocationRepository,
+    U: LibraryRepository,
 {
     location_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
 }
 
-impl FindAllLocationFromUserUseCaseV1<LocationRepositoryMySQL> {
-    pub fn new(location_repository: LocationRepositoryMySQL) -> Self {
+impl FindAllLocationFromUserUseCaseV1<LocationRepositoryMySQL, LibraryRepositoryMySQL> {
+    pub fn new(
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
         Self {
             location_repository: Arc::new(location_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
         }
     }
 
     pub async fn find_all_location_from_user(
         &self,
         authed_user_id: u64,
+        library_id: Option<u64>,
     ) -> Result<Vec<Location>, APIError> {
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(authed_user_id, library_id, LibraryRole::Viewer)
+            .await?;
+
         match self
             .location_repository
-            .find_all_by_user_id(authed_user_id)
+            .find_all_by_library_id(library_id)
             .await
         {
             Ok(found_location_option) => Ok(found_location_option),
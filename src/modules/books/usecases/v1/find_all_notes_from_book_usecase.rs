@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::dtos::note_dto::NoteDto,
+        infra::repositories::{
+            book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+            note_repository::NoteRepository, note_repository_mysql::NoteRepositoryMySQL,
+        },
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct FindAllNotesFromBookUseCaseV1<T, U, V>
+where
+    T: NoteRepository,
+    U: BookRepository,
+    V: LibraryRepository,
+{
+    note_repository: Arc<T>,
+    book_repository: Arc<U>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<V>,
+}
+
+impl
+    FindAllNotesFromBookUseCaseV1<NoteRepositoryMySQL, BookRepositoryMySQL, LibraryRepositoryMySQL>
+{
+    pub fn new(
+        note_repository: NoteRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
+        Self {
+            note_repository: Arc::new(note_repository),
+            book_repository: Arc::new(book_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn find_all_notes_from_book(
+        &self,
+        user_id: u64,
+        book_id: u64,
+    ) -> Result<Vec<NoteDto>, APIError> {
+        let library_id = match self.book_repository.find_by_id(book_id).await {
+            Ok(Some(book)) => book.library_id,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Book not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        self.authorize_library_member_usecase
+            .authorize(user_id, Some(library_id), LibraryRole::Viewer)
+            .await?;
+
+        match self
+            .note_repository
+            .find_all_by_book_id_and_user_id(book_id, user_id)
+            .await
+        {
+            Ok(notes) => Ok(notes),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
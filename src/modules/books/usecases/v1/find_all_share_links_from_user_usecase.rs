@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::dtos::share_link_dto::ShareLinkDto,
+        infra::repositories::{
+            share_link_repository::ShareLinkRepository,
+            share_link_repository_mysql::ShareLinkRepositoryMySQL,
+        },
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct FindAllShareLinksFromUserUseCaseV1<T, U>
+where
+    T: ShareLinkRepository,
+    U: LibraryRepository,
+{
+    share_link_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+}
+
+impl FindAllShareLinksFromUserUseCaseV1<ShareLinkRepositoryMySQL, LibraryRepositoryMySQL> {
+    pub fn new(
+        share_link_repository: ShareLinkRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
+        Self {
+            share_link_repository: Arc::new(share_link_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn find_all_share_links_from_user(
+        &self,
+        user_id: u64,
+        library_id: Option<u64>,
+    ) -> Result<Vec<ShareLinkDto>, APIError> {
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(user_id, library_id, LibraryRole::Editor)
+            .await?;
+
+        match self
+            .share_link_repository
+            .find_all_by_library_id(library_id)
+            .await
+        {
+            Ok(share_links) => Ok(share_links.into_iter().map(ShareLinkDto::from).collect()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::public_book_dto::PublicBookDto,
+            entities::{book_listing::BookListingOptions, share_link::ShareLinkScope},
+        },
+        infra::repositories::{
+            book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+            share_link_repository::ShareLinkRepository,
+            share_link_repository_mysql::ShareLinkRepositoryMySQL,
+        },
+    },
+    shared::{
+        domain::dtos::paginated_dto::PaginatedDto,
+        errors::{simple_api_error::SimpleAPIError, APIError},
+    },
+};
+
+const MAX_PAGE_SIZE: i64 = 100;
+
+pub struct FindAllSharedBooksUseCaseV1<T, U>
+where
+    T: ShareLinkRepository,
+    U: BookRepository,
+{
+    share_link_repository: Arc<T>,
+    book_repository: Arc<U>,
+}
+
+impl FindAllSharedBooksUseCaseV1<ShareLinkRepositoryMySQL, BookRepositoryMySQL> {
+    pub fn new(
+        share_link_repository: ShareLinkRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+    ) -> Self {
+        Self {
+            share_link_repository: Arc::new(share_link_repository),
+            book_repository: Arc::new(book_repository),
+        }
+    }
+
+    pub async fn find_all_shared_books(
+        &self,
+        slug: &str,
+        page: Option<i64>,
+        page_size: Option<i64>,
+        query: Option<String>,
+    ) -> Result<PaginatedDto<PublicBookDto>, APIError> {
+        // Unknown, revoked and expired links look the same to the caller
+        let share_link = match self.share_link_repository.find_by_slug(slug).await {
+            Ok(Some(share_link)) if share_link.is_active(chrono::Utc::now()) => share_link,
+            Ok(_) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Shared library not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        let converted_page: u64 = match page {
+            Some(page) if page < 1 => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: "Requested page must have a value greater than one".to_string(),
+                    code: 400,
+                }));
+            }
+            Some(page) => page as u64,
+            None => 1,
+        };
+        let converted_page_size: u64 = match page_size {
+            Some(page_size) if page_size < 1 => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: "Requested page size must have a value greater than one".to_string(),
+                    code: 400,
+                }));
+            }
+            Some(page_size) if page_size > MAX_PAGE_SIZE => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: format!(
+                        "Requested page size must have a value up to {}",
+                        MAX_PAGE_SIZE
+                    ),
+                    code: 400,
+                }));
+            }
+            Some(page_size) => page_size as u64,
+            None => 10,
+        };
+
+        let (collection_id, location_id) = match share_link.scope {
+            ShareLinkScope::Library => (None, None),
+            ShareLinkScope::Location(location_id) => (None, Some(location_id as i64)),
+            ShareLinkScope::Collection(collection_id) => (Some(collection_id as i64), None),
+        };
+
+        match self
+            .book_repository
+            .find_all_by_library_id_as_complete_book_dto(
+                share_link.library_id,
+                converted_page,
+                converted_page_size,
+                collection_id,
+                location_id,
+                query,
+                &BookListingOptions::default(),
+            )
+            .await
+        {
+            Ok(found_books) => Ok(PaginatedDto {
+                page: found_books.page,
+                page_size: found_books.page_size,
+                total_items: found_books.total_items,
+                next_cursor: found_books.next_cursor,
+                prev_cursor: found_books.prev_cursor,
+                items: found_books
+                    .items
+                    .into_iter()
+                    .map(PublicBookDto::from)
+                    .collect(),
+            }),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: e.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::dtos::tag_usage_dto::TagUsageDto,
+        infra::repositories::{
+            tag_repository::TagRepository, tag_repository_mysql::TagRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct FindAllTagsFromUserUseCaseV1<T>
+where
+    T: TagRepository,
+{
+    tag_repository: Arc<T>,
+}
+
+impl FindAllTagsFromUserUseCaseV1<TagRepositoryMySQL> {
+    pub fn new(tag_repository: TagRepositoryMySQL) -> Self {
+        Self {
+            tag_repository: Arc::new(tag_repository),
+        }
+    }
+
+    pub async fn find_all_tags_from_user(
+        &self,
+        authed_user_id: u64,
+    ) -> Result<Vec<TagUsageDto>, APIError> {
+        match self
+            .tag_repository
+            .find_all_by_user_id_with_usage(authed_user_id)
+            .await
+        {
+            Ok(found_tags) => Ok(found_tags),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::dtos::trash_item_dto::TrashItemDto,
+        infra::repositories::{
+            trash_repository::TrashRepository, trash_repository_mysql::TrashRepositoryMySQL,
+        },
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct FindAllTrashItemsUseCaseV1<T, U>
+where
+    T: TrashRepository,
+    U: LibraryRepository,
+{
+    trash_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+}
+
+impl FindAllTrashItemsUseCaseV1<TrashRepositoryMySQL, LibraryRepositoryMySQL> {
+    pub fn new(
+        trash_repository: TrashRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
+        Self {
+            trash_repository: Arc::new(trash_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn find_all_trash_items(
+        &self,
+        user_id: u64,
+        library_id: Option<u64>,
+    ) -> Result<Vec<TrashItemDto>, APIError> {
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(user_id, library_id, LibraryRole::Editor)
+            .await?;
+
+        match self
+            .trash_repository
+            .find_all_by_library_id(library_id)
+            .await
+        {
+            Ok(items) => Ok(items.into_iter().map(TrashItemDto::from).collect()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::{barcode_lookup_dto::BarcodeLookupDto, create_book_dto::CreateBookDto},
+            entities::isbn::{isbn10_from_isbn13, isbn13_from_ean13},
+        },
+        infra::{
+            barcodes::ean13_decoder::decode_ean13_from_image,
+            repositories::{
+                book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+            },
+        },
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct FindBookByBarcodeUseCaseV1<T, U>
+where
+    T: BookRepository,
+    U: LibraryRepository,
+{
+    book_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+}
+
+impl FindBookByBarcodeUseCaseV1<BookRepositoryMySQL, LibraryRepositoryMySQL> {
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
+        Self {
+            book_repository: Arc::new(book_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn find_by_barcode(
+        &self,
+        user_id: u64,
+        library_id: Option<u64>,
+        image: Vec<u8>,
+    ) -> Result<BarcodeLookupDto, APIError> {
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(user_id, library_id, LibraryRole::Viewer)
+            .await?;
+
+        // Decoding is CPU bound, it must not hold the async workers
+        let decoded_barcode =
+            match tokio::task::spawn_blocking(move || decode_ean13_from_image(&image)).await {
+                Ok(decoded_barcode) => decoded_barcode,
+                Err(error) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        error.to_string(),
+                        500,
+                    )))
+                }
+            };
+
+        let ean13 = match decoded_barcode {
+            Ok(Some(ean13)) => ean13,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "No EAN-13 barcode was found in the image".to_string(),
+                    422,
+                )))
+            }
+            Err(msg) => return Err(APIError::SimpleAPIError(SimpleAPIError::new(msg, 400))),
+        };
+
+        let isbn13 = match isbn13_from_ean13(&ean13) {
+            Some(isbn13) => isbn13,
+            None => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!("Barcode {} is not an ISBN", ean13),
+                    422,
+                )))
+            }
+        };
+
+        let mut isbns = vec![isbn13.clone()];
+        if let Some(isbn10) = isbn10_from_isbn13(&isbn13) {
+            isbns.push(isbn10);
+        }
+
+        match self
+            .book_repository
+            .find_by_isbns_as_complete_book_dto(library_id, &isbns)
+            .await
+        {
+            Ok(Some(book)) => Ok(BarcodeLookupDto {
+                isbn: isbn13,
+                found: true,
+                book: Some(book),
+                new_book: None,
+            }),
+            Ok(None) => Ok(BarcodeLookupDto {
+                isbn: isbn13.clone(),
+                found: false,
+                book: None,
+                new_book: Some(CreateBookDto {
+                    isbn: Some(isbn13),
+                    ..Default::default()
+                }),
+            }),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -5,22 +5,61 @@ use crate::modules::{
         domain::dtos::complete_book_dto::CompleteBookDto,
         infra::repositories::{
             book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+            custom_field_repository::CustomFieldRepository,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+            note_repository::NoteRepository, note_repository_mysql::NoteRepositoryMySQL,
+            tag_repository::TagRepository, tag_repository_mysql::TagRepositoryMySQL,
         },
     },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
     shared::errors::{simple_api_error::SimpleAPIError, APIError},
 };
 
-pub struct FindBookByIDUseCaseV1<T>
+pub struct FindBookByIDUseCaseV1<T, U, V, W, X>
 where
     T: BookRepository,
+    U: LibraryRepository,
+    V: TagRepository,
+    W: CustomFieldRepository,
+    X: NoteRepository,
 {
     book_repository: Arc<T>,
+    tag_repository: Arc<V>,
+    custom_field_repository: Arc<W>,
+    note_repository: Arc<X>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
 }
 
-impl FindBookByIDUseCaseV1<BookRepositoryMySQL> {
-    pub fn new(book_repository: BookRepositoryMySQL) -> Self {
+impl
+    FindBookByIDUseCaseV1<
+        BookRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        NoteRepositoryMySQL,
+    >
+{
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        note_repository: NoteRepositoryMySQL,
+    ) -> Self {
         Self {
             book_repository: Arc::new(book_repository),
+            tag_repository: Arc::new(tag_repository),
+            custom_field_repository: Arc::new(custom_field_repository),
+            note_repository: Arc::new(note_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
         }
     }
 
@@ -29,20 +68,86 @@ impl FindBookByIDUseCaseV1<BookRepositoryMySQL> {
         user_id: u64,
         book_id: u64,
     ) -> Result<CompleteBookDto, APIError> {
-        match self
+        let library_id = match self.book_repository.find_by_id(book_id).await {
+            Ok(Some(found_book)) => found_book.library_id,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: "Book not found".to_string(),
+                    code: 404,
+                }))
+            }
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        self.authorize_library_member_usecase
+            .authorize(user_id, Some(library_id), LibraryRole::Viewer)
+            .await?;
+
+        let mut found_book = match self
             .book_repository
-            .find_by_id_as_complete_book_dto(user_id, book_id)
+            .find_by_id_as_complete_book_dto(library_id, book_id)
+            .await
+        {
+            Ok(Some(found_book)) => found_book,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: "Book not found".to_string(),
+                    code: 404,
+                }))
+            }
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        match self
+            .tag_repository
+            .find_all_by_book_ids_and_user_id(&[book_id], user_id)
+            .await
+        {
+            Ok(mut tags_by_book) => {
+                found_book.tags = tags_by_book.remove(&book_id).unwrap_or_default();
+            }
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self
+            .custom_field_repository
+            .find_all_values_by_book_ids_and_user_id(&[book_id], user_id)
+            .await
+        {
+            Ok(mut custom_fields_by_book) => {
+                found_book.custom_fields =
+                    custom_fields_by_book.remove(&book_id).unwrap_or_default();
+            }
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self
+            .note_repository
+            .count_by_book_ids_and_user_id(&[book_id], user_id)
             .await
         {
-            Ok(found_book) => {
-                if let Some(found_book) = found_book {
-                    Ok(found_book)
-                } else {
-                    Err(APIError::SimpleAPIError(SimpleAPIError {
-                        msg: "Book not found".to_string(),
-                        code: 404,
-                    }))
-                }
+            Ok(mut note_counts_by_book) => {
+                found_book.note_count = note_counts_by_book.remove(&book_id).unwrap_or_default();
+                Ok(found_book)
             }
             Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError {
                 msg: e.to_string(),
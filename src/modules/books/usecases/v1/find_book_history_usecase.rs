@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::find_book_history_dto::FindBookHistoryDto, entities::audit_entry::AuditEntityType,
+        },
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+        },
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct FindBookHistoryUseCaseV1<T, U>
+where
+    T: AuditRepository,
+    U: LibraryRepository,
+{
+    audit_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+}
+
+impl FindBookHistoryUseCaseV1<AuditRepositoryMySQL, LibraryRepositoryMySQL> {
+    pub fn new(
+        audit_repository: AuditRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
+        Self {
+            audit_repository: Arc::new(audit_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn find_book_history(
+        &self,
+        user_id: u64,
+        book_id: u64,
+    ) -> Result<FindBookHistoryDto, APIError> {
+        let entries = match self
+            .audit_repository
+            .find_all_by_entity(AuditEntityType::Book, book_id)
+            .await
+        {
+            Ok(entries) => entries,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        // Books never move between libraries, any entry tells which one the
+        // book belongs to
+        let library_id = match entries.first() {
+            Some(entry) => entry.library_id,
+            None => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Book history not found".to_string(),
+                    404,
+                )));
+            }
+        };
+        self.authorize_library_member_usecase
+            .authorize(user_id, Some(library_id), LibraryRole::Viewer)
+            .await?;
+
+        Ok(FindBookHistoryDto { entries })
+    }
+}
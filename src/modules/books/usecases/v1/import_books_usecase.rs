@@ -0,0 +1,400 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use chrono::Utc;
+use serde_json::json;
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::{
+                book_import_report_dto::BookImportReportDto, book_import_row_dto::BookImportRowDto,
+            },
+            entities::{
+                audit_entry::{book_audit_snapshot, AuditAction, AuditEntityType, AuditEntry},
+                book::Book,
+                book_import::{normalize_isbn, BookImport, BookImportRow},
+            },
+        },
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+            book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+            collection_repository::CollectionRepository,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            location_repository::LocationRepository,
+            location_repository_mysql::LocationRepositoryMySQL,
+        },
+        usecases::v1::record_audit_entries_usecase::RecordAuditEntriesUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{
+        detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+    },
+    webhooks::{
+        domain::entities::webhook_event::WebhookEvent,
+        infra::repositories::{
+            webhook_repository::WebhookRepository, webhook_repository_mysql::WebhookRepositoryMySQL,
+        },
+        usecases::v1::enqueue_webhook_event_usecase::EnqueueWebhookEventUseCaseV1,
+    },
+};
+
+const PENDING_ID: u64 = 0;
+
+pub struct ImportBooksUseCaseV1<T, U, V, W, X, Y>
+where
+    T: BookRepository,
+    U: CollectionRepository,
+    V: LocationRepository,
+    W: LibraryRepository,
+    X: AuditRepository,
+    Y: WebhookRepository,
+{
+    book_repository: Arc<T>,
+    collection_repository: Arc<U>,
+    location_repository: Arc<V>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<W>,
+    record_audit_entries_usecase: RecordAuditEntriesUseCaseV1<X>,
+    enqueue_webhook_event_usecase: EnqueueWebhookEventUseCaseV1<Y>,
+}
+
+impl
+    ImportBooksUseCaseV1<
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        AuditRepositoryMySQL,
+        WebhookRepositoryMySQL,
+    >
+{
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+        webhook_repository: WebhookRepositoryMySQL,
+    ) -> Self {
+        Self {
+            book_repository: Arc::new(book_repository),
+            collection_repository: Arc::new(collection_repository),
+            location_repository: Arc::new(location_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            record_audit_entries_usecase: RecordAuditEntriesUseCaseV1::new(
+                audit_repository,
+                change_event_bus,
+            ),
+            enqueue_webhook_event_usecase: EnqueueWebhookEventUseCaseV1::new(webhook_repository),
+        }
+    }
+
+    pub async fn import_books(
+        &self,
+        user_id: u64,
+        library_id: Option<u64>,
+        book_import: BookImport,
+    ) -> Result<BookImportReportDto, APIError> {
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(user_id, library_id, LibraryRole::Editor)
+            .await?;
+
+        if let Some(default_location_id) = book_import.default_location_id {
+            match self
+                .location_repository
+                .find_by_id(default_location_id)
+                .await
+            {
+                Ok(maybe_a_location) => match maybe_a_location {
+                    None => {
+                        return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                            "The informed default location does not exist".to_string(),
+                            404,
+                        )));
+                    }
+                    Some(location) if location.library_id != library_id => {
+                        return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                            "The informed default location belongs to another library".to_string(),
+                            403,
+                        )));
+                    }
+                    Some(_) => {}
+                },
+                Err(error) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError {
+                        msg: error.to_string(),
+                        code: 500,
+                    }))
+                }
+            }
+        }
+
+        let location_ids: HashMap<String, u64> = match self
+            .location_repository
+            .find_all_by_library_id(library_id)
+            .await
+        {
+            Ok(locations) => locations
+                .into_iter()
+                .filter_map(|location| location.id.map(|id| (location.name.to_lowercase(), id)))
+                .collect(),
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let collection_ids: HashMap<String, u64> = match self
+            .collection_repository
+            .find_all_by_library_id(library_id)
+            .await
+        {
+            Ok(collections) => collections
+                .into_iter()
+                .filter_map(|collection| {
+                    collection.id.map(|id| (collection.name.to_lowercase(), id))
+                })
+                .collect(),
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let mut known_titles: HashSet<String> = HashSet::new();
+        let mut known_isbns: HashSet<String> = HashSet::new();
+        match self
+            .book_repository
+            .find_all_by_library_id(library_id)
+            .await
+        {
+            Ok(books) => {
+                for book in books.into_iter() {
+                    known_titles.insert(book.title.to_lowercase());
+                    if let Some(isbn) = book.isbn.as_deref().and_then(normalize_isbn) {
+                        known_isbns.insert(isbn);
+                    }
+                }
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        let mut report = BookImportReportDto {
+            dry_run: book_import.dry_run,
+            total_rows: book_import.candidates.len() as u64,
+            ..Default::default()
+        };
+        let mut rows_to_import: Vec<BookImportRow> = Vec::new();
+
+        for mut candidate in book_import.candidates.into_iter() {
+            let mut new_location_name = None;
+            match candidate.location_name.take() {
+                Some(location_name) => match location_ids.get(&location_name.to_lowercase()) {
+                    Some(location_id) => candidate.book_dto.location_id = Some(*location_id),
+                    None => {
+                        candidate.book_dto.location_id = Some(PENDING_ID);
+                        new_location_name = Some(location_name);
+                    }
+                },
+                None => candidate.book_dto.location_id = book_import.default_location_id,
+            }
+            let mut new_collection_name = None;
+            if let Some(collection_name) = candidate.collection_name.take() {
+                match collection_ids.get(&collection_name.to_lowercase()) {
+                    Some(collection_id) => candidate.book_dto.collection_id = Some(*collection_id),
+                    None => {
+                        candidate.book_dto.collection_id = Some(PENDING_ID);
+                        new_collection_name = Some(collection_name);
+                    }
+                }
+            }
+            candidate.book_dto.user_id = Some(user_id);
+
+            let title = candidate.book_dto.title.clone();
+            let book = match Book::try_from(candidate.book_dto) {
+                Ok(book) => book,
+                Err(error) => {
+                    report.invalid_rows += 1;
+                    report.rows.push(BookImportRowDto {
+                        row: candidate.row_number,
+                        status: "invalid".to_string(),
+                        title,
+                        book_id: None,
+                        error: Some(error),
+                    });
+                    continue;
+                }
+            };
+
+            let duplicated_isbn = book
+                .isbn
+                .as_deref()
+                .and_then(normalize_isbn)
+                .is_some_and(|isbn| !known_isbns.insert(isbn));
+            let duplicated_title = !known_titles.insert(book.title.to_lowercase());
+            if duplicated_isbn || duplicated_title {
+                let (field, validation) = if duplicated_isbn {
+                    ("isbn", "O ISBN já está em uso por outro livro")
+                } else {
+                    ("title", "O título já está em uso por outro livro")
+                };
+                report.skipped_rows += 1;
+                report.rows.push(BookImportRowDto {
+                    row: candidate.row_number,
+                    status: "skipped".to_string(),
+                    title: Some(book.title),
+                    book_id: None,
+                    error: Some(DetailedAPIError::new(
+                        "Livro já existe na biblioteca".to_string(),
+                        409,
+                        Some(HashMap::from([(field.to_string(), validation.to_string())])),
+                    )),
+                });
+                continue;
+            }
+
+            if let Some(location_name) = &new_location_name {
+                if !report
+                    .created_locations
+                    .iter()
+                    .any(|created| created.eq_ignore_ascii_case(location_name))
+                {
+                    report.created_locations.push(location_name.clone());
+                }
+            }
+            if let Some(collection_name) = &new_collection_name {
+                if !report
+                    .created_collections
+                    .iter()
+                    .any(|created| created.eq_ignore_ascii_case(collection_name))
+                {
+                    report.created_collections.push(collection_name.clone());
+                }
+            }
+
+            report.valid_rows += 1;
+            report.rows.push(BookImportRowDto {
+                row: candidate.row_number,
+                status: "valid".to_string(),
+                title: Some(book.title.clone()),
+                book_id: None,
+                error: None,
+            });
+            rows_to_import.push(BookImportRow {
+                row_number: candidate.row_number,
+                book,
+                new_location_name,
+                new_collection_name,
+            });
+        }
+
+        if report.dry_run || rows_to_import.is_empty() {
+            return Ok(report);
+        }
+
+        let imported_ids = match self
+            .book_repository
+            .import_books(library_id, user_id, &rows_to_import)
+            .await
+        {
+            Ok(imported_ids) => imported_ids,
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    e.to_string(),
+                    500,
+                )))
+            }
+        };
+
+        match self
+            .book_repository
+            .find_all_by_ids(library_id, &imported_ids)
+            .await
+        {
+            Ok(imported_books) => {
+                let entries = imported_books
+                    .iter()
+                    .map(|book| {
+                        AuditEntry::new(
+                            AuditEntityType::Book,
+                            book.id.unwrap_or_default(),
+                            library_id,
+                            user_id,
+                            AuditAction::Create,
+                            None,
+                            Some(book_audit_snapshot(book)),
+                        )
+                    })
+                    .collect();
+                self.record_audit_entries_usecase.record(entries).await;
+            }
+            Err(error) => tracing::error!("Failed to load imported books for audit: {}", error),
+        }
+
+        let imported_by_row: HashMap<u64, u64> = rows_to_import
+            .iter()
+            .map(|row| row.row_number)
+            .zip(imported_ids)
+            .collect();
+        for row in report.rows.iter_mut() {
+            if let Some(book_id) = imported_by_row.get(&row.row) {
+                row.status = "imported".to_string();
+                row.book_id = Some(*book_id);
+            }
+        }
+        report.imported_rows = imported_by_row.len() as u64;
+
+        if let Err(error) = self
+            .enqueue_webhook_event_usecase
+            .enqueue(
+                library_id,
+                WebhookEvent::ImportFinished,
+                Utc::now(),
+                json!({
+                    "user_id": user_id,
+                    "total_rows": report.total_rows,
+                    "imported_rows": report.imported_rows,
+                    "invalid_rows": report.invalid_rows,
+                    "skipped_rows": report.skipped_rows,
+                    "created_locations": report.created_locations,
+                    "created_collections": report.created_collections,
+                }),
+            )
+            .await
+        {
+            match error {
+                APIError::SimpleAPIError(error) => {
+                    tracing::error!("Failed to queue the import webhooks: {}", error.msg)
+                }
+                APIError::DetailedAPIError(error) => {
+                    tracing::error!("Failed to queue the import webhooks: {}", error.msg)
+                }
+                APIError::PreconditionFailedAPIError(error) => {
+                    tracing::error!("Failed to queue the import webhooks: {}", error.msg)
+                }
+            }
+        }
+
+        Ok(report)
+    }
+}
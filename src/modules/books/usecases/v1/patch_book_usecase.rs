@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::complete_book_dto::CompleteBookDto,
+            mappers::book_merge_patch_mapper::book_from_merge_patch,
+        },
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+            book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+            collection_repository::CollectionRepository,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            custom_field_repository::CustomFieldRepository,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+            location_repository::LocationRepository,
+            location_repository_mysql::LocationRepositoryMySQL, tag_repository::TagRepository,
+            tag_repository_mysql::TagRepositoryMySQL,
+        },
+        usecases::v1::create_update_book_usecase::CreateUpdateBookUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct PatchBookUseCaseV1<T, U, V, W, X, Y, Z>
+where
+    T: BookRepository,
+    U: CollectionRepository,
+    V: LocationRepository,
+    W: LibraryRepository,
+    X: TagRepository,
+    Y: CustomFieldRepository,
+    Z: AuditRepository,
+{
+    book_repository: Arc<T>,
+    custom_field_repository: Arc<Y>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<W>,
+    create_update_book_usecase: CreateUpdateBookUseCaseV1<T, U, V, W, X, Y, Z>,
+}
+
+impl
+    PatchBookUseCaseV1<
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        Self {
+            book_repository: Arc::new(book_repository.clone()),
+            custom_field_repository: Arc::new(custom_field_repository.clone()),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository.clone(),
+            ),
+            create_update_book_usecase: CreateUpdateBookUseCaseV1::new(
+                book_repository,
+                collection_repository,
+                location_repository,
+                library_repository,
+                tag_repository,
+                custom_field_repository,
+                audit_repository,
+                change_event_bus,
+            ),
+        }
+    }
+
+    /// Custom field values are merged with the user's current ones, while
+    /// `tag_ids`, like any other array, replaces the current tags.
+    pub async fn patch_book(
+        &self,
+        user_id: u64,
+        book_id: u64,
+        patch: Value,
+        expected_version: Option<u64>,
+    ) -> Result<CompleteBookDto, APIError> {
+        let mut stored_book = match self.book_repository.find_by_id(book_id).await {
+            Ok(Some(book)) => book,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Book not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        self.authorize_library_member_usecase
+            .authorize(user_id, Some(stored_book.library_id), LibraryRole::Editor)
+            .await?;
+
+        if patch.get("custom_fields").is_some() {
+            match self
+                .custom_field_repository
+                .find_all_values_by_book_ids_and_user_id(&[book_id], user_id)
+                .await
+            {
+                Ok(mut custom_fields_by_book) => {
+                    stored_book.custom_fields =
+                        Some(custom_fields_by_book.remove(&book_id).unwrap_or_default())
+                }
+                Err(error) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError {
+                        msg: error.to_string(),
+                        code: 500,
+                    }))
+                }
+            }
+        }
+        let book = match book_from_merge_patch(stored_book, &patch) {
+            Ok(book) => book,
+            Err(error) => return Err(APIError::DetailedAPIError(error)),
+        };
+
+        self.create_update_book_usecase
+            .create_update_book(user_id, book, expected_version)
+            .await
+    }
+}
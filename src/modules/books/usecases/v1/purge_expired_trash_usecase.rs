@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use crate::modules::{
+    books::{
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+            trash_repository::TrashRepository, trash_repository_mysql::TrashRepositoryMySQL,
+        },
+        usecases::v1::record_audit_entries_usecase::RecordAuditEntriesUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct PurgeExpiredTrashUseCaseV1<T, U>
+where
+    T: TrashRepository,
+    U: AuditRepository,
+{
+    trash_repository: Arc<T>,
+    record_audit_entries_usecase: RecordAuditEntriesUseCaseV1<U>,
+}
+
+impl PurgeExpiredTrashUseCaseV1<TrashRepositoryMySQL, AuditRepositoryMySQL> {
+    pub fn new(
+        trash_repository: TrashRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        Self {
+            trash_repository: Arc::new(trash_repository),
+            record_audit_entries_usecase: RecordAuditEntriesUseCaseV1::new(
+                audit_repository,
+                change_event_bus,
+            ),
+        }
+    }
+
+    pub async fn purge_expired_trash(&self, retention_days: i64) -> Result<u64, APIError> {
+        let deleted_before = Utc::now() - Duration::days(retention_days);
+        match self
+            .trash_repository
+            .purge_trashed_before(deleted_before)
+            .await
+        {
+            Ok(audit_entries) => {
+                self.record_audit_entries_usecase.publish(&audit_entries);
+                Ok(audit_entries.len() as u64)
+            }
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::entities::{
+            audit_entry::{AuditAction, AuditEntry},
+            trash::TrashItemType,
+        },
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+            trash_repository::TrashRepository, trash_repository_mysql::TrashRepositoryMySQL,
+        },
+        usecases::v1::record_audit_entries_usecase::RecordAuditEntriesUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct PurgeTrashItemUseCaseV1<T, U, V>
+where
+    T: TrashRepository,
+    U: LibraryRepository,
+    V: AuditRepository,
+{
+    trash_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+    record_audit_entries_usecase: RecordAuditEntriesUseCaseV1<V>,
+}
+
+impl PurgeTrashItemUseCaseV1<TrashRepositoryMySQL, LibraryRepositoryMySQL, AuditRepositoryMySQL> {
+    pub fn new(
+        trash_repository: TrashRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        Self {
+            trash_repository: Arc::new(trash_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            record_audit_entries_usecase: RecordAuditEntriesUseCaseV1::new(
+                audit_repository,
+                change_event_bus,
+            ),
+        }
+    }
+
+    pub async fn purge_trash_item(
+        &self,
+        user_id: u64,
+        item_type: TrashItemType,
+        item_id: u64,
+    ) -> Result<(), APIError> {
+        let library_id = match self.trash_repository.find_by_id(item_type, item_id).await {
+            Ok(Some(item)) => item.library_id,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Trash item not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        self.authorize_library_member_usecase
+            .authorize(user_id, Some(library_id), LibraryRole::Editor)
+            .await?;
+
+        match self.trash_repository.purge(item_type, item_id).await {
+            Ok(_) => {
+                self.record_audit_entries_usecase
+                    .record(vec![AuditEntry::new(
+                        item_type.into(),
+                        item_id,
+                        library_id,
+                        user_id,
+                        AuditAction::Purge,
+                        None,
+                        None,
+                    )])
+                    .await;
+                Ok(())
+            }
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
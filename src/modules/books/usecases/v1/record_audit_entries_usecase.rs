@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::entities::audit_entry::{AuditAction, AuditEntry},
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+        },
+    },
+    events::{
+        domain::entities::change_event::ChangeEvent, infra::buses::change_event_bus::ChangeEventBus,
+    },
+};
+
+pub struct RecordAuditEntriesUseCaseV1<T: AuditRepository> {
+    audit_repository: Arc<T>,
+    change_event_bus: ChangeEventBus,
+}
+
+impl RecordAuditEntriesUseCaseV1<AuditRepositoryMySQL> {
+    pub fn new(audit_repository: AuditRepositoryMySQL, change_event_bus: ChangeEventBus) -> Self {
+        Self {
+            audit_repository: Arc::new(audit_repository),
+            change_event_bus,
+        }
+    }
+
+    /// The change being audited is already persisted when this runs, so a
+    /// failure to save the trail is logged instead of failing the request.
+    /// Updates that didn't modify any audited field are left out. Recorded
+    /// changes are also published to the clients following the library.
+    pub async fn record(&self, entries: Vec<AuditEntry>) {
+        let entries: Vec<AuditEntry> = entries
+            .into_iter()
+            .filter(|entry| entry.action != AuditAction::Update || !entry.changes.is_empty())
+            .collect();
+        if entries.is_empty() {
+            return;
+        }
+
+        if let Err(error) = self.audit_repository.save_all(&entries).await {
+            tracing::error!(
+                "Failed to record {} audit entries: {}",
+                entries.len(),
+                error
+            );
+        }
+        self.publish(&entries);
+    }
+
+    pub fn publish(&self, entries: &[AuditEntry]) {
+        for entry in entries.iter() {
+            self.change_event_bus.publish(ChangeEvent::from(entry));
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::entities::tag::Tag,
+        infra::repositories::{
+            tag_repository::TagRepository, tag_repository_mysql::TagRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct RenameTagUseCaseV1<T>
+where
+    T: TagRepository,
+{
+    tag_repository: Arc<T>,
+}
+
+impl RenameTagUseCaseV1<TagRepositoryMySQL> {
+    pub fn new(tag_repository: TagRepositoryMySQL) -> Self {
+        Self {
+            tag_repository: Arc::new(tag_repository),
+        }
+    }
+
+    pub async fn rename_tag(&self, tag_id: u64, mut renamed_tag: Tag) -> Result<Tag, APIError> {
+        match self.tag_repository.find_by_id(tag_id).await {
+            Ok(Some(found_tag)) if found_tag.user_id == renamed_tag.user_id => {}
+            Ok(_) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Tag not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self
+            .tag_repository
+            .find_by_name_and_user_id(&renamed_tag.name, renamed_tag.user_id)
+            .await
+        {
+            Ok(Some(duplicated_tag)) if duplicated_tag.id != Some(tag_id) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "There is already a tag with the given name".to_string(),
+                    409,
+                )));
+            }
+            Ok(_) => {}
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        renamed_tag.id = Some(tag_id);
+        match self.tag_repository.save(&renamed_tag).await {
+            Ok(t) => match t {
+                Some(returned_tag) => Ok(returned_tag),
+                None => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Failed to load renamed tag info".to_string(),
+                    500,
+                ))),
+            },
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::get_all_books_params::GetAllBooksParams,
+            entities::book_listing::BookListingOptions,
+        },
+        infra::repositories::{
+            custom_field_repository::CustomFieldRepository,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct ResolveBookListingOptionsUseCaseV1<T>
+where
+    T: CustomFieldRepository,
+{
+    custom_field_repository: Arc<T>,
+}
+
+impl ResolveBookListingOptionsUseCaseV1<CustomFieldRepositoryMySQL> {
+    pub fn new(custom_field_repository: CustomFieldRepositoryMySQL) -> Self {
+        Self {
+            custom_field_repository: Arc::new(custom_field_repository),
+        }
+    }
+
+    pub async fn resolve(
+        &self,
+        user_id: u64,
+        params: &GetAllBooksParams,
+    ) -> Result<BookListingOptions, APIError> {
+        let custom_fields = if BookListingOptions::uses_custom_fields(params) {
+            match self
+                .custom_field_repository
+                .find_all_by_user_id(user_id)
+                .await
+            {
+                Ok(custom_fields) => custom_fields,
+                Err(error) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError {
+                        msg: error.to_string(),
+                        code: 500,
+                    }))
+                }
+            }
+        } else {
+            Vec::new()
+        };
+
+        match BookListingOptions::from_params(user_id, params, &custom_fields) {
+            Ok(listing_options) => Ok(listing_options),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError::new(error, 400))),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::library_restore_report_dto::LibraryRestoreReportDto,
+            entities::library_backup::{LibraryRestore, LibraryRestoreMode},
+        },
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+            book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+        },
+        usecases::v1::record_audit_entries_usecase::RecordAuditEntriesUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct RestoreLibraryUseCaseV1<T, U, V>
+where
+    T: BookRepository,
+    U: LibraryRepository,
+    V: AuditRepository,
+{
+    book_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+    record_audit_entries_usecase: RecordAuditEntriesUseCaseV1<V>,
+}
+
+impl RestoreLibraryUseCaseV1<BookRepositoryMySQL, LibraryRepositoryMySQL, AuditRepositoryMySQL> {
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        Self {
+            book_repository: Arc::new(book_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            record_audit_entries_usecase: RecordAuditEntriesUseCaseV1::new(
+                audit_repository,
+                change_event_bus,
+            ),
+        }
+    }
+
+    /// Replacing wipes what every member has catalogued, so only the owner
+    /// may do it, merging is open to editors.
+    pub async fn restore_library(
+        &self,
+        user_id: u64,
+        library_id: Option<u64>,
+        library_restore: LibraryRestore,
+    ) -> Result<LibraryRestoreReportDto, APIError> {
+        let required_role = match library_restore.mode {
+            LibraryRestoreMode::Merge => LibraryRole::Editor,
+            LibraryRestoreMode::Replace => LibraryRole::Owner,
+        };
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(user_id, library_id, required_role)
+            .await?;
+
+        match self
+            .book_repository
+            .restore_library(library_id, user_id, &library_restore)
+            .await
+        {
+            Ok((report, audit_entries)) => {
+                self.record_audit_entries_usecase.publish(&audit_entries);
+                Ok(report)
+            }
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::modules::{
+    books::{
+        domain::entities::{
+            audit_entry::{
+                book_audit_snapshot, collection_audit_snapshot, location_audit_snapshot,
+                AuditAction, AuditEntry,
+            },
+            trash::TrashItemType,
+        },
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+            book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+            collection_repository::CollectionRepository,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            location_repository::LocationRepository,
+            location_repository_mysql::LocationRepositoryMySQL, trash_repository::TrashRepository,
+            trash_repository_mysql::TrashRepositoryMySQL,
+        },
+        usecases::v1::record_audit_entries_usecase::RecordAuditEntriesUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct RestoreTrashItemUseCaseV1<T, U, V, W, X, Y>
+where
+    T: TrashRepository,
+    U: BookRepository,
+    V: CollectionRepository,
+    W: LocationRepository,
+    X: LibraryRepository,
+    Y: AuditRepository,
+{
+    trash_repository: Arc<T>,
+    book_repository: Arc<U>,
+    collection_repository: Arc<V>,
+    location_repository: Arc<W>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<X>,
+    record_audit_entries_usecase: RecordAuditEntriesUseCaseV1<Y>,
+}
+
+impl
+    RestoreTrashItemUseCaseV1<
+        TrashRepositoryMySQL,
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >
+{
+    pub fn new(
+        trash_repository: TrashRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        Self {
+            trash_repository: Arc::new(trash_repository),
+            book_repository: Arc::new(book_repository),
+            collection_repository: Arc::new(collection_repository),
+            location_repository: Arc::new(location_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            record_audit_entries_usecase: RecordAuditEntriesUseCaseV1::new(
+                audit_repository,
+                change_event_bus,
+            ),
+        }
+    }
+
+    pub async fn restore_trash_item(
+        &self,
+        user_id: u64,
+        item_type: TrashItemType,
+        item_id: u64,
+    ) -> Result<(), APIError> {
+        let item = match self.trash_repository.find_by_id(item_type, item_id).await {
+            Ok(Some(item)) => item,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Trash item not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        self.authorize_library_member_usecase
+            .authorize(user_id, Some(item.library_id), LibraryRole::Editor)
+            .await?;
+
+        let conflict = match item_type {
+            TrashItemType::Book => {
+                let location = self
+                    .location_repository
+                    .find_by_id(item.location_id.unwrap_or_default())
+                    .await;
+                match location {
+                    Ok(None) => Ok(Some(
+                        "The book's location is in the trash, it must be restored first",
+                    )),
+                    Ok(Some(_)) => self
+                        .book_repository
+                        .find_by_title_and_library_id(&item.name, item.library_id)
+                        .await
+                        .map(|duplicated| {
+                            duplicated.map(|_| "Já existe um livro com o mesmo nome")
+                        }),
+                    Err(error) => Err(error),
+                }
+            }
+            TrashItemType::Collection => self
+                .collection_repository
+                .find_by_name_and_library_id(&item.name, item.library_id)
+                .await
+                .map(|duplicated| {
+                    duplicated.map(|_| "There is already a collection with the same name")
+                }),
+            TrashItemType::Location => self
+                .location_repository
+                .find_by_name_and_library_id(&item.name, item.library_id)
+                .await
+                .map(|duplicated| {
+                    duplicated.map(|_| "There is already a location with the same name")
+                }),
+        };
+        match conflict {
+            Ok(Some(msg)) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    msg.to_string(),
+                    409,
+                )));
+            }
+            Ok(None) => {}
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self.trash_repository.restore(item_type, item_id).await {
+            Ok(_) => {
+                let snapshot = self.restored_snapshot(item_type, item_id).await;
+                self.record_audit_entries_usecase
+                    .record(vec![AuditEntry::new(
+                        item_type.into(),
+                        item_id,
+                        item.library_id,
+                        user_id,
+                        AuditAction::Restore,
+                        None,
+                        snapshot,
+                    )])
+                    .await;
+                Ok(())
+            }
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+
+    async fn restored_snapshot(&self, item_type: TrashItemType, item_id: u64) -> Option<Value> {
+        let snapshot = match item_type {
+            TrashItemType::Book => self
+                .book_repository
+                .find_by_id(item_id)
+                .await
+                .map(|book| book.as_ref().map(book_audit_snapshot)),
+            TrashItemType::Collection => self
+                .collection_repository
+                .find_by_id(item_id)
+                .await
+                .map(|collection| collection.as_ref().map(collection_audit_snapshot)),
+            TrashItemType::Location => self
+                .location_repository
+                .find_by_id(item_id)
+                .await
+                .map(|location| location.as_ref().map(location_audit_snapshot)),
+        };
+        snapshot.unwrap_or_else(|error| {
+            tracing::error!("Failed to load restored item for audit: {}", error);
+            None
+        })
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use serde_json::{json, Value};
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::complete_book_dto::CompleteBookDto,
+            entities::{audit_entry::AuditEntityType, book::Book},
+        },
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+            book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+            collection_repository::CollectionRepository,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            custom_field_repository::CustomFieldRepository,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+            location_repository::LocationRepository,
+            location_repository_mysql::LocationRepositoryMySQL, tag_repository::TagRepository,
+            tag_repository_mysql::TagRepositoryMySQL,
+        },
+        usecases::v1::create_update_book_usecase::CreateUpdateBookUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::infra::repositories::{
+        library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct RevertBookUseCaseV1<T, U, V, W, X, Y, Z>
+where
+    T: BookRepository,
+    U: CollectionRepository,
+    V: LocationRepository,
+    W: LibraryRepository,
+    X: TagRepository,
+    Y: CustomFieldRepository,
+    Z: AuditRepository,
+{
+    audit_repository: Arc<Z>,
+    create_update_book_usecase: CreateUpdateBookUseCaseV1<T, U, V, W, X, Y, Z>,
+}
+
+impl
+    RevertBookUseCaseV1<
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        Self {
+            audit_repository: Arc::new(audit_repository.clone()),
+            create_update_book_usecase: CreateUpdateBookUseCaseV1::new(
+                book_repository,
+                collection_repository,
+                location_repository,
+                library_repository,
+                tag_repository,
+                custom_field_repository,
+                audit_repository,
+                change_event_bus,
+            ),
+        }
+    }
+
+    pub async fn revert_book(
+        &self,
+        user_id: u64,
+        book_id: u64,
+        entry_id: u64,
+    ) -> Result<CompleteBookDto, APIError> {
+        let entry = match self.audit_repository.find_by_id(entry_id).await {
+            Ok(Some(entry))
+                if entry.entity_type == AuditEntityType::Book && entry.entity_id == book_id =>
+            {
+                entry
+            }
+            Ok(_) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "History entry not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        let mut snapshot = match entry.snapshot {
+            Some(Value::Object(snapshot)) => snapshot,
+            _ => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "The informed history entry has no version to revert to".to_string(),
+                    422,
+                )));
+            }
+        };
+        snapshot.insert("id".to_string(), json!(book_id));
+        snapshot.insert("user_id".to_string(), json!(user_id));
+        snapshot.insert("library_id".to_string(), json!(entry.library_id));
+        // Replaced by the current version when saving
+        snapshot.insert("version".to_string(), json!(0));
+
+        let book: Book = match serde_json::from_value(Value::Object(snapshot)) {
+            Ok(book) => book,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: format!("Failed to read book version: {}", error),
+                    code: 500,
+                }))
+            }
+        };
+
+        self.create_update_book_usecase
+            .create_update_book(user_id, book, None)
+            .await
+    }
+}
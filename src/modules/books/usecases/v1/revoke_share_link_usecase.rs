@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::infra::repositories::{
+        share_link_repository::ShareLinkRepository,
+        share_link_repository_mysql::ShareLinkRepositoryMySQL,
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct RevokeShareLinkUseCaseV1<T, U>
+where
+    T: ShareLinkRepository,
+    U: LibraryRepository,
+{
+    share_link_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+}
+
+impl RevokeShareLinkUseCaseV1<ShareLinkRepositoryMySQL, LibraryRepositoryMySQL> {
+    pub fn new(
+        share_link_repository: ShareLinkRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
+        Self {
+            share_link_repository: Arc::new(share_link_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn revoke_share_link(
+        &self,
+        share_link_id: u64,
+        authed_user_id: u64,
+    ) -> Result<(), APIError> {
+        let share_link = match self.share_link_repository.find_by_id(share_link_id).await {
+            Ok(found_share_link_option) => match found_share_link_option {
+                None => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "Share link not found".to_string(),
+                        404,
+                    )));
+                }
+                Some(found_share_link) => found_share_link,
+            },
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        self.authorize_library_member_usecase
+            .authorize(
+                authed_user_id,
+                Some(share_link.library_id),
+                LibraryRole::Editor,
+            )
+            .await?;
+
+        match self.share_link_repository.revoke_by_id(share_link_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
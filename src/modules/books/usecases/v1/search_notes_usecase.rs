@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::{note_dto::NoteDto, search_notes_params::SearchNotesParams},
+            entities::note::NoteType,
+        },
+        infra::repositories::{
+            note_repository::NoteRepository, note_repository_mysql::NoteRepositoryMySQL,
+        },
+    },
+    shared::{
+        domain::dtos::paginated_dto::PaginatedDto,
+        errors::{simple_api_error::SimpleAPIError, APIError},
+    },
+};
+
+pub struct SearchNotesUseCaseV1<T>
+where
+    T: NoteRepository,
+{
+    note_repository: Arc<T>,
+}
+
+impl SearchNotesUseCaseV1<NoteRepositoryMySQL> {
+    pub fn new(note_repository: NoteRepositoryMySQL) -> Self {
+        Self {
+            note_repository: Arc::new(note_repository),
+        }
+    }
+
+    pub async fn search_notes(
+        &self,
+        user_id: u64,
+        params: SearchNotesParams,
+    ) -> Result<PaginatedDto<NoteDto>, APIError> {
+        let converted_page: u64 = match params.page {
+            Some(page) if page < 1 => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: "Requested page must have a value greater than one".to_string(),
+                    code: 400,
+                }));
+            }
+            Some(page) => u64::from_ne_bytes(page.to_ne_bytes()),
+            None => 1,
+        };
+        let converted_page_size: u64 = match params.page_size {
+            Some(page_size) if page_size < 1 => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: "Requested page size must have a value greater than one".to_string(),
+                    code: 400,
+                }));
+            }
+            Some(page_size) => u64::from_ne_bytes(page_size.to_ne_bytes()),
+            None => 10,
+        };
+        let note_type = match params.note_type.as_deref().map(NoteType::try_from) {
+            Some(Ok(note_type)) => Some(note_type),
+            Some(Err(error)) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(error, 400)));
+            }
+            None => None,
+        };
+        let query = params
+            .query
+            .map(|query| query.trim().to_string())
+            .filter(|query| !query.is_empty());
+
+        match self
+            .note_repository
+            .search_by_user_id(
+                user_id,
+                converted_page,
+                converted_page_size,
+                query,
+                note_type.as_ref().map(NoteType::as_str),
+            )
+            .await
+        {
+            Ok(found_notes) => Ok(found_notes),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
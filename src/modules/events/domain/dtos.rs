@@ -0,0 +1 @@
+pub mod change_event_dto;
@@ -0,0 +1,17 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct ChangeEventDto {
+    pub entity_type: String,
+    pub entity_id: u64,
+    pub library_id: u64,
+    pub actor_id: u64,
+    pub action: String,
+    #[schema(value_type = Object)]
+    pub changes: Map<String, Value>,
+    #[schema(value_type = Option<Object>)]
+    pub snapshot: Option<Value>,
+    pub occurred_at: String,
+}
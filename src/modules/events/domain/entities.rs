@@ -0,0 +1,2 @@
+pub mod change_event;
+pub mod change_stream_message;
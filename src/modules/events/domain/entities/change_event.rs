@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+
+use crate::modules::books::domain::entities::audit_entry::{AuditAction, AuditEntityType};
+
+/// Change made to a book, collection or location, pushed to the clients
+/// following its library. `changes` and `snapshot` hold the audited fields
+/// only, clients needing the rest of the entity fetch it again.
+#[derive(Debug, Clone)]
+pub struct ChangeEvent {
+    pub entity_type: AuditEntityType,
+    pub entity_id: u64,
+    pub library_id: u64,
+    pub actor_id: u64,
+    pub action: AuditAction,
+    pub changes: Map<String, Value>,
+    pub snapshot: Option<Value>,
+    pub occurred_at: DateTime<Utc>,
+}
@@ -0,0 +1,10 @@
+use super::change_event::ChangeEvent;
+
+#[derive(Debug, Clone)]
+pub enum ChangeStreamMessage {
+    Change(ChangeEvent),
+    /// Events were dropped because the client couldn't keep up, it should
+    /// reload what it shows.
+    Resync,
+    KeepAlive,
+}
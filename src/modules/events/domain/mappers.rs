@@ -0,0 +1,2 @@
+pub mod change_event_dto_mapper;
+pub mod change_event_mapper;
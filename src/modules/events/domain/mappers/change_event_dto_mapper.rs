@@ -0,0 +1,18 @@
+use crate::modules::events::domain::{
+    dtos::change_event_dto::ChangeEventDto, entities::change_event::ChangeEvent,
+};
+
+impl From<ChangeEvent> for ChangeEventDto {
+    fn from(event: ChangeEvent) -> Self {
+        ChangeEventDto {
+            entity_type: event.entity_type.as_str().to_string(),
+            entity_id: event.entity_id,
+            library_id: event.library_id,
+            actor_id: event.actor_id,
+            action: event.action.as_str().to_string(),
+            changes: event.changes,
+            snapshot: event.snapshot,
+            occurred_at: event.occurred_at.to_rfc3339(),
+        }
+    }
+}
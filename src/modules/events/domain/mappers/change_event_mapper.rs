@@ -0,0 +1,21 @@
+use chrono::Utc;
+
+use crate::modules::{
+    books::domain::entities::audit_entry::AuditEntry,
+    events::domain::entities::change_event::ChangeEvent,
+};
+
+impl From<&AuditEntry> for ChangeEvent {
+    fn from(entry: &AuditEntry) -> Self {
+        ChangeEvent {
+            entity_type: entry.entity_type,
+            entity_id: entry.entity_id,
+            library_id: entry.library_id,
+            actor_id: entry.actor_id,
+            action: entry.action,
+            changes: entry.changes.clone(),
+            snapshot: entry.snapshot.clone(),
+            occurred_at: entry.created_at.unwrap_or_else(Utc::now),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod buses;
+pub mod controllers;
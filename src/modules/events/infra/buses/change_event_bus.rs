@@ -0,0 +1,31 @@
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+use crate::modules::events::domain::entities::change_event::ChangeEvent;
+
+const CHANGE_EVENT_BUS_CAPACITY: usize = 1024;
+
+#[derive(Clone)]
+pub struct ChangeEventBus {
+    sender: Sender<ChangeEvent>,
+}
+
+impl ChangeEventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANGE_EVENT_BUS_CAPACITY);
+        ChangeEventBus { sender }
+    }
+
+    pub fn publish(&self, event: ChangeEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    pub fn subscribe(&self) -> Receiver<ChangeEvent> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for ChangeEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
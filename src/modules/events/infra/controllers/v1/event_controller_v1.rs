@@ -0,0 +1,103 @@
+use crate::modules::{
+    events::{
+        domain::{
+            dtos::change_event_dto::ChangeEventDto,
+            entities::change_stream_message::ChangeStreamMessage,
+        },
+        infra::buses::change_event_bus::ChangeEventBus,
+        usecases::v1::stream_change_events_usecase::StreamChangeEventsUseCaseV1,
+    },
+    libraries::{
+        domain::dtos::library_scope_params::LibraryScopeParams,
+        infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::domain::dtos::authed_user::AuthedUser,
+};
+use actix_web::{get, http::header, web, HttpResponse, Scope};
+use futures_util::StreamExt;
+use utoipa::OpenApi;
+
+pub struct EventControllerV1 {
+    stream_change_events_usecase: StreamChangeEventsUseCaseV1<LibraryRepositoryMySQL>,
+}
+
+impl EventControllerV1 {
+    pub fn new(
+        library_repository: LibraryRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        EventControllerV1 {
+            stream_change_events_usecase: StreamChangeEventsUseCaseV1::new(
+                library_repository.clone(),
+                change_event_bus.clone(),
+            ),
+        }
+    }
+}
+
+fn server_sent_event(message: ChangeStreamMessage) -> web::Bytes {
+    let frame = match message {
+        ChangeStreamMessage::Change(event) => format!(
+            "event: change\ndata: {}\n\n",
+            serde_json::to_string(&ChangeEventDto::from(event)).unwrap_or_default()
+        ),
+        ChangeStreamMessage::Resync => "event: resync\ndata: {}\n\n".to_string(),
+        ChangeStreamMessage::KeepAlive => ": keep-alive\n\n".to_string(),
+    };
+    web::Bytes::from(frame)
+}
+
+#[utoipa::path(
+    tag = "events",
+    summary = "Follow the changes made to a library",
+    description = "Server-Sent Events stream. Every created, updated, deleted, restored or purged book, collection and location is sent as a `change` event. A `resync` event means events were missed and the client should reload its data.",
+    params(
+        LibraryScopeParams,
+    ),
+    responses(
+        (status = 200, description = "Stream of change events", content(
+            (ChangeEventDto = "text/event-stream"),
+        )),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 404, description = "Library not found", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn stream_change_events(
+    event_controller: web::Data<EventControllerV1>,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let change_stream = match event_controller
+        .stream_change_events_usecase
+        .stream_change_events(authed_user.id.unwrap(), library_scope_params.library_id)
+        .await
+    {
+        Ok(change_stream) => {
+            change_stream.map(|message| Ok::<_, actix_web::Error>(server_sent_event(message)))
+        }
+        Err(error) => return HttpResponse::from(error),
+    };
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .insert_header(("X-Accel-Buffering", "no"))
+        .streaming(change_stream)
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(stream_change_events))]
+pub struct EventApiV1;
+
+pub fn get_event_scope() -> Scope {
+    web::scope("/v1/events").service(stream_change_events)
+}
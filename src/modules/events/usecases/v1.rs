@@ -0,0 +1 @@
+pub mod stream_change_events_usecase;
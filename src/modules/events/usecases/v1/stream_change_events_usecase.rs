@@ -0,0 +1,92 @@
+use std::{sync::Arc, time::Duration};
+
+use async_stream::stream;
+use futures_util::Stream;
+use tokio::{sync::broadcast::error::RecvError, time::Instant};
+
+use crate::modules::{
+    events::{
+        domain::entities::change_stream_message::ChangeStreamMessage,
+        infra::buses::change_event_bus::ChangeEventBus,
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::APIError,
+};
+
+/// Longest silence on a stream, proxies tend to close idle connections.
+/// Membership is checked again at the same pace.
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+pub struct StreamChangeEventsUseCaseV1<T>
+where
+    T: LibraryRepository,
+{
+    library_repository: Arc<T>,
+    change_event_bus: ChangeEventBus,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<T>,
+}
+
+impl StreamChangeEventsUseCaseV1<LibraryRepositoryMySQL> {
+    pub fn new(
+        library_repository: LibraryRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        Self {
+            library_repository: Arc::new(library_repository.clone()),
+            change_event_bus,
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn stream_change_events(
+        &self,
+        user_id: u64,
+        library_id: Option<u64>,
+    ) -> Result<impl Stream<Item = ChangeStreamMessage>, APIError> {
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(user_id, library_id, LibraryRole::Viewer)
+            .await?;
+
+        let library_repository = self.library_repository.clone();
+        let mut receiver = self.change_event_bus.subscribe();
+        let mut keep_alive =
+            tokio::time::interval_at(Instant::now() + KEEP_ALIVE_INTERVAL, KEEP_ALIVE_INTERVAL);
+
+        Ok(stream! {
+            loop {
+                let message = tokio::select! {
+                    received = receiver.recv() => match received {
+                        Ok(event) if event.library_id == library_id => {
+                            Some(ChangeStreamMessage::Change(event))
+                        }
+                        Ok(_) => None,
+                        Err(RecvError::Lagged(_)) => Some(ChangeStreamMessage::Resync),
+                        Err(RecvError::Closed) => break,
+                    },
+                    _ = keep_alive.tick() => {
+                        match library_repository.find_member_role(library_id, user_id).await {
+                            Ok(None) => break,
+                            Ok(Some(_)) => Some(ChangeStreamMessage::KeepAlive),
+                            Err(error) => {
+                                tracing::error!("Failed to check the change stream membership: {}", error);
+                                Some(ChangeStreamMessage::KeepAlive)
+                            }
+                        }
+                    }
+                };
+                if let Some(message) = message {
+                    yield message;
+                }
+            }
+        })
+    }
+}
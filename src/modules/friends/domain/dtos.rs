@@ -0,0 +1,7 @@
+pub mod block_user_dto;
+pub mod create_friend_request_dto;
+pub mod created_friendship_dto;
+pub mod find_all_friend_libraries_dto;
+pub mod find_all_friendships_dto;
+pub mod friend_library_dto;
+pub mod friendship_dto;
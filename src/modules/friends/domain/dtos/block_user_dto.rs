@@ -0,0 +1,7 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct BlockUserDto {
+    pub user_id: Option<u64>,
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct CreateFriendRequestDto {
+    pub email: Option<String>,
+    pub requester_id: Option<u64>,
+}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct CreatedFriendshipDto {
+    pub id: u64,
+    pub requester_id: u64,
+    pub addressee_id: u64,
+    pub status: String,
+    pub created_at: Option<String>,
+}
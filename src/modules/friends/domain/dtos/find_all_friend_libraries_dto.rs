@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::friend_library_dto::FriendLibraryDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FindAllFriendLibrariesDto {
+    pub libraries: Vec<FriendLibraryDto>,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::friendship_dto::FriendshipDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FindAllFriendshipsDto {
+    pub friendships: Vec<FriendshipDto>,
+}
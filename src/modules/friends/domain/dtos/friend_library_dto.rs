@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FriendLibraryDto {
+    pub id: u64,
+    pub name: String,
+    pub owner_id: u64,
+}
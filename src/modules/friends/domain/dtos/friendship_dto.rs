@@ -0,0 +1,14 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Friendship as seen by one of its users, `user_id` and `name` refer to
+/// the other side and `direction` tells who sent the request.
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FriendshipDto {
+    pub id: u64,
+    pub user_id: u64,
+    pub name: String,
+    pub status: String,
+    pub direction: String,
+    pub created_at: Option<String>,
+}
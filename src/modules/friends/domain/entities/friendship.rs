@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FriendshipStatus {
+    Pending,
+    Accepted,
+    Blocked,
+}
+
+impl FriendshipStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FriendshipStatus::Pending => "pending",
+            FriendshipStatus::Accepted => "accepted",
+            FriendshipStatus::Blocked => "blocked",
+        }
+    }
+}
+
+impl TryFrom<&str> for FriendshipStatus {
+    type Error = String;
+
+    fn try_from(status: &str) -> Result<Self, Self::Error> {
+        match status {
+            "pending" => Ok(FriendshipStatus::Pending),
+            "accepted" => Ok(FriendshipStatus::Accepted),
+            "blocked" => Ok(FriendshipStatus::Blocked),
+            _ => Err(format!("Unknown friendship status {}", status)),
+        }
+    }
+}
+
+/// Relationship between two users. The requester is whoever sent the friend
+/// request or, for blocked relationships, whoever blocked the other user.
+#[derive(Debug)]
+pub struct Friendship {
+    pub id: Option<u64>,
+    pub requester_id: u64,
+    pub addressee_id: u64,
+    pub status: FriendshipStatus,
+    pub created_at: Option<DateTime<Utc>>,
+    pub responded_at: Option<DateTime<Utc>>,
+}
+
+impl Friendship {
+    pub fn involves(&self, user_id: u64) -> bool {
+        self.requester_id == user_id || self.addressee_id == user_id
+    }
+}
+
+#[derive(Debug)]
+pub struct FriendRequest {
+    pub requester_id: u64,
+    pub email: String,
+}
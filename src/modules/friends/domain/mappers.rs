@@ -0,0 +1,3 @@
+pub mod create_friend_request_dto_mapper;
+pub mod created_friendship_dto_mapper;
+pub mod friend_library_dto_mapper;
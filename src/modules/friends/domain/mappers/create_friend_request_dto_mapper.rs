@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    friends::domain::{
+        dtos::create_friend_request_dto::CreateFriendRequestDto,
+        entities::friendship::FriendRequest,
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<CreateFriendRequestDto> for FriendRequest {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: CreateFriendRequestDto) -> Result<Self, Self::Error> {
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        let email = match dto.email.map(|email| email.trim().to_lowercase()) {
+            Some(email) if email.contains('@') => email,
+            Some(_) => {
+                validations.insert(
+                    "email".to_string(),
+                    "E-mail must be a valid address".to_string(),
+                );
+                String::new()
+            }
+            None => {
+                validations.insert("email".to_string(), "E-mail must be informed".to_string());
+                String::new()
+            }
+        };
+
+        if dto.requester_id.is_none() {
+            validations.insert(
+                "requester_id".to_string(),
+                "Friend request must be sent by an user".to_string(),
+            );
+        }
+
+        if !validations.is_empty() {
+            return Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            });
+        }
+        Ok(FriendRequest {
+            requester_id: dto.requester_id.unwrap_or_default(),
+            email,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emails_are_normalized() {
+        let friend_request = FriendRequest::try_from(CreateFriendRequestDto {
+            email: Some(" Reader@Example.com ".to_string()),
+            requester_id: Some(1),
+        })
+        .unwrap();
+
+        assert_eq!("reader@example.com", friend_request.email);
+        assert_eq!(1, friend_request.requester_id);
+    }
+
+    #[test]
+    fn requests_need_an_email_and_a_requester() {
+        for email in [None, Some("reader".to_string())] {
+            let error = FriendRequest::try_from(CreateFriendRequestDto {
+                email,
+                requester_id: None,
+            })
+            .unwrap_err();
+
+            let validations = error.field_validations.unwrap();
+            assert!(validations.contains_key("email"));
+            assert!(validations.contains_key("requester_id"));
+        }
+    }
+}
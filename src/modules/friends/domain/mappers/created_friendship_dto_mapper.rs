@@ -0,0 +1,15 @@
+use crate::modules::friends::domain::{
+    dtos::created_friendship_dto::CreatedFriendshipDto, entities::friendship::Friendship,
+};
+
+impl From<Friendship> for CreatedFriendshipDto {
+    fn from(friendship: Friendship) -> Self {
+        CreatedFriendshipDto {
+            id: friendship.id.unwrap_or_default(),
+            requester_id: friendship.requester_id,
+            addressee_id: friendship.addressee_id,
+            status: friendship.status.as_str().to_string(),
+            created_at: friendship.created_at.map(|date| date.to_rfc3339()),
+        }
+    }
+}
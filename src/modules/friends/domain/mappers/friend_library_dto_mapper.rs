@@ -0,0 +1,14 @@
+use crate::modules::{
+    friends::domain::dtos::friend_library_dto::FriendLibraryDto,
+    libraries::domain::entities::library::Library,
+};
+
+impl From<Library> for FriendLibraryDto {
+    fn from(library: Library) -> Self {
+        FriendLibraryDto {
+            id: library.id.unwrap_or_default(),
+            name: library.name,
+            owner_id: library.owner_id,
+        }
+    }
+}
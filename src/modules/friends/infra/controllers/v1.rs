@@ -0,0 +1 @@
+pub mod friend_controller_v1;
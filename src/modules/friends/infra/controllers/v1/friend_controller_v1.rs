@@ -0,0 +1,397 @@
+use crate::modules::{
+    books::{
+        domain::dtos::{
+            complete_book_dto::CompleteBookDto, get_all_books_params::GetAllBooksParams,
+        },
+        infra::repositories::book_repository_mysql::BookRepositoryMySQL,
+    },
+    friends::{
+        domain::{
+            dtos::{
+                block_user_dto::BlockUserDto, create_friend_request_dto::CreateFriendRequestDto,
+                created_friendship_dto::CreatedFriendshipDto,
+                find_all_friend_libraries_dto::FindAllFriendLibrariesDto,
+                find_all_friendships_dto::FindAllFriendshipsDto,
+            },
+            entities::friendship::FriendRequest,
+        },
+        infra::repositories::friendship_repository_mysql::FriendshipRepositoryMySQL,
+        usecases::v1::{
+            block_user_usecase::BlockUserUseCaseV1,
+            find_all_friend_libraries_usecase::FindAllFriendLibrariesUseCaseV1,
+            find_all_friend_library_books_usecase::FindAllFriendLibraryBooksUseCaseV1,
+            find_all_friendships_from_user_usecase::FindAllFriendshipsFromUserUseCaseV1,
+            respond_friend_request_usecase::RespondFriendRequestUseCaseV1,
+            send_friend_request_usecase::SendFriendRequestUseCaseV1,
+        },
+    },
+    libraries::infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    shared::{
+        domain::dtos::paginated_dto::PaginatedDto,
+        errors::{
+            detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+        },
+    },
+    users::{
+        domain::dtos::authed_user::AuthedUser,
+        infra::repositories::user_repository_mysql::UserRepositoryMySQL,
+    },
+};
+use actix_web::{delete, get, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct FriendControllerV1 {
+    find_all_friendships_from_user_usecase:
+        FindAllFriendshipsFromUserUseCaseV1<FriendshipRepositoryMySQL>,
+    send_friend_request_usecase:
+        SendFriendRequestUseCaseV1<FriendshipRepositoryMySQL, UserRepositoryMySQL>,
+    respond_friend_request_usecase: RespondFriendRequestUseCaseV1<FriendshipRepositoryMySQL>,
+    block_user_usecase: BlockUserUseCaseV1<FriendshipRepositoryMySQL, UserRepositoryMySQL>,
+    find_all_friend_libraries_usecase:
+        FindAllFriendLibrariesUseCaseV1<FriendshipRepositoryMySQL, LibraryRepositoryMySQL>,
+    find_all_friend_library_books_usecase: FindAllFriendLibraryBooksUseCaseV1<
+        FriendshipRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        BookRepositoryMySQL,
+    >,
+}
+
+impl FriendControllerV1 {
+    pub fn new(
+        friendship_repository: FriendshipRepositoryMySQL,
+        user_repository: UserRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+    ) -> Self {
+        FriendControllerV1 {
+            find_all_friendships_from_user_usecase: FindAllFriendshipsFromUserUseCaseV1::new(
+                friendship_repository.clone(),
+            ),
+            send_friend_request_usecase: SendFriendRequestUseCaseV1::new(
+                friendship_repository.clone(),
+                user_repository.clone(),
+            ),
+            respond_friend_request_usecase: RespondFriendRequestUseCaseV1::new(
+                friendship_repository.clone(),
+            ),
+            block_user_usecase: BlockUserUseCaseV1::new(
+                friendship_repository.clone(),
+                user_repository.clone(),
+            ),
+            find_all_friend_libraries_usecase: FindAllFriendLibrariesUseCaseV1::new(
+                friendship_repository.clone(),
+                library_repository.clone(),
+            ),
+            find_all_friend_library_books_usecase: FindAllFriendLibraryBooksUseCaseV1::new(
+                friendship_repository.clone(),
+                library_repository.clone(),
+                book_repository.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "friends",
+    summary = "List the user's friendships, received requests and blocks",
+    responses(
+        (status = 200, description = "Friendships", body = FindAllFriendshipsDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn get_all_friendships_from_user(
+    friend_controller: web::Data<FriendControllerV1>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match friend_controller
+        .find_all_friendships_from_user_usecase
+        .find_all_friendships_from_user(authed_user.id.unwrap())
+        .await
+    {
+        Ok(friendships) => {
+            HttpResponse::Ok().json(web::Json(FindAllFriendshipsDto { friendships }))
+        }
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "friends",
+    summary = "Send a friend request",
+    request_body = CreateFriendRequestDto,
+    responses(
+        (status = 202, description = "Friend request sent, unless the e-mail doesn't belong to an account"),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 409, description = "Users are already related", body = SimpleAPIError),
+        (status = 422, description = "Users can't befriend themselves", body = SimpleAPIError),
+    ),
+)]
+#[post("/requests")]
+async fn send_friend_request(
+    friend_controller: web::Data<FriendControllerV1>,
+    create_friend_request_dto: web::Json<CreateFriendRequestDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let mut dto = create_friend_request_dto.into_inner();
+    dto.requester_id = authed_user.id;
+    let friend_request = match FriendRequest::try_from(dto) {
+        Ok(converted_friend_request) => converted_friend_request,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match friend_controller
+        .send_friend_request_usecase
+        .send_friend_request(friend_request)
+        .await
+    {
+        Ok(_) => HttpResponse::Accepted().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "friends",
+    summary = "Accept a friend request",
+    params(
+        ("friendship_id" = u64, Path, description = "Id of the friendship"),
+    ),
+    responses(
+        (status = 200, description = "Friend request accepted"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Friend request not found", body = SimpleAPIError),
+    ),
+)]
+#[post("/requests/{friendship_id}/accept")]
+async fn accept_friend_request(
+    friend_controller: web::Data<FriendControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match friend_controller
+        .respond_friend_request_usecase
+        .accept_friend_request(path_variables.into_inner(), authed_user.id.unwrap())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "friends",
+    summary = "Block a user",
+    request_body = BlockUserDto,
+    responses(
+        (status = 201, description = "User blocked", body = CreatedFriendshipDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 404, description = "User not found", body = SimpleAPIError),
+    ),
+)]
+#[post("/blocks")]
+async fn block_user(
+    friend_controller: web::Data<FriendControllerV1>,
+    block_user_dto: web::Json<BlockUserDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let blocked_user_id = match block_user_dto.user_id {
+        Some(user_id) => user_id,
+        None => {
+            return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+                "The user to be blocked must be informed".to_string(),
+                400,
+            )));
+        }
+    };
+
+    match friend_controller
+        .block_user_usecase
+        .block_user(blocked_user_id, authed_user.id.unwrap())
+        .await
+    {
+        Ok(friendship) => {
+            HttpResponse::Created().json(web::Json(CreatedFriendshipDto::from(friendship)))
+        }
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "friends",
+    summary = "Remove a friendship, request or block",
+    params(
+        ("friendship_id" = u64, Path, description = "Id of the friendship"),
+    ),
+    responses(
+        (status = 200, description = "Friendship removed"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Friendship not found", body = SimpleAPIError),
+    ),
+)]
+#[delete("/{friendship_id}")]
+async fn remove_friendship(
+    friend_controller: web::Data<FriendControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match friend_controller
+        .respond_friend_request_usecase
+        .remove_friendship(path_variables.into_inner(), authed_user.id.unwrap())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "friends",
+    summary = "List the libraries a friend shares with friends",
+    params(
+        ("friend_id" = u64, Path, description = "Id of the friend"),
+    ),
+    responses(
+        (status = 200, description = "Friend libraries", body = FindAllFriendLibrariesDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[get("/users/{friend_id}/libraries")]
+async fn get_all_friend_libraries(
+    friend_controller: web::Data<FriendControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match friend_controller
+        .find_all_friend_libraries_usecase
+        .find_all_friend_libraries(path_variables.into_inner(), authed_user.id.unwrap())
+        .await
+    {
+        Ok(libraries) => {
+            HttpResponse::Ok().json(web::Json(FindAllFriendLibrariesDto { libraries }))
+        }
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "friends",
+    summary = "List the books of a friend's library",
+    params(
+        ("friend_id" = u64, Path, description = "Id of the friend"),
+        ("library_id" = u64, Path, description = "Id of the library"),
+        GetAllBooksParams,
+    ),
+    responses(
+        (status = 200, description = "Page of books", body = PaginatedDto<CompleteBookDto>),
+        (status = 400, description = "Invalid parameters", body = SimpleAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Library not found", body = SimpleAPIError),
+    ),
+)]
+#[get("/users/{friend_id}/libraries/{library_id}/books")]
+async fn get_all_friend_library_books(
+    friend_controller: web::Data<FriendControllerV1>,
+    path_variables: web::Path<(u64, u64)>,
+    params: web::Query<GetAllBooksParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    let (friend_id, library_id) = path_variables.into_inner();
+
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match friend_controller
+        .find_all_friend_library_books_usecase
+        .find_all_friend_library_books(
+            friend_id,
+            library_id,
+            authed_user.id.unwrap(),
+            params.page,
+            params.page_size,
+            params.collection_id,
+            params.location_id,
+            params.query.clone(),
+        )
+        .await
+    {
+        Ok(books_page) => HttpResponse::Ok().json(web::Json(books_page)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_all_friendships_from_user,
+    send_friend_request,
+    accept_friend_request,
+    block_user,
+    remove_friendship,
+    get_all_friend_libraries,
+    get_all_friend_library_books
+))]
+pub struct FriendApiV1;
+
+pub fn get_friend_scope() -> Scope {
+    web::scope("/v1/friends")
+        .service(get_all_friendships_from_user)
+        .service(send_friend_request)
+        .service(accept_friend_request)
+        .service(block_user)
+        .service(remove_friendship)
+        .service(get_all_friend_libraries)
+        .service(get_all_friend_library_books)
+}
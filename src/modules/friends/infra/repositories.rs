@@ -0,0 +1,2 @@
+pub mod friendship_repository;
+pub mod friendship_repository_mysql;
@@ -0,0 +1,33 @@
+use crate::modules::friends::domain::{
+    dtos::friendship_dto::FriendshipDto, entities::friendship::Friendship,
+};
+use sqlx::Error;
+use std::future::Future;
+
+pub trait FriendshipRepository {
+    fn save(
+        &self,
+        friendship: &Friendship,
+    ) -> impl Future<Output = Result<Option<Friendship>, Error>> + Send;
+    fn find_by_id(&self, id: u64)
+        -> impl Future<Output = Result<Option<Friendship>, Error>> + Send;
+    fn find_between(
+        &self,
+        user_id: u64,
+        other_user_id: u64,
+    ) -> impl Future<Output = Result<Option<Friendship>, Error>> + Send;
+    /// Lists the relationships of an user, leaving out the ones where the
+    /// user was blocked by someone else and the requests the user sent that
+    /// weren't accepted yet, which would tell whether an e-mail has an account.
+    fn find_all_by_user_id(
+        &self,
+        user_id: u64,
+    ) -> impl Future<Output = Result<Vec<FriendshipDto>, Error>> + Send;
+    fn accept(&self, id: u64) -> impl Future<Output = Result<(), Error>> + Send;
+    fn delete_by_id(&self, id: u64) -> impl Future<Output = Result<(), Error>> + Send;
+    fn block(
+        &self,
+        blocker_id: u64,
+        blocked_id: u64,
+    ) -> impl Future<Output = Result<Option<Friendship>, Error>> + Send;
+}
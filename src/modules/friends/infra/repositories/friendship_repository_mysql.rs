@@ -0,0 +1,197 @@
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, MySqlPool, Row};
+use std::sync::Arc;
+
+use crate::modules::friends::domain::{
+    dtos::friendship_dto::FriendshipDto,
+    entities::friendship::{Friendship, FriendshipStatus},
+};
+
+use super::friendship_repository::FriendshipRepository;
+
+#[derive(Clone)]
+pub struct FriendshipRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl FriendshipRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        FriendshipRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn friendship_from_row(row: &MySqlRow) -> Friendship {
+    Friendship {
+        id: Some(row.get("id")),
+        requester_id: row.get("requester_id"),
+        addressee_id: row.get("addressee_id"),
+        status: FriendshipStatus::try_from(row.get::<String, &str>("status").as_str())
+            .unwrap_or(FriendshipStatus::Pending),
+        created_at: Some(row.get::<DateTime<Utc>, &str>("created_at")),
+        responded_at: row.get::<Option<DateTime<Utc>>, &str>("responded_at"),
+    }
+}
+
+impl FriendshipRepository for FriendshipRepositoryMySQL {
+    async fn save(&self, friendship: &Friendship) -> Result<Option<Friendship>, sqlx::Error> {
+        let insert_result = sqlx::query(
+            r#"
+            INSERT INTO friendships (id, requester_id, addressee_id, status)
+            VALUES (DEFAULT, ?, ?, ?)
+            "#,
+        )
+        .bind(friendship.requester_id)
+        .bind(friendship.addressee_id)
+        .bind(friendship.status.as_str())
+        .execute(self.connection.as_ref())
+        .await;
+        match insert_result {
+            Ok(result) => {
+                let new_friendship_id = result.last_insert_id();
+                tracing::info!("Generated friendship ID: {}", new_friendship_id);
+                self.find_by_id(new_friendship_id).await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn find_by_id(&self, id: u64) -> Result<Option<Friendship>, sqlx::Error> {
+        let query_result = sqlx::query("SELECT * FROM friendships f WHERE f.id = ?")
+            .bind(id)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(friendship_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_between(
+        &self,
+        user_id: u64,
+        other_user_id: u64,
+    ) -> Result<Option<Friendship>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT * FROM friendships f
+            WHERE (f.requester_id = ? AND f.addressee_id = ?)
+                OR (f.requester_id = ? AND f.addressee_id = ?)
+            LIMIT 1
+            "#,
+        )
+        .bind(user_id)
+        .bind(other_user_id)
+        .bind(other_user_id)
+        .bind(user_id)
+        .fetch_optional(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(friendship_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_user_id(&self, user_id: u64) -> Result<Vec<FriendshipDto>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT f.id, f.status, f.created_at, u.id 'user_id', u.name,
+                f.requester_id = ? 'outgoing'
+            FROM friendships f
+            INNER JOIN users u
+                ON u.id = IF(f.requester_id = ?, f.addressee_id, f.requester_id)
+            WHERE (f.requester_id = ? OR f.addressee_id = ?)
+                AND NOT (f.status = 'blocked' AND f.addressee_id = ?)
+                AND NOT (f.status = 'pending' AND f.requester_id = ?)
+            ORDER BY f.status, u.name
+            "#,
+        )
+        .bind(user_id)
+        .bind(user_id)
+        .bind(user_id)
+        .bind(user_id)
+        .bind(user_id)
+        .bind(user_id)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows
+                .iter()
+                .map(|row| FriendshipDto {
+                    id: row.get("id"),
+                    user_id: row.get("user_id"),
+                    name: row.get("name"),
+                    status: row.get("status"),
+                    direction: if row.get::<bool, &str>("outgoing") {
+                        "outgoing".to_string()
+                    } else {
+                        "incoming".to_string()
+                    },
+                    created_at: Some(row.get::<DateTime<Utc>, &str>("created_at").to_rfc3339()),
+                })
+                .collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn accept(&self, id: u64) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query(
+            "UPDATE friendships SET status = ?, responded_at = CURRENT_TIMESTAMP WHERE id = ?",
+        )
+        .bind(FriendshipStatus::Accepted.as_str())
+        .bind(id)
+        .execute(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn delete_by_id(&self, id: u64) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query("DELETE FROM friendships WHERE id = ?")
+            .bind(id)
+            .execute(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn block(
+        &self,
+        blocker_id: u64,
+        blocked_id: u64,
+    ) -> Result<Option<Friendship>, sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+        sqlx::query(
+            r#"
+            DELETE FROM friendships
+            WHERE (requester_id = ? AND addressee_id = ?)
+                OR (requester_id = ? AND addressee_id = ?)
+            "#,
+        )
+        .bind(blocker_id)
+        .bind(blocked_id)
+        .bind(blocked_id)
+        .bind(blocker_id)
+        .execute(&mut *transaction)
+        .await?;
+        let insert_result = sqlx::query(
+            r#"
+            INSERT INTO friendships (id, requester_id, addressee_id, status)
+            VALUES (DEFAULT, ?, ?, ?)
+            "#,
+        )
+        .bind(blocker_id)
+        .bind(blocked_id)
+        .bind(FriendshipStatus::Blocked.as_str())
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+
+        self.find_by_id(insert_result.last_insert_id()).await
+    }
+}
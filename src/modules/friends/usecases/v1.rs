@@ -0,0 +1,7 @@
+pub mod authorize_friend_usecase;
+pub mod block_user_usecase;
+pub mod find_all_friend_libraries_usecase;
+pub mod find_all_friend_library_books_usecase;
+pub mod find_all_friendships_from_user_usecase;
+pub mod respond_friend_request_usecase;
+pub mod send_friend_request_usecase;
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    friends::{
+        domain::entities::friendship::FriendshipStatus,
+        infra::repositories::{
+            friendship_repository::FriendshipRepository,
+            friendship_repository_mysql::FriendshipRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct AuthorizeFriendUseCaseV1<T>
+where
+    T: FriendshipRepository,
+{
+    friendship_repository: Arc<T>,
+}
+
+impl AuthorizeFriendUseCaseV1<FriendshipRepositoryMySQL> {
+    pub fn new(friendship_repository: FriendshipRepositoryMySQL) -> Self {
+        Self {
+            friendship_repository: Arc::new(friendship_repository),
+        }
+    }
+
+    pub async fn authorize(&self, user_id: u64, friend_id: u64) -> Result<(), APIError> {
+        match self
+            .friendship_repository
+            .find_between(user_id, friend_id)
+            .await
+        {
+            Ok(Some(friendship)) if friendship.status == FriendshipStatus::Accepted => Ok(()),
+            Ok(_) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Friend not found".to_string(),
+                404,
+            ))),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    friends::{
+        domain::entities::friendship::{Friendship, FriendshipStatus},
+        infra::repositories::{
+            friendship_repository::FriendshipRepository,
+            friendship_repository_mysql::FriendshipRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::infra::repositories::{
+        user_repository::UserRepository, user_repository_mysql::UserRepositoryMySQL,
+    },
+};
+
+pub struct BlockUserUseCaseV1<T, U>
+where
+    T: FriendshipRepository,
+    U: UserRepository,
+{
+    friendship_repository: Arc<T>,
+    user_repository: Arc<U>,
+}
+
+impl BlockUserUseCaseV1<FriendshipRepositoryMySQL, UserRepositoryMySQL> {
+    pub fn new(
+        friendship_repository: FriendshipRepositoryMySQL,
+        user_repository: UserRepositoryMySQL,
+    ) -> Self {
+        Self {
+            friendship_repository: Arc::new(friendship_repository),
+            user_repository: Arc::new(user_repository),
+        }
+    }
+
+    pub async fn block_user(
+        &self,
+        blocked_user_id: u64,
+        authed_user_id: u64,
+    ) -> Result<Friendship, APIError> {
+        if blocked_user_id == authed_user_id {
+            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Users can't block themselves".to_string(),
+                422,
+            )));
+        }
+        match self.user_repository.find_by_id(blocked_user_id).await {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "User not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self
+            .friendship_repository
+            .find_between(authed_user_id, blocked_user_id)
+            .await
+        {
+            Ok(Some(friendship))
+                if friendship.status == FriendshipStatus::Blocked
+                    && friendship.requester_id == authed_user_id =>
+            {
+                return Ok(friendship);
+            }
+            Ok(_) => {}
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self
+            .friendship_repository
+            .block(authed_user_id, blocked_user_id)
+            .await
+        {
+            Ok(Some(friendship)) => Ok(friendship),
+            Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Failed to load block info".to_string(),
+                500,
+            ))),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
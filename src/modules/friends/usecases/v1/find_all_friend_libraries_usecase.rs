@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    friends::{
+        domain::dtos::friend_library_dto::FriendLibraryDto,
+        infra::repositories::{
+            friendship_repository::FriendshipRepository,
+            friendship_repository_mysql::FriendshipRepositoryMySQL,
+        },
+    },
+    libraries::{
+        domain::entities::library_visibility::LibraryVisibility,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+use super::authorize_friend_usecase::AuthorizeFriendUseCaseV1;
+
+pub struct FindAllFriendLibrariesUseCaseV1<T, U>
+where
+    T: FriendshipRepository,
+    U: LibraryRepository,
+{
+    library_repository: Arc<U>,
+    authorize_friend_usecase: AuthorizeFriendUseCaseV1<T>,
+}
+
+impl FindAllFriendLibrariesUseCaseV1<FriendshipRepositoryMySQL, LibraryRepositoryMySQL> {
+    pub fn new(
+        friendship_repository: FriendshipRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
+        Self {
+            library_repository: Arc::new(library_repository),
+            authorize_friend_usecase: AuthorizeFriendUseCaseV1::new(friendship_repository),
+        }
+    }
+
+    pub async fn find_all_friend_libraries(
+        &self,
+        friend_id: u64,
+        authed_user_id: u64,
+    ) -> Result<Vec<FriendLibraryDto>, APIError> {
+        self.authorize_friend_usecase
+            .authorize(authed_user_id, friend_id)
+            .await?;
+
+        match self
+            .library_repository
+            .find_all_by_owner_id_and_visibility(friend_id, LibraryVisibility::Friends)
+            .await
+        {
+            Ok(libraries) => Ok(libraries.into_iter().map(FriendLibraryDto::from).collect()),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::{
+        domain::{
+            dtos::complete_book_dto::CompleteBookDto, entities::book_listing::BookListingOptions,
+        },
+        infra::repositories::{
+            book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+        },
+    },
+    friends::infra::repositories::{
+        friendship_repository::FriendshipRepository,
+        friendship_repository_mysql::FriendshipRepositoryMySQL,
+    },
+    libraries::{
+        domain::entities::library_visibility::LibraryVisibility,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+    },
+    shared::{
+        domain::dtos::paginated_dto::PaginatedDto,
+        errors::{simple_api_error::SimpleAPIError, APIError},
+    },
+};
+
+use super::authorize_friend_usecase::AuthorizeFriendUseCaseV1;
+
+pub struct FindAllFriendLibraryBooksUseCaseV1<T, U, V>
+where
+    T: FriendshipRepository,
+    U: LibraryRepository,
+    V: BookRepository,
+{
+    library_repository: Arc<U>,
+    book_repository: Arc<V>,
+    authorize_friend_usecase: AuthorizeFriendUseCaseV1<T>,
+}
+
+impl
+    FindAllFriendLibraryBooksUseCaseV1<
+        FriendshipRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        BookRepositoryMySQL,
+    >
+{
+    pub fn new(
+        friendship_repository: FriendshipRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+    ) -> Self {
+        Self {
+            library_repository: Arc::new(library_repository),
+            book_repository: Arc::new(book_repository),
+            authorize_friend_usecase: AuthorizeFriendUseCaseV1::new(friendship_repository),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn find_all_friend_library_books(
+        &self,
+        friend_id: u64,
+        library_id: u64,
+        authed_user_id: u64,
+        page: Option<i64>,
+        page_size: Option<i64>,
+        collection_id: Option<i64>,
+        location_id: Option<i64>,
+        query: Option<String>,
+    ) -> Result<PaginatedDto<CompleteBookDto>, APIError> {
+        let converted_page: u64 = match page {
+            Some(page) if page < 1 => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: "Requested page must have a value greater than one".to_string(),
+                    code: 400,
+                }));
+            }
+            Some(page) => u64::from_ne_bytes(page.to_ne_bytes()),
+            None => 1,
+        };
+        let converted_page_size: u64 = match page_size {
+            Some(page_size) if page_size < 1 => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: "Requested page size must have a value greater than one".to_string(),
+                    code: 400,
+                }));
+            }
+            Some(page_size) => u64::from_ne_bytes(page_size.to_ne_bytes()),
+            None => 10,
+        };
+        self.authorize_friend_usecase
+            .authorize(authed_user_id, friend_id)
+            .await?;
+
+        // Private libraries and the ones owned by someone else are reported
+        // as missing
+        match self.library_repository.find_by_id(library_id).await {
+            Ok(Some(library))
+                if library.owner_id == friend_id
+                    && library.visibility == LibraryVisibility::Friends => {}
+            Ok(_) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Library not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self
+            .book_repository
+            .find_all_by_library_id_as_complete_book_dto(
+                library_id,
+                converted_page,
+                converted_page_size,
+                collection_id,
+                location_id,
+                query,
+                &BookListingOptions::default(),
+            )
+            .await
+        {
+            Ok(found_books) => Ok(found_books),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: e.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
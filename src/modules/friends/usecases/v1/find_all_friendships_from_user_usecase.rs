@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    friends::{
+        domain::dtos::friendship_dto::FriendshipDto,
+        infra::repositories::{
+            friendship_repository::FriendshipRepository,
+            friendship_repository_mysql::FriendshipRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct FindAllFriendshipsFromUserUseCaseV1<T>
+where
+    T: FriendshipRepository,
+{
+    friendship_repository: Arc<T>,
+}
+
+impl FindAllFriendshipsFromUserUseCaseV1<FriendshipRepositoryMySQL> {
+    pub fn new(friendship_repository: FriendshipRepositoryMySQL) -> Self {
+        Self {
+            friendship_repository: Arc::new(friendship_repository),
+        }
+    }
+
+    pub async fn find_all_friendships_from_user(
+        &self,
+        user_id: u64,
+    ) -> Result<Vec<FriendshipDto>, APIError> {
+        match self
+            .friendship_repository
+            .find_all_by_user_id(user_id)
+            .await
+        {
+            Ok(friendships) => Ok(friendships),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    friends::{
+        domain::entities::friendship::{Friendship, FriendshipStatus},
+        infra::repositories::{
+            friendship_repository::FriendshipRepository,
+            friendship_repository_mysql::FriendshipRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct RespondFriendRequestUseCaseV1<T>
+where
+    T: FriendshipRepository,
+{
+    friendship_repository: Arc<T>,
+}
+
+impl RespondFriendRequestUseCaseV1<FriendshipRepositoryMySQL> {
+    pub fn new(friendship_repository: FriendshipRepositoryMySQL) -> Self {
+        Self {
+            friendship_repository: Arc::new(friendship_repository),
+        }
+    }
+
+    pub async fn accept_friend_request(
+        &self,
+        friendship_id: u64,
+        authed_user_id: u64,
+    ) -> Result<(), APIError> {
+        let friendship = self.find_friendship(friendship_id, authed_user_id).await?;
+        if friendship.addressee_id != authed_user_id
+            || friendship.status != FriendshipStatus::Pending
+        {
+            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "There is no pending friend request to accept".to_string(),
+                409,
+            )));
+        }
+
+        match self.friendship_repository.accept(friendship_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+
+    pub async fn remove_friendship(
+        &self,
+        friendship_id: u64,
+        authed_user_id: u64,
+    ) -> Result<(), APIError> {
+        let friendship = self.find_friendship(friendship_id, authed_user_id).await?;
+        if friendship.status == FriendshipStatus::Blocked
+            && friendship.requester_id != authed_user_id
+        {
+            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Friendship not found".to_string(),
+                404,
+            )));
+        }
+
+        match self.friendship_repository.delete_by_id(friendship_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+
+    async fn find_friendship(
+        &self,
+        friendship_id: u64,
+        authed_user_id: u64,
+    ) -> Result<Friendship, APIError> {
+        match self.friendship_repository.find_by_id(friendship_id).await {
+            Ok(Some(friendship)) if friendship.involves(authed_user_id) => Ok(friendship),
+            Ok(_) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Friendship not found".to_string(),
+                404,
+            ))),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
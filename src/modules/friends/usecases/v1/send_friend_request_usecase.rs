@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    friends::{
+        domain::entities::friendship::{FriendRequest, Friendship, FriendshipStatus},
+        infra::repositories::{
+            friendship_repository::FriendshipRepository,
+            friendship_repository_mysql::FriendshipRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::infra::repositories::{
+        user_repository::UserRepository, user_repository_mysql::UserRepositoryMySQL,
+    },
+};
+
+pub struct SendFriendRequestUseCaseV1<T, U>
+where
+    T: FriendshipRepository,
+    U: UserRepository,
+{
+    friendship_repository: Arc<T>,
+    user_repository: Arc<U>,
+}
+
+impl SendFriendRequestUseCaseV1<FriendshipRepositoryMySQL, UserRepositoryMySQL> {
+    pub fn new(
+        friendship_repository: FriendshipRepositoryMySQL,
+        user_repository: UserRepositoryMySQL,
+    ) -> Self {
+        Self {
+            friendship_repository: Arc::new(friendship_repository),
+            user_repository: Arc::new(user_repository),
+        }
+    }
+
+    /// Succeeds the same way whether the e-mail belongs to an account or not,
+    /// was already sent a request or blocked the requester, so requests can't
+    /// be used to find out who has an account.
+    pub async fn send_friend_request(&self, friend_request: FriendRequest) -> Result<(), APIError> {
+        let addressee_id = match self
+            .user_repository
+            .find_by_email(&friend_request.email)
+            .await
+        {
+            Ok(Some(user)) => user.id.unwrap_or_default(),
+            Ok(None) => return Ok(()),
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        if addressee_id == friend_request.requester_id {
+            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Users can't send friend requests to themselves".to_string(),
+                422,
+            )));
+        }
+
+        match self
+            .friendship_repository
+            .find_between(friend_request.requester_id, addressee_id)
+            .await
+        {
+            Ok(None) => {}
+            // Requests crossing each other become a friendship right away
+            Ok(Some(friendship))
+                if friendship.status == FriendshipStatus::Pending
+                    && friendship.addressee_id == friend_request.requester_id =>
+            {
+                let friendship_id = friendship.id.unwrap_or_default();
+                return match self.friendship_repository.accept(friendship_id).await {
+                    Ok(_) => Ok(()),
+                    Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        e.to_string(),
+                        500,
+                    ))),
+                };
+            }
+            Ok(Some(friendship)) if friendship.status == FriendshipStatus::Accepted => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Users are already friends".to_string(),
+                    409,
+                )));
+            }
+            Ok(Some(_)) => return Ok(()),
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self
+            .friendship_repository
+            .save(&Friendship {
+                id: None,
+                requester_id: friend_request.requester_id,
+                addressee_id,
+                status: FriendshipStatus::Pending,
+                created_at: None,
+                responded_at: None,
+            })
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
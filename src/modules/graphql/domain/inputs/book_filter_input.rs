@@ -0,0 +1,18 @@
+use async_graphql::InputObject;
+
+#[derive(Debug, Default, InputObject)]
+#[graphql(name = "BookFilter")]
+pub struct BookFilterInput {
+    pub collection_id: Option<i64>,
+    pub location_id: Option<i64>,
+    pub query: Option<String>,
+    pub tag_ids: Option<Vec<u64>>,
+    /// Either `any` or `all` of the tags.
+    pub tag_match: Option<String>,
+    pub custom_field: Option<String>,
+    pub custom_field_value: Option<String>,
+    pub custom_field_min: Option<String>,
+    pub custom_field_max: Option<String>,
+    pub sort_custom_field: Option<String>,
+    pub sort_direction: Option<String>,
+}
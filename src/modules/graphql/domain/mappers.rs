@@ -0,0 +1,4 @@
+pub mod book_filter_input_mapper;
+pub mod book_page_object_mapper;
+pub mod collection_object_mapper;
+pub mod location_object_mapper;
@@ -0,0 +1,29 @@
+use crate::modules::{
+    books::domain::dtos::get_all_books_params::GetAllBooksParams,
+    graphql::domain::inputs::book_filter_input::BookFilterInput,
+};
+
+impl From<BookFilterInput> for GetAllBooksParams {
+    fn from(input: BookFilterInput) -> Self {
+        GetAllBooksParams {
+            collection_id: input.collection_id,
+            location_id: input.location_id,
+            query: input.query,
+            tag_ids: input.tag_ids.map(|tag_ids| {
+                tag_ids
+                    .iter()
+                    .map(|tag_id| tag_id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            }),
+            tag_match: input.tag_match,
+            custom_field: input.custom_field,
+            custom_field_value: input.custom_field_value,
+            custom_field_min: input.custom_field_min,
+            custom_field_max: input.custom_field_max,
+            sort_custom_field: input.sort_custom_field,
+            sort_direction: input.sort_direction,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::modules::{
+    books::domain::dtos::complete_book_dto::CompleteBookDto,
+    graphql::domain::objects::{book_object::BookObject, book_page_object::BookPageObject},
+    shared::domain::dtos::paginated_dto::PaginatedDto,
+};
+
+impl From<PaginatedDto<CompleteBookDto>> for BookPageObject {
+    fn from(dto: PaginatedDto<CompleteBookDto>) -> Self {
+        BookPageObject {
+            page: dto.page,
+            page_size: dto.page_size,
+            total_items: dto.total_items,
+            next_cursor: dto.next_cursor,
+            prev_cursor: dto.prev_cursor,
+            items: dto.items.into_iter().map(BookObject).collect(),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::modules::{
+    books::domain::{dtos::collection_dto::CollectionDto, entities::collection::Collection},
+    graphql::domain::objects::collection_object::CollectionObject,
+};
+
+impl From<Collection> for CollectionObject {
+    fn from(entity: Collection) -> Self {
+        CollectionObject {
+            id: entity.id.unwrap_or_default(),
+            name: entity.name,
+            user_id: entity.user_id,
+            library_id: entity.library_id,
+            version: entity.version,
+        }
+    }
+}
+
+impl From<&CollectionDto> for CollectionObject {
+    fn from(dto: &CollectionDto) -> Self {
+        CollectionObject {
+            id: dto.id.unwrap_or_default(),
+            name: dto.name.clone(),
+            user_id: dto.user_id,
+            library_id: dto.library_id,
+            version: dto.version,
+        }
+    }
+}
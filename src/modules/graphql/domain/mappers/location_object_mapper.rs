@@ -0,0 +1,28 @@
+use crate::modules::{
+    books::domain::{dtos::location_dto::LocationDto, entities::location::Location},
+    graphql::domain::objects::location_object::LocationObject,
+};
+
+impl From<Location> for LocationObject {
+    fn from(entity: Location) -> Self {
+        LocationObject {
+            id: entity.id.unwrap_or_default(),
+            name: entity.name,
+            user_id: entity.user_id,
+            library_id: entity.library_id,
+            version: entity.version,
+        }
+    }
+}
+
+impl From<&LocationDto> for LocationObject {
+    fn from(dto: &LocationDto) -> Self {
+        LocationObject {
+            id: dto.id.unwrap_or_default(),
+            name: dto.name.clone(),
+            user_id: dto.user_id,
+            library_id: dto.library_id,
+            version: dto.version,
+        }
+    }
+}
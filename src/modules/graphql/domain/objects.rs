@@ -0,0 +1,4 @@
+pub mod book_object;
+pub mod book_page_object;
+pub mod collection_object;
+pub mod location_object;
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use async_graphql::{dataloader::DataLoader, Context, Json, Object, Result};
+use serde_json::Value;
+
+use crate::modules::{
+    books::domain::dtos::{
+        author_dto::AuthorDto, complete_book_dto::CompleteBookDto, genre_dto::GenreDto,
+        language_dto::LanguageDto, tag_dto::TagDto,
+    },
+    graphql::{
+        domain::objects::{collection_object::CollectionObject, location_object::LocationObject},
+        infra::loaders::loan_loader::LoanLoader,
+    },
+    loans::domain::dtos::loan_dto::LoanDto,
+};
+
+pub struct BookObject(pub CompleteBookDto);
+
+#[Object(name = "Book")]
+impl BookObject {
+    async fn id(&self) -> u64 {
+        self.0.id
+    }
+
+    async fn title(&self) -> &str {
+        &self.0.title
+    }
+
+    async fn authors(&self) -> &Vec<AuthorDto> {
+        &self.0.authors
+    }
+
+    async fn publisher(&self) -> &str {
+        &self.0.publisher
+    }
+
+    async fn languages(&self) -> &Vec<LanguageDto> {
+        &self.0.languages
+    }
+
+    async fn edition(&self) -> Option<&str> {
+        self.0.edition.as_deref()
+    }
+
+    async fn isbn(&self) -> Option<&str> {
+        self.0.isbn.as_deref()
+    }
+
+    async fn year(&self) -> Option<&str> {
+        self.0.year.as_deref()
+    }
+
+    async fn genres(&self) -> Option<&Vec<GenreDto>> {
+        self.0.genres.as_ref()
+    }
+
+    async fn cover(&self) -> Option<&str> {
+        self.0.cover.as_deref()
+    }
+
+    async fn collection(&self) -> Option<CollectionObject> {
+        self.0.collection.as_ref().map(CollectionObject::from)
+    }
+
+    async fn location(&self) -> LocationObject {
+        LocationObject::from(&self.0.location)
+    }
+
+    async fn user_id(&self) -> u64 {
+        self.0.user_id
+    }
+
+    async fn library_id(&self) -> u64 {
+        self.0.library_id
+    }
+
+    async fn version(&self) -> u64 {
+        self.0.version
+    }
+
+    /// Tags the requesting user put on the book.
+    async fn tags(&self) -> &Vec<TagDto> {
+        &self.0.tags
+    }
+
+    /// Values of the requesting user's custom fields, by field name.
+    async fn custom_fields(&self) -> Json<&HashMap<String, Value>> {
+        Json(&self.0.custom_fields)
+    }
+
+    async fn note_count(&self) -> u64 {
+        self.0.note_count
+    }
+
+    /// Loans of the book where the requesting user is either the lender or
+    /// the borrower, open loans first.
+    async fn loans(&self, ctx: &Context<'_>) -> Result<Vec<LoanDto>> {
+        let loans = ctx
+            .data::<DataLoader<LoanLoader>>()?
+            .load_one(self.0.id)
+            .await?;
+        Ok(loans.unwrap_or_default())
+    }
+}
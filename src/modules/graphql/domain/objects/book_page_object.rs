@@ -0,0 +1,14 @@
+use async_graphql::SimpleObject;
+
+use super::book_object::BookObject;
+
+#[derive(SimpleObject)]
+#[graphql(name = "BookPage")]
+pub struct BookPageObject {
+    pub page: Option<u64>,
+    pub page_size: u64,
+    pub total_items: Option<u64>,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
+    pub items: Vec<BookObject>,
+}
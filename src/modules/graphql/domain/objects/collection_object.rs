@@ -0,0 +1,26 @@
+use async_graphql::{dataloader::DataLoader, ComplexObject, Context, Result, SimpleObject};
+
+use crate::modules::graphql::infra::loaders::book_count_loader::{
+    BookCountLoader, CollectionBookCount,
+};
+
+#[derive(Debug, Default, Clone, SimpleObject)]
+#[graphql(name = "Collection", complex)]
+pub struct CollectionObject {
+    pub id: u64,
+    pub name: String,
+    pub user_id: u64,
+    pub library_id: u64,
+    pub version: u64,
+}
+
+#[ComplexObject]
+impl CollectionObject {
+    async fn book_count(&self, ctx: &Context<'_>) -> Result<u64> {
+        let book_count = ctx
+            .data::<DataLoader<BookCountLoader>>()?
+            .load_one(CollectionBookCount(self.id))
+            .await?;
+        Ok(book_count.unwrap_or_default())
+    }
+}
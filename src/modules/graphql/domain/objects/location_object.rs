@@ -0,0 +1,26 @@
+use async_graphql::{dataloader::DataLoader, ComplexObject, Context, Result, SimpleObject};
+
+use crate::modules::graphql::infra::loaders::book_count_loader::{
+    BookCountLoader, LocationBookCount,
+};
+
+#[derive(Debug, Default, Clone, SimpleObject)]
+#[graphql(name = "Location", complex)]
+pub struct LocationObject {
+    pub id: u64,
+    pub name: String,
+    pub user_id: u64,
+    pub library_id: u64,
+    pub version: u64,
+}
+
+#[ComplexObject]
+impl LocationObject {
+    async fn book_count(&self, ctx: &Context<'_>) -> Result<u64> {
+        let book_count = ctx
+            .data::<DataLoader<BookCountLoader>>()?
+            .load_one(LocationBookCount(self.id))
+            .await?;
+        Ok(book_count.unwrap_or_default())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod controllers;
+pub mod loaders;
+pub mod resolvers;
@@ -0,0 +1 @@
+pub mod graphql_controller;
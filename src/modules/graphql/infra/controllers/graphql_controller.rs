@@ -0,0 +1,112 @@
+use actix_web::{web, HttpResponse};
+use async_graphql::{
+    dataloader::DataLoader, http::GraphiQLSource, EmptyMutation, EmptySubscription, Request, Schema,
+};
+
+use crate::{
+    configuration::GraphQLSettings,
+    modules::{
+        books::infra::repositories::{
+            book_repository_mysql::BookRepositoryMySQL,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+            location_repository_mysql::LocationRepositoryMySQL,
+            note_repository_mysql::NoteRepositoryMySQL, tag_repository_mysql::TagRepositoryMySQL,
+        },
+        graphql::infra::{
+            loaders::{book_count_loader::BookCountLoader, loan_loader::LoanLoader},
+            resolvers::query_resolver::QueryResolver,
+        },
+        libraries::infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+        loans::infra::repositories::loan_repository_mysql::LoanRepositoryMySQL,
+        shared::errors::{simple_api_error::SimpleAPIError, APIError},
+        users::{
+            domain::dtos::authed_user::AuthedUser,
+            infra::repositories::user_repository_mysql::UserRepositoryMySQL,
+        },
+    },
+};
+
+pub type LibrarianSchema = Schema<QueryResolver, EmptyMutation, EmptySubscription>;
+
+pub struct GraphQLController {
+    schema: LibrarianSchema,
+    book_repository: BookRepositoryMySQL,
+    loan_repository: LoanRepositoryMySQL,
+    playground: bool,
+}
+
+impl GraphQLController {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_repository: UserRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        note_repository: NoteRepositoryMySQL,
+        loan_repository: LoanRepositoryMySQL,
+        graphql_settings: GraphQLSettings,
+    ) -> Self {
+        GraphQLController {
+            schema: Schema::build(
+                QueryResolver::new(
+                    user_repository,
+                    book_repository.clone(),
+                    collection_repository,
+                    location_repository,
+                    library_repository,
+                    tag_repository,
+                    custom_field_repository,
+                    note_repository,
+                ),
+                EmptyMutation,
+                EmptySubscription,
+            )
+            .finish(),
+            book_repository,
+            loan_repository,
+            playground: graphql_settings.playground,
+        }
+    }
+}
+
+/// Executes a query on behalf of the authenticated user. Dataloaders are
+/// built for each request, so nothing is cached across users.
+pub async fn graphql(
+    graphql_controller: web::Data<GraphQLController>,
+    request: web::Json<Request>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let loan_loader = LoanLoader::new(
+        graphql_controller.loan_repository.clone(),
+        authed_user.id.unwrap(),
+    );
+    let book_count_loader = BookCountLoader::new(graphql_controller.book_repository.clone());
+    let request = request
+        .into_inner()
+        .data(DataLoader::new(loan_loader, tokio::spawn))
+        .data(DataLoader::new(book_count_loader, tokio::spawn))
+        .data(authed_user);
+
+    HttpResponse::Ok().json(graphql_controller.schema.execute(request).await)
+}
+
+pub async fn graphql_playground(graphql_controller: web::Data<GraphQLController>) -> HttpResponse {
+    if !graphql_controller.playground {
+        return HttpResponse::NotFound().finish();
+    }
+
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(GraphiQLSource::build().endpoint("/graphql").finish())
+}
@@ -0,0 +1,2 @@
+pub mod book_count_loader;
+pub mod loan_loader;
@@ -0,0 +1,69 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_graphql::dataloader::Loader;
+
+use crate::modules::books::infra::repositories::{
+    book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollectionBookCount(pub u64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocationBookCount(pub u64);
+
+pub struct BookCountLoader {
+    book_repository: BookRepositoryMySQL,
+}
+
+impl BookCountLoader {
+    pub fn new(book_repository: BookRepositoryMySQL) -> Self {
+        Self { book_repository }
+    }
+}
+
+impl Loader<CollectionBookCount> for BookCountLoader {
+    type Value = u64;
+    type Error = Arc<sqlx::Error>;
+
+    async fn load(
+        &self,
+        keys: &[CollectionBookCount],
+    ) -> Result<HashMap<CollectionBookCount, Self::Value>, Self::Error> {
+        let collection_ids: Vec<u64> = keys.iter().map(|key| key.0).collect();
+        match self
+            .book_repository
+            .count_by_collection_ids(&collection_ids)
+            .await
+        {
+            Ok(counts) => Ok(counts
+                .into_iter()
+                .map(|(collection_id, count)| (CollectionBookCount(collection_id), count))
+                .collect()),
+            Err(error) => Err(Arc::new(error)),
+        }
+    }
+}
+
+impl Loader<LocationBookCount> for BookCountLoader {
+    type Value = u64;
+    type Error = Arc<sqlx::Error>;
+
+    async fn load(
+        &self,
+        keys: &[LocationBookCount],
+    ) -> Result<HashMap<LocationBookCount, Self::Value>, Self::Error> {
+        let location_ids: Vec<u64> = keys.iter().map(|key| key.0).collect();
+        match self
+            .book_repository
+            .count_by_location_ids(&location_ids)
+            .await
+        {
+            Ok(counts) => Ok(counts
+                .into_iter()
+                .map(|(location_id, count)| (LocationBookCount(location_id), count))
+                .collect()),
+            Err(error) => Err(Arc::new(error)),
+        }
+    }
+}
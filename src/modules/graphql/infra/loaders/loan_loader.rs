@@ -0,0 +1,40 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_graphql::dataloader::Loader;
+
+use crate::modules::loans::{
+    domain::dtos::loan_dto::LoanDto,
+    infra::repositories::{
+        loan_repository::LoanRepository, loan_repository_mysql::LoanRepositoryMySQL,
+    },
+};
+
+pub struct LoanLoader {
+    loan_repository: LoanRepositoryMySQL,
+    user_id: u64,
+}
+
+impl LoanLoader {
+    pub fn new(loan_repository: LoanRepositoryMySQL, user_id: u64) -> Self {
+        Self {
+            loan_repository,
+            user_id,
+        }
+    }
+}
+
+impl Loader<u64> for LoanLoader {
+    type Value = Vec<LoanDto>;
+    type Error = Arc<sqlx::Error>;
+
+    async fn load(&self, book_ids: &[u64]) -> Result<HashMap<u64, Self::Value>, Self::Error> {
+        match self
+            .loan_repository
+            .find_all_by_book_ids_and_user_id(book_ids, self.user_id)
+            .await
+        {
+            Ok(loans_by_book) => Ok(loans_by_book),
+            Err(error) => Err(Arc::new(error)),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use async_graphql::{Context, Object, Result};
+
+use crate::modules::{
+    books::{
+        domain::dtos::get_all_books_params::GetAllBooksParams,
+        infra::repositories::{
+            book_repository_mysql::BookRepositoryMySQL,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+            location_repository_mysql::LocationRepositoryMySQL,
+            note_repository_mysql::NoteRepositoryMySQL, tag_repository_mysql::TagRepositoryMySQL,
+        },
+        usecases::v1::{
+            find_all_books_from_user_usecase::FindAllBooksFromUserUseCaseV1,
+            find_all_collection_from_user_usecase::FindAllCollectionFromUserUseCaseV1,
+            find_all_location_from_user_usecase::FindAllLocationFromUserUseCaseV1,
+            find_book_by_id_usecase::FindBookByIDUseCaseV1,
+        },
+    },
+    graphql::domain::{
+        inputs::book_filter_input::BookFilterInput,
+        objects::{
+            book_object::BookObject, book_page_object::BookPageObject,
+            collection_object::CollectionObject, location_object::LocationObject,
+        },
+    },
+    libraries::infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::{
+        domain::dtos::{authed_user::AuthedUser, created_user_dto::CreatedUserDto},
+        infra::repositories::user_repository_mysql::UserRepositoryMySQL,
+        usecases::v1::get_user_info::GetUserInfoUseCaseV1,
+    },
+};
+
+pub struct QueryResolver {
+    get_user_info_usecase: GetUserInfoUseCaseV1<UserRepositoryMySQL>,
+    get_all_books_from_user_usecase: FindAllBooksFromUserUseCaseV1<
+        BookRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        NoteRepositoryMySQL,
+    >,
+    find_book_by_id_usecase: FindBookByIDUseCaseV1<
+        BookRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        NoteRepositoryMySQL,
+    >,
+    find_all_collection_from_user_usecase:
+        FindAllCollectionFromUserUseCaseV1<CollectionRepositoryMySQL, LibraryRepositoryMySQL>,
+    find_all_location_from_user_usecase:
+        FindAllLocationFromUserUseCaseV1<LocationRepositoryMySQL, LibraryRepositoryMySQL>,
+}
+
+impl QueryResolver {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_repository: UserRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        note_repository: NoteRepositoryMySQL,
+    ) -> Self {
+        QueryResolver {
+            get_user_info_usecase: GetUserInfoUseCaseV1::new(user_repository),
+            get_all_books_from_user_usecase: FindAllBooksFromUserUseCaseV1::new(
+                book_repository.clone(),
+                library_repository.clone(),
+                tag_repository.clone(),
+                custom_field_repository.clone(),
+                note_repository.clone(),
+            ),
+            find_book_by_id_usecase: FindBookByIDUseCaseV1::new(
+                book_repository.clone(),
+                library_repository.clone(),
+                tag_repository.clone(),
+                custom_field_repository.clone(),
+                note_repository.clone(),
+            ),
+            find_all_collection_from_user_usecase: FindAllCollectionFromUserUseCaseV1::new(
+                collection_repository.clone(),
+                library_repository.clone(),
+            ),
+            find_all_location_from_user_usecase: FindAllLocationFromUserUseCaseV1::new(
+                location_repository.clone(),
+                library_repository.clone(),
+            ),
+        }
+    }
+}
+
+fn authed_user_id(ctx: &Context<'_>) -> Result<u64> {
+    match ctx.data::<AuthedUser>()?.id {
+        Some(user_id) => Ok(user_id),
+        None => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        ))
+        .into()),
+    }
+}
+
+#[Object(name = "Query")]
+impl QueryResolver {
+    async fn me(&self, ctx: &Context<'_>) -> Result<CreatedUserDto> {
+        let user_id = authed_user_id(ctx)?;
+        self.user(ctx, user_id).await
+    }
+
+    async fn user(&self, ctx: &Context<'_>, id: u64) -> Result<CreatedUserDto> {
+        if authed_user_id(ctx)? != id {
+            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "User doesn't have permission to access this resource".to_string(),
+                403,
+            ))
+            .into());
+        }
+
+        let user = self.get_user_info_usecase.get_user_info(id).await?;
+        Ok(CreatedUserDto::try_from(user)?)
+    }
+
+    async fn book(&self, ctx: &Context<'_>, id: u64) -> Result<BookObject> {
+        let book = self
+            .find_book_by_id_usecase
+            .find_book_by_id(authed_user_id(ctx)?, id)
+            .await?;
+        Ok(BookObject(book))
+    }
+
+    /// Books of a library, of the user's own library when none is given.
+    /// Pages are numbered unless a cursor is given, an empty cursor asks for
+    /// the first cursor page.
+    #[allow(clippy::too_many_arguments)]
+    async fn books(
+        &self,
+        ctx: &Context<'_>,
+        library_id: Option<u64>,
+        filter: Option<BookFilterInput>,
+        page: Option<i64>,
+        page_size: Option<i64>,
+        cursor: Option<String>,
+        include_total: Option<bool>,
+    ) -> Result<BookPageObject> {
+        let params = GetAllBooksParams {
+            page,
+            page_size,
+            cursor,
+            include_total,
+            ..GetAllBooksParams::from(filter.unwrap_or_default())
+        };
+        let books_page = self
+            .get_all_books_from_user_usecase
+            .find_all_from_user(authed_user_id(ctx)?, library_id, params)
+            .await?;
+        Ok(BookPageObject::from(books_page))
+    }
+
+    async fn collections(
+        &self,
+        ctx: &Context<'_>,
+        library_id: Option<u64>,
+    ) -> Result<Vec<CollectionObject>> {
+        let collections = self
+            .find_all_collection_from_user_usecase
+            .find_all_collection_from_user(authed_user_id(ctx)?, library_id)
+            .await?;
+        Ok(collections
+            .into_iter()
+            .map(CollectionObject::from)
+            .collect())
+    }
+
+    async fn locations(
+        &self,
+        ctx: &Context<'_>,
+        library_id: Option<u64>,
+    ) -> Result<Vec<LocationObject>> {
+        let locations = self
+            .find_all_location_from_user_usecase
+            .find_all_location_from_user(authed_user_id(ctx)?, library_id)
+            .await?;
+        Ok(locations.into_iter().map(LocationObject::from).collect())
+    }
+}
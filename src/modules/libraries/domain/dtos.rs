@@ -0,0 +1,10 @@
+pub mod create_library_dto;
+pub mod create_library_invitation_dto;
+pub mod find_all_libraries_from_user_dto;
+pub mod find_all_library_invitations_dto;
+pub mod find_all_library_members_dto;
+pub mod library_dto;
+pub mod library_invitation_dto;
+pub mod library_member_dto;
+pub mod library_scope_params;
+pub mod update_library_visibility_dto;
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct CreateLibraryDto {
+    pub name: Option<String>,
+    pub owner_id: Option<u64>,
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct CreateLibraryInvitationDto {
+    pub email: Option<String>,
+    pub role: Option<String>,
+    pub library_id: Option<u64>,
+    pub invited_by: Option<u64>,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::library_dto::LibraryDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FindAllLibrariesFromUserDto {
+    pub libraries: Vec<LibraryDto>,
+}
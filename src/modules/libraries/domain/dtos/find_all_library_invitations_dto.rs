@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::library_invitation_dto::LibraryInvitationDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FindAllLibraryInvitationsDto {
+    pub invitations: Vec<LibraryInvitationDto>,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::library_member_dto::LibraryMemberDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FindAllLibraryMembersDto {
+    pub members: Vec<LibraryMemberDto>,
+}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct LibraryDto {
+    pub id: u64,
+    pub name: String,
+    pub owner_id: u64,
+    pub role: String,
+    pub visibility: String,
+}
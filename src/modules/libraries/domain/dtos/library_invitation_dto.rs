@@ -0,0 +1,14 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct LibraryInvitationDto {
+    pub id: u64,
+    pub library_id: u64,
+    pub email: String,
+    pub role: String,
+    pub invited_by: u64,
+    pub status: String,
+    pub created_at: Option<String>,
+    pub responded_at: Option<String>,
+}
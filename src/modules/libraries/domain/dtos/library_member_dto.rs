@@ -0,0 +1,10 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct LibraryMemberDto {
+    pub user_id: u64,
+    pub name: String,
+    pub email: String,
+    pub role: String,
+}
@@ -0,0 +1,7 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct UpdateLibraryVisibilityDto {
+    pub visibility: Option<String>,
+}
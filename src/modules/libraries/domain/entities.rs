@@ -0,0 +1,4 @@
+pub mod library;
+pub mod library_invitation;
+pub mod library_role;
+pub mod library_visibility;
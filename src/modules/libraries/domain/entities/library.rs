@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+
+use super::library_visibility::LibraryVisibility;
+
+pub const PERSONAL_LIBRARY_NAME: &str = "My library";
+
+#[derive(Debug, Default)]
+pub struct Library {
+    pub id: Option<u64>,
+    pub name: String,
+    pub owner_id: u64,
+    pub visibility: LibraryVisibility,
+    pub created_at: Option<DateTime<Utc>>,
+}
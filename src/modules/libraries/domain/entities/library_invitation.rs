@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+
+use super::library_role::LibraryRole;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LibraryInvitationStatus {
+    Pending,
+    Accepted,
+    Declined,
+}
+
+impl LibraryInvitationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LibraryInvitationStatus::Pending => "pending",
+            LibraryInvitationStatus::Accepted => "accepted",
+            LibraryInvitationStatus::Declined => "declined",
+        }
+    }
+}
+
+impl TryFrom<&str> for LibraryInvitationStatus {
+    type Error = String;
+
+    fn try_from(status: &str) -> Result<Self, Self::Error> {
+        match status {
+            "pending" => Ok(LibraryInvitationStatus::Pending),
+            "accepted" => Ok(LibraryInvitationStatus::Accepted),
+            "declined" => Ok(LibraryInvitationStatus::Declined),
+            _ => Err(format!("Unknown invitation status {}", status)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LibraryInvitation {
+    pub id: Option<u64>,
+    pub library_id: u64,
+    pub email: String,
+    pub role: LibraryRole,
+    pub invited_by: u64,
+    pub status: LibraryInvitationStatus,
+    pub created_at: Option<DateTime<Utc>>,
+    pub responded_at: Option<DateTime<Utc>>,
+}
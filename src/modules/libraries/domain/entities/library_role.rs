@@ -0,0 +1,52 @@
+/// Roles are ordered by the permissions they grant, every role can do what
+/// the roles before it can.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LibraryRole {
+    Viewer,
+    Editor,
+    Owner,
+}
+
+impl LibraryRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LibraryRole::Viewer => "viewer",
+            LibraryRole::Editor => "editor",
+            LibraryRole::Owner => "owner",
+        }
+    }
+}
+
+impl TryFrom<&str> for LibraryRole {
+    type Error = String;
+
+    fn try_from(role: &str) -> Result<Self, Self::Error> {
+        match role {
+            "viewer" => Ok(LibraryRole::Viewer),
+            "editor" => Ok(LibraryRole::Editor),
+            "owner" => Ok(LibraryRole::Owner),
+            _ => Err(format!("Unknown library role {}", role)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roles_grant_the_permissions_of_the_lower_ones() {
+        assert!(LibraryRole::Viewer < LibraryRole::Editor);
+        assert!(LibraryRole::Editor < LibraryRole::Owner);
+        assert!(LibraryRole::Owner >= LibraryRole::Editor);
+    }
+
+    #[test]
+    fn roles_are_read_from_their_names() {
+        for role in [LibraryRole::Viewer, LibraryRole::Editor, LibraryRole::Owner] {
+            assert_eq!(Ok(role), LibraryRole::try_from(role.as_str()));
+        }
+        assert!(LibraryRole::try_from("Owner").is_err());
+        assert!(LibraryRole::try_from("admin").is_err());
+    }
+}
@@ -0,0 +1,27 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LibraryVisibility {
+    #[default]
+    Private,
+    Friends,
+}
+
+impl LibraryVisibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LibraryVisibility::Private => "private",
+            LibraryVisibility::Friends => "friends",
+        }
+    }
+}
+
+impl TryFrom<&str> for LibraryVisibility {
+    type Error = String;
+
+    fn try_from(visibility: &str) -> Result<Self, Self::Error> {
+        match visibility {
+            "private" => Ok(LibraryVisibility::Private),
+            "friends" => Ok(LibraryVisibility::Friends),
+            _ => Err(format!("Unknown library visibility {}", visibility)),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod create_library_dto_mapper;
+pub mod create_library_invitation_dto_mapper;
+pub mod library_invitation_dto_mapper;
+pub mod update_library_visibility_dto_mapper;
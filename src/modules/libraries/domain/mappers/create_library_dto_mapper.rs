@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    libraries::domain::{dtos::create_library_dto::CreateLibraryDto, entities::library::Library},
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<CreateLibraryDto> for Library {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: CreateLibraryDto) -> Result<Self, Self::Error> {
+        let mut library = Library::default();
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        match dto.name {
+            Some(name) if !name.trim().is_empty() => library.name = name.trim().to_string(),
+            Some(_) => {
+                validations.insert(
+                    "name".to_string(),
+                    "Library name must not be empty".to_string(),
+                );
+            }
+            None => {
+                validations.insert(
+                    "name".to_string(),
+                    "Library name must be informed".to_string(),
+                );
+            }
+        }
+
+        match dto.owner_id {
+            Some(owner_id) => library.owner_id = owner_id,
+            None => {
+                validations.insert(
+                    "owner_id".to_string(),
+                    "Library must be owned by an user".to_string(),
+                );
+            }
+        }
+
+        if !validations.is_empty() {
+            return Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            });
+        }
+        Ok(library)
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    libraries::domain::{
+        dtos::create_library_invitation_dto::CreateLibraryInvitationDto,
+        entities::{
+            library_invitation::{LibraryInvitation, LibraryInvitationStatus},
+            library_role::LibraryRole,
+        },
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<CreateLibraryInvitationDto> for LibraryInvitation {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: CreateLibraryInvitationDto) -> Result<Self, Self::Error> {
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        let email = match dto.email.map(|email| email.trim().to_lowercase()) {
+            Some(email) if email.contains('@') => email,
+            Some(_) => {
+                validations.insert(
+                    "email".to_string(),
+                    "E-mail must be a valid address".to_string(),
+                );
+                String::new()
+            }
+            None => {
+                validations.insert("email".to_string(), "E-mail must be informed".to_string());
+                String::new()
+            }
+        };
+
+        // Ownership can't be handed over through an invitation
+        let role = match dto.role.as_deref() {
+            None | Some("viewer") => LibraryRole::Viewer,
+            Some("editor") => LibraryRole::Editor,
+            Some(_) => {
+                validations.insert(
+                    "role".to_string(),
+                    "Role must be one of editor, viewer".to_string(),
+                );
+                LibraryRole::Viewer
+            }
+        };
+
+        if dto.library_id.is_none() {
+            validations.insert(
+                "library_id".to_string(),
+                "Invitation must be related to a library".to_string(),
+            );
+        }
+        if dto.invited_by.is_none() {
+            validations.insert(
+                "invited_by".to_string(),
+                "Invitation must be sent by an user".to_string(),
+            );
+        }
+
+        if !validations.is_empty() {
+            return Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            });
+        }
+        Ok(LibraryInvitation {
+            id: None,
+            library_id: dto.library_id.unwrap_or_default(),
+            email,
+            role,
+            invited_by: dto.invited_by.unwrap_or_default(),
+            status: LibraryInvitationStatus::Pending,
+            created_at: None,
+            responded_at: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invitation(role: Option<&str>) -> CreateLibraryInvitationDto {
+        CreateLibraryInvitationDto {
+            email: Some(" Reader@Example.com ".to_string()),
+            role: role.map(str::to_string),
+            library_id: Some(1),
+            invited_by: Some(2),
+        }
+    }
+
+    #[test]
+    fn invitations_default_to_viewers_with_normalized_emails() {
+        let library_invitation = LibraryInvitation::try_from(invitation(None)).unwrap();
+
+        assert_eq!("reader@example.com", library_invitation.email);
+        assert_eq!(LibraryRole::Viewer, library_invitation.role);
+        assert_eq!(LibraryInvitationStatus::Pending, library_invitation.status);
+        assert_eq!(
+            LibraryRole::Editor,
+            LibraryInvitation::try_from(invitation(Some("editor")))
+                .unwrap()
+                .role
+        );
+    }
+
+    #[test]
+    fn ownership_isnt_handed_over_by_invitations() {
+        let error = LibraryInvitation::try_from(invitation(Some("owner"))).unwrap_err();
+
+        assert!(error.field_validations.unwrap().contains_key("role"));
+    }
+}
@@ -0,0 +1,19 @@
+use crate::modules::libraries::domain::{
+    dtos::library_invitation_dto::LibraryInvitationDto,
+    entities::library_invitation::LibraryInvitation,
+};
+
+impl From<LibraryInvitation> for LibraryInvitationDto {
+    fn from(invitation: LibraryInvitation) -> Self {
+        LibraryInvitationDto {
+            id: invitation.id.unwrap_or_default(),
+            library_id: invitation.library_id,
+            email: invitation.email,
+            role: invitation.role.as_str().to_string(),
+            invited_by: invitation.invited_by,
+            status: invitation.status.as_str().to_string(),
+            created_at: invitation.created_at.map(|date| date.to_rfc3339()),
+            responded_at: invitation.responded_at.map(|date| date.to_rfc3339()),
+        }
+    }
+}
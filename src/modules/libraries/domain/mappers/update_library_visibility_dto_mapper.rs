@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    libraries::domain::{
+        dtos::update_library_visibility_dto::UpdateLibraryVisibilityDto,
+        entities::library_visibility::LibraryVisibility,
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<UpdateLibraryVisibilityDto> for LibraryVisibility {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: UpdateLibraryVisibilityDto) -> Result<Self, Self::Error> {
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        match dto.visibility.as_deref().map(LibraryVisibility::try_from) {
+            Some(Ok(visibility)) => return Ok(visibility),
+            Some(Err(_)) => {
+                validations.insert(
+                    "visibility".to_string(),
+                    "Visibility must be either private or friends".to_string(),
+                );
+            }
+            None => {
+                validations.insert(
+                    "visibility".to_string(),
+                    "Visibility must be informed".to_string(),
+                );
+            }
+        }
+
+        Err(DetailedAPIError {
+            msg: "Request contains invalid data".to_string(),
+            code: 400,
+            field_validations: Some(validations),
+        })
+    }
+}
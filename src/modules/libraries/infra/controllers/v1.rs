@@ -0,0 +1,2 @@
+pub mod library_controller_v1;
+pub mod library_invitation_controller_v1;
@@ -0,0 +1,360 @@
+use crate::modules::{
+    libraries::{
+        domain::{
+            dtos::{
+                create_library_dto::CreateLibraryDto,
+                create_library_invitation_dto::CreateLibraryInvitationDto,
+                find_all_libraries_from_user_dto::FindAllLibrariesFromUserDto,
+                find_all_library_members_dto::FindAllLibraryMembersDto, library_dto::LibraryDto,
+                library_invitation_dto::LibraryInvitationDto,
+                update_library_visibility_dto::UpdateLibraryVisibilityDto,
+            },
+            entities::{
+                library::Library, library_invitation::LibraryInvitation, library_role::LibraryRole,
+                library_visibility::LibraryVisibility,
+            },
+        },
+        infra::repositories::{
+            library_invitation_repository_mysql::LibraryInvitationRepositoryMySQL,
+            library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::{
+            create_library_invitation_usecase::CreateLibraryInvitationUseCaseV1,
+            create_library_usecase::CreateLibraryUseCaseV1,
+            find_all_libraries_from_user_usecase::FindAllLibrariesFromUserUseCaseV1,
+            find_all_library_members_usecase::FindAllLibraryMembersUseCaseV1,
+            remove_library_member_usecase::RemoveLibraryMemberUseCaseV1,
+            update_library_visibility_usecase::UpdateLibraryVisibilityUseCaseV1,
+        },
+    },
+    shared::errors::{
+        detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+    },
+    users::{
+        domain::dtos::authed_user::AuthedUser,
+        infra::repositories::user_repository_mysql::UserRepositoryMySQL,
+    },
+};
+use actix_web::{delete, get, post, put, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct LibraryControllerV1 {
+    create_library_usecase: CreateLibraryUseCaseV1<LibraryRepositoryMySQL>,
+    find_all_libraries_from_user_usecase: FindAllLibrariesFromUserUseCaseV1<LibraryRepositoryMySQL>,
+    find_all_library_members_usecase: FindAllLibraryMembersUseCaseV1<LibraryRepositoryMySQL>,
+    remove_library_member_usecase: RemoveLibraryMemberUseCaseV1<LibraryRepositoryMySQL>,
+    update_library_visibility_usecase: UpdateLibraryVisibilityUseCaseV1<LibraryRepositoryMySQL>,
+    create_library_invitation_usecase: CreateLibraryInvitationUseCaseV1<
+        LibraryInvitationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        UserRepositoryMySQL,
+    >,
+}
+
+impl LibraryControllerV1 {
+    pub fn new(
+        library_repository: LibraryRepositoryMySQL,
+        library_invitation_repository: LibraryInvitationRepositoryMySQL,
+        user_repository: UserRepositoryMySQL,
+    ) -> Self {
+        LibraryControllerV1 {
+            create_library_usecase: CreateLibraryUseCaseV1::new(library_repository.clone()),
+            find_all_libraries_from_user_usecase: FindAllLibrariesFromUserUseCaseV1::new(
+                library_repository.clone(),
+            ),
+            find_all_library_members_usecase: FindAllLibraryMembersUseCaseV1::new(
+                library_repository.clone(),
+            ),
+            remove_library_member_usecase: RemoveLibraryMemberUseCaseV1::new(
+                library_repository.clone(),
+            ),
+            update_library_visibility_usecase: UpdateLibraryVisibilityUseCaseV1::new(
+                library_repository.clone(),
+            ),
+            create_library_invitation_usecase: CreateLibraryInvitationUseCaseV1::new(
+                library_invitation_repository,
+                library_repository.clone(),
+                user_repository,
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "libraries",
+    summary = "Create a library",
+    request_body = CreateLibraryDto,
+    responses(
+        (status = 201, description = "Library created", body = LibraryDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+    ),
+)]
+#[post("")]
+async fn create_library(
+    library_controller: web::Data<LibraryControllerV1>,
+    create_library_dto: web::Json<CreateLibraryDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let mut dto = create_library_dto.into_inner();
+    dto.owner_id = authed_user.id;
+    let library = match Library::try_from(dto) {
+        Ok(converted_library) => converted_library,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match library_controller
+        .create_library_usecase
+        .create_library(library)
+        .await
+    {
+        Ok(library) => HttpResponse::Created().json(web::Json(LibraryDto {
+            id: library.id.unwrap_or_default(),
+            name: library.name,
+            owner_id: library.owner_id,
+            role: LibraryRole::Owner.as_str().to_string(),
+            visibility: library.visibility.as_str().to_string(),
+        })),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "libraries",
+    summary = "List the libraries the user is a member of",
+    responses(
+        (status = 200, description = "Libraries", body = FindAllLibrariesFromUserDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn get_all_libraries_from_user(
+    library_controller: web::Data<LibraryControllerV1>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match library_controller
+        .find_all_libraries_from_user_usecase
+        .find_all_libraries_from_user(authed_user.id.unwrap())
+        .await
+    {
+        Ok(libraries) => {
+            HttpResponse::Ok().json(web::Json(FindAllLibrariesFromUserDto { libraries }))
+        }
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "libraries",
+    summary = "List the members of a library",
+    params(
+        ("library_id" = u64, Path, description = "Id of the library"),
+    ),
+    responses(
+        (status = 200, description = "Members", body = FindAllLibraryMembersDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Library not found", body = SimpleAPIError),
+    ),
+)]
+#[get("/{library_id}/members")]
+async fn get_all_library_members(
+    library_controller: web::Data<LibraryControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match library_controller
+        .find_all_library_members_usecase
+        .find_all_library_members(path_variables.into_inner(), authed_user.id.unwrap())
+        .await
+    {
+        Ok(members) => HttpResponse::Ok().json(web::Json(FindAllLibraryMembersDto { members })),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "libraries",
+    summary = "Remove a member from a library",
+    params(
+        ("library_id" = u64, Path, description = "Id of the library"),
+        ("user_id" = u64, Path, description = "Id of the member"),
+    ),
+    responses(
+        (status = 200, description = "Member removed"),
+        (status = 400, description = "Invalid parameters", body = SimpleAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Member not found", body = SimpleAPIError),
+    ),
+)]
+#[delete("/{library_id}/members/{user_id}")]
+async fn remove_library_member(
+    library_controller: web::Data<LibraryControllerV1>,
+    path_variables: web::Path<(u64, u64)>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    let (library_id, member_user_id) = path_variables.into_inner();
+
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match library_controller
+        .remove_library_member_usecase
+        .remove_library_member(library_id, member_user_id, authed_user.id.unwrap())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "libraries",
+    summary = "Change who can see a library",
+    params(
+        ("library_id" = u64, Path, description = "Id of the library"),
+    ),
+    request_body = UpdateLibraryVisibilityDto,
+    responses(
+        (status = 200, description = "Visibility changed"),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Library not found", body = SimpleAPIError),
+    ),
+)]
+#[put("/{library_id}/visibility")]
+async fn update_library_visibility(
+    library_controller: web::Data<LibraryControllerV1>,
+    path_variables: web::Path<u64>,
+    update_library_visibility_dto: web::Json<UpdateLibraryVisibilityDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let visibility = match LibraryVisibility::try_from(update_library_visibility_dto.into_inner()) {
+        Ok(converted_visibility) => converted_visibility,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match library_controller
+        .update_library_visibility_usecase
+        .update_library_visibility(
+            path_variables.into_inner(),
+            visibility,
+            authed_user.id.unwrap(),
+        )
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "libraries",
+    summary = "Invite a user to a library",
+    params(
+        ("library_id" = u64, Path, description = "Id of the library"),
+    ),
+    request_body = CreateLibraryInvitationDto,
+    responses(
+        (status = 201, description = "Invitation created", body = LibraryInvitationDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "User not found", body = SimpleAPIError),
+        (status = 409, description = "User is already a member or invited", body = SimpleAPIError),
+    ),
+)]
+#[post("/{library_id}/invitations")]
+async fn create_library_invitation(
+    library_controller: web::Data<LibraryControllerV1>,
+    path_variables: web::Path<u64>,
+    create_library_invitation_dto: web::Json<CreateLibraryInvitationDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let mut dto = create_library_invitation_dto.into_inner();
+    dto.library_id = Some(path_variables.into_inner());
+    dto.invited_by = authed_user.id;
+    let invitation = match LibraryInvitation::try_from(dto) {
+        Ok(converted_invitation) => converted_invitation,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match library_controller
+        .create_library_invitation_usecase
+        .create_library_invitation(invitation)
+        .await
+    {
+        Ok(invitation) => {
+            HttpResponse::Created().json(web::Json(LibraryInvitationDto::from(invitation)))
+        }
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    create_library,
+    get_all_libraries_from_user,
+    get_all_library_members,
+    remove_library_member,
+    update_library_visibility,
+    create_library_invitation
+))]
+pub struct LibraryApiV1;
+
+pub fn get_library_scope() -> Scope {
+    web::scope("/v1/libraries")
+        .service(create_library)
+        .service(get_all_libraries_from_user)
+        .service(get_all_library_members)
+        .service(remove_library_member)
+        .service(update_library_visibility)
+        .service(create_library_invitation)
+}
@@ -0,0 +1,170 @@
+use crate::modules::{
+    libraries::{
+        domain::dtos::{
+            find_all_library_invitations_dto::FindAllLibraryInvitationsDto,
+            library_invitation_dto::LibraryInvitationDto,
+        },
+        infra::repositories::library_invitation_repository_mysql::LibraryInvitationRepositoryMySQL,
+        usecases::v1::{
+            find_all_library_invitations_from_user_usecase::FindAllLibraryInvitationsFromUserUseCaseV1,
+            respond_library_invitation_usecase::RespondLibraryInvitationUseCaseV1,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::{
+        domain::dtos::authed_user::AuthedUser,
+        infra::repositories::user_repository_mysql::UserRepositoryMySQL,
+    },
+};
+use actix_web::{get, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct LibraryInvitationControllerV1 {
+    find_all_library_invitations_from_user_usecase: FindAllLibraryInvitationsFromUserUseCaseV1<
+        LibraryInvitationRepositoryMySQL,
+        UserRepositoryMySQL,
+    >,
+    respond_library_invitation_usecase:
+        RespondLibraryInvitationUseCaseV1<LibraryInvitationRepositoryMySQL, UserRepositoryMySQL>,
+}
+
+impl LibraryInvitationControllerV1 {
+    pub fn new(
+        library_invitation_repository: LibraryInvitationRepositoryMySQL,
+        user_repository: UserRepositoryMySQL,
+    ) -> Self {
+        LibraryInvitationControllerV1 {
+            find_all_library_invitations_from_user_usecase:
+                FindAllLibraryInvitationsFromUserUseCaseV1::new(
+                    library_invitation_repository.clone(),
+                    user_repository.clone(),
+                ),
+            respond_library_invitation_usecase: RespondLibraryInvitationUseCaseV1::new(
+                library_invitation_repository.clone(),
+                user_repository.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "invitations",
+    summary = "List the pending invitations of the user",
+    responses(
+        (status = 200, description = "Invitations", body = FindAllLibraryInvitationsDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn get_all_library_invitations_from_user(
+    library_invitation_controller: web::Data<LibraryInvitationControllerV1>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match library_invitation_controller
+        .find_all_library_invitations_from_user_usecase
+        .find_all_library_invitations_from_user(authed_user.id.unwrap())
+        .await
+    {
+        Ok(invitations) => HttpResponse::Ok().json(web::Json(FindAllLibraryInvitationsDto {
+            invitations: invitations
+                .into_iter()
+                .map(LibraryInvitationDto::from)
+                .collect(),
+        })),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "invitations",
+    summary = "Accept an invitation to a library",
+    params(
+        ("invitation_id" = u64, Path, description = "Id of the invitation"),
+    ),
+    responses(
+        (status = 200, description = "Invitation accepted"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Invitation not found", body = SimpleAPIError),
+    ),
+)]
+#[post("/{invitation_id}/accept")]
+async fn accept_library_invitation(
+    library_invitation_controller: web::Data<LibraryInvitationControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match library_invitation_controller
+        .respond_library_invitation_usecase
+        .accept_library_invitation(path_variables.into_inner(), authed_user.id.unwrap())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "invitations",
+    summary = "Decline an invitation to a library",
+    params(
+        ("invitation_id" = u64, Path, description = "Id of the invitation"),
+    ),
+    responses(
+        (status = 200, description = "Invitation declined"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Invitation not found", body = SimpleAPIError),
+    ),
+)]
+#[post("/{invitation_id}/decline")]
+async fn decline_library_invitation(
+    library_invitation_controller: web::Data<LibraryInvitationControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match library_invitation_controller
+        .respond_library_invitation_usecase
+        .decline_library_invitation(path_variables.into_inner(), authed_user.id.unwrap())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_all_library_invitations_from_user,
+    accept_library_invitation,
+    decline_library_invitation
+))]
+pub struct LibraryInvitationApiV1;
+
+pub fn get_library_invitation_scope() -> Scope {
+    web::scope("/v1/invitations")
+        .service(get_all_library_invitations_from_user)
+        .service(accept_library_invitation)
+        .service(decline_library_invitation)
+}
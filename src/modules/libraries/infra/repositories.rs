@@ -0,0 +1,4 @@
+pub mod library_invitation_repository;
+pub mod library_invitation_repository_mysql;
+pub mod library_repository;
+pub mod library_repository_mysql;
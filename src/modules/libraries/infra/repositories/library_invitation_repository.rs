@@ -0,0 +1,29 @@
+use crate::modules::libraries::domain::entities::library_invitation::LibraryInvitation;
+use sqlx::Error;
+use std::future::Future;
+
+pub trait LibraryInvitationRepository {
+    fn save(
+        &self,
+        invitation: &LibraryInvitation,
+    ) -> impl Future<Output = Result<Option<LibraryInvitation>, Error>> + Send;
+    fn find_by_id(
+        &self,
+        id: u64,
+    ) -> impl Future<Output = Result<Option<LibraryInvitation>, Error>> + Send;
+    fn find_pending_by_library_id_and_email(
+        &self,
+        library_id: u64,
+        email: &str,
+    ) -> impl Future<Output = Result<Option<LibraryInvitation>, Error>> + Send;
+    fn find_all_pending_by_email(
+        &self,
+        email: &str,
+    ) -> impl Future<Output = Result<Vec<LibraryInvitation>, Error>> + Send;
+    fn accept(
+        &self,
+        invitation: &LibraryInvitation,
+        user_id: u64,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn decline(&self, id: u64) -> impl Future<Output = Result<(), Error>> + Send;
+}
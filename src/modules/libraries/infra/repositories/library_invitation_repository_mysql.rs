@@ -0,0 +1,159 @@
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, MySqlPool, Row};
+use std::sync::Arc;
+
+use crate::modules::libraries::domain::entities::{
+    library_invitation::{LibraryInvitation, LibraryInvitationStatus},
+    library_role::LibraryRole,
+};
+
+use super::library_invitation_repository::LibraryInvitationRepository;
+
+#[derive(Clone)]
+pub struct LibraryInvitationRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl LibraryInvitationRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        LibraryInvitationRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn library_invitation_from_row(row: &MySqlRow) -> LibraryInvitation {
+    LibraryInvitation {
+        id: Some(row.get("id")),
+        library_id: row.get("library_id"),
+        email: row.get("email"),
+        role: LibraryRole::try_from(row.get::<String, &str>("role").as_str())
+            .unwrap_or(LibraryRole::Viewer),
+        invited_by: row.get("invited_by"),
+        status: LibraryInvitationStatus::try_from(row.get::<String, &str>("status").as_str())
+            .unwrap_or(LibraryInvitationStatus::Pending),
+        created_at: Some(row.get::<DateTime<Utc>, &str>("created_at")),
+        responded_at: row.get::<Option<DateTime<Utc>>, &str>("responded_at"),
+    }
+}
+
+impl LibraryInvitationRepository for LibraryInvitationRepositoryMySQL {
+    async fn save(
+        &self,
+        invitation: &LibraryInvitation,
+    ) -> Result<Option<LibraryInvitation>, sqlx::Error> {
+        let insert_result = sqlx::query(
+            r#"
+            INSERT INTO library_invitations (id, library_id, email, role, invited_by, status)
+            VALUES (DEFAULT, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(invitation.library_id)
+        .bind(&invitation.email)
+        .bind(invitation.role.as_str())
+        .bind(invitation.invited_by)
+        .bind(invitation.status.as_str())
+        .execute(self.connection.as_ref())
+        .await;
+        match insert_result {
+            Ok(result) => {
+                let new_invitation_id = result.last_insert_id();
+                tracing::info!("Generated library invitation ID: {}", new_invitation_id);
+                self.find_by_id(new_invitation_id).await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn find_by_id(&self, id: u64) -> Result<Option<LibraryInvitation>, sqlx::Error> {
+        let query_result = sqlx::query("SELECT * FROM library_invitations i WHERE i.id = ?")
+            .bind(id)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(library_invitation_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_pending_by_library_id_and_email(
+        &self,
+        library_id: u64,
+        email: &str,
+    ) -> Result<Option<LibraryInvitation>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT * FROM library_invitations i
+            WHERE i.library_id = ? AND i.email = ? AND i.status = 'pending'
+            LIMIT 1
+            "#,
+        )
+        .bind(library_id)
+        .bind(email)
+        .fetch_optional(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(library_invitation_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_pending_by_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<LibraryInvitation>, sqlx::Error> {
+        let query_result = sqlx::query(
+            "SELECT * FROM library_invitations i WHERE i.email = ? AND i.status = 'pending' ORDER BY i.id DESC",
+        )
+        .bind(email)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(library_invitation_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn accept(
+        &self,
+        invitation: &LibraryInvitation,
+        user_id: u64,
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+        sqlx::query(
+            "UPDATE library_invitations SET status = ?, responded_at = CURRENT_TIMESTAMP WHERE id = ?",
+        )
+        .bind(LibraryInvitationStatus::Accepted.as_str())
+        .bind(invitation.id)
+        .execute(&mut *transaction)
+        .await?;
+        // Members accepting a newer invitation take its role, the owner
+        // always keeps theirs
+        sqlx::query(
+            r#"
+            INSERT INTO library_members (library_id, user_id, role) VALUES (?, ?, ?)
+            ON DUPLICATE KEY UPDATE role = IF(role = 'owner', role, VALUES(role))
+            "#,
+        )
+        .bind(invitation.library_id)
+        .bind(user_id)
+        .bind(invitation.role.as_str())
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await
+    }
+
+    async fn decline(&self, id: u64) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query(
+            "UPDATE library_invitations SET status = ?, responded_at = CURRENT_TIMESTAMP WHERE id = ?",
+        )
+        .bind(LibraryInvitationStatus::Declined.as_str())
+        .bind(id)
+        .execute(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::modules::libraries::domain::{
+    dtos::{library_dto::LibraryDto, library_member_dto::LibraryMemberDto},
+    entities::{
+        library::Library, library_role::LibraryRole, library_visibility::LibraryVisibility,
+    },
+};
+use sqlx::Error;
+use std::future::Future;
+
+pub trait LibraryRepository {
+    fn save(
+        &self,
+        library: &Library,
+    ) -> impl Future<Output = Result<Option<Library>, Error>> + Send;
+    fn find_by_id(&self, id: u64) -> impl Future<Output = Result<Option<Library>, Error>> + Send;
+    fn find_all_by_member_user_id(
+        &self,
+        user_id: u64,
+    ) -> impl Future<Output = Result<Vec<LibraryDto>, Error>> + Send;
+    fn find_all_by_owner_id_and_visibility(
+        &self,
+        owner_id: u64,
+        visibility: LibraryVisibility,
+    ) -> impl Future<Output = Result<Vec<Library>, Error>> + Send;
+    fn update_visibility(
+        &self,
+        library_id: u64,
+        visibility: LibraryVisibility,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    fn find_default_library_id(
+        &self,
+        user_id: u64,
+    ) -> impl Future<Output = Result<Option<u64>, Error>> + Send;
+    fn find_member_role(
+        &self,
+        library_id: u64,
+        user_id: u64,
+    ) -> impl Future<Output = Result<Option<LibraryRole>, Error>> + Send;
+    fn find_all_members(
+        &self,
+        library_id: u64,
+    ) -> impl Future<Output = Result<Vec<LibraryMemberDto>, Error>> + Send;
+    fn delete_member(
+        &self,
+        library_id: u64,
+        user_id: u64,
+    ) -> impl Future<Output = Result<bool, Error>> + Send;
+}
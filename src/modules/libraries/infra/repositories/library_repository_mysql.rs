@@ -0,0 +1,221 @@
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, MySqlPool, Row};
+use std::sync::Arc;
+
+use crate::modules::libraries::domain::{
+    dtos::{library_dto::LibraryDto, library_member_dto::LibraryMemberDto},
+    entities::{
+        library::Library, library_role::LibraryRole, library_visibility::LibraryVisibility,
+    },
+};
+
+use super::library_repository::LibraryRepository;
+
+#[derive(Clone)]
+pub struct LibraryRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl LibraryRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        LibraryRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn library_from_row(row: &MySqlRow) -> Library {
+    Library {
+        id: Some(row.get("id")),
+        name: row.get("name"),
+        owner_id: row.get("owner_id"),
+        visibility: LibraryVisibility::try_from(row.get::<String, &str>("visibility").as_str())
+            .unwrap_or_default(),
+        created_at: Some(row.get::<DateTime<Utc>, &str>("created_at")),
+    }
+}
+
+impl LibraryRepository for LibraryRepositoryMySQL {
+    async fn save(&self, library: &Library) -> Result<Option<Library>, sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+        let insert_result = sqlx::query(
+            "INSERT INTO libraries (id, name, owner_id, visibility) VALUES (DEFAULT, ?, ?, ?)",
+        )
+        .bind(&library.name)
+        .bind(library.owner_id)
+        .bind(library.visibility.as_str())
+        .execute(&mut *transaction)
+        .await?;
+        let new_library_id = insert_result.last_insert_id();
+        sqlx::query("INSERT INTO library_members (library_id, user_id, role) VALUES (?, ?, ?)")
+            .bind(new_library_id)
+            .bind(library.owner_id)
+            .bind(LibraryRole::Owner.as_str())
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+
+        tracing::info!("Generated library ID: {}", new_library_id);
+        self.find_by_id(new_library_id).await
+    }
+
+    async fn find_by_id(&self, id: u64) -> Result<Option<Library>, sqlx::Error> {
+        let query_result = sqlx::query("SELECT * FROM libraries l WHERE l.id = ?")
+            .bind(id)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(library_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_member_user_id(
+        &self,
+        user_id: u64,
+    ) -> Result<Vec<LibraryDto>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT l.id, l.name, l.owner_id, m.role, l.visibility
+            FROM libraries l
+            INNER JOIN library_members m ON m.library_id = l.id
+            WHERE m.user_id = ?
+            ORDER BY l.id
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows
+                .iter()
+                .map(|row| LibraryDto {
+                    id: row.get("id"),
+                    name: row.get("name"),
+                    owner_id: row.get("owner_id"),
+                    role: row.get("role"),
+                    visibility: row.get("visibility"),
+                })
+                .collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_owner_id_and_visibility(
+        &self,
+        owner_id: u64,
+        visibility: LibraryVisibility,
+    ) -> Result<Vec<Library>, sqlx::Error> {
+        let query_result = sqlx::query(
+            "SELECT * FROM libraries l WHERE l.owner_id = ? AND l.visibility = ? ORDER BY l.id",
+        )
+        .bind(owner_id)
+        .bind(visibility.as_str())
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(library_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn update_visibility(
+        &self,
+        library_id: u64,
+        visibility: LibraryVisibility,
+    ) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query("UPDATE libraries SET visibility = ? WHERE id = ?")
+            .bind(visibility.as_str())
+            .bind(library_id)
+            .execute(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_default_library_id(&self, user_id: u64) -> Result<Option<u64>, sqlx::Error> {
+        // Libraries owned by the user come first, so the personal library is
+        // picked even after joining other households
+        let query_result = sqlx::query(
+            r#"
+            SELECT m.library_id
+            FROM library_members m
+            WHERE m.user_id = ?
+            ORDER BY m.role = 'owner' DESC, m.library_id
+            LIMIT 1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(row) => Ok(row.map(|row| row.get("library_id"))),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_member_role(
+        &self,
+        library_id: u64,
+        user_id: u64,
+    ) -> Result<Option<LibraryRole>, sqlx::Error> {
+        let query_result = sqlx::query(
+            "SELECT m.role FROM library_members m WHERE m.library_id = ? AND m.user_id = ?",
+        )
+        .bind(library_id)
+        .bind(user_id)
+        .fetch_optional(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(row) => Ok(row.and_then(|row| {
+                LibraryRole::try_from(row.get::<String, &str>("role").as_str()).ok()
+            })),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_members(
+        &self,
+        library_id: u64,
+    ) -> Result<Vec<LibraryMemberDto>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT m.user_id, u.name, u.email, m.role
+            FROM library_members m
+            INNER JOIN users u ON u.id = m.user_id
+            WHERE m.library_id = ?
+            ORDER BY m.created_at, m.user_id
+            "#,
+        )
+        .bind(library_id)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows
+                .iter()
+                .map(|row| LibraryMemberDto {
+                    user_id: row.get("user_id"),
+                    name: row.get("name"),
+                    email: row.get("email"),
+                    role: row.get("role"),
+                })
+                .collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn delete_member(&self, library_id: u64, user_id: u64) -> Result<bool, sqlx::Error> {
+        let query_result =
+            sqlx::query("DELETE FROM library_members WHERE library_id = ? AND user_id = ?")
+                .bind(library_id)
+                .bind(user_id)
+                .execute(self.connection.as_ref())
+                .await;
+        match query_result {
+            Ok(result) => Ok(result.rows_affected() > 0),
+            Err(error) => Err(error),
+        }
+    }
+}
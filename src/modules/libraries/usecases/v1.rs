@@ -0,0 +1,9 @@
+pub mod authorize_library_member_usecase;
+pub mod create_library_invitation_usecase;
+pub mod create_library_usecase;
+pub mod find_all_libraries_from_user_usecase;
+pub mod find_all_library_invitations_from_user_usecase;
+pub mod find_all_library_members_usecase;
+pub mod remove_library_member_usecase;
+pub mod respond_library_invitation_usecase;
+pub mod update_library_visibility_usecase;
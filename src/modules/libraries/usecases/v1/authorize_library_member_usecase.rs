@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct AuthorizeLibraryMemberUseCaseV1<T>
+where
+    T: LibraryRepository,
+{
+    library_repository: Arc<T>,
+}
+
+impl AuthorizeLibraryMemberUseCaseV1<LibraryRepositoryMySQL> {
+    pub fn new(library_repository: LibraryRepositoryMySQL) -> Self {
+        Self {
+            library_repository: Arc::new(library_repository),
+        }
+    }
+
+    /// Resolves the library a request acts on, falling back to the user's
+    /// personal library, and checks the user holds at least `required_role`
+    /// in it. Libraries the user isn't a member of are reported as missing.
+    pub async fn authorize(
+        &self,
+        user_id: u64,
+        library_id: Option<u64>,
+        required_role: LibraryRole,
+    ) -> Result<u64, APIError> {
+        let library_id = match library_id {
+            Some(library_id) => library_id,
+            None => match self
+                .library_repository
+                .find_default_library_id(user_id)
+                .await
+            {
+                Ok(Some(library_id)) => library_id,
+                Ok(None) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "User doesn't belong to any library".to_string(),
+                        404,
+                    )));
+                }
+                Err(error) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError {
+                        msg: error.to_string(),
+                        code: 500,
+                    }))
+                }
+            },
+        };
+
+        match self
+            .library_repository
+            .find_member_role(library_id, user_id)
+            .await
+        {
+            Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Library not found".to_string(),
+                404,
+            ))),
+            Ok(Some(role)) if role < required_role => {
+                Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!(
+                        "This action requires the {} role in the library",
+                        required_role.as_str()
+                    ),
+                    403,
+                )))
+            }
+            Ok(Some(_)) => Ok(library_id),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    libraries::{
+        domain::entities::{library_invitation::LibraryInvitation, library_role::LibraryRole},
+        infra::repositories::{
+            library_invitation_repository::LibraryInvitationRepository,
+            library_invitation_repository_mysql::LibraryInvitationRepositoryMySQL,
+            library_repository::LibraryRepository,
+            library_repository_mysql::LibraryRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::infra::repositories::{
+        user_repository::UserRepository, user_repository_mysql::UserRepositoryMySQL,
+    },
+};
+
+use super::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1;
+
+pub struct CreateLibraryInvitationUseCaseV1<T, U, V>
+where
+    T: LibraryInvitationRepository,
+    U: LibraryRepository,
+    V: UserRepository,
+{
+    library_invitation_repository: Arc<T>,
+    library_repository: Arc<U>,
+    user_repository: Arc<V>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+}
+
+impl
+    CreateLibraryInvitationUseCaseV1<
+        LibraryInvitationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        UserRepositoryMySQL,
+    >
+{
+    pub fn new(
+        library_invitation_repository: LibraryInvitationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        user_repository: UserRepositoryMySQL,
+    ) -> Self {
+        Self {
+            library_invitation_repository: Arc::new(library_invitation_repository),
+            library_repository: Arc::new(library_repository.clone()),
+            user_repository: Arc::new(user_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn create_library_invitation(
+        &self,
+        invitation: LibraryInvitation,
+    ) -> Result<LibraryInvitation, APIError> {
+        self.authorize_library_member_usecase
+            .authorize(
+                invitation.invited_by,
+                Some(invitation.library_id),
+                LibraryRole::Owner,
+            )
+            .await?;
+
+        // Users that already have an account and are members don't need one
+        match self.user_repository.find_by_email(&invitation.email).await {
+            Ok(Some(user)) => match self
+                .library_repository
+                .find_member_role(invitation.library_id, user.id.unwrap_or_default())
+                .await
+            {
+                Ok(Some(_)) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "User is already a member of this library".to_string(),
+                        409,
+                    )));
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError {
+                        msg: error.to_string(),
+                        code: 500,
+                    }))
+                }
+            },
+            Ok(None) => {}
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self
+            .library_invitation_repository
+            .find_pending_by_library_id_and_email(invitation.library_id, &invitation.email)
+            .await
+        {
+            Ok(Some(_)) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "There is already a pending invitation for this e-mail".to_string(),
+                    409,
+                )));
+            }
+            Ok(None) => {}
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self.library_invitation_repository.save(&invitation).await {
+            Ok(Some(invitation)) => Ok(invitation),
+            Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Failed to load created invitation info".to_string(),
+                500,
+            ))),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
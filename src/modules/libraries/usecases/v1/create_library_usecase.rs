@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    libraries::{
+        domain::entities::library::Library,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct CreateLibraryUseCaseV1<T>
+where
+    T: LibraryRepository,
+{
+    library_repository: Arc<T>,
+}
+
+impl CreateLibraryUseCaseV1<LibraryRepositoryMySQL> {
+    pub fn new(library_repository: LibraryRepositoryMySQL) -> Self {
+        Self {
+            library_repository: Arc::new(library_repository),
+        }
+    }
+
+    pub async fn create_library(
+        &self,
+        library_to_be_created: Library,
+    ) -> Result<Library, APIError> {
+        match self.library_repository.save(&library_to_be_created).await {
+            Ok(Some(library)) => Ok(library),
+            Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Failed to load created library info".to_string(),
+                500,
+            ))),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    libraries::{
+        domain::dtos::library_dto::LibraryDto,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct FindAllLibrariesFromUserUseCaseV1<T>
+where
+    T: LibraryRepository,
+{
+    library_repository: Arc<T>,
+}
+
+impl FindAllLibrariesFromUserUseCaseV1<LibraryRepositoryMySQL> {
+    pub fn new(library_repository: LibraryRepositoryMySQL) -> Self {
+        Self {
+            library_repository: Arc::new(library_repository),
+        }
+    }
+
+    pub async fn find_all_libraries_from_user(
+        &self,
+        authed_user_id: u64,
+    ) -> Result<Vec<LibraryDto>, APIError> {
+        match self
+            .library_repository
+            .find_all_by_member_user_id(authed_user_id)
+            .await
+        {
+            Ok(libraries) => Ok(libraries),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
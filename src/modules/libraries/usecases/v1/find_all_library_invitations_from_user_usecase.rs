@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    libraries::{
+        domain::entities::library_invitation::LibraryInvitation,
+        infra::repositories::{
+            library_invitation_repository::LibraryInvitationRepository,
+            library_invitation_repository_mysql::LibraryInvitationRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::infra::repositories::{
+        user_repository::UserRepository, user_repository_mysql::UserRepositoryMySQL,
+    },
+};
+
+pub struct FindAllLibraryInvitationsFromUserUseCaseV1<T, U>
+where
+    T: LibraryInvitationRepository,
+    U: UserRepository,
+{
+    library_invitation_repository: Arc<T>,
+    user_repository: Arc<U>,
+}
+
+impl
+    FindAllLibraryInvitationsFromUserUseCaseV1<
+        LibraryInvitationRepositoryMySQL,
+        UserRepositoryMySQL,
+    >
+{
+    pub fn new(
+        library_invitation_repository: LibraryInvitationRepositoryMySQL,
+        user_repository: UserRepositoryMySQL,
+    ) -> Self {
+        Self {
+            library_invitation_repository: Arc::new(library_invitation_repository),
+            user_repository: Arc::new(user_repository),
+        }
+    }
+
+    pub async fn find_all_library_invitations_from_user(
+        &self,
+        authed_user_id: u64,
+    ) -> Result<Vec<LibraryInvitation>, APIError> {
+        let email = match self.user_repository.find_by_id(authed_user_id).await {
+            Ok(Some(user)) => user.email.to_lowercase(),
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "User not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        match self
+            .library_invitation_repository
+            .find_all_pending_by_email(&email)
+            .await
+        {
+            Ok(invitations) => Ok(invitations),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
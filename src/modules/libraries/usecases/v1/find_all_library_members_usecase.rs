@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    libraries::{
+        domain::{dtos::library_member_dto::LibraryMemberDto, entities::library_role::LibraryRole},
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+use super::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1;
+
+pub struct FindAllLibraryMembersUseCaseV1<T>
+where
+    T: LibraryRepository,
+{
+    library_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<T>,
+}
+
+impl FindAllLibraryMembersUseCaseV1<LibraryRepositoryMySQL> {
+    pub fn new(library_repository: LibraryRepositoryMySQL) -> Self {
+        Self {
+            library_repository: Arc::new(library_repository.clone()),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn find_all_library_members(
+        &self,
+        library_id: u64,
+        authed_user_id: u64,
+    ) -> Result<Vec<LibraryMemberDto>, APIError> {
+        self.authorize_library_member_usecase
+            .authorize(authed_user_id, Some(library_id), LibraryRole::Viewer)
+            .await?;
+
+        match self.library_repository.find_all_members(library_id).await {
+            Ok(members) => Ok(members),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+use super::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1;
+
+pub struct RemoveLibraryMemberUseCaseV1<T>
+where
+    T: LibraryRepository,
+{
+    library_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<T>,
+}
+
+impl RemoveLibraryMemberUseCaseV1<LibraryRepositoryMySQL> {
+    pub fn new(library_repository: LibraryRepositoryMySQL) -> Self {
+        Self {
+            library_repository: Arc::new(library_repository.clone()),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn remove_library_member(
+        &self,
+        library_id: u64,
+        member_user_id: u64,
+        authed_user_id: u64,
+    ) -> Result<(), APIError> {
+        let required_role = if member_user_id == authed_user_id {
+            LibraryRole::Viewer
+        } else {
+            LibraryRole::Owner
+        };
+        self.authorize_library_member_usecase
+            .authorize(authed_user_id, Some(library_id), required_role)
+            .await?;
+
+        match self
+            .library_repository
+            .find_member_role(library_id, member_user_id)
+            .await
+        {
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Member not found".to_string(),
+                    404,
+                )));
+            }
+            Ok(Some(LibraryRole::Owner)) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "The owner can't be removed from the library".to_string(),
+                    409,
+                )));
+            }
+            Ok(Some(_)) => {}
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self
+            .library_repository
+            .delete_member(library_id, member_user_id)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    libraries::{
+        domain::entities::library_invitation::{LibraryInvitation, LibraryInvitationStatus},
+        infra::repositories::{
+            library_invitation_repository::LibraryInvitationRepository,
+            library_invitation_repository_mysql::LibraryInvitationRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::infra::repositories::{
+        user_repository::UserRepository, user_repository_mysql::UserRepositoryMySQL,
+    },
+};
+
+pub struct RespondLibraryInvitationUseCaseV1<T, U>
+where
+    T: LibraryInvitationRepository,
+    U: UserRepository,
+{
+    library_invitation_repository: Arc<T>,
+    user_repository: Arc<U>,
+}
+
+impl RespondLibraryInvitationUseCaseV1<LibraryInvitationRepositoryMySQL, UserRepositoryMySQL> {
+    pub fn new(
+        library_invitation_repository: LibraryInvitationRepositoryMySQL,
+        user_repository: UserRepositoryMySQL,
+    ) -> Self {
+        Self {
+            library_invitation_repository: Arc::new(library_invitation_repository),
+            user_repository: Arc::new(user_repository),
+        }
+    }
+
+    pub async fn accept_library_invitation(
+        &self,
+        invitation_id: u64,
+        authed_user_id: u64,
+    ) -> Result<(), APIError> {
+        let invitation = self
+            .find_pending_invitation(invitation_id, authed_user_id)
+            .await?;
+        match self
+            .library_invitation_repository
+            .accept(&invitation, authed_user_id)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+
+    pub async fn decline_library_invitation(
+        &self,
+        invitation_id: u64,
+        authed_user_id: u64,
+    ) -> Result<(), APIError> {
+        self.find_pending_invitation(invitation_id, authed_user_id)
+            .await?;
+        match self
+            .library_invitation_repository
+            .decline(invitation_id)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+
+    async fn find_pending_invitation(
+        &self,
+        invitation_id: u64,
+        authed_user_id: u64,
+    ) -> Result<LibraryInvitation, APIError> {
+        let email = match self.user_repository.find_by_id(authed_user_id).await {
+            Ok(Some(user)) => user.email.to_lowercase(),
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "User not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        match self
+            .library_invitation_repository
+            .find_by_id(invitation_id)
+            .await
+        {
+            Ok(Some(invitation)) if invitation.email != email => Err(APIError::SimpleAPIError(
+                SimpleAPIError::new("Invitation not found".to_string(), 404),
+            )),
+            Ok(Some(invitation)) if invitation.status != LibraryInvitationStatus::Pending => {
+                Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Invitation was already answered".to_string(),
+                    409,
+                )))
+            }
+            Ok(Some(invitation)) => Ok(invitation),
+            Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Invitation not found".to_string(),
+                404,
+            ))),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
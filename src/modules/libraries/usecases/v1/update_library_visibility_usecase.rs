@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    libraries::{
+        domain::entities::{library_role::LibraryRole, library_visibility::LibraryVisibility},
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+use super::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1;
+
+pub struct UpdateLibraryVisibilityUseCaseV1<T>
+where
+    T: LibraryRepository,
+{
+    library_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<T>,
+}
+
+impl UpdateLibraryVisibilityUseCaseV1<LibraryRepositoryMySQL> {
+    pub fn new(library_repository: LibraryRepositoryMySQL) -> Self {
+        Self {
+            library_repository: Arc::new(library_repository.clone()),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn update_library_visibility(
+        &self,
+        library_id: u64,
+        visibility: LibraryVisibility,
+        authed_user_id: u64,
+    ) -> Result<(), APIError> {
+        self.authorize_library_member_usecase
+            .authorize(authed_user_id, Some(library_id), LibraryRole::Owner)
+            .await?;
+
+        match self
+            .library_repository
+            .update_visibility(library_id, visibility)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod borrow_request_dto;
+pub mod create_borrow_request_dto;
+pub mod find_all_borrow_requests_dto;
+pub mod find_all_loans_dto;
+pub mod loan_dto;
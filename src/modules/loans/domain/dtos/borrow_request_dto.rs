@@ -0,0 +1,17 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct BorrowRequestDto {
+    pub id: u64,
+    pub book_id: u64,
+    pub book_title: String,
+    pub requester_id: u64,
+    pub requester_name: String,
+    pub owner_id: u64,
+    pub owner_name: String,
+    pub status: String,
+    pub message: Option<String>,
+    pub created_at: Option<String>,
+    pub responded_at: Option<String>,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct CreateBorrowRequestDto {
+    pub book_id: Option<u64>,
+    pub message: Option<String>,
+    pub requester_id: Option<u64>,
+}
@@ -0,0 +1,10 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::borrow_request_dto::BorrowRequestDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FindAllBorrowRequestsDto {
+    pub incoming: Vec<BorrowRequestDto>,
+    pub outgoing: Vec<BorrowRequestDto>,
+}
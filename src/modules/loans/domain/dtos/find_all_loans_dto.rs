@@ -0,0 +1,10 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::loan_dto::LoanDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FindAllLoansDto {
+    pub lent: Vec<LoanDto>,
+    pub borrowed: Vec<LoanDto>,
+}
@@ -0,0 +1,18 @@
+use async_graphql::SimpleObject;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Clone, Serialize, ToSchema, SimpleObject)]
+#[graphql(name = "Loan")]
+pub struct LoanDto {
+    pub id: u64,
+    pub book_id: u64,
+    pub book_title: String,
+    pub library_id: u64,
+    pub lender_id: u64,
+    pub lender_name: String,
+    pub borrower_id: u64,
+    pub borrower_name: String,
+    pub loaned_at: Option<String>,
+    pub returned_at: Option<String>,
+}
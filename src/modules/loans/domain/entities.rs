@@ -0,0 +1,2 @@
+pub mod borrow_request;
+pub mod loan;
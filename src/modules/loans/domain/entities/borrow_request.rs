@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+
+pub const MAX_BORROW_REQUEST_MESSAGE_LENGTH: usize = 500;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BorrowRequestStatus {
+    #[default]
+    Pending,
+    Approved,
+    Declined,
+    Cancelled,
+}
+
+impl BorrowRequestStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BorrowRequestStatus::Pending => "pending",
+            BorrowRequestStatus::Approved => "approved",
+            BorrowRequestStatus::Declined => "declined",
+            BorrowRequestStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+impl TryFrom<&str> for BorrowRequestStatus {
+    type Error = String;
+
+    fn try_from(status: &str) -> Result<Self, Self::Error> {
+        match status {
+            "pending" => Ok(BorrowRequestStatus::Pending),
+            "approved" => Ok(BorrowRequestStatus::Approved),
+            "declined" => Ok(BorrowRequestStatus::Declined),
+            "cancelled" => Ok(BorrowRequestStatus::Cancelled),
+            _ => Err(format!("Unknown borrow request status {}", status)),
+        }
+    }
+}
+
+/// Request from an user to borrow a book from a friend's library. The owner
+/// is the owner of the library holding the book when the request was made.
+#[derive(Debug, Default)]
+pub struct BorrowRequest {
+    pub id: Option<u64>,
+    pub book_id: u64,
+    pub library_id: u64,
+    pub requester_id: u64,
+    pub owner_id: u64,
+    pub status: BorrowRequestStatus,
+    pub message: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub responded_at: Option<DateTime<Utc>>,
+}
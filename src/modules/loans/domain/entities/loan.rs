@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug)]
+pub struct Loan {
+    pub id: Option<u64>,
+    pub book_id: u64,
+    pub borrow_request_id: u64,
+    pub lender_id: u64,
+    pub borrower_id: u64,
+    pub loaned_at: Option<DateTime<Utc>>,
+    pub returned_at: Option<DateTime<Utc>>,
+}
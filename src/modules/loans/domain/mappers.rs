@@ -0,0 +1,3 @@
+pub mod borrow_request_dto_mapper;
+pub mod create_borrow_request_dto_mapper;
+pub mod loan_dto_mapper;
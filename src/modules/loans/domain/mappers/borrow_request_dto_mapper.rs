@@ -0,0 +1,19 @@
+use crate::modules::loans::domain::{
+    dtos::borrow_request_dto::BorrowRequestDto, entities::borrow_request::BorrowRequest,
+};
+
+impl From<BorrowRequest> for BorrowRequestDto {
+    fn from(borrow_request: BorrowRequest) -> Self {
+        BorrowRequestDto {
+            id: borrow_request.id.unwrap_or_default(),
+            book_id: borrow_request.book_id,
+            requester_id: borrow_request.requester_id,
+            owner_id: borrow_request.owner_id,
+            status: borrow_request.status.as_str().to_string(),
+            message: borrow_request.message,
+            created_at: borrow_request.created_at.map(|date| date.to_rfc3339()),
+            responded_at: borrow_request.responded_at.map(|date| date.to_rfc3339()),
+            ..Default::default()
+        }
+    }
+}
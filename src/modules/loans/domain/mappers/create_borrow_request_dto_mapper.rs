@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    loans::domain::{
+        dtos::create_borrow_request_dto::CreateBorrowRequestDto,
+        entities::borrow_request::{BorrowRequest, MAX_BORROW_REQUEST_MESSAGE_LENGTH},
+    },
+    shared::errors::detailed_api_error::DetailedAPIError,
+};
+
+impl TryFrom<CreateBorrowRequestDto> for BorrowRequest {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: CreateBorrowRequestDto) -> Result<Self, Self::Error> {
+        let mut borrow_request = BorrowRequest::default();
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        match dto.book_id {
+            Some(book_id) => borrow_request.book_id = book_id,
+            None => {
+                validations.insert(
+                    "book_id".to_string(),
+                    "The requested book must be informed".to_string(),
+                );
+            }
+        }
+
+        match dto.message.map(|message| message.trim().to_string()) {
+            Some(message) if message.chars().count() > MAX_BORROW_REQUEST_MESSAGE_LENGTH => {
+                validations.insert(
+                    "message".to_string(),
+                    format!(
+                        "Message must have at most {} characters",
+                        MAX_BORROW_REQUEST_MESSAGE_LENGTH
+                    ),
+                );
+            }
+            Some(message) if !message.is_empty() => borrow_request.message = Some(message),
+            _ => {}
+        }
+
+        match dto.requester_id {
+            Some(requester_id) => borrow_request.requester_id = requester_id,
+            None => {
+                validations.insert(
+                    "requester_id".to_string(),
+                    "Borrow request must be made by an user".to_string(),
+                );
+            }
+        }
+
+        if !validations.is_empty() {
+            return Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            });
+        }
+        Ok(borrow_request)
+    }
+}
@@ -0,0 +1,15 @@
+use crate::modules::loans::domain::{dtos::loan_dto::LoanDto, entities::loan::Loan};
+
+impl From<Loan> for LoanDto {
+    fn from(loan: Loan) -> Self {
+        LoanDto {
+            id: loan.id.unwrap_or_default(),
+            book_id: loan.book_id,
+            lender_id: loan.lender_id,
+            borrower_id: loan.borrower_id,
+            loaned_at: loan.loaned_at.map(|date| date.to_rfc3339()),
+            returned_at: loan.returned_at.map(|date| date.to_rfc3339()),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod borrow_request_controller_v1;
+pub mod loan_controller_v1;
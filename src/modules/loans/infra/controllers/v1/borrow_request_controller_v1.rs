@@ -0,0 +1,278 @@
+use crate::modules::{
+    books::infra::repositories::book_repository_mysql::BookRepositoryMySQL,
+    friends::infra::repositories::friendship_repository_mysql::FriendshipRepositoryMySQL,
+    libraries::infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    loans::{
+        domain::{
+            dtos::{
+                borrow_request_dto::BorrowRequestDto,
+                create_borrow_request_dto::CreateBorrowRequestDto,
+                find_all_borrow_requests_dto::FindAllBorrowRequestsDto, loan_dto::LoanDto,
+            },
+            entities::borrow_request::BorrowRequest,
+        },
+        infra::repositories::{
+            borrow_request_repository_mysql::BorrowRequestRepositoryMySQL,
+            loan_repository_mysql::LoanRepositoryMySQL,
+        },
+        usecases::v1::{
+            create_borrow_request_usecase::CreateBorrowRequestUseCaseV1,
+            find_all_borrow_requests_from_user_usecase::FindAllBorrowRequestsFromUserUseCaseV1,
+            respond_borrow_request_usecase::RespondBorrowRequestUseCaseV1,
+        },
+    },
+    shared::errors::{
+        detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+    },
+    users::domain::dtos::authed_user::AuthedUser,
+};
+use actix_web::{get, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct BorrowRequestControllerV1 {
+    create_borrow_request_usecase: CreateBorrowRequestUseCaseV1<
+        BorrowRequestRepositoryMySQL,
+        LoanRepositoryMySQL,
+        BookRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        FriendshipRepositoryMySQL,
+    >,
+    find_all_borrow_requests_from_user_usecase:
+        FindAllBorrowRequestsFromUserUseCaseV1<BorrowRequestRepositoryMySQL>,
+    respond_borrow_request_usecase:
+        RespondBorrowRequestUseCaseV1<BorrowRequestRepositoryMySQL, LoanRepositoryMySQL>,
+}
+
+impl BorrowRequestControllerV1 {
+    pub fn new(
+        borrow_request_repository: BorrowRequestRepositoryMySQL,
+        loan_repository: LoanRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        friendship_repository: FriendshipRepositoryMySQL,
+    ) -> Self {
+        BorrowRequestControllerV1 {
+            create_borrow_request_usecase: CreateBorrowRequestUseCaseV1::new(
+                borrow_request_repository.clone(),
+                loan_repository.clone(),
+                book_repository.clone(),
+                library_repository.clone(),
+                friendship_repository.clone(),
+            ),
+            find_all_borrow_requests_from_user_usecase: FindAllBorrowRequestsFromUserUseCaseV1::new(
+                borrow_request_repository.clone(),
+            ),
+            respond_borrow_request_usecase: RespondBorrowRequestUseCaseV1::new(
+                borrow_request_repository.clone(),
+                loan_repository.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "borrow-requests",
+    summary = "Ask to borrow a friend's book",
+    request_body = CreateBorrowRequestDto,
+    responses(
+        (status = 201, description = "Borrow request created", body = BorrowRequestDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Book not found", body = SimpleAPIError),
+        (status = 409, description = "Book is not available", body = SimpleAPIError),
+    ),
+)]
+#[post("")]
+async fn create_borrow_request(
+    borrow_request_controller: web::Data<BorrowRequestControllerV1>,
+    create_borrow_request_dto: web::Json<CreateBorrowRequestDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let mut dto = create_borrow_request_dto.into_inner();
+    dto.requester_id = authed_user.id;
+    let borrow_request = match BorrowRequest::try_from(dto) {
+        Ok(converted_borrow_request) => converted_borrow_request,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match borrow_request_controller
+        .create_borrow_request_usecase
+        .create_borrow_request(borrow_request)
+        .await
+    {
+        Ok(borrow_request) => {
+            HttpResponse::Created().json(web::Json(BorrowRequestDto::from(borrow_request)))
+        }
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "borrow-requests",
+    summary = "List the borrow requests sent and received by the user",
+    responses(
+        (status = 200, description = "Borrow requests", body = FindAllBorrowRequestsDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn get_all_borrow_requests_from_user(
+    borrow_request_controller: web::Data<BorrowRequestControllerV1>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match borrow_request_controller
+        .find_all_borrow_requests_from_user_usecase
+        .find_all_borrow_requests_from_user(authed_user.id.unwrap())
+        .await
+    {
+        Ok(borrow_requests) => HttpResponse::Ok().json(web::Json(borrow_requests)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "borrow-requests",
+    summary = "Approve a borrow request, lending the book",
+    params(
+        ("borrow_request_id" = u64, Path, description = "Id of the borrow request"),
+    ),
+    responses(
+        (status = 201, description = "Loan created", body = LoanDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Borrow request not found", body = SimpleAPIError),
+        (status = 409, description = "Borrow request is no longer pending", body = SimpleAPIError),
+    ),
+)]
+#[post("/{borrow_request_id}/approve")]
+async fn approve_borrow_request(
+    borrow_request_controller: web::Data<BorrowRequestControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match borrow_request_controller
+        .respond_borrow_request_usecase
+        .approve_borrow_request(path_variables.into_inner(), authed_user.id.unwrap())
+        .await
+    {
+        Ok(loan) => HttpResponse::Created().json(web::Json(LoanDto::from(loan))),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "borrow-requests",
+    summary = "Decline a borrow request",
+    params(
+        ("borrow_request_id" = u64, Path, description = "Id of the borrow request"),
+    ),
+    responses(
+        (status = 200, description = "Borrow request declined"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Borrow request not found", body = SimpleAPIError),
+        (status = 409, description = "Borrow request is no longer pending", body = SimpleAPIError),
+    ),
+)]
+#[post("/{borrow_request_id}/decline")]
+async fn decline_borrow_request(
+    borrow_request_controller: web::Data<BorrowRequestControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match borrow_request_controller
+        .respond_borrow_request_usecase
+        .decline_borrow_request(path_variables.into_inner(), authed_user.id.unwrap())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "borrow-requests",
+    summary = "Cancel a borrow request",
+    params(
+        ("borrow_request_id" = u64, Path, description = "Id of the borrow request"),
+    ),
+    responses(
+        (status = 200, description = "Borrow request cancelled"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Borrow request not found", body = SimpleAPIError),
+        (status = 409, description = "Borrow request is no longer pending", body = SimpleAPIError),
+    ),
+)]
+#[post("/{borrow_request_id}/cancel")]
+async fn cancel_borrow_request(
+    borrow_request_controller: web::Data<BorrowRequestControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match borrow_request_controller
+        .respond_borrow_request_usecase
+        .cancel_borrow_request(path_variables.into_inner(), authed_user.id.unwrap())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    create_borrow_request,
+    get_all_borrow_requests_from_user,
+    approve_borrow_request,
+    decline_borrow_request,
+    cancel_borrow_request
+))]
+pub struct BorrowRequestApiV1;
+
+pub fn get_borrow_request_scope() -> Scope {
+    web::scope("/v1/borrow-requests")
+        .service(create_borrow_request)
+        .service(get_all_borrow_requests_from_user)
+        .service(approve_borrow_request)
+        .service(decline_borrow_request)
+        .service(cancel_borrow_request)
+}
@@ -0,0 +1,107 @@
+use crate::modules::{
+    loans::{
+        domain::dtos::find_all_loans_dto::FindAllLoansDto,
+        infra::repositories::loan_repository_mysql::LoanRepositoryMySQL,
+        usecases::v1::{
+            find_all_loans_from_user_usecase::FindAllLoansFromUserUseCaseV1,
+            return_loan_usecase::ReturnLoanUseCaseV1,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::domain::dtos::authed_user::AuthedUser,
+};
+use actix_web::{get, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct LoanControllerV1 {
+    find_all_loans_from_user_usecase: FindAllLoansFromUserUseCaseV1<LoanRepositoryMySQL>,
+    return_loan_usecase: ReturnLoanUseCaseV1<LoanRepositoryMySQL>,
+}
+
+impl LoanControllerV1 {
+    pub fn new(loan_repository: LoanRepositoryMySQL) -> Self {
+        LoanControllerV1 {
+            find_all_loans_from_user_usecase: FindAllLoansFromUserUseCaseV1::new(
+                loan_repository.clone(),
+            ),
+            return_loan_usecase: ReturnLoanUseCaseV1::new(loan_repository.clone()),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "loans",
+    summary = "List the loans the user is lender or borrower of",
+    responses(
+        (status = 200, description = "Loans", body = FindAllLoansDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn get_all_loans_from_user(
+    loan_controller: web::Data<LoanControllerV1>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match loan_controller
+        .find_all_loans_from_user_usecase
+        .find_all_loans_from_user(authed_user.id.unwrap())
+        .await
+    {
+        Ok(loans) => HttpResponse::Ok().json(web::Json(loans)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "loans",
+    summary = "Mark a lent book as returned",
+    params(
+        ("loan_id" = u64, Path, description = "Id of the loan"),
+    ),
+    responses(
+        (status = 200, description = "Loan returned"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "Loan not found", body = SimpleAPIError),
+        (status = 409, description = "Loan was already returned", body = SimpleAPIError),
+    ),
+)]
+#[post("/{loan_id}/return")]
+async fn return_loan(
+    loan_controller: web::Data<LoanControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match loan_controller
+        .return_loan_usecase
+        .return_loan(path_variables.into_inner(), authed_user.id.unwrap())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(get_all_loans_from_user, return_loan))]
+pub struct LoanApiV1;
+
+pub fn get_loan_scope() -> Scope {
+    web::scope("/v1/loans")
+        .service(get_all_loans_from_user)
+        .service(return_loan)
+}
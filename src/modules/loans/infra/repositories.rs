@@ -0,0 +1,4 @@
+pub mod borrow_request_repository;
+pub mod borrow_request_repository_mysql;
+pub mod loan_repository;
+pub mod loan_repository_mysql;
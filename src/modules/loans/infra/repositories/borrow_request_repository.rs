@@ -0,0 +1,38 @@
+use crate::modules::loans::domain::{
+    dtos::borrow_request_dto::BorrowRequestDto,
+    entities::borrow_request::{BorrowRequest, BorrowRequestStatus},
+};
+use sqlx::Error;
+use std::future::Future;
+
+pub trait BorrowRequestRepository {
+    fn save(
+        &self,
+        borrow_request: &BorrowRequest,
+    ) -> impl Future<Output = Result<Option<BorrowRequest>, Error>> + Send;
+    fn find_by_id(
+        &self,
+        id: u64,
+    ) -> impl Future<Output = Result<Option<BorrowRequest>, Error>> + Send;
+    fn find_pending_by_book_id_and_requester_id(
+        &self,
+        book_id: u64,
+        requester_id: u64,
+    ) -> impl Future<Output = Result<Option<BorrowRequest>, Error>> + Send;
+    fn find_all_by_user_id(
+        &self,
+        user_id: u64,
+    ) -> impl Future<Output = Result<Vec<BorrowRequestDto>, Error>> + Send;
+    fn update_status(
+        &self,
+        id: u64,
+        status: BorrowRequestStatus,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+    /// Approves a pending request and opens the loan in a single
+    /// transaction. Returns the id of the new loan, or `None` when the book
+    /// is already lent or the request was answered in the meantime.
+    fn approve(
+        &self,
+        borrow_request: &BorrowRequest,
+    ) -> impl Future<Output = Result<Option<u64>, Error>> + Send;
+}
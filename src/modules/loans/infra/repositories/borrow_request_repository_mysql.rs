@@ -0,0 +1,200 @@
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, MySqlPool, Row};
+use std::sync::Arc;
+
+use crate::modules::loans::domain::{
+    dtos::borrow_request_dto::BorrowRequestDto,
+    entities::borrow_request::{BorrowRequest, BorrowRequestStatus},
+};
+
+use super::borrow_request_repository::BorrowRequestRepository;
+
+#[derive(Clone)]
+pub struct BorrowRequestRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl BorrowRequestRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        BorrowRequestRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn borrow_request_from_row(row: &MySqlRow) -> BorrowRequest {
+    BorrowRequest {
+        id: Some(row.get("id")),
+        book_id: row.get("book_id"),
+        library_id: row.get("library_id"),
+        requester_id: row.get("requester_id"),
+        owner_id: row.get("owner_id"),
+        status: BorrowRequestStatus::try_from(row.get::<String, &str>("status").as_str())
+            .unwrap_or_default(),
+        message: row.get("message"),
+        created_at: Some(row.get::<DateTime<Utc>, &str>("created_at")),
+        responded_at: row.get::<Option<DateTime<Utc>>, &str>("responded_at"),
+    }
+}
+
+impl BorrowRequestRepository for BorrowRequestRepositoryMySQL {
+    async fn save(
+        &self,
+        borrow_request: &BorrowRequest,
+    ) -> Result<Option<BorrowRequest>, sqlx::Error> {
+        let insert_result = sqlx::query(
+            r#"
+            INSERT INTO borrow_requests
+                (id, book_id, library_id, requester_id, owner_id, status, message)
+            VALUES (DEFAULT, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(borrow_request.book_id)
+        .bind(borrow_request.library_id)
+        .bind(borrow_request.requester_id)
+        .bind(borrow_request.owner_id)
+        .bind(borrow_request.status.as_str())
+        .bind(&borrow_request.message)
+        .execute(self.connection.as_ref())
+        .await;
+        match insert_result {
+            Ok(result) => {
+                let new_borrow_request_id = result.last_insert_id();
+                tracing::info!("Generated borrow request ID: {}", new_borrow_request_id);
+                self.find_by_id(new_borrow_request_id).await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn find_by_id(&self, id: u64) -> Result<Option<BorrowRequest>, sqlx::Error> {
+        let query_result = sqlx::query("SELECT * FROM borrow_requests r WHERE r.id = ?")
+            .bind(id)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(borrow_request_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_pending_by_book_id_and_requester_id(
+        &self,
+        book_id: u64,
+        requester_id: u64,
+    ) -> Result<Option<BorrowRequest>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT * FROM borrow_requests r
+            WHERE r.book_id = ? AND r.requester_id = ? AND r.status = 'pending'
+            LIMIT 1
+            "#,
+        )
+        .bind(book_id)
+        .bind(requester_id)
+        .fetch_optional(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(borrow_request_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_user_id(
+        &self,
+        user_id: u64,
+    ) -> Result<Vec<BorrowRequestDto>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT r.*, b.title 'book_title',
+                requester.name 'requester_name', owner.name 'owner_name'
+            FROM borrow_requests r
+            INNER JOIN books b ON b.id = r.book_id
+            INNER JOIN users requester ON requester.id = r.requester_id
+            INNER JOIN users owner ON owner.id = r.owner_id
+            WHERE r.requester_id = ? OR r.owner_id = ?
+            ORDER BY r.created_at DESC, r.id DESC
+            "#,
+        )
+        .bind(user_id)
+        .bind(user_id)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows
+                .iter()
+                .map(|row| BorrowRequestDto {
+                    book_title: row.get("book_title"),
+                    requester_name: row.get("requester_name"),
+                    owner_name: row.get("owner_name"),
+                    ..BorrowRequestDto::from(borrow_request_from_row(row))
+                })
+                .collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn update_status(&self, id: u64, status: BorrowRequestStatus) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query(
+            "UPDATE borrow_requests SET status = ?, responded_at = CURRENT_TIMESTAMP WHERE id = ?",
+        )
+        .bind(status.as_str())
+        .bind(id)
+        .execute(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn approve(&self, borrow_request: &BorrowRequest) -> Result<Option<u64>, sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+
+        // Locks the open loans of the book so two requests for the same copy
+        // can't be approved at once
+        let open_loan = sqlx::query(
+            "SELECT id FROM loans WHERE book_id = ? AND returned_at IS NULL FOR UPDATE",
+        )
+        .bind(borrow_request.book_id)
+        .fetch_optional(&mut *transaction)
+        .await?;
+        if open_loan.is_some() {
+            transaction.rollback().await?;
+            return Ok(None);
+        }
+
+        let update_result = sqlx::query(
+            r#"
+            UPDATE borrow_requests SET status = ?, responded_at = CURRENT_TIMESTAMP
+            WHERE id = ? AND status = 'pending'
+            "#,
+        )
+        .bind(BorrowRequestStatus::Approved.as_str())
+        .bind(borrow_request.id)
+        .execute(&mut *transaction)
+        .await?;
+        if update_result.rows_affected() == 0 {
+            transaction.rollback().await?;
+            return Ok(None);
+        }
+
+        let insert_result = sqlx::query(
+            r#"
+            INSERT INTO loans (id, book_id, borrow_request_id, lender_id, borrower_id)
+            VALUES (DEFAULT, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(borrow_request.book_id)
+        .bind(borrow_request.id)
+        .bind(borrow_request.owner_id)
+        .bind(borrow_request.requester_id)
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+
+        let new_loan_id = insert_result.last_insert_id();
+        tracing::info!("Generated loan ID: {}", new_loan_id);
+        Ok(Some(new_loan_id))
+    }
+}
@@ -0,0 +1,27 @@
+use crate::modules::loans::domain::{dtos::loan_dto::LoanDto, entities::loan::Loan};
+use chrono::{DateTime, Utc};
+use sqlx::Error;
+use std::{collections::HashMap, future::Future};
+
+pub trait LoanRepository {
+    fn find_by_id(&self, id: u64) -> impl Future<Output = Result<Option<Loan>, Error>> + Send;
+    fn find_open_by_book_id(
+        &self,
+        book_id: u64,
+    ) -> impl Future<Output = Result<Option<Loan>, Error>> + Send;
+    fn find_all_by_user_id(
+        &self,
+        user_id: u64,
+    ) -> impl Future<Output = Result<Vec<LoanDto>, Error>> + Send;
+    fn find_all_by_book_ids_and_user_id(
+        &self,
+        book_ids: &[u64],
+        user_id: u64,
+    ) -> impl Future<Output = Result<HashMap<u64, Vec<LoanDto>>, Error>> + Send;
+    fn mark_returned(&self, id: u64) -> impl Future<Output = Result<(), Error>> + Send;
+    fn find_all_overdue(
+        &self,
+        loaned_before: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<LoanDto>, Error>> + Send;
+    fn mark_overdue_notified(&self, ids: &[u64]) -> impl Future<Output = Result<(), Error>> + Send;
+}
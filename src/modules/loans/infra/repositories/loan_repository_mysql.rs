@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, MySqlPool, Row};
+use std::{collections::HashMap, sync::Arc};
+
+use crate::modules::loans::domain::{dtos::loan_dto::LoanDto, entities::loan::Loan};
+
+use super::loan_repository::LoanRepository;
+
+#[derive(Clone)]
+pub struct LoanRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl LoanRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        LoanRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn in_clause_placeholders(n_of_items: usize) -> String {
+    vec!["?"; n_of_items].join(", ")
+}
+
+fn loan_from_row(row: &MySqlRow) -> Loan {
+    Loan {
+        id: Some(row.get("id")),
+        book_id: row.get("book_id"),
+        borrow_request_id: row.get("borrow_request_id"),
+        lender_id: row.get("lender_id"),
+        borrower_id: row.get("borrower_id"),
+        loaned_at: Some(row.get::<DateTime<Utc>, &str>("loaned_at")),
+        returned_at: row.get::<Option<DateTime<Utc>>, &str>("returned_at"),
+    }
+}
+
+fn loan_dto_from_row(row: &MySqlRow) -> LoanDto {
+    LoanDto {
+        book_title: row.get("book_title"),
+        library_id: row.get("library_id"),
+        lender_name: row.get("lender_name"),
+        borrower_name: row.get("borrower_name"),
+        ..LoanDto::from(loan_from_row(row))
+    }
+}
+
+impl LoanRepository for LoanRepositoryMySQL {
+    async fn find_by_id(&self, id: u64) -> Result<Option<Loan>, sqlx::Error> {
+        let query_result = sqlx::query("SELECT * FROM loans l WHERE l.id = ?")
+            .bind(id)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(loan_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_open_by_book_id(&self, book_id: u64) -> Result<Option<Loan>, sqlx::Error> {
+        let query_result = sqlx::query(
+            "SELECT * FROM loans l WHERE l.book_id = ? AND l.returned_at IS NULL LIMIT 1",
+        )
+        .bind(book_id)
+        .fetch_optional(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(loan_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_user_id(&self, user_id: u64) -> Result<Vec<LoanDto>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT l.*, b.title 'book_title', b.library_id,
+                lender.name 'lender_name', borrower.name 'borrower_name'
+            FROM loans l
+            INNER JOIN books b ON b.id = l.book_id
+            INNER JOIN users lender ON lender.id = l.lender_id
+            INNER JOIN users borrower ON borrower.id = l.borrower_id
+            WHERE l.lender_id = ? OR l.borrower_id = ?
+            ORDER BY l.returned_at IS NOT NULL, l.loaned_at DESC, l.id DESC
+            "#,
+        )
+        .bind(user_id)
+        .bind(user_id)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(loan_dto_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_book_ids_and_user_id(
+        &self,
+        book_ids: &[u64],
+        user_id: u64,
+    ) -> Result<HashMap<u64, Vec<LoanDto>>, sqlx::Error> {
+        let mut loans_by_book: HashMap<u64, Vec<LoanDto>> = HashMap::new();
+        if book_ids.is_empty() {
+            return Ok(loans_by_book);
+        }
+        let query = format!(
+            r#"
+            SELECT l.*, b.title 'book_title', b.library_id,
+                lender.name 'lender_name', borrower.name 'borrower_name'
+            FROM loans l
+            INNER JOIN books b ON b.id = l.book_id
+            INNER JOIN users lender ON lender.id = l.lender_id
+            INNER JOIN users borrower ON borrower.id = l.borrower_id
+            WHERE (l.lender_id = ? OR l.borrower_id = ?)
+                AND l.book_id IN ({})
+            ORDER BY l.returned_at IS NOT NULL, l.loaned_at DESC, l.id DESC
+            "#,
+            in_clause_placeholders(book_ids.len())
+        );
+        let mut query_ps = sqlx::query(&query).bind(user_id).bind(user_id);
+        for book_id in book_ids.iter() {
+            query_ps = query_ps.bind(book_id);
+        }
+        match query_ps.fetch_all(self.connection.as_ref()).await {
+            Ok(rows) => {
+                for row in rows.iter() {
+                    loans_by_book
+                        .entry(row.get("book_id"))
+                        .or_default()
+                        .push(loan_dto_from_row(row));
+                }
+                Ok(loans_by_book)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn mark_returned(&self, id: u64) -> Result<(), sqlx::Error> {
+        let query_result =
+            sqlx::query("UPDATE loans SET returned_at = CURRENT_TIMESTAMP WHERE id = ?")
+                .bind(id)
+                .execute(self.connection.as_ref())
+                .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_overdue(
+        &self,
+        loaned_before: DateTime<Utc>,
+    ) -> Result<Vec<LoanDto>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT l.*, b.title 'book_title', b.library_id,
+                lender.name 'lender_name', borrower.name 'borrower_name'
+            FROM loans l
+            INNER JOIN books b ON b.id = l.book_id
+            INNER JOIN users lender ON lender.id = l.lender_id
+            INNER JOIN users borrower ON borrower.id = l.borrower_id
+            WHERE l.returned_at IS NULL
+                AND l.overdue_notified_at IS NULL
+                AND l.loaned_at < ?
+            ORDER BY l.loaned_at ASC, l.id ASC
+            "#,
+        )
+        .bind(loaned_before)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(loan_dto_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn mark_overdue_notified(&self, ids: &[u64]) -> Result<(), sqlx::Error> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let query = format!(
+            "UPDATE loans SET overdue_notified_at = CURRENT_TIMESTAMP WHERE id IN ({})",
+            in_clause_placeholders(ids.len())
+        );
+        let mut query_ps = sqlx::query(&query);
+        for id in ids.iter() {
+            query_ps = query_ps.bind(id);
+        }
+        match query_ps.execute(self.connection.as_ref()).await {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
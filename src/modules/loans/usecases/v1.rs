@@ -0,0 +1,5 @@
+pub mod create_borrow_request_usecase;
+pub mod find_all_borrow_requests_from_user_usecase;
+pub mod find_all_loans_from_user_usecase;
+pub mod respond_borrow_request_usecase;
+pub mod return_loan_usecase;
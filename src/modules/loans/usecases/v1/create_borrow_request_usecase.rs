@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    books::infra::repositories::{
+        book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+    },
+    friends::{
+        infra::repositories::{
+            friendship_repository::FriendshipRepository,
+            friendship_repository_mysql::FriendshipRepositoryMySQL,
+        },
+        usecases::v1::authorize_friend_usecase::AuthorizeFriendUseCaseV1,
+    },
+    libraries::{
+        domain::entities::library_visibility::LibraryVisibility,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+    },
+    loans::{
+        domain::entities::borrow_request::{BorrowRequest, BorrowRequestStatus},
+        infra::repositories::{
+            borrow_request_repository::BorrowRequestRepository,
+            borrow_request_repository_mysql::BorrowRequestRepositoryMySQL,
+            loan_repository::LoanRepository, loan_repository_mysql::LoanRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct CreateBorrowRequestUseCaseV1<T, U, V, W, X>
+where
+    T: BorrowRequestRepository,
+    U: LoanRepository,
+    V: BookRepository,
+    W: LibraryRepository,
+    X: FriendshipRepository,
+{
+    borrow_request_repository: Arc<T>,
+    loan_repository: Arc<U>,
+    book_repository: Arc<V>,
+    library_repository: Arc<W>,
+    authorize_friend_usecase: AuthorizeFriendUseCaseV1<X>,
+}
+
+impl
+    CreateBorrowRequestUseCaseV1<
+        BorrowRequestRepositoryMySQL,
+        LoanRepositoryMySQL,
+        BookRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        FriendshipRepositoryMySQL,
+    >
+{
+    pub fn new(
+        borrow_request_repository: BorrowRequestRepositoryMySQL,
+        loan_repository: LoanRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        friendship_repository: FriendshipRepositoryMySQL,
+    ) -> Self {
+        Self {
+            borrow_request_repository: Arc::new(borrow_request_repository),
+            loan_repository: Arc::new(loan_repository),
+            book_repository: Arc::new(book_repository),
+            library_repository: Arc::new(library_repository),
+            authorize_friend_usecase: AuthorizeFriendUseCaseV1::new(friendship_repository),
+        }
+    }
+
+    pub async fn create_borrow_request(
+        &self,
+        mut borrow_request: BorrowRequest,
+    ) -> Result<BorrowRequest, APIError> {
+        let book_not_found =
+            || APIError::SimpleAPIError(SimpleAPIError::new("Book not found".to_string(), 404));
+
+        let book = match self
+            .book_repository
+            .find_by_id(borrow_request.book_id)
+            .await
+        {
+            Ok(Some(book)) => book,
+            Ok(None) => return Err(book_not_found()),
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        match self
+            .library_repository
+            .find_member_role(book.library_id, borrow_request.requester_id)
+            .await
+        {
+            Ok(Some(_)) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Book already belongs to one of your libraries".to_string(),
+                    422,
+                )));
+            }
+            Ok(None) => {}
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        // Only books in libraries a friend opened to friends can be asked
+        // for, everything else is reported as missing
+        let library = match self.library_repository.find_by_id(book.library_id).await {
+            Ok(Some(library)) if library.visibility == LibraryVisibility::Friends => library,
+            Ok(_) => return Err(book_not_found()),
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        if self
+            .authorize_friend_usecase
+            .authorize(borrow_request.requester_id, library.owner_id)
+            .await
+            .is_err()
+        {
+            return Err(book_not_found());
+        }
+
+        match self
+            .loan_repository
+            .find_open_by_book_id(book.id.unwrap_or_default())
+            .await
+        {
+            Ok(Some(_)) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Book is currently lent".to_string(),
+                    409,
+                )));
+            }
+            Ok(None) => {}
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self
+            .borrow_request_repository
+            .find_pending_by_book_id_and_requester_id(
+                borrow_request.book_id,
+                borrow_request.requester_id,
+            )
+            .await
+        {
+            Ok(Some(_)) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "There is already a pending request for this book".to_string(),
+                    409,
+                )));
+            }
+            Ok(None) => {}
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        borrow_request.library_id = book.library_id;
+        borrow_request.owner_id = library.owner_id;
+        borrow_request.status = BorrowRequestStatus::Pending;
+        match self.borrow_request_repository.save(&borrow_request).await {
+            Ok(Some(created_borrow_request)) => Ok(created_borrow_request),
+            Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Failed to load created borrow request info".to_string(),
+                500,
+            ))),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
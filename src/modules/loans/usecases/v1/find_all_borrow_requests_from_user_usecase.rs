@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    loans::{
+        domain::dtos::find_all_borrow_requests_dto::FindAllBorrowRequestsDto,
+        infra::repositories::{
+            borrow_request_repository::BorrowRequestRepository,
+            borrow_request_repository_mysql::BorrowRequestRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct FindAllBorrowRequestsFromUserUseCaseV1<T>
+where
+    T: BorrowRequestRepository,
+{
+    borrow_request_repository: Arc<T>,
+}
+
+impl FindAllBorrowRequestsFromUserUseCaseV1<BorrowRequestRepositoryMySQL> {
+    pub fn new(borrow_request_repository: BorrowRequestRepositoryMySQL) -> Self {
+        Self {
+            borrow_request_repository: Arc::new(borrow_request_repository),
+        }
+    }
+
+    pub async fn find_all_borrow_requests_from_user(
+        &self,
+        user_id: u64,
+    ) -> Result<FindAllBorrowRequestsDto, APIError> {
+        match self
+            .borrow_request_repository
+            .find_all_by_user_id(user_id)
+            .await
+        {
+            Ok(borrow_requests) => {
+                let (incoming, outgoing) = borrow_requests
+                    .into_iter()
+                    .partition(|borrow_request| borrow_request.owner_id == user_id);
+                Ok(FindAllBorrowRequestsDto { incoming, outgoing })
+            }
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    loans::{
+        domain::dtos::find_all_loans_dto::FindAllLoansDto,
+        infra::repositories::{
+            loan_repository::LoanRepository, loan_repository_mysql::LoanRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct FindAllLoansFromUserUseCaseV1<T>
+where
+    T: LoanRepository,
+{
+    loan_repository: Arc<T>,
+}
+
+impl FindAllLoansFromUserUseCaseV1<LoanRepositoryMySQL> {
+    pub fn new(loan_repository: LoanRepositoryMySQL) -> Self {
+        Self {
+            loan_repository: Arc::new(loan_repository),
+        }
+    }
+
+    pub async fn find_all_loans_from_user(
+        &self,
+        user_id: u64,
+    ) -> Result<FindAllLoansDto, APIError> {
+        match self.loan_repository.find_all_by_user_id(user_id).await {
+            Ok(loans) => {
+                let (lent, borrowed) = loans
+                    .into_iter()
+                    .partition(|loan| loan.lender_id == user_id);
+                Ok(FindAllLoansDto { lent, borrowed })
+            }
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
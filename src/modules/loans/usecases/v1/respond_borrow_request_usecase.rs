@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    loans::{
+        domain::entities::{
+            borrow_request::{BorrowRequest, BorrowRequestStatus},
+            loan::Loan,
+        },
+        infra::repositories::{
+            borrow_request_repository::BorrowRequestRepository,
+            borrow_request_repository_mysql::BorrowRequestRepositoryMySQL,
+            loan_repository::LoanRepository, loan_repository_mysql::LoanRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct RespondBorrowRequestUseCaseV1<T, U>
+where
+    T: BorrowRequestRepository,
+    U: LoanRepository,
+{
+    borrow_request_repository: Arc<T>,
+    loan_repository: Arc<U>,
+}
+
+impl RespondBorrowRequestUseCaseV1<BorrowRequestRepositoryMySQL, LoanRepositoryMySQL> {
+    pub fn new(
+        borrow_request_repository: BorrowRequestRepositoryMySQL,
+        loan_repository: LoanRepositoryMySQL,
+    ) -> Self {
+        Self {
+            borrow_request_repository: Arc::new(borrow_request_repository),
+            loan_repository: Arc::new(loan_repository),
+        }
+    }
+
+    pub async fn approve_borrow_request(
+        &self,
+        borrow_request_id: u64,
+        authed_user_id: u64,
+    ) -> Result<Loan, APIError> {
+        let borrow_request = self
+            .find_pending_borrow_request(borrow_request_id, |borrow_request| {
+                borrow_request.owner_id == authed_user_id
+            })
+            .await?;
+
+        let loan_id = match self
+            .borrow_request_repository
+            .approve(&borrow_request)
+            .await
+        {
+            Ok(Some(loan_id)) => loan_id,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Book is currently lent".to_string(),
+                    409,
+                )));
+            }
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    e.to_string(),
+                    500,
+                )))
+            }
+        };
+
+        match self.loan_repository.find_by_id(loan_id).await {
+            Ok(Some(loan)) => Ok(loan),
+            Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Failed to load created loan info".to_string(),
+                500,
+            ))),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+
+    pub async fn decline_borrow_request(
+        &self,
+        borrow_request_id: u64,
+        authed_user_id: u64,
+    ) -> Result<(), APIError> {
+        self.find_pending_borrow_request(borrow_request_id, |borrow_request| {
+            borrow_request.owner_id == authed_user_id
+        })
+        .await?;
+        self.update_status(borrow_request_id, BorrowRequestStatus::Declined)
+            .await
+    }
+
+    pub async fn cancel_borrow_request(
+        &self,
+        borrow_request_id: u64,
+        authed_user_id: u64,
+    ) -> Result<(), APIError> {
+        self.find_pending_borrow_request(borrow_request_id, |borrow_request| {
+            borrow_request.requester_id == authed_user_id
+        })
+        .await?;
+        self.update_status(borrow_request_id, BorrowRequestStatus::Cancelled)
+            .await
+    }
+
+    async fn update_status(
+        &self,
+        borrow_request_id: u64,
+        status: BorrowRequestStatus,
+    ) -> Result<(), APIError> {
+        match self
+            .borrow_request_repository
+            .update_status(borrow_request_id, status)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+
+    async fn find_pending_borrow_request(
+        &self,
+        borrow_request_id: u64,
+        can_respond: impl Fn(&BorrowRequest) -> bool,
+    ) -> Result<BorrowRequest, APIError> {
+        match self
+            .borrow_request_repository
+            .find_by_id(borrow_request_id)
+            .await
+        {
+            Ok(Some(borrow_request)) if !can_respond(&borrow_request) => {
+                Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Borrow request not found".to_string(),
+                    404,
+                )))
+            }
+            Ok(Some(borrow_request)) if borrow_request.status != BorrowRequestStatus::Pending => {
+                Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Borrow request was already answered".to_string(),
+                    409,
+                )))
+            }
+            Ok(Some(borrow_request)) => Ok(borrow_request),
+            Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Borrow request not found".to_string(),
+                404,
+            ))),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    loans::infra::repositories::{
+        loan_repository::LoanRepository, loan_repository_mysql::LoanRepositoryMySQL,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+};
+
+pub struct ReturnLoanUseCaseV1<T>
+where
+    T: LoanRepository,
+{
+    loan_repository: Arc<T>,
+}
+
+impl ReturnLoanUseCaseV1<LoanRepositoryMySQL> {
+    pub fn new(loan_repository: LoanRepositoryMySQL) -> Self {
+        Self {
+            loan_repository: Arc::new(loan_repository),
+        }
+    }
+
+    pub async fn return_loan(&self, loan_id: u64, authed_user_id: u64) -> Result<(), APIError> {
+        match self.loan_repository.find_by_id(loan_id).await {
+            Ok(Some(loan)) if loan.lender_id != authed_user_id => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Loan not found".to_string(),
+                    404,
+                )));
+            }
+            Ok(Some(loan)) if loan.returned_at.is_some() => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Book was already returned".to_string(),
+                    409,
+                )));
+            }
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Loan not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        }
+
+        match self.loan_repository.mark_returned(loan_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+}
@@ -1 +1,4 @@
+pub mod binary_dto;
+pub mod if_match;
 pub mod paginated_dto;
+pub mod version_dto;
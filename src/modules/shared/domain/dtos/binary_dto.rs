@@ -0,0 +1,7 @@
+use utoipa::ToSchema;
+
+/// Raw request and response bodies, such as archives and images, only used
+/// to document them as binary strings in the OpenAPI specification.
+#[derive(ToSchema)]
+#[schema(value_type = String, format = Binary)]
+pub struct BinaryDto(pub Vec<u8>);
@@ -0,0 +1,109 @@
+use actix_web::{http::header, Error, FromRequest, HttpRequest};
+use futures_util::future::{ok, Ready};
+
+use crate::modules::shared::errors::{simple_api_error::SimpleAPIError, APIError};
+
+pub fn etag_from_version(version: u64) -> String {
+    format!("\"{}\"", version)
+}
+
+pub struct IfMatch {
+    pub value: Option<String>,
+}
+
+impl IfMatch {
+    /// Version the change is based on, `None` when the client accepts any
+    /// version through `If-Match: *`. A missing version is rejected with 428
+    /// since unconditional changes would silently overwrite other clients.
+    pub fn expected_version(&self, body_version: Option<u64>) -> Result<Option<u64>, APIError> {
+        match self.value.as_deref().map(str::trim) {
+            Some("*") => Ok(None),
+            Some(value) => {
+                let tag = value.strip_prefix("W/").unwrap_or(value).trim_matches('"');
+                match tag.parse::<u64>() {
+                    Ok(version) => Ok(Some(version)),
+                    Err(_) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "If-Match must hold an ETag returned by the API".to_string(),
+                        400,
+                    ))),
+                }
+            }
+            None => match body_version {
+                Some(version) => Ok(Some(version)),
+                None => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "This action requires the resource version in If-Match or in the body"
+                        .to_string(),
+                    428,
+                ))),
+            },
+        }
+    }
+}
+
+impl FromRequest for IfMatch {
+    type Error = Error;
+
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut actix_web::dev::Payload) -> Self::Future {
+        let value = req
+            .headers()
+            .get(header::IF_MATCH)
+            .and_then(|header| header.to_str().ok())
+            .map(str::to_string);
+        ok(IfMatch { value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn if_match(value: Option<&str>) -> IfMatch {
+        IfMatch {
+            value: value.map(str::to_string),
+        }
+    }
+
+    fn status_code(result: Result<Option<u64>, APIError>) -> u16 {
+        match result {
+            Err(APIError::SimpleAPIError(error)) => error.code,
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn versions_are_read_from_strong_and_weak_etags() {
+        assert_eq!(
+            Some(3),
+            if_match(Some("\"3\""))
+                .expected_version(None)
+                .ok()
+                .flatten()
+        );
+        assert_eq!(
+            Some(3),
+            if_match(Some("W/\"3\""))
+                .expected_version(Some(2))
+                .ok()
+                .flatten()
+        );
+        assert_eq!(
+            Some(None),
+            if_match(Some(" * ")).expected_version(Some(2)).ok()
+        );
+    }
+
+    #[test]
+    fn the_body_version_is_only_used_without_the_header() {
+        assert_eq!(
+            Some(2),
+            if_match(None).expected_version(Some(2)).ok().flatten()
+        );
+        assert_eq!(428, status_code(if_match(None).expected_version(None)));
+        assert_eq!(
+            400,
+            status_code(if_match(Some("\"abc\"")).expected_version(Some(2)))
+        );
+    }
+}
@@ -1,9 +1,12 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
 pub struct PaginatedDto<T> {
-    pub page: u64,
+    pub page: Option<u64>,
     pub page_size: u64,
-    pub total_items: u64,
+    pub total_items: Option<u64>,
+    pub next_cursor: Option<String>,
+    pub prev_cursor: Option<String>,
     pub items: Vec<T>,
 }
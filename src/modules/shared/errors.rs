@@ -1,13 +1,23 @@
-use actix_web::{http::StatusCode, HttpResponse, HttpResponseBuilder};
+use actix_web::{
+    http::{header, StatusCode},
+    HttpResponse, HttpResponseBuilder,
+};
+use async_graphql::{ErrorExtensions, Name};
 
-use self::{detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError};
+use self::{
+    detailed_api_error::DetailedAPIError,
+    precondition_failed_api_error::PreconditionFailedAPIError, simple_api_error::SimpleAPIError,
+};
+use super::domain::dtos::if_match::etag_from_version;
 
 pub mod detailed_api_error;
+pub mod precondition_failed_api_error;
 pub mod simple_api_error;
 
 pub enum APIError {
     SimpleAPIError(SimpleAPIError),
     DetailedAPIError(DetailedAPIError),
+    PreconditionFailedAPIError(PreconditionFailedAPIError),
 }
 
 impl From<APIError> for HttpResponse {
@@ -23,6 +33,42 @@ impl From<APIError> for HttpResponse {
                     StatusCode::from_u16(dae.code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
                 HttpResponseBuilder::new(status_code).json(dae)
             }
+            APIError::PreconditionFailedAPIError(pfae) => HttpResponse::PreconditionFailed()
+                .insert_header((header::ETAG, etag_from_version(pfae.current_version)))
+                .json(pfae),
+        }
+    }
+}
+
+/// GraphQL responses are always `200 OK`, the status code the REST API would
+/// answer with goes into the `code` extension of the error.
+impl From<APIError> for async_graphql::Error {
+    fn from(value: APIError) -> Self {
+        match value {
+            APIError::SimpleAPIError(sae) => {
+                async_graphql::Error::new(sae.msg).extend_with(|_, e| e.set("code", sae.code))
+            }
+            APIError::DetailedAPIError(dae) => {
+                async_graphql::Error::new(dae.msg).extend_with(|_, e| {
+                    e.set("code", dae.code);
+                    if let Some(field_validations) = dae.field_validations {
+                        e.set(
+                            "fieldValidations",
+                            async_graphql::Value::Object(
+                                field_validations
+                                    .into_iter()
+                                    .map(|(field, validation)| {
+                                        (Name::new(field), async_graphql::Value::from(validation))
+                                    })
+                                    .collect(),
+                            ),
+                        );
+                    }
+                })
+            }
+            APIError::PreconditionFailedAPIError(pfae) => {
+                async_graphql::Error::new(pfae.msg).extend_with(|_, e| e.set("code", pfae.code))
+            }
         }
     }
 }
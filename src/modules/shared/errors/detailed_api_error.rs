@@ -1,8 +1,9 @@
 use std::{collections::HashMap, error::Error, fmt};
 
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct DetailedAPIError {
     pub msg: String,
     pub code: u16,
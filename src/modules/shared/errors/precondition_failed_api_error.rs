@@ -0,0 +1,27 @@
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// Returned when a change is based on an outdated version of a resource,
+/// `current` holds the resource as it is now so clients can merge their
+/// changes without fetching it again.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PreconditionFailedAPIError {
+    pub msg: String,
+    pub code: u16,
+    pub current: Value,
+    #[serde(skip)]
+    pub current_version: u64,
+}
+
+impl PreconditionFailedAPIError {
+    pub fn new(current: Value, current_version: u64) -> Self {
+        PreconditionFailedAPIError {
+            msg: "The resource was changed since it was loaded, reload it and try again"
+                .to_string(),
+            code: 412,
+            current,
+            current_version,
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use std::{error::Error, fmt};
 
 use serde::Serialize;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct SimpleAPIError {
     pub msg: String,
     pub code: u16,
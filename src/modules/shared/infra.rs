@@ -0,0 +1,2 @@
+pub mod clocks;
+pub mod qr_codes;
@@ -0,0 +1,718 @@
+pub const MAX_QR_CODE_VERSION: usize = 10;
+
+const ECC_CODEWORDS_PER_BLOCK: [usize; MAX_QR_CODE_VERSION + 1] =
+    [0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26];
+const NUM_ERROR_CORRECTION_BLOCKS: [usize; MAX_QR_CODE_VERSION + 1] =
+    [0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5];
+const ECC_LEVEL_M_FORMAT_BITS: u32 = 0;
+
+/// QR code of some bytes, at error correction level M. Only what scanning
+/// an `otpauth://` URI takes is supported: byte mode and versions up to 10.
+pub struct QrCode {
+    size: usize,
+    modules: Vec<bool>,
+    is_function: Vec<bool>,
+}
+
+impl QrCode {
+    pub fn encode(data: &[u8]) -> Result<QrCode, String> {
+        let version = (1..=MAX_QR_CODE_VERSION)
+            .find(|version| {
+                4 + char_count_bits(*version) + data.len() * 8 <= num_data_codewords(*version) * 8
+            })
+            .ok_or("Data is too long to fit in a QR code".to_string())?;
+
+        let mut bits: Vec<bool> = Vec::new();
+        append_bits(&mut bits, 0b0100, 4);
+        append_bits(&mut bits, data.len() as u32, char_count_bits(version));
+        for byte in data {
+            append_bits(&mut bits, *byte as u32, 8);
+        }
+        let capacity_bits = num_data_codewords(version) * 8;
+        let terminator_bits = (capacity_bits - bits.len()).min(4);
+        append_bits(&mut bits, 0, terminator_bits);
+        let padding_bits = (8 - bits.len() % 8) % 8;
+        append_bits(&mut bits, 0, padding_bits);
+        for pad_byte in [0xEC, 0x11].iter().cycle() {
+            if bits.len() >= capacity_bits {
+                break;
+            }
+            append_bits(&mut bits, *pad_byte, 8);
+        }
+        let codewords: Vec<u8> = bits
+            .chunks(8)
+            .map(|byte| byte.iter().fold(0, |acc, bit| (acc << 1) | *bit as u8))
+            .collect();
+
+        let size = version * 4 + 17;
+        let mut qr_code = QrCode {
+            size,
+            modules: vec![false; size * size],
+            is_function: vec![false; size * size],
+        };
+        qr_code.draw_function_patterns(version);
+        qr_code.draw_codewords(&add_ecc_and_interleave(version, &codewords));
+
+        let mut best_mask = 0;
+        let mut min_penalty = u32::MAX;
+        for mask in 0..8 {
+            qr_code.apply_mask(mask);
+            qr_code.draw_format_bits(mask);
+            let penalty = qr_code.penalty_score();
+            if penalty < min_penalty {
+                best_mask = mask;
+                min_penalty = penalty;
+            }
+            // Masks are undone by applying them again.
+            qr_code.apply_mask(mask);
+        }
+        qr_code.apply_mask(best_mask);
+        qr_code.draw_format_bits(best_mask);
+        Ok(qr_code)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn module(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+
+    pub fn to_svg(&self) -> String {
+        let border = 4;
+        let mut path = String::new();
+        for y in 0..self.size {
+            for x in 0..self.size {
+                if self.module(x, y) {
+                    if !path.is_empty() {
+                        path.push(' ');
+                    }
+                    path.push_str(&format!("M{},{}h1v1h-1z", x + border, y + border));
+                }
+            }
+        }
+        format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {0} {0}" shape-rendering="crispEdges">"#,
+                r##"<rect width="100%" height="100%" fill="#FFFFFF"/>"##,
+                r##"<path d="{1}" fill="#000000"/>"##,
+                "</svg>"
+            ),
+            self.size + border * 2,
+            path
+        )
+    }
+
+    fn set_function_module(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y * self.size + x] = dark;
+        self.is_function[y * self.size + x] = true;
+    }
+
+    fn draw_function_patterns(&mut self, version: usize) {
+        let size = self.size;
+        for i in 0..size {
+            self.set_function_module(6, i, i % 2 == 0);
+            self.set_function_module(i, 6, i % 2 == 0);
+        }
+
+        for (x, y) in [(3, 3), (size - 4, 3), (3, size - 4)] {
+            self.draw_finder_pattern(x, y);
+        }
+
+        let positions = alignment_pattern_positions(version);
+        let last = positions.len().saturating_sub(1);
+        for (i, x) in positions.iter().enumerate() {
+            for (j, y) in positions.iter().enumerate() {
+                // Corners taken by the finder patterns.
+                if matches!((i, j), (0, 0)) || (i == 0 && j == last) || (i == last && j == 0) {
+                    continue;
+                }
+                self.draw_alignment_pattern(*x, *y);
+            }
+        }
+
+        // Reserved for now, drawn once the mask is chosen.
+        self.draw_format_bits(0);
+        self.draw_version(version);
+    }
+
+    fn draw_finder_pattern(&mut self, x: usize, y: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let module_x = x as i32 + dx;
+                let module_y = y as i32 + dy;
+                if (0..self.size as i32).contains(&module_x)
+                    && (0..self.size as i32).contains(&module_y)
+                {
+                    let distance = dx.abs().max(dy.abs());
+                    self.set_function_module(
+                        module_x as usize,
+                        module_y as usize,
+                        distance != 2 && distance != 4,
+                    );
+                }
+            }
+        }
+    }
+
+    fn draw_alignment_pattern(&mut self, x: usize, y: usize) {
+        for dy in -2i32..=2 {
+            for dx in -2i32..=2 {
+                self.set_function_module(
+                    (x as i32 + dx) as usize,
+                    (y as i32 + dy) as usize,
+                    dx.abs().max(dy.abs()) != 1,
+                );
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, mask: u32) {
+        let data = (ECC_LEVEL_M_FORMAT_BITS << 3) | mask;
+        let mut remainder = data;
+        for _ in 0..10 {
+            remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+        }
+        let bits = ((data << 10) | remainder) ^ 0x5412;
+        let bit = |i: usize| (bits >> i) & 1 != 0;
+
+        for i in 0..6 {
+            self.set_function_module(8, i, bit(i));
+        }
+        self.set_function_module(8, 7, bit(6));
+        self.set_function_module(8, 8, bit(7));
+        self.set_function_module(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function_module(14 - i, 8, bit(i));
+        }
+
+        let size = self.size;
+        for i in 0..8 {
+            self.set_function_module(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function_module(8, size - 15 + i, bit(i));
+        }
+        self.set_function_module(8, size - 8, true);
+    }
+
+    fn draw_version(&mut self, version: usize) {
+        if version < 7 {
+            return;
+        }
+        let mut remainder = version as u32;
+        for _ in 0..12 {
+            remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1F25);
+        }
+        let bits = ((version as u32) << 12) | remainder;
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 != 0;
+            let a = self.size - 11 + i % 3;
+            let b = i / 3;
+            self.set_function_module(a, b, dark);
+            self.set_function_module(b, a, dark);
+        }
+    }
+
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size as i32;
+        let total_bits = codewords.len() * 8;
+        let mut bit_index = 0;
+        let mut right = size - 1;
+        while right >= 1 {
+            if right == 6 {
+                right = 5;
+            }
+            for vertical in 0..size {
+                for j in 0..2 {
+                    let x = (right - j) as usize;
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward {
+                        size - 1 - vertical
+                    } else {
+                        vertical
+                    } as usize;
+                    if !self.is_function[y * self.size + x] && bit_index < total_bits {
+                        self.modules[y * self.size + x] =
+                            (codewords[bit_index >> 3] >> (7 - (bit_index & 7))) & 1 != 0;
+                        bit_index += 1;
+                    }
+                }
+            }
+            right -= 2;
+        }
+    }
+
+    fn apply_mask(&mut self, mask: u32) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let index = y * self.size + x;
+                if invert && !self.is_function[index] {
+                    self.modules[index] = !self.modules[index];
+                }
+            }
+        }
+    }
+
+    fn penalty_score(&self) -> u32 {
+        let size = self.size;
+        let mut penalty = 0;
+        let finder_like = [
+            true, false, true, true, true, false, true, false, false, false, false,
+        ];
+
+        for horizontal in [true, false] {
+            for line in 0..size {
+                let module = |i: usize| {
+                    if horizontal {
+                        self.module(i, line)
+                    } else {
+                        self.module(line, i)
+                    }
+                };
+
+                // Runs of five or more modules of the same color.
+                let mut run_length = 1;
+                for i in 1..size {
+                    if module(i) == module(i - 1) {
+                        run_length += 1;
+                    } else {
+                        if run_length >= 5 {
+                            penalty += run_length - 2;
+                        }
+                        run_length = 1;
+                    }
+                }
+                if run_length >= 5 {
+                    penalty += run_length - 2;
+                }
+
+                // Patterns looking like a finder pattern.
+                for start in 0..=size - finder_like.len() {
+                    let matches = |reversed: bool| {
+                        finder_like.iter().enumerate().all(|(i, dark)| {
+                            let index = if reversed {
+                                start + finder_like.len() - 1 - i
+                            } else {
+                                start + i
+                            };
+                            module(index) == *dark
+                        })
+                    };
+                    if matches(false) {
+                        penalty += 40;
+                    }
+                    if matches(true) {
+                        penalty += 40;
+                    }
+                }
+            }
+        }
+
+        // Blocks of 2x2 modules of the same color.
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let dark = self.module(x, y);
+                if dark == self.module(x + 1, y)
+                    && dark == self.module(x, y + 1)
+                    && dark == self.module(x + 1, y + 1)
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        // Balance of dark and light modules.
+        let dark_modules = self.modules.iter().filter(|dark| **dark).count();
+        let total_modules = size * size;
+        let deviation = (dark_modules * 20).abs_diff(total_modules * 10);
+        penalty += deviation.div_ceil(total_modules).saturating_sub(1) * 10;
+
+        penalty as u32
+    }
+}
+
+fn append_bits(bits: &mut Vec<bool>, value: u32, length: usize) {
+    for i in (0..length).rev() {
+        bits.push((value >> i) & 1 != 0);
+    }
+}
+
+fn char_count_bits(version: usize) -> usize {
+    if version <= 9 {
+        8
+    } else {
+        16
+    }
+}
+
+fn num_raw_data_modules(version: usize) -> usize {
+    let mut result = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let num_align = version / 7 + 2;
+        result -= (25 * num_align - 10) * num_align - 55;
+        if version >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+fn num_data_codewords(version: usize) -> usize {
+    num_raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[version] * NUM_ERROR_CORRECTION_BLOCKS[version]
+}
+
+fn alignment_pattern_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let num_align = version / 7 + 2;
+    let step = (version * 4 + num_align * 2 + 1) / (num_align * 2 - 2) * 2;
+    let mut positions = vec![6];
+    let mut position = version * 4 + 17 - 7;
+    for _ in 0..num_align - 1 {
+        positions.insert(1, position);
+        position -= step;
+    }
+    positions
+}
+
+fn add_ecc_and_interleave(version: usize, data: &[u8]) -> Vec<u8> {
+    let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[version];
+    let block_ecc_length = ECC_CODEWORDS_PER_BLOCK[version];
+    let raw_codewords = num_raw_data_modules(version) / 8;
+    let num_short_blocks = num_blocks - raw_codewords % num_blocks;
+    let short_block_length = raw_codewords / num_blocks;
+
+    let divisor = reed_solomon_divisor(block_ecc_length);
+    let mut blocks: Vec<Vec<u8>> = Vec::with_capacity(num_blocks);
+    let mut offset = 0;
+    for i in 0..num_blocks {
+        let data_length =
+            short_block_length - block_ecc_length + if i < num_short_blocks { 0 } else { 1 };
+        let mut block = data[offset..offset + data_length].to_vec();
+        offset += data_length;
+        let ecc = reed_solomon_remainder(&block, &divisor);
+        if i < num_short_blocks {
+            block.push(0);
+        }
+        block.extend(ecc);
+        blocks.push(block);
+    }
+
+    let mut result = Vec::with_capacity(raw_codewords);
+    for i in 0..blocks[0].len() {
+        for (j, block) in blocks.iter().enumerate() {
+            // Short blocks are padded, the padding isn't part of the code.
+            if i != short_block_length - block_ecc_length || j >= num_short_blocks {
+                result.push(block[i]);
+            }
+        }
+    }
+    result
+}
+
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0u8; degree];
+    result[degree - 1] = 1;
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = reed_solomon_multiply(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = reed_solomon_multiply(root, 0x02);
+    }
+    result
+}
+
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0u8; divisor.len()];
+    for byte in data {
+        let factor = byte ^ result.remove(0);
+        result.push(0);
+        for (remainder, coefficient) in result.iter_mut().zip(divisor) {
+            *remainder ^= reed_solomon_multiply(*coefficient, factor);
+        }
+    }
+    result
+}
+
+fn reed_solomon_multiply(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= ((y as u32 >> i) & 1) * x as u32;
+    }
+    z as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Format information at level M by mask, as tabulated in ISO/IEC
+    /// 18004, already XORed with its mask pattern.
+    const LEVEL_M_FORMAT_INFORMATION: [u32; 8] = [
+        0b101010000010010,
+        0b101000100100101,
+        0b101111001111100,
+        0b101101101001011,
+        0b100010111111001,
+        0b100000011001110,
+        0b100111110010111,
+        0b100101010100000,
+    ];
+    const VERSION_INFORMATION: [u32; 4] = [0x07C94, 0x085BC, 0x09A99, 0x0A4D3];
+    const LEVEL_M_BLOCKS: [(usize, usize, usize); MAX_QR_CODE_VERSION + 1] = [
+        (0, 0, 0),
+        (26, 10, 1),
+        (44, 16, 1),
+        (70, 26, 1),
+        (100, 18, 2),
+        (134, 24, 2),
+        (172, 16, 4),
+        (196, 18, 4),
+        (242, 22, 4),
+        (292, 22, 5),
+        (346, 26, 5),
+    ];
+    const ALIGNMENT_PATTERN_CENTERS: [&[usize]; MAX_QR_CODE_VERSION + 1] = [
+        &[],
+        &[],
+        &[6, 18],
+        &[6, 22],
+        &[6, 26],
+        &[6, 30],
+        &[6, 34],
+        &[6, 22, 38],
+        &[6, 24, 42],
+        &[6, 26, 46],
+        &[6, 28, 50],
+    ];
+
+    /// Decodes the matrix the way a scanner would, without any of the
+    /// encoder's helpers, and returns the byte mode data.
+    fn decode(qr_code: &QrCode) -> Vec<u8> {
+        let size = qr_code.size();
+        assert_eq!((size - 17) % 4, 0);
+        let version = (size - 17) / 4;
+        let module = |x: usize, y: usize| qr_code.module(x, y) as u32;
+
+        let mut format_information = 0;
+        for (x, y) in [
+            (8, 0),
+            (8, 1),
+            (8, 2),
+            (8, 3),
+            (8, 4),
+            (8, 5),
+            (8, 7),
+            (8, 8),
+        ]
+        .into_iter()
+        .chain([(7, 8), (5, 8), (4, 8), (3, 8), (2, 8), (1, 8), (0, 8)])
+        .rev()
+        {
+            format_information = (format_information << 1) | module(x, y);
+        }
+        let mut format_information_copy = 0;
+        for i in (0..15).rev() {
+            let (x, y) = if i < 8 {
+                (size - 1 - i, 8)
+            } else {
+                (8, size - 15 + i)
+            };
+            format_information_copy = (format_information_copy << 1) | module(x, y);
+        }
+        assert_eq!(format_information, format_information_copy);
+        let mask = LEVEL_M_FORMAT_INFORMATION
+            .iter()
+            .position(|bits| *bits == format_information)
+            .expect("Format information isn't the one of level M");
+        assert_eq!(module(8, size - 8), 1, "Dark module is missing");
+
+        if version >= 7 {
+            let mut version_information = 0;
+            let mut version_information_copy = 0;
+            for i in (0..18).rev() {
+                let (a, b) = (size - 11 + i % 3, i / 3);
+                version_information = (version_information << 1) | module(a, b);
+                version_information_copy = (version_information_copy << 1) | module(b, a);
+            }
+            assert_eq!(version_information, VERSION_INFORMATION[version - 7]);
+            assert_eq!(version_information_copy, VERSION_INFORMATION[version - 7]);
+        }
+
+        let is_function = |x: usize, y: usize| {
+            let in_finder = |x: usize, y: usize| {
+                (y < 9 && (x < 9 || x >= size - 8)) || (x < 9 && y >= size - 8)
+            };
+            let centers = ALIGNMENT_PATTERN_CENTERS[version];
+            let in_alignment = centers.iter().any(|cx| {
+                centers
+                    .iter()
+                    .any(|cy| !in_finder(*cx, *cy) && x.abs_diff(*cx) <= 2 && y.abs_diff(*cy) <= 2)
+            });
+            let in_version = version >= 7
+                && ((x >= size - 11 && x < size - 8 && y < 6)
+                    || (y >= size - 11 && y < size - 8 && x < 6));
+            x == 6 || y == 6 || in_finder(x, y) || in_alignment || in_version
+        };
+        let is_masked = |x: usize, y: usize| match mask {
+            0 => (y + x).is_multiple_of(2),
+            1 => y.is_multiple_of(2),
+            2 => x.is_multiple_of(3),
+            3 => (y + x).is_multiple_of(3),
+            4 => (y / 2 + x / 3).is_multiple_of(2),
+            5 => (y * x) % 2 + (y * x) % 3 == 0,
+            6 => ((y * x) % 2 + (y * x) % 3).is_multiple_of(2),
+            _ => ((y + x) % 2 + (y * x) % 3).is_multiple_of(2),
+        };
+
+        let (total_codewords, ecc_length, num_blocks) = LEVEL_M_BLOCKS[version];
+        let mut codewords = vec![0u8; total_codewords];
+        let mut bit_index = 0;
+        // Column pairs from the right, the vertical timing pattern shifts
+        // the ones left of it by one.
+        let right_columns =
+            (1..size)
+                .rev()
+                .step_by(2)
+                .map(|right| if right <= 6 { right - 1 } else { right });
+        for (pair, right) in right_columns.enumerate() {
+            let upward = pair % 2 == 0;
+            for vertical in 0..size {
+                let y = if upward {
+                    size - 1 - vertical
+                } else {
+                    vertical
+                };
+                for x in [right, right - 1] {
+                    if is_function(x, y) || bit_index >= total_codewords * 8 {
+                        continue;
+                    }
+                    let dark = qr_code.module(x, y) ^ is_masked(x, y);
+                    codewords[bit_index / 8] |= (dark as u8) << (7 - bit_index % 8);
+                    bit_index += 1;
+                }
+            }
+        }
+        assert_eq!(bit_index, total_codewords * 8);
+
+        let num_long_blocks = total_codewords % num_blocks;
+        let short_data_length = total_codewords / num_blocks - ecc_length;
+        let mut blocks: Vec<Vec<u8>> = vec![Vec::new(); num_blocks];
+        let mut codeword_iter = codewords.iter();
+        for i in 0..=short_data_length {
+            for (j, block) in blocks.iter_mut().enumerate() {
+                if i < short_data_length || j >= num_blocks - num_long_blocks {
+                    block.push(*codeword_iter.next().unwrap());
+                }
+            }
+        }
+        for _ in 0..ecc_length {
+            for block in blocks.iter_mut() {
+                block.push(*codeword_iter.next().unwrap());
+            }
+        }
+        assert!(codeword_iter.next().is_none());
+
+        let mut data = Vec::new();
+        for block in blocks {
+            // A valid codeword evaluates to zero at the roots of the
+            // generator, a^0 to a^(ecc_length - 1).
+            for root_exponent in 0..ecc_length {
+                let root = (0..root_exponent).fold(1, |root, _| gf_multiply(root, 2));
+                let syndrome = block
+                    .iter()
+                    .fold(0, |value, codeword| gf_multiply(value, root) ^ codeword);
+                assert_eq!(syndrome, 0, "Error correction codewords don't match");
+            }
+            data.extend_from_slice(&block[..block.len() - ecc_length]);
+        }
+
+        let bit = |index: usize| (data[index / 8] >> (7 - index % 8)) as u32 & 1;
+        let read = |start: usize, length: usize| {
+            (start..start + length).fold(0, |value, index| (value << 1) | bit(index))
+        };
+        assert_eq!(read(0, 4), 0b0100, "Data isn't in byte mode");
+        let count_length = if version <= 9 { 8 } else { 16 };
+        let byte_count = read(4, count_length) as usize;
+        (0..byte_count)
+            .map(|i| read(4 + count_length + i * 8, 8) as u8)
+            .collect()
+    }
+
+    fn gf_multiply(x: u8, y: u8) -> u8 {
+        let (mut x, mut y, mut product) = (x as u16, y, 0u16);
+        while y > 0 {
+            if y & 1 == 1 {
+                product ^= x;
+            }
+            x <<= 1;
+            if x & 0x100 != 0 {
+                x ^= 0x11D;
+            }
+            y >>= 1;
+        }
+        product as u8
+    }
+
+    #[test]
+    fn short_data_fits_in_the_smallest_version() {
+        let qr_code = QrCode::encode(b"librarian").unwrap();
+
+        assert_eq!(qr_code.size(), 21);
+        assert_eq!(decode(&qr_code), b"librarian");
+    }
+
+    #[test]
+    fn otpauth_uris_are_decoded_back() {
+        let uri = "otpauth://totp/Online%20Librarian:reader%40example.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=Online%20Librarian&algorithm=SHA1&digits=6&period=30";
+        let qr_code = QrCode::encode(uri.as_bytes()).unwrap();
+
+        assert!(
+            qr_code.size() >= 7 * 4 + 17,
+            "Version information isn't covered"
+        );
+        assert_eq!(decode(&qr_code), uri.as_bytes());
+    }
+
+    #[test]
+    fn every_version_is_decoded_back() {
+        // Largest byte count each version holds at level M.
+        let capacities = [14, 26, 42, 62, 84, 106, 122, 152, 180, 213];
+        for (i, capacity) in capacities.iter().enumerate() {
+            let version = i + 1;
+            let data: Vec<u8> = (0..*capacity).map(|i| (i * 7 + 3) as u8).collect();
+            let qr_code = QrCode::encode(&data).unwrap();
+
+            assert_eq!(qr_code.size(), version * 4 + 17);
+            assert_eq!(decode(&qr_code), data);
+            if version < MAX_QR_CODE_VERSION {
+                let qr_code = QrCode::encode(&vec![0; capacity + 1]).unwrap();
+                assert_eq!(qr_code.size(), (version + 1) * 4 + 17);
+            }
+        }
+    }
+
+    #[test]
+    fn data_too_long_is_refused() {
+        assert!(QrCode::encode(&[b'a'; 214]).is_err());
+    }
+}
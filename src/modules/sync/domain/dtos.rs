@@ -0,0 +1,10 @@
+pub mod push_sync_changes_dto;
+pub mod pushed_sync_changes_dto;
+pub mod sync_change_dto;
+pub mod sync_change_result_dto;
+pub mod sync_changes_dto;
+pub mod sync_params;
+pub mod sync_tombstone_dto;
+pub mod synced_book_dto;
+pub mod synced_collection_dto;
+pub mod synced_location_dto;
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use super::sync_change_dto::SyncChangeDto;
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct PushSyncChangesDto {
+    /// Applied in order, at most 500.
+    pub changes: Option<Vec<SyncChangeDto>>,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::sync_change_result_dto::SyncChangeResultDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct PushedSyncChangesDto {
+    pub results: Vec<SyncChangeResultDto>,
+}
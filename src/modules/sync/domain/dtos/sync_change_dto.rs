@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::modules::books::domain::dtos::create_book_dto::CreateBookDto;
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct SyncChangeDto {
+    /// `book`, `collection` or `location`.
+    pub entity_type: Option<String>,
+    /// `upsert` or `delete`. Collections and locations can only be created
+    /// or deleted.
+    pub operation: Option<String>,
+    pub id: Option<u64>,
+    pub client_id: Option<String>,
+    /// `updated_at` of the synced copy the change is based on.
+    pub base_updated_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub name: Option<String>,
+    pub book: Option<CreateBookDto>,
+    /// `client_id` of a location created in the same push, used instead of
+    /// `book.location_id`.
+    pub location_client_id: Option<String>,
+    pub collection_client_id: Option<String>,
+}
@@ -0,0 +1,16 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct SyncChangeResultDto {
+    pub index: usize,
+    pub entity_type: String,
+    pub id: Option<u64>,
+    pub client_id: Option<String>,
+    /// `applied`, `merged`, `conflict` or `rejected`.
+    pub status: String,
+    /// How a conflict with the server copy was resolved, `client_wins` or
+    /// `server_wins`.
+    pub resolution: Option<String>,
+    pub message: Option<String>,
+}
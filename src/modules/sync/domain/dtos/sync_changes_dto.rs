@@ -0,0 +1,17 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::{
+    sync_tombstone_dto::SyncTombstoneDto, synced_book_dto::SyncedBookDto,
+    synced_collection_dto::SyncedCollectionDto, synced_location_dto::SyncedLocationDto,
+};
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct SyncChangesDto {
+    pub books: Vec<SyncedBookDto>,
+    pub collections: Vec<SyncedCollectionDto>,
+    pub locations: Vec<SyncedLocationDto>,
+    pub tombstones: Vec<SyncTombstoneDto>,
+    pub sync_token: String,
+    pub has_more: bool,
+}
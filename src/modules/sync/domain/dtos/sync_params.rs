@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Default, Clone, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SyncParams {
+    /// Token returned by the previous sync, everything is sent when it is
+    /// not informed.
+    pub sync_token: Option<String>,
+    pub page_size: Option<u64>,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct SyncTombstoneDto {
+    pub entity_type: String,
+    pub id: u64,
+    pub deleted_at: String,
+}
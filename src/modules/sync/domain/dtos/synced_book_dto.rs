@@ -0,0 +1,11 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::modules::books::domain::dtos::complete_book_dto::CompleteBookDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct SyncedBookDto {
+    #[serde(flatten)]
+    pub book: CompleteBookDto,
+    pub updated_at: String,
+}
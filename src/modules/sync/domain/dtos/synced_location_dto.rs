@@ -0,0 +1,12 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct SyncedLocationDto {
+    pub id: u64,
+    pub name: String,
+    pub user_id: u64,
+    pub library_id: u64,
+    pub version: u64,
+    pub updated_at: String,
+}
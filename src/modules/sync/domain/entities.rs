@@ -0,0 +1,5 @@
+pub mod sync_change;
+pub mod sync_state;
+pub mod sync_token;
+pub mod sync_tombstone;
+pub mod synced_entity;
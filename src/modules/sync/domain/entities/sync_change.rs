@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+
+use crate::modules::books::domain::{
+    dtos::create_book_dto::CreateBookDto, entities::audit_entry::AuditEntityType,
+};
+
+pub const MAX_SYNC_PUSH_CHANGES: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncOperation {
+    Upsert,
+    Delete,
+}
+
+impl TryFrom<&str> for SyncOperation {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "upsert" => Ok(SyncOperation::Upsert),
+            "delete" => Ok(SyncOperation::Delete),
+            _ => Err(format!("Unknown sync operation {}", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncChangeStatus {
+    Applied,
+    /// A collection or location created offline already existed with the
+    /// same name, the existing one is used instead.
+    Merged,
+    /// The server copy changed as well and won, the change was discarded.
+    Conflict,
+    Rejected,
+}
+
+impl SyncChangeStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SyncChangeStatus::Applied => "applied",
+            SyncChangeStatus::Merged => "merged",
+            SyncChangeStatus::Conflict => "conflict",
+            SyncChangeStatus::Rejected => "rejected",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncResolution {
+    ClientWins,
+    ServerWins,
+}
+
+impl SyncResolution {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SyncResolution::ClientWins => "client_wins",
+            SyncResolution::ServerWins => "server_wins",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SyncChange {
+    pub entity_type: AuditEntityType,
+    pub operation: SyncOperation,
+    pub id: Option<u64>,
+    pub client_id: Option<String>,
+    pub base_updated_at: Option<DateTime<Utc>>,
+    pub changed_at: DateTime<Utc>,
+    pub name: Option<String>,
+    pub book: Option<CreateBookDto>,
+    pub location_client_id: Option<String>,
+    pub collection_client_id: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct SyncChangeOutcome {
+    pub id: Option<u64>,
+    pub status: SyncChangeStatus,
+    pub resolution: Option<SyncResolution>,
+    pub message: Option<String>,
+}
+
+impl SyncChangeOutcome {
+    pub fn new(id: Option<u64>, status: SyncChangeStatus) -> Self {
+        SyncChangeOutcome {
+            id,
+            status,
+            resolution: None,
+            message: None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SyncPush {
+    pub changes: Vec<SyncChange>,
+}
+
+/// Decides between a change made offline and the server copy, `None` when
+/// the change was based on the current server copy and there is nothing
+/// to resolve. Otherwise the latest change wins and the server wins ties,
+/// client clocks can't be trusted so changes are never considered newer
+/// than `now`.
+pub fn resolve_sync_conflict(
+    base_updated_at: Option<DateTime<Utc>>,
+    changed_at: DateTime<Utc>,
+    server_updated_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Option<SyncResolution> {
+    if base_updated_at == Some(server_updated_at) {
+        return None;
+    }
+    if changed_at.min(now) > server_updated_at {
+        Some(SyncResolution::ClientWins)
+    } else {
+        Some(SyncResolution::ServerWins)
+    }
+}
@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone)]
+pub struct SyncState {
+    pub library_id: u64,
+    pub version: u64,
+    pub updated_at: DateTime<Utc>,
+    pub deleted: bool,
+}
@@ -0,0 +1,41 @@
+use base64::prelude::*;
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+
+/// Point of the change history of a library a client is synced up to,
+/// handed to clients as an opaque token. Books are paged through in
+/// `(updated_at, id)` order, so a sync interrupted by the page size resumes
+/// right after `book_id`. Completed syncs use `u64::MAX`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncToken {
+    pub library_id: u64,
+    pub synced_until: DateTime<Utc>,
+    pub book_id: u64,
+}
+
+impl SyncToken {
+    pub fn encode(&self) -> String {
+        let token = json!({
+            "l": self.library_id,
+            "t": self.synced_until.timestamp_micros(),
+            "b": self.book_id,
+        });
+        BASE64_URL_SAFE_NO_PAD.encode(token.to_string())
+    }
+
+    pub fn decode(token: &str) -> Result<SyncToken, String> {
+        let token: Option<Value> = BASE64_URL_SAFE_NO_PAD
+            .decode(token.trim())
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok());
+        token
+            .and_then(|token| {
+                Some(SyncToken {
+                    library_id: token["l"].as_u64()?,
+                    synced_until: DateTime::from_timestamp_micros(token["t"].as_i64()?)?,
+                    book_id: token["b"].as_u64()?,
+                })
+            })
+            .ok_or("Informed sync token is not valid".to_string())
+    }
+}
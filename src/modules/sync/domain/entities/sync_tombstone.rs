@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+
+use crate::modules::books::domain::entities::audit_entry::AuditEntityType;
+
+/// Book, collection or location clients should remove, either moved to the
+/// trash or removed for good.
+#[derive(Debug, Clone)]
+pub struct SyncTombstone {
+    pub entity_type: AuditEntityType,
+    pub entity_id: u64,
+    pub deleted_at: DateTime<Utc>,
+}
@@ -0,0 +1,7 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone)]
+pub struct SyncedEntity<T> {
+    pub entity: T,
+    pub updated_at: DateTime<Utc>,
+}
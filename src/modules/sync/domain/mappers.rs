@@ -0,0 +1,3 @@
+pub mod sync_change_dto_mapper;
+pub mod sync_tombstone_dto_mapper;
+pub mod synced_entity_dto_mapper;
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::modules::{
+    books::domain::entities::audit_entry::AuditEntityType,
+    shared::errors::detailed_api_error::DetailedAPIError,
+    sync::domain::{
+        dtos::push_sync_changes_dto::PushSyncChangesDto,
+        entities::sync_change::{SyncChange, SyncOperation, SyncPush, MAX_SYNC_PUSH_CHANGES},
+    },
+};
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value.trim())
+        .ok()
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+}
+
+impl TryFrom<PushSyncChangesDto> for SyncPush {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: PushSyncChangesDto) -> Result<Self, Self::Error> {
+        let mut push = SyncPush::default();
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        let changes = dto.changes.unwrap_or_default();
+        if changes.is_empty() {
+            validations.insert(
+                "changes".to_string(),
+                "At least one change must be informed".to_string(),
+            );
+        } else if changes.len() > MAX_SYNC_PUSH_CHANGES {
+            validations.insert(
+                "changes".to_string(),
+                format!(
+                    "At most {} changes can be pushed at once",
+                    MAX_SYNC_PUSH_CHANGES
+                ),
+            );
+        }
+
+        for (index, change) in changes.into_iter().enumerate() {
+            let field = |name: &str| format!("changes[{}].{}", index, name);
+
+            let entity_type = change
+                .entity_type
+                .as_deref()
+                .and_then(|entity_type| AuditEntityType::try_from(entity_type).ok());
+            if entity_type.is_none() {
+                validations.insert(
+                    field("entity_type"),
+                    "Entity type must be book, collection or location".to_string(),
+                );
+            }
+
+            let operation = change
+                .operation
+                .as_deref()
+                .and_then(|operation| SyncOperation::try_from(operation).ok());
+            if operation.is_none() {
+                validations.insert(
+                    field("operation"),
+                    "Operation must be upsert or delete".to_string(),
+                );
+            }
+
+            let changed_at = change.updated_at.as_deref().and_then(parse_timestamp);
+            if changed_at.is_none() {
+                validations.insert(
+                    field("updated_at"),
+                    "Change time must be informed as RFC 3339".to_string(),
+                );
+            }
+
+            let base_updated_at = match change.base_updated_at.as_deref() {
+                Some(base_updated_at) => match parse_timestamp(base_updated_at) {
+                    Some(base_updated_at) => Some(base_updated_at),
+                    None => {
+                        validations.insert(
+                            field("base_updated_at"),
+                            "Base time must be informed as RFC 3339".to_string(),
+                        );
+                        None
+                    }
+                },
+                None => None,
+            };
+
+            let client_id = change
+                .client_id
+                .map(|client_id| client_id.trim().to_string())
+                .filter(|client_id| !client_id.is_empty());
+
+            match (operation, change.id) {
+                (Some(SyncOperation::Delete), None) => {
+                    validations.insert(
+                        field("id"),
+                        "Id of the deleted entity must be informed".to_string(),
+                    );
+                }
+                (Some(SyncOperation::Upsert), None) if client_id.is_none() => {
+                    validations.insert(
+                        field("client_id"),
+                        "Client id of the created entity must be informed".to_string(),
+                    );
+                }
+                _ => {}
+            }
+
+            let name = change
+                .name
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty());
+            if operation == Some(SyncOperation::Upsert) {
+                match entity_type {
+                    Some(AuditEntityType::Book) if change.book.is_none() => {
+                        validations
+                            .insert(field("book"), "Book contents must be informed".to_string());
+                    }
+                    Some(AuditEntityType::Collection) | Some(AuditEntityType::Location)
+                        if change.id.is_some() =>
+                    {
+                        validations.insert(
+                            field("id"),
+                            "Collections and locations can only be created or deleted".to_string(),
+                        );
+                    }
+                    Some(AuditEntityType::Collection) | Some(AuditEntityType::Location)
+                        if name.is_none() =>
+                    {
+                        validations.insert(field("name"), "Name must be informed".to_string());
+                    }
+                    _ => {}
+                }
+            }
+
+            if let (Some(entity_type), Some(operation), Some(changed_at)) =
+                (entity_type, operation, changed_at)
+            {
+                push.changes.push(SyncChange {
+                    entity_type,
+                    operation,
+                    id: change.id,
+                    client_id,
+                    base_updated_at,
+                    changed_at,
+                    name,
+                    book: change.book,
+                    location_client_id: change.location_client_id,
+                    collection_client_id: change.collection_client_id,
+                });
+            }
+        }
+
+        if !validations.is_empty() {
+            return Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            });
+        }
+        Ok(push)
+    }
+}
@@ -0,0 +1,17 @@
+use chrono::SecondsFormat;
+
+use crate::modules::sync::domain::{
+    dtos::sync_tombstone_dto::SyncTombstoneDto, entities::sync_tombstone::SyncTombstone,
+};
+
+impl From<SyncTombstone> for SyncTombstoneDto {
+    fn from(tombstone: SyncTombstone) -> Self {
+        SyncTombstoneDto {
+            entity_type: tombstone.entity_type.as_str().to_string(),
+            id: tombstone.entity_id,
+            deleted_at: tombstone
+                .deleted_at
+                .to_rfc3339_opts(SecondsFormat::Micros, true),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::SecondsFormat;
+
+use crate::modules::{
+    books::domain::entities::{collection::Collection, location::Location},
+    sync::domain::{
+        dtos::{
+            synced_collection_dto::SyncedCollectionDto, synced_location_dto::SyncedLocationDto,
+        },
+        entities::synced_entity::SyncedEntity,
+    },
+};
+
+impl From<SyncedEntity<Location>> for SyncedLocationDto {
+    fn from(synced: SyncedEntity<Location>) -> Self {
+        SyncedLocationDto {
+            id: synced.entity.id.unwrap_or_default(),
+            name: synced.entity.name,
+            user_id: synced.entity.user_id,
+            library_id: synced.entity.library_id,
+            version: synced.entity.version,
+            updated_at: synced
+                .updated_at
+                .to_rfc3339_opts(SecondsFormat::Micros, true),
+        }
+    }
+}
+
+impl From<SyncedEntity<Collection>> for SyncedCollectionDto {
+    fn from(synced: SyncedEntity<Collection>) -> Self {
+        SyncedCollectionDto {
+            id: synced.entity.id.unwrap_or_default(),
+            name: synced.entity.name,
+            user_id: synced.entity.user_id,
+            library_id: synced.entity.library_id,
+            version: synced.entity.version,
+            updated_at: synced
+                .updated_at
+                .to_rfc3339_opts(SecondsFormat::Micros, true),
+        }
+    }
+}
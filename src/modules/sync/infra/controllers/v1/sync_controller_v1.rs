@@ -0,0 +1,196 @@
+use crate::modules::{
+    books::infra::repositories::{
+        audit_repository_mysql::AuditRepositoryMySQL, book_repository_mysql::BookRepositoryMySQL,
+        collection_repository_mysql::CollectionRepositoryMySQL,
+        custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+        location_repository_mysql::LocationRepositoryMySQL,
+        note_repository_mysql::NoteRepositoryMySQL, tag_repository_mysql::TagRepositoryMySQL,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::dtos::library_scope_params::LibraryScopeParams,
+        infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    },
+    shared::errors::{
+        detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+    },
+    sync::{
+        domain::{
+            dtos::{
+                push_sync_changes_dto::PushSyncChangesDto,
+                pushed_sync_changes_dto::PushedSyncChangesDto, sync_changes_dto::SyncChangesDto,
+                sync_params::SyncParams,
+            },
+            entities::sync_change::SyncPush,
+        },
+        infra::repositories::sync_repository_mysql::SyncRepositoryMySQL,
+        usecases::v1::{
+            pull_sync_changes_usecase::PullSyncChangesUseCaseV1,
+            push_sync_changes_usecase::PushSyncChangesUseCaseV1,
+        },
+    },
+    users::domain::dtos::authed_user::AuthedUser,
+};
+use actix_web::{get, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct SyncControllerV1 {
+    pull_sync_changes_usecase: PullSyncChangesUseCaseV1<
+        SyncRepositoryMySQL,
+        BookRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        NoteRepositoryMySQL,
+    >,
+    push_sync_changes_usecase: PushSyncChangesUseCaseV1<
+        SyncRepositoryMySQL,
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >,
+}
+
+impl SyncControllerV1 {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sync_repository: SyncRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        note_repository: NoteRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        SyncControllerV1 {
+            pull_sync_changes_usecase: PullSyncChangesUseCaseV1::new(
+                sync_repository.clone(),
+                book_repository.clone(),
+                library_repository.clone(),
+                tag_repository.clone(),
+                custom_field_repository.clone(),
+                note_repository,
+            ),
+            push_sync_changes_usecase: PushSyncChangesUseCaseV1::new(
+                sync_repository,
+                book_repository,
+                collection_repository,
+                location_repository,
+                library_repository,
+                tag_repository,
+                custom_field_repository,
+                audit_repository,
+                change_event_bus,
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "sync",
+    summary = "Fetch the changes of a library since the last sync",
+    description = "Sends the books, collections and locations changed since the informed sync token, and tombstones of the ones deleted, or the whole library when no token is informed. Store the returned token and send it on the next sync, while `has_more` is set sync again right away.",
+    params(
+        LibraryScopeParams,
+        SyncParams,
+    ),
+    responses(
+        (status = 200, description = "Changes since the last sync", body = SyncChangesDto),
+        (status = 400, description = "Informed sync token or page size is not valid", body = SimpleAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn pull_sync_changes(
+    sync_controller: web::Data<SyncControllerV1>,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    sync_params: web::Query<SyncParams>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+    match sync_controller
+        .pull_sync_changes_usecase
+        .pull(
+            authed_user.id.unwrap(),
+            library_scope_params.library_id,
+            sync_params.into_inner(),
+        )
+        .await
+    {
+        Ok(changes) => HttpResponse::Ok().json(web::Json(changes)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "sync",
+    summary = "Push changes made offline",
+    description = "Applies the changes in order and reports what became of each one. A change based on a copy that was changed on the server since is a conflict, the latest change wins, never considered newer than the time it reaches the server, and the server copy wins ties. Collections and locations created offline with the name of an existing one are merged into it.",
+    params(
+        LibraryScopeParams,
+    ),
+    request_body = PushSyncChangesDto,
+    responses(
+        (status = 200, description = "Outcome of each change", body = PushedSyncChangesDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[post("")]
+async fn push_sync_changes(
+    sync_controller: web::Data<SyncControllerV1>,
+    library_scope_params: web::Query<LibraryScopeParams>,
+    push_sync_changes_dto: web::Json<PushSyncChangesDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let push = match SyncPush::try_from(push_sync_changes_dto.0) {
+        Ok(push) => push,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match sync_controller
+        .push_sync_changes_usecase
+        .push(
+            authed_user.id.unwrap(),
+            library_scope_params.library_id,
+            push,
+        )
+        .await
+    {
+        Ok(pushed_changes) => HttpResponse::Ok().json(web::Json(pushed_changes)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(pull_sync_changes, push_sync_changes))]
+pub struct SyncApiV1;
+
+pub fn get_sync_scope() -> Scope {
+    web::scope("/v1/sync")
+        .service(pull_sync_changes)
+        .service(push_sync_changes)
+}
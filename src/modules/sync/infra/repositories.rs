@@ -0,0 +1,2 @@
+pub mod sync_repository;
+pub mod sync_repository_mysql;
@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use sqlx::Error;
+use std::future::Future;
+
+use crate::modules::{
+    books::domain::entities::{
+        audit_entry::AuditEntityType, collection::Collection, location::Location,
+    },
+    sync::domain::entities::{
+        sync_state::SyncState, sync_tombstone::SyncTombstone, synced_entity::SyncedEntity,
+    },
+};
+
+pub trait SyncRepository {
+    /// Current time of the database, the clock `updated_at` is taken from.
+    fn find_current_timestamp(&self) -> impl Future<Output = Result<DateTime<Utc>, Error>> + Send;
+    fn find_all_changed_book_ids(
+        &self,
+        library_id: u64,
+        after: Option<(DateTime<Utc>, u64)>,
+        until: DateTime<Utc>,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<SyncedEntity<u64>>, Error>> + Send;
+    fn find_all_changed_locations(
+        &self,
+        library_id: u64,
+        after: Option<DateTime<Utc>>,
+        until: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<SyncedEntity<Location>>, Error>> + Send;
+    fn find_all_changed_collections(
+        &self,
+        library_id: u64,
+        after: Option<DateTime<Utc>>,
+        until: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<SyncedEntity<Collection>>, Error>> + Send;
+    fn find_all_tombstones(
+        &self,
+        library_id: u64,
+        after: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<SyncTombstone>, Error>> + Send;
+    fn find_state(
+        &self,
+        entity_type: AuditEntityType,
+        id: u64,
+    ) -> impl Future<Output = Result<Option<SyncState>, Error>> + Send;
+}
@@ -0,0 +1,266 @@
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, MySqlPool, Row};
+use std::sync::Arc;
+
+use crate::modules::{
+    books::domain::entities::{
+        audit_entry::AuditEntityType, collection::Collection, location::Location,
+    },
+    sync::domain::entities::{
+        sync_state::SyncState, sync_tombstone::SyncTombstone, synced_entity::SyncedEntity,
+    },
+};
+
+use super::sync_repository::SyncRepository;
+
+#[derive(Clone)]
+pub struct SyncRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl SyncRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        SyncRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn table_name(entity_type: AuditEntityType) -> &'static str {
+    match entity_type {
+        AuditEntityType::Book => "books",
+        AuditEntityType::Collection => "collections",
+        AuditEntityType::Location => "locations",
+    }
+}
+
+/// Conditional of the rows changed after `after`, when informed, up to
+/// `until`, bound by `find_all_changed`.
+fn changed_conditional(after: Option<DateTime<Utc>>) -> &'static str {
+    match after {
+        Some(_) => "AND u.updated_at > ? AND u.updated_at <= ?",
+        None => "AND u.updated_at <= ?",
+    }
+}
+
+fn location_from_row(row: &MySqlRow) -> SyncedEntity<Location> {
+    SyncedEntity {
+        entity: Location {
+            id: Some(row.get("id")),
+            name: row.get("name"),
+            user_id: row.get("user_id"),
+            library_id: row.get("library_id"),
+            version: row.get("version"),
+        },
+        updated_at: row.get::<DateTime<Utc>, &str>("updated_at"),
+    }
+}
+
+fn collection_from_row(row: &MySqlRow) -> SyncedEntity<Collection> {
+    SyncedEntity {
+        entity: Collection {
+            id: Some(row.get("id")),
+            name: row.get("name"),
+            user_id: row.get("user_id"),
+            library_id: row.get("library_id"),
+            version: row.get("version"),
+        },
+        updated_at: row.get::<DateTime<Utc>, &str>("updated_at"),
+    }
+}
+
+fn tombstone_from_row(row: &MySqlRow) -> Option<SyncTombstone> {
+    Some(SyncTombstone {
+        entity_type: AuditEntityType::try_from(row.get::<String, &str>("entity_type").as_str())
+            .ok()?,
+        entity_id: row.get("entity_id"),
+        deleted_at: row.get::<DateTime<Utc>, &str>("deleted_at"),
+    })
+}
+
+impl SyncRepositoryMySQL {
+    async fn find_all_changed(
+        &self,
+        entity_type: AuditEntityType,
+        library_id: u64,
+        after: Option<DateTime<Utc>>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<MySqlRow>, sqlx::Error> {
+        let query = format!(
+            r#"
+            SELECT u.id, u.name, u.user_id, u.library_id, u.version, u.updated_at
+            FROM {} u
+            WHERE u.library_id = ? AND u.deleted_at IS NULL {}
+            ORDER BY u.updated_at, u.id
+            "#,
+            table_name(entity_type),
+            changed_conditional(after)
+        );
+        let mut query = sqlx::query(&query).bind(library_id);
+        if let Some(after) = after {
+            query = query.bind(after);
+        }
+        query.bind(until).fetch_all(self.connection.as_ref()).await
+    }
+}
+
+impl SyncRepository for SyncRepositoryMySQL {
+    async fn find_current_timestamp(&self) -> Result<DateTime<Utc>, sqlx::Error> {
+        let query_result = sqlx::query("SELECT CURRENT_TIMESTAMP(6) 'now'")
+            .fetch_one(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.get::<DateTime<Utc>, &str>("now")),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_changed_book_ids(
+        &self,
+        library_id: u64,
+        after: Option<(DateTime<Utc>, u64)>,
+        until: DateTime<Utc>,
+        limit: u64,
+    ) -> Result<Vec<SyncedEntity<u64>>, sqlx::Error> {
+        let query = format!(
+            r#"
+            SELECT u.id, u.updated_at
+            FROM books u
+            WHERE u.library_id = ? AND u.deleted_at IS NULL AND u.updated_at <= ? {}
+            ORDER BY u.updated_at, u.id
+            LIMIT ?
+            "#,
+            match after {
+                Some(_) => "AND (u.updated_at > ? OR (u.updated_at = ? AND u.id > ?))",
+                None => "",
+            }
+        );
+        let mut query = sqlx::query(&query).bind(library_id).bind(until);
+        if let Some((updated_at, book_id)) = after {
+            query = query.bind(updated_at).bind(updated_at).bind(book_id);
+        }
+        let query_result = query.bind(limit).fetch_all(self.connection.as_ref()).await;
+        match query_result {
+            Ok(rows) => Ok(rows
+                .iter()
+                .map(|row| SyncedEntity {
+                    entity: row.get("id"),
+                    updated_at: row.get::<DateTime<Utc>, &str>("updated_at"),
+                })
+                .collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_changed_locations(
+        &self,
+        library_id: u64,
+        after: Option<DateTime<Utc>>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<SyncedEntity<Location>>, sqlx::Error> {
+        let query_result = self
+            .find_all_changed(AuditEntityType::Location, library_id, after, until)
+            .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(location_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_changed_collections(
+        &self,
+        library_id: u64,
+        after: Option<DateTime<Utc>>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<SyncedEntity<Collection>>, sqlx::Error> {
+        let query_result = self
+            .find_all_changed(AuditEntityType::Collection, library_id, after, until)
+            .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(collection_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_tombstones(
+        &self,
+        library_id: u64,
+        after: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<SyncTombstone>, sqlx::Error> {
+        // Trashed rows are told apart by `updated_at`, which changes when
+        // they are moved to the trash, rows removed for good only remain
+        // in `sync_tombstones`.
+        let query_result = sqlx::query(
+            r#"
+            SELECT 'book' 'entity_type', t.id 'entity_id', t.updated_at 'deleted_at'
+            FROM books t
+            WHERE t.library_id = ? AND t.deleted_at IS NOT NULL
+                AND t.updated_at > ? AND t.updated_at <= ?
+            UNION ALL
+            SELECT 'collection' 'entity_type', t.id 'entity_id', t.updated_at 'deleted_at'
+            FROM collections t
+            WHERE t.library_id = ? AND t.deleted_at IS NOT NULL
+                AND t.updated_at > ? AND t.updated_at <= ?
+            UNION ALL
+            SELECT 'location' 'entity_type', t.id 'entity_id', t.updated_at 'deleted_at'
+            FROM locations t
+            WHERE t.library_id = ? AND t.deleted_at IS NOT NULL
+                AND t.updated_at > ? AND t.updated_at <= ?
+            UNION ALL
+            SELECT t.entity_type, t.entity_id, t.deleted_at
+            FROM sync_tombstones t
+            WHERE t.library_id = ? AND t.deleted_at > ? AND t.deleted_at <= ?
+            ORDER BY deleted_at, entity_id
+            "#,
+        )
+        .bind(library_id)
+        .bind(after)
+        .bind(until)
+        .bind(library_id)
+        .bind(after)
+        .bind(until)
+        .bind(library_id)
+        .bind(after)
+        .bind(until)
+        .bind(library_id)
+        .bind(after)
+        .bind(until)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().filter_map(tombstone_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_state(
+        &self,
+        entity_type: AuditEntityType,
+        id: u64,
+    ) -> Result<Option<SyncState>, sqlx::Error> {
+        let query = format!(
+            r#"
+            SELECT u.library_id, u.version, u.updated_at, u.deleted_at
+            FROM {} u
+            WHERE u.id = ?
+            "#,
+            table_name(entity_type)
+        );
+        let query_result = sqlx::query(&query)
+            .bind(id)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.map(|row| SyncState {
+                library_id: row.get("library_id"),
+                version: row.get("version"),
+                updated_at: row.get::<DateTime<Utc>, &str>("updated_at"),
+                deleted: row
+                    .get::<Option<DateTime<Utc>>, &str>("deleted_at")
+                    .is_some(),
+            })),
+            Err(error) => Err(error),
+        }
+    }
+}
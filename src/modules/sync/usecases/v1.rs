@@ -0,0 +1,2 @@
+pub mod pull_sync_changes_usecase;
+pub mod push_sync_changes_usecase;
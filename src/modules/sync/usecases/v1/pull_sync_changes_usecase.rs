@@ -0,0 +1,312 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+
+use crate::modules::{
+    books::infra::repositories::{
+        book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+        custom_field_repository::CustomFieldRepository,
+        custom_field_repository_mysql::CustomFieldRepositoryMySQL, note_repository::NoteRepository,
+        note_repository_mysql::NoteRepositoryMySQL, tag_repository::TagRepository,
+        tag_repository_mysql::TagRepositoryMySQL,
+    },
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    sync::{
+        domain::{
+            dtos::{
+                sync_changes_dto::SyncChangesDto, sync_params::SyncParams,
+                sync_tombstone_dto::SyncTombstoneDto, synced_book_dto::SyncedBookDto,
+                synced_collection_dto::SyncedCollectionDto, synced_location_dto::SyncedLocationDto,
+            },
+            entities::sync_token::SyncToken,
+        },
+        infra::repositories::{
+            sync_repository::SyncRepository, sync_repository_mysql::SyncRepositoryMySQL,
+        },
+    },
+};
+
+const DEFAULT_SYNC_PAGE_SIZE: u64 = 500;
+const MAX_SYNC_PAGE_SIZE: u64 = 1000;
+
+/// Changes are only sent once they are this old, so ones being committed
+/// with an earlier `updated_at` while a sync runs aren't skipped.
+const SYNC_SETTLE_DELAY_MILLIS: i64 = 2000;
+
+pub struct PullSyncChangesUseCaseV1<S, T, U, V, W, X>
+where
+    S: SyncRepository,
+    T: BookRepository,
+    U: LibraryRepository,
+    V: TagRepository,
+    W: CustomFieldRepository,
+    X: NoteRepository,
+{
+    sync_repository: Arc<S>,
+    book_repository: Arc<T>,
+    tag_repository: Arc<V>,
+    custom_field_repository: Arc<W>,
+    note_repository: Arc<X>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+}
+
+impl
+    PullSyncChangesUseCaseV1<
+        SyncRepositoryMySQL,
+        BookRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        NoteRepositoryMySQL,
+    >
+{
+    pub fn new(
+        sync_repository: SyncRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        note_repository: NoteRepositoryMySQL,
+    ) -> Self {
+        Self {
+            sync_repository: Arc::new(sync_repository),
+            book_repository: Arc::new(book_repository),
+            tag_repository: Arc::new(tag_repository),
+            custom_field_repository: Arc::new(custom_field_repository),
+            note_repository: Arc::new(note_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+        }
+    }
+
+    pub async fn pull(
+        &self,
+        user_id: u64,
+        library_id: Option<u64>,
+        params: SyncParams,
+    ) -> Result<SyncChangesDto, APIError> {
+        let page_size = match params.page_size {
+            Some(page_size) if !(1..=MAX_SYNC_PAGE_SIZE).contains(&page_size) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!(
+                        "Requested page size must be between 1 and {}",
+                        MAX_SYNC_PAGE_SIZE
+                    ),
+                    400,
+                )));
+            }
+            Some(page_size) => page_size,
+            None => DEFAULT_SYNC_PAGE_SIZE,
+        };
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(user_id, library_id, LibraryRole::Viewer)
+            .await?;
+
+        let token = match params.sync_token.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(token) => match SyncToken::decode(token) {
+                Ok(token) if token.library_id == library_id => Some(token),
+                Ok(_) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "Informed sync token belongs to another library".to_string(),
+                        400,
+                    )))
+                }
+                Err(error) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(error, 400)))
+                }
+            },
+        };
+
+        let now = match self.sync_repository.find_current_timestamp().await {
+            Ok(found) => found,
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let mut until = now - Duration::milliseconds(SYNC_SETTLE_DELAY_MILLIS);
+        if let Some(token) = &token {
+            until = until.max(token.synced_until);
+        }
+
+        let mut changed_books = match self
+            .sync_repository
+            .find_all_changed_book_ids(
+                library_id,
+                token
+                    .as_ref()
+                    .map(|token| (token.synced_until, token.book_id)),
+                until,
+                page_size + 1,
+            )
+            .await
+        {
+            Ok(found) => found,
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let has_more = changed_books.len() as u64 > page_size;
+        let mut next_token = SyncToken {
+            library_id,
+            synced_until: until,
+            book_id: u64::MAX,
+        };
+        if has_more {
+            changed_books.truncate(page_size as usize);
+            if let Some(last_book) = changed_books.last() {
+                until = last_book.updated_at;
+                next_token.synced_until = last_book.updated_at;
+                next_token.book_id = last_book.entity;
+            }
+        }
+
+        // Collections, locations and tombstones are sent once, along with
+        // the page of books reaching their `updated_at`.
+        let after: Option<DateTime<Utc>> = token.as_ref().map(|token| token.synced_until);
+        let mut locations = Vec::new();
+        let mut collections = Vec::new();
+        let mut tombstones = Vec::new();
+        if after.is_none_or(|after| after < until) {
+            locations = match self
+                .sync_repository
+                .find_all_changed_locations(library_id, after, until)
+                .await
+            {
+                Ok(found) => found,
+                Err(e) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError {
+                        msg: e.to_string(),
+                        code: 500,
+                    }))
+                }
+            };
+            collections = match self
+                .sync_repository
+                .find_all_changed_collections(library_id, after, until)
+                .await
+            {
+                Ok(found) => found,
+                Err(e) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError {
+                        msg: e.to_string(),
+                        code: 500,
+                    }))
+                }
+            };
+            if let Some(after) = after {
+                tombstones = match self
+                    .sync_repository
+                    .find_all_tombstones(library_id, after, until)
+                    .await
+                {
+                    Ok(found) => found,
+                    Err(e) => {
+                        return Err(APIError::SimpleAPIError(SimpleAPIError {
+                            msg: e.to_string(),
+                            code: 500,
+                        }))
+                    }
+                };
+            }
+        }
+
+        let book_ids: Vec<u64> = changed_books.iter().map(|book| book.entity).collect();
+        let mut books = match self
+            .book_repository
+            .find_all_by_ids_as_complete_book_dto(library_id, &book_ids)
+            .await
+        {
+            Ok(found) => found,
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let mut tags_by_book = match self
+            .tag_repository
+            .find_all_by_book_ids_and_user_id(&book_ids, user_id)
+            .await
+        {
+            Ok(found) => found,
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let mut custom_fields_by_book = match self
+            .custom_field_repository
+            .find_all_values_by_book_ids_and_user_id(&book_ids, user_id)
+            .await
+        {
+            Ok(found) => found,
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        let mut note_counts_by_book = match self
+            .note_repository
+            .count_by_book_ids_and_user_id(&book_ids, user_id)
+            .await
+        {
+            Ok(found) => found,
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+        for book in books.iter_mut() {
+            book.tags = tags_by_book.remove(&book.id).unwrap_or_default();
+            book.custom_fields = custom_fields_by_book.remove(&book.id).unwrap_or_default();
+            book.note_count = note_counts_by_book.remove(&book.id).unwrap_or_default();
+        }
+
+        let mut synced_books = Vec::with_capacity(books.len());
+        for changed_book in changed_books {
+            if let Some(position) = books.iter().position(|book| book.id == changed_book.entity) {
+                synced_books.push(SyncedBookDto {
+                    book: books.swap_remove(position),
+                    updated_at: changed_book
+                        .updated_at
+                        .to_rfc3339_opts(SecondsFormat::Micros, true),
+                });
+            }
+        }
+
+        Ok(SyncChangesDto {
+            books: synced_books,
+            collections: collections
+                .into_iter()
+                .map(SyncedCollectionDto::from)
+                .collect(),
+            locations: locations.into_iter().map(SyncedLocationDto::from).collect(),
+            tombstones: tombstones.into_iter().map(SyncTombstoneDto::from).collect(),
+            sync_token: next_token.encode(),
+            has_more,
+        })
+    }
+}
@@ -0,0 +1,512 @@
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::{DateTime, Utc};
+
+use crate::modules::{
+    books::{
+        domain::entities::{
+            audit_entry::AuditEntityType, book::Book, collection::Collection, location::Location,
+        },
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+            book_repository::BookRepository, book_repository_mysql::BookRepositoryMySQL,
+            collection_repository::CollectionRepository,
+            collection_repository_mysql::CollectionRepositoryMySQL,
+            custom_field_repository::CustomFieldRepository,
+            custom_field_repository_mysql::CustomFieldRepositoryMySQL,
+            location_repository::LocationRepository,
+            location_repository_mysql::LocationRepositoryMySQL, tag_repository::TagRepository,
+            tag_repository_mysql::TagRepositoryMySQL,
+        },
+        usecases::v1::{
+            create_collection_usecase::CreateCollectionUseCaseV1,
+            create_location_usecase::CreateLocationUseCaseV1,
+            create_update_book_usecase::CreateUpdateBookUseCaseV1,
+            delete_book_usecase::DeleteBookUseCaseV1,
+            delete_collection_usecase::DeleteCollectionUseCaseV1,
+            delete_location_usecase::DeleteLocationUseCaseV1,
+        },
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    sync::{
+        domain::{
+            dtos::{
+                pushed_sync_changes_dto::PushedSyncChangesDto,
+                sync_change_result_dto::SyncChangeResultDto,
+            },
+            entities::{
+                sync_change::{
+                    resolve_sync_conflict, SyncChange, SyncChangeOutcome, SyncChangeStatus,
+                    SyncOperation, SyncPush, SyncResolution,
+                },
+                sync_state::SyncState,
+            },
+        },
+        infra::repositories::{
+            sync_repository::SyncRepository, sync_repository_mysql::SyncRepositoryMySQL,
+        },
+    },
+};
+
+fn error_code(error: &APIError) -> u16 {
+    match error {
+        APIError::SimpleAPIError(sae) => sae.code,
+        APIError::DetailedAPIError(dae) => dae.code,
+        APIError::PreconditionFailedAPIError(pfae) => pfae.code,
+    }
+}
+
+fn error_message(error: APIError) -> String {
+    match error {
+        APIError::SimpleAPIError(sae) => sae.msg,
+        APIError::DetailedAPIError(dae) => {
+            let mut validations: Vec<String> = dae
+                .field_validations
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(field, validation)| format!("{}: {}", field, validation))
+                .collect();
+            validations.sort();
+            if validations.is_empty() {
+                dae.msg
+            } else {
+                format!("{} ({})", dae.msg, validations.join("; "))
+            }
+        }
+        APIError::PreconditionFailedAPIError(pfae) => pfae.msg,
+    }
+}
+
+fn server_wins(id: u64, message: &str) -> SyncChangeOutcome {
+    SyncChangeOutcome {
+        id: Some(id),
+        status: SyncChangeStatus::Conflict,
+        resolution: Some(SyncResolution::ServerWins),
+        message: Some(message.to_string()),
+    }
+}
+
+fn rejected(message: &str) -> APIError {
+    APIError::SimpleAPIError(SimpleAPIError::new(message.to_string(), 422))
+}
+
+#[derive(Default)]
+struct CreatedIds {
+    locations: HashMap<String, u64>,
+    collections: HashMap<String, u64>,
+}
+
+pub struct PushSyncChangesUseCaseV1<S, T, U, V, W, X, Y, Z>
+where
+    S: SyncRepository,
+    T: BookRepository,
+    U: CollectionRepository,
+    V: LocationRepository,
+    W: LibraryRepository,
+    X: TagRepository,
+    Y: CustomFieldRepository,
+    Z: AuditRepository,
+{
+    sync_repository: Arc<S>,
+    collection_repository: Arc<U>,
+    location_repository: Arc<V>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<W>,
+    create_update_book_usecase: CreateUpdateBookUseCaseV1<T, U, V, W, X, Y, Z>,
+    delete_book_usecase: DeleteBookUseCaseV1<T, W, Z>,
+    create_collection_usecase: CreateCollectionUseCaseV1<U, W, Z>,
+    delete_collection_usecase: DeleteCollectionUseCaseV1<U, W, Z>,
+    create_location_usecase: CreateLocationUseCaseV1<V, W, Z>,
+    delete_location_usecase: DeleteLocationUseCaseV1<V, W, Z>,
+}
+
+impl
+    PushSyncChangesUseCaseV1<
+        SyncRepositoryMySQL,
+        BookRepositoryMySQL,
+        CollectionRepositoryMySQL,
+        LocationRepositoryMySQL,
+        LibraryRepositoryMySQL,
+        TagRepositoryMySQL,
+        CustomFieldRepositoryMySQL,
+        AuditRepositoryMySQL,
+    >
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sync_repository: SyncRepositoryMySQL,
+        book_repository: BookRepositoryMySQL,
+        collection_repository: CollectionRepositoryMySQL,
+        location_repository: LocationRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        tag_repository: TagRepositoryMySQL,
+        custom_field_repository: CustomFieldRepositoryMySQL,
+        audit_repository: AuditRepositoryMySQL,
+        change_event_bus: ChangeEventBus,
+    ) -> Self {
+        Self {
+            sync_repository: Arc::new(sync_repository),
+            collection_repository: Arc::new(collection_repository.clone()),
+            location_repository: Arc::new(location_repository.clone()),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository.clone(),
+            ),
+            create_update_book_usecase: CreateUpdateBookUseCaseV1::new(
+                book_repository.clone(),
+                collection_repository.clone(),
+                location_repository.clone(),
+                library_repository.clone(),
+                tag_repository,
+                custom_field_repository,
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
+            delete_book_usecase: DeleteBookUseCaseV1::new(
+                book_repository,
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
+            create_collection_usecase: CreateCollectionUseCaseV1::new(
+                collection_repository.clone(),
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
+            delete_collection_usecase: DeleteCollectionUseCaseV1::new(
+                collection_repository,
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
+            create_location_usecase: CreateLocationUseCaseV1::new(
+                location_repository.clone(),
+                library_repository.clone(),
+                audit_repository.clone(),
+                change_event_bus.clone(),
+            ),
+            delete_location_usecase: DeleteLocationUseCaseV1::new(
+                location_repository,
+                library_repository,
+                audit_repository,
+                change_event_bus,
+            ),
+        }
+    }
+
+    pub async fn push(
+        &self,
+        user_id: u64,
+        library_id: Option<u64>,
+        push: SyncPush,
+    ) -> Result<PushedSyncChangesDto, APIError> {
+        let library_id = self
+            .authorize_library_member_usecase
+            .authorize(user_id, library_id, LibraryRole::Editor)
+            .await?;
+
+        let now = match self.sync_repository.find_current_timestamp().await {
+            Ok(now) => now,
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: e.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        let mut created_ids = CreatedIds::default();
+        let mut results = Vec::with_capacity(push.changes.len());
+        for (index, change) in push.changes.into_iter().enumerate() {
+            let entity_type = change.entity_type;
+            let client_id = change.client_id.clone();
+            let outcome = match self
+                .apply(user_id, library_id, now, change, &mut created_ids)
+                .await
+            {
+                Ok(outcome) => outcome,
+                Err(error) if error_code(&error) >= 500 => return Err(error),
+                Err(error) => SyncChangeOutcome {
+                    id: None,
+                    status: SyncChangeStatus::Rejected,
+                    resolution: None,
+                    message: Some(error_message(error)),
+                },
+            };
+            results.push(SyncChangeResultDto {
+                index,
+                entity_type: entity_type.as_str().to_string(),
+                id: outcome.id,
+                client_id,
+                status: outcome.status.as_str().to_string(),
+                resolution: outcome
+                    .resolution
+                    .map(|resolution| resolution.as_str().to_string()),
+                message: outcome.message,
+            });
+        }
+
+        Ok(PushedSyncChangesDto { results })
+    }
+
+    async fn apply(
+        &self,
+        user_id: u64,
+        library_id: u64,
+        now: DateTime<Utc>,
+        change: SyncChange,
+        created_ids: &mut CreatedIds,
+    ) -> Result<SyncChangeOutcome, APIError> {
+        let id = match change.id {
+            Some(id) => id,
+            None => return self.create(user_id, library_id, change, created_ids).await,
+        };
+
+        let state = match self.find_state(change.entity_type, id).await? {
+            Some(state) if state.library_id != library_id => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Entity not found".to_string(),
+                    404,
+                )))
+            }
+            Some(state) if !state.deleted => state,
+            _ if change.operation == SyncOperation::Delete => {
+                return Ok(SyncChangeOutcome::new(Some(id), SyncChangeStatus::Applied));
+            }
+            _ => return Ok(server_wins(id, "Entity was deleted on the server")),
+        };
+
+        let resolution = resolve_sync_conflict(
+            change.base_updated_at,
+            change.changed_at,
+            state.updated_at,
+            now,
+        );
+        if resolution == Some(SyncResolution::ServerWins) {
+            return Ok(server_wins(
+                id,
+                "Server copy was changed after the client one",
+            ));
+        }
+
+        let applied = match change.operation {
+            SyncOperation::Upsert => {
+                let book = self
+                    .book_from_change(user_id, library_id, change, created_ids)
+                    .await?;
+                self.create_update_book_usecase
+                    .create_update_book(
+                        user_id,
+                        Book {
+                            id: Some(id),
+                            ..book
+                        },
+                        Some(state.version),
+                    )
+                    .await
+                    .map(|_| ())
+            }
+            SyncOperation::Delete => match change.entity_type {
+                AuditEntityType::Book => {
+                    self.delete_book_usecase
+                        .delete_book_by_id(user_id, id, Some(state.version))
+                        .await
+                }
+                AuditEntityType::Collection => {
+                    self.delete_collection_usecase
+                        .delete_collection(id, user_id, Some(state.version))
+                        .await
+                }
+                AuditEntityType::Location => {
+                    self.delete_location_usecase
+                        .delete_location(id, user_id, Some(state.version))
+                        .await
+                }
+            },
+        };
+
+        match applied {
+            Ok(()) => Ok(SyncChangeOutcome {
+                id: Some(id),
+                status: SyncChangeStatus::Applied,
+                resolution,
+                message: None,
+            }),
+            // Changed again while the push was being applied.
+            Err(APIError::PreconditionFailedAPIError(_)) => Ok(server_wins(
+                id,
+                "Server copy was changed while the change was applied",
+            )),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn create(
+        &self,
+        user_id: u64,
+        library_id: u64,
+        change: SyncChange,
+        created_ids: &mut CreatedIds,
+    ) -> Result<SyncChangeOutcome, APIError> {
+        let client_id = change.client_id.clone().unwrap_or_default();
+        match change.entity_type {
+            AuditEntityType::Book => {
+                let book = self
+                    .book_from_change(user_id, library_id, change, created_ids)
+                    .await?;
+                let created_book = self
+                    .create_update_book_usecase
+                    .create_update_book(user_id, book, None)
+                    .await?;
+                Ok(SyncChangeOutcome::new(
+                    Some(created_book.id),
+                    SyncChangeStatus::Applied,
+                ))
+            }
+            AuditEntityType::Collection => {
+                let name = change.name.unwrap_or_default();
+                let created = self
+                    .create_collection_usecase
+                    .create_collection(
+                        Collection {
+                            name: name.clone(),
+                            user_id,
+                            ..Default::default()
+                        },
+                        Some(library_id),
+                    )
+                    .await;
+                let outcome = match created {
+                    Ok(collection) => {
+                        SyncChangeOutcome::new(collection.id, SyncChangeStatus::Applied)
+                    }
+                    // Created offline on more than one device, or pushed
+                    // again, the existing collection is kept.
+                    Err(APIError::SimpleAPIError(sae)) if sae.code == 409 => {
+                        match self
+                            .collection_repository
+                            .find_by_name_and_library_id(&name, library_id)
+                            .await
+                        {
+                            Ok(Some(collection)) => {
+                                SyncChangeOutcome::new(collection.id, SyncChangeStatus::Merged)
+                            }
+                            Ok(None) => return Err(APIError::SimpleAPIError(sae)),
+                            Err(e) => {
+                                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                                    msg: e.to_string(),
+                                    code: 500,
+                                }))
+                            }
+                        }
+                    }
+                    Err(error) => return Err(error),
+                };
+                if let Some(id) = outcome.id {
+                    created_ids.collections.insert(client_id, id);
+                }
+                Ok(outcome)
+            }
+            AuditEntityType::Location => {
+                let name = change.name.unwrap_or_default();
+                let created = self
+                    .create_location_usecase
+                    .create_location(
+                        Location {
+                            name: name.clone(),
+                            user_id,
+                            ..Default::default()
+                        },
+                        Some(library_id),
+                    )
+                    .await;
+                let outcome = match created {
+                    Ok(location) => SyncChangeOutcome::new(location.id, SyncChangeStatus::Applied),
+                    Err(APIError::SimpleAPIError(sae)) if sae.code == 409 => {
+                        match self
+                            .location_repository
+                            .find_by_name_and_library_id(&name, library_id)
+                            .await
+                        {
+                            Ok(Some(location)) => {
+                                SyncChangeOutcome::new(location.id, SyncChangeStatus::Merged)
+                            }
+                            Ok(None) => return Err(APIError::SimpleAPIError(sae)),
+                            Err(e) => {
+                                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                                    msg: e.to_string(),
+                                    code: 500,
+                                }))
+                            }
+                        }
+                    }
+                    Err(error) => return Err(error),
+                };
+                if let Some(id) = outcome.id {
+                    created_ids.locations.insert(client_id, id);
+                }
+                Ok(outcome)
+            }
+        }
+    }
+
+    async fn book_from_change(
+        &self,
+        user_id: u64,
+        library_id: u64,
+        change: SyncChange,
+        created_ids: &CreatedIds,
+    ) -> Result<Book, APIError> {
+        let mut book_dto = change.book.unwrap_or_default();
+        if let Some(location_client_id) = &change.location_client_id {
+            match created_ids.locations.get(location_client_id) {
+                Some(location_id) => book_dto.location_id = Some(*location_id),
+                None => return Err(rejected("Informed location client id is unknown")),
+            }
+        }
+        if let Some(collection_client_id) = &change.collection_client_id {
+            match created_ids.collections.get(collection_client_id) {
+                Some(collection_id) => book_dto.collection_id = Some(*collection_id),
+                None => return Err(rejected("Informed collection client id is unknown")),
+            }
+        }
+        book_dto.user_id = Some(user_id);
+        book_dto.version = None;
+
+        let book = match Book::try_from(book_dto) {
+            Ok(book) => book,
+            Err(e) => return Err(APIError::DetailedAPIError(e)),
+        };
+        match self
+            .find_state(AuditEntityType::Location, book.location_id)
+            .await?
+        {
+            Some(location) if location.library_id == library_id && !location.deleted => Ok(book),
+            _ => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "The informed book's location does not exist".to_string(),
+                404,
+            ))),
+        }
+    }
+
+    async fn find_state(
+        &self,
+        entity_type: AuditEntityType,
+        id: u64,
+    ) -> Result<Option<SyncState>, APIError> {
+        match self.sync_repository.find_state(entity_type, id).await {
+            Ok(state) => Ok(state),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: e.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
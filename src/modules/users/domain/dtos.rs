@@ -1,6 +1,15 @@
 pub mod authed_user;
 pub mod claims_dto;
+pub mod confirm_two_factor_dto;
 pub mod create_user_dto;
 pub mod created_user_dto;
+pub mod disable_two_factor_dto;
 pub mod login_user_dto;
+pub mod oidc_callback_params_dto;
+pub mod oidc_provider_dto;
+pub mod recovery_codes_dto;
 pub mod token_user_dto;
+pub mod two_factor_challenge_claims_dto;
+pub mod two_factor_challenge_dto;
+pub mod two_factor_enrollment_dto;
+pub mod verify_two_factor_login_dto;
@@ -1,6 +1,9 @@
+use async_graphql::SimpleObject;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Deserialize, Default, Serialize)]
+#[derive(Deserialize, Default, Serialize, ToSchema, SimpleObject)]
+#[graphql(name = "User")]
 pub struct CreatedUserDto {
     pub id: u64,
     pub email: String,
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct DisableTwoFactorDto {
+    pub code: Option<String>,
+    pub recovery_code: Option<String>,
+}
@@ -0,0 +1,7 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct OidcProviderDto {
+    pub name: String,
+}
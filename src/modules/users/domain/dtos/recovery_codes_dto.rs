@@ -0,0 +1,8 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct RecoveryCodesDto {
+    /// Single use codes, shown only this time.
+    pub recovery_codes: Vec<String>,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+pub const TWO_FACTOR_CHALLENGE_AUDIENCE: &str = "two_factor";
+
+/// Claims of the token returned by the password step when two-factor
+/// authentication is enabled. The audience keeps it from being taken as an
+/// access token.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct TwoFactorChallengeClaimsDto {
+    pub id: u64,
+    pub email: String,
+    pub exp: i64,
+    pub aud: String,
+}
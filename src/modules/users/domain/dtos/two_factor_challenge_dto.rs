@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct TwoFactorChallengeDto {
+    /// Informed with a code to `/v1/auth/login/two-factor`.
+    pub challenge_token: String,
+    pub expires_in: i64,
+}
@@ -0,0 +1,10 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct TwoFactorEnrollmentDto {
+    /// Base32 encoded, for typing into the authenticator app.
+    pub secret: String,
+    pub otpauth_uri: String,
+    pub qr_code_svg: Option<String>,
+}
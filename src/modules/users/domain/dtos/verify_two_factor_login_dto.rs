@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct VerifyTwoFactorLoginDto {
+    pub challenge_token: Option<String>,
+    pub code: Option<String>,
+    pub recovery_code: Option<String>,
+}
@@ -1 +1,10 @@
+pub mod id_token;
+pub mod login_failures;
+pub mod oidc_authorization;
+pub mod oidc_provider_metadata;
+pub mod recovery_code;
+pub mod token_bucket;
+pub mod totp;
+pub mod two_factor;
 pub mod user;
+pub mod user_identity;
@@ -0,0 +1,88 @@
+use jsonwebtoken::{
+    decode, decode_header,
+    jwk::{Jwk, JwkSet},
+    Algorithm, DecodingKey, Validation,
+};
+use serde::Deserialize;
+
+/// Algorithms an ID token may be signed with, the symmetric ones would take
+/// the client secret as key and `none` is never accepted.
+const ID_TOKEN_ALGORITHMS: [Algorithm; 8] = [
+    Algorithm::RS256,
+    Algorithm::RS384,
+    Algorithm::RS512,
+    Algorithm::PS256,
+    Algorithm::PS384,
+    Algorithm::PS512,
+    Algorithm::ES256,
+    Algorithm::ES384,
+];
+
+#[derive(Debug, Deserialize)]
+pub struct IdTokenClaims {
+    pub iss: String,
+    pub sub: String,
+    pub exp: i64,
+    pub nonce: Option<String>,
+    pub email: Option<String>,
+    #[serde(default)]
+    pub email_verified: bool,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum IdTokenError {
+    UnknownKey,
+    Invalid(String),
+}
+
+pub fn validate_id_token(
+    id_token: &str,
+    jwks: &JwkSet,
+    issuer: &str,
+    client_id: &str,
+    nonce: &str,
+) -> Result<IdTokenClaims, IdTokenError> {
+    let header = match decode_header(id_token) {
+        Ok(header) => header,
+        Err(error) => return Err(IdTokenError::Invalid(error.to_string())),
+    };
+    if !ID_TOKEN_ALGORITHMS.contains(&header.alg) {
+        return Err(IdTokenError::Invalid(format!(
+            "ID token signed with unsupported algorithm {:?}",
+            header.alg
+        )));
+    }
+    let jwk = match find_key(jwks, header.kid.as_deref()) {
+        Some(jwk) => jwk,
+        None => return Err(IdTokenError::UnknownKey),
+    };
+    let decoding_key = match DecodingKey::from_jwk(jwk) {
+        Ok(decoding_key) => decoding_key,
+        Err(error) => return Err(IdTokenError::Invalid(error.to_string())),
+    };
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_issuer(&[issuer]);
+    validation.set_audience(&[client_id]);
+    validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+    let claims = match decode::<IdTokenClaims>(id_token, &decoding_key, &validation) {
+        Ok(token_data) => token_data.claims,
+        Err(error) => return Err(IdTokenError::Invalid(error.to_string())),
+    };
+
+    if claims.nonce.as_deref() != Some(nonce) {
+        return Err(IdTokenError::Invalid(
+            "ID token nonce does not match the login".to_string(),
+        ));
+    }
+    Ok(claims)
+}
+
+fn find_key<'a>(jwks: &'a JwkSet, kid: Option<&str>) -> Option<&'a Jwk> {
+    match kid {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+}
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Duration, Utc};
+
+#[derive(Debug, Clone, Copy)]
+pub struct LockoutPolicy {
+    pub threshold: u32,
+    pub base: Duration,
+    pub max: Duration,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LoginFailures {
+    pub failures: u32,
+    pub last_failure_at: Option<DateTime<Utc>>,
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+impl LoginFailures {
+    pub fn locked_until(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.locked_until.filter(|locked_until| *locked_until > now)
+    }
+
+    pub fn record_failure(
+        &mut self,
+        policy: &LockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        if self
+            .last_failure_at
+            .is_some_and(|last_failure_at| now - last_failure_at > policy.max)
+        {
+            *self = LoginFailures::default();
+        }
+        self.failures += 1;
+        self.last_failure_at = Some(now);
+
+        if policy.threshold > 0 && self.failures >= policy.threshold {
+            let doublings = (self.failures - policy.threshold).min(30);
+            let lock = policy
+                .base
+                .checked_mul(1 << doublings)
+                .unwrap_or(policy.max)
+                .min(policy.max);
+            self.locked_until = Some(now + lock);
+        }
+        self.locked_until(now)
+    }
+
+    pub fn is_expired(&self, policy: &LockoutPolicy, now: DateTime<Utc>) -> bool {
+        self.locked_until(now).is_none()
+            && self
+                .last_failure_at
+                .is_none_or(|last_failure_at| now - last_failure_at > policy.max)
+    }
+}
@@ -0,0 +1,53 @@
+use base64::prelude::*;
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// Random bytes of the state, nonce and code verifier, encoded to 43
+/// characters, the least RFC 7636 allows for the verifier.
+const OIDC_RANDOM_LENGTH: usize = 32;
+
+#[derive(Debug, Clone)]
+pub struct OidcAuthorization {
+    pub provider: String,
+    pub state: String,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl OidcAuthorization {
+    pub fn new(provider: &str, expires_at: DateTime<Utc>) -> Self {
+        OidcAuthorization {
+            provider: provider.to_string(),
+            state: random_url_safe_string(),
+            nonce: random_url_safe_string(),
+            code_verifier: random_url_safe_string(),
+            expires_at,
+        }
+    }
+
+    pub fn code_challenge(&self) -> String {
+        pkce_code_challenge(&self.code_verifier)
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+pub fn pkce_code_challenge(code_verifier: &str) -> String {
+    BASE64_URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+fn random_url_safe_string() -> String {
+    let mut bytes = [0u8; OIDC_RANDOM_LENGTH];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    BASE64_URL_SAFE_NO_PAD.encode(bytes)
+}
+
+#[derive(Debug)]
+pub struct OidcCallback {
+    pub code: String,
+    pub state: String,
+}
@@ -0,0 +1,35 @@
+use rand::{distributions::Alphanumeric, Rng};
+use sha2::{Digest, Sha256};
+
+pub const RECOVERY_CODES_COUNT: usize = 10;
+const RECOVERY_CODE_GROUP_LENGTH: usize = 5;
+
+pub fn generate_recovery_codes() -> Vec<String> {
+    let mut rng = rand::thread_rng();
+    (0..RECOVERY_CODES_COUNT)
+        .map(|_| {
+            let code: String = (&mut rng)
+                .sample_iter(&Alphanumeric)
+                .take(RECOVERY_CODE_GROUP_LENGTH * 2)
+                .map(|c| (c as char).to_ascii_lowercase())
+                .collect();
+            format!(
+                "{}-{}",
+                &code[..RECOVERY_CODE_GROUP_LENGTH],
+                &code[RECOVERY_CODE_GROUP_LENGTH..]
+            )
+        })
+        .collect()
+}
+
+/// Only hashes are stored, case, dashes and spaces are ignored.
+pub fn hash_recovery_code(code: &str) -> String {
+    let normalized: String = code
+        .chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    let mut hasher = Sha256::new();
+    hasher.update(normalized.as_bytes());
+    hex::encode(hasher.finalize())
+}
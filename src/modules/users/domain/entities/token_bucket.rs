@@ -0,0 +1,44 @@
+use chrono::{DateTime, Duration, Utc};
+
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    pub tokens: f64,
+    pub refilled_at: DateTime<Utc>,
+    pub full_at: DateTime<Utc>,
+}
+
+impl TokenBucket {
+    pub fn new(capacity: u32, now: DateTime<Utc>) -> Self {
+        TokenBucket {
+            tokens: capacity as f64,
+            refilled_at: now,
+            full_at: now,
+        }
+    }
+
+    pub fn take(
+        &mut self,
+        capacity: u32,
+        refill_interval: Duration,
+        now: DateTime<Utc>,
+    ) -> Result<(), Duration> {
+        let interval_millis = refill_interval.num_milliseconds().max(1) as f64;
+        let elapsed_millis = (now - self.refilled_at).num_milliseconds().max(0) as f64;
+        self.tokens = (self.tokens + elapsed_millis / interval_millis).min(capacity as f64);
+        self.refilled_at = self.refilled_at.max(now);
+
+        let taken = if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::milliseconds(
+                ((1.0 - self.tokens) * interval_millis).ceil() as i64,
+            ))
+        };
+        self.full_at = self.refilled_at
+            + Duration::milliseconds(
+                ((capacity as f64 - self.tokens) * interval_millis).ceil() as i64
+            );
+        taken
+    }
+}
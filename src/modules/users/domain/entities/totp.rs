@@ -0,0 +1,219 @@
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use subtle::ConstantTimeEq;
+
+pub const TOTP_SECRET_LENGTH: usize = 20;
+pub const TOTP_DIGITS: u32 = 6;
+pub const TOTP_PERIOD_SECONDS: i64 = 30;
+/// Codes of the time steps right before and after the current one are
+/// accepted too, to make up for clock drift.
+pub const TOTP_ALLOWED_DRIFT_STEPS: u64 = 1;
+pub const TOTP_ISSUER: &str = "Online Librarian";
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+pub fn totp_step(at: DateTime<Utc>) -> u64 {
+    (at.timestamp().max(0) / TOTP_PERIOD_SECONDS) as u64
+}
+
+pub fn totp_code(secret: &[u8], step: u64) -> String {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret).expect("HMAC can take a key of any size");
+    mac.update(&step.to_be_bytes());
+    let digest = mac.finalize().into_bytes();
+    let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        digest[offset] & 0x7f,
+        digest[offset + 1],
+        digest[offset + 2],
+        digest[offset + 3],
+    ]);
+    format!(
+        "{:0width$}",
+        binary % 10u32.pow(TOTP_DIGITS),
+        width = TOTP_DIGITS as usize
+    )
+}
+
+pub fn verify_totp_code(secret: &[u8], code: &str, at: DateTime<Utc>) -> Option<u64> {
+    let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+    if code.len() != TOTP_DIGITS as usize {
+        return None;
+    }
+    let current_step = totp_step(at);
+    (current_step.saturating_sub(TOTP_ALLOWED_DRIFT_STEPS)
+        ..=current_step + TOTP_ALLOWED_DRIFT_STEPS)
+        .find(|step| {
+            totp_code(secret, *step)
+                .as_bytes()
+                .ct_eq(code.as_bytes())
+                .into()
+        })
+}
+
+pub fn otpauth_uri(account: &str, encoded_secret: &str) -> String {
+    format!(
+        "otpauth://totp/{issuer}:{account}?secret={secret}&issuer={issuer}&algorithm=SHA1&digits={digits}&period={period}",
+        issuer = percent_encode(TOTP_ISSUER),
+        account = percent_encode(account),
+        secret = encoded_secret,
+        digits = TOTP_DIGITS,
+        period = TOTP_PERIOD_SECONDS
+    )
+}
+
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'@' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+pub fn base32_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    let mut buffer: u32 = 0;
+    let mut buffered_bits = 0;
+    for byte in bytes {
+        buffer = (buffer << 8) | *byte as u32;
+        buffered_bits += 8;
+        while buffered_bits >= 5 {
+            buffered_bits -= 5;
+            encoded.push(BASE32_ALPHABET[((buffer >> buffered_bits) & 0x1f) as usize] as char);
+        }
+    }
+    if buffered_bits > 0 {
+        encoded.push(BASE32_ALPHABET[((buffer << (5 - buffered_bits)) & 0x1f) as usize] as char);
+    }
+    encoded
+}
+
+pub fn base32_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(encoded.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut buffered_bits = 0;
+    for c in encoded.trim_end_matches('=').chars() {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|letter| *letter as char == c.to_ascii_uppercase())?;
+        buffer = (buffer << 5) | value as u32;
+        buffered_bits += 5;
+        if buffered_bits >= 8 {
+            buffered_bits -= 8;
+            bytes.push((buffer >> buffered_bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const RFC_SECRET: &[u8] = b"12345678901234567890";
+
+    #[test]
+    fn codes_match_the_hotp_test_vectors() {
+        let expected_codes = [
+            "755224", "287082", "359152", "969429", "338314", "254676", "287922", "162583",
+            "399871", "520489",
+        ];
+        for (counter, expected_code) in expected_codes.iter().enumerate() {
+            assert_eq!(totp_code(RFC_SECRET, counter as u64), *expected_code);
+        }
+    }
+
+    /// RFC 6238 Appendix B lists 8 digit codes, these are their last 6
+    /// digits.
+    #[test]
+    fn codes_match_the_totp_test_vectors() {
+        for (timestamp, expected_code) in [
+            (59, "287082"),
+            (1111111109, "081804"),
+            (1111111111, "050471"),
+            (1234567890, "005924"),
+            (2000000000, "279037"),
+            (20000000000, "353130"),
+        ] {
+            let at = Utc.timestamp_opt(timestamp, 0).unwrap();
+            assert_eq!(totp_code(RFC_SECRET, totp_step(at)), expected_code);
+            assert_eq!(
+                verify_totp_code(RFC_SECRET, expected_code, at),
+                Some(totp_step(at))
+            );
+        }
+    }
+
+    #[test]
+    fn codes_of_the_adjacent_steps_are_accepted() {
+        let at = Utc.timestamp_opt(1111111111, 0).unwrap();
+        let step = totp_step(at);
+
+        assert_eq!(
+            verify_totp_code(RFC_SECRET, &totp_code(RFC_SECRET, step - 1), at),
+            Some(step - 1)
+        );
+        assert_eq!(
+            verify_totp_code(RFC_SECRET, &totp_code(RFC_SECRET, step + 1), at),
+            Some(step + 1)
+        );
+        assert_eq!(
+            verify_totp_code(RFC_SECRET, &totp_code(RFC_SECRET, step + 2), at),
+            None
+        );
+        assert_eq!(
+            verify_totp_code(RFC_SECRET, &totp_code(RFC_SECRET, step - 2), at),
+            None
+        );
+    }
+
+    #[test]
+    fn codes_are_verified_regardless_of_whitespace_but_not_length() {
+        let at = Utc.timestamp_opt(59, 0).unwrap();
+
+        assert_eq!(verify_totp_code(RFC_SECRET, " 287 082 ", at), Some(1));
+        assert_eq!(verify_totp_code(RFC_SECRET, "94287082", at), None);
+        assert_eq!(verify_totp_code(RFC_SECRET, "28708", at), None);
+        assert_eq!(verify_totp_code(RFC_SECRET, "", at), None);
+    }
+
+    #[test]
+    fn base32_matches_the_rfc_4648_test_vectors() {
+        for (decoded, encoded) in [
+            ("", ""),
+            ("f", "MY"),
+            ("fo", "MZXQ"),
+            ("foo", "MZXW6"),
+            ("foob", "MZXW6YQ"),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI"),
+        ] {
+            assert_eq!(base32_encode(decoded.as_bytes()), encoded);
+            assert_eq!(base32_decode(encoded), Some(decoded.as_bytes().to_vec()));
+        }
+        assert_eq!(
+            base32_encode(RFC_SECRET),
+            "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ"
+        );
+    }
+
+    #[test]
+    fn base32_round_trips_any_secret() {
+        for length in 0..=TOTP_SECRET_LENGTH {
+            let secret: Vec<u8> = (0..length).map(|i| (i * 37 + 11) as u8).collect();
+            assert_eq!(base32_decode(&base32_encode(&secret)), Some(secret));
+        }
+    }
+
+    #[test]
+    fn base32_decoding_ignores_padding_and_case_but_not_other_letters() {
+        assert_eq!(base32_decode("mzxw6yq="), Some(b"foob".to_vec()));
+        assert_eq!(base32_decode("MZXW6YQ1"), None);
+        assert_eq!(base32_decode("MZ XW"), None);
+    }
+}
@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Default)]
+pub struct TwoFactor {
+    pub user_id: u64,
+    pub secret: String,
+    pub confirmed_at: Option<DateTime<Utc>>,
+    pub last_used_step: Option<u64>,
+}
+
+impl TwoFactor {
+    pub fn is_enabled(&self) -> bool {
+        self.confirmed_at.is_some()
+    }
+}
+
+#[derive(Debug)]
+pub enum TwoFactorProof {
+    Code(String),
+    RecoveryCode(String),
+}
+
+impl TwoFactorProof {
+    pub fn from_codes(code: Option<String>, recovery_code: Option<String>) -> Result<Self, String> {
+        let code = code
+            .map(|code| code.trim().to_string())
+            .filter(|code| !code.is_empty());
+        let recovery_code = recovery_code
+            .map(|recovery_code| recovery_code.trim().to_string())
+            .filter(|recovery_code| !recovery_code.is_empty());
+        match (code, recovery_code) {
+            (Some(code), None) => Ok(TwoFactorProof::Code(code)),
+            (None, Some(recovery_code)) => Ok(TwoFactorProof::RecoveryCode(recovery_code)),
+            (Some(_), Some(_)) => {
+                Err("Code and recovery code must not be informed together".to_string())
+            }
+            (None, None) => Err("Code or recovery code not informed".to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TwoFactorLogin {
+    pub challenge_token: String,
+    pub proof: TwoFactorProof,
+}
@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Default)]
+pub struct UserIdentity {
+    pub id: Option<u64>,
+    pub user_id: u64,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
@@ -1,3 +1,7 @@
+pub mod confirm_two_factor_dto_mapper;
 pub mod create_user_dto_mapper;
 pub mod created_user_dto_mapper;
+pub mod disable_two_factor_dto_mapper;
 pub mod login_user_dto_mapper;
+pub mod oidc_callback_params_dto_mapper;
+pub mod verify_two_factor_login_dto_mapper;
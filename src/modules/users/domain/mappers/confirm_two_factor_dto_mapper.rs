@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    shared::errors::detailed_api_error::DetailedAPIError,
+    users::domain::{
+        dtos::confirm_two_factor_dto::ConfirmTwoFactorDto, entities::two_factor::TwoFactorProof,
+    },
+};
+
+impl TryFrom<ConfirmTwoFactorDto> for TwoFactorProof {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: ConfirmTwoFactorDto) -> Result<Self, Self::Error> {
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        match dto.code.map(|code| code.trim().to_string()) {
+            Some(code) if !code.is_empty() => return Ok(TwoFactorProof::Code(code)),
+            Some(_) => {
+                validations.insert("code".to_string(), "Code must not be empty".to_string());
+            }
+            None => {
+                validations.insert("code".to_string(), "Code not informed".to_string());
+            }
+        }
+
+        Err(DetailedAPIError {
+            msg: "Request contains invalid data".to_string(),
+            code: 400,
+            field_validations: Some(validations),
+        })
+    }
+}
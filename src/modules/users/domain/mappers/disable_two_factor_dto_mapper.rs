@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    shared::errors::detailed_api_error::DetailedAPIError,
+    users::domain::{
+        dtos::disable_two_factor_dto::DisableTwoFactorDto, entities::two_factor::TwoFactorProof,
+    },
+};
+
+impl TryFrom<DisableTwoFactorDto> for TwoFactorProof {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: DisableTwoFactorDto) -> Result<Self, Self::Error> {
+        match TwoFactorProof::from_codes(dto.code, dto.recovery_code) {
+            Ok(proof) => Ok(proof),
+            Err(error) => Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(HashMap::from([("code".to_string(), error)])),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disable_dto(code: Option<&str>, recovery_code: Option<&str>) -> DisableTwoFactorDto {
+        DisableTwoFactorDto {
+            code: code.map(str::to_string),
+            recovery_code: recovery_code.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn either_a_code_or_a_recovery_code_is_taken() {
+        assert!(matches!(
+            TwoFactorProof::try_from(disable_dto(Some(" 123456 "), Some(" "))),
+            Ok(TwoFactorProof::Code(code)) if code == "123456"
+        ));
+        assert!(matches!(
+            TwoFactorProof::try_from(disable_dto(None, Some("abcd-efgh"))),
+            Ok(TwoFactorProof::RecoveryCode(recovery_code)) if recovery_code == "abcd-efgh"
+        ));
+    }
+
+    #[test]
+    fn exactly_one_proof_must_be_informed() {
+        for dto in [
+            disable_dto(None, None),
+            disable_dto(Some("123456"), Some("abcd-efgh")),
+        ] {
+            let error = TwoFactorProof::try_from(dto).unwrap_err();
+            assert_eq!(400, error.code);
+            assert!(error.field_validations.unwrap().contains_key("code"));
+        }
+    }
+}
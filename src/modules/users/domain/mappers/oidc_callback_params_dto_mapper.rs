@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    shared::errors::detailed_api_error::DetailedAPIError,
+    users::domain::{
+        dtos::oidc_callback_params_dto::OidcCallbackParamsDto,
+        entities::oidc_authorization::OidcCallback,
+    },
+};
+
+impl TryFrom<OidcCallbackParamsDto> for OidcCallback {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: OidcCallbackParamsDto) -> Result<Self, Self::Error> {
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        if let Some(error) = dto.error {
+            validations.insert("error".to_string(), dto.error_description.unwrap_or(error));
+            return Err(DetailedAPIError {
+                msg: "The provider refused the login".to_string(),
+                code: 401,
+                field_validations: Some(validations),
+            });
+        }
+
+        let code = dto
+            .code
+            .map(|code| code.trim().to_string())
+            .filter(|code| !code.is_empty());
+        if code.is_none() {
+            validations.insert("code".to_string(), "Code not informed".to_string());
+        }
+        let state = dto
+            .state
+            .map(|state| state.trim().to_string())
+            .filter(|state| !state.is_empty());
+        if state.is_none() {
+            validations.insert("state".to_string(), "State not informed".to_string());
+        }
+
+        match (code, state) {
+            (Some(code), Some(state)) => Ok(OidcCallback { code, state }),
+            _ => Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            }),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use crate::modules::{
+    shared::errors::detailed_api_error::DetailedAPIError,
+    users::domain::{
+        dtos::verify_two_factor_login_dto::VerifyTwoFactorLoginDto,
+        entities::two_factor::{TwoFactorLogin, TwoFactorProof},
+    },
+};
+
+impl TryFrom<VerifyTwoFactorLoginDto> for TwoFactorLogin {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: VerifyTwoFactorLoginDto) -> Result<Self, Self::Error> {
+        let mut errors = false;
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        let challenge_token = match dto.challenge_token {
+            Some(challenge_token) => {
+                let candidate_challenge_token = challenge_token.trim();
+                if candidate_challenge_token.is_empty() {
+                    validations.insert(
+                        "challenge_token".to_string(),
+                        "Challenge token must not be empty".to_string(),
+                    );
+                    errors = true;
+                }
+                candidate_challenge_token.to_string()
+            }
+            None => {
+                validations.insert(
+                    "challenge_token".to_string(),
+                    "Challenge token not informed".to_string(),
+                );
+                errors = true;
+                String::new()
+            }
+        };
+
+        let proof = match TwoFactorProof::from_codes(dto.code, dto.recovery_code) {
+            Ok(proof) => Some(proof),
+            Err(error) => {
+                validations.insert("code".to_string(), error);
+                errors = true;
+                None
+            }
+        };
+
+        match proof {
+            Some(proof) if !errors => Ok(TwoFactorLogin {
+                challenge_token,
+                proof,
+            }),
+            _ => Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            }),
+        }
+    }
+}
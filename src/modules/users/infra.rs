@@ -1,2 +1,5 @@
+pub mod clients;
 pub mod controllers;
+pub mod middlewares;
 pub mod repositories;
+pub mod stores;
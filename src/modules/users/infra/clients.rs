@@ -0,0 +1,2 @@
+pub mod oidc_client;
+pub mod oidc_client_reqwest;
@@ -0,0 +1,22 @@
+use jsonwebtoken::jwk::JwkSet;
+use std::future::Future;
+
+use crate::{
+    configuration::OidcProviderSettings,
+    modules::users::domain::entities::oidc_provider_metadata::OidcProviderMetadata,
+};
+
+pub trait OidcClient {
+    fn discover(
+        &self,
+        issuer: &str,
+    ) -> impl Future<Output = Result<OidcProviderMetadata, String>> + Send;
+    fn fetch_jwks(&self, jwks_uri: &str) -> impl Future<Output = Result<JwkSet, String>> + Send;
+    fn exchange_code(
+        &self,
+        provider: &OidcProviderSettings,
+        metadata: &OidcProviderMetadata,
+        code: &str,
+        code_verifier: &str,
+    ) -> impl Future<Output = Result<String, String>> + Send;
+}
@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use jsonwebtoken::jwk::JwkSet;
+use reqwest::{header, Client, Response};
+use secrecy::ExposeSecret;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::{
+    configuration::OidcProviderSettings,
+    modules::users::domain::entities::oidc_provider_metadata::OidcProviderMetadata,
+};
+
+use super::oidc_client::OidcClient;
+
+const OIDC_REQUEST_TIMEOUT_SECONDS: u64 = 10;
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    id_token: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct OidcClientReqwest {
+    client: Client,
+}
+
+impl OidcClientReqwest {
+    pub fn new() -> Self {
+        OidcClientReqwest {
+            client: Client::builder()
+                .timeout(Duration::from_secs(OIDC_REQUEST_TIMEOUT_SECONDS))
+                .user_agent(concat!("new-online-librarian/", env!("CARGO_PKG_VERSION")))
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Default for OidcClientReqwest {
+    fn default() -> Self {
+        OidcClientReqwest::new()
+    }
+}
+
+async fn read_json<T: DeserializeOwned>(
+    response: Result<Response, reqwest::Error>,
+) -> Result<T, String> {
+    let response = match response {
+        Ok(response) => response,
+        Err(error) => return Err(error.to_string()),
+    };
+    let status = response.status();
+    let body = match response.bytes().await {
+        Ok(body) => body,
+        Err(error) => return Err(error.to_string()),
+    };
+    if !status.is_success() {
+        return Err(format!(
+            "Provider answered with {}: {}",
+            status,
+            String::from_utf8_lossy(&body)
+        ));
+    }
+    match serde_json::from_slice(&body) {
+        Ok(value) => Ok(value),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+impl OidcClient for OidcClientReqwest {
+    async fn discover(&self, issuer: &str) -> Result<OidcProviderMetadata, String> {
+        let discovery_url = format!(
+            "{}/.well-known/openid-configuration",
+            issuer.trim_end_matches('/')
+        );
+        let response = self
+            .client
+            .get(discovery_url)
+            .header(header::ACCEPT, "application/json")
+            .send()
+            .await;
+        read_json(response).await
+    }
+
+    async fn fetch_jwks(&self, jwks_uri: &str) -> Result<JwkSet, String> {
+        let response = self
+            .client
+            .get(jwks_uri)
+            .header(header::ACCEPT, "application/json")
+            .send()
+            .await;
+        read_json(response).await
+    }
+
+    async fn exchange_code(
+        &self,
+        provider: &OidcProviderSettings,
+        metadata: &OidcProviderMetadata,
+        code: &str,
+        code_verifier: &str,
+    ) -> Result<String, String> {
+        let response = self
+            .client
+            .post(&metadata.token_endpoint)
+            .header(header::ACCEPT, "application/json")
+            .basic_auth(
+                &provider.client_id,
+                Some(provider.client_secret.expose_secret()),
+            )
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", provider.redirect_uri.as_str()),
+                ("client_id", provider.client_id.as_str()),
+                ("code_verifier", code_verifier),
+            ])
+            .send()
+            .await;
+        match read_json::<TokenResponse>(response).await?.id_token {
+            Some(id_token) => Ok(id_token),
+            None => Err("Provider answered without an ID token".to_string()),
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod auth_controller_v1;
+pub mod oidc_controller_v1;
 pub mod user_controller_v1;
@@ -3,31 +3,93 @@ use std::sync::Arc;
 use crate::{
     configuration::TokenSettings,
     modules::{
-        shared::errors::APIError,
+        shared::errors::{
+            detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+        },
         users::{
-            domain::{dtos::login_user_dto::LoginUserDto, entities::user::User},
-            infra::repositories::user_repository_mysql::UserRepositoryMySQL,
-            usecases::v1::login_user::LoginUserUseCaseV1,
+            domain::{
+                dtos::{
+                    authed_user::AuthedUser, confirm_two_factor_dto::ConfirmTwoFactorDto,
+                    disable_two_factor_dto::DisableTwoFactorDto, login_user_dto::LoginUserDto,
+                    recovery_codes_dto::RecoveryCodesDto, token_user_dto::TokenUserDto,
+                    two_factor_challenge_dto::TwoFactorChallengeDto,
+                    two_factor_enrollment_dto::TwoFactorEnrollmentDto,
+                    verify_two_factor_login_dto::VerifyTwoFactorLoginDto,
+                },
+                entities::{
+                    two_factor::{TwoFactorLogin, TwoFactorProof},
+                    user::User,
+                },
+            },
+            infra::{
+                middlewares::login_rate_limit::LoginRateLimit,
+                repositories::{
+                    two_factor_repository_mysql::TwoFactorRepositoryMySQL,
+                    user_repository_mysql::UserRepositoryMySQL,
+                },
+            },
+            usecases::v1::{
+                confirm_two_factor::ConfirmTwoFactorUseCaseV1,
+                disable_two_factor::DisableTwoFactorUseCaseV1,
+                enroll_two_factor::EnrollTwoFactorUseCaseV1,
+                login_user::{LoginOutcome, LoginUserUseCaseV1},
+            },
         },
     },
 };
 use actix_web::{post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
 
 pub struct AuthControllerV1 {
     token_settings: Arc<TokenSettings>,
-    login_user_usecase: LoginUserUseCaseV1<UserRepositoryMySQL>,
+    login_user_usecase: LoginUserUseCaseV1<UserRepositoryMySQL, TwoFactorRepositoryMySQL>,
+    enroll_two_factor_usecase:
+        EnrollTwoFactorUseCaseV1<UserRepositoryMySQL, TwoFactorRepositoryMySQL>,
+    confirm_two_factor_usecase: ConfirmTwoFactorUseCaseV1<TwoFactorRepositoryMySQL>,
+    disable_two_factor_usecase: DisableTwoFactorUseCaseV1<TwoFactorRepositoryMySQL>,
 }
 
 impl AuthControllerV1 {
-    pub fn new(user_repository: UserRepositoryMySQL, token_settings: Arc<TokenSettings>) -> Self {
+    pub fn new(
+        user_repository: UserRepositoryMySQL,
+        two_factor_repository: TwoFactorRepositoryMySQL,
+        token_settings: Arc<TokenSettings>,
+    ) -> Self {
         AuthControllerV1 {
             token_settings,
-            login_user_usecase: LoginUserUseCaseV1::new(user_repository),
+            login_user_usecase: LoginUserUseCaseV1::new(
+                user_repository.clone(),
+                two_factor_repository.clone(),
+            ),
+            enroll_two_factor_usecase: EnrollTwoFactorUseCaseV1::new(
+                user_repository.clone(),
+                two_factor_repository.clone(),
+            ),
+            confirm_two_factor_usecase: ConfirmTwoFactorUseCaseV1::new(
+                two_factor_repository.clone(),
+            ),
+            disable_two_factor_usecase: DisableTwoFactorUseCaseV1::new(
+                two_factor_repository.clone(),
+            ),
         }
     }
 }
 
-#[post("/login")]
+#[utoipa::path(
+    tag = "auth",
+    summary = "Log in, returning a bearer token",
+    description = "Users with two-factor authentication enabled get a challenge token instead, traded for the bearer token at `/v1/auth/login/two-factor`.",
+    request_body = LoginUserDto,
+    responses(
+        (status = 200, description = "Logged in", body = TokenUserDto),
+        (status = 202, description = "Password accepted, a two-factor code is required", body = TwoFactorChallengeDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Invalid credentials", body = SimpleAPIError),
+        (status = 429, description = "Too many login attempts, or the account is locked after failing repeatedly", body = SimpleAPIError, headers(("Retry-After" = u64, description = "Seconds to wait before trying again"))),
+    ),
+    security(()),
+)]
+#[post("/login", wrap = "LoginRateLimit")]
 async fn login_user(
     auth_controller: web::Data<AuthControllerV1>,
     login_user_dto: web::Json<LoginUserDto>,
@@ -42,12 +104,179 @@ async fn login_user(
         .login_user_usecase
         .login_user(user, &auth_controller.token_settings)
         .await
+    {
+        Ok(LoginOutcome::Authenticated(token_user_dto)) => {
+            HttpResponse::Ok().json(web::Json(token_user_dto))
+        }
+        Ok(LoginOutcome::TwoFactorRequired(two_factor_challenge_dto)) => {
+            HttpResponse::Accepted().json(web::Json(two_factor_challenge_dto))
+        }
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "auth",
+    summary = "Finish logging in with a two-factor code, returning a bearer token",
+    request_body = VerifyTwoFactorLoginDto,
+    responses(
+        (status = 200, description = "Logged in", body = TokenUserDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Invalid or expired challenge token, or invalid code", body = SimpleAPIError),
+        (status = 429, description = "Too many login attempts, or the account is locked after failing repeatedly", body = SimpleAPIError, headers(("Retry-After" = u64, description = "Seconds to wait before trying again"))),
+    ),
+    security(()),
+)]
+#[post("/login/two-factor", wrap = "LoginRateLimit")]
+async fn verify_two_factor_login(
+    auth_controller: web::Data<AuthControllerV1>,
+    verify_two_factor_login_dto: web::Json<VerifyTwoFactorLoginDto>,
+) -> HttpResponse {
+    let two_factor_login = match TwoFactorLogin::try_from(verify_two_factor_login_dto.0) {
+        Ok(converted_two_factor_login) => converted_two_factor_login,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+    match auth_controller
+        .login_user_usecase
+        .verify_two_factor_login(two_factor_login, &auth_controller.token_settings)
+        .await
     {
         Ok(token_user_dto) => HttpResponse::Ok().json(web::Json(token_user_dto)),
         Err(error) => HttpResponse::from(error),
     }
 }
 
+#[utoipa::path(
+    tag = "auth",
+    summary = "Enroll in two-factor authentication",
+    description = "Generates a new secret for an authenticator app, required on logins once confirmed.",
+    responses(
+        (status = 201, description = "Secret generated", body = TwoFactorEnrollmentDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 409, description = "Two-factor authentication is already enabled", body = SimpleAPIError),
+    ),
+)]
+#[post("/two-factor")]
+async fn enroll_two_factor(
+    auth_controller: web::Data<AuthControllerV1>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+    match auth_controller
+        .enroll_two_factor_usecase
+        .enroll_two_factor(authed_user.id.unwrap())
+        .await
+    {
+        Ok(two_factor_enrollment_dto) => {
+            HttpResponse::Created().json(web::Json(two_factor_enrollment_dto))
+        }
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "auth",
+    summary = "Confirm two-factor authentication with a first code",
+    request_body = ConfirmTwoFactorDto,
+    responses(
+        (status = 200, description = "Two-factor authentication enabled, recovery codes are only shown now", body = RecoveryCodesDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required, or the code is invalid", body = SimpleAPIError),
+        (status = 404, description = "Two-factor authentication was not enrolled", body = SimpleAPIError),
+        (status = 409, description = "Two-factor authentication is already enabled", body = SimpleAPIError),
+    ),
+)]
+#[post("/two-factor/confirm")]
+async fn confirm_two_factor(
+    auth_controller: web::Data<AuthControllerV1>,
+    authed_user: AuthedUser,
+    confirm_two_factor_dto: web::Json<ConfirmTwoFactorDto>,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+    let proof = match TwoFactorProof::try_from(confirm_two_factor_dto.0) {
+        Ok(converted_proof) => converted_proof,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+    match auth_controller
+        .confirm_two_factor_usecase
+        .confirm_two_factor(authed_user.id.unwrap(), proof)
+        .await
+    {
+        Ok(recovery_codes_dto) => HttpResponse::Ok().json(web::Json(recovery_codes_dto)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "auth",
+    summary = "Disable two-factor authentication",
+    description = "Requires a current code or an unused recovery code besides the bearer token.",
+    request_body = DisableTwoFactorDto,
+    responses(
+        (status = 200, description = "Two-factor authentication disabled"),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required, or the code is invalid", body = SimpleAPIError),
+        (status = 404, description = "Two-factor authentication is not enabled", body = SimpleAPIError),
+        (status = 429, description = "Too many attempts, retry after the `Retry-After` seconds", body = SimpleAPIError),
+    ),
+)]
+#[post("/two-factor/disable", wrap = "LoginRateLimit")]
+async fn disable_two_factor(
+    auth_controller: web::Data<AuthControllerV1>,
+    authed_user: AuthedUser,
+    disable_two_factor_dto: web::Json<DisableTwoFactorDto>,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+    let proof = match TwoFactorProof::try_from(disable_two_factor_dto.0) {
+        Ok(converted_proof) => converted_proof,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+    match auth_controller
+        .disable_two_factor_usecase
+        .disable_two_factor(authed_user.id.unwrap(), proof)
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    login_user,
+    verify_two_factor_login,
+    enroll_two_factor,
+    confirm_two_factor,
+    disable_two_factor
+))]
+pub struct AuthApiV1;
+
 pub fn get_auth_scope() -> Scope {
-    web::scope("/v1/auth").service(login_user)
+    web::scope("/v1/auth")
+        .service(login_user)
+        .service(verify_two_factor_login)
+        .service(enroll_two_factor)
+        .service(confirm_two_factor)
+        .service(disable_two_factor)
 }
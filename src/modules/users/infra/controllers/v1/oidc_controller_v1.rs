@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use crate::{
+    configuration::{OidcSettings, TokenSettings},
+    modules::{
+        libraries::infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+        shared::errors::{
+            detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+        },
+        users::{
+            domain::{
+                dtos::{
+                    oidc_callback_params_dto::OidcCallbackParamsDto,
+                    oidc_provider_dto::OidcProviderDto, token_user_dto::TokenUserDto,
+                    two_factor_challenge_dto::TwoFactorChallengeDto,
+                },
+                entities::oidc_authorization::OidcCallback,
+            },
+            infra::{
+                clients::oidc_client_reqwest::OidcClientReqwest,
+                repositories::{
+                    two_factor_repository_mysql::TwoFactorRepositoryMySQL,
+                    user_identity_repository_mysql::UserIdentityRepositoryMySQL,
+                    user_repository_mysql::UserRepositoryMySQL,
+                },
+                stores::oidc_state_store_memory::OidcStateStoreMemory,
+            },
+            usecases::v1::{login_oidc_user::LoginOidcUserUseCaseV1, login_user::LoginOutcome},
+        },
+    },
+};
+use actix_web::{get, http::header, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct OidcControllerV1 {
+    token_settings: Arc<TokenSettings>,
+    login_oidc_user_usecase: LoginOidcUserUseCaseV1<
+        UserRepositoryMySQL,
+        UserIdentityRepositoryMySQL,
+        OidcClientReqwest,
+        OidcStateStoreMemory,
+    >,
+}
+
+impl OidcControllerV1 {
+    pub fn new(
+        user_repository: UserRepositoryMySQL,
+        user_identity_repository: UserIdentityRepositoryMySQL,
+        two_factor_repository: TwoFactorRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        oidc_settings: OidcSettings,
+        token_settings: Arc<TokenSettings>,
+    ) -> Self {
+        OidcControllerV1 {
+            token_settings,
+            login_oidc_user_usecase: LoginOidcUserUseCaseV1::new(
+                user_repository,
+                user_identity_repository,
+                two_factor_repository,
+                library_repository,
+                OidcClientReqwest::new(),
+                OidcStateStoreMemory::new(),
+                oidc_settings,
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "auth",
+    summary = "List the OpenID Connect providers users can sign in with",
+    responses(
+        (status = 200, description = "Providers configured", body = [OidcProviderDto]),
+    ),
+    security(()),
+)]
+#[get("")]
+async fn get_oidc_providers(oidc_controller: web::Data<OidcControllerV1>) -> HttpResponse {
+    HttpResponse::Ok().json(web::Json(
+        oidc_controller.login_oidc_user_usecase.list_providers(),
+    ))
+}
+
+#[utoipa::path(
+    tag = "auth",
+    summary = "Start signing in with an OpenID Connect provider",
+    description = "Redirects to the provider, which sends the user back to its configured redirect URI with the `code` and `state` to inform to the callback.",
+    params(
+        ("provider" = String, Path, description = "Name of the provider"),
+    ),
+    responses(
+        (status = 302, description = "Redirect to the provider", headers(("Location" = String, description = "Authorization URL of the provider"))),
+        (status = 404, description = "OIDC provider not found", body = SimpleAPIError),
+        (status = 502, description = "Failed to reach the OIDC provider", body = SimpleAPIError),
+    ),
+    security(()),
+)]
+#[get("/{provider}/authorize")]
+async fn authorize_oidc_user(
+    oidc_controller: web::Data<OidcControllerV1>,
+    path: web::Path<(String,)>,
+) -> HttpResponse {
+    match oidc_controller
+        .login_oidc_user_usecase
+        .authorize(&path.into_inner().0)
+        .await
+    {
+        Ok(authorization_url) => HttpResponse::Found()
+            .insert_header((header::LOCATION, authorization_url))
+            .finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "auth",
+    summary = "Finish signing in with an OpenID Connect provider, returning a bearer token",
+    description = "The identity is linked to the account of its verified email, or to a new account when the provider is configured to create them. Users with two-factor authentication enabled get a challenge token instead, as on `/v1/auth/login`.",
+    params(
+        ("provider" = String, Path, description = "Name of the provider"),
+        OidcCallbackParamsDto,
+    ),
+    responses(
+        (status = 200, description = "Logged in", body = TokenUserDto),
+        (status = 202, description = "A two-factor code is required", body = TwoFactorChallengeDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Login refused by the provider, invalid state or invalid ID token", body = SimpleAPIError),
+        (status = 403, description = "No account is linked to this identity", body = SimpleAPIError),
+        (status = 404, description = "OIDC provider not found", body = SimpleAPIError),
+        (status = 502, description = "Failed to reach the OIDC provider", body = SimpleAPIError),
+    ),
+    security(()),
+)]
+#[get("/{provider}/callback")]
+async fn login_oidc_user(
+    oidc_controller: web::Data<OidcControllerV1>,
+    path: web::Path<(String,)>,
+    params: web::Query<OidcCallbackParamsDto>,
+) -> HttpResponse {
+    let callback = match OidcCallback::try_from(params.into_inner()) {
+        Ok(converted_callback) => converted_callback,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+    match oidc_controller
+        .login_oidc_user_usecase
+        .login_oidc_user(
+            &path.into_inner().0,
+            callback,
+            &oidc_controller.token_settings,
+        )
+        .await
+    {
+        Ok(LoginOutcome::Authenticated(token_user_dto)) => {
+            HttpResponse::Ok().json(web::Json(token_user_dto))
+        }
+        Ok(LoginOutcome::TwoFactorRequired(two_factor_challenge_dto)) => {
+            HttpResponse::Accepted().json(web::Json(two_factor_challenge_dto))
+        }
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(get_oidc_providers, authorize_oidc_user, login_oidc_user))]
+pub struct OidcApiV1;
+
+pub fn get_oidc_scope() -> Scope {
+    web::scope("/v1/auth/oidc")
+        .service(get_oidc_providers)
+        .service(authorize_oidc_user)
+        .service(login_oidc_user)
+}
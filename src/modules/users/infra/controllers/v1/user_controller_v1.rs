@@ -1,5 +1,8 @@
 use crate::modules::{
-    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    libraries::infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    shared::errors::{
+        detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+    },
     users::{
         domain::{
             dtos::{
@@ -13,21 +16,39 @@ use crate::modules::{
     },
 };
 use actix_web::{get, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
 
 pub struct UserControllerV1 {
-    create_user_usecase: CreateUserUseCaseV1<UserRepositoryMySQL>,
+    create_user_usecase: CreateUserUseCaseV1<UserRepositoryMySQL, LibraryRepositoryMySQL>,
     get_user_info_usecase: GetUserInfoUseCaseV1<UserRepositoryMySQL>,
 }
 
 impl UserControllerV1 {
-    pub fn new(user_repository: UserRepositoryMySQL) -> Self {
+    pub fn new(
+        user_repository: UserRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
         UserControllerV1 {
-            create_user_usecase: CreateUserUseCaseV1::new(user_repository.clone()),
+            create_user_usecase: CreateUserUseCaseV1::new(
+                user_repository.clone(),
+                library_repository.clone(),
+            ),
             get_user_info_usecase: GetUserInfoUseCaseV1::new(user_repository.clone()),
         }
     }
 }
 
+#[utoipa::path(
+    tag = "users",
+    summary = "Sign up",
+    request_body = CreateUserDto,
+    responses(
+        (status = 201, description = "User created", body = CreatedUserDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 409, description = "Email already in use", body = SimpleAPIError),
+    ),
+    security(()),
+)]
 #[post("")]
 async fn create_user(
     user_controller: web::Data<UserControllerV1>,
@@ -48,6 +69,19 @@ async fn create_user(
     }
 }
 
+#[utoipa::path(
+    tag = "users",
+    summary = "Find a user",
+    params(
+        ("user_id" = u64, Path, description = "Id of the user"),
+    ),
+    responses(
+        (status = 201, description = "User found", body = CreatedUserDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+        (status = 404, description = "User not found", body = SimpleAPIError),
+    ),
+)]
 #[get("/{user_id}")]
 async fn get_user(
     user_controller: web::Data<UserControllerV1>,
@@ -83,6 +117,10 @@ async fn get_user(
     }
 }
 
+#[derive(OpenApi)]
+#[openapi(paths(create_user, get_user))]
+pub struct UserApiV1;
+
 pub fn get_user_scope() -> Scope {
     web::scope("/v1/users")
         .service(create_user)
@@ -0,0 +1,153 @@
+use std::{net::SocketAddr, pin::Pin, rc::Rc};
+
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform},
+    error::PayloadError,
+    http::header,
+    web, Error, HttpResponse,
+};
+use base64::{prelude::BASE64_URL_SAFE_NO_PAD, Engine};
+use futures_util::{
+    future::{ok, LocalBoxFuture, Ready},
+    stream, Stream,
+};
+use serde_json::Value;
+use tracing::error;
+
+use crate::modules::{
+    shared::errors::simple_api_error::SimpleAPIError,
+    users::{
+        infra::stores::rate_limit_store::RateLimitStore,
+        usecases::v1::limit_login_attempts::LimitLoginAttemptsUseCaseV1,
+    },
+};
+
+/// Limits login attempts with `LimitLoginAttemptsUseCaseV1`, registered as
+/// app data. Refused attempts are answered with `429 Too Many Requests` and
+/// a `Retry-After` header before the credentials are checked, failed logins
+/// are told apart by their `401` or `403` status. Only a `200` issues the
+/// access token, the `202` of a password step waiting for a two-factor code
+/// keeps the failures of the account.
+pub struct LoginRateLimit;
+
+impl<S, B> Transform<S, ServiceRequest> for LoginRateLimit
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = LoginRateLimitMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(LoginRateLimitMiddleware {
+            service: Rc::new(service),
+        })
+    }
+}
+
+pub struct LoginRateLimitMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for LoginRateLimitMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        Box::pin(async move {
+            let limit_login_attempts_usecase = match req
+                .app_data::<web::Data<LimitLoginAttemptsUseCaseV1<dyn RateLimitStore>>>()
+            {
+                Some(usecase) => usecase.clone(),
+                None => {
+                    error!("Failed to load the login rate limit");
+                    return Ok(service.call(req).await?.map_into_left_body());
+                }
+            };
+
+            let client_address = if limit_login_attempts_usecase.trust_forwarded_for() {
+                req.connection_info()
+                    .realip_remote_addr()
+                    .map(address_without_port)
+            } else {
+                req.peer_addr().map(|address| address.ip().to_string())
+            };
+
+            // The account is read from the body, which is put back for the
+            // handler.
+            let body = req.extract::<web::Bytes>().await?;
+            let account = serde_json::from_slice::<Value>(&body)
+                .ok()
+                .and_then(|body| account_from_body(&body))
+                .filter(|email| !email.trim().is_empty());
+            req.set_payload(payload_from_bytes(body));
+
+            if let Err(retry_after) = limit_login_attempts_usecase
+                .check_attempt(client_address.as_deref(), account.as_deref())
+                .await
+            {
+                let retry_after_seconds = (retry_after.num_milliseconds().max(0) + 999) / 1000;
+                let response = HttpResponse::TooManyRequests()
+                    .insert_header((header::RETRY_AFTER, retry_after_seconds.max(1).to_string()))
+                    .json(SimpleAPIError::new(
+                        "Too many login attempts, try again later".to_string(),
+                        429,
+                    ));
+                return Ok(req.into_response(response).map_into_right_body());
+            }
+
+            let response = service.call(req).await?;
+            if let Some(account) = account {
+                match response.status().as_u16() {
+                    401 | 403 => {
+                        limit_login_attempts_usecase.record_failure(&account).await;
+                    }
+                    200 => {
+                        limit_login_attempts_usecase.record_success(&account).await;
+                    }
+                    _ => {}
+                }
+            }
+            Ok(response.map_into_left_body())
+        })
+    }
+}
+
+/// Email of the login, or of the challenge token of the two-factor step. The
+/// token is only read, its signature is checked by the handler; a forged one
+/// can only spend the attempts of the account it names.
+fn account_from_body(body: &Value) -> Option<String> {
+    if let Some(email) = body["email"].as_str() {
+        return Some(email.to_string());
+    }
+    let claims = body["challenge_token"].as_str()?.split('.').nth(1)?;
+    let claims = BASE64_URL_SAFE_NO_PAD.decode(claims).ok()?;
+    serde_json::from_slice::<Value>(&claims).ok()?["email"]
+        .as_str()
+        .map(str::to_string)
+}
+
+fn address_without_port(address: &str) -> String {
+    match address.parse::<SocketAddr>() {
+        Ok(address) => address.ip().to_string(),
+        Err(_) => address.to_string(),
+    }
+}
+
+fn payload_from_bytes(body: web::Bytes) -> Payload {
+    let body: Pin<Box<dyn Stream<Item = Result<web::Bytes, PayloadError>>>> =
+        Box::pin(stream::once(async move { Ok(body) }));
+    Payload::from(body)
+}
@@ -1,2 +1,6 @@
+pub mod two_factor_repository;
+pub mod two_factor_repository_mysql;
+pub mod user_identity_repository;
+pub mod user_identity_repository_mysql;
 pub mod user_repository;
 pub mod user_repository_mysql;
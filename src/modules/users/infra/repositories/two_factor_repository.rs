@@ -0,0 +1,36 @@
+use crate::modules::users::domain::entities::two_factor::TwoFactor;
+use sqlx::Error;
+
+pub trait TwoFactorRepository {
+    fn save_secret(
+        &self,
+        user_id: u64,
+        secret: &str,
+    ) -> impl std::future::Future<Output = Result<(), Error>> + Send;
+    fn find_by_user_id(
+        &self,
+        user_id: u64,
+    ) -> impl std::future::Future<Output = Result<Option<TwoFactor>, Error>> + Send;
+    fn confirm(
+        &self,
+        user_id: u64,
+        step: u64,
+        recovery_code_hashes: &[String],
+    ) -> impl std::future::Future<Output = Result<(), Error>> + Send;
+    /// Whether the time step was used, it fails when the same or a later one
+    /// was used before.
+    fn use_step(
+        &self,
+        user_id: u64,
+        step: u64,
+    ) -> impl std::future::Future<Output = Result<bool, Error>> + Send;
+    fn use_recovery_code(
+        &self,
+        user_id: u64,
+        code_hash: &str,
+    ) -> impl std::future::Future<Output = Result<bool, Error>> + Send;
+    fn delete_by_user_id(
+        &self,
+        user_id: u64,
+    ) -> impl std::future::Future<Output = Result<(), Error>> + Send;
+}
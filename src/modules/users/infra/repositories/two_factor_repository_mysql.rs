@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sqlx::{MySqlPool, Row};
+
+use super::two_factor_repository::TwoFactorRepository;
+use crate::modules::users::domain::entities::two_factor::TwoFactor;
+
+#[derive(Clone)]
+pub struct TwoFactorRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl TwoFactorRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        TwoFactorRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+impl TwoFactorRepository for TwoFactorRepositoryMySQL {
+    async fn save_secret(&self, user_id: u64, secret: &str) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            INSERT INTO user_two_factors (user_id, secret, confirmed_at, last_used_step, created_at)
+            VALUES (?, ?, NULL, NULL, DEFAULT)
+            ON DUPLICATE KEY UPDATE secret = VALUES(secret), confirmed_at = NULL,
+            last_used_step = NULL, created_at = CURRENT_TIMESTAMP
+            "#,
+        )
+        .bind(user_id)
+        .bind(secret)
+        .execute(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_by_user_id(&self, user_id: u64) -> Result<Option<TwoFactor>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT u.user_id, u.secret, u.confirmed_at, u.last_used_step
+            FROM user_two_factors u
+            WHERE u.user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(row) => Ok(row.map(|row| TwoFactor {
+                user_id: row.get("user_id"),
+                secret: row.get("secret"),
+                confirmed_at: row.get::<Option<DateTime<Utc>>, &str>("confirmed_at"),
+                last_used_step: row.get("last_used_step"),
+            })),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn confirm(
+        &self,
+        user_id: u64,
+        step: u64,
+        recovery_code_hashes: &[String],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+        sqlx::query(
+            r#"
+            UPDATE user_two_factors
+            SET confirmed_at = CURRENT_TIMESTAMP, last_used_step = ?
+            WHERE user_id = ?
+            "#,
+        )
+        .bind(step)
+        .bind(user_id)
+        .execute(&mut *transaction)
+        .await?;
+        sqlx::query("DELETE FROM user_recovery_codes WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&mut *transaction)
+            .await?;
+        for code_hash in recovery_code_hashes {
+            sqlx::query(
+                r#"
+                INSERT INTO user_recovery_codes (id, user_id, code_hash, used_at)
+                VALUES (DEFAULT, ?, ?, NULL)
+                "#,
+            )
+            .bind(user_id)
+            .bind(code_hash)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    async fn use_step(&self, user_id: u64, step: u64) -> Result<bool, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            UPDATE user_two_factors
+            SET last_used_step = ?
+            WHERE user_id = ? AND (last_used_step IS NULL OR last_used_step < ?)
+            "#,
+        )
+        .bind(step)
+        .bind(user_id)
+        .bind(step)
+        .execute(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(result) => Ok(result.rows_affected() > 0),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn use_recovery_code(&self, user_id: u64, code_hash: &str) -> Result<bool, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            UPDATE user_recovery_codes
+            SET used_at = CURRENT_TIMESTAMP
+            WHERE user_id = ? AND code_hash = ? AND used_at IS NULL
+            "#,
+        )
+        .bind(user_id)
+        .bind(code_hash)
+        .execute(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(result) => Ok(result.rows_affected() > 0),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn delete_by_user_id(&self, user_id: u64) -> Result<(), sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+        sqlx::query("DELETE FROM user_recovery_codes WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query("DELETE FROM user_two_factors WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+use crate::modules::users::domain::entities::user_identity::UserIdentity;
+use sqlx::Error;
+
+pub trait UserIdentityRepository {
+    fn save(
+        &self,
+        user_identity: &UserIdentity,
+    ) -> impl std::future::Future<Output = Result<(), Error>> + Send;
+    fn find_by_provider_and_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> impl std::future::Future<Output = Result<Option<UserIdentity>, Error>> + Send;
+}
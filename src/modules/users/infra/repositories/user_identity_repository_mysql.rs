@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sqlx::{MySqlPool, Row};
+
+use super::user_identity_repository::UserIdentityRepository;
+use crate::modules::users::domain::entities::user_identity::UserIdentity;
+
+#[derive(Clone)]
+pub struct UserIdentityRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl UserIdentityRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        UserIdentityRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+impl UserIdentityRepository for UserIdentityRepositoryMySQL {
+    async fn save(&self, user_identity: &UserIdentity) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            INSERT INTO user_identities (id, user_id, provider, subject, email, created_at)
+            VALUES (DEFAULT, ?, ?, ?, ?, DEFAULT)
+            "#,
+        )
+        .bind(user_identity.user_id)
+        .bind(&user_identity.provider)
+        .bind(&user_identity.subject)
+        .bind(&user_identity.email)
+        .execute(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_by_provider_and_subject(
+        &self,
+        provider: &str,
+        subject: &str,
+    ) -> Result<Option<UserIdentity>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT u.id, u.user_id, u.provider, u.subject, u.email, u.created_at
+            FROM user_identities u
+            WHERE u.provider = ? AND u.subject = ?
+            "#,
+        )
+        .bind(provider)
+        .bind(subject)
+        .fetch_optional(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(row) => Ok(row.map(|row| UserIdentity {
+                id: Some(row.get("id")),
+                user_id: row.get("user_id"),
+                provider: row.get("provider"),
+                subject: row.get("subject"),
+                email: row.get("email"),
+                created_at: row.get::<DateTime<Utc>, &str>("created_at"),
+            })),
+            Err(error) => Err(error),
+        }
+    }
+}
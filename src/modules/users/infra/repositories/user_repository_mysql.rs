@@ -109,7 +109,7 @@ impl UserRepository for UserRepositoryMySQL {
         }
     }
 
-    async fn delete_by_id(&self, id: u64) -> Result<(), sqlx::Error> {
+    async fn delete_by_id(&self, _id: u64) -> Result<(), sqlx::Error> {
         todo!()
     }
 }
@@ -0,0 +1,5 @@
+pub mod oidc_state_store;
+pub mod oidc_state_store_memory;
+pub mod rate_limit_store;
+pub mod rate_limit_store_memory;
+pub mod rate_limit_store_mysql;
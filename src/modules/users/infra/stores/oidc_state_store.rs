@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use std::future::Future;
+
+use crate::modules::users::domain::entities::oidc_authorization::OidcAuthorization;
+
+pub trait OidcStateStore {
+    fn save(
+        &self,
+        authorization: OidcAuthorization,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<(), anyhow::Error>> + Send;
+    /// Removes the login of the state, so it can only be finished once.
+    fn take(
+        &self,
+        state: &str,
+    ) -> impl Future<Output = Result<Option<OidcAuthorization>, anyhow::Error>> + Send;
+}
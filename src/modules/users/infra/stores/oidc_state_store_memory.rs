@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::modules::users::domain::entities::oidc_authorization::OidcAuthorization;
+
+use super::oidc_state_store::OidcStateStore;
+
+const MAX_ENTRIES_BEFORE_PRUNE: usize = 10_000;
+
+/// Store local to the process, instances behind a load balancer need sticky
+/// sessions for the callback to reach the instance the login started at.
+#[derive(Clone, Default)]
+pub struct OidcStateStoreMemory {
+    authorizations: Arc<Mutex<HashMap<String, OidcAuthorization>>>,
+}
+
+impl OidcStateStoreMemory {
+    pub fn new() -> Self {
+        OidcStateStoreMemory::default()
+    }
+}
+
+impl OidcStateStore for OidcStateStoreMemory {
+    async fn save(
+        &self,
+        authorization: OidcAuthorization,
+        now: DateTime<Utc>,
+    ) -> Result<(), anyhow::Error> {
+        let mut authorizations = self
+            .authorizations
+            .lock()
+            .map_err(|_| anyhow::anyhow!("OIDC authorizations are poisoned"))?;
+        if authorizations.len() >= MAX_ENTRIES_BEFORE_PRUNE {
+            authorizations.retain(|_, authorization| !authorization.is_expired(now));
+        }
+        authorizations.insert(authorization.state.clone(), authorization);
+        Ok(())
+    }
+
+    async fn take(&self, state: &str) -> Result<Option<OidcAuthorization>, anyhow::Error> {
+        let mut authorizations = self
+            .authorizations
+            .lock()
+            .map_err(|_| anyhow::anyhow!("OIDC authorizations are poisoned"))?;
+        Ok(authorizations.remove(state))
+    }
+}
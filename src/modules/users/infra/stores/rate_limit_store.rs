@@ -0,0 +1,28 @@
+use chrono::{DateTime, Duration, Utc};
+use futures_util::future::BoxFuture;
+
+use crate::modules::users::domain::entities::login_failures::LockoutPolicy;
+
+/// Boxes its futures so the backend chosen in the configuration can be held
+/// as `dyn RateLimitStore`.
+pub trait RateLimitStore: Send + Sync {
+    fn take_token<'a>(
+        &'a self,
+        key: &'a str,
+        capacity: u32,
+        refill_interval: Duration,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Result<(), Duration>, anyhow::Error>>;
+    fn find_locked_until<'a>(
+        &'a self,
+        key: &'a str,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Option<DateTime<Utc>>, anyhow::Error>>;
+    fn record_failure<'a>(
+        &'a self,
+        key: &'a str,
+        policy: &'a LockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Option<DateTime<Utc>>, anyhow::Error>>;
+    fn clear_failures<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), anyhow::Error>>;
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Duration, Utc};
+use futures_util::future::BoxFuture;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::modules::users::domain::entities::{
+    login_failures::{LockoutPolicy, LoginFailures},
+    token_bucket::TokenBucket,
+};
+
+use super::rate_limit_store::RateLimitStore;
+
+const MAX_ENTRIES_BEFORE_PRUNE: usize = 10_000;
+
+/// Store local to the process, instances behind a load balancer each keep
+/// their own limits.
+#[derive(Clone, Default)]
+pub struct RateLimitStoreMemory {
+    buckets: Arc<Mutex<HashMap<String, TokenBucket>>>,
+    failures: Arc<Mutex<HashMap<String, LoginFailures>>>,
+}
+
+impl RateLimitStoreMemory {
+    pub fn new() -> Self {
+        RateLimitStoreMemory::default()
+    }
+}
+
+impl RateLimitStore for RateLimitStoreMemory {
+    fn take_token<'a>(
+        &'a self,
+        key: &'a str,
+        capacity: u32,
+        refill_interval: Duration,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Result<(), Duration>, anyhow::Error>> {
+        Box::pin(async move {
+            let mut buckets = self
+                .buckets
+                .lock()
+                .map_err(|_| anyhow::anyhow!("Rate limit buckets are poisoned"))?;
+            if buckets.len() >= MAX_ENTRIES_BEFORE_PRUNE {
+                buckets.retain(|_, bucket| bucket.full_at > now);
+            }
+            Ok(buckets
+                .entry(key.to_string())
+                .or_insert_with(|| TokenBucket::new(capacity, now))
+                .take(capacity, refill_interval, now))
+        })
+    }
+
+    fn find_locked_until<'a>(
+        &'a self,
+        key: &'a str,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Option<DateTime<Utc>>, anyhow::Error>> {
+        Box::pin(async move {
+            let failures = self
+                .failures
+                .lock()
+                .map_err(|_| anyhow::anyhow!("Login failures are poisoned"))?;
+            Ok(failures
+                .get(key)
+                .and_then(|failures| failures.locked_until(now)))
+        })
+    }
+
+    fn record_failure<'a>(
+        &'a self,
+        key: &'a str,
+        policy: &'a LockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Option<DateTime<Utc>>, anyhow::Error>> {
+        Box::pin(async move {
+            let mut failures = self
+                .failures
+                .lock()
+                .map_err(|_| anyhow::anyhow!("Login failures are poisoned"))?;
+            if failures.len() >= MAX_ENTRIES_BEFORE_PRUNE {
+                failures.retain(|_, failures| !failures.is_expired(policy, now));
+            }
+            Ok(failures
+                .entry(key.to_string())
+                .or_default()
+                .record_failure(policy, now))
+        })
+    }
+
+    fn clear_failures<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let mut failures = self
+                .failures
+                .lock()
+                .map_err(|_| anyhow::anyhow!("Login failures are poisoned"))?;
+            failures.remove(key);
+            Ok(())
+        })
+    }
+}
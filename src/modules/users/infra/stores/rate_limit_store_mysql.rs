@@ -0,0 +1,160 @@
+use chrono::{DateTime, Duration, Utc};
+use futures_util::future::BoxFuture;
+use sqlx::{MySqlPool, Row};
+use std::sync::Arc;
+
+use crate::modules::users::domain::entities::{
+    login_failures::{LockoutPolicy, LoginFailures},
+    token_bucket::TokenBucket,
+};
+
+use super::rate_limit_store::RateLimitStore;
+
+const PRUNED_ENTRIES_PER_WRITE: u64 = 100;
+
+/// Store shared by every instance using the same database. Rows are locked
+/// while a bucket or the failures of an account are updated, so concurrent
+/// attempts can't spend the same token.
+#[derive(Clone)]
+pub struct RateLimitStoreMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl RateLimitStoreMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        RateLimitStoreMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+impl RateLimitStore for RateLimitStoreMySQL {
+    fn take_token<'a>(
+        &'a self,
+        key: &'a str,
+        capacity: u32,
+        refill_interval: Duration,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Result<(), Duration>, anyhow::Error>> {
+        Box::pin(async move {
+            let mut transaction = self.connection.begin().await?;
+            sqlx::query("DELETE FROM login_rate_limit_buckets WHERE full_at <= ? LIMIT ?")
+                .bind(now)
+                .bind(PRUNED_ENTRIES_PER_WRITE)
+                .execute(&mut *transaction)
+                .await?;
+            let existing_bucket = sqlx::query(
+                "SELECT tokens, refilled_at, full_at FROM login_rate_limit_buckets WHERE bucket_key = ? FOR UPDATE",
+            )
+            .bind(key)
+            .fetch_optional(&mut *transaction)
+            .await?;
+            let mut bucket = match existing_bucket {
+                Some(row) => TokenBucket {
+                    tokens: row.get("tokens"),
+                    refilled_at: row.get("refilled_at"),
+                    full_at: row.get("full_at"),
+                },
+                None => TokenBucket::new(capacity, now),
+            };
+            let taken = bucket.take(capacity, refill_interval, now);
+            sqlx::query(
+                r#"
+                INSERT INTO login_rate_limit_buckets (bucket_key, tokens, refilled_at, full_at)
+                VALUES (?, ?, ?, ?)
+                ON DUPLICATE KEY UPDATE tokens = VALUES(tokens),
+                refilled_at = VALUES(refilled_at), full_at = VALUES(full_at)
+                "#,
+            )
+            .bind(key)
+            .bind(bucket.tokens)
+            .bind(bucket.refilled_at)
+            .bind(bucket.full_at)
+            .execute(&mut *transaction)
+            .await?;
+            transaction.commit().await?;
+            Ok(taken)
+        })
+    }
+
+    fn find_locked_until<'a>(
+        &'a self,
+        key: &'a str,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Option<DateTime<Utc>>, anyhow::Error>> {
+        Box::pin(async move {
+            let locked_until: Option<Option<DateTime<Utc>>> =
+                sqlx::query_scalar("SELECT locked_until FROM login_failures WHERE account_key = ?")
+                    .bind(key)
+                    .fetch_optional(self.connection.as_ref())
+                    .await?;
+            Ok(locked_until
+                .flatten()
+                .filter(|locked_until| *locked_until > now))
+        })
+    }
+
+    fn record_failure<'a>(
+        &'a self,
+        key: &'a str,
+        policy: &'a LockoutPolicy,
+        now: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Option<DateTime<Utc>>, anyhow::Error>> {
+        Box::pin(async move {
+            let mut transaction = self.connection.begin().await?;
+            sqlx::query(
+                r#"
+                DELETE FROM login_failures
+                WHERE (locked_until IS NULL OR locked_until <= ?) AND last_failure_at < ?
+                LIMIT ?
+                "#,
+            )
+            .bind(now)
+            .bind(now - policy.max)
+            .bind(PRUNED_ENTRIES_PER_WRITE)
+            .execute(&mut *transaction)
+            .await?;
+            let existing_failures = sqlx::query(
+                "SELECT failures, last_failure_at, locked_until FROM login_failures WHERE account_key = ? FOR UPDATE",
+            )
+            .bind(key)
+            .fetch_optional(&mut *transaction)
+            .await?;
+            let mut failures = match existing_failures {
+                Some(row) => LoginFailures {
+                    failures: row.get("failures"),
+                    last_failure_at: row.get("last_failure_at"),
+                    locked_until: row.get("locked_until"),
+                },
+                None => LoginFailures::default(),
+            };
+            let locked_until = failures.record_failure(policy, now);
+            sqlx::query(
+                r#"
+                INSERT INTO login_failures (account_key, failures, last_failure_at, locked_until)
+                VALUES (?, ?, ?, ?)
+                ON DUPLICATE KEY UPDATE failures = VALUES(failures),
+                last_failure_at = VALUES(last_failure_at), locked_until = VALUES(locked_until)
+                "#,
+            )
+            .bind(key)
+            .bind(failures.failures)
+            .bind(failures.last_failure_at)
+            .bind(failures.locked_until)
+            .execute(&mut *transaction)
+            .await?;
+            transaction.commit().await?;
+            Ok(locked_until)
+        })
+    }
+
+    fn clear_failures<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            sqlx::query("DELETE FROM login_failures WHERE account_key = ?")
+                .bind(key)
+                .execute(self.connection.as_ref())
+                .await?;
+            Ok(())
+        })
+    }
+}
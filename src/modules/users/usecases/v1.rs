@@ -1,3 +1,9 @@
+pub mod confirm_two_factor;
 pub mod create_user;
+pub mod disable_two_factor;
+pub mod enroll_two_factor;
 pub mod get_user_info;
+pub mod limit_login_attempts;
+pub mod login_oidc_user;
 pub mod login_user;
+pub mod verify_two_factor_proof;
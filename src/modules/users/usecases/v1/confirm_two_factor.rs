@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::{
+        domain::{
+            dtos::recovery_codes_dto::RecoveryCodesDto,
+            entities::{
+                recovery_code::{generate_recovery_codes, hash_recovery_code},
+                totp::{base32_decode, verify_totp_code},
+                two_factor::TwoFactorProof,
+            },
+        },
+        infra::repositories::{
+            two_factor_repository::TwoFactorRepository,
+            two_factor_repository_mysql::TwoFactorRepositoryMySQL,
+        },
+    },
+};
+
+pub struct ConfirmTwoFactorUseCaseV1<T>
+where
+    T: TwoFactorRepository,
+{
+    two_factor_repository: Arc<T>,
+}
+
+impl ConfirmTwoFactorUseCaseV1<TwoFactorRepositoryMySQL> {
+    pub fn new(two_factor_repository: TwoFactorRepositoryMySQL) -> Self {
+        Self {
+            two_factor_repository: Arc::new(two_factor_repository),
+        }
+    }
+
+    pub async fn confirm_two_factor(
+        &self,
+        user_id: u64,
+        proof: TwoFactorProof,
+    ) -> Result<RecoveryCodesDto, APIError> {
+        let two_factor = match self.two_factor_repository.find_by_user_id(user_id).await {
+            Ok(Some(two_factor)) => two_factor,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Two-factor authentication was not enrolled".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!(
+                        "Failed to retrieve two-factor authentication from DB: {}",
+                        error
+                    ),
+                    500,
+                )));
+            }
+        };
+        if two_factor.is_enabled() {
+            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Two-factor authentication is already enabled".to_string(),
+                409,
+            )));
+        }
+
+        let code = match proof {
+            TwoFactorProof::Code(code) => code,
+            TwoFactorProof::RecoveryCode(_) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Invalid two-factor code".to_string(),
+                    401,
+                )));
+            }
+        };
+        let secret = match base32_decode(&two_factor.secret) {
+            Some(secret) => secret,
+            None => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Failed to parse two-factor secret from DB".to_string(),
+                    500,
+                )));
+            }
+        };
+        let step = match verify_totp_code(&secret, &code, chrono::offset::Utc::now()) {
+            Some(step) => step,
+            None => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Invalid two-factor code".to_string(),
+                    401,
+                )));
+            }
+        };
+
+        let recovery_codes = generate_recovery_codes();
+        let recovery_code_hashes: Vec<String> = recovery_codes
+            .iter()
+            .map(|recovery_code| hash_recovery_code(recovery_code))
+            .collect();
+        match self
+            .two_factor_repository
+            .confirm(user_id, step, &recovery_code_hashes)
+            .await
+        {
+            Ok(_) => Ok(RecoveryCodesDto { recovery_codes }),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                format!("Failed to confirm two-factor authentication: {}", error),
+                500,
+            ))),
+        }
+    }
+}
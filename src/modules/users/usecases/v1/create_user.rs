@@ -1,6 +1,13 @@
 use std::sync::Arc;
 
 use crate::modules::{
+    libraries::{
+        domain::entities::library::{Library, PERSONAL_LIBRARY_NAME},
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::create_library_usecase::CreateLibraryUseCaseV1,
+    },
     shared::errors::{simple_api_error::SimpleAPIError, APIError},
     users::{
         domain::entities::user::User,
@@ -10,17 +17,23 @@ use crate::modules::{
     },
 };
 
-pub struct CreateUserUseCaseV1<T>
+pub struct CreateUserUseCaseV1<T, U>
 where
     T: UserRepository,
+    U: LibraryRepository,
 {
     user_repository: Arc<T>,
+    create_library_usecase: CreateLibraryUseCaseV1<U>,
 }
 
-impl CreateUserUseCaseV1<UserRepositoryMySQL> {
-    pub fn new(user_repository: UserRepositoryMySQL) -> Self {
+impl CreateUserUseCaseV1<UserRepositoryMySQL, LibraryRepositoryMySQL> {
+    pub fn new(
+        user_repository: UserRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+    ) -> Self {
         Self {
             user_repository: Arc::new(user_repository),
+            create_library_usecase: CreateLibraryUseCaseV1::new(library_repository),
         }
     }
 
@@ -46,18 +59,33 @@ impl CreateUserUseCaseV1<UserRepositoryMySQL> {
             }
         }
 
-        match self.user_repository.save(&user_to_be_created).await {
+        let created_user = match self.user_repository.save(&user_to_be_created).await {
             Ok(t) => match t {
-                Some(returned_user) => Ok(returned_user),
-                None => Err(APIError::SimpleAPIError(SimpleAPIError::new(
-                    "Failed to load created user info".to_string(),
-                    500,
-                ))),
+                Some(returned_user) => returned_user,
+                None => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "Failed to load created user info".to_string(),
+                        500,
+                    )))
+                }
             },
-            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
-                e.to_string(),
-                500,
-            ))),
-        }
+            Err(e) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    e.to_string(),
+                    500,
+                )))
+            }
+        };
+
+        // Every account starts with a personal library it owns
+        self.create_library_usecase
+            .create_library(Library {
+                name: PERSONAL_LIBRARY_NAME.to_string(),
+                owner_id: created_user.id.unwrap_or_default(),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(created_user)
     }
 }
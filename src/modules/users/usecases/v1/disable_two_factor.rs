@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::{
+        domain::entities::two_factor::TwoFactorProof,
+        infra::repositories::{
+            two_factor_repository::TwoFactorRepository,
+            two_factor_repository_mysql::TwoFactorRepositoryMySQL,
+        },
+        usecases::v1::verify_two_factor_proof::VerifyTwoFactorProofUseCaseV1,
+    },
+};
+
+pub struct DisableTwoFactorUseCaseV1<T>
+where
+    T: TwoFactorRepository,
+{
+    two_factor_repository: Arc<T>,
+    verify_two_factor_proof_usecase: VerifyTwoFactorProofUseCaseV1<T>,
+}
+
+impl DisableTwoFactorUseCaseV1<TwoFactorRepositoryMySQL> {
+    pub fn new(two_factor_repository: TwoFactorRepositoryMySQL) -> Self {
+        Self {
+            two_factor_repository: Arc::new(two_factor_repository.clone()),
+            verify_two_factor_proof_usecase: VerifyTwoFactorProofUseCaseV1::new(
+                two_factor_repository,
+            ),
+        }
+    }
+
+    /// A stolen access token alone can't turn two-factor authentication off,
+    /// a current code or an unused recovery code is required.
+    pub async fn disable_two_factor(
+        &self,
+        user_id: u64,
+        proof: TwoFactorProof,
+    ) -> Result<(), APIError> {
+        let two_factor = match self.two_factor_repository.find_by_user_id(user_id).await {
+            Ok(Some(two_factor)) if two_factor.is_enabled() => two_factor,
+            Ok(_) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Two-factor authentication is not enabled".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!(
+                        "Failed to retrieve two-factor authentication from DB: {}",
+                        error
+                    ),
+                    500,
+                )));
+            }
+        };
+        self.verify_two_factor_proof_usecase
+            .verify(&two_factor, proof)
+            .await?;
+
+        match self.two_factor_repository.delete_by_user_id(user_id).await {
+            Ok(_) => Ok(()),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                format!("Failed to disable two-factor authentication: {}", error),
+                500,
+            ))),
+        }
+    }
+}
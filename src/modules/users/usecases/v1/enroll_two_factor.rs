@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use rand::Rng;
+use tracing::error;
+
+use crate::modules::{
+    shared::{
+        errors::{simple_api_error::SimpleAPIError, APIError},
+        infra::qr_codes::qr_code::QrCode,
+    },
+    users::{
+        domain::{
+            dtos::two_factor_enrollment_dto::TwoFactorEnrollmentDto,
+            entities::totp::{base32_encode, otpauth_uri, TOTP_SECRET_LENGTH},
+        },
+        infra::repositories::{
+            two_factor_repository::TwoFactorRepository,
+            two_factor_repository_mysql::TwoFactorRepositoryMySQL, user_repository::UserRepository,
+            user_repository_mysql::UserRepositoryMySQL,
+        },
+    },
+};
+
+pub struct EnrollTwoFactorUseCaseV1<T, U>
+where
+    T: UserRepository,
+    U: TwoFactorRepository,
+{
+    user_repository: Arc<T>,
+    two_factor_repository: Arc<U>,
+}
+
+impl EnrollTwoFactorUseCaseV1<UserRepositoryMySQL, TwoFactorRepositoryMySQL> {
+    pub fn new(
+        user_repository: UserRepositoryMySQL,
+        two_factor_repository: TwoFactorRepositoryMySQL,
+    ) -> Self {
+        Self {
+            user_repository: Arc::new(user_repository),
+            two_factor_repository: Arc::new(two_factor_repository),
+        }
+    }
+
+    pub async fn enroll_two_factor(
+        &self,
+        user_id: u64,
+    ) -> Result<TwoFactorEnrollmentDto, APIError> {
+        match self.two_factor_repository.find_by_user_id(user_id).await {
+            Ok(Some(two_factor)) if two_factor.is_enabled() => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Two-factor authentication is already enabled".to_string(),
+                    409,
+                )));
+            }
+            Ok(_) => {}
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!(
+                        "Failed to retrieve two-factor authentication from DB: {}",
+                        error
+                    ),
+                    500,
+                )));
+            }
+        }
+
+        let user = match self.user_repository.find_by_id(user_id).await {
+            Ok(Some(user)) => user,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "User not found".to_string(),
+                    404,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!("Failed to retrieve user from DB: {}", error),
+                    500,
+                )));
+            }
+        };
+
+        let secret: [u8; TOTP_SECRET_LENGTH] = rand::thread_rng().gen();
+        let encoded_secret = base32_encode(&secret);
+        if let Err(error) = self
+            .two_factor_repository
+            .save_secret(user_id, &encoded_secret)
+            .await
+        {
+            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                format!("Failed to save two-factor secret: {}", error),
+                500,
+            )));
+        }
+
+        let otpauth_uri = otpauth_uri(&user.email, &encoded_secret);
+        // Long emails may not fit a QR code, the URI is still usable.
+        let qr_code_svg = match QrCode::encode(otpauth_uri.as_bytes()) {
+            Ok(qr_code) => Some(qr_code.to_svg()),
+            Err(error) => {
+                error!("Failed to generate QR code: {}", error);
+                None
+            }
+        };
+
+        Ok(TwoFactorEnrollmentDto {
+            secret: encoded_secret,
+            otpauth_uri,
+            qr_code_svg,
+        })
+    }
+}
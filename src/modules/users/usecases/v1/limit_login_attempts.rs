@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use chrono::Duration;
+use tracing::error;
+
+use crate::{
+    configuration::LoginRateLimitSettings,
+    modules::{
+        shared::infra::clocks::clock::Clock,
+        users::{
+            domain::entities::login_failures::LockoutPolicy,
+            infra::stores::rate_limit_store::RateLimitStore,
+        },
+    },
+};
+
+/// Login attempts are limited by client address and by account, each with
+/// its own token bucket, and accounts are locked after failing repeatedly.
+/// Limits aren't enforced when the store fails, logins keep working.
+pub struct LimitLoginAttemptsUseCaseV1<T>
+where
+    T: RateLimitStore + ?Sized,
+{
+    rate_limit_store: Arc<T>,
+    settings: LoginRateLimitSettings,
+    clock: Arc<dyn Clock>,
+}
+
+impl LimitLoginAttemptsUseCaseV1<dyn RateLimitStore> {
+    pub fn new(
+        rate_limit_store: Arc<dyn RateLimitStore>,
+        settings: LoginRateLimitSettings,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            rate_limit_store,
+            settings,
+            clock,
+        }
+    }
+}
+
+impl<T> LimitLoginAttemptsUseCaseV1<T>
+where
+    T: RateLimitStore + ?Sized,
+{
+    pub fn trust_forwarded_for(&self) -> bool {
+        self.settings.trust_forwarded_for
+    }
+
+    fn lockout_policy(&self) -> LockoutPolicy {
+        LockoutPolicy {
+            threshold: self.settings.lockout_threshold,
+            base: Duration::seconds(self.settings.lockout_base_seconds as i64),
+            max: Duration::seconds(self.settings.lockout_max_seconds as i64),
+        }
+    }
+
+    pub async fn check_attempt(
+        &self,
+        client_address: Option<&str>,
+        account: Option<&str>,
+    ) -> Result<(), Duration> {
+        let now = self.clock.now();
+        if let Some(account) = account {
+            match self
+                .rate_limit_store
+                .find_locked_until(&account_key(account), now)
+                .await
+            {
+                Ok(Some(locked_until)) => return Err(locked_until - now),
+                Ok(None) => {}
+                Err(error) => error!("Failed to check the account lockout: {}", error),
+            }
+        }
+
+        let mut buckets = Vec::with_capacity(2);
+        if let Some(client_address) = client_address {
+            buckets.push((
+                format!("ip:{}", client_address),
+                self.settings.ip_capacity,
+                self.settings.ip_refill_seconds,
+            ));
+        }
+        if let Some(account) = account {
+            buckets.push((
+                account_key(account),
+                self.settings.account_capacity,
+                self.settings.account_refill_seconds,
+            ));
+        }
+        for (key, capacity, refill_seconds) in buckets {
+            match self
+                .rate_limit_store
+                .take_token(
+                    &key,
+                    capacity,
+                    Duration::seconds(refill_seconds as i64),
+                    now,
+                )
+                .await
+            {
+                Ok(Ok(())) => {}
+                Ok(Err(retry_after)) => return Err(retry_after),
+                Err(error) => error!("Failed to take a login attempt: {}", error),
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn record_failure(&self, account: &str) -> Option<Duration> {
+        let now = self.clock.now();
+        match self
+            .rate_limit_store
+            .record_failure(&account_key(account), &self.lockout_policy(), now)
+            .await
+        {
+            Ok(locked_until) => locked_until.map(|locked_until| locked_until - now),
+            Err(error) => {
+                error!("Failed to record a failed login: {}", error);
+                None
+            }
+        }
+    }
+
+    pub async fn record_success(&self, account: &str) {
+        if let Err(error) = self
+            .rate_limit_store
+            .clear_failures(&account_key(account))
+            .await
+        {
+            error!("Failed to clear the failed logins: {}", error);
+        }
+    }
+}
+
+fn account_key(account: &str) -> String {
+    format!("account:{}", account.trim().to_lowercase())
+}
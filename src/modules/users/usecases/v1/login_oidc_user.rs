@@ -0,0 +1,449 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHasher, SaltString},
+    Argon2,
+};
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::jwk::JwkSet;
+use rand::{distributions::Alphanumeric, Rng};
+use reqwest::Url;
+use sha2::{Digest, Sha256};
+use tracing::error;
+
+use crate::{
+    configuration::{OidcProviderSettings, OidcSettings, TokenSettings},
+    modules::{
+        libraries::infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+        shared::errors::{simple_api_error::SimpleAPIError, APIError},
+        users::{
+            domain::{
+                dtos::oidc_provider_dto::OidcProviderDto,
+                entities::{
+                    id_token::{validate_id_token, IdTokenClaims, IdTokenError},
+                    oidc_authorization::{OidcAuthorization, OidcCallback},
+                    oidc_provider_metadata::OidcProviderMetadata,
+                    user::User,
+                    user_identity::UserIdentity,
+                },
+            },
+            infra::{
+                clients::{oidc_client::OidcClient, oidc_client_reqwest::OidcClientReqwest},
+                repositories::{
+                    two_factor_repository_mysql::TwoFactorRepositoryMySQL,
+                    user_identity_repository::UserIdentityRepository,
+                    user_identity_repository_mysql::UserIdentityRepositoryMySQL,
+                    user_repository::UserRepository, user_repository_mysql::UserRepositoryMySQL,
+                },
+                stores::{
+                    oidc_state_store::OidcStateStore, oidc_state_store_memory::OidcStateStoreMemory,
+                },
+            },
+            usecases::v1::{
+                create_user::CreateUserUseCaseV1,
+                login_user::{LoginOutcome, LoginUserUseCaseV1},
+            },
+        },
+    },
+};
+
+const OIDC_DISCOVERY_CACHE_SECONDS: i64 = 3600;
+
+#[derive(Clone)]
+struct DiscoveredProvider {
+    metadata: OidcProviderMetadata,
+    jwks: JwkSet,
+    discovered_at: DateTime<Utc>,
+}
+
+pub struct LoginOidcUserUseCaseV1<T, U, V, W>
+where
+    T: UserRepository,
+    U: UserIdentityRepository,
+    V: OidcClient,
+    W: OidcStateStore,
+{
+    user_repository: Arc<T>,
+    user_identity_repository: Arc<U>,
+    oidc_client: Arc<V>,
+    oidc_state_store: Arc<W>,
+    login_user_usecase: LoginUserUseCaseV1<UserRepositoryMySQL, TwoFactorRepositoryMySQL>,
+    create_user_usecase: CreateUserUseCaseV1<UserRepositoryMySQL, LibraryRepositoryMySQL>,
+    oidc_settings: OidcSettings,
+    discovered_providers: Mutex<HashMap<String, DiscoveredProvider>>,
+}
+
+impl
+    LoginOidcUserUseCaseV1<
+        UserRepositoryMySQL,
+        UserIdentityRepositoryMySQL,
+        OidcClientReqwest,
+        OidcStateStoreMemory,
+    >
+{
+    pub fn new(
+        user_repository: UserRepositoryMySQL,
+        user_identity_repository: UserIdentityRepositoryMySQL,
+        two_factor_repository: TwoFactorRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        oidc_client: OidcClientReqwest,
+        oidc_state_store: OidcStateStoreMemory,
+        oidc_settings: OidcSettings,
+    ) -> Self {
+        Self {
+            user_repository: Arc::new(user_repository.clone()),
+            user_identity_repository: Arc::new(user_identity_repository),
+            oidc_client: Arc::new(oidc_client),
+            oidc_state_store: Arc::new(oidc_state_store),
+            login_user_usecase: LoginUserUseCaseV1::new(
+                user_repository.clone(),
+                two_factor_repository,
+            ),
+            create_user_usecase: CreateUserUseCaseV1::new(user_repository, library_repository),
+            oidc_settings,
+            discovered_providers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn list_providers(&self) -> Vec<OidcProviderDto> {
+        self.oidc_settings
+            .providers
+            .iter()
+            .map(|provider| OidcProviderDto {
+                name: provider.name.clone(),
+            })
+            .collect()
+    }
+
+    pub async fn authorize(&self, provider_name: &str) -> Result<String, APIError> {
+        let provider = self.find_provider(provider_name)?;
+        let discovered_provider = self.discover(provider, false).await?;
+
+        let now = Utc::now();
+        let authorization = OidcAuthorization::new(
+            &provider.name,
+            now + Duration::seconds(self.oidc_settings.state_expiration_seconds as i64),
+        );
+        let authorization_url = match Url::parse_with_params(
+            &discovered_provider.metadata.authorization_endpoint,
+            &[
+                ("response_type", "code"),
+                ("client_id", provider.client_id.as_str()),
+                ("redirect_uri", provider.redirect_uri.as_str()),
+                ("scope", provider.scopes.join(" ").as_str()),
+                ("state", authorization.state.as_str()),
+                ("nonce", authorization.nonce.as_str()),
+                ("code_challenge", authorization.code_challenge().as_str()),
+                ("code_challenge_method", "S256"),
+            ],
+        ) {
+            Ok(url) => url,
+            Err(error) => {
+                error!(
+                    "Invalid authorization endpoint of {}: {}",
+                    provider.name, error
+                );
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Failed to reach the OIDC provider".to_string(),
+                    502,
+                )));
+            }
+        };
+
+        match self.oidc_state_store.save(authorization, now).await {
+            Ok(_) => Ok(authorization_url.to_string()),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                format!("Failed to save OIDC login: {}", error),
+                500,
+            ))),
+        }
+    }
+
+    pub async fn login_oidc_user(
+        &self,
+        provider_name: &str,
+        callback: OidcCallback,
+        token_settings: &TokenSettings,
+    ) -> Result<LoginOutcome, APIError> {
+        let provider = self.find_provider(provider_name)?;
+        let authorization = match self.oidc_state_store.take(&callback.state).await {
+            Ok(Some(authorization))
+                if authorization.provider == provider.name
+                    && !authorization.is_expired(Utc::now()) =>
+            {
+                authorization
+            }
+            Ok(_) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Invalid or expired OIDC state".to_string(),
+                    401,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!("Failed to retrieve OIDC login: {}", error),
+                    500,
+                )));
+            }
+        };
+
+        let discovered_provider = self.discover(provider, false).await?;
+        let id_token = match self
+            .oidc_client
+            .exchange_code(
+                provider,
+                &discovered_provider.metadata,
+                &callback.code,
+                &authorization.code_verifier,
+            )
+            .await
+        {
+            Ok(id_token) => id_token,
+            Err(error) => {
+                error!("Failed to exchange code with {}: {}", provider.name, error);
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Failed to exchange the authorization code".to_string(),
+                    401,
+                )));
+            }
+        };
+        let claims = self
+            .validate_id_token(
+                provider,
+                discovered_provider,
+                &id_token,
+                &authorization.nonce,
+            )
+            .await?;
+
+        let user = self.find_or_link_user(provider, claims).await?;
+        self.login_user_usecase
+            .login_verified_user(user, token_settings)
+            .await
+    }
+
+    fn find_provider(&self, provider_name: &str) -> Result<&OidcProviderSettings, APIError> {
+        match self
+            .oidc_settings
+            .providers
+            .iter()
+            .find(|provider| provider.name == provider_name)
+        {
+            Some(provider) => Ok(provider),
+            None => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "OIDC provider not found".to_string(),
+                404,
+            ))),
+        }
+    }
+
+    async fn discover(
+        &self,
+        provider: &OidcProviderSettings,
+        refresh: bool,
+    ) -> Result<DiscoveredProvider, APIError> {
+        let now = Utc::now();
+        if !refresh {
+            let cached = match self.discovered_providers.lock() {
+                Ok(discovered_providers) => discovered_providers.get(&provider.name).cloned(),
+                Err(_) => None,
+            };
+            if let Some(discovered_provider) = cached.filter(|discovered_provider| {
+                now - discovered_provider.discovered_at
+                    < Duration::seconds(OIDC_DISCOVERY_CACHE_SECONDS)
+            }) {
+                return Ok(discovered_provider);
+            }
+        }
+
+        let metadata = match self.oidc_client.discover(&provider.issuer).await {
+            Ok(metadata) => metadata,
+            Err(error) => return Err(provider_unreachable(provider, error)),
+        };
+        // Tokens are validated against the configured issuer, a document
+        // naming another one is not trusted.
+        if metadata.issuer.trim_end_matches('/') != provider.issuer.trim_end_matches('/') {
+            return Err(provider_unreachable(
+                provider,
+                format!("Discovery document names issuer {}", metadata.issuer),
+            ));
+        }
+        let jwks = match self.oidc_client.fetch_jwks(&metadata.jwks_uri).await {
+            Ok(jwks) => jwks,
+            Err(error) => return Err(provider_unreachable(provider, error)),
+        };
+
+        let discovered_provider = DiscoveredProvider {
+            metadata,
+            jwks,
+            discovered_at: now,
+        };
+        if let Ok(mut discovered_providers) = self.discovered_providers.lock() {
+            discovered_providers.insert(provider.name.clone(), discovered_provider.clone());
+        }
+        Ok(discovered_provider)
+    }
+
+    async fn validate_id_token(
+        &self,
+        provider: &OidcProviderSettings,
+        discovered_provider: DiscoveredProvider,
+        id_token: &str,
+        nonce: &str,
+    ) -> Result<IdTokenClaims, APIError> {
+        let mut result = validate_id_token(
+            id_token,
+            &discovered_provider.jwks,
+            &discovered_provider.metadata.issuer,
+            &provider.client_id,
+            nonce,
+        );
+        // The provider may have rotated its keys since they were cached.
+        if matches!(result, Err(IdTokenError::UnknownKey)) {
+            let discovered_provider = self.discover(provider, true).await?;
+            result = validate_id_token(
+                id_token,
+                &discovered_provider.jwks,
+                &discovered_provider.metadata.issuer,
+                &provider.client_id,
+                nonce,
+            );
+        }
+        match result {
+            Ok(claims) => Ok(claims),
+            Err(error) => {
+                error!("Invalid ID token from {}: {:?}", provider.name, error);
+                Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Invalid ID token".to_string(),
+                    401,
+                )))
+            }
+        }
+    }
+
+    async fn find_or_link_user(
+        &self,
+        provider: &OidcProviderSettings,
+        claims: IdTokenClaims,
+    ) -> Result<User, APIError> {
+        match self
+            .user_identity_repository
+            .find_by_provider_and_subject(&provider.name, &claims.sub)
+            .await
+        {
+            Ok(Some(user_identity)) => {
+                return match self.user_repository.find_by_id(user_identity.user_id).await {
+                    Ok(Some(user)) => Ok(user),
+                    Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        "User not found".to_string(),
+                        404,
+                    ))),
+                    Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                        format!("Failed to retrieve user from DB: {}", error),
+                        500,
+                    ))),
+                };
+            }
+            Ok(None) => {}
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!("Failed to retrieve identity from DB: {}", error),
+                    500,
+                )));
+            }
+        }
+
+        // Only an email the provider verified can claim an account.
+        let email = match claims.email.clone().filter(|_| claims.email_verified) {
+            Some(email) => email,
+            None => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "The provider did not share a verified email".to_string(),
+                    403,
+                )));
+            }
+        };
+        let user = match self.user_repository.find_by_email(&email).await {
+            Ok(Some(user)) => user,
+            Ok(None) if provider.auto_create_users => {
+                self.create_user_usecase
+                    .create_user(new_oidc_user(&email, claims.name.as_deref())?)
+                    .await?
+            }
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "No account is linked to this identity".to_string(),
+                    403,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!("Failed to retrieve user from DB: {}", error),
+                    500,
+                )));
+            }
+        };
+
+        let user_identity = UserIdentity {
+            user_id: user.id.unwrap_or_default(),
+            provider: provider.name.clone(),
+            subject: claims.sub,
+            email: Some(email),
+            ..Default::default()
+        };
+        match self.user_identity_repository.save(&user_identity).await {
+            Ok(_) => Ok(user),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                format!("Failed to link identity: {}", error),
+                500,
+            ))),
+        }
+    }
+}
+
+fn provider_unreachable(provider: &OidcProviderSettings, error: String) -> APIError {
+    error!("Failed to discover {}: {}", provider.name, error);
+    APIError::SimpleAPIError(SimpleAPIError::new(
+        "Failed to reach the OIDC provider".to_string(),
+        502,
+    ))
+}
+
+/// Account of an user signing in with a provider for the first time. Its
+/// password is random, logins by password need it to be reset first.
+fn new_oidc_user(email: &str, name: Option<&str>) -> Result<User, APIError> {
+    let mut rng = rand::thread_rng();
+    let password: String = (&mut rng)
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect();
+    let salt = SaltString::generate(&mut OsRng);
+    let password = match Argon2::default().hash_password(password.as_bytes(), &salt) {
+        Ok(hashed_password) => hashed_password.to_string(),
+        Err(error) => {
+            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                error.to_string(),
+                500,
+            )));
+        }
+    };
+
+    let mut hasher = Sha256::new();
+    hasher.update(rng.gen::<u64>().to_ne_bytes());
+
+    let name = match name.map(str::trim).filter(|name| !name.is_empty()) {
+        Some(name) => name.to_string(),
+        None => email.split('@').next().unwrap_or(email).to_string(),
+    };
+    Ok(User {
+        email: email.to_string(),
+        password,
+        email_token: Some(format!("{:x}", hasher.finalize())),
+        name,
+        ..Default::default()
+    })
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use argon2::{Argon2, PasswordHash, PasswordVerifier};
 use chrono::Duration;
-use jsonwebtoken::{encode, EncodingKey, Header};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use tracing::error;
 
 use crate::{
@@ -11,27 +11,54 @@ use crate::{
         shared::errors::{simple_api_error::SimpleAPIError, APIError},
         users::{
             domain::{
-                dtos::{claims_dto::ClaimsDto, token_user_dto::TokenUserDto},
-                entities::user::User,
+                dtos::{
+                    claims_dto::ClaimsDto,
+                    token_user_dto::TokenUserDto,
+                    two_factor_challenge_claims_dto::{
+                        TwoFactorChallengeClaimsDto, TWO_FACTOR_CHALLENGE_AUDIENCE,
+                    },
+                    two_factor_challenge_dto::TwoFactorChallengeDto,
+                },
+                entities::{two_factor::TwoFactorLogin, user::User},
             },
             infra::repositories::{
+                two_factor_repository::TwoFactorRepository,
+                two_factor_repository_mysql::TwoFactorRepositoryMySQL,
                 user_repository::UserRepository, user_repository_mysql::UserRepositoryMySQL,
             },
+            usecases::v1::verify_two_factor_proof::VerifyTwoFactorProofUseCaseV1,
         },
     },
 };
 
-pub struct LoginUserUseCaseV1<T>
+const TWO_FACTOR_CHALLENGE_EXPIRATION_SECONDS: i64 = 300;
+
+pub enum LoginOutcome {
+    Authenticated(TokenUserDto),
+    TwoFactorRequired(TwoFactorChallengeDto),
+}
+
+pub struct LoginUserUseCaseV1<T, U>
 where
     T: UserRepository,
+    U: TwoFactorRepository,
 {
     user_repository: Arc<T>,
+    two_factor_repository: Arc<U>,
+    verify_two_factor_proof_usecase: VerifyTwoFactorProofUseCaseV1<U>,
 }
 
-impl LoginUserUseCaseV1<UserRepositoryMySQL> {
-    pub fn new(user_repository: UserRepositoryMySQL) -> Self {
+impl LoginUserUseCaseV1<UserRepositoryMySQL, TwoFactorRepositoryMySQL> {
+    pub fn new(
+        user_repository: UserRepositoryMySQL,
+        two_factor_repository: TwoFactorRepositoryMySQL,
+    ) -> Self {
         Self {
             user_repository: Arc::new(user_repository),
+            two_factor_repository: Arc::new(two_factor_repository.clone()),
+            verify_two_factor_proof_usecase: VerifyTwoFactorProofUseCaseV1::new(
+                two_factor_repository,
+            ),
         }
     }
 
@@ -39,7 +66,7 @@ impl LoginUserUseCaseV1<UserRepositoryMySQL> {
         &self,
         user: User,
         token_settings: &TokenSettings,
-    ) -> Result<TokenUserDto, APIError> {
+    ) -> Result<LoginOutcome, APIError> {
         let option_user_from_db = match self.user_repository.find_by_email(&user.email).await {
             Ok(found_user) => found_user,
             Err(error) => {
@@ -70,53 +97,191 @@ impl LoginUserUseCaseV1<UserRepositoryMySQL> {
             }
         };
 
-        match Argon2::default().verify_password(user.password.as_bytes(), &password_hash) {
+        if let Err(error) =
+            Argon2::default().verify_password(user.password.as_bytes(), &password_hash)
+        {
+            error!("{}", error);
+            return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Invalid credentials".to_string(),
+                403,
+            )));
+        }
+
+        self.login_verified_user(user_from_db, token_settings).await
+    }
+
+    pub async fn login_verified_user(
+        &self,
+        user_from_db: User,
+        token_settings: &TokenSettings,
+    ) -> Result<LoginOutcome, APIError> {
+        let user_id = match user_from_db.id {
+            Some(id) => id,
+            None => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "User id not found".to_string(),
+                    500,
+                )));
+            }
+        };
+
+        let two_factor = match self.two_factor_repository.find_by_user_id(user_id).await {
+            Ok(two_factor) => two_factor,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!(
+                        "Failed to retrieve two-factor authentication from DB: {}",
+                        error
+                    ),
+                    500,
+                )));
+            }
+        };
+        if two_factor.is_some_and(|two_factor| two_factor.is_enabled()) {
+            return Ok(LoginOutcome::TwoFactorRequired(
+                self.issue_challenge_token(user_id, user_from_db.email, token_settings)?,
+            ));
+        }
+
+        Ok(LoginOutcome::Authenticated(self.issue_access_token(
+            user_id,
+            user_from_db.name,
+            token_settings,
+        )?))
+    }
+
+    pub async fn verify_two_factor_login(
+        &self,
+        two_factor_login: TwoFactorLogin,
+        token_settings: &TokenSettings,
+    ) -> Result<TokenUserDto, APIError> {
+        let mut validation = Validation::new(jsonwebtoken::Algorithm::HS256);
+        validation.validate_exp = true;
+        validation.set_audience(&[TWO_FACTOR_CHALLENGE_AUDIENCE]);
+        let user_id = match decode::<TwoFactorChallengeClaimsDto>(
+            &two_factor_login.challenge_token,
+            &DecodingKey::from_secret(token_settings.secret.as_ref()),
+            &validation,
+        ) {
+            Ok(token_data) => token_data.claims.id,
+            Err(error) => {
+                error!("{}", error);
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Invalid or expired challenge token".to_string(),
+                    401,
+                )));
+            }
+        };
+
+        let two_factor = match self.two_factor_repository.find_by_user_id(user_id).await {
+            Ok(Some(two_factor)) if two_factor.is_enabled() => two_factor,
             Ok(_) => {
-                let expiration_time =
-                    chrono::offset::Utc::now() + Duration::seconds(token_settings.expiration_time);
-
-                let user_id = match user_from_db.id {
-                    Some(id) => id,
-                    None => {
-                        return Err(APIError::SimpleAPIError(SimpleAPIError::new(
-                            "User id not found".to_string(),
-                            500,
-                        )));
-                    }
-                };
-
-                let claims_dto = ClaimsDto {
-                    id: user_id,
-                    exp: expiration_time.timestamp(),
-                    user_name: user_from_db.name,
-                };
-
-                let generated_token = match encode(
-                    &Header::default(),
-                    &claims_dto,
-                    &EncodingKey::from_secret(token_settings.secret.as_ref()),
-                ) {
-                    Ok(token) => token,
-                    Err(error) => {
-                        error!("{}", error);
-                        return Err(APIError::SimpleAPIError(SimpleAPIError::new(
-                            "Unexpected error while authorizing".to_string(),
-                            500,
-                        )));
-                    }
-                };
-                Ok(TokenUserDto {
-                    access_token: generated_token,
-                    token_type: "Bearer".to_string(),
-                    expires_in: expiration_time.timestamp(),
-                    scope: None,
-                })
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Invalid or expired challenge token".to_string(),
+                    401,
+                )));
             }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!(
+                        "Failed to retrieve two-factor authentication from DB: {}",
+                        error
+                    ),
+                    500,
+                )));
+            }
+        };
+
+        self.verify_two_factor_proof_usecase
+            .verify(&two_factor, two_factor_login.proof)
+            .await?;
+
+        let user = match self.user_repository.find_by_id(user_id).await {
+            Ok(Some(user)) => user,
+            Ok(None) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Invalid or expired challenge token".to_string(),
+                    401,
+                )));
+            }
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    format!("Failed to retrieve user from DB: {}", error),
+                    500,
+                )));
+            }
+        };
+
+        self.issue_access_token(user_id, user.name, token_settings)
+    }
+
+    fn issue_access_token(
+        &self,
+        user_id: u64,
+        user_name: String,
+        token_settings: &TokenSettings,
+    ) -> Result<TokenUserDto, APIError> {
+        let expiration_time =
+            chrono::offset::Utc::now() + Duration::seconds(token_settings.expiration_time);
+
+        let claims_dto = ClaimsDto {
+            id: user_id,
+            exp: expiration_time.timestamp(),
+            user_name,
+        };
+
+        let generated_token = match encode(
+            &Header::default(),
+            &claims_dto,
+            &EncodingKey::from_secret(token_settings.secret.as_ref()),
+        ) {
+            Ok(token) => token,
+            Err(error) => {
+                error!("{}", error);
+                return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                    "Unexpected error while authorizing".to_string(),
+                    500,
+                )));
+            }
+        };
+        Ok(TokenUserDto {
+            access_token: generated_token,
+            token_type: "Bearer".to_string(),
+            expires_in: expiration_time.timestamp(),
+            scope: None,
+        })
+    }
+
+    fn issue_challenge_token(
+        &self,
+        user_id: u64,
+        email: String,
+        token_settings: &TokenSettings,
+    ) -> Result<TwoFactorChallengeDto, APIError> {
+        let expiration_time =
+            chrono::offset::Utc::now() + Duration::seconds(TWO_FACTOR_CHALLENGE_EXPIRATION_SECONDS);
+
+        let claims_dto = TwoFactorChallengeClaimsDto {
+            id: user_id,
+            email,
+            exp: expiration_time.timestamp(),
+            aud: TWO_FACTOR_CHALLENGE_AUDIENCE.to_string(),
+        };
+
+        match encode(
+            &Header::default(),
+            &claims_dto,
+            &EncodingKey::from_secret(token_settings.secret.as_ref()),
+        ) {
+            Ok(token) => Ok(TwoFactorChallengeDto {
+                challenge_token: token,
+                expires_in: expiration_time.timestamp(),
+            }),
             Err(error) => {
                 error!("{}", error);
                 Err(APIError::SimpleAPIError(SimpleAPIError::new(
-                    "Invalid credentials".to_string(),
-                    403,
+                    "Unexpected error while authorizing".to_string(),
+                    500,
                 )))
             }
         }
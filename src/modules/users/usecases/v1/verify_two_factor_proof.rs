@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    users::{
+        domain::entities::{
+            recovery_code::hash_recovery_code,
+            totp::{base32_decode, verify_totp_code},
+            two_factor::{TwoFactor, TwoFactorProof},
+        },
+        infra::repositories::{
+            two_factor_repository::TwoFactorRepository,
+            two_factor_repository_mysql::TwoFactorRepositoryMySQL,
+        },
+    },
+};
+
+pub struct VerifyTwoFactorProofUseCaseV1<T>
+where
+    T: TwoFactorRepository,
+{
+    two_factor_repository: Arc<T>,
+}
+
+impl VerifyTwoFactorProofUseCaseV1<TwoFactorRepositoryMySQL> {
+    pub fn new(two_factor_repository: TwoFactorRepositoryMySQL) -> Self {
+        Self {
+            two_factor_repository: Arc::new(two_factor_repository),
+        }
+    }
+
+    /// Codes and recovery codes are spent when they are accepted, so neither
+    /// can be replayed.
+    pub async fn verify(
+        &self,
+        two_factor: &TwoFactor,
+        proof: TwoFactorProof,
+    ) -> Result<(), APIError> {
+        let verified = match proof {
+            TwoFactorProof::Code(code) => {
+                let secret = match base32_decode(&two_factor.secret) {
+                    Some(secret) => secret,
+                    None => {
+                        return Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                            "Failed to parse two-factor secret from DB".to_string(),
+                            500,
+                        )));
+                    }
+                };
+                match verify_totp_code(&secret, &code, chrono::offset::Utc::now()) {
+                    Some(step) => {
+                        self.two_factor_repository
+                            .use_step(two_factor.user_id, step)
+                            .await
+                    }
+                    None => Ok(false),
+                }
+            }
+            TwoFactorProof::RecoveryCode(recovery_code) => {
+                self.two_factor_repository
+                    .use_recovery_code(two_factor.user_id, &hash_recovery_code(&recovery_code))
+                    .await
+            }
+        };
+        match verified {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Invalid two-factor code".to_string(),
+                401,
+            ))),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                format!("Failed to verify two-factor code: {}", error),
+                500,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod create_webhook_dto;
+pub mod created_webhook_dto;
+pub mod find_all_webhook_deliveries_dto;
+pub mod find_all_webhooks_dto;
+pub mod webhook_delivery_dto;
+pub mod webhook_dto;
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct CreateWebhookDto {
+    /// Absolute `http` or `https` URL the events are posted to.
+    pub url: Option<String>,
+    /// Any of `book.created`, `book.updated`, `book.deleted`,
+    /// `loan.overdue` and `import.finished`.
+    pub events: Option<Vec<String>>,
+    pub user_id: Option<u64>,
+    pub library_id: Option<u64>,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::webhook_delivery_dto::WebhookDeliveryDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FindAllWebhookDeliveriesDto {
+    pub deliveries: Vec<WebhookDeliveryDto>,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::webhook_dto::WebhookDto;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct FindAllWebhooksDto {
+    pub webhooks: Vec<WebhookDto>,
+}
@@ -0,0 +1,18 @@
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct WebhookDeliveryDto {
+    pub id: u64,
+    pub webhook_id: u64,
+    pub event: String,
+    pub payload: Value,
+    pub status: String,
+    pub attempts: u32,
+    pub next_attempt_at: Option<String>,
+    pub last_response_status: Option<u16>,
+    pub last_error: Option<String>,
+    pub created_at: Option<String>,
+    pub delivered_at: Option<String>,
+}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct WebhookDto {
+    pub id: u64,
+    pub library_id: u64,
+    pub url: String,
+    pub events: Vec<String>,
+    pub created_at: Option<String>,
+}
@@ -0,0 +1,4 @@
+pub mod webhook;
+pub mod webhook_delivery;
+pub mod webhook_event;
+pub mod webhook_target;
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+
+use super::webhook_event::WebhookEvent;
+
+pub const WEBHOOK_SECRET_LENGTH: usize = 32;
+
+#[derive(Debug, Clone, Default)]
+pub struct Webhook {
+    pub id: Option<u64>,
+    pub user_id: u64,
+    pub library_id: u64,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+    pub created_at: Option<DateTime<Utc>>,
+}
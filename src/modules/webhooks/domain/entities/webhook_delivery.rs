@@ -0,0 +1,118 @@
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use serde_json::{json, Value};
+use sha2::Sha256;
+
+use super::webhook_event::WebhookEvent;
+
+pub const MAX_WEBHOOK_BACKOFF_SECONDS: u64 = 6 * 60 * 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookDeliveryStatus::Pending => "pending",
+            WebhookDeliveryStatus::Delivered => "delivered",
+            WebhookDeliveryStatus::Failed => "failed",
+        }
+    }
+}
+
+impl TryFrom<&str> for WebhookDeliveryStatus {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "pending" => Ok(WebhookDeliveryStatus::Pending),
+            "delivered" => Ok(WebhookDeliveryStatus::Delivered),
+            "failed" => Ok(WebhookDeliveryStatus::Failed),
+            _ => Err(format!("Unknown webhook delivery status {}", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: Option<u64>,
+    pub webhook_id: u64,
+    pub event: WebhookEvent,
+    pub payload: Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: u32,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_response_status: Option<u16>,
+    pub last_error: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookDelivery {
+    pub fn record_attempt(
+        &mut self,
+        now: DateTime<Utc>,
+        response_status: Option<u16>,
+        error: Option<String>,
+        max_attempts: u32,
+        backoff_base_seconds: u64,
+    ) {
+        self.attempts += 1;
+        self.last_response_status = response_status;
+        if response_status.is_some_and(|status| (200..300).contains(&status)) {
+            self.status = WebhookDeliveryStatus::Delivered;
+            self.last_error = None;
+            self.delivered_at = Some(now);
+            return;
+        }
+
+        self.last_error = match (error, response_status) {
+            (Some(error), _) => Some(error),
+            (None, Some(status)) => Some(format!("Endpoint answered with status {}", status)),
+            (None, None) => None,
+        };
+        if self.attempts >= max_attempts {
+            self.status = WebhookDeliveryStatus::Failed;
+        } else {
+            self.next_attempt_at = Some(now + webhook_backoff(self.attempts, backoff_base_seconds));
+        }
+    }
+}
+
+pub fn webhook_payload(
+    event: WebhookEvent,
+    library_id: u64,
+    occurred_at: DateTime<Utc>,
+    data: Value,
+) -> Value {
+    json!({
+        "event": event.as_str(),
+        "library_id": library_id,
+        "occurred_at": occurred_at.to_rfc3339(),
+        "data": data,
+    })
+}
+
+pub fn webhook_backoff(attempts: u32, backoff_base_seconds: u64) -> Duration {
+    let exponent = attempts.saturating_sub(1).min(32);
+    let seconds = backoff_base_seconds
+        .saturating_mul(1u64 << exponent)
+        .min(MAX_WEBHOOK_BACKOFF_SECONDS);
+    Duration::seconds(seconds as i64)
+}
+
+/// Signature sent along a delivery: the HMAC-SHA256 of `{timestamp}.{body}`
+/// keyed with the webhook secret, hex encoded. Including the timestamp lets
+/// receivers reject replayed deliveries.
+pub fn webhook_signature(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
@@ -0,0 +1,59 @@
+use crate::modules::books::domain::entities::audit_entry::AuditAction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookEvent {
+    BookCreated,
+    BookUpdated,
+    BookDeleted,
+    LoanOverdue,
+    ImportFinished,
+    Test,
+}
+
+pub const SUBSCRIBABLE_WEBHOOK_EVENTS: [WebhookEvent; 5] = [
+    WebhookEvent::BookCreated,
+    WebhookEvent::BookUpdated,
+    WebhookEvent::BookDeleted,
+    WebhookEvent::LoanOverdue,
+    WebhookEvent::ImportFinished,
+];
+
+impl WebhookEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::BookCreated => "book.created",
+            WebhookEvent::BookUpdated => "book.updated",
+            WebhookEvent::BookDeleted => "book.deleted",
+            WebhookEvent::LoanOverdue => "loan.overdue",
+            WebhookEvent::ImportFinished => "import.finished",
+            WebhookEvent::Test => "webhook.test",
+        }
+    }
+
+    /// Event published for an audited change of a book, restores and purges
+    /// of trashed books aren't published.
+    pub fn from_book_audit_action(action: AuditAction) -> Option<Self> {
+        match action {
+            AuditAction::Create => Some(WebhookEvent::BookCreated),
+            AuditAction::Update => Some(WebhookEvent::BookUpdated),
+            AuditAction::Delete => Some(WebhookEvent::BookDeleted),
+            AuditAction::Restore | AuditAction::Purge => None,
+        }
+    }
+}
+
+impl TryFrom<&str> for WebhookEvent {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "book.created" => Ok(WebhookEvent::BookCreated),
+            "book.updated" => Ok(WebhookEvent::BookUpdated),
+            "book.deleted" => Ok(WebhookEvent::BookDeleted),
+            "loan.overdue" => Ok(WebhookEvent::LoanOverdue),
+            "import.finished" => Ok(WebhookEvent::ImportFinished),
+            "webhook.test" => Ok(WebhookEvent::Test),
+            _ => Err(format!("Unknown webhook event {}", value)),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+#[derive(Debug, Clone, Default)]
+pub struct WebhookTargetPolicy {
+    allowed_hosts: Vec<String>,
+}
+
+impl WebhookTargetPolicy {
+    pub fn new(allowed_hosts: Vec<String>) -> Self {
+        WebhookTargetPolicy {
+            allowed_hosts: allowed_hosts
+                .iter()
+                .map(|host| host.trim().to_ascii_lowercase())
+                .collect(),
+        }
+    }
+
+    pub fn allows(&self, host: &str, ip: IpAddr) -> bool {
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        self.allowed_hosts
+            .iter()
+            .any(|allowed_host| allowed_host.eq_ignore_ascii_case(host))
+            || is_public_ip(ip)
+    }
+}
+
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ipv4(ip),
+            None => is_public_ipv6(ip),
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // "This network" 0.0.0.0/8
+        || first == 0
+        // Carrier-grade NAT 100.64.0.0/10
+        || (first == 100 && (second & 0b1100_0000) == 64)
+        // IETF protocol assignments 192.0.0.0/24
+        || (first == 192 && second == 0 && ip.octets()[2] == 0)
+        // Benchmarking 198.18.0.0/15
+        || (first == 198 && (second & 0xfe) == 18)
+        // Reserved 240.0.0.0/4
+        || first >= 240)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first_segment = ip.segments()[0];
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // Unique local fc00::/7
+        || (first_segment & 0xfe00) == 0xfc00
+        // Link-local fe80::/10
+        || (first_segment & 0xffc0) == 0xfe80
+        // Documentation 2001:db8::/32
+        || (first_segment == 0x2001 && ip.segments()[1] == 0x0db8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(address: &str) -> IpAddr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn private_and_reserved_addresses_are_not_public() {
+        for address in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.10",
+            "169.254.169.254",
+            "0.0.0.0",
+            "100.64.0.1",
+            "255.255.255.255",
+            "::1",
+            "::",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "::ffff:169.254.169.254",
+        ] {
+            assert!(!is_public_ip(ip(address)), "{} is public", address);
+        }
+    }
+
+    #[test]
+    fn public_addresses_are_public() {
+        for address in ["93.184.215.14", "8.8.8.8", "172.32.0.1", "2606:4700::1111"] {
+            assert!(is_public_ip(ip(address)), "{} isn't public", address);
+        }
+    }
+
+    #[test]
+    fn allowed_hosts_may_resolve_to_private_addresses() {
+        let policy = WebhookTargetPolicy::new(vec!["Receiver.Internal".to_string()]);
+
+        assert!(policy.allows("receiver.internal", ip("10.0.0.5")));
+        assert!(!policy.allows("other.internal", ip("10.0.0.5")));
+        assert!(policy.allows("other.internal", ip("8.8.8.8")));
+    }
+
+    #[test]
+    fn bracketed_ipv6_hosts_match_the_allowed_hosts() {
+        let policy = WebhookTargetPolicy::new(vec!["::1".to_string()]);
+
+        assert!(policy.allows("[::1]", ip("::1")));
+        assert!(!WebhookTargetPolicy::default().allows("[::1]", ip("::1")));
+    }
+}
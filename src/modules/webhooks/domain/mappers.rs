@@ -0,0 +1,3 @@
+pub mod create_webhook_dto_mapper;
+pub mod webhook_delivery_dto_mapper;
+pub mod webhook_dto_mapper;
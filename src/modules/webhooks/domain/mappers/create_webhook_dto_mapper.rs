@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use rand::{distributions::Alphanumeric, Rng};
+use reqwest::Url;
+
+use crate::modules::{
+    shared::errors::detailed_api_error::DetailedAPIError,
+    webhooks::domain::{
+        dtos::create_webhook_dto::CreateWebhookDto,
+        entities::{
+            webhook::{Webhook, WEBHOOK_SECRET_LENGTH},
+            webhook_event::{WebhookEvent, SUBSCRIBABLE_WEBHOOK_EVENTS},
+        },
+    },
+};
+
+impl TryFrom<CreateWebhookDto> for Webhook {
+    type Error = DetailedAPIError;
+
+    fn try_from(dto: CreateWebhookDto) -> Result<Self, Self::Error> {
+        let mut webhook = Webhook::default();
+        let mut validations: HashMap<String, String> = HashMap::default();
+
+        match dto.url.as_deref().map(|url| Url::parse(url.trim())) {
+            Some(Ok(url)) if url.scheme() == "http" || url.scheme() == "https" => {
+                webhook.url = url.to_string();
+            }
+            Some(_) => {
+                validations.insert(
+                    "url".to_string(),
+                    "URL must be an absolute http or https URL".to_string(),
+                );
+            }
+            None => {
+                validations.insert("url".to_string(), "URL must be informed".to_string());
+            }
+        }
+
+        match dto.events {
+            Some(events) if !events.is_empty() => {
+                for event in events.iter() {
+                    match WebhookEvent::try_from(event.as_str()) {
+                        Ok(event) if SUBSCRIBABLE_WEBHOOK_EVENTS.contains(&event) => {
+                            if !webhook.events.contains(&event) {
+                                webhook.events.push(event);
+                            }
+                        }
+                        _ => {
+                            validations.insert(
+                                "events".to_string(),
+                                format!(
+                                    "Events must be any of {}",
+                                    SUBSCRIBABLE_WEBHOOK_EVENTS
+                                        .iter()
+                                        .map(|event| event.as_str())
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+            _ => {
+                validations.insert(
+                    "events".to_string(),
+                    "At least one event must be informed".to_string(),
+                );
+            }
+        }
+
+        match dto.user_id {
+            Some(user_id) => webhook.user_id = user_id,
+            None => {
+                validations.insert(
+                    "user_id".to_string(),
+                    "Webhook must be related to an user".to_string(),
+                );
+            }
+        }
+
+        webhook.secret = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(WEBHOOK_SECRET_LENGTH)
+            .map(char::from)
+            .collect();
+
+        if !validations.is_empty() {
+            return Err(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(validations),
+            });
+        }
+        Ok(webhook)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::modules::webhooks::domain::{
+    dtos::webhook_delivery_dto::WebhookDeliveryDto, entities::webhook_delivery::WebhookDelivery,
+};
+
+impl From<WebhookDelivery> for WebhookDeliveryDto {
+    fn from(delivery: WebhookDelivery) -> Self {
+        WebhookDeliveryDto {
+            id: delivery.id.unwrap_or_default(),
+            webhook_id: delivery.webhook_id,
+            event: delivery.event.as_str().to_string(),
+            payload: delivery.payload,
+            status: delivery.status.as_str().to_string(),
+            attempts: delivery.attempts,
+            next_attempt_at: delivery.next_attempt_at.map(|date| date.to_rfc3339()),
+            last_response_status: delivery.last_response_status,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at.map(|date| date.to_rfc3339()),
+            delivered_at: delivery.delivered_at.map(|date| date.to_rfc3339()),
+        }
+    }
+}
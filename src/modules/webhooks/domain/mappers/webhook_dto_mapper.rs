@@ -0,0 +1,37 @@
+use crate::modules::webhooks::domain::{
+    dtos::{created_webhook_dto::CreatedWebhookDto, webhook_dto::WebhookDto},
+    entities::webhook::Webhook,
+};
+
+impl From<Webhook> for WebhookDto {
+    fn from(webhook: Webhook) -> Self {
+        WebhookDto {
+            id: webhook.id.unwrap_or_default(),
+            library_id: webhook.library_id,
+            url: webhook.url,
+            events: webhook
+                .events
+                .iter()
+                .map(|event| event.as_str().to_string())
+                .collect(),
+            created_at: webhook.created_at.map(|date| date.to_rfc3339()),
+        }
+    }
+}
+
+impl From<Webhook> for CreatedWebhookDto {
+    fn from(webhook: Webhook) -> Self {
+        CreatedWebhookDto {
+            id: webhook.id.unwrap_or_default(),
+            library_id: webhook.library_id,
+            url: webhook.url,
+            events: webhook
+                .events
+                .iter()
+                .map(|event| event.as_str().to_string())
+                .collect(),
+            secret: webhook.secret,
+            created_at: webhook.created_at.map(|date| date.to_rfc3339()),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod clients;
+pub mod controllers;
+pub mod jobs;
+pub mod repositories;
@@ -0,0 +1,3 @@
+pub mod webhook_client;
+pub mod webhook_client_reqwest;
+pub mod webhook_target_resolver;
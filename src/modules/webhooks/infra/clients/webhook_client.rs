@@ -0,0 +1,15 @@
+use crate::modules::webhooks::domain::entities::{
+    webhook::Webhook, webhook_delivery::WebhookDelivery,
+};
+use std::future::Future;
+
+pub trait WebhookClient {
+    /// Posts the payload of the delivery to the webhook, signed with its
+    /// secret. Resolves to the status the endpoint answered with, whatever
+    /// it is, or to why the endpoint couldn't be reached.
+    fn send(
+        &self,
+        webhook: &Webhook,
+        delivery: &WebhookDelivery,
+    ) -> impl Future<Output = Result<u16, String>> + Send;
+}
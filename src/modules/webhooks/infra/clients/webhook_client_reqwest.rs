@@ -0,0 +1,89 @@
+use std::{net::IpAddr, sync::Arc, time::Duration};
+
+use chrono::Utc;
+use reqwest::{header, redirect, Client, Url};
+
+use crate::modules::webhooks::domain::entities::{
+    webhook::Webhook,
+    webhook_delivery::{webhook_signature, WebhookDelivery},
+    webhook_target::WebhookTargetPolicy,
+};
+
+use super::{webhook_client::WebhookClient, webhook_target_resolver::WebhookTargetResolver};
+
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Librarian-Event";
+pub const WEBHOOK_DELIVERY_HEADER: &str = "X-Librarian-Delivery";
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Librarian-Timestamp";
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Librarian-Signature";
+
+#[derive(Clone)]
+pub struct WebhookClientReqwest {
+    client: Client,
+    resolver: WebhookTargetResolver,
+}
+
+impl WebhookClientReqwest {
+    /// Redirects aren't followed and proxies aren't used, either would let
+    /// the request reach an address the policy didn't check.
+    pub fn new(timeout: Duration, policy: WebhookTargetPolicy) -> Result<Self, reqwest::Error> {
+        let resolver = WebhookTargetResolver::new(policy);
+        let client = Client::builder()
+            .timeout(timeout)
+            .user_agent(concat!("new-online-librarian/", env!("CARGO_PKG_VERSION")))
+            .redirect(redirect::Policy::none())
+            .no_proxy()
+            .dns_resolver(Arc::new(resolver.clone()))
+            .build()?;
+        Ok(WebhookClientReqwest { client, resolver })
+    }
+}
+
+impl WebhookClient for WebhookClientReqwest {
+    async fn send(&self, webhook: &Webhook, delivery: &WebhookDelivery) -> Result<u16, String> {
+        let body = match serde_json::to_vec(&delivery.payload) {
+            Ok(body) => body,
+            Err(error) => return Err(error.to_string()),
+        };
+        // The resolver isn't consulted for IP literals, they're checked here
+        let url = match Url::parse(&webhook.url) {
+            Ok(url) => url,
+            Err(error) => return Err(error.to_string()),
+        };
+        match (url.host_str(), url.port_or_known_default()) {
+            (Some(host), Some(port)) => {
+                if host
+                    .trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .parse::<IpAddr>()
+                    .is_ok()
+                {
+                    self.resolver.resolve_allowed(host, port).await?;
+                }
+            }
+            _ => return Err(format!("{} has no host to deliver to", webhook.url)),
+        }
+        let timestamp = Utc::now().timestamp();
+
+        let response = self
+            .client
+            .post(url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_EVENT_HEADER, delivery.event.as_str())
+            .header(
+                WEBHOOK_DELIVERY_HEADER,
+                delivery.id.unwrap_or_default().to_string(),
+            )
+            .header(WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+            .header(
+                WEBHOOK_SIGNATURE_HEADER,
+                webhook_signature(&webhook.secret, timestamp, &body),
+            )
+            .body(body)
+            .send()
+            .await;
+        match response {
+            Ok(response) => Ok(response.status().as_u16()),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+}
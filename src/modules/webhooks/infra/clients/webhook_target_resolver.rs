@@ -0,0 +1,60 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use tokio::net::lookup_host;
+
+use crate::modules::webhooks::domain::entities::webhook_target::WebhookTargetPolicy;
+
+/// Resolves the hosts of webhooks to the addresses the policy allows. Used by
+/// the client to connect as well, so a host can't pass the check when the
+/// webhook is created and resolve to a private address once it's delivered.
+#[derive(Clone)]
+pub struct WebhookTargetResolver {
+    policy: Arc<WebhookTargetPolicy>,
+}
+
+impl WebhookTargetResolver {
+    pub fn new(policy: WebhookTargetPolicy) -> Self {
+        WebhookTargetResolver {
+            policy: Arc::new(policy),
+        }
+    }
+
+    pub async fn resolve_allowed(&self, host: &str, port: u16) -> Result<Vec<SocketAddr>, String> {
+        let addresses: Vec<SocketAddr> = match host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+        {
+            Ok(ip) => vec![SocketAddr::new(ip, port)],
+            Err(_) => match lookup_host((host, port)).await {
+                Ok(addresses) => addresses.collect(),
+                Err(error) => return Err(format!("Failed to resolve {}: {}", host, error)),
+            },
+        };
+
+        let allowed_addresses: Vec<SocketAddr> = addresses
+            .into_iter()
+            .filter(|address| self.policy.allows(host, address.ip()))
+            .collect();
+        match allowed_addresses.is_empty() {
+            true => Err(format!("{} doesn't resolve to a public address", host)),
+            false => Ok(allowed_addresses),
+        }
+    }
+}
+
+impl Resolve for WebhookTargetResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let resolver = self.clone();
+        Box::pin(async move {
+            match resolver.resolve_allowed(name.as_str(), 0).await {
+                Ok(addresses) => Ok(Box::new(addresses.into_iter()) as Addrs),
+                Err(error) => Err(error.into()),
+            }
+        })
+    }
+}
@@ -0,0 +1,270 @@
+use crate::modules::{
+    libraries::infra::repositories::library_repository_mysql::LibraryRepositoryMySQL,
+    shared::errors::{
+        detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+    },
+    users::domain::dtos::authed_user::AuthedUser,
+    webhooks::{
+        domain::{
+            dtos::{
+                create_webhook_dto::CreateWebhookDto, created_webhook_dto::CreatedWebhookDto,
+                find_all_webhook_deliveries_dto::FindAllWebhookDeliveriesDto,
+                find_all_webhooks_dto::FindAllWebhooksDto,
+                webhook_delivery_dto::WebhookDeliveryDto,
+            },
+            entities::{webhook::Webhook, webhook_target::WebhookTargetPolicy},
+        },
+        infra::{
+            clients::webhook_target_resolver::WebhookTargetResolver,
+            repositories::webhook_repository_mysql::WebhookRepositoryMySQL,
+        },
+        usecases::v1::{
+            create_webhook_usecase::CreateWebhookUseCaseV1,
+            delete_webhook_usecase::DeleteWebhookUseCaseV1,
+            find_all_webhook_deliveries_usecase::FindAllWebhookDeliveriesUseCaseV1,
+            find_all_webhooks_from_user_usecase::FindAllWebhooksFromUserUseCaseV1,
+            send_test_webhook_event_usecase::SendTestWebhookEventUseCaseV1,
+        },
+    },
+};
+use actix_web::{delete, get, post, web, HttpResponse, Scope};
+use utoipa::OpenApi;
+
+pub struct WebhookControllerV1 {
+    create_webhook_usecase: CreateWebhookUseCaseV1<WebhookRepositoryMySQL, LibraryRepositoryMySQL>,
+    find_all_webhooks_from_user_usecase: FindAllWebhooksFromUserUseCaseV1<WebhookRepositoryMySQL>,
+    delete_webhook_usecase: DeleteWebhookUseCaseV1<WebhookRepositoryMySQL>,
+    find_all_webhook_deliveries_usecase: FindAllWebhookDeliveriesUseCaseV1<WebhookRepositoryMySQL>,
+    send_test_webhook_event_usecase: SendTestWebhookEventUseCaseV1<WebhookRepositoryMySQL>,
+}
+
+impl WebhookControllerV1 {
+    pub fn new(
+        webhook_repository: WebhookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        webhook_target_policy: WebhookTargetPolicy,
+    ) -> Self {
+        WebhookControllerV1 {
+            create_webhook_usecase: CreateWebhookUseCaseV1::new(
+                webhook_repository.clone(),
+                library_repository.clone(),
+                WebhookTargetResolver::new(webhook_target_policy),
+            ),
+            find_all_webhooks_from_user_usecase: FindAllWebhooksFromUserUseCaseV1::new(
+                webhook_repository.clone(),
+            ),
+            delete_webhook_usecase: DeleteWebhookUseCaseV1::new(webhook_repository.clone()),
+            find_all_webhook_deliveries_usecase: FindAllWebhookDeliveriesUseCaseV1::new(
+                webhook_repository.clone(),
+            ),
+            send_test_webhook_event_usecase: SendTestWebhookEventUseCaseV1::new(
+                webhook_repository.clone(),
+            ),
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "webhooks",
+    summary = "Subscribe an URL to the events of a library",
+    description = "The secret is only returned once, it signs the body of every delivery in the `X-Librarian-Signature` header.",
+    request_body = CreateWebhookDto,
+    responses(
+        (status = 201, description = "Webhook created", body = CreatedWebhookDto),
+        (status = 400, description = "Request contains invalid data", body = DetailedAPIError),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 403, description = "User can't access the resource", body = SimpleAPIError),
+    ),
+)]
+#[post("")]
+async fn create_webhook(
+    webhook_controller: web::Data<WebhookControllerV1>,
+    create_webhook_dto: web::Json<CreateWebhookDto>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    let mut create_webhook_dto = create_webhook_dto.0;
+    create_webhook_dto.user_id = authed_user.id;
+    let library_id = create_webhook_dto.library_id;
+    let webhook = match Webhook::try_from(create_webhook_dto) {
+        Ok(converted_webhook) => converted_webhook,
+        Err(e) => {
+            return HttpResponse::from(APIError::DetailedAPIError(e));
+        }
+    };
+
+    match webhook_controller
+        .create_webhook_usecase
+        .create_webhook(webhook, library_id)
+        .await
+    {
+        Ok(webhook) => HttpResponse::Created().json(web::Json(webhook)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "webhooks",
+    summary = "List the webhooks of the user",
+    responses(
+        (status = 200, description = "Webhooks", body = FindAllWebhooksDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+    ),
+)]
+#[get("")]
+async fn get_all_webhooks_from_user(
+    webhook_controller: web::Data<WebhookControllerV1>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match webhook_controller
+        .find_all_webhooks_from_user_usecase
+        .find_all_webhooks_from_user(authed_user.id.unwrap())
+        .await
+    {
+        Ok(webhooks) => HttpResponse::Ok().json(web::Json(webhooks)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "webhooks",
+    summary = "Delete a webhook and its deliveries",
+    params(
+        ("webhook_id" = u64, Path, description = "Id of the webhook"),
+    ),
+    responses(
+        (status = 200, description = "Webhook deleted"),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 404, description = "Webhook not found", body = SimpleAPIError),
+    ),
+)]
+#[delete("/{webhook_id}")]
+async fn delete_webhook(
+    webhook_controller: web::Data<WebhookControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    let path_webhook_id = path_variables.into_inner();
+
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match webhook_controller
+        .delete_webhook_usecase
+        .delete_webhook(authed_user.id.unwrap(), path_webhook_id)
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().finish(),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "webhooks",
+    summary = "List the latest deliveries of a webhook",
+    params(
+        ("webhook_id" = u64, Path, description = "Id of the webhook"),
+    ),
+    responses(
+        (status = 200, description = "Deliveries, newest first", body = FindAllWebhookDeliveriesDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 404, description = "Webhook not found", body = SimpleAPIError),
+    ),
+)]
+#[get("/{webhook_id}/deliveries")]
+async fn get_all_webhook_deliveries(
+    webhook_controller: web::Data<WebhookControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    let path_webhook_id = path_variables.into_inner();
+
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match webhook_controller
+        .find_all_webhook_deliveries_usecase
+        .find_all_webhook_deliveries(authed_user.id.unwrap(), path_webhook_id)
+        .await
+    {
+        Ok(deliveries) => HttpResponse::Ok().json(web::Json(deliveries)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[utoipa::path(
+    tag = "webhooks",
+    summary = "Queue a test event for a webhook",
+    params(
+        ("webhook_id" = u64, Path, description = "Id of the webhook"),
+    ),
+    responses(
+        (status = 202, description = "Test event queued", body = WebhookDeliveryDto),
+        (status = 401, description = "Authentication is required", body = SimpleAPIError),
+        (status = 404, description = "Webhook not found", body = SimpleAPIError),
+    ),
+)]
+#[post("/{webhook_id}/test")]
+async fn send_test_webhook_event(
+    webhook_controller: web::Data<WebhookControllerV1>,
+    path_variables: web::Path<u64>,
+    authed_user: AuthedUser,
+) -> HttpResponse {
+    let path_webhook_id = path_variables.into_inner();
+
+    if authed_user.id.is_none() {
+        return HttpResponse::from(APIError::SimpleAPIError(SimpleAPIError::new(
+            "This action requires authentication".to_string(),
+            401,
+        )));
+    }
+
+    match webhook_controller
+        .send_test_webhook_event_usecase
+        .send_test_webhook_event(authed_user.id.unwrap(), path_webhook_id)
+        .await
+    {
+        Ok(delivery) => HttpResponse::Accepted().json(web::Json(delivery)),
+        Err(error) => HttpResponse::from(error),
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    create_webhook,
+    get_all_webhooks_from_user,
+    delete_webhook,
+    get_all_webhook_deliveries,
+    send_test_webhook_event
+))]
+pub struct WebhookApiV1;
+
+pub fn get_webhook_scope() -> Scope {
+    web::scope("/v1/webhooks")
+        .service(create_webhook)
+        .service(get_all_webhooks_from_user)
+        .service(delete_webhook)
+        .service(get_all_webhook_deliveries)
+        .service(send_test_webhook_event)
+}
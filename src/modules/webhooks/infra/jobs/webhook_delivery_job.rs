@@ -0,0 +1,96 @@
+use std::{sync::Arc, time::Duration};
+
+use sqlx::MySqlPool;
+use tokio::task::JoinHandle;
+
+use crate::{
+    configuration::WebhookSettings,
+    modules::{
+        books::infra::repositories::audit_repository_mysql::AuditRepositoryMySQL,
+        loans::infra::repositories::loan_repository_mysql::LoanRepositoryMySQL,
+        shared::errors::APIError,
+        webhooks::{
+            domain::entities::webhook_target::WebhookTargetPolicy,
+            infra::{
+                clients::webhook_client_reqwest::WebhookClientReqwest,
+                repositories::webhook_repository_mysql::WebhookRepositoryMySQL,
+            },
+            usecases::v1::{
+                deliver_webhooks_usecase::DeliverWebhooksUseCaseV1,
+                publish_audited_book_events_usecase::PublishAuditedBookEventsUseCaseV1,
+                publish_overdue_loans_usecase::PublishOverdueLoansUseCaseV1,
+            },
+        },
+    },
+};
+
+pub fn spawn_webhook_delivery_job(
+    db_pool: Arc<MySqlPool>,
+    webhook_settings: WebhookSettings,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let webhook_repository = WebhookRepositoryMySQL::new(db_pool.clone());
+        let publish_audited_book_events_usecase = PublishAuditedBookEventsUseCaseV1::new(
+            AuditRepositoryMySQL::new(db_pool.clone()),
+            webhook_repository.clone(),
+        );
+        let publish_overdue_loans_usecase = PublishOverdueLoansUseCaseV1::new(
+            LoanRepositoryMySQL::new(db_pool),
+            webhook_repository.clone(),
+        );
+        let webhook_client = match WebhookClientReqwest::new(
+            Duration::from_secs(webhook_settings.timeout_seconds),
+            WebhookTargetPolicy::new(webhook_settings.allowed_hosts.clone()),
+        ) {
+            Ok(webhook_client) => webhook_client,
+            Err(error) => {
+                tracing::error!("Failed to build the webhook client: {}", error);
+                return;
+            }
+        };
+        let deliver_webhooks_usecase =
+            DeliverWebhooksUseCaseV1::new(webhook_repository, webhook_client);
+        let mut interval = tokio::time::interval(Duration::from_secs(
+            webhook_settings.delivery_interval_seconds,
+        ));
+
+        loop {
+            interval.tick().await;
+            if let Err(error) = publish_audited_book_events_usecase.publish().await {
+                log_job_error("publish the book events", error);
+            }
+            if let Err(error) = publish_overdue_loans_usecase
+                .publish(webhook_settings.loan_overdue_days)
+                .await
+            {
+                log_job_error("publish the overdue loans", error);
+            }
+            match deliver_webhooks_usecase
+                .deliver_due(
+                    webhook_settings.max_attempts,
+                    webhook_settings.backoff_base_seconds,
+                    webhook_settings.timeout_seconds,
+                )
+                .await
+            {
+                Ok(0) => {}
+                Ok(n_of_attempts) => {
+                    tracing::info!("{} webhook deliveries attempted", n_of_attempts)
+                }
+                Err(error) => log_job_error("deliver the webhooks", error),
+            }
+        }
+    })
+}
+
+fn log_job_error(action: &str, error: APIError) {
+    match error {
+        APIError::SimpleAPIError(error) => tracing::error!("Failed to {}: {}", action, error.msg),
+        APIError::DetailedAPIError(error) => {
+            tracing::error!("Failed to {}: {}", action, error.msg)
+        }
+        APIError::PreconditionFailedAPIError(error) => {
+            tracing::error!("Failed to {}: {}", action, error.msg)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod webhook_repository;
+pub mod webhook_repository_mysql;
@@ -0,0 +1,50 @@
+use crate::modules::webhooks::domain::entities::{
+    webhook::Webhook, webhook_delivery::WebhookDelivery, webhook_event::WebhookEvent,
+};
+use serde_json::Value;
+use sqlx::Error;
+use std::future::Future;
+
+pub trait WebhookRepository {
+    fn save(
+        &self,
+        webhook: &Webhook,
+    ) -> impl Future<Output = Result<Option<Webhook>, Error>> + Send;
+    fn find_by_id(&self, id: u64) -> impl Future<Output = Result<Option<Webhook>, Error>> + Send;
+    fn find_all_by_user_id(
+        &self,
+        user_id: u64,
+    ) -> impl Future<Output = Result<Vec<Webhook>, Error>> + Send;
+    fn delete_by_id(&self, id: u64) -> impl Future<Output = Result<(), Error>> + Send;
+    /// Queues a delivery of the event for every webhook of the library
+    /// subscribed to it, returning how many were queued. Webhooks of users
+    /// who are no longer members of the library are left out.
+    fn enqueue_for_library(
+        &self,
+        library_id: u64,
+        event: WebhookEvent,
+        payload: &Value,
+    ) -> impl Future<Output = Result<u64, Error>> + Send;
+    fn enqueue_for_webhook(
+        &self,
+        webhook_id: u64,
+        event: WebhookEvent,
+        payload: &Value,
+    ) -> impl Future<Output = Result<Option<WebhookDelivery>, Error>> + Send;
+    /// Claims up to `limit` due deliveries for `lease_seconds`, deliveries
+    /// claimed by another worker are skipped until their claim expires.
+    fn claim_due_deliveries(
+        &self,
+        limit: u64,
+        lease_seconds: u64,
+    ) -> impl Future<Output = Result<Vec<WebhookDelivery>, Error>> + Send;
+    fn find_all_deliveries_by_webhook_id(
+        &self,
+        webhook_id: u64,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<WebhookDelivery>, Error>> + Send;
+    fn update_delivery(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+}
@@ -0,0 +1,288 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{mysql::MySqlRow, MySqlPool, Row};
+use std::sync::Arc;
+
+use crate::modules::webhooks::domain::entities::{
+    webhook::Webhook,
+    webhook_delivery::{WebhookDelivery, WebhookDeliveryStatus},
+    webhook_event::WebhookEvent,
+};
+
+use super::webhook_repository::WebhookRepository;
+
+#[derive(Clone)]
+pub struct WebhookRepositoryMySQL {
+    connection: Arc<MySqlPool>,
+}
+
+impl WebhookRepositoryMySQL {
+    pub fn new(db_pool: Arc<MySqlPool>) -> Self {
+        WebhookRepositoryMySQL {
+            connection: db_pool.clone(),
+        }
+    }
+}
+
+fn webhook_from_row(row: &MySqlRow) -> Webhook {
+    let events: Value = row.get("events");
+    Webhook {
+        id: Some(row.get("id")),
+        user_id: row.get("user_id"),
+        library_id: row.get("library_id"),
+        url: row.get("url"),
+        secret: row.get("secret"),
+        events: events
+            .as_array()
+            .map(|events| {
+                events
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .filter_map(|event| WebhookEvent::try_from(event).ok())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        created_at: Some(row.get::<DateTime<Utc>, &str>("created_at")),
+    }
+}
+
+fn webhook_delivery_from_row(row: &MySqlRow) -> WebhookDelivery {
+    let event: String = row.get("event");
+    let status: String = row.get("status");
+    WebhookDelivery {
+        id: Some(row.get("id")),
+        webhook_id: row.get("webhook_id"),
+        event: WebhookEvent::try_from(event.as_str()).unwrap_or(WebhookEvent::Test),
+        payload: row.get("payload"),
+        status: WebhookDeliveryStatus::try_from(status.as_str())
+            .unwrap_or(WebhookDeliveryStatus::Failed),
+        attempts: row.get("attempts"),
+        next_attempt_at: Some(row.get::<DateTime<Utc>, &str>("next_attempt_at")),
+        last_response_status: row.get("last_response_status"),
+        last_error: row.get("last_error"),
+        created_at: Some(row.get::<DateTime<Utc>, &str>("created_at")),
+        delivered_at: row.get::<Option<DateTime<Utc>>, &str>("delivered_at"),
+    }
+}
+
+fn in_clause_placeholders(n_of_items: usize) -> String {
+    vec!["?"; n_of_items].join(", ")
+}
+
+fn truncate_error(error: &str) -> String {
+    error.chars().take(500).collect()
+}
+
+impl WebhookRepository for WebhookRepositoryMySQL {
+    async fn save(&self, webhook: &Webhook) -> Result<Option<Webhook>, sqlx::Error> {
+        let events: Vec<&str> = webhook.events.iter().map(|event| event.as_str()).collect();
+        let insert_result = sqlx::query(
+            r#"
+            INSERT INTO webhooks (id, user_id, library_id, url, secret, events)
+            VALUES (DEFAULT, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(webhook.user_id)
+        .bind(webhook.library_id)
+        .bind(&webhook.url)
+        .bind(&webhook.secret)
+        .bind(Value::from(events))
+        .execute(self.connection.as_ref())
+        .await;
+        match insert_result {
+            Ok(result) => {
+                let new_webhook_id = result.last_insert_id();
+                tracing::info!("Generated webhook ID: {}", new_webhook_id);
+                self.find_by_id(new_webhook_id).await
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn find_by_id(&self, id: u64) -> Result<Option<Webhook>, sqlx::Error> {
+        let query_result = sqlx::query("SELECT * FROM webhooks w WHERE w.id = ?")
+            .bind(id)
+            .fetch_optional(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(row) => Ok(row.as_ref().map(webhook_from_row)),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn find_all_by_user_id(&self, user_id: u64) -> Result<Vec<Webhook>, sqlx::Error> {
+        let query_result =
+            sqlx::query("SELECT * FROM webhooks w WHERE w.user_id = ? ORDER BY w.id ASC")
+                .bind(user_id)
+                .fetch_all(self.connection.as_ref())
+                .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(webhook_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn delete_by_id(&self, id: u64) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query("DELETE FROM webhooks WHERE id = ?")
+            .bind(id)
+            .execute(self.connection.as_ref())
+            .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn enqueue_for_library(
+        &self,
+        library_id: u64,
+        event: WebhookEvent,
+        payload: &Value,
+    ) -> Result<u64, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            INSERT INTO webhook_deliveries (webhook_id, event, payload)
+            SELECT w.id, ?, ?
+            FROM webhooks w
+                INNER JOIN library_members m
+                    ON m.library_id = w.library_id AND m.user_id = w.user_id
+            WHERE w.library_id = ? AND JSON_CONTAINS(w.events, JSON_QUOTE(?))
+            "#,
+        )
+        .bind(event.as_str())
+        .bind(payload)
+        .bind(library_id)
+        .bind(event.as_str())
+        .execute(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(result) => Ok(result.rows_affected()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn enqueue_for_webhook(
+        &self,
+        webhook_id: u64,
+        event: WebhookEvent,
+        payload: &Value,
+    ) -> Result<Option<WebhookDelivery>, sqlx::Error> {
+        let insert_result = sqlx::query(
+            "INSERT INTO webhook_deliveries (webhook_id, event, payload) VALUES (?, ?, ?)",
+        )
+        .bind(webhook_id)
+        .bind(event.as_str())
+        .bind(payload)
+        .execute(self.connection.as_ref())
+        .await;
+        match insert_result {
+            Ok(result) => {
+                let query_result = sqlx::query("SELECT * FROM webhook_deliveries d WHERE d.id = ?")
+                    .bind(result.last_insert_id())
+                    .fetch_optional(self.connection.as_ref())
+                    .await;
+                match query_result {
+                    Ok(row) => Ok(row.as_ref().map(webhook_delivery_from_row)),
+                    Err(error) => Err(error),
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        limit: u64,
+        lease_seconds: u64,
+    ) -> Result<Vec<WebhookDelivery>, sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+
+        // Rows locked by a worker claiming at the same time are skipped
+        // instead of waited on, so each delivery goes to a single worker
+        let rows = sqlx::query(
+            r#"
+            SELECT *
+            FROM webhook_deliveries d
+            WHERE d.status = ? AND d.next_attempt_at <= CURRENT_TIMESTAMP
+                AND (d.claimed_until IS NULL OR d.claimed_until <= CURRENT_TIMESTAMP)
+            ORDER BY d.next_attempt_at ASC, d.id ASC
+            LIMIT ?
+            FOR UPDATE SKIP LOCKED
+            "#,
+        )
+        .bind(WebhookDeliveryStatus::Pending.as_str())
+        .bind(limit)
+        .fetch_all(&mut *transaction)
+        .await?;
+        let deliveries: Vec<WebhookDelivery> = rows.iter().map(webhook_delivery_from_row).collect();
+        if deliveries.is_empty() {
+            transaction.commit().await?;
+            return Ok(deliveries);
+        }
+
+        let query = format!(
+            r#"
+            UPDATE webhook_deliveries
+            SET claimed_until = DATE_ADD(CURRENT_TIMESTAMP, INTERVAL ? SECOND)
+            WHERE id IN ({})
+            "#,
+            in_clause_placeholders(deliveries.len())
+        );
+        let mut query_ps = sqlx::query(&query).bind(lease_seconds);
+        for delivery in deliveries.iter() {
+            query_ps = query_ps.bind(delivery.id);
+        }
+        query_ps.execute(&mut *transaction).await?;
+        transaction.commit().await?;
+
+        Ok(deliveries)
+    }
+
+    async fn find_all_deliveries_by_webhook_id(
+        &self,
+        webhook_id: u64,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            SELECT *
+            FROM webhook_deliveries d
+            WHERE d.webhook_id = ?
+            ORDER BY d.created_at DESC, d.id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(webhook_id)
+        .bind(limit)
+        .fetch_all(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(rows) => Ok(rows.iter().map(webhook_delivery_from_row).collect()),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn update_delivery(&self, delivery: &WebhookDelivery) -> Result<(), sqlx::Error> {
+        let query_result = sqlx::query(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = ?, attempts = ?, next_attempt_at = ?, last_response_status = ?,
+                last_error = ?, delivered_at = ?, claimed_until = NULL
+            WHERE id = ?
+            "#,
+        )
+        .bind(delivery.status.as_str())
+        .bind(delivery.attempts)
+        .bind(delivery.next_attempt_at)
+        .bind(delivery.last_response_status)
+        .bind(delivery.last_error.as_deref().map(truncate_error))
+        .bind(delivery.delivered_at)
+        .bind(delivery.id)
+        .execute(self.connection.as_ref())
+        .await;
+        match query_result {
+            Ok(_) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+pub mod authorize_webhook_owner_usecase;
+pub mod create_webhook_usecase;
+pub mod delete_webhook_usecase;
+pub mod deliver_webhooks_usecase;
+pub mod enqueue_webhook_event_usecase;
+pub mod find_all_webhook_deliveries_usecase;
+pub mod find_all_webhooks_from_user_usecase;
+pub mod publish_audited_book_events_usecase;
+pub mod publish_overdue_loans_usecase;
+pub mod send_test_webhook_event_usecase;
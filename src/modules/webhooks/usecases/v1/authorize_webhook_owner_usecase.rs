@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    webhooks::{
+        domain::entities::webhook::Webhook,
+        infra::repositories::{
+            webhook_repository::WebhookRepository, webhook_repository_mysql::WebhookRepositoryMySQL,
+        },
+    },
+};
+
+pub struct AuthorizeWebhookOwnerUseCaseV1<T>
+where
+    T: WebhookRepository,
+{
+    webhook_repository: Arc<T>,
+}
+
+impl AuthorizeWebhookOwnerUseCaseV1<WebhookRepositoryMySQL> {
+    pub fn new(webhook_repository: WebhookRepositoryMySQL) -> Self {
+        Self {
+            webhook_repository: Arc::new(webhook_repository),
+        }
+    }
+
+    pub async fn authorize(&self, user_id: u64, webhook_id: u64) -> Result<Webhook, APIError> {
+        match self.webhook_repository.find_by_id(webhook_id).await {
+            Ok(Some(webhook)) if webhook.user_id == user_id => Ok(webhook),
+            Ok(_) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Webhook not found".to_string(),
+                404,
+            ))),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
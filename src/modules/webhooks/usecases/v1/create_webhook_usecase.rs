@@ -0,0 +1,101 @@
+use std::{collections::HashMap, sync::Arc};
+
+use reqwest::Url;
+
+use crate::modules::{
+    libraries::{
+        domain::entities::library_role::LibraryRole,
+        infra::repositories::{
+            library_repository::LibraryRepository, library_repository_mysql::LibraryRepositoryMySQL,
+        },
+        usecases::v1::authorize_library_member_usecase::AuthorizeLibraryMemberUseCaseV1,
+    },
+    shared::errors::{
+        detailed_api_error::DetailedAPIError, simple_api_error::SimpleAPIError, APIError,
+    },
+    webhooks::{
+        domain::{dtos::created_webhook_dto::CreatedWebhookDto, entities::webhook::Webhook},
+        infra::{
+            clients::webhook_target_resolver::WebhookTargetResolver,
+            repositories::{
+                webhook_repository::WebhookRepository,
+                webhook_repository_mysql::WebhookRepositoryMySQL,
+            },
+        },
+    },
+};
+
+pub struct CreateWebhookUseCaseV1<T, U>
+where
+    T: WebhookRepository,
+    U: LibraryRepository,
+{
+    webhook_repository: Arc<T>,
+    authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1<U>,
+    webhook_target_resolver: WebhookTargetResolver,
+}
+
+impl CreateWebhookUseCaseV1<WebhookRepositoryMySQL, LibraryRepositoryMySQL> {
+    pub fn new(
+        webhook_repository: WebhookRepositoryMySQL,
+        library_repository: LibraryRepositoryMySQL,
+        webhook_target_resolver: WebhookTargetResolver,
+    ) -> Self {
+        Self {
+            webhook_repository: Arc::new(webhook_repository),
+            authorize_library_member_usecase: AuthorizeLibraryMemberUseCaseV1::new(
+                library_repository,
+            ),
+            webhook_target_resolver,
+        }
+    }
+
+    pub async fn create_webhook(
+        &self,
+        mut webhook: Webhook,
+        library_id: Option<u64>,
+    ) -> Result<CreatedWebhookDto, APIError> {
+        webhook.library_id = self
+            .authorize_library_member_usecase
+            .authorize(webhook.user_id, library_id, LibraryRole::Editor)
+            .await?;
+        self.check_target(&webhook.url).await?;
+
+        match self.webhook_repository.save(&webhook).await {
+            Ok(Some(webhook)) => Ok(CreatedWebhookDto::from(webhook)),
+            Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Failed to load created webhook info".to_string(),
+                500,
+            ))),
+            Err(e) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                e.to_string(),
+                500,
+            ))),
+        }
+    }
+
+    async fn check_target(&self, url: &str) -> Result<(), APIError> {
+        let resolved = match Url::parse(url) {
+            Ok(url) => match (url.host_str(), url.port_or_known_default()) {
+                (Some(host), Some(port)) => self
+                    .webhook_target_resolver
+                    .resolve_allowed(host, port)
+                    .await
+                    .is_ok(),
+                _ => false,
+            },
+            Err(_) => false,
+        };
+        match resolved {
+            true => Ok(()),
+            false => Err(APIError::DetailedAPIError(DetailedAPIError {
+                msg: "Request contains invalid data".to_string(),
+                code: 400,
+                field_validations: Some(HashMap::from([(
+                    "url".to_string(),
+                    "URL must resolve to a public address".to_string(),
+                )])),
+            })),
+        }
+    }
+}
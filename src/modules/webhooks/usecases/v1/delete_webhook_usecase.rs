@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    webhooks::infra::repositories::{
+        webhook_repository::WebhookRepository, webhook_repository_mysql::WebhookRepositoryMySQL,
+    },
+};
+
+use super::authorize_webhook_owner_usecase::AuthorizeWebhookOwnerUseCaseV1;
+
+pub struct DeleteWebhookUseCaseV1<T>
+where
+    T: WebhookRepository,
+{
+    webhook_repository: Arc<T>,
+    authorize_webhook_owner_usecase: AuthorizeWebhookOwnerUseCaseV1<T>,
+}
+
+impl DeleteWebhookUseCaseV1<WebhookRepositoryMySQL> {
+    pub fn new(webhook_repository: WebhookRepositoryMySQL) -> Self {
+        Self {
+            webhook_repository: Arc::new(webhook_repository.clone()),
+            authorize_webhook_owner_usecase: AuthorizeWebhookOwnerUseCaseV1::new(
+                webhook_repository,
+            ),
+        }
+    }
+
+    pub async fn delete_webhook(&self, user_id: u64, webhook_id: u64) -> Result<(), APIError> {
+        self.authorize_webhook_owner_usecase
+            .authorize(user_id, webhook_id)
+            .await?;
+
+        match self.webhook_repository.delete_by_id(webhook_id).await {
+            Ok(_) => Ok(()),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::Utc;
+use futures_util::future::join_all;
+
+use crate::modules::{
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    webhooks::{
+        domain::entities::{webhook::Webhook, webhook_delivery::WebhookDelivery},
+        infra::{
+            clients::{
+                webhook_client::WebhookClient, webhook_client_reqwest::WebhookClientReqwest,
+            },
+            repositories::{
+                webhook_repository::WebhookRepository,
+                webhook_repository_mysql::WebhookRepositoryMySQL,
+            },
+        },
+    },
+};
+
+const DELIVERY_BATCH_SIZE: u64 = 100;
+/// Added to the request timeout when claiming deliveries, a claim only
+/// expires after its attempts had time to finish and be recorded.
+const CLAIM_LEASE_MARGIN_SECONDS: u64 = 60;
+
+pub struct DeliverWebhooksUseCaseV1<T, U>
+where
+    T: WebhookRepository,
+    U: WebhookClient,
+{
+    webhook_repository: Arc<T>,
+    webhook_client: Arc<U>,
+}
+
+impl DeliverWebhooksUseCaseV1<WebhookRepositoryMySQL, WebhookClientReqwest> {
+    pub fn new(
+        webhook_repository: WebhookRepositoryMySQL,
+        webhook_client: WebhookClientReqwest,
+    ) -> Self {
+        Self {
+            webhook_repository: Arc::new(webhook_repository),
+            webhook_client: Arc::new(webhook_client),
+        }
+    }
+
+    pub async fn deliver_due(
+        &self,
+        max_attempts: u32,
+        backoff_base_seconds: u64,
+        timeout_seconds: u64,
+    ) -> Result<u64, APIError> {
+        let deliveries = match self
+            .webhook_repository
+            .claim_due_deliveries(
+                DELIVERY_BATCH_SIZE,
+                timeout_seconds + CLAIM_LEASE_MARGIN_SECONDS,
+            )
+            .await
+        {
+            Ok(deliveries) => deliveries,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        let mut webhooks: HashMap<u64, Webhook> = HashMap::new();
+        for delivery in deliveries.iter() {
+            if webhooks.contains_key(&delivery.webhook_id) {
+                continue;
+            }
+            match self
+                .webhook_repository
+                .find_by_id(delivery.webhook_id)
+                .await
+            {
+                Ok(Some(webhook)) => {
+                    webhooks.insert(delivery.webhook_id, webhook);
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    return Err(APIError::SimpleAPIError(SimpleAPIError {
+                        msg: error.to_string(),
+                        code: 500,
+                    }))
+                }
+            }
+        }
+
+        let attempts = deliveries.into_iter().filter_map(|delivery| {
+            webhooks
+                .get(&delivery.webhook_id)
+                .map(|webhook| self.attempt(webhook, delivery, max_attempts, backoff_base_seconds))
+        });
+        Ok(join_all(attempts).await.len() as u64)
+    }
+
+    async fn attempt(
+        &self,
+        webhook: &Webhook,
+        mut delivery: WebhookDelivery,
+        max_attempts: u32,
+        backoff_base_seconds: u64,
+    ) {
+        match self.webhook_client.send(webhook, &delivery).await {
+            Ok(response_status) => delivery.record_attempt(
+                Utc::now(),
+                Some(response_status),
+                None,
+                max_attempts,
+                backoff_base_seconds,
+            ),
+            Err(error) => delivery.record_attempt(
+                Utc::now(),
+                None,
+                Some(error),
+                max_attempts,
+                backoff_base_seconds,
+            ),
+        }
+
+        if let Err(error) = self.webhook_repository.update_delivery(&delivery).await {
+            tracing::error!(
+                "Failed to record the attempt of webhook delivery {}: {}",
+                delivery.id.unwrap_or_default(),
+                error
+            );
+        }
+    }
+}
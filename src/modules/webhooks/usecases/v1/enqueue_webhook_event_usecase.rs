@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::modules::{
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    webhooks::{
+        domain::entities::{webhook_delivery::webhook_payload, webhook_event::WebhookEvent},
+        infra::repositories::{
+            webhook_repository::WebhookRepository, webhook_repository_mysql::WebhookRepositoryMySQL,
+        },
+    },
+};
+
+pub struct EnqueueWebhookEventUseCaseV1<T: WebhookRepository> {
+    webhook_repository: Arc<T>,
+}
+
+impl EnqueueWebhookEventUseCaseV1<WebhookRepositoryMySQL> {
+    pub fn new(webhook_repository: WebhookRepositoryMySQL) -> Self {
+        Self {
+            webhook_repository: Arc::new(webhook_repository),
+        }
+    }
+
+    pub async fn enqueue(
+        &self,
+        library_id: u64,
+        event: WebhookEvent,
+        occurred_at: DateTime<Utc>,
+        data: Value,
+    ) -> Result<u64, APIError> {
+        let payload = webhook_payload(event, library_id, occurred_at, data);
+        match self
+            .webhook_repository
+            .enqueue_for_library(library_id, event, &payload)
+            .await
+        {
+            Ok(n_of_deliveries) => Ok(n_of_deliveries),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
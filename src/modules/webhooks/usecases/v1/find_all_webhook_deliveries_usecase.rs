@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    webhooks::{
+        domain::dtos::{
+            find_all_webhook_deliveries_dto::FindAllWebhookDeliveriesDto,
+            webhook_delivery_dto::WebhookDeliveryDto,
+        },
+        infra::repositories::{
+            webhook_repository::WebhookRepository, webhook_repository_mysql::WebhookRepositoryMySQL,
+        },
+    },
+};
+
+use super::authorize_webhook_owner_usecase::AuthorizeWebhookOwnerUseCaseV1;
+
+const DELIVERY_LOG_SIZE: u64 = 100;
+
+pub struct FindAllWebhookDeliveriesUseCaseV1<T>
+where
+    T: WebhookRepository,
+{
+    webhook_repository: Arc<T>,
+    authorize_webhook_owner_usecase: AuthorizeWebhookOwnerUseCaseV1<T>,
+}
+
+impl FindAllWebhookDeliveriesUseCaseV1<WebhookRepositoryMySQL> {
+    pub fn new(webhook_repository: WebhookRepositoryMySQL) -> Self {
+        Self {
+            webhook_repository: Arc::new(webhook_repository.clone()),
+            authorize_webhook_owner_usecase: AuthorizeWebhookOwnerUseCaseV1::new(
+                webhook_repository,
+            ),
+        }
+    }
+
+    pub async fn find_all_webhook_deliveries(
+        &self,
+        user_id: u64,
+        webhook_id: u64,
+    ) -> Result<FindAllWebhookDeliveriesDto, APIError> {
+        self.authorize_webhook_owner_usecase
+            .authorize(user_id, webhook_id)
+            .await?;
+
+        match self
+            .webhook_repository
+            .find_all_deliveries_by_webhook_id(webhook_id, DELIVERY_LOG_SIZE)
+            .await
+        {
+            Ok(deliveries) => Ok(FindAllWebhookDeliveriesDto {
+                deliveries: deliveries
+                    .into_iter()
+                    .map(WebhookDeliveryDto::from)
+                    .collect(),
+            }),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use crate::modules::{
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    webhooks::{
+        domain::dtos::{find_all_webhooks_dto::FindAllWebhooksDto, webhook_dto::WebhookDto},
+        infra::repositories::{
+            webhook_repository::WebhookRepository, webhook_repository_mysql::WebhookRepositoryMySQL,
+        },
+    },
+};
+
+pub struct FindAllWebhooksFromUserUseCaseV1<T>
+where
+    T: WebhookRepository,
+{
+    webhook_repository: Arc<T>,
+}
+
+impl FindAllWebhooksFromUserUseCaseV1<WebhookRepositoryMySQL> {
+    pub fn new(webhook_repository: WebhookRepositoryMySQL) -> Self {
+        Self {
+            webhook_repository: Arc::new(webhook_repository),
+        }
+    }
+
+    pub async fn find_all_webhooks_from_user(
+        &self,
+        user_id: u64,
+    ) -> Result<FindAllWebhooksDto, APIError> {
+        match self.webhook_repository.find_all_by_user_id(user_id).await {
+            Ok(webhooks) => Ok(FindAllWebhooksDto {
+                webhooks: webhooks.into_iter().map(WebhookDto::from).collect(),
+            }),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
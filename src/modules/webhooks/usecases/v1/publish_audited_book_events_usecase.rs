@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::{json, Value};
+
+use crate::modules::{
+    books::{
+        domain::entities::audit_entry::AuditEntityType,
+        infra::repositories::{
+            audit_repository::AuditRepository, audit_repository_mysql::AuditRepositoryMySQL,
+        },
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    webhooks::{
+        domain::entities::webhook_event::WebhookEvent,
+        infra::repositories::{
+            webhook_repository::WebhookRepository, webhook_repository_mysql::WebhookRepositoryMySQL,
+        },
+    },
+};
+
+use super::enqueue_webhook_event_usecase::EnqueueWebhookEventUseCaseV1;
+
+const PUBLISH_BATCH_SIZE: u64 = 500;
+
+pub struct PublishAuditedBookEventsUseCaseV1<T, U>
+where
+    T: AuditRepository,
+    U: WebhookRepository,
+{
+    audit_repository: Arc<T>,
+    enqueue_webhook_event_usecase: EnqueueWebhookEventUseCaseV1<U>,
+}
+
+impl PublishAuditedBookEventsUseCaseV1<AuditRepositoryMySQL, WebhookRepositoryMySQL> {
+    pub fn new(
+        audit_repository: AuditRepositoryMySQL,
+        webhook_repository: WebhookRepositoryMySQL,
+    ) -> Self {
+        Self {
+            audit_repository: Arc::new(audit_repository),
+            enqueue_webhook_event_usecase: EnqueueWebhookEventUseCaseV1::new(webhook_repository),
+        }
+    }
+
+    /// Turns the changes of books recorded in the audit trail into webhook
+    /// deliveries, so every change that was persisted is eventually
+    /// published. An entry is only marked as published once its deliveries
+    /// are queued, returning how many entries were published.
+    pub async fn publish(&self) -> Result<u64, APIError> {
+        let entries = match self
+            .audit_repository
+            .find_all_unpublished(PUBLISH_BATCH_SIZE)
+            .await
+        {
+            Ok(entries) => entries,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        let mut published_ids = Vec::new();
+        let mut publishing_result = Ok(());
+        for entry in entries.into_iter() {
+            let event = match entry.entity_type {
+                AuditEntityType::Book => WebhookEvent::from_book_audit_action(entry.action),
+                AuditEntityType::Collection | AuditEntityType::Location => None,
+            };
+            if let Some(event) = event {
+                let data = json!({
+                    "book_id": entry.entity_id,
+                    "actor_id": entry.actor_id,
+                    "changes": Value::Object(entry.changes),
+                    "book": entry.snapshot,
+                });
+                // Entries after a failed one stay unpublished, keeping the
+                // events of a book in order
+                if let Err(error) = self
+                    .enqueue_webhook_event_usecase
+                    .enqueue(
+                        entry.library_id,
+                        event,
+                        entry.created_at.unwrap_or_else(Utc::now),
+                        data,
+                    )
+                    .await
+                {
+                    publishing_result = Err(error);
+                    break;
+                }
+            }
+            published_ids.push(entry.id.unwrap_or_default());
+        }
+
+        if let Err(error) = self.audit_repository.mark_published(&published_ids).await {
+            return Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            }));
+        }
+        publishing_result.map(|_| published_ids.len() as u64)
+    }
+}
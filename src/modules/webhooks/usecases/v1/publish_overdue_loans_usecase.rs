@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use serde_json::json;
+
+use crate::modules::{
+    loans::infra::repositories::{
+        loan_repository::LoanRepository, loan_repository_mysql::LoanRepositoryMySQL,
+    },
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    webhooks::{
+        domain::entities::webhook_event::WebhookEvent,
+        infra::repositories::{
+            webhook_repository::WebhookRepository, webhook_repository_mysql::WebhookRepositoryMySQL,
+        },
+    },
+};
+
+use super::enqueue_webhook_event_usecase::EnqueueWebhookEventUseCaseV1;
+
+pub struct PublishOverdueLoansUseCaseV1<T, U>
+where
+    T: LoanRepository,
+    U: WebhookRepository,
+{
+    loan_repository: Arc<T>,
+    enqueue_webhook_event_usecase: EnqueueWebhookEventUseCaseV1<U>,
+}
+
+impl PublishOverdueLoansUseCaseV1<LoanRepositoryMySQL, WebhookRepositoryMySQL> {
+    pub fn new(
+        loan_repository: LoanRepositoryMySQL,
+        webhook_repository: WebhookRepositoryMySQL,
+    ) -> Self {
+        Self {
+            loan_repository: Arc::new(loan_repository),
+            enqueue_webhook_event_usecase: EnqueueWebhookEventUseCaseV1::new(webhook_repository),
+        }
+    }
+
+    pub async fn publish(&self, loan_overdue_days: i64) -> Result<u64, APIError> {
+        let now = Utc::now();
+        let overdue_loans = match self
+            .loan_repository
+            .find_all_overdue(now - Duration::days(loan_overdue_days))
+            .await
+        {
+            Ok(overdue_loans) => overdue_loans,
+            Err(error) => {
+                return Err(APIError::SimpleAPIError(SimpleAPIError {
+                    msg: error.to_string(),
+                    code: 500,
+                }))
+            }
+        };
+
+        let mut notified_ids = Vec::new();
+        let mut publishing_result = Ok(());
+        for loan in overdue_loans.iter() {
+            if let Err(error) = self
+                .enqueue_webhook_event_usecase
+                .enqueue(
+                    loan.library_id,
+                    WebhookEvent::LoanOverdue,
+                    now,
+                    json!({ "loan": loan }),
+                )
+                .await
+            {
+                publishing_result = Err(error);
+                break;
+            }
+            notified_ids.push(loan.id);
+        }
+
+        if let Err(error) = self
+            .loan_repository
+            .mark_overdue_notified(&notified_ids)
+            .await
+        {
+            return Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            }));
+        }
+        publishing_result.map(|_| notified_ids.len() as u64)
+    }
+}
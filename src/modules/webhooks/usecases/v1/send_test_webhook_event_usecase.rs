@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::json;
+
+use crate::modules::{
+    shared::errors::{simple_api_error::SimpleAPIError, APIError},
+    webhooks::{
+        domain::{
+            dtos::webhook_delivery_dto::WebhookDeliveryDto,
+            entities::{webhook_delivery::webhook_payload, webhook_event::WebhookEvent},
+        },
+        infra::repositories::{
+            webhook_repository::WebhookRepository, webhook_repository_mysql::WebhookRepositoryMySQL,
+        },
+    },
+};
+
+use super::authorize_webhook_owner_usecase::AuthorizeWebhookOwnerUseCaseV1;
+
+pub struct SendTestWebhookEventUseCaseV1<T>
+where
+    T: WebhookRepository,
+{
+    webhook_repository: Arc<T>,
+    authorize_webhook_owner_usecase: AuthorizeWebhookOwnerUseCaseV1<T>,
+}
+
+impl SendTestWebhookEventUseCaseV1<WebhookRepositoryMySQL> {
+    pub fn new(webhook_repository: WebhookRepositoryMySQL) -> Self {
+        Self {
+            webhook_repository: Arc::new(webhook_repository.clone()),
+            authorize_webhook_owner_usecase: AuthorizeWebhookOwnerUseCaseV1::new(
+                webhook_repository,
+            ),
+        }
+    }
+
+    pub async fn send_test_webhook_event(
+        &self,
+        user_id: u64,
+        webhook_id: u64,
+    ) -> Result<WebhookDeliveryDto, APIError> {
+        let webhook = self
+            .authorize_webhook_owner_usecase
+            .authorize(user_id, webhook_id)
+            .await?;
+
+        let payload = webhook_payload(
+            WebhookEvent::Test,
+            webhook.library_id,
+            Utc::now(),
+            json!({ "webhook_id": webhook_id }),
+        );
+        match self
+            .webhook_repository
+            .enqueue_for_webhook(webhook_id, WebhookEvent::Test, &payload)
+            .await
+        {
+            Ok(Some(delivery)) => Ok(WebhookDeliveryDto::from(delivery)),
+            Ok(None) => Err(APIError::SimpleAPIError(SimpleAPIError::new(
+                "Failed to load queued delivery info".to_string(),
+                500,
+            ))),
+            Err(error) => Err(APIError::SimpleAPIError(SimpleAPIError {
+                msg: error.to_string(),
+                code: 500,
+            })),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use utoipa::{
+    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
+use utoipa_swagger_ui::{Config, SwaggerUi};
+
+use crate::modules::{
+    books::infra::controllers::v1::{
+        backup_controller_v1::BackupApiV1,
+        book_controller_v1::BookApiV1,
+        book_history_controller_v1::BookHistoryApiV1,
+        collection_controller_v1::CollectionApiV1,
+        custom_field_controller_v1::CustomFieldApiV1,
+        export_controller_v1::ExportApiV1,
+        import_controller_v1::ImportApiV1,
+        location_controller_v1::LocationApiV1,
+        note_controller_v1::{BookNoteApiV1, NoteApiV1},
+        public_controller_v1::PublicApiV1,
+        share_link_controller_v1::ShareLinkApiV1,
+        tag_controller_v1::TagApiV1,
+        trash_controller_v1::TrashApiV1,
+    },
+    events::infra::controllers::v1::event_controller_v1::EventApiV1,
+    friends::infra::controllers::v1::friend_controller_v1::FriendApiV1,
+    libraries::infra::controllers::v1::{
+        library_controller_v1::LibraryApiV1,
+        library_invitation_controller_v1::LibraryInvitationApiV1,
+    },
+    loans::infra::controllers::v1::{
+        borrow_request_controller_v1::BorrowRequestApiV1, loan_controller_v1::LoanApiV1,
+    },
+    sync::infra::controllers::v1::sync_controller_v1::SyncApiV1,
+    users::infra::controllers::v1::{
+        auth_controller_v1::AuthApiV1, oidc_controller_v1::OidcApiV1, user_controller_v1::UserApiV1,
+    },
+    webhooks::infra::controllers::v1::webhook_controller_v1::WebhookApiV1,
+};
+
+/// Every `/v1` scope, nested under the same prefix it is registered with in
+/// `startup::configure_v1_routes`. Operations require the bearer token
+/// unless they opt out.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Online Librarian API",
+        description = "Catalog of personal and shared libraries, their books and loans."
+    ),
+    nest(
+        (path = "/v1/users", api = UserApiV1),
+        (path = "/v1/auth/oidc", api = OidcApiV1),
+        (path = "/v1/auth", api = AuthApiV1),
+        (path = "/v1/locations", api = LocationApiV1),
+        (path = "/v1/collections", api = CollectionApiV1),
+        (path = "/v1/books/{book_id}/notes", api = BookNoteApiV1),
+        (path = "/v1/books/{book_id}/history", api = BookHistoryApiV1),
+        (path = "/v1/books", api = BookApiV1),
+        (path = "/v1/notes", api = NoteApiV1),
+        (path = "/v1/tags", api = TagApiV1),
+        (path = "/v1/custom-fields", api = CustomFieldApiV1),
+        (path = "/v1/trash", api = TrashApiV1),
+        (path = "/v1/imports", api = ImportApiV1),
+        (path = "/v1/export", api = ExportApiV1),
+        (path = "/v1/backup", api = BackupApiV1),
+        (path = "/v1/share-links", api = ShareLinkApiV1),
+        (path = "/v1/public", api = PublicApiV1),
+        (path = "/v1/libraries", api = LibraryApiV1),
+        (path = "/v1/invitations", api = LibraryInvitationApiV1),
+        (path = "/v1/friends", api = FriendApiV1),
+        (path = "/v1/borrow-requests", api = BorrowRequestApiV1),
+        (path = "/v1/loans", api = LoanApiV1),
+        (path = "/v1/webhooks", api = WebhookApiV1),
+        (path = "/v1/events", api = EventApiV1),
+        (path = "/v1/sync", api = SyncApiV1),
+    ),
+    modifiers(&BearerAuth),
+    security(("bearer_auth" = []))
+)]
+pub struct ApiDoc;
+
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .description(Some("Token returned by `POST /v1/auth/login`"))
+                    .build(),
+            ),
+        );
+    }
+}
+
+pub fn api_docs() -> SwaggerUi {
+    SwaggerUi::new("/docs/{_:.*}")
+        .url("/openapi.json", ApiDoc::openapi())
+        .config(Config::default().persist_authorization(true))
+}
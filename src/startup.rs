@@ -8,48 +8,294 @@ use actix_web::{web, App, HttpServer};
 use sqlx::MySqlPool;
 use tracing_actix_web::TracingLogger;
 
-use crate::configuration::TokenSettings;
+use crate::configuration::{
+    GraphQLSettings, LoginRateLimitSettings, OidcSettings, RateLimitStoreBackend, TokenSettings,
+    WebhookSettings,
+};
+use crate::modules::books::infra::controllers::v1::backup_controller_v1::{
+    self, BackupControllerV1,
+};
 use crate::modules::books::infra::controllers::v1::book_controller_v1::{self, BookControllerV1};
+use crate::modules::books::infra::controllers::v1::book_history_controller_v1::{
+    self, BookHistoryControllerV1,
+};
 use crate::modules::books::infra::controllers::v1::collection_controller_v1::{
     self, CollectionControllerV1,
 };
+use crate::modules::books::infra::controllers::v1::custom_field_controller_v1::{
+    self, CustomFieldControllerV1,
+};
+use crate::modules::books::infra::controllers::v1::export_controller_v1::{
+    self, ExportControllerV1,
+};
+use crate::modules::books::infra::controllers::v1::import_controller_v1::{
+    self, ImportControllerV1,
+};
 use crate::modules::books::infra::controllers::v1::location_controller_v1::{
     self, LocationControllerV1,
 };
+use crate::modules::books::infra::controllers::v1::note_controller_v1::{self, NoteControllerV1};
+use crate::modules::books::infra::controllers::v1::public_controller_v1::{
+    self, PublicControllerV1,
+};
+use crate::modules::books::infra::controllers::v1::share_link_controller_v1::{
+    self, ShareLinkControllerV1,
+};
+use crate::modules::books::infra::controllers::v1::tag_controller_v1::{self, TagControllerV1};
+use crate::modules::books::infra::controllers::v1::trash_controller_v1::{self, TrashControllerV1};
+use crate::modules::books::infra::repositories::audit_repository_mysql::AuditRepositoryMySQL;
 use crate::modules::books::infra::repositories::book_repository_mysql::BookRepositoryMySQL;
 use crate::modules::books::infra::repositories::collection_repository_mysql::CollectionRepositoryMySQL;
+use crate::modules::books::infra::repositories::custom_field_repository_mysql::CustomFieldRepositoryMySQL;
 use crate::modules::books::infra::repositories::location_repository_mysql::LocationRepositoryMySQL;
+use crate::modules::books::infra::repositories::note_repository_mysql::NoteRepositoryMySQL;
+use crate::modules::books::infra::repositories::share_link_repository_mysql::ShareLinkRepositoryMySQL;
+use crate::modules::books::infra::repositories::tag_repository_mysql::TagRepositoryMySQL;
+use crate::modules::books::infra::repositories::trash_repository_mysql::TrashRepositoryMySQL;
+use crate::modules::events::infra::buses::change_event_bus::ChangeEventBus;
+use crate::modules::events::infra::controllers::v1::event_controller_v1::{
+    self, EventControllerV1,
+};
+use crate::modules::friends::infra::controllers::v1::friend_controller_v1::{
+    self, FriendControllerV1,
+};
+use crate::modules::friends::infra::repositories::friendship_repository_mysql::FriendshipRepositoryMySQL;
+use crate::modules::graphql::infra::controllers::graphql_controller::{self, GraphQLController};
+use crate::modules::libraries::infra::controllers::v1::library_controller_v1::{
+    self, LibraryControllerV1,
+};
+use crate::modules::libraries::infra::controllers::v1::library_invitation_controller_v1::{
+    self, LibraryInvitationControllerV1,
+};
+use crate::modules::libraries::infra::repositories::library_invitation_repository_mysql::LibraryInvitationRepositoryMySQL;
+use crate::modules::libraries::infra::repositories::library_repository_mysql::LibraryRepositoryMySQL;
+use crate::modules::loans::infra::controllers::v1::borrow_request_controller_v1::{
+    self, BorrowRequestControllerV1,
+};
+use crate::modules::loans::infra::controllers::v1::loan_controller_v1::{self, LoanControllerV1};
+use crate::modules::loans::infra::repositories::borrow_request_repository_mysql::BorrowRequestRepositoryMySQL;
+use crate::modules::loans::infra::repositories::loan_repository_mysql::LoanRepositoryMySQL;
+use crate::modules::shared::infra::clocks::system_clock::SystemClock;
+use crate::modules::sync::infra::controllers::v1::sync_controller_v1::{self, SyncControllerV1};
+use crate::modules::sync::infra::repositories::sync_repository_mysql::SyncRepositoryMySQL;
 use crate::modules::users::infra::controllers::v1::auth_controller_v1::{self, AuthControllerV1};
+use crate::modules::users::infra::controllers::v1::oidc_controller_v1::{self, OidcControllerV1};
 use crate::modules::users::infra::controllers::v1::user_controller_v1::{self, UserControllerV1};
+use crate::modules::users::infra::repositories::two_factor_repository_mysql::TwoFactorRepositoryMySQL;
+use crate::modules::users::infra::repositories::user_identity_repository_mysql::UserIdentityRepositoryMySQL;
 use crate::modules::users::infra::repositories::user_repository_mysql::UserRepositoryMySQL;
+use crate::modules::users::infra::stores::rate_limit_store::RateLimitStore;
+use crate::modules::users::infra::stores::rate_limit_store_memory::RateLimitStoreMemory;
+use crate::modules::users::infra::stores::rate_limit_store_mysql::RateLimitStoreMySQL;
+use crate::modules::users::usecases::v1::limit_login_attempts::LimitLoginAttemptsUseCaseV1;
+use crate::modules::webhooks::domain::entities::webhook_target::WebhookTargetPolicy;
+use crate::modules::webhooks::infra::controllers::v1::webhook_controller_v1::{
+    self, WebhookControllerV1,
+};
+use crate::modules::webhooks::infra::repositories::webhook_repository_mysql::WebhookRepositoryMySQL;
 use crate::routes::health_check::health_check;
+use crate::routes::openapi::api_docs;
 
 pub fn run(
     listener: TcpListener,
     db_pool: MySqlPool,
     token_settings: TokenSettings,
+    graphql_settings: GraphQLSettings,
+    login_rate_limit_settings: LoginRateLimitSettings,
+    oidc_settings: OidcSettings,
+    webhook_settings: WebhookSettings,
 ) -> Result<Server, std::io::Error> {
     let arc_db_pool = Arc::new(db_pool);
     let arc_token_settings = Arc::new(token_settings);
 
     let user_repository = UserRepositoryMySQL::new(arc_db_pool.clone());
+    let two_factor_repository = TwoFactorRepositoryMySQL::new(arc_db_pool.clone());
+    let user_identity_repository = UserIdentityRepositoryMySQL::new(arc_db_pool.clone());
     let location_repository = LocationRepositoryMySQL::new(arc_db_pool.clone());
     let collection_repository = CollectionRepositoryMySQL::new(arc_db_pool.clone());
     let book_repository = BookRepositoryMySQL::new(arc_db_pool.clone());
+    let share_link_repository = ShareLinkRepositoryMySQL::new(arc_db_pool.clone());
+    let tag_repository = TagRepositoryMySQL::new(arc_db_pool.clone());
+    let custom_field_repository = CustomFieldRepositoryMySQL::new(arc_db_pool.clone());
+    let note_repository = NoteRepositoryMySQL::new(arc_db_pool.clone());
+    let trash_repository = TrashRepositoryMySQL::new(arc_db_pool.clone());
+    let audit_repository = AuditRepositoryMySQL::new(arc_db_pool.clone());
+    let library_repository = LibraryRepositoryMySQL::new(arc_db_pool.clone());
+    let webhook_repository = WebhookRepositoryMySQL::new(arc_db_pool.clone());
+    let library_invitation_repository = LibraryInvitationRepositoryMySQL::new(arc_db_pool.clone());
+    let friendship_repository = FriendshipRepositoryMySQL::new(arc_db_pool.clone());
+    let borrow_request_repository = BorrowRequestRepositoryMySQL::new(arc_db_pool.clone());
+    let loan_repository = LoanRepositoryMySQL::new(arc_db_pool.clone());
+    let sync_repository = SyncRepositoryMySQL::new(arc_db_pool.clone());
+    let change_event_bus = ChangeEventBus::new();
 
-    let user_controller_v1 = web::Data::new(UserControllerV1::new(user_repository.clone()));
+    let rate_limit_store: Arc<dyn RateLimitStore> = match login_rate_limit_settings.store {
+        RateLimitStoreBackend::Memory => Arc::new(RateLimitStoreMemory::new()),
+        RateLimitStoreBackend::MySQL => Arc::new(RateLimitStoreMySQL::new(arc_db_pool.clone())),
+    };
+    let limit_login_attempts_usecase = web::Data::new(LimitLoginAttemptsUseCaseV1::new(
+        rate_limit_store,
+        login_rate_limit_settings,
+        Arc::new(SystemClock),
+    ));
+
+    let user_controller_v1 = web::Data::new(UserControllerV1::new(
+        user_repository.clone(),
+        library_repository.clone(),
+    ));
     let auth_controller_v1 = web::Data::new(AuthControllerV1::new(
         user_repository.clone(),
+        two_factor_repository.clone(),
         arc_token_settings.clone(),
     ));
-    let location_controller_v1 =
-        web::Data::new(LocationControllerV1::new(location_repository.clone()));
-    let collection_controller_v1 =
-        web::Data::new(CollectionControllerV1::new(collection_repository.clone()));
+    let oidc_controller_v1 = web::Data::new(OidcControllerV1::new(
+        user_repository.clone(),
+        user_identity_repository.clone(),
+        two_factor_repository.clone(),
+        library_repository.clone(),
+        oidc_settings,
+        arc_token_settings.clone(),
+    ));
+    let location_controller_v1 = web::Data::new(LocationControllerV1::new(
+        location_repository.clone(),
+        library_repository.clone(),
+        audit_repository.clone(),
+        change_event_bus.clone(),
+    ));
+    let collection_controller_v1 = web::Data::new(CollectionControllerV1::new(
+        collection_repository.clone(),
+        library_repository.clone(),
+        audit_repository.clone(),
+        change_event_bus.clone(),
+    ));
     let book_controller_v1 = web::Data::new(BookControllerV1::new(
         book_repository.clone(),
         collection_repository.clone(),
         location_repository.clone(),
+        library_repository.clone(),
+        tag_repository.clone(),
+        custom_field_repository.clone(),
+        note_repository.clone(),
+        audit_repository.clone(),
+        change_event_bus.clone(),
+    ));
+    let book_history_controller_v1 = web::Data::new(BookHistoryControllerV1::new(
+        book_repository.clone(),
+        collection_repository.clone(),
+        location_repository.clone(),
+        library_repository.clone(),
+        tag_repository.clone(),
+        custom_field_repository.clone(),
+        audit_repository.clone(),
+        change_event_bus.clone(),
+    ));
+    let note_controller_v1 = web::Data::new(NoteControllerV1::new(
+        note_repository.clone(),
+        book_repository.clone(),
+        library_repository.clone(),
+    ));
+    let tag_controller_v1 = web::Data::new(TagControllerV1::new(tag_repository.clone()));
+    let trash_controller_v1 = web::Data::new(TrashControllerV1::new(
+        trash_repository.clone(),
+        book_repository.clone(),
+        collection_repository.clone(),
+        location_repository.clone(),
+        library_repository.clone(),
+        audit_repository.clone(),
+        change_event_bus.clone(),
+    ));
+    let custom_field_controller_v1 = web::Data::new(CustomFieldControllerV1::new(
+        custom_field_repository.clone(),
+    ));
+    let import_controller_v1 = web::Data::new(ImportControllerV1::new(
+        book_repository.clone(),
+        collection_repository.clone(),
+        location_repository.clone(),
+        library_repository.clone(),
+        audit_repository.clone(),
+        change_event_bus.clone(),
+        webhook_repository.clone(),
+    ));
+    let export_controller_v1 = web::Data::new(ExportControllerV1::new(
+        book_repository.clone(),
+        library_repository.clone(),
+        custom_field_repository.clone(),
+    ));
+    let backup_controller_v1 = web::Data::new(BackupControllerV1::new(
+        book_repository.clone(),
+        collection_repository.clone(),
+        location_repository.clone(),
+        library_repository.clone(),
+        tag_repository.clone(),
+        custom_field_repository.clone(),
+        audit_repository.clone(),
+        change_event_bus.clone(),
+    ));
+    let share_link_controller_v1 = web::Data::new(ShareLinkControllerV1::new(
+        share_link_repository.clone(),
+        collection_repository.clone(),
+        location_repository.clone(),
+        library_repository.clone(),
+    ));
+    let public_controller_v1 = web::Data::new(PublicControllerV1::new(
+        share_link_repository.clone(),
+        book_repository.clone(),
+    ));
+    let library_controller_v1 = web::Data::new(LibraryControllerV1::new(
+        library_repository.clone(),
+        library_invitation_repository.clone(),
+        user_repository.clone(),
+    ));
+    let library_invitation_controller_v1 = web::Data::new(LibraryInvitationControllerV1::new(
+        library_invitation_repository.clone(),
+        user_repository.clone(),
+    ));
+    let friend_controller_v1 = web::Data::new(FriendControllerV1::new(
+        friendship_repository.clone(),
+        user_repository.clone(),
+        library_repository.clone(),
+        book_repository.clone(),
+    ));
+    let borrow_request_controller_v1 = web::Data::new(BorrowRequestControllerV1::new(
+        borrow_request_repository.clone(),
+        loan_repository.clone(),
+        book_repository.clone(),
+        library_repository.clone(),
+        friendship_repository.clone(),
+    ));
+    let loan_controller_v1 = web::Data::new(LoanControllerV1::new(loan_repository.clone()));
+    let event_controller_v1 = web::Data::new(EventControllerV1::new(
+        library_repository.clone(),
+        change_event_bus.clone(),
+    ));
+    let sync_controller_v1 = web::Data::new(SyncControllerV1::new(
+        sync_repository.clone(),
+        book_repository.clone(),
+        collection_repository.clone(),
+        location_repository.clone(),
+        library_repository.clone(),
+        tag_repository.clone(),
+        custom_field_repository.clone(),
+        note_repository.clone(),
+        audit_repository.clone(),
+        change_event_bus.clone(),
+    ));
+    let webhook_controller_v1 = web::Data::new(WebhookControllerV1::new(
+        webhook_repository.clone(),
+        library_repository.clone(),
+        WebhookTargetPolicy::new(webhook_settings.allowed_hosts),
+    ));
+    let graphql_controller = web::Data::new(GraphQLController::new(
+        user_repository.clone(),
+        book_repository.clone(),
+        collection_repository.clone(),
+        location_repository.clone(),
+        library_repository.clone(),
+        tag_repository.clone(),
+        custom_field_repository.clone(),
+        note_repository.clone(),
+        loan_repository.clone(),
+        graphql_settings,
     ));
 
     let server = HttpServer::new(move || {
@@ -60,25 +306,82 @@ pub fn run(
                 header::AUTHORIZATION,
                 header::ACCEPT,
                 header::CONTENT_TYPE,
+                header::IF_MATCH,
             ])
+            .expose_headers(vec![header::CONTENT_DISPOSITION, header::ETAG])
             .max_age(3600);
         App::new()
             .wrap(TracingLogger::default())
             .wrap(cors)
             .route("/health_check", web::get().to(health_check))
-            .service(user_controller_v1::get_user_scope())
-            .service(auth_controller_v1::get_auth_scope())
-            .service(location_controller_v1::get_location_scope())
-            .service(collection_controller_v1::get_collection_scope())
-            .service(book_controller_v1::get_book_scope())
+            .service(web::redirect("/docs", "/docs/"))
+            .service(api_docs())
+            .route("/graphql", web::post().to(graphql_controller::graphql))
+            .route(
+                "/graphql",
+                web::get().to(graphql_controller::graphql_playground),
+            )
+            .configure(configure_v1_routes)
             .app_data(user_controller_v1.clone())
             .app_data(auth_controller_v1.clone())
+            .app_data(oidc_controller_v1.clone())
             .app_data(collection_controller_v1.clone())
             .app_data(arc_token_settings.clone())
+            .app_data(limit_login_attempts_usecase.clone())
             .app_data(location_controller_v1.clone())
             .app_data(book_controller_v1.clone())
+            .app_data(book_history_controller_v1.clone())
+            .app_data(note_controller_v1.clone())
+            .app_data(tag_controller_v1.clone())
+            .app_data(custom_field_controller_v1.clone())
+            .app_data(trash_controller_v1.clone())
+            .app_data(import_controller_v1.clone())
+            .app_data(export_controller_v1.clone())
+            .app_data(backup_controller_v1.clone())
+            .app_data(share_link_controller_v1.clone())
+            .app_data(public_controller_v1.clone())
+            .app_data(library_controller_v1.clone())
+            .app_data(library_invitation_controller_v1.clone())
+            .app_data(friend_controller_v1.clone())
+            .app_data(borrow_request_controller_v1.clone())
+            .app_data(loan_controller_v1.clone())
+            .app_data(webhook_controller_v1.clone())
+            .app_data(event_controller_v1.clone())
+            .app_data(sync_controller_v1.clone())
+            .app_data(graphql_controller.clone())
     })
     .listen(listener)?
     .run();
     Ok(server)
 }
+
+pub fn configure_v1_routes(cfg: &mut web::ServiceConfig) {
+    // Scopes nested under the prefix of another one (oidc under auth, notes
+    // and history under books) go first, the outer scope would take their
+    // requests otherwise.
+    cfg.service(user_controller_v1::get_user_scope())
+        .service(oidc_controller_v1::get_oidc_scope())
+        .service(auth_controller_v1::get_auth_scope())
+        .service(location_controller_v1::get_location_scope())
+        .service(collection_controller_v1::get_collection_scope())
+        .service(note_controller_v1::get_book_note_scope())
+        .service(book_history_controller_v1::get_book_history_scope())
+        .service(book_controller_v1::get_book_scope())
+        .service(note_controller_v1::get_note_scope())
+        .service(tag_controller_v1::get_tag_scope())
+        .service(custom_field_controller_v1::get_custom_field_scope())
+        .service(trash_controller_v1::get_trash_scope())
+        .service(import_controller_v1::get_import_scope())
+        .service(export_controller_v1::get_export_scope())
+        .service(backup_controller_v1::get_backup_scope())
+        .service(share_link_controller_v1::get_share_link_scope())
+        .service(public_controller_v1::get_public_scope())
+        .service(library_controller_v1::get_library_scope())
+        .service(library_invitation_controller_v1::get_library_invitation_scope())
+        .service(friend_controller_v1::get_friend_scope())
+        .service(borrow_request_controller_v1::get_borrow_request_scope())
+        .service(loan_controller_v1::get_loan_scope())
+        .service(webhook_controller_v1::get_webhook_scope())
+        .service(event_controller_v1::get_event_scope())
+        .service(sync_controller_v1::get_sync_scope());
+}
@@ -0,0 +1,120 @@
+use new_online_librarian_backend::modules::{
+    books::{
+        infra::repositories::{
+            audit_repository_mysql::AuditRepositoryMySQL,
+            trash_repository_mysql::TrashRepositoryMySQL,
+        },
+        usecases::v1::purge_expired_trash_usecase::PurgeExpiredTrashUseCaseV1,
+    },
+    events::infra::buses::change_event_bus::ChangeEventBus,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::helpers::{response_json, spawn_app, TestApp};
+
+async fn find_audit_actions(app: &TestApp, entity_type: &str, entity_id: u64) -> Vec<String> {
+    sqlx::query_scalar(
+        "SELECT action FROM audit_entries WHERE entity_type = ? AND entity_id = ? ORDER BY id",
+    )
+    .bind(entity_type)
+    .bind(entity_id)
+    .fetch_all(&app.db_pool)
+    .await
+    .expect("Failed to fetch audit entries.")
+}
+
+async fn find_audit_actor(app: &TestApp, entity_type: &str, entity_id: u64) -> u64 {
+    sqlx::query_scalar(
+        "SELECT actor_id FROM audit_entries WHERE entity_type = ? AND entity_id = ? ORDER BY id DESC LIMIT 1",
+    )
+    .bind(entity_type)
+    .bind(entity_id)
+    .fetch_one(&app.db_pool)
+    .await
+    .expect("Failed to fetch audit entries.")
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn replacing_a_library_audits_removed_and_restored_items() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let book = app.create_book(&user, shelf, "Dom Casmurro").await["id"]
+        .as_u64()
+        .unwrap();
+
+    let response = app.get("/v1/backup", &user).await;
+    assert_eq!(200, response.status().as_u16());
+    let archive = response.bytes().await.expect("Failed to read response.");
+
+    let response = app
+        .api_client
+        .post(format!("{}/v1/backup/restore?mode=replace", &app.address))
+        .bearer_auth(&user.token)
+        .header("Content-Type", "application/zip")
+        .body(archive)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(json!(1), response_json(response).await["restored_books"]);
+    assert_eq!(
+        vec!["create", "purge"],
+        find_audit_actions(&app, "book", book).await
+    );
+    assert_eq!(
+        vec!["create", "purge"],
+        find_audit_actions(&app, "location", shelf).await
+    );
+    let restored_book: u64 =
+        sqlx::query_scalar("SELECT id FROM books WHERE title = 'Dom Casmurro'")
+            .fetch_one(&app.db_pool)
+            .await
+            .expect("Failed to fetch the restored book.");
+    assert_eq!(
+        vec!["create"],
+        find_audit_actions(&app, "book", restored_book).await
+    );
+    assert_eq!(user.id, find_audit_actor(&app, "book", restored_book).await);
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn expired_trash_is_purged_by_the_system_actor() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let expired = app.create_book(&user, shelf, "Dom Casmurro").await["id"]
+        .as_u64()
+        .unwrap();
+    let kept = app.create_book(&user, shelf, "Helena").await["id"]
+        .as_u64()
+        .unwrap();
+    sqlx::query("UPDATE books SET deleted_at = NOW() - INTERVAL 40 DAY WHERE id = ?")
+        .bind(expired)
+        .execute(&app.db_pool)
+        .await
+        .expect("Failed to trash the book.");
+
+    let db_pool = Arc::new(app.db_pool.clone());
+    let purge_expired_trash_usecase = PurgeExpiredTrashUseCaseV1::new(
+        TrashRepositoryMySQL::new(db_pool.clone()),
+        AuditRepositoryMySQL::new(db_pool),
+        ChangeEventBus::new(),
+    );
+    let n_of_purged_items = match purge_expired_trash_usecase.purge_expired_trash(30).await {
+        Ok(n_of_purged_items) => n_of_purged_items,
+        Err(_) => panic!("Failed to purge the trash."),
+    };
+
+    assert_eq!(1, n_of_purged_items);
+    assert_eq!(
+        vec!["create", "purge"],
+        find_audit_actions(&app, "book", expired).await
+    );
+    assert_eq!(0, find_audit_actor(&app, "book", expired).await);
+    assert_eq!(vec!["create"], find_audit_actions(&app, "book", kept).await);
+}
@@ -0,0 +1,175 @@
+use serde_json::json;
+
+use crate::helpers::{book_body, response_json, spawn_app, TestApp, TestUser};
+
+async fn restore(
+    app: &TestApp,
+    user: &TestUser,
+    mode: &str,
+    archive: Vec<u8>,
+) -> reqwest::Response {
+    app.api_client
+        .post(format!("{}/v1/backup/restore?mode={}", &app.address, mode))
+        .bearer_auth(&user.token)
+        .header("Content-Type", "application/zip")
+        .body(archive)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn backup(app: &TestApp, user: &TestUser) -> Vec<u8> {
+    let response = app.get("/v1/backup", user).await;
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(
+        "application/zip",
+        response.headers()["content-type"].to_str().unwrap()
+    );
+    response.bytes().await.unwrap().to_vec()
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn backups_are_restored_into_another_account() {
+    let app = spawn_app().await;
+    let owner = app.create_user().await;
+    let reader = app.create_user().await;
+    let shelf = app.create_location(&owner, "Shelf").await;
+    let classics = app.create_collection(&owner, "Classics").await;
+    let mut body = book_body(&owner, shelf, "Dom Casmurro");
+    body["collection_id"] = json!(classics);
+    assert_eq!(
+        201,
+        app.post("/v1/books", &owner, &body).await.status().as_u16()
+    );
+    app.create_book(&owner, shelf, "Helena").await;
+    let archive = backup(&app, &owner).await;
+
+    let response = restore(&app, &reader, "merge", archive.clone()).await;
+    assert_eq!(200, response.status().as_u16());
+    let report = response_json(response).await;
+    assert_eq!(json!(1), report["created_locations"]);
+    assert_eq!(json!(1), report["created_collections"]);
+    assert_eq!(json!(2), report["restored_books"]);
+
+    let books = response_json(app.get("/v1/books", &reader).await).await;
+    let restored_book = books["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|book| book["title"] == json!("Dom Casmurro"))
+        .expect("Book wasn't restored");
+    assert_eq!(json!(reader.id), restored_book["user_id"]);
+    assert_eq!(json!("Classics"), restored_book["collection"]["name"]);
+    assert_eq!(json!("Shelf"), restored_book["location"]["name"]);
+
+    // Merging again reuses everything and skips the books already there
+    let report = response_json(restore(&app, &reader, "merge", archive.clone()).await).await;
+    assert_eq!(json!(1), report["reused_locations"]);
+    assert_eq!(json!(1), report["reused_collections"]);
+    assert_eq!(json!(0), report["restored_books"]);
+    assert_eq!(json!(2), report["skipped_books"]);
+
+    let report = response_json(restore(&app, &reader, "replace", archive).await).await;
+    assert_eq!(json!(2), report["removed_books"]);
+    assert_eq!(json!(2), report["restored_books"]);
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn invalid_archives_are_rejected() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+
+    let response = restore(&app, &user, "merge", b"not a zip archive".to_vec()).await;
+    assert_eq!(400, response.status().as_u16());
+
+    let archive = backup(&app, &user).await;
+    let response = restore(&app, &user, "overwrite", archive).await;
+    assert_eq!(400, response.status().as_u16());
+    assert!(response_json(response).await["field_validations"]["mode"].is_string());
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn tags_are_restored_onto_the_tags_of_the_restoring_user() {
+    let app = spawn_app().await;
+    let owner = app.create_user().await;
+    let reader = app.create_user().await;
+    let shelf = app.create_location(&owner, "Shelf").await;
+    let favourite = app.create_tag(&owner, "favourite").await;
+    let to_read = app.create_tag(&owner, "to read").await;
+    let mut body = book_body(&owner, shelf, "Dom Casmurro");
+    body["tag_ids"] = json!([favourite, to_read]);
+    assert_eq!(
+        201,
+        app.post("/v1/books", &owner, &body).await.status().as_u16()
+    );
+    let readers_favourite = app.create_tag(&reader, "favourite").await;
+
+    let response = restore(&app, &reader, "merge", backup(&app, &owner).await).await;
+    assert_eq!(200, response.status().as_u16());
+    let report = response_json(response).await;
+    assert_eq!(json!(1), report["reused_tags"]);
+    assert_eq!(json!(1), report["created_tags"]);
+
+    let books = response_json(app.get("/v1/books", &reader).await).await;
+    let mut tags: Vec<(u64, String)> = books["items"][0]["tags"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tag| {
+            (
+                tag["id"].as_u64().unwrap(),
+                tag["name"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    tags.sort_by(|a, b| a.1.cmp(&b.1));
+    assert_eq!(2, tags.len());
+    assert_eq!((readers_favourite, "favourite".to_string()), tags[0]);
+    assert_eq!("to read", tags[1].1);
+    assert_ne!(to_read, tags[1].0);
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn custom_field_values_are_skipped_when_the_existing_field_rejects_them() {
+    let app = spawn_app().await;
+    let owner = app.create_user().await;
+    let reader = app.create_user().await;
+    let shelf = app.create_location(&owner, "Shelf").await;
+    for (user, name, field_type) in [
+        (&owner, "Pages", "number"),
+        (&owner, "Signed", "boolean"),
+        (&reader, "pages", "text"),
+    ] {
+        let response = app
+            .post(
+                "/v1/custom-fields",
+                user,
+                &json!({ "name": name, "field_type": field_type }),
+            )
+            .await;
+        assert_eq!(201, response.status().as_u16());
+    }
+    let mut body = book_body(&owner, shelf, "Dom Casmurro");
+    body["custom_fields"] = json!({ "Pages": 256, "Signed": true });
+    assert_eq!(
+        201,
+        app.post("/v1/books", &owner, &body).await.status().as_u16()
+    );
+
+    let response = restore(&app, &reader, "merge", backup(&app, &owner).await).await;
+    assert_eq!(200, response.status().as_u16());
+    let report = response_json(response).await;
+    assert_eq!(json!(1), report["reused_custom_fields"]);
+    assert_eq!(json!(1), report["created_custom_fields"]);
+    assert_eq!(json!(1), report["skipped_custom_field_values"]);
+
+    let books = response_json(app.get("/v1/books", &reader).await).await;
+    assert_eq!(
+        json!({ "Signed": true }),
+        books["items"][0]["custom_fields"]
+    );
+}
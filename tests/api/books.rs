@@ -0,0 +1,164 @@
+use serde_json::json;
+
+use crate::helpers::{book_body, response_json, spawn_app};
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn patching_a_book_of_another_member_keeps_its_owner() {
+    let app = spawn_app().await;
+    let owner = app.create_user().await;
+    let editor = app.create_user().await;
+    let shelf = app.create_location(&owner, "Shelf").await;
+    let book = app.create_book(&owner, shelf, "Dom Casmurro").await;
+    let library_id = book["library_id"].as_u64().unwrap();
+    app.add_library_member(library_id, &editor, "editor").await;
+    let editors_tag = app.create_tag(&editor, "favourite").await;
+
+    let response = app
+        .patch(
+            &format!("/v1/books/{}", book["id"]),
+            &editor,
+            book["version"].as_u64().unwrap(),
+            &json!({ "year": "1899", "tag_ids": [editors_tag] }),
+        )
+        .await;
+
+    assert_eq!(200, response.status().as_u16());
+    let patched_book = response_json(response).await;
+    assert_eq!(json!("1899"), patched_book["year"]);
+    assert_eq!(json!(owner.id), patched_book["user_id"]);
+    assert_eq!(json!(editors_tag), patched_book["tags"][0]["id"]);
+    let stored_owner: u64 = sqlx::query_scalar("SELECT user_id FROM books WHERE id = ?")
+        .bind(book["id"].as_u64())
+        .fetch_one(&app.db_pool)
+        .await
+        .expect("Failed to fetch the book.");
+    assert_eq!(owner.id, stored_owner);
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn malformed_cursors_are_rejected() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+
+    let response = app.get("/v1/books?cursor=not-a-cursor", &user).await;
+
+    assert_eq!(400, response.status().as_u16());
+    assert_eq!(
+        json!("Informed cursor is not valid"),
+        response_json(response).await["msg"]
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn cursors_page_forwards_and_backwards() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    for title in ["Dom Casmurro", "Helena", "Iaiá Garcia"] {
+        app.create_book(&user, shelf, title).await;
+    }
+
+    let response = app.get("/v1/books?cursor=&page_size=2", &user).await;
+    assert_eq!(200, response.status().as_u16());
+    let first_page = response_json(response).await;
+    let next_cursor = first_page["next_cursor"].as_str().unwrap().to_string();
+
+    let response = app
+        .get(
+            &format!("/v1/books?cursor={}&page_size=2", next_cursor),
+            &user,
+        )
+        .await;
+    assert_eq!(200, response.status().as_u16());
+    let second_page = response_json(response).await;
+    assert_eq!(json!("Iaiá Garcia"), second_page["items"][0]["title"]);
+    let previous_cursor = second_page["prev_cursor"].as_str().unwrap().to_string();
+
+    let response = app
+        .get(
+            &format!("/v1/books?cursor={}&page_size=2", previous_cursor),
+            &user,
+        )
+        .await;
+    assert_eq!(200, response.status().as_u16());
+    let previous_page = response_json(response).await;
+    assert_eq!(first_page["items"], previous_page["items"]);
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn books_are_filtered_by_any_or_all_of_the_tags() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let stranger = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let favourite = app.create_tag(&user, "favourite").await;
+    let to_read = app.create_tag(&user, "to read").await;
+    let strangers_tag = app.create_tag(&stranger, "mine").await;
+    for (title, tag_ids) in [
+        ("Dom Casmurro", vec![favourite, to_read]),
+        ("Helena", vec![favourite]),
+        ("Iaiá Garcia", vec![]),
+    ] {
+        let mut body = book_body(&user, shelf, title);
+        body["tag_ids"] = json!(tag_ids);
+        assert_eq!(
+            201,
+            app.post("/v1/books", &user, &body).await.status().as_u16()
+        );
+    }
+    let titles = |page: serde_json::Value| -> Vec<String> {
+        page["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|book| book["title"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let path = format!("/v1/books?tag_ids={},{}", favourite, to_read);
+    let page = response_json(app.get(&path, &user).await).await;
+    assert_eq!(vec!["Dom Casmurro", "Helena"], titles(page));
+    let page = response_json(app.get(&format!("{}&tag_match=all", path), &user).await).await;
+    assert_eq!(vec!["Dom Casmurro"], titles(page));
+
+    let mut body = book_body(&user, shelf, "Quincas Borba");
+    body["tag_ids"] = json!([strangers_tag]);
+    let response = app.post("/v1/books", &user, &body).await;
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn custom_field_values_must_match_the_field_type() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    for custom_field in [
+        json!({ "name": "Pages", "field_type": "number" }),
+        json!({ "name": "Binding", "field_type": "enum", "options": ["Hardcover", "Paperback"] }),
+    ] {
+        let response = app.post("/v1/custom-fields", &user, &custom_field).await;
+        assert_eq!(201, response.status().as_u16());
+    }
+
+    let mut body = book_body(&user, shelf, "Dom Casmurro");
+    body["custom_fields"] = json!({ "Pages": "many", "Binding": "Leather", "Year": 1899 });
+    let response = app.post("/v1/books", &user, &body).await;
+    assert_eq!(400, response.status().as_u16());
+    let validations = response_json(response).await["field_validations"].clone();
+    assert!(validations["custom_fields.Pages"].is_string());
+    assert!(validations["custom_fields.Binding"].is_string());
+    assert!(validations["custom_fields.Year"].is_string());
+
+    body["custom_fields"] = json!({ "pages": 256, "Binding": "paperback" });
+    let response = app.post("/v1/books", &user, &body).await;
+    assert_eq!(201, response.status().as_u16());
+    assert_eq!(
+        json!({ "Pages": 256.0, "Binding": "Paperback" }),
+        response_json(response).await["custom_fields"]
+    );
+}
@@ -0,0 +1,265 @@
+use serde_json::{json, Value};
+
+use crate::helpers::{response_json, spawn_app, TestApp, TestUser};
+
+async fn find_book(app: &TestApp, user: &TestUser, book_id: u64) -> Value {
+    let response = app.get(&format!("/v1/books/{}", book_id), user).await;
+    assert_eq!(200, response.status().as_u16());
+    response_json(response).await
+}
+
+async fn count_book_updates(app: &TestApp, book_id: u64) -> i64 {
+    sqlx::query_scalar(
+        "SELECT COUNT(*) FROM audit_entries WHERE entity_type = 'book' AND entity_id = ? AND action = 'update'",
+    )
+    .bind(book_id)
+    .fetch_one(&app.db_pool)
+    .await
+    .expect("Failed to count audit entries.")
+}
+
+fn item_status(result: &Value, book_id: u64) -> String {
+    result["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["book_id"] == json!(book_id))
+        .map(|item| item["status"].as_str().unwrap().to_string())
+        .unwrap()
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn listed_books_are_moved_to_the_informed_location() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let attic = app.create_location(&user, "Attic").await;
+    let first = app.create_book(&user, shelf, "Dom Casmurro").await["id"]
+        .as_u64()
+        .unwrap();
+    let second = app.create_book(&user, shelf, "Helena").await["id"]
+        .as_u64()
+        .unwrap();
+    let untouched = app.create_book(&user, shelf, "Iaiá Garcia").await["id"]
+        .as_u64()
+        .unwrap();
+
+    let response = app
+        .post(
+            "/v1/books/bulk",
+            &user,
+            &json!({ "action": "move_to_location", "ids": [first, second], "location_id": attic }),
+        )
+        .await;
+
+    assert_eq!(200, response.status().as_u16());
+    let result = response_json(response).await;
+    assert_eq!(json!(true), result["committed"]);
+    assert_eq!(json!(2), result["succeeded_items"]);
+    assert_eq!("updated", item_status(&result, first));
+    assert_eq!("updated", item_status(&result, second));
+    assert_eq!(
+        json!(attic),
+        find_book(&app, &user, first).await["location"]["id"]
+    );
+    assert_eq!(
+        json!(attic),
+        find_book(&app, &user, second).await["location"]["id"]
+    );
+    assert_eq!(
+        json!(shelf),
+        find_book(&app, &user, untouched).await["location"]["id"]
+    );
+    assert_eq!(1, count_book_updates(&app, first).await);
+    assert_eq!(0, count_book_updates(&app, untouched).await);
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn books_matching_the_filter_are_changed() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let attic = app.create_location(&user, "Attic").await;
+    let classics = app.create_collection(&user, "Classics").await;
+    let on_shelf = app.create_book(&user, shelf, "Dom Casmurro").await["id"]
+        .as_u64()
+        .unwrap();
+    let in_attic = app.create_book(&user, attic, "Helena").await["id"]
+        .as_u64()
+        .unwrap();
+
+    let response = app
+        .post(
+            "/v1/books/bulk",
+            &user,
+            &json!({
+                "action": "set_collection",
+                "filter": { "location_id": shelf },
+                "collection_id": classics,
+            }),
+        )
+        .await;
+
+    assert_eq!(200, response.status().as_u16());
+    let result = response_json(response).await;
+    assert_eq!(json!(1), result["total_items"]);
+    assert_eq!("updated", item_status(&result, on_shelf));
+    assert_eq!(
+        json!(classics),
+        find_book(&app, &user, on_shelf).await["collection"]["id"]
+    );
+    assert_eq!(
+        Value::Null,
+        find_book(&app, &user, in_attic).await["collection"]
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn ids_and_filter_cannot_be_informed_together() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+
+    let response = app
+        .post(
+            "/v1/books/bulk",
+            &user,
+            &json!({ "action": "delete", "ids": [1], "filter": {} }),
+        )
+        .await;
+
+    assert_eq!(400, response.status().as_u16());
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn books_of_other_libraries_roll_the_whole_operation_back() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let stranger = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let attic = app.create_location(&user, "Attic").await;
+    let strangers_shelf = app.create_location(&stranger, "Shelf").await;
+    let own_book = app.create_book(&user, shelf, "Dom Casmurro").await["id"]
+        .as_u64()
+        .unwrap();
+    let strangers_book = app.create_book(&stranger, strangers_shelf, "Helena").await["id"]
+        .as_u64()
+        .unwrap();
+
+    let response = app
+        .post(
+            "/v1/books/bulk",
+            &user,
+            &json!({
+                "action": "move_to_location",
+                "ids": [own_book, strangers_book],
+                "location_id": attic,
+            }),
+        )
+        .await;
+
+    assert_eq!(422, response.status().as_u16());
+    let result = response_json(response).await;
+    assert_eq!(json!(false), result["committed"]);
+    assert_eq!(json!(1), result["failed_items"]);
+    assert_eq!("rolled_back", item_status(&result, own_book));
+    assert_eq!("not_found", item_status(&result, strangers_book));
+    assert_eq!(
+        json!(shelf),
+        find_book(&app, &user, own_book).await["location"]["id"]
+    );
+    assert_eq!(0, count_book_updates(&app, own_book).await);
+    assert_eq!(
+        json!(strangers_shelf),
+        find_book(&app, &stranger, strangers_book).await["location"]["id"]
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn locations_of_other_libraries_are_rejected() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let stranger = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let strangers_shelf = app.create_location(&stranger, "Shelf").await;
+    let book = app.create_book(&user, shelf, "Dom Casmurro").await["id"]
+        .as_u64()
+        .unwrap();
+
+    let response = app
+        .post(
+            "/v1/books/bulk",
+            &user,
+            &json!({ "action": "move_to_location", "ids": [book], "location_id": strangers_shelf }),
+        )
+        .await;
+
+    assert_eq!(403, response.status().as_u16());
+    assert_eq!(
+        json!(shelf),
+        find_book(&app, &user, book).await["location"]["id"]
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn tags_are_added_and_removed() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let favourite = app.create_tag(&user, "favourite").await;
+    let book = app.create_book(&user, shelf, "Dom Casmurro").await;
+    let book_id = book["id"].as_u64().unwrap();
+
+    let response = app
+        .post(
+            "/v1/books/bulk",
+            &user,
+            &json!({ "action": "add_tags", "ids": [book_id], "tag_ids": [favourite] }),
+        )
+        .await;
+
+    assert_eq!(200, response.status().as_u16());
+    let tagged_book = find_book(&app, &user, book_id).await;
+    assert_eq!(json!(favourite), tagged_book["tags"][0]["id"]);
+    assert!(tagged_book["version"].as_u64() > book["version"].as_u64());
+
+    let response = app
+        .post(
+            "/v1/books/bulk",
+            &user,
+            &json!({ "action": "remove_tags", "ids": [book_id], "tag_ids": [favourite] }),
+        )
+        .await;
+
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(json!([]), find_book(&app, &user, book_id).await["tags"]);
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn tags_of_other_users_are_rejected() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let stranger = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let strangers_tag = app.create_tag(&stranger, "favourite").await;
+    let book_id = app.create_book(&user, shelf, "Dom Casmurro").await["id"]
+        .as_u64()
+        .unwrap();
+
+    let response = app
+        .post(
+            "/v1/books/bulk",
+            &user,
+            &json!({ "action": "add_tags", "ids": [book_id], "tag_ids": [strangers_tag] }),
+        )
+        .await;
+
+    assert_eq!(404, response.status().as_u16());
+    assert_eq!(json!([]), find_book(&app, &user, book_id).await["tags"]);
+}
@@ -0,0 +1,100 @@
+use serde_json::{json, Value};
+use std::time::Duration;
+
+use crate::helpers::{spawn_app, TestApp, TestUser};
+
+async fn follow_library(app: &TestApp, user: &TestUser) -> reqwest::Response {
+    let response = app.get("/v1/events", user).await;
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(
+        "text/event-stream",
+        response.headers()["content-type"].to_str().unwrap()
+    );
+    response
+}
+
+async fn next_change(stream: &mut reqwest::Response) -> Value {
+    let mut buffer = String::new();
+    loop {
+        if let Some(end) = buffer.find("\n\n") {
+            let frame: String = buffer.drain(..end + 2).collect();
+            if let Some(data) = frame
+                .strip_prefix("event: change\ndata: ")
+                .map(str::trim_end)
+            {
+                return serde_json::from_str(data).expect("Event data is not JSON.");
+            }
+            continue;
+        }
+        let chunk = tokio::time::timeout(Duration::from_secs(10), stream.chunk())
+            .await
+            .expect("No event received.")
+            .expect("Failed to read the stream.")
+            .expect("Stream ended.");
+        buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn book_updates_are_streamed_to_library_members() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let book = app.create_book(&user, shelf, "Dom Casmurro").await;
+    let mut stream = follow_library(&app, &user).await;
+
+    let response = app
+        .patch(
+            &format!("/v1/books/{}", book["id"]),
+            &user,
+            book["version"].as_u64().unwrap(),
+            &json!({ "year": "1899" }),
+        )
+        .await;
+    assert_eq!(200, response.status().as_u16());
+
+    let event = next_change(&mut stream).await;
+    assert_eq!(json!("book"), event["entity_type"]);
+    assert_eq!(book["id"], event["entity_id"]);
+    assert_eq!(json!("update"), event["action"]);
+    assert_eq!(json!(user.id), event["actor_id"]);
+    assert_eq!(
+        json!({ "before": null, "after": "1899" }),
+        event["changes"]["year"]
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn changes_of_other_libraries_are_not_streamed() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let stranger = app.create_user().await;
+    let mut stream = follow_library(&app, &user).await;
+
+    app.create_location(&stranger, "Shelf").await;
+    let shelf = app.create_location(&user, "Attic").await;
+
+    let event = next_change(&mut stream).await;
+    assert_eq!(json!("location"), event["entity_type"]);
+    assert_eq!(json!(shelf), event["entity_id"]);
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn libraries_of_others_cant_be_followed() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let stranger = app.create_user().await;
+    let shelf = app.create_location(&stranger, "Shelf").await;
+    let library_id = app.create_book(&stranger, shelf, "Helena").await["library_id"]
+        .as_u64()
+        .unwrap();
+
+    let response = app
+        .get(&format!("/v1/events?library_id={}", library_id), &user)
+        .await;
+
+    assert_eq!(404, response.status().as_u16());
+}
@@ -0,0 +1,102 @@
+use serde_json::json;
+
+use crate::helpers::{book_body, response_json, spawn_app, TestApp, TestUser};
+
+async fn exported_titles(app: &TestApp, user: &TestUser, params: &str) -> Vec<String> {
+    let response = app
+        .get(&format!("/v1/export?format=json{}", params), user)
+        .await;
+    assert_eq!(200, response.status().as_u16());
+    let books = response_json(response).await;
+    books
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|book| book["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn exports_apply_the_listing_filters() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let favourite = app.create_tag(&user, "favourite").await;
+    let response = app
+        .post(
+            "/v1/custom-fields",
+            &user,
+            &json!({ "name": "Pages", "field_type": "number" }),
+        )
+        .await;
+    assert_eq!(201, response.status().as_u16());
+    for (title, pages, tag_ids) in [
+        ("Dom Casmurro", 256, vec![favourite]),
+        ("Helena", 320, vec![]),
+        ("Iaiá Garcia", 180, vec![favourite]),
+    ] {
+        let mut body = book_body(&user, shelf, title);
+        body["tag_ids"] = json!(tag_ids);
+        body["custom_fields"] = json!({ "Pages": pages });
+        let response = app.post("/v1/books", &user, &body).await;
+        assert_eq!(201, response.status().as_u16());
+    }
+
+    assert_eq!(
+        vec!["Dom Casmurro", "Iaiá Garcia"],
+        exported_titles(&app, &user, &format!("&tag_ids={}", favourite)).await
+    );
+    assert_eq!(
+        vec!["Dom Casmurro", "Helena"],
+        exported_titles(&app, &user, "&custom_field=Pages&custom_field_min=200").await
+    );
+    assert_eq!(
+        vec!["Helena", "Dom Casmurro", "Iaiá Garcia"],
+        exported_titles(&app, &user, "&sort_custom_field=Pages&sort_direction=desc").await
+    );
+    assert_eq!(
+        vec!["Iaiá Garcia"],
+        exported_titles(
+            &app,
+            &user,
+            &format!(
+                "&tag_ids={}&custom_field=Pages&custom_field_max=200",
+                favourite
+            )
+        )
+        .await
+    );
+
+    let response = app.get("/v1/export?format=json&tag_ids=abc", &user).await;
+    assert_eq!(400, response.status().as_u16());
+    let response = app
+        .get(
+            "/v1/export?format=json&custom_field=Missing&custom_field_value=1",
+            &user,
+        )
+        .await;
+    assert_eq!(400, response.status().as_u16());
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn csv_exports_escape_formulas() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    app.create_book(&user, shelf, "=HYPERLINK(\"http://evil.test\")")
+        .await;
+
+    let response = app.get("/v1/export?format=csv", &user).await;
+
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(
+        "text/csv; charset=utf-8",
+        response.headers()["content-type"].to_str().unwrap()
+    );
+    let content = response.text().await.unwrap();
+    let mut reader = csv::Reader::from_reader(content.as_bytes());
+    let record = reader.records().next().unwrap().unwrap();
+    assert_eq!("'=HYPERLINK(\"http://evil.test\")", &record[1]);
+}
@@ -0,0 +1,128 @@
+use serde_json::{json, Value};
+
+use crate::helpers::{response_json, spawn_app, TestApp, TestUser};
+
+async fn friendships(app: &TestApp, user: &TestUser) -> Vec<Value> {
+    let response = app.get("/v1/friends", user).await;
+    assert_eq!(200, response.status().as_u16());
+    response_json(response).await["friendships"]
+        .as_array()
+        .unwrap()
+        .clone()
+}
+
+async fn send_friend_request(app: &TestApp, user: &TestUser, email: &str) -> u16 {
+    app.post("/v1/friends/requests", user, &json!({ "email": email }))
+        .await
+        .status()
+        .as_u16()
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn friend_requests_dont_tell_whether_an_account_exists() {
+    let app = spawn_app().await;
+    let requester = app.create_user().await;
+    let addressee = app.create_user().await;
+
+    assert_eq!(
+        202,
+        send_friend_request(&app, &requester, "nobody@librarian.test").await
+    );
+    assert_eq!(
+        202,
+        send_friend_request(&app, &requester, &addressee.email).await
+    );
+    assert_eq!(
+        202,
+        send_friend_request(&app, &requester, &addressee.email).await
+    );
+    assert!(friendships(&app, &requester).await.is_empty());
+
+    let received = friendships(&app, &addressee).await;
+    assert_eq!(1, received.len());
+    assert_eq!(json!(requester.id), received[0]["user_id"]);
+    assert_eq!(json!("incoming"), received[0]["direction"]);
+    assert_eq!(
+        422,
+        send_friend_request(&app, &requester, &requester.email).await
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn only_the_addressee_accepts_a_friend_request() {
+    let app = spawn_app().await;
+    let requester = app.create_user().await;
+    let addressee = app.create_user().await;
+    let stranger = app.create_user().await;
+    send_friend_request(&app, &requester, &addressee.email).await;
+    let friendship_id = friendships(&app, &addressee).await[0]["id"].clone();
+    let accept_path = format!("/v1/friends/requests/{}/accept", friendship_id);
+
+    let response = app.post(&accept_path, &requester, &json!({})).await;
+    assert_eq!(409, response.status().as_u16());
+    let response = app.post(&accept_path, &stranger, &json!({})).await;
+    assert_eq!(404, response.status().as_u16());
+    let response = app.post(&accept_path, &addressee, &json!({})).await;
+    assert_eq!(200, response.status().as_u16());
+
+    let friends = friendships(&app, &requester).await;
+    assert_eq!(json!("accepted"), friends[0]["status"]);
+    assert_eq!(json!("outgoing"), friends[0]["direction"]);
+    assert_eq!(
+        409,
+        send_friend_request(&app, &requester, &addressee.email).await
+    );
+    let response = app
+        .delete(&format!("/v1/friends/{}", friendship_id), &stranger)
+        .await;
+    assert_eq!(404, response.status().as_u16());
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn friends_only_see_libraries_shared_with_friends() {
+    let app = spawn_app().await;
+    let owner = app.create_user().await;
+    let friend = app.create_user().await;
+    let shelf = app.create_location(&owner, "Shelf").await;
+    let book = app.create_book(&owner, shelf, "Dom Casmurro").await;
+    let library_id = book["library_id"].as_u64().unwrap();
+    let books_path = format!(
+        "/v1/friends/users/{}/libraries/{}/books",
+        owner.id, library_id
+    );
+
+    assert_eq!(404, app.get(&books_path, &friend).await.status().as_u16());
+    send_friend_request(&app, &friend, &owner.email).await;
+    let friendship_id = friendships(&app, &owner).await[0]["id"].clone();
+    app.post(
+        &format!("/v1/friends/requests/{}/accept", friendship_id),
+        &owner,
+        &json!({}),
+    )
+    .await;
+    // Libraries are private until their owner shares them
+    assert_eq!(404, app.get(&books_path, &friend).await.status().as_u16());
+
+    let response = app
+        .api_client
+        .put(format!(
+            "{}/v1/libraries/{}/visibility",
+            &app.address, library_id
+        ))
+        .bearer_auth(&owner.token)
+        .header("Content-Type", "application/json")
+        .body(json!({ "visibility": "friends" }).to_string())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(200, response.status().as_u16());
+    let response = app.get(&books_path, &friend).await;
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(
+        json!("Dom Casmurro"),
+        response_json(response).await["items"][0]["title"]
+    );
+}
@@ -0,0 +1,114 @@
+use serde_json::{json, Value};
+
+use crate::helpers::{response_json, spawn_app, TestApp, TestUser};
+
+const LIBRARY_BOOKS_QUERY: &str = r#"
+    query LibraryBooks($libraryId: Int) {
+        books(libraryId: $libraryId) { items { id title libraryId } }
+        locations(libraryId: $libraryId) { id name }
+    }
+"#;
+
+async fn execute(app: &TestApp, user: &TestUser, query: &str, variables: Value) -> Value {
+    let response = app
+        .post(
+            "/graphql",
+            user,
+            &json!({ "query": query, "variables": variables }),
+        )
+        .await;
+    assert_eq!(200, response.status().as_u16());
+    response_json(response).await
+}
+
+fn error_code(result: &Value) -> Value {
+    result["errors"][0]["extensions"]["code"].clone()
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn members_read_the_books_of_shared_libraries() {
+    let app = spawn_app().await;
+    let owner = app.create_user().await;
+    let viewer = app.create_user().await;
+    let shelf = app.create_location(&owner, "Shelf").await;
+    let book = app.create_book(&owner, shelf, "Dom Casmurro").await;
+    let library_id = book["library_id"].as_u64().unwrap();
+    app.add_library_member(library_id, &viewer, "viewer").await;
+
+    let result = execute(
+        &app,
+        &viewer,
+        LIBRARY_BOOKS_QUERY,
+        json!({ "libraryId": library_id }),
+    )
+    .await;
+
+    assert_eq!(Value::Null, result["errors"]);
+    assert_eq!(book["id"], result["data"]["books"]["items"][0]["id"]);
+    assert_eq!(
+        json!(library_id),
+        result["data"]["books"]["items"][0]["libraryId"]
+    );
+    assert_eq!(json!("Shelf"), result["data"]["locations"][0]["name"]);
+
+    let result = execute(
+        &app,
+        &viewer,
+        "query Book($id: Int!) { book(id: $id) { title } }",
+        json!({ "id": book["id"] }),
+    )
+    .await;
+
+    assert_eq!(json!("Dom Casmurro"), result["data"]["book"]["title"]);
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn libraries_of_others_are_not_exposed() {
+    let app = spawn_app().await;
+    let owner = app.create_user().await;
+    let stranger = app.create_user().await;
+    let shelf = app.create_location(&owner, "Shelf").await;
+    let book = app.create_book(&owner, shelf, "Dom Casmurro").await;
+    let library_id = book["library_id"].as_u64().unwrap();
+
+    let result = execute(
+        &app,
+        &stranger,
+        LIBRARY_BOOKS_QUERY,
+        json!({ "libraryId": library_id }),
+    )
+    .await;
+
+    assert_eq!(Value::Null, result["data"]);
+    assert_eq!(json!(404), error_code(&result));
+
+    let result = execute(
+        &app,
+        &stranger,
+        "query Book($id: Int!) { book(id: $id) { title } }",
+        json!({ "id": book["id"] }),
+    )
+    .await;
+
+    assert_eq!(Value::Null, result["data"]);
+    assert_eq!(json!(404), error_code(&result));
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn anonymous_requests_are_rejected() {
+    let app = spawn_app().await;
+
+    let response = app
+        .api_client
+        .post(format!("{}/graphql", &app.address))
+        .header("Content-Type", "application/json")
+        .body(json!({ "query": "{ me { id } }" }).to_string())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(401, response.status().as_u16());
+}
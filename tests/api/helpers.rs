@@ -0,0 +1,230 @@
+use new_online_librarian_backend::{
+    configuration::{get_configuration, DatabaseSettings},
+    startup::run,
+    telemetry::{get_subscriber, init_subscriber},
+};
+use once_cell::sync::Lazy;
+use serde_json::{json, Value};
+use sqlx::{mysql::MySqlPoolOptions, Connection, Executor, MySqlConnection, MySqlPool};
+use std::net::TcpListener;
+
+// Ensure that the `tracing` stack is only initialised once using `once_cell`
+static TRACING: Lazy<()> = Lazy::new(|| {
+    let default_filter_level = "info".to_string();
+    let subscriber_name = "test".to_string();
+    if std::env::var("TEST_LOG").is_ok() {
+        let subscriber = get_subscriber(subscriber_name, default_filter_level, std::io::stdout);
+        init_subscriber(subscriber);
+    } else {
+        let subscriber = get_subscriber(subscriber_name, default_filter_level, std::io::sink);
+        init_subscriber(subscriber);
+    };
+});
+
+pub struct TestApp {
+    pub address: String,
+    pub db_pool: MySqlPool,
+    pub api_client: reqwest::Client,
+}
+
+pub struct TestUser {
+    pub id: u64,
+    pub email: String,
+    pub token: String,
+}
+
+pub async fn spawn_app() -> TestApp {
+    Lazy::force(&TRACING);
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind address.");
+    let port = listener.local_addr().unwrap().port();
+    let address = format!("http://127.0.0.1:{}", port);
+    let mut configuration = get_configuration().expect("Failed to read configuration.");
+    // Every test runs against its own database
+    configuration.database.database_name = format!("test_{:016x}", rand::random::<u64>());
+    let connection_pool = configure_database(&configuration.database).await;
+
+    let server = run(
+        listener,
+        connection_pool.clone(),
+        configuration.token,
+        configuration.graphql,
+        configuration.login_rate_limit,
+        configuration.oidc,
+        configuration.webhooks,
+    )
+    .expect("Failed to bind address");
+
+    tokio::spawn(server);
+
+    TestApp {
+        address,
+        db_pool: connection_pool,
+        api_client: reqwest::Client::new(),
+    }
+}
+
+async fn configure_database(config: &DatabaseSettings) -> MySqlPool {
+    let mut connection = MySqlConnection::connect_with(&config.without_db())
+        .await
+        .expect("Failed to connect to MySQL.");
+    connection
+        .execute(format!("CREATE DATABASE `{}`;", config.database_name).as_str())
+        .await
+        .expect("Failed to create database.");
+
+    let connection_pool = MySqlPoolOptions::new()
+        .acquire_timeout(std::time::Duration::from_secs(2))
+        .connect_with(config.connection_options())
+        .await
+        .expect("Failed to connect to MySQL.");
+    sqlx::migrate!("./migrations")
+        .run(&connection_pool)
+        .await
+        .expect("Failed to migrate the database.");
+    connection_pool
+}
+
+pub async fn response_json(response: reqwest::Response) -> Value {
+    let body = response.bytes().await.expect("Failed to read response.");
+    serde_json::from_slice(&body).expect("Response is not JSON.")
+}
+
+impl TestApp {
+    pub async fn post(&self, path: &str, user: &TestUser, body: &Value) -> reqwest::Response {
+        self.api_client
+            .post(format!("{}{}", &self.address, path))
+            .bearer_auth(&user.token)
+            .header("Content-Type", "application/json")
+            .body(body.to_string())
+            .send()
+            .await
+            .expect("Failed to execute request.")
+    }
+
+    pub async fn get(&self, path: &str, user: &TestUser) -> reqwest::Response {
+        self.api_client
+            .get(format!("{}{}", &self.address, path))
+            .bearer_auth(&user.token)
+            .send()
+            .await
+            .expect("Failed to execute request.")
+    }
+
+    pub async fn patch(
+        &self,
+        path: &str,
+        user: &TestUser,
+        version: u64,
+        body: &Value,
+    ) -> reqwest::Response {
+        self.api_client
+            .patch(format!("{}{}", &self.address, path))
+            .bearer_auth(&user.token)
+            .header("Content-Type", "application/merge-patch+json")
+            .header("If-Match", format!("\"{}\"", version))
+            .body(body.to_string())
+            .send()
+            .await
+            .expect("Failed to execute request.")
+    }
+
+    pub async fn delete(&self, path: &str, user: &TestUser) -> reqwest::Response {
+        self.api_client
+            .delete(format!("{}{}", &self.address, path))
+            .bearer_auth(&user.token)
+            .header("If-Match", "*")
+            .send()
+            .await
+            .expect("Failed to execute request.")
+    }
+
+    pub async fn add_library_member(&self, library_id: u64, user: &TestUser, role: &str) {
+        sqlx::query("INSERT INTO library_members (library_id, user_id, role) VALUES (?, ?, ?)")
+            .bind(library_id)
+            .bind(user.id)
+            .bind(role)
+            .execute(&self.db_pool)
+            .await
+            .expect("Failed to add library member.");
+    }
+
+    pub async fn create_user(&self) -> TestUser {
+        let email = format!("reader{:08x}@librarian.test", rand::random::<u32>());
+        let password = "Sup3rSecret";
+        let response = self
+            .api_client
+            .post(format!("{}/v1/users", &self.address))
+            .header("Content-Type", "application/json")
+            .body(json!({ "email": email, "password": password, "name": "Reader" }).to_string())
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(201, response.status().as_u16());
+        let id = response_json(response).await["id"].as_u64().unwrap();
+
+        let response = self
+            .api_client
+            .post(format!("{}/v1/auth/login", &self.address))
+            .header("Content-Type", "application/json")
+            .body(json!({ "email": email, "password": password }).to_string())
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(200, response.status().as_u16());
+        let token = response_json(response).await["access_token"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        TestUser { id, email, token }
+    }
+
+    pub async fn create_location(&self, user: &TestUser, name: &str) -> u64 {
+        let response = self
+            .post(
+                "/v1/locations",
+                user,
+                &json!({ "name": name, "user_id": user.id }),
+            )
+            .await;
+        assert_eq!(201, response.status().as_u16());
+        response_json(response).await["id"].as_u64().unwrap()
+    }
+
+    pub async fn create_collection(&self, user: &TestUser, name: &str) -> u64 {
+        let response = self
+            .post(
+                "/v1/collections",
+                user,
+                &json!({ "name": name, "user_id": user.id }),
+            )
+            .await;
+        assert_eq!(201, response.status().as_u16());
+        response_json(response).await["id"].as_u64().unwrap()
+    }
+
+    pub async fn create_tag(&self, user: &TestUser, name: &str) -> u64 {
+        let response = self.post("/v1/tags", user, &json!({ "name": name })).await;
+        assert_eq!(201, response.status().as_u16());
+        response_json(response).await["id"].as_u64().unwrap()
+    }
+
+    pub async fn create_book(&self, user: &TestUser, location_id: u64, title: &str) -> Value {
+        let response = self
+            .post("/v1/books", user, &book_body(user, location_id, title))
+            .await;
+        assert_eq!(201, response.status().as_u16());
+        response_json(response).await
+    }
+}
+
+pub fn book_body(user: &TestUser, location_id: u64, title: &str) -> Value {
+    json!({
+        "title": title,
+        "authors": [{ "name": "Machado de Assis" }],
+        "languages": [{ "name": "Portuguese", "code": "pt" }],
+        "publisher": "Garnier",
+        "location_id": location_id,
+        "user_id": user.id,
+    })
+}
@@ -0,0 +1,63 @@
+use serde_json::{json, Value};
+
+use crate::helpers::{response_json, spawn_app, TestApp, TestUser};
+
+const CSV: &str = "title,authors,publisher,languages,location,collection\n\
+                   Dom Casmurro,Machado de Assis,Garnier,Portuguese,Shelf,Classics\n";
+
+async fn list(app: &TestApp, user: &TestUser, path: &str) -> Value {
+    let response = app.get(path, user).await;
+    assert_eq!(200, response.status().as_u16());
+    response_json(response).await
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn dry_runs_report_the_import_without_writing_it() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+
+    let response = app
+        .post(
+            "/v1/imports/csv",
+            &user,
+            &json!({ "content": CSV, "dry_run": true }),
+        )
+        .await;
+
+    assert_eq!(200, response.status().as_u16());
+    let report = response_json(response).await;
+    assert_eq!(json!(true), report["dry_run"]);
+    assert_eq!(json!(1), report["valid_rows"]);
+    assert_eq!(json!(0), report["imported_rows"]);
+    assert_eq!(json!(["Shelf"]), report["created_locations"]);
+    assert_eq!(json!(["Classics"]), report["created_collections"]);
+    assert_eq!(json!([]), list(&app, &user, "/v1/books").await["items"]);
+    assert_eq!(
+        json!([]),
+        list(&app, &user, "/v1/locations").await["locations"]
+    );
+    assert_eq!(
+        json!([]),
+        list(&app, &user, "/v1/collections").await["collections"]
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn imports_create_the_books_with_their_locations_and_collections() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+
+    let response = app
+        .post("/v1/imports/csv", &user, &json!({ "content": CSV }))
+        .await;
+
+    assert_eq!(201, response.status().as_u16());
+    let report = response_json(response).await;
+    assert_eq!(json!(1), report["imported_rows"]);
+    let books = list(&app, &user, "/v1/books").await;
+    assert_eq!(json!("Dom Casmurro"), books["items"][0]["title"]);
+    assert_eq!(json!("Shelf"), books["items"][0]["location"]["name"]);
+    assert_eq!(json!("Classics"), books["items"][0]["collection"]["name"]);
+}
@@ -0,0 +1,76 @@
+use serde_json::json;
+
+use crate::helpers::{book_body, spawn_app};
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn members_act_on_a_library_within_their_role() {
+    let app = spawn_app().await;
+    let owner = app.create_user().await;
+    let editor = app.create_user().await;
+    let viewer = app.create_user().await;
+    let stranger = app.create_user().await;
+    let shelf = app.create_location(&owner, "Shelf").await;
+    let book = app.create_book(&owner, shelf, "Dom Casmurro").await;
+    let library_id = book["library_id"].as_u64().unwrap();
+    app.add_library_member(library_id, &editor, "editor").await;
+    app.add_library_member(library_id, &viewer, "viewer").await;
+    let books_path = format!("/v1/books?library_id={}", library_id);
+
+    assert_eq!(200, app.get(&books_path, &viewer).await.status().as_u16());
+    assert_eq!(404, app.get(&books_path, &stranger).await.status().as_u16());
+
+    let response = app
+        .post("/v1/books", &viewer, &book_body(&viewer, shelf, "Helena"))
+        .await;
+    assert_eq!(403, response.status().as_u16());
+    let response = app
+        .post(
+            "/v1/books",
+            &stranger,
+            &book_body(&stranger, shelf, "Helena"),
+        )
+        .await;
+    assert_eq!(404, response.status().as_u16());
+    let response = app
+        .post("/v1/books", &editor, &book_body(&editor, shelf, "Helena"))
+        .await;
+    assert_eq!(201, response.status().as_u16());
+
+    let response = app
+        .post(
+            &format!("/v1/libraries/{}/invitations", library_id),
+            &editor,
+            &json!({ "email": "friend@librarian.test" }),
+        )
+        .await;
+    assert_eq!(403, response.status().as_u16());
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn only_owners_remove_other_members() {
+    let app = spawn_app().await;
+    let owner = app.create_user().await;
+    let editor = app.create_user().await;
+    let viewer = app.create_user().await;
+    let shelf = app.create_location(&owner, "Shelf").await;
+    let book = app.create_book(&owner, shelf, "Dom Casmurro").await;
+    let library_id = book["library_id"].as_u64().unwrap();
+    app.add_library_member(library_id, &editor, "editor").await;
+    app.add_library_member(library_id, &viewer, "viewer").await;
+    let member_path = |user_id: u64| format!("/v1/libraries/{}/members/{}", library_id, user_id);
+
+    let response = app.delete(&member_path(viewer.id), &editor).await;
+    assert_eq!(403, response.status().as_u16());
+    let response = app.delete(&member_path(owner.id), &owner).await;
+    assert_eq!(409, response.status().as_u16());
+    let response = app.delete(&member_path(viewer.id), &owner).await;
+    assert_eq!(200, response.status().as_u16());
+    // Members can always leave
+    let response = app.delete(&member_path(editor.id), &editor).await;
+    assert_eq!(200, response.status().as_u16());
+
+    let books_path = format!("/v1/books?library_id={}", library_id);
+    assert_eq!(404, app.get(&books_path, &viewer).await.status().as_u16());
+}
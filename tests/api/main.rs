@@ -0,0 +1,18 @@
+mod audit;
+mod backups;
+mod books;
+mod books_bulk;
+mod events;
+mod exports;
+mod friends;
+mod graphql;
+mod helpers;
+mod imports;
+mod libraries;
+mod notes;
+mod share_links;
+mod sync;
+mod trash;
+mod two_factor;
+mod versions;
+mod webhooks;
@@ -0,0 +1,113 @@
+use serde_json::json;
+
+use crate::helpers::{response_json, spawn_app, TestApp, TestUser};
+
+async fn create_note(
+    app: &TestApp,
+    user: &TestUser,
+    book_id: u64,
+    body: &serde_json::Value,
+) -> u64 {
+    let response = app
+        .post(&format!("/v1/books/{}/notes", book_id), user, body)
+        .await;
+    assert_eq!(201, response.status().as_u16());
+    response_json(response).await["id"].as_u64().unwrap()
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn notes_are_only_visible_to_their_author() {
+    let app = spawn_app().await;
+    let owner = app.create_user().await;
+    let viewer = app.create_user().await;
+    let stranger = app.create_user().await;
+    let shelf = app.create_location(&owner, "Shelf").await;
+    let book = app.create_book(&owner, shelf, "Dom Casmurro").await;
+    let book_id = book["id"].as_u64().unwrap();
+    app.add_library_member(book["library_id"].as_u64().unwrap(), &viewer, "viewer")
+        .await;
+    let notes_path = format!("/v1/books/{}/notes", book_id);
+
+    let note_id = create_note(
+        &app,
+        &owner,
+        book_id,
+        &json!({ "note_type": "quote", "page": 12, "text": "Olhos de ressaca" }),
+    )
+    .await;
+    create_note(&app, &viewer, book_id, &json!({ "text": "Did she?" })).await;
+    let response = app
+        .post(&notes_path, &stranger, &json!({ "text": "Mine" }))
+        .await;
+    assert_eq!(404, response.status().as_u16());
+
+    let notes = response_json(app.get(&notes_path, &owner).await).await;
+    assert_eq!(1, notes["notes"].as_array().unwrap().len());
+    assert_eq!(json!("Olhos de ressaca"), notes["notes"][0]["text"]);
+    assert_eq!(json!(12), notes["notes"][0]["page"]);
+
+    let note_path = format!("{}/{}", notes_path, note_id);
+    let response = app
+        .api_client
+        .put(format!("{}{}", &app.address, note_path))
+        .bearer_auth(&viewer.token)
+        .header("Content-Type", "application/json")
+        .body(json!({ "text": "Changed" }).to_string())
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(404, response.status().as_u16());
+    assert_eq!(404, app.delete(&note_path, &viewer).await.status().as_u16());
+
+    let books = response_json(app.get("/v1/books", &owner).await).await;
+    assert_eq!(json!(1), books["items"][0]["note_count"]);
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn notes_are_searched_across_the_books_of_the_user() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let other = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let other_shelf = app.create_location(&other, "Shelf").await;
+    for (title, note_type, text) in [
+        (
+            "Dom Casmurro",
+            "quote",
+            "Olhos de cigana oblíqua e dissimulada",
+        ),
+        ("Helena", "summary", "A story about Helena and Estácio"),
+    ] {
+        let book = app.create_book(&user, shelf, title).await;
+        create_note(
+            &app,
+            &user,
+            book["id"].as_u64().unwrap(),
+            &json!({ "note_type": note_type, "text": text }),
+        )
+        .await;
+    }
+    let other_book = app
+        .create_book(&other, other_shelf, "Memórias Póstumas")
+        .await;
+    create_note(
+        &app,
+        &other,
+        other_book["id"].as_u64().unwrap(),
+        &json!({ "note_type": "quote", "text": "Olhos de defunto" }),
+    )
+    .await;
+
+    let page = response_json(app.get("/v1/notes?query=OLHOS", &user).await).await;
+    assert_eq!(json!(1), page["total_items"]);
+    assert_eq!(json!("Dom Casmurro"), page["items"][0]["book_title"]);
+
+    let page = response_json(app.get("/v1/notes?note_type=summary", &user).await).await;
+    assert_eq!(json!(1), page["total_items"]);
+    assert_eq!(json!("Helena"), page["items"][0]["book_title"]);
+
+    let response = app.get("/v1/notes?note_type=highlight", &user).await;
+    assert_eq!(400, response.status().as_u16());
+}
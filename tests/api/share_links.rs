@@ -0,0 +1,74 @@
+use serde_json::json;
+
+use crate::helpers::{book_body, response_json, spawn_app, TestApp};
+
+async fn get_shared_books(app: &TestApp, slug: &str) -> reqwest::Response {
+    app.api_client
+        .get(format!("{}/v1/public/{}", &app.address, slug))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn collection_links_only_show_public_data_of_the_collection() {
+    let app = spawn_app().await;
+    let owner = app.create_user().await;
+    let shelf = app.create_location(&owner, "Shelf").await;
+    let classics = app.create_collection(&owner, "Classics").await;
+    let mut body = book_body(&owner, shelf, "Dom Casmurro");
+    body["collection_id"] = json!(classics);
+    assert_eq!(
+        201,
+        app.post("/v1/books", &owner, &body).await.status().as_u16()
+    );
+    app.create_book(&owner, shelf, "Helena").await;
+
+    let response = app
+        .post(
+            "/v1/share-links",
+            &owner,
+            &json!({ "scope": "collection", "collection_id": classics }),
+        )
+        .await;
+    assert_eq!(201, response.status().as_u16());
+    let share_link = response_json(response).await;
+    let slug = share_link["slug"].as_str().unwrap();
+
+    let response = get_shared_books(&app, slug).await;
+    assert_eq!(200, response.status().as_u16());
+    let page = response_json(response).await;
+    let books = page["items"].as_array().unwrap();
+    assert_eq!(1, books.len());
+    assert_eq!(json!("Dom Casmurro"), books[0]["title"]);
+    assert_eq!(json!("Shelf"), books[0]["location"]["name"]);
+    for private_field in ["user_id", "library_id", "version", "tags", "custom_fields"] {
+        assert!(books[0].get(private_field).is_none(), "{}", private_field);
+    }
+    assert!(books[0]["location"].get("user_id").is_none());
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn revoked_links_are_not_found_and_only_members_revoke() {
+    let app = spawn_app().await;
+    let owner = app.create_user().await;
+    let stranger = app.create_user().await;
+    let shelf = app.create_location(&owner, "Shelf").await;
+    app.create_book(&owner, shelf, "Dom Casmurro").await;
+    let share_link = response_json(app.post("/v1/share-links", &owner, &json!({})).await).await;
+    let slug = share_link["slug"].as_str().unwrap();
+    let path = format!("/v1/share-links/{}", share_link["id"]);
+
+    assert_eq!(404, app.delete(&path, &stranger).await.status().as_u16());
+    assert_eq!(200, get_shared_books(&app, slug).await.status().as_u16());
+
+    let response = app.delete(&path, &owner).await;
+    assert!(response.status().is_success());
+    assert_eq!(404, get_shared_books(&app, slug).await.status().as_u16());
+    assert_eq!(
+        404,
+        get_shared_books(&app, "unknown").await.status().as_u16()
+    );
+}
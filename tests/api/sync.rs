@@ -0,0 +1,159 @@
+use serde_json::{json, Value};
+use std::time::Duration;
+
+use crate::helpers::{book_body, response_json, spawn_app, TestApp, TestUser};
+
+async fn wait_for_changes_to_settle() {
+    tokio::time::sleep(Duration::from_millis(2500)).await;
+}
+
+async fn pull(app: &TestApp, user: &TestUser, sync_token: Option<&str>) -> Value {
+    let path = match sync_token {
+        Some(sync_token) => format!("/v1/sync?sync_token={}", sync_token),
+        None => "/v1/sync".to_string(),
+    };
+    let response = app.get(&path, user).await;
+    assert_eq!(200, response.status().as_u16());
+    response_json(response).await
+}
+
+async fn push(app: &TestApp, user: &TestUser, changes: Value) -> Value {
+    let response = app
+        .post("/v1/sync", user, &json!({ "changes": changes }))
+        .await;
+    assert_eq!(200, response.status().as_u16());
+    response_json(response).await["results"][0].clone()
+}
+
+fn synced_book(changes: &Value, book_id: &Value) -> Value {
+    changes["books"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|book| &book["id"] == book_id)
+        .cloned()
+        .unwrap_or(Value::Null)
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn pulls_since_a_token_return_changes_and_tombstones() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let updated = app.create_book(&user, shelf, "Dom Casmurro").await;
+    let trashed = app.create_book(&user, shelf, "Helena").await;
+    let purged = app.create_book(&user, shelf, "Iaiá Garcia").await;
+    let untouched = app.create_book(&user, shelf, "Ressurreição").await;
+    wait_for_changes_to_settle().await;
+    let first_sync = pull(&app, &user, None).await;
+    assert_eq!(4, first_sync["books"].as_array().unwrap().len());
+    let sync_token = first_sync["sync_token"].as_str().unwrap().to_string();
+
+    let response = app
+        .patch(
+            &format!("/v1/books/{}", updated["id"]),
+            &user,
+            updated["version"].as_u64().unwrap(),
+            &json!({ "year": "1899" }),
+        )
+        .await;
+    assert_eq!(200, response.status().as_u16());
+    for book in [&trashed, &purged] {
+        let response = app
+            .delete(&format!("/v1/books/{}", book["id"]), &user)
+            .await;
+        assert!(response.status().is_success());
+    }
+    let response = app
+        .delete(&format!("/v1/trash/books/{}", purged["id"]), &user)
+        .await;
+    assert!(response.status().is_success());
+    wait_for_changes_to_settle().await;
+
+    let changes = pull(&app, &user, Some(&sync_token)).await;
+
+    assert_eq!(1, changes["books"].as_array().unwrap().len());
+    assert_eq!(json!("1899"), synced_book(&changes, &updated["id"])["year"]);
+    assert_eq!(Value::Null, synced_book(&changes, &untouched["id"]));
+    let tombstones: Vec<(Value, Value)> = changes["tombstones"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tombstone| (tombstone["entity_type"].clone(), tombstone["id"].clone()))
+        .collect();
+    assert_eq!(2, tombstones.len());
+    assert!(tombstones.contains(&(json!("book"), trashed["id"].clone())));
+    assert!(tombstones.contains(&(json!("book"), purged["id"].clone())));
+    assert_ne!(json!(sync_token), changes["sync_token"]);
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn conflicting_pushes_keep_the_latest_change() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let book = app.create_book(&user, shelf, "Dom Casmurro").await;
+    wait_for_changes_to_settle().await;
+    let base_updated_at = synced_book(&pull(&app, &user, None).await, &book["id"])["updated_at"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    // Changed offline, before the server copy below
+    let offline_changed_at = chrono::Utc::now().to_rfc3339();
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let response = app
+        .patch(
+            &format!("/v1/books/{}", book["id"]),
+            &user,
+            book["version"].as_u64().unwrap(),
+            &json!({ "year": "1899" }),
+        )
+        .await;
+    assert_eq!(200, response.status().as_u16());
+
+    let mut offline_book = book_body(&user, shelf, "Dom Casmurro");
+    offline_book["edition"] = json!("2nd");
+    let result = push(
+        &app,
+        &user,
+        json!([{
+            "entity_type": "book",
+            "operation": "upsert",
+            "id": book["id"],
+            "base_updated_at": base_updated_at,
+            "updated_at": offline_changed_at,
+            "book": offline_book,
+        }]),
+    )
+    .await;
+
+    assert_eq!(json!("conflict"), result["status"]);
+    assert_eq!(json!("server_wins"), result["resolution"]);
+    let server_book =
+        response_json(app.get(&format!("/v1/books/{}", book["id"]), &user).await).await;
+    assert_eq!(json!("1899"), server_book["year"]);
+    assert_eq!(Value::Null, server_book["edition"]);
+
+    let result = push(
+        &app,
+        &user,
+        json!([{
+            "entity_type": "book",
+            "operation": "upsert",
+            "id": book["id"],
+            "base_updated_at": base_updated_at,
+            "updated_at": chrono::Utc::now().to_rfc3339(),
+            "book": offline_book,
+        }]),
+    )
+    .await;
+
+    assert_eq!(json!("applied"), result["status"]);
+    assert_eq!(json!("client_wins"), result["resolution"]);
+    let server_book =
+        response_json(app.get(&format!("/v1/books/{}", book["id"]), &user).await).await;
+    assert_eq!(json!("2nd"), server_book["edition"]);
+}
@@ -0,0 +1,93 @@
+use serde_json::{json, Value};
+
+use crate::helpers::{response_json, spawn_app, TestApp, TestUser};
+
+async fn trash_items(app: &TestApp, user: &TestUser) -> Vec<Value> {
+    let response = app.get("/v1/trash", user).await;
+    assert_eq!(200, response.status().as_u16());
+    response_json(response).await["items"]
+        .as_array()
+        .unwrap()
+        .clone()
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn trashed_books_are_hidden_until_restored() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let book = app.create_book(&user, shelf, "Dom Casmurro").await;
+    let book_path = format!("/v1/books/{}", book["id"]);
+
+    assert_eq!(200, app.delete(&book_path, &user).await.status().as_u16());
+    assert_eq!(404, app.get(&book_path, &user).await.status().as_u16());
+    let books = response_json(app.get("/v1/books", &user).await).await;
+    assert!(books["items"].as_array().unwrap().is_empty());
+    let items = trash_items(&app, &user).await;
+    assert_eq!(1, items.len());
+    assert_eq!(json!("book"), items[0]["item_type"]);
+    assert_eq!(json!("Dom Casmurro"), items[0]["name"]);
+
+    // The location of a trashed book has no books left and can be trashed too
+    let location_path = format!("/v1/locations/{}", shelf);
+    assert_eq!(
+        200,
+        app.delete(&location_path, &user).await.status().as_u16()
+    );
+    let restore_book_path = format!("/v1/trash/books/{}/restore", book["id"]);
+    let response = app.post(&restore_book_path, &user, &json!({})).await;
+    assert_eq!(409, response.status().as_u16());
+
+    let restore_location_path = format!("/v1/trash/locations/{}/restore", shelf);
+    let response = app.post(&restore_location_path, &user, &json!({})).await;
+    assert_eq!(200, response.status().as_u16());
+    let response = app.post(&restore_book_path, &user, &json!({})).await;
+    assert_eq!(200, response.status().as_u16());
+    assert_eq!(200, app.get(&book_path, &user).await.status().as_u16());
+    assert!(trash_items(&app, &user).await.is_empty());
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn trashed_names_can_be_reused_but_not_restored_over() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let stranger = app.create_user().await;
+    let classics = app.create_collection(&user, "Classics").await;
+    let collection_path = format!("/v1/collections/{}", classics);
+    assert_eq!(
+        200,
+        app.delete(&collection_path, &user).await.status().as_u16()
+    );
+
+    // Trashed rows don't take part in the unique name constraint
+    let new_classics = app.create_collection(&user, "Classics").await;
+    let restore_path = format!("/v1/trash/collections/{}/restore", classics);
+    let response = app.post(&restore_path, &user, &json!({})).await;
+    assert_eq!(409, response.status().as_u16());
+
+    let purge_path = format!("/v1/trash/collections/{}", classics);
+    assert_eq!(
+        404,
+        app.delete(&purge_path, &stranger).await.status().as_u16()
+    );
+    assert_eq!(200, app.delete(&purge_path, &user).await.status().as_u16());
+    assert!(trash_items(&app, &user).await.is_empty());
+    let response = app.post(&restore_path, &user, &json!({})).await;
+    assert_eq!(404, response.status().as_u16());
+
+    // Trashing the new collection too is fine once the old one is gone
+    let new_collection_path = format!("/v1/collections/{}", new_classics);
+    assert_eq!(
+        200,
+        app.delete(&new_collection_path, &user)
+            .await
+            .status()
+            .as_u16()
+    );
+    let response = app
+        .post("/v1/trash/tags/1/restore", &user, &json!({}))
+        .await;
+    assert_eq!(400, response.status().as_u16());
+}
@@ -0,0 +1,62 @@
+use chrono::Utc;
+use new_online_librarian_backend::modules::users::domain::entities::totp::{
+    base32_decode, totp_code, totp_step,
+};
+use serde_json::json;
+
+use crate::helpers::{response_json, spawn_app};
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn disabling_two_factor_requires_a_valid_proof() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+
+    let response = app.post("/v1/auth/two-factor", &user, &json!({})).await;
+    assert_eq!(201, response.status().as_u16());
+    let secret = base32_decode(response_json(response).await["secret"].as_str().unwrap()).unwrap();
+
+    let code = totp_code(&secret, totp_step(Utc::now()));
+    let response = app
+        .post(
+            "/v1/auth/two-factor/confirm",
+            &user,
+            &json!({ "code": code }),
+        )
+        .await;
+    assert_eq!(200, response.status().as_u16());
+    let recovery_codes = response_json(response).await["recovery_codes"].clone();
+    let recovery_code = recovery_codes[0].as_str().unwrap();
+
+    let response = app
+        .post("/v1/auth/two-factor/disable", &user, &json!({}))
+        .await;
+    assert_eq!(400, response.status().as_u16());
+
+    let response = app
+        .post(
+            "/v1/auth/two-factor/disable",
+            &user,
+            &json!({ "recovery_code": "not-a-recovery-code" }),
+        )
+        .await;
+    assert_eq!(401, response.status().as_u16());
+
+    let response = app
+        .post(
+            "/v1/auth/two-factor/disable",
+            &user,
+            &json!({ "recovery_code": recovery_code }),
+        )
+        .await;
+    assert_eq!(200, response.status().as_u16());
+
+    let response = app
+        .post(
+            "/v1/auth/two-factor/disable",
+            &user,
+            &json!({ "recovery_code": recovery_code }),
+        )
+        .await;
+    assert_eq!(404, response.status().as_u16());
+}
@@ -0,0 +1,110 @@
+use serde_json::json;
+
+use crate::helpers::{book_body, response_json, spawn_app, TestApp, TestUser};
+
+async fn put_book(
+    app: &TestApp,
+    user: &TestUser,
+    book_id: u64,
+    if_match: Option<&str>,
+    body: &serde_json::Value,
+) -> reqwest::Response {
+    let mut request = app
+        .api_client
+        .put(format!("{}/v1/books/{}", &app.address, book_id))
+        .bearer_auth(&user.token)
+        .header("Content-Type", "application/json");
+    if let Some(if_match) = if_match {
+        request = request.header("If-Match", if_match);
+    }
+    request
+        .body(body.to_string())
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn stale_book_updates_are_rejected_with_the_current_book() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let book = app.create_book(&user, shelf, "Dom Casmurro").await;
+    let book_id = book["id"].as_u64().unwrap();
+    let version = book["version"].as_u64().unwrap();
+    let body = book_body(&user, shelf, "Dom Casmurro (2nd edition)");
+
+    let response = put_book(&app, &user, book_id, None, &body).await;
+    assert_eq!(428, response.status().as_u16());
+
+    let response = put_book(
+        &app,
+        &user,
+        book_id,
+        Some(&format!("\"{}\"", version)),
+        &body,
+    )
+    .await;
+    assert_eq!(201, response.status().as_u16());
+    let new_etag = response.headers()["etag"].to_str().unwrap().to_string();
+    assert_eq!(format!("\"{}\"", version + 1), new_etag);
+
+    // Another device still holding the first version
+    let response = put_book(
+        &app,
+        &user,
+        book_id,
+        Some(&format!("\"{}\"", version)),
+        &book_body(&user, shelf, "Dom Casmurro (annotated)"),
+    )
+    .await;
+    assert_eq!(412, response.status().as_u16());
+    assert_eq!(new_etag, response.headers()["etag"].to_str().unwrap());
+    let error = response_json(response).await;
+    assert_eq!(
+        json!("Dom Casmurro (2nd edition)"),
+        error["current"]["title"]
+    );
+
+    // The version may also travel in the body
+    let mut stale_body = book_body(&user, shelf, "Dom Casmurro (annotated)");
+    stale_body["version"] = json!(version);
+    let response = put_book(&app, &user, book_id, None, &stale_body).await;
+    assert_eq!(412, response.status().as_u16());
+    stale_body["version"] = json!(version + 1);
+    let response = put_book(&app, &user, book_id, None, &stale_body).await;
+    assert_eq!(201, response.status().as_u16());
+}
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn stale_deletes_are_rejected() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let classics = app.create_collection(&user, "Classics").await;
+    let collection_path = format!("{}/v1/collections/{}", &app.address, classics);
+
+    let response = app
+        .api_client
+        .delete(&collection_path)
+        .bearer_auth(&user.token)
+        .header("If-Match", "\"999\"")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(412, response.status().as_u16());
+    assert_eq!(
+        json!("Classics"),
+        response_json(response).await["current"]["name"]
+    );
+
+    let response = app
+        .api_client
+        .delete(&collection_path)
+        .bearer_auth(&user.token)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(428, response.status().as_u16());
+}
@@ -0,0 +1,65 @@
+use std::{collections::HashSet, sync::Arc};
+
+use new_online_librarian_backend::modules::webhooks::infra::repositories::{
+    webhook_repository::WebhookRepository, webhook_repository_mysql::WebhookRepositoryMySQL,
+};
+
+use crate::helpers::spawn_app;
+
+#[tokio::test]
+#[ignore = "requires a MySQL server"]
+async fn due_deliveries_are_claimed_by_a_single_worker() {
+    let app = spawn_app().await;
+    let user = app.create_user().await;
+    let shelf = app.create_location(&user, "Shelf").await;
+    let book = app.create_book(&user, shelf, "Dom Casmurro").await;
+    let webhook_id = sqlx::query(
+        r#"
+        INSERT INTO webhooks (user_id, library_id, url, secret, events)
+        VALUES (?, ?, 'https://hooks.example.com', 'secret', JSON_ARRAY('book.created'))
+        "#,
+    )
+    .bind(user.id)
+    .bind(book["library_id"].as_u64().unwrap())
+    .execute(&app.db_pool)
+    .await
+    .expect("Failed to create webhook.")
+    .last_insert_id();
+    for _ in 0..20 {
+        sqlx::query(
+            "INSERT INTO webhook_deliveries (webhook_id, event, payload) VALUES (?, 'book.created', JSON_OBJECT())",
+        )
+        .bind(webhook_id)
+        .execute(&app.db_pool)
+        .await
+        .expect("Failed to queue delivery.");
+    }
+
+    let webhook_repository = WebhookRepositoryMySQL::new(Arc::new(app.db_pool.clone()));
+    let (first_claim, second_claim) = tokio::join!(
+        webhook_repository.claim_due_deliveries(15, 60),
+        webhook_repository.claim_due_deliveries(15, 60),
+    );
+    let first_ids: HashSet<u64> = first_claim
+        .unwrap()
+        .iter()
+        .filter_map(|delivery| delivery.id)
+        .collect();
+    let second_ids: HashSet<u64> = second_claim
+        .unwrap()
+        .iter()
+        .filter_map(|delivery| delivery.id)
+        .collect();
+    assert!(first_ids.is_disjoint(&second_ids));
+
+    // Claimed deliveries stay with their worker until the claim expires
+    let third_ids: HashSet<u64> = webhook_repository
+        .claim_due_deliveries(20, 60)
+        .await
+        .unwrap()
+        .iter()
+        .filter_map(|delivery| delivery.id)
+        .collect();
+    assert!(third_ids.is_disjoint(&first_ids));
+    assert!(third_ids.is_disjoint(&second_ids));
+}
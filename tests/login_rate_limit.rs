@@ -0,0 +1,296 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use actix_web::{http::header, test, web, App, HttpResponse};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use new_online_librarian_backend::{
+    configuration::{LoginRateLimitSettings, RateLimitStoreBackend},
+    modules::{
+        shared::infra::clocks::clock::Clock,
+        users::{
+            infra::{
+                middlewares::login_rate_limit::LoginRateLimit,
+                stores::{
+                    rate_limit_store::RateLimitStore, rate_limit_store_memory::RateLimitStoreMemory,
+                },
+            },
+            usecases::v1::limit_login_attempts::LimitLoginAttemptsUseCaseV1,
+        },
+    },
+};
+use serde_json::{json, Value};
+
+#[derive(Clone)]
+struct ManualClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl ManualClock {
+    fn new() -> Self {
+        ManualClock {
+            now: Arc::new(Mutex::new(
+                Utc.with_ymd_and_hms(2026, 10, 19, 12, 0, 0).unwrap(),
+            )),
+        }
+    }
+
+    fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
+
+fn settings() -> LoginRateLimitSettings {
+    LoginRateLimitSettings {
+        ip_capacity: 100,
+        ip_refill_seconds: 1,
+        account_capacity: 100,
+        account_refill_seconds: 1,
+        lockout_threshold: 3,
+        lockout_base_seconds: 60,
+        lockout_max_seconds: 300,
+        trust_forwarded_for: false,
+        store: RateLimitStoreBackend::Memory,
+    }
+}
+
+fn limiter(
+    settings: LoginRateLimitSettings,
+    clock: &ManualClock,
+) -> LimitLoginAttemptsUseCaseV1<dyn RateLimitStore> {
+    LimitLoginAttemptsUseCaseV1::new(
+        Arc::new(RateLimitStoreMemory::new()),
+        settings,
+        Arc::new(clock.clone()),
+    )
+}
+
+#[tokio::test]
+async fn client_addresses_regain_attempts_over_time() {
+    let clock = ManualClock::new();
+    let limiter = limiter(
+        LoginRateLimitSettings {
+            ip_capacity: 3,
+            ip_refill_seconds: 10,
+            ..settings()
+        },
+        &clock,
+    );
+
+    for _ in 0..3 {
+        assert!(limiter.check_attempt(Some("10.0.0.1"), None).await.is_ok());
+    }
+    assert_eq!(
+        limiter.check_attempt(Some("10.0.0.1"), None).await,
+        Err(Duration::seconds(10))
+    );
+    assert!(limiter.check_attempt(Some("10.0.0.2"), None).await.is_ok());
+
+    clock.advance(Duration::seconds(4));
+    assert_eq!(
+        limiter.check_attempt(Some("10.0.0.1"), None).await,
+        Err(Duration::seconds(6))
+    );
+    clock.advance(Duration::seconds(6));
+    assert!(limiter.check_attempt(Some("10.0.0.1"), None).await.is_ok());
+    assert!(limiter.check_attempt(Some("10.0.0.1"), None).await.is_err());
+
+    // The bucket never holds more than its capacity.
+    clock.advance(Duration::hours(1));
+    for _ in 0..3 {
+        assert!(limiter.check_attempt(Some("10.0.0.1"), None).await.is_ok());
+    }
+    assert!(limiter.check_attempt(Some("10.0.0.1"), None).await.is_err());
+}
+
+#[tokio::test]
+async fn accounts_are_limited_across_client_addresses() {
+    let clock = ManualClock::new();
+    let limiter = limiter(
+        LoginRateLimitSettings {
+            account_capacity: 2,
+            account_refill_seconds: 30,
+            ..settings()
+        },
+        &clock,
+    );
+
+    assert!(limiter
+        .check_attempt(Some("10.0.0.1"), Some("reader@example.com"))
+        .await
+        .is_ok());
+    assert!(limiter
+        .check_attempt(Some("10.0.0.2"), Some(" Reader@Example.com"))
+        .await
+        .is_ok());
+    assert_eq!(
+        limiter
+            .check_attempt(Some("10.0.0.3"), Some("reader@example.com"))
+            .await,
+        Err(Duration::seconds(30))
+    );
+    assert!(limiter
+        .check_attempt(Some("10.0.0.3"), Some("other@example.com"))
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn lockouts_double_with_every_failure_up_to_the_maximum() {
+    let clock = ManualClock::new();
+    let limiter = limiter(settings(), &clock);
+    let account = "reader@example.com";
+
+    assert_eq!(limiter.record_failure(account).await, None);
+    assert_eq!(limiter.record_failure(account).await, None);
+    assert_eq!(
+        limiter.record_failure(account).await,
+        Some(Duration::seconds(60))
+    );
+    assert_eq!(
+        limiter.check_attempt(None, Some(account)).await,
+        Err(Duration::seconds(60))
+    );
+    assert!(limiter
+        .check_attempt(None, Some("other@example.com"))
+        .await
+        .is_ok());
+
+    clock.advance(Duration::seconds(45));
+    assert_eq!(
+        limiter.check_attempt(None, Some(account)).await,
+        Err(Duration::seconds(15))
+    );
+    clock.advance(Duration::seconds(15));
+    assert!(limiter.check_attempt(None, Some(account)).await.is_ok());
+
+    let mut lockouts = Vec::new();
+    for _ in 0..4 {
+        lockouts.push(limiter.record_failure(account).await);
+        clock.advance(Duration::seconds(300));
+    }
+    assert_eq!(
+        lockouts,
+        vec![
+            Some(Duration::seconds(120)),
+            Some(Duration::seconds(240)),
+            Some(Duration::seconds(300)),
+            Some(Duration::seconds(300)),
+        ]
+    );
+
+    limiter.record_success(account).await;
+    assert_eq!(limiter.record_failure(account).await, None);
+}
+
+#[tokio::test]
+async fn failures_are_forgotten_after_the_maximum_lockout() {
+    let clock = ManualClock::new();
+    let limiter = limiter(settings(), &clock);
+    let account = "reader@example.com";
+
+    limiter.record_failure(account).await;
+    limiter.record_failure(account).await;
+    clock.advance(Duration::seconds(301));
+    assert_eq!(limiter.record_failure(account).await, None);
+    assert_eq!(limiter.record_failure(account).await, None);
+    assert_eq!(
+        limiter.record_failure(account).await,
+        Some(Duration::seconds(60))
+    );
+}
+
+async fn login(body: web::Json<Value>) -> HttpResponse {
+    if body["password"] == "right" {
+        HttpResponse::Ok().json(json!({ "email": body["email"] }))
+    } else {
+        HttpResponse::Forbidden().finish()
+    }
+}
+
+#[actix_web::test]
+async fn refused_logins_are_answered_with_retry_after() {
+    let clock = ManualClock::new();
+    let limiter = web::Data::new(limiter(settings(), &clock));
+    let app = test::init_service(
+        App::new().app_data(limiter.clone()).service(
+            web::resource("/login")
+                .wrap(LoginRateLimit)
+                .route(web::post().to(login)),
+        ),
+    )
+    .await;
+    let address: SocketAddr = "10.0.0.1:40000".parse().unwrap();
+    let attempt = |password: &str| {
+        test::TestRequest::post()
+            .uri("/login")
+            .peer_addr(address)
+            .set_json(json!({ "email": "reader@example.com", "password": password }))
+            .to_request()
+    };
+
+    for _ in 0..3 {
+        let response = test::call_service(&app, attempt("wrong")).await;
+        assert_eq!(response.status().as_u16(), 403);
+    }
+
+    let response = test::call_service(&app, attempt("right")).await;
+    assert_eq!(response.status().as_u16(), 429);
+    assert_eq!(response.headers().get(header::RETRY_AFTER).unwrap(), "60");
+
+    clock.advance(Duration::seconds(60));
+    let response = test::call_service(&app, attempt("right")).await;
+    assert_eq!(response.status().as_u16(), 200);
+    let body: Value = test::read_body_json(response).await;
+    assert_eq!(body["email"], "reader@example.com");
+
+    // A successful login starts the count over.
+    for _ in 0..2 {
+        let response = test::call_service(&app, attempt("wrong")).await;
+        assert_eq!(response.status().as_u16(), 403);
+    }
+    let response = test::call_service(&app, attempt("right")).await;
+    assert_eq!(response.status().as_u16(), 200);
+}
+
+#[actix_web::test]
+async fn client_addresses_are_limited_without_an_account() {
+    let clock = ManualClock::new();
+    let limiter = web::Data::new(limiter(
+        LoginRateLimitSettings {
+            ip_capacity: 1,
+            ip_refill_seconds: 90,
+            ..settings()
+        },
+        &clock,
+    ));
+    let app = test::init_service(
+        App::new().app_data(limiter.clone()).service(
+            web::resource("/login")
+                .wrap(LoginRateLimit)
+                .route(web::post().to(login)),
+        ),
+    )
+    .await;
+    let address: SocketAddr = "10.0.0.1:40000".parse().unwrap();
+    let attempt = || {
+        test::TestRequest::post()
+            .uri("/login")
+            .peer_addr(address)
+            .set_json(json!({ "password": "wrong" }))
+            .to_request()
+    };
+
+    let response = test::call_service(&app, attempt()).await;
+    assert_eq!(response.status().as_u16(), 403);
+    let response = test::call_service(&app, attempt()).await;
+    assert_eq!(response.status().as_u16(), 429);
+    assert_eq!(response.headers().get(header::RETRY_AFTER).unwrap(), "90");
+}
@@ -0,0 +1,226 @@
+use actix_web::{
+    http::Method,
+    test::{call_and_read_body, call_service, init_service, TestRequest},
+    App,
+};
+use new_online_librarian_backend::{
+    routes::openapi::{api_docs, ApiDoc},
+    startup::configure_v1_routes,
+};
+use regex::Regex;
+use std::{collections::HashSet, fs, path::Path};
+use utoipa::OpenApi;
+
+fn documented_operations() -> Vec<(Method, String)> {
+    let openapi = ApiDoc::openapi();
+    let mut operations = Vec::new();
+    for (path, item) in openapi.paths.paths.iter() {
+        let probed_path = path
+            .split('/')
+            .map(|segment| match segment.starts_with('{') {
+                true => "1",
+                false => segment,
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        for (method, operation) in [
+            (Method::GET, &item.get),
+            (Method::POST, &item.post),
+            (Method::PUT, &item.put),
+            (Method::PATCH, &item.patch),
+            (Method::DELETE, &item.delete),
+        ] {
+            if operation.is_some() {
+                operations.push((method, probed_path.clone()));
+            }
+        }
+    }
+    operations
+}
+
+#[actix_web::test]
+async fn every_documented_operation_is_registered() {
+    // No controller is configured, so matched routes fail while extracting
+    // their arguments instead of reaching the database
+    let app = init_service(App::new().configure(configure_v1_routes)).await;
+
+    let operations = documented_operations();
+    assert!(!operations.is_empty());
+    let mut unregistered = Vec::new();
+    for (method, path) in operations.into_iter() {
+        let request = TestRequest::default()
+            .method(method.clone())
+            .uri(&path)
+            .to_request();
+        let status = call_service(&app, request).await.status().as_u16();
+        if status == 404 || status == 405 {
+            unregistered.push(format!("{} {} ({})", method, path, status));
+        }
+    }
+    assert!(
+        unregistered.is_empty(),
+        "Spec operations that aren't registered: {:?}",
+        unregistered
+    );
+}
+
+/// Actix can't list its routes, so they're read from the scopes
+/// `configure_v1_routes` registers and the route attributes of their handlers.
+fn registered_operations() -> Vec<(String, String)> {
+    let source_root = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let startup = fs::read_to_string(source_root.join("startup.rs")).unwrap();
+    let routes = startup
+        .split("pub fn configure_v1_routes")
+        .nth(1)
+        .and_then(|rest| rest.split("\n}").next())
+        .expect("configure_v1_routes isn't in startup.rs");
+    let scope_call = Regex::new(r"(\w+_controller_v1)::(get_\w+_scope)\(\)").unwrap();
+    let scope_path = Regex::new(r#"web::scope\("([^"]*)"\)"#).unwrap();
+    let service = Regex::new(r"\.service\((\w+)\)").unwrap();
+
+    let mut operations = Vec::new();
+    for call in scope_call.captures_iter(routes) {
+        let controller = find_controller(&source_root.join("modules"), &call[1]);
+        let scope = controller
+            .split(&format!("pub fn {}()", &call[2]))
+            .nth(1)
+            .and_then(|rest| rest.split("\n}").next())
+            .unwrap_or_else(|| panic!("{} isn't in {}", &call[2], &call[1]));
+        let prefix = &scope_path.captures(scope).expect("Scope has no path")[1];
+        for handler in service.captures_iter(scope) {
+            let route = Regex::new(&format!(
+                r#"#\[(get|post|put|patch|delete)\("([^"]*)"[^\]]*\]\s*(?:#\[[^\]]*\]\s*)*(?:pub )?async fn {}\("#,
+                &handler[1]
+            ))
+            .unwrap();
+            let route = route
+                .captures(&controller)
+                .unwrap_or_else(|| panic!("{} has no route attribute", &handler[1]));
+            operations.push((
+                route[1].to_uppercase(),
+                normalized_path(&format!("{}{}", prefix, &route[2])),
+            ));
+        }
+    }
+    operations
+}
+
+fn find_controller(modules: &Path, controller: &str) -> String {
+    for module in fs::read_dir(modules).unwrap() {
+        let path = module
+            .unwrap()
+            .path()
+            .join(format!("infra/controllers/v1/{}.rs", controller));
+        if path.exists() {
+            // Commented out services aren't registered
+            return fs::read_to_string(path)
+                .unwrap()
+                .lines()
+                .filter(|line| !line.trim_start().starts_with("//"))
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+    }
+    panic!("{} isn't in any module", controller);
+}
+
+fn normalized_path(path: &str) -> String {
+    Regex::new(r"\{[^}]*\}")
+        .unwrap()
+        .replace_all(path.trim_end_matches('/'), "{}")
+        .into_owned()
+}
+
+#[test]
+fn every_registered_operation_is_documented() {
+    let openapi = ApiDoc::openapi();
+    let mut documented = HashSet::new();
+    for (path, item) in openapi.paths.paths.iter() {
+        for (method, operation) in [
+            ("GET", &item.get),
+            ("POST", &item.post),
+            ("PUT", &item.put),
+            ("PATCH", &item.patch),
+            ("DELETE", &item.delete),
+        ] {
+            if operation.is_some() {
+                documented.insert((method.to_string(), normalized_path(path)));
+            }
+        }
+    }
+
+    let operations = registered_operations();
+    assert!(!operations.is_empty());
+    let undocumented: Vec<String> = operations
+        .into_iter()
+        .filter(|operation| !documented.contains(operation))
+        .map(|(method, path)| format!("{} {}", method, path))
+        .collect();
+    assert!(
+        undocumented.is_empty(),
+        "Registered operations that aren't documented: {:?}",
+        undocumented
+    );
+}
+
+#[actix_web::test]
+async fn unknown_operations_are_not_found() {
+    let app = init_service(App::new().configure(configure_v1_routes)).await;
+
+    for (method, path) in [(Method::GET, "/v1/unknown"), (Method::PUT, "/v1/books")] {
+        let request = TestRequest::default().method(method).uri(path).to_request();
+        let status = call_service(&app, request).await.status().as_u16();
+        assert!(
+            status == 404 || status == 405,
+            "{} answered {}",
+            path,
+            status
+        );
+    }
+}
+
+#[actix_web::test]
+async fn swagger_ui_is_served_without_external_assets() {
+    let app = init_service(App::new().service(api_docs())).await;
+
+    let page = call_and_read_body(&app, TestRequest::get().uri("/docs/").to_request()).await;
+    let page = String::from_utf8(page.to_vec()).unwrap();
+    assert!(page.contains("swagger-ui"));
+    assert!(
+        !page.contains("https://"),
+        "Docs page loads external assets"
+    );
+
+    let response = call_service(
+        &app,
+        TestRequest::get()
+            .uri("/docs/swagger-ui-bundle.js")
+            .to_request(),
+    )
+    .await;
+    assert_eq!(200, response.status().as_u16());
+
+    let spec = call_and_read_body(&app, TestRequest::get().uri("/openapi.json").to_request()).await;
+    let spec: serde_json::Value = serde_json::from_slice(&spec).unwrap();
+    assert_eq!("Online Librarian API", spec["info"]["title"]);
+}
+
+#[test]
+fn openapi_spec_documents_the_bearer_auth_scheme() {
+    let openapi = ApiDoc::openapi();
+    let components = openapi.components.expect("Spec has no components");
+
+    assert!(components.security_schemes.contains_key("bearer_auth"));
+    for schema in [
+        "CreateBookDto",
+        "CompleteBookDto",
+        "SimpleAPIError",
+        "DetailedAPIError",
+    ] {
+        assert!(
+            components.schemas.contains_key(schema),
+            "Schema {} is missing",
+            schema
+        );
+    }
+}
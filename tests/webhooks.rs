@@ -0,0 +1,264 @@
+use std::{
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+use chrono::{TimeZone, Utc};
+use hmac::{Hmac, Mac};
+use new_online_librarian_backend::modules::webhooks::{
+    domain::entities::{
+        webhook::Webhook,
+        webhook_delivery::{
+            webhook_backoff, webhook_payload, WebhookDelivery, WebhookDeliveryStatus,
+            MAX_WEBHOOK_BACKOFF_SECONDS,
+        },
+        webhook_event::WebhookEvent,
+        webhook_target::WebhookTargetPolicy,
+    },
+    infra::clients::{
+        webhook_client::WebhookClient,
+        webhook_client_reqwest::{
+            WebhookClientReqwest, WEBHOOK_DELIVERY_HEADER, WEBHOOK_EVENT_HEADER,
+            WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER,
+        },
+    },
+};
+use serde_json::{json, Value};
+use sha2::Sha256;
+
+#[derive(Clone)]
+struct ReceivedRequest {
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl ReceivedRequest {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+fn spawn_receiver(status: u16) -> (String, Arc<Mutex<Vec<ReceivedRequest>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind random port");
+    let port = listener.local_addr().unwrap().port();
+    let received = Arc::new(Mutex::new(Vec::new()));
+
+    let app_received = received.clone();
+    let server = HttpServer::new(move || {
+        let received = app_received.clone();
+        App::new().default_service(web::to(move |request: HttpRequest, body: web::Bytes| {
+            let received = received.clone();
+            async move {
+                received.lock().unwrap().push(ReceivedRequest {
+                    headers: request
+                        .headers()
+                        .iter()
+                        .map(|(name, value)| {
+                            (name.to_string(), value.to_str().unwrap_or("").to_string())
+                        })
+                        .collect(),
+                    body: body.to_vec(),
+                });
+                HttpResponse::build(actix_web::http::StatusCode::from_u16(status).unwrap()).finish()
+            }
+        }))
+    })
+    .workers(1)
+    .listen(listener)
+    .expect("Failed to listen")
+    .run();
+    tokio::spawn(server);
+
+    (format!("http://127.0.0.1:{}/hooks", port), received)
+}
+
+fn spawn_redirect(location: String) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind random port");
+    let port = listener.local_addr().unwrap().port();
+    let server = HttpServer::new(move || {
+        let location = location.clone();
+        App::new().default_service(web::to(move || {
+            let location = location.clone();
+            async move {
+                HttpResponse::TemporaryRedirect()
+                    .insert_header(("Location", location))
+                    .finish()
+            }
+        }))
+    })
+    .workers(1)
+    .listen(listener)
+    .expect("Failed to listen")
+    .run();
+    tokio::spawn(server);
+
+    format!("http://127.0.0.1:{}/hooks", port)
+}
+
+fn local_client() -> WebhookClientReqwest {
+    WebhookClientReqwest::new(
+        Duration::from_secs(5),
+        WebhookTargetPolicy::new(vec!["127.0.0.1".to_string()]),
+    )
+    .expect("Failed to build the client")
+}
+
+fn webhook(url: String) -> Webhook {
+    Webhook {
+        id: Some(7),
+        user_id: 1,
+        library_id: 3,
+        url,
+        secret: "s3cr3t".to_string(),
+        events: vec![WebhookEvent::BookCreated],
+        created_at: None,
+    }
+}
+
+fn delivery() -> WebhookDelivery {
+    WebhookDelivery {
+        id: Some(42),
+        webhook_id: 7,
+        event: WebhookEvent::BookCreated,
+        payload: webhook_payload(
+            WebhookEvent::BookCreated,
+            3,
+            Utc.with_ymd_and_hms(2026, 10, 19, 18, 0, 0).unwrap(),
+            json!({ "book_id": 12 }),
+        ),
+        status: WebhookDeliveryStatus::Pending,
+        attempts: 0,
+        next_attempt_at: None,
+        last_response_status: None,
+        last_error: None,
+        created_at: None,
+        delivered_at: None,
+    }
+}
+
+#[tokio::test]
+async fn deliveries_are_posted_with_a_verifiable_signature() {
+    let (url, received) = spawn_receiver(204);
+    let client = local_client();
+
+    let response_status = client.send(&webhook(url), &delivery()).await;
+
+    assert_eq!(response_status, Ok(204));
+    let requests = received.lock().unwrap().clone();
+    assert_eq!(requests.len(), 1);
+    let request = &requests[0];
+    assert_eq!(request.header(WEBHOOK_EVENT_HEADER), Some("book.created"));
+    assert_eq!(request.header(WEBHOOK_DELIVERY_HEADER), Some("42"));
+
+    let body: Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(body["event"], "book.created");
+    assert_eq!(body["library_id"], 3);
+    assert_eq!(body["data"]["book_id"], 12);
+
+    let timestamp = request.header(WEBHOOK_TIMESTAMP_HEADER).unwrap();
+    let mut mac = Hmac::<Sha256>::new_from_slice(b"s3cr3t").unwrap();
+    mac.update(format!("{}.", timestamp).as_bytes());
+    mac.update(&request.body);
+    let signature = request.header(WEBHOOK_SIGNATURE_HEADER).unwrap();
+    let signature = hex::decode(signature.strip_prefix("sha256=").unwrap()).unwrap();
+    assert!(mac.verify_slice(&signature).is_ok());
+}
+
+#[tokio::test]
+async fn failing_endpoints_report_their_status() {
+    let (url, _) = spawn_receiver(500);
+    let client = local_client();
+
+    assert_eq!(client.send(&webhook(url), &delivery()).await, Ok(500));
+}
+
+#[tokio::test]
+async fn unreachable_endpoints_report_an_error() {
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let client = local_client();
+
+    let url = format!("http://127.0.0.1:{}/hooks", port);
+    assert!(client.send(&webhook(url), &delivery()).await.is_err());
+}
+
+#[tokio::test]
+async fn loopback_endpoints_are_refused_unless_allowed() {
+    let (url, received) = spawn_receiver(204);
+    let client =
+        WebhookClientReqwest::new(Duration::from_secs(5), WebhookTargetPolicy::default()).unwrap();
+
+    assert!(client.send(&webhook(url), &delivery()).await.is_err());
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn hosts_resolving_to_loopback_are_refused_unless_allowed() {
+    let (url, received) = spawn_receiver(204);
+    let client = local_client();
+
+    let url = url.replace("127.0.0.1", "localhost");
+    assert!(client.send(&webhook(url), &delivery()).await.is_err());
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn redirects_are_not_followed() {
+    let (url, received) = spawn_receiver(204);
+    let client = local_client();
+
+    let url = spawn_redirect(url);
+    assert_eq!(client.send(&webhook(url), &delivery()).await, Ok(307));
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[test]
+fn backoff_doubles_after_every_attempt_up_to_a_cap() {
+    assert_eq!(webhook_backoff(1, 30).num_seconds(), 30);
+    assert_eq!(webhook_backoff(2, 30).num_seconds(), 60);
+    assert_eq!(webhook_backoff(3, 30).num_seconds(), 120);
+    assert_eq!(
+        webhook_backoff(40, 30).num_seconds(),
+        MAX_WEBHOOK_BACKOFF_SECONDS as i64
+    );
+}
+
+#[test]
+fn failed_attempts_are_retried_until_they_run_out() {
+    let now = Utc.with_ymd_and_hms(2026, 10, 19, 18, 0, 0).unwrap();
+    let mut delivery = delivery();
+
+    delivery.record_attempt(now, Some(500), None, 3, 30);
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(delivery.next_attempt_at, Some(now + webhook_backoff(1, 30)));
+    assert_eq!(delivery.last_response_status, Some(500));
+
+    delivery.record_attempt(now, None, Some("timed out".to_string()), 3, 30);
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(delivery.last_error.as_deref(), Some("timed out"));
+
+    delivery.record_attempt(now, Some(502), None, 3, 30);
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+    assert_eq!(delivery.attempts, 3);
+}
+
+#[test]
+fn successful_attempts_mark_the_delivery_as_delivered() {
+    let now = Utc.with_ymd_and_hms(2026, 10, 19, 18, 0, 0).unwrap();
+    let mut delivery = delivery();
+
+    delivery.record_attempt(now, Some(500), None, 3, 30);
+    delivery.record_attempt(now, Some(200), None, 3, 30);
+
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Delivered);
+    assert_eq!(delivery.delivered_at, Some(now));
+    assert_eq!(delivery.last_error, None);
+}